@@ -0,0 +1,58 @@
+//! The full analysis pass on one thread against the rayon pool, on two years of
+//! 12 symbols: `cargo bench --bench compute_all`
+
+use std::hint::black_box;
+
+use chrono::NaiveDate;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use mkt_noise_analysis::analysis::compute_all;
+use mkt_noise_analysis::data::models::{AnalysisWindows, MarketData, OhlcvBar, SectorTimeSeries};
+use rand::{Rng, SeedableRng};
+
+const SYMBOLS: usize = 12;
+const DAYS: usize = 504;
+const KURTOSIS_WINDOW: usize = 60;
+
+/// `n` symbols of random-walk daily bars, seeded so runs are comparable
+fn market(n: usize, days: usize) -> MarketData {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    let start = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+    let mut walk = |symbol: String| {
+        let mut close = 100.0;
+        let bars = (0..days)
+            .map(|i| {
+                close *= 1.0 + rng.gen_range(-0.02..0.02);
+                OhlcvBar {
+                    date: start + chrono::Duration::days(i as i64),
+                    open: close,
+                    high: close * 1.01,
+                    low: close * 0.99,
+                    close,
+                    volume: 1_000,
+                }
+            })
+            .collect();
+        SectorTimeSeries { name: symbol.clone(), symbol, bars }
+    };
+    let sectors = (0..n).map(|i| walk(format!("XL{}", i))).collect();
+    MarketData { sectors, benchmark: Some(walk("SPY".to_string())), ..Default::default() }
+}
+
+fn analysis_pass(c: &mut Criterion) {
+    let data = market(SYMBOLS, DAYS);
+    let windows = AnalysisWindows::default();
+    let single = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+
+    let mut group = c.benchmark_group("compute_all");
+    group.sample_size(20);
+    group.bench_function("one_thread", |bench| {
+        single.install(|| bench.iter(|| compute_all(black_box(&data), windows, KURTOSIS_WINDOW)))
+    });
+    group.bench_function(BenchmarkId::new("rayon_pool", rayon::current_num_threads()), |bench| {
+        bench.iter(|| compute_all(black_box(&data), windows, KURTOSIS_WINDOW))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, analysis_pass);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! Sliding-window kernels against recomputing every window from scratch, on a year of
+//! one-minute bars: `cargo bench --bench rolling`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use mkt_noise_analysis::analysis::{cross_sector, kurtosis, volatility};
+use rand::{Rng, SeedableRng};
+
+const BARS: usize = 252 * 390;
+const WINDOW: usize = 390;
+
+/// Fat-tailed random returns
+fn returns(n: usize, seed: u64) -> Vec<f64> {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    (0..n)
+        .map(|_| {
+            let r: f64 = rng.gen_range(-0.01..0.01);
+            if rng.gen_bool(0.05) { r * 5.0 } else { r }
+        })
+        .collect()
+}
+
+fn naive_volatility(data: &[f64], window: usize) -> Vec<f64> {
+    data.windows(window)
+        .map(|w| {
+            let mean = w.iter().sum::<f64>() / w.len() as f64;
+            let var = w.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (w.len() - 1) as f64;
+            (var * 252.0).sqrt()
+        })
+        .collect()
+}
+
+fn rolling_kernels(c: &mut Criterion) {
+    let a = returns(BARS, 4);
+    let b = returns(BARS, 5);
+
+    let mut group = c.benchmark_group("volatility");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("naive", WINDOW), |bench| {
+        bench.iter(|| naive_volatility(black_box(&a), WINDOW))
+    });
+    group.bench_function(BenchmarkId::new("sliding", WINDOW), |bench| {
+        bench.iter(|| volatility::rolling_volatility(black_box(&a), WINDOW))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("correlation");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("naive", WINDOW), |bench| {
+        bench.iter(|| {
+            black_box(&a)
+                .windows(WINDOW)
+                .zip(black_box(&b).windows(WINDOW))
+                .map(|(x, y)| cross_sector::pearson_correlation(x, y))
+                .collect::<Vec<f64>>()
+        })
+    });
+    group.bench_function(BenchmarkId::new("sliding", WINDOW), |bench| {
+        bench.iter(|| cross_sector::rolling_correlation(black_box(&a), black_box(&b), WINDOW))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("kurtosis");
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("naive", WINDOW), |bench| {
+        bench.iter(|| black_box(&a).windows(WINDOW).map(kurtosis::excess_kurtosis).collect::<Vec<f64>>())
+    });
+    group.bench_function(BenchmarkId::new("sliding", WINDOW), |bench| {
+        bench.iter(|| kurtosis::rolling_kurtosis(black_box(&a), WINDOW))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, rolling_kernels);
+criterion_main!(benches);
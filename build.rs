@@ -0,0 +1,15 @@
+fn main() {
+    println!("cargo:rerun-if-changed=proto/vol_analysis.proto");
+
+    // The gRPC service is generated from the proto with a bundled protoc, so building
+    // with `--features grpc` needs no system install
+    #[cfg(feature = "grpc")]
+    {
+        let protoc = protoc_bin_vendored::protoc_bin_path().expect("No bundled protoc for this platform");
+        std::env::set_var("PROTOC", protoc);
+        tonic_build::configure()
+            .build_client(false)
+            .compile_protos(&["proto/vol_analysis.proto"], &["proto"])
+            .expect("Failed to compile proto/vol_analysis.proto");
+    }
+}
@@ -0,0 +1,1142 @@
+# Deutsche Oberfläche. Nachrichten-IDs und { $name }-Platzhalter entsprechen en.ftl.
+
+## Allgemein
+
+cancel = Abbrechen
+copy = Kopieren
+dismiss = Schließen
+reset = Zurücksetzen
+sort-by-column = Nach dieser Spalte sortieren
+
+## Tabs
+
+tab-dashboard = Übersicht
+tab-sector-vol = Sektor-Vola
+tab-correlations = Korrelationen
+tab-bonds = Anleihen
+tab-rates = Zinsen
+tab-kurtosis = Kurtosis
+tab-risk = Risiko
+tab-compare = Vergleich
+tab-watchlist = Beobachtungsliste
+tab-plugins = Plugins
+tab-data = Daten
+tab-neural-net = Neuronales Netz
+tab-settings = Einstellungen
+tab-detached = { $tab } ist in einem eigenen Fenster geöffnet.
+reattach = Wieder andocken
+
+## Obere Leiste
+
+refresh-data = Daten aktualisieren
+report-hover = HTML-Marktbericht speichern (für PDF im Browser drucken; Rechtsklick zum Versenden per E-Mail)
+email-report = Bericht per E-Mail senden
+email-report-disabled = SMTP-Server und Empfänger in den Einstellungen einrichten
+workbook-hover = Gesamte Analyse als Excel-Arbeitsmappe exportieren (Rechtsklick für Arrow)
+export-arrow = Als Arrow IPC exportieren (polars/pandas)
+export-arrow-hover = Kurs- und Vola-Reihen sowie Spreads als .arrow-Dateien (Feather)
+report-saved = Bericht gespeichert: { $path }
+report-failed = Bericht fehlgeschlagen: { $error }
+report-emailing = Bericht wird an { $count } Empfänger gesendet…
+report-email-failed = Berichts-E-Mail fehlgeschlagen: { $error }
+workbook-saved = Arbeitsmappe gespeichert: { $path }
+workbook-failed = Export der Arbeitsmappe fehlgeschlagen: { $error }
+export-arrow-saved = Arrow-Dateien gespeichert: { $paths }
+export-arrow-failed = Arrow-Export fehlgeschlagen: { $error }
+detach-tab-hover = Diesen Tab in einem eigenen Fenster öffnen
+screenshot-hover = Bildschirmfoto aufnehmen (Rechtsklick für Bereich und Zwischenablage)
+screenshot-save-file = Als Datei speichern
+screenshot-copy = In die Zwischenablage kopieren
+screenshot-scope-window = Ganzes Fenster
+screenshot-scope-active-tab = Aktiver Reiter
+screenshot-scope-region = Bereich auswählen…
+screenshot-region-hint = Bereich für das Bildschirmfoto aufziehen (Esc bricht ab)
+screenshot-saved = Bildschirmfoto gespeichert: { $path }
+screenshot-failed = Bildschirmfoto fehlgeschlagen: { $error }
+screenshot-copied = Bildschirmfoto in die Zwischenablage kopiert.
+screenshot-copy-failed = Kopieren des Bildschirmfotos fehlgeschlagen: { $error }
+
+## Statusleiste
+
+fixtures-hover = Antworten der Anbieter stammen aus dem Ordner { $folder }/
+status-errors = Fehler: { $count }
+status-cache = Cache { $hits } Treffer / { $misses } Fehlgriffe
+source-data-as-of = Datenstand { $time }
+source-not-fetched = In dieser Sitzung nicht abgerufen
+source-cache = Cache-Treffer: { $hits }  Fehlgriffe: { $misses }
+source-last-error = Letzter Fehler: { $error }
+auto-refresh-off = Aus
+auto-refresh-15m = 15 Min.
+auto-refresh-30m = 30 Min.
+auto-refresh-1h = 1 Stunde
+auto-refresh-4h = 4 Stunden
+auto-refresh-custom = Benutzerdefiniert
+auto-refresh-label = Auto-Aktualisierung:
+auto-refresh-now = Auto-Aktualisierung: jetzt
+auto-refresh-next = Nächste Aktualisierung in { $wait }
+auto-refresh-save-failed = Aktualisierungsintervall konnte nicht gespeichert werden: { $error }
+
+## Benachrichtigungen
+
+notices-title = Benachrichtigungen
+notices-placeholder = Bereit. Auf „Daten aktualisieren“ klicken, um Marktdaten abzurufen.
+notices-latest-hover = { $time } · { $source } · klicken für alle Benachrichtigungen
+notices-unread = ⚠ { $count } neu
+notices-hover = Fehler und Meldungen dieser Sitzung
+notices-min-severity = { $severity } und höher
+notices-all-sources = Alle Quellen
+notices-counts = { $errors } Fehler, { $warnings } Warnungen
+notices-empty = Nichts anzuzeigen.
+notices-retry-busy = Warten, bis die Aktualisierung abgeschlossen ist
+notices-copy-hover = Aufgelistete Benachrichtigungen als Text kopieren
+notices-clear-all = Alle löschen
+session-restored = Die vorige Sitzung vom { $time } wurde nach einem unerwarteten Ende wiederhergestellt.
+session-training-interrupted = Das Training wurde nach { $epochs }/{ $total } Epochen unterbrochen; der unvollständige Lauf steht im Trainingsverlauf.
+severity-info = Info
+severity-warning = Warnung
+severity-error = Fehler
+source-data = Daten
+source-analysis = Analyse
+source-training = Training
+source-export = Export
+source-settings = Einstellungen
+source-jobs = Aufträge
+retry-refresh = Aktualisieren
+retry-refetch = { $symbol } erneut abrufen
+retry-train = Neu trainieren
+notice-analysis-failed = { $name } fehlgeschlagen: { $error }
+
+## Aufgaben
+
+tasks-title = Aufgaben
+tasks-running = { $count } laufend
+tasks-failed = ⚠ { $count } fehlgeschlagen
+tasks-hover = Abrufe, Training und Benchmarks im Hintergrund
+tasks-empty = Keine Hintergrundaufgaben in dieser Sitzung.
+tasks-clear-finished = Abgeschlossene entfernen
+task-running = Läuft
+task-cancelling = Wird abgebrochen…
+task-done = Fertig
+task-cancelled = Abgebrochen
+task-failed = Fehlgeschlagen
+task-finished-in = Fertig nach { $seconds } s
+task-symbols = { $done }/{ $total } Symbole
+task-failed-notice = { $task } fehlgeschlagen: { $error }
+task-cancelled-notice = { $task } abgebrochen
+task-kind-startup = Start
+task-kind-refresh = Aktualisierung
+task-kind-refetch = Erneuter Abruf
+task-kind-watchlist = Beobachtungsliste
+task-kind-api-test = API-Test
+task-kind-training = Training
+task-kind-sweep = Hyperparametersuche
+task-kind-gpu-diagnostics = GPU-Diagnose
+task-kind-webhook = Webhook
+task-kind-email = E-Mail
+task-kind-grpc = gRPC
+task-load-cache = Zwischengespeicherte Daten laden
+task-webhook-one = Alarme an { $name } senden
+task-webhook-many = Alarme an { $count } Webhooks senden
+task-email = Bericht per E-Mail an { $count } Empfänger
+task-grpc = gRPC-Server auf { $address }
+
+## Übersicht
+
+dashboard-heading = Marktstruktur-Übersicht
+dashboard-no-data = Keine Daten geladen. Auf „Daten aktualisieren“ klicken, um Marktdaten abzurufen.
+card-sectors-loaded = Geladene Sektoren
+card-benchmark-last-close = { $symbol } Schlusskurs
+card-avg-correlation = Mittlere Kreuzkorrelation
+card-term-spread = 10J-2J-Spread
+card-treasury-points = Treasury-Datenpunkte
+unit-bps = { $value } Bp.
+dashboard-heatmap-heading = Heatmap der Sektorvolatilität
+dashboard-search-matches = { $count } Sektor(en) passend zu „{ $query }“
+dashboard-open-sector = { $symbol } in Sektor-Vola öffnen
+dashboard-sector-performance-heading = FMP-Sektorperformance (Echtzeit)
+col-sector = Sektor
+col-symbol = Symbol
+col-last-close = Schlusskurs
+col-short-vol = 21T-Vola
+col-long-vol = 63T-Vola
+col-vol-ratio = Vola-Verhältnis
+col-beta = Beta
+col-bars = Kurse
+col-change-pct = Änderung %
+col-entropy = Entropie
+col-hurst = Hurst-Exp.
+col-behavior = Verhalten
+dashboard-put-call-heading = Put/Call-Verhältnis & SKEW
+dashboard-put-call-empty = Keine Put/Call- oder SKEW-Daten. Sie werden mit „Daten aktualisieren“ abgerufen.
+dashboard-put-call-height = Diagrammhöhe P/C-Verhältnis & SKEW
+axis-trading-day = Handelstag (aktuell -> früher)
+axis-pc-ratio = P/C-Verhältnis
+series-total-pc-ratio = Gesamt-P/C-Verhältnis
+dashboard-randomness-heading = Marktzufälligkeit - 3D-Verteilung gemeinsamer Renditen
+randomness-sector-x = Sektor X:
+randomness-sector-y = Sektor Y:
+randomness-pitch = Neigung:
+randomness-yaw = Drehung:
+randomness-rendering = 3D-Verteilung wird gezeichnet...
+randomness-caption = Gemeinsame Verteilung: { $x } vs. { $y }
+randomness-metrics-heading = Zufälligkeitskennzahlen der Sektoren
+randomness-legend = Entropie: höher = zufälliger. Hurst: 0,5 = Random Walk, >0,5 = trendfolgend, <0,5 = mittelwertrückkehrend. AC = Autokorrelation (nahe 0 = zufällig).
+behavior-random-walk = Random Walk
+behavior-trending = Trendfolgend
+behavior-mean-reverting = Mittelwertrückkehrend
+
+## Einstellungen
+
+settings-profiles = Profile
+settings-workspace = Arbeitsbereich
+settings-screenshot = Bildschirmfoto
+settings-scheduled-capture = Geplante Aufnahme
+settings-email = E-Mail-Berichte
+settings-background = Hintergrundbetrieb
+settings-grpc = gRPC-Server
+settings-webhooks = Alarm-Webhooks
+settings-display = Anzeige
+settings-api-keys = API-Schlüssel
+settings-logging = Protokollierung
+settings-analysis-windows = Analysefenster
+settings-custom-indicators = Eigene Indikatoren
+settings-nn-training = Training des neuronalen Netzes
+settings-language = Sprache:
+settings-language-hover = Sprache der Oberfläche sowie der Zahlen- und Datumsformate.
+settings-language-save-failed = Sprache konnte nicht gespeichert werden: { $error }
+settings-zoom = Oberflächenzoom:
+settings-zoom-hover = Skaliert die gesamte Oberfläche, z. B. für hochauflösende Monitore oder Präsentationen.
+settings-font-size = Schriftgröße:
+settings-display-save-failed = Anzeigeeinstellungen konnten nicht gespeichert werden: { $error }
+settings-palette = Farbpalette:
+settings-palette-hover = Farben für ruhige und angespannte Werte in Übersicht, Heatmap und Korrelationsansichten.
+settings-palette-save-failed = Farbpalette konnte nicht gespeichert werden: { $error }
+palette-calm = Ruhig / Gewinn
+palette-normal = Normal
+palette-stressed = Angespannt / Verlust
+settings-explain-metrics = Kennzahlen beim Überfahren erklären
+settings-explain-metrics-hover = Beim Überfahren von Parkinson-Vola, Vola-Verhältnis, 10J-2J-Spread oder mittlerer Korrelation werden Definition, Formel und Lesart angezeigt.
+settings-explain-save-failed = Erklärmodus konnte nicht gespeichert werden: { $error }
+settings-save = Speichern
+settings-delete = Löschen
+settings-overwrite = Überschreiben
+settings-select = Auswählen…
+settings-test = Testen
+settings-save-settings = Einstellungen speichern
+settings-profile = Profil:
+settings-load = Laden
+settings-profile-save-as = Aktuelle speichern als:
+settings-profile-hint = z. B. Makro wöchentlich
+settings-profile-saved = Profil „{ $name }“ gespeichert.
+settings-profile-loaded = Profil „{ $name }“ geladen.
+settings-profile-help = Ein Profil bündelt Sektoruniversum, Beobachtungsliste, Analysefenster, NN-Hyperparameter und Merkmalsgruppen sowie Farbpalette und Anzeigeeinstellungen.
+settings-workspace-label = Arbeitsbereich:
+settings-workspace-open = Arbeitsbereich öffnen
+settings-workspace-open-hover = Ersetzt die geladenen Daten, Analyseeinstellungen, das Layout und das gespeicherte Modell
+settings-workspace-save-as = Arbeitsbereich speichern als:
+settings-workspace-hint = z. B. Rückblick Q3
+settings-workspace-save = Arbeitsbereich speichern
+settings-workspace-saved = Arbeitsbereich gespeichert: { $path }
+settings-workspace-opened = Arbeitsbereich „{ $name }“ geöffnet.
+settings-workspace-help = Ein Arbeitsbereich bündelt Marktdaten, Analyseeinstellungen, eigene Indikatoren, Layout, NN-Prognosen und das trainierte Modell in einer Datei unter { $dir }/, um eine Sitzung zu archivieren oder weiterzugeben. Die Analyse wird beim Öffnen aus den enthaltenen Daten neu berechnet. Webhooks, E-Mail, Aufträge, gRPC und andere rechnerspezifische Einstellungen sind nicht enthalten.
+settings-save-path = Speicherort:
+settings-browse = Durchsuchen…
+settings-file-type = Dateityp:
+settings-tiff-hover = Die Komprimierungsstufe hat bei TIFF keine Wirkung
+settings-compression = Komprimierung:
+settings-compression-none = Keine
+settings-compression-low = Niedrig
+settings-compression-high = Hoch
+settings-capture = Aufnahme:
+settings-screenshot-saved = Bildschirmfoto-Einstellungen gespeichert.
+settings-screenshot-save-failed = Bildschirmfoto-Einstellungen konnten nicht gespeichert werden: { $error }
+settings-screenshot-help = Mit der 📷-Kamera in der Reiterleiste wird ein Bildschirmfoto aufgenommen; ein Rechtsklick kopiert es stattdessen in die Zwischenablage.
+settings-output-folder = Ausgabeordner:
+settings-open-jobs = Aufträge öffnen
+settings-jobs-help = Bericht-, Bildschirmfoto- und E-Mail-Aufträge und ihre Zeitpläne werden im Fenster Aufträge eingerichtet.
+settings-output-help = Jeder Lauf schreibt in einen Unterordner JJJJ-MM-TT. Ein Auftrag, der verpasst wurde, während die App geschlossen war, läuft einmal beim nächsten Start; das Bildschirmfoto zeigt den Reiter Übersicht.
+settings-output-save-failed = Ausgabeordner konnte nicht gespeichert werden: { $error }
+settings-smtp-server = SMTP-Server:
+settings-port = Port:
+settings-security = Sicherheit:
+settings-security-none = Keine (lokales Relay)
+settings-username = Benutzername:
+settings-from = Absender:
+settings-from-hint = Wie Benutzername
+settings-recipients = Empfänger:
+settings-smtp-password-missing = Das SMTP-Passwort unter API-Schlüssel hinterlegen.
+settings-send-report = Bericht jetzt senden
+settings-send-report-disabled = SMTP-Server und mindestens einen Empfänger eingeben
+settings-email-help = Der Bericht wird als HTML mit eingebetteten Diagrammen gesendet. Für den zeitgesteuerten Versand im Fenster Aufträge einen Auftrag „Bericht per E-Mail“ anlegen.
+settings-email-save-failed = E-Mail-Einstellungen konnten nicht gespeichert werden: { $error }
+settings-tray-close-hint = Beim Schließen wird das Fenster in den Infobereich verschoben; automatische Aktualisierung und Alarme laufen weiter. Beenden über das Menü im Infobereich.
+settings-no-tray-close-hint = Dieser Build hat kein Infobereichssymbol (Feature `tray`), daher minimiert das Schließen das Fenster; automatische Aktualisierung und Alarme laufen weiter.
+settings-keep-running = Nach dem Schließen des Fensters weiterlaufen
+settings-desktop-notifications = Desktop-Benachrichtigungen für Alarme im Hintergrund
+settings-tray-unavailable = Infobereichssymbol nicht verfügbar: { $error }
+settings-test-notification = Testbenachrichtigung senden
+settings-test-notification-body = Alarmbenachrichtigungen funktionieren.
+settings-background-save-failed = Einstellungen für den Hintergrundmodus konnten nicht gespeichert werden: { $error }
+settings-grpc-unavailable = Dieser Build hat keinen gRPC-Server. Zum Aktivieren mit `cargo build --release --features grpc` neu bauen.
+settings-grpc-enable = Analyse und Prognosen über gRPC bereitstellen
+settings-grpc-enable-hover = Dienstdefinition: proto/vol_analysis.proto
+settings-grpc-address = Adresse:
+settings-grpc-address-hover = Mit 0.0.0.0:<port> werden auch Verbindungen von anderen Rechnern angenommen
+settings-grpc-listening = Lauscht auf { $address }
+settings-grpc-stopped = Gestoppt.
+settings-grpc-not-started = gRPC-Server nicht gestartet: { $error }
+settings-grpc-start = Starten
+settings-grpc-save-failed = gRPC-Einstellungen konnten nicht gespeichert werden: { $error }
+settings-webhooks-empty = Noch keine Webhooks. Ausgelöste Alarme werden an jeden aktiven Webhook gesendet.
+settings-webhook-name = Name
+settings-webhook-test-hover = Einen Beispielalarm an diesen Webhook senden
+settings-webhook-delete = Webhook löschen
+settings-webhook-add = Webhook hinzufügen
+settings-webhooks-help = Slack und Discord: die Incoming-Webhook-URL des Kanals einfügen. JSON sendet {"text", "alerts"} an eine beliebige URL. Fehlgeschlagene Sendungen erscheinen im Fenster Aufgaben.
+settings-webhook-testing = Testalarm an { $webhook } wird gesendet…
+settings-webhooks-save-failed = Webhooks konnten nicht gespeichert werden: { $error }
+settings-api-used-for = Verwendet für: { $uses }
+settings-api-not-set = Nicht gesetzt
+settings-api-new-key = Neuer Schlüssel
+settings-api-key-saved = Schlüssel für { $provider } gespeichert; er gilt ab der nächsten Aktualisierung.
+settings-api-key-save-failed = Schlüssel für { $provider } konnte nicht gespeichert werden: { $error }
+settings-api-clear = Entfernen
+settings-api-clear-hover = Gespeicherten Schlüssel vergessen; { $var } aus .env oder der Umgebung gilt beim nächsten Start weiterhin
+settings-api-key-cleared = Schlüssel für { $provider } entfernt.
+settings-api-key-clear-failed = Schlüssel für { $provider } konnte nicht entfernt werden: { $error }
+settings-api-test = Verbindung testen
+settings-api-show-keys = Schlüssel anzeigen
+settings-api-help = Schlüssel werden je Umgebung in cache/{ $file } gespeichert und haben Vorrang vor .env; ein in der Prozessumgebung gesetzter Schlüssel hat beim Start Vorrang vor beiden. Andere Umgebungen speichern ihre Downloads unter cache/env/.
+settings-api-environment = Umgebung:
+settings-api-env-remove = Entfernen
+settings-api-env-remove-hover = Die Schlüssel dieser Umgebung löschen; ihre zwischengespeicherten Daten bleiben erhalten
+settings-api-env-removed = API-Umgebung { $name } entfernt.
+settings-api-env-remove-failed = API-Umgebung { $name } konnte nicht entfernt werden: { $error }
+settings-api-env-new = Neue Umgebung
+settings-api-env-add = Hinzufügen
+settings-api-env-add-failed = API-Umgebung konnte nicht hinzugefügt werden: { $error }
+settings-api-env-switched = Zur API-Umgebung { $name } gewechselt; zum Laden ihrer Daten aktualisieren.
+settings-api-env-switch-failed = Wechsel der API-Umgebung fehlgeschlagen: { $error }
+settings-fmp-endpoint = FMP-Endpunkt:
+settings-fmp-endpoint-hover = FMP-Anfragen dieser Umgebung hierhin senden, z. B. an eine Sandbox; leer lassen für die Live-API
+settings-fmp-endpoint-saved = FMP-Endpunkt gespeichert; er gilt ab der nächsten Aktualisierung.
+settings-fmp-endpoint-save-failed = FMP-Endpunkt konnte nicht gespeichert werden: { $error }
+settings-api-test-task = Verbindungstest { $provider }
+settings-log-level = Protokollstufe:
+settings-log-level-hover = Debug und Trace enthalten Details je Anfrage und Epoche und lassen die Protokolldateien schnell wachsen.
+settings-log-level-save-failed = Protokollstufe konnte nicht gespeichert werden: { $error }
+settings-log-help = Protokolle werden mit einer Datei pro Tag nach { $dir }/ geschrieben; die letzten { $days } Tage bleiben erhalten.
+settings-short-vol = Kurzes Vola-Fenster:
+settings-days = Tage
+settings-long-vol = Langes Vola-Fenster:
+settings-lookback = Historie:
+settings-calendar-days = Kalendertage
+settings-lookback-hover = Wie viel Historie jede Aktualisierung herunterlädt
+settings-presets = Vorgaben:
+settings-preset = { $short }T / { $long }T
+settings-defaults = Standard
+settings-apply = Übernehmen
+settings-windows-save-failed = Analysefenster konnten nicht gespeichert werden: { $error }
+settings-windows-applied-lookback = Analysefenster übernommen; die neue Historie gilt ab der nächsten Aktualisierung.
+settings-windows-applied = Analysefenster übernommen.
+settings-revert = Verwerfen
+settings-windows-help = Die Vola-Fenster gelten für Übersicht, Sektoren, Risiko, Vergleich und Beobachtungsliste. Das neuronale Netz behält seine eingebauten Fenster, damit gespeicherte Modelle gültig bleiben.
+settings-indicator-enabled-hover = In Sektor-Vola anzeigen
+settings-edit = Bearbeiten
+settings-indicator-new = Neuer Indikator
+settings-indicator-name = Name:
+settings-indicator-test-result = { $symbol }: { $count } Werte, zuletzt { $latest }
+settings-indicator-duplicate = Name bereits vergeben
+settings-indicators-help = Rhai-Skripte über den in Sektor-Vola gezeigten Sektor: open, high, low, close, volume, returns, vol_short, vol_long und parkinson sind Arrays (älteste zuerst); sma(a, n), ema(a, n) und stdev(a, n) stehen zur Verfügung. Das Skript endet mit einem Array von Zahlen, das am letzten Balken ausgerichtet wird. Unter Training des neuronalen Netzes markierte Indikatoren dienen als Merkmal.
+settings-nn-features = Eingabemerkmale für das Modelltraining auswählen:
+settings-nn-sector-vol = Sektor-Vola (11 Merkmale)
+settings-nn-randomness = Marktzufälligkeit (22 Merkmale)
+settings-nn-kurtosis = Kurtosis (22 Merkmale)
+settings-nn-custom = Eigener: { $name } ({ $count } Merkmale)
+settings-nn-feature-count = Eingabemerkmale pro Tag: { $count } ({ $base } Basis + aktivierte Gruppen)
+settings-nn-hyperparameters = Hyperparameter:
+settings-nn-hidden-size = Versteckte Größe:
+settings-nn-lookback = Rückblick:
+settings-nn-learning-rate = Lernrate:
+settings-nn-epochs = Epochen:
+settings-nn-target = Ziel:
+settings-nn-target-level = Vola-Niveau (MSE)
+settings-nn-target-regime = Vola-Regime (niedrig / normal / hoch)
+settings-nn-forecast = Prognose:
+settings-nn-forecast-hover = Reihe, die der Vola-Ausgang vorhersagt. Änderungs- und Log-Ziele werden für Prognosen und Backtests in Vola-Niveaus zurückgerechnet.
+settings-nn-loss = Verlustfunktion:
+settings-nn-loss-mse = (MSE für dieses Ziel)
+settings-nn-grad-clip = Gradientenbegrenzung:
+settings-nn-max-norm = Max. Norm
+settings-nn-precision = Genauigkeit:
+settings-nn-half-precision = Halbe Genauigkeit (f16) auf der GPU
+settings-nn-half-precision-hover = Weniger VRAM-Bedarf und schneller auf Consumer-GPUs; nutzt dynamische Verlustskalierung. Fällt ohne Unterstützung auf f32 zurück.
+settings-nn-batch-size = Batchgröße:
+settings-nn-batch-auto = Automatisch
+settings-nn-batch-auto-hover = GPU: größter Batch, der in den freien VRAM passt. CPU: 32.
+settings-nn-augmentation = Augmentierung:
+settings-nn-augmentation-hover = Zufällige Störungen der Trainingsfenster gegen Überanpassung. 0 schaltet die jeweilige ab.
+settings-nn-jitter = Rauschen σ
+settings-nn-warp = Zeitverzerrung ±
+settings-nn-feature-dropout = Merkmal-Dropout
+settings-nn-metrics-export = Metrikexport:
+settings-nn-metrics-csv-hover = Verlust, Lernrate und Gradientennorm je Epoche in cache/training_runs/
+settings-nn-tensorboard = TensorBoard-Ereignisse
+settings-nn-gpu-adapter = GPU-Adapter:
+settings-nn-gpu-default = Standard
+settings-nn-gpu-save-failed = GPU-Adapter konnte nicht gespeichert werden: { $error }
+settings-nn-inference-precision = Inferenzgenauigkeit:
+settings-nn-inference-precision-hover = Quantisierte Gewichte machen Prognoseaktualisierungen auf der CPU nahezu sofort, bei geringem Genauigkeitsverlust. Das Training bleibt unberührt.
+settings-nn-inference-save-failed = Inferenzgenauigkeit konnte nicht gespeichert werden: { $error }
+settings-nn-drift = Drift-Warnung:
+settings-nn-drift-hover = Live-Prognosen werden bewertet, sobald die realisierte Vola bekannt ist; warnen, wenn ihr rollierender Fehler die Schwelle überschreitet.
+settings-nn-drift-window = Rollierender Fehler über die letzten
+settings-nn-drift-threshold = Prognosen über
+settings-saved = Einstellungen gespeichert.
+settings-save-failed = Einstellungen konnten nicht gespeichert werden: { $error }
+settings-nn-apply-help = Die Einstellungen gelten ab dem nächsten Trainingslauf.
+palette-standard = Standard (rot/grün)
+palette-colorblind = Farbenblind-sicher (blau/orange)
+
+## Diagramme und Tabellen
+
+chart-drag-to-resize = zum Anpassen ziehen ·
+export-csv = CSV exportieren
+export-csv-hover = Die hier gezeigten Reihen als CSV-Datei speichern
+export-csv-done = Exportiert: { $path }
+export-csv-failed = CSV-Export fehlgeschlagen: { $error }
+copy-table = Tabelle kopieren
+copy-table-done = { $count } Zeilen in die Zwischenablage kopiert
+no-data-loaded = Keine Daten geladen. Auf „Daten aktualisieren“ klicken, um Marktdaten abzurufen.
+axis-trading-day-plain = Handelstag
+
+## Sektoren
+
+event-fomc = FOMC
+event-cpi = VPI
+event-earnings = Quartalszahlen
+event-inversion = Zinskurveninversion
+event-note = Notiz
+sector-heading = Sektorvolatilitätsanalyse
+sector-select = Sektor wählen:
+sector-unknown = Unbekannt
+sector-select-placeholder = Auswählen …
+sector-overlay = { $symbol } überlagern
+sector-overlay-hover = Benchmarkkurs auf den ersten Schlusskurs des Sektors umbasiert, dazu seine Kurzfenster-Vola
+sector-period = Zeitraum:
+sector-period-range = { $from } bis { $to } ({ $days } Handelstage)
+sector-full-history = Gesamte Historie
+sector-chart-help = Ziehen oder Strg+Scrollen verschiebt/zoomt die Zeit in allen Diagrammen unten; Umschalt+Ziehen zoomt auf einen Bereich; Rechts- oder Doppelklick setzt zurück.
+sector-rebased = { $symbol } (umbasiert)
+sector-vol-series = { $days }T-Vola
+sector-price-chart = Kursdiagramm
+sector-price-height = Höhe des Kursdiagramms
+sector-axis-price = Kurs ($)
+sector-volume-height = Höhe des Volumendiagramms
+sector-volume-avg = Ø { $days }T
+sector-volume = Volumen
+sector-axis-volume = Volumen (Mio.)
+sector-drawdown-height = Höhe des Drawdown-Diagramms
+sector-axis-drawdown = Drawdown (%)
+sector-max-dd = Max. DD { $depth }
+sector-not-recovered = noch nicht erholt
+sector-recovered = erholt am { $date }
+sector-drawdown-summary = { $symbol } maximaler Drawdown { $depth }: Hoch { $peak } → Tief { $trough }, { $recovery }
+sector-return-distribution = Renditeverteilung
+sector-qq-plot = QQ-Diagramm
+sector-qq-window = Verwendet das Fenster der Renditeverteilung.
+sector-showing-vol = Rollierende { $short }- und { $long }-Tage-Volatilität
+sector-parkinson = Parkinson-Vola
+sector-vol-height = Höhe des Volatilitätsdiagramms
+sector-axis-annualized-vol = Annualisierte Vola (%)
+sector-ratio-heading = Volatilitätsverhältnis (kurz / lang) – über 1,0 deutet auf ein steigendes Vola-Regime hin
+sector-ratio-height = Höhe des Vola-Verhältnis-Diagramms
+sector-baseline = Basislinie (1,0)
+sector-latest-vol = Aktuell: { $short }T-Vola = { $short_vol } | { $long }T-Vola = { $long_vol } | Verhältnis = { $ratio }
+sector-no-vol = Für diesen Sektor wurden noch keine Volatilitätsdaten berechnet.
+sector-custom-indicators = Eigene Indikatoren
+sector-custom-height = Höhe des Indikatordiagramms
+sector-window-all = Alle
+sector-not-enough-returns = Zu wenige Renditen in diesem Fenster.
+sector-empirical = Empirisch
+sector-normal = Normal
+sector-histogram-height = Höhe des Renditehistogramms
+sector-return-count = { $count } Renditen
+sector-mean = Mittelwert: { $value }
+sector-std-dev = Std.-Abw.: { $value }
+sector-skew = Schiefe: { $value }
+sector-excess-kurtosis = Exzess-Kurtosis: { $value }
+sector-qq-sample = Stichprobe (σ)
+sector-qq-height = Höhe des QQ-Diagramms
+sector-axis-normal-quantile = Normalquantil (σ)
+sector-axis-sample-quantile = Stichprobenquantil (σ)
+sector-qq-reference = Normal (y = x)
+sector-qq-note = Punkte, die an den Enden von der Linie abweichen, zeigen breitere Ränder als bei einer Normalverteilung.
+sector-events = Ereignisse & Anmerkungen
+sector-events-show = Anzeigen:
+sector-events-note = Eingebaute Marker umfassen FOMC-Entscheidungen, VPI-Veröffentlichungen und 10J-2J-Inversionen. Über einem markierten Tag im Diagramm werden seine Ereignisse angezeigt.
+sector-events-add-label = Hinzufügen:
+sector-events-label-hint = Bezeichnung
+sector-events-add = Hinzufügen
+sector-events-need-label = Bitte eine Bezeichnung für die Anmerkung eingeben
+sector-events-bad-date = Datum muss im Format JJJJ-MM-TT sein
+sector-events-delete = Anmerkung löschen
+sector-ribbon-vol = Vola
+sector-ribbon-corr = Korr.
+sector-regime-since = { $regime } seit { $date }
+
+## Korrelation
+
+regime-low-vol = Niedrige Vola
+regime-normal-vol = Normale Vola
+regime-high-vol = Hohe Vola
+regime-dispersed = Gestreut
+regime-normal = Normal
+regime-herding = Herdenverhalten
+unit-days-short = { $days }T
+corr-heading = Sektorübergreifende Korrelationsmatrix
+corr-no-data = Keine Korrelationsdaten verfügbar. Zuerst Marktdaten laden.
+corr-cell-hover = { $a } vs. { $b }: Klicken für Paardetails, Rechtsklick kopiert die Tabelle
+corr-legend = Legende:
+corr-less-correlated = { $value } (schwächer korreliert)
+corr-more-correlated = { $value } (stärker korreliert)
+corr-network-heading = Korrelationsnetz (minimaler Spannbaum)
+corr-reset-layout = Anordnung zurücksetzen
+corr-network-height = Höhe des Netzes
+corr-node-hover = { $symbol }: { $days }-Tage-Vola { $vol }
+corr-network-note = Kanten verbinden jeden Sektor mit seinem am stärksten korrelierten Nachbarn (Abstand sqrt(2(1 - rho))). Knotengröße und -farbe: { $days }-Tage-Vola. Knoten lassen sich verschieben.
+corr-pair-heading = { $a } vs. { $b }
+corr-close-pair = Paaransicht schließen
+corr-pair-summary = Korrelation über den gesamten Zeitraum: { $correlation } | Beta von { $b } auf { $a }: { $beta }
+corr-pair-short = Zu wenig Historie für die rollierende { $days }-Tage-Korrelation.
+corr-pair-rolling = Rollierende { $days }-Tage-Korrelation
+corr-correlation = Korrelation
+corr-pair-height = Höhe des Paarkorrelationsdiagramms
+corr-daily-returns = Tägliche Log-Renditen (%)
+corr-scatter-height = Höhe des Streudiagramms
+corr-daily-returns-series = Tagesrenditen
+corr-window = Fenster:
+corr-full-sample = Gesamter Zeitraum
+corr-ending = Ende:
+corr-days-ago = Tage zurück
+corr-show-change = Änderung zeigen ggü.
+corr-days-earlier = Handelstage früher
+corr-sample = Stichprobe: { $from } bis { $to } ({ $days } Handelstage)
+corr-change-versus = Änderung ggü. dem Fenster bis { $date }: durchschnittliche Korrelation { $change }
+corr-window-average = Durchschnittliche Korrelation im Fenster: { $value }
+corr-average-short = Durchschnittliche sektorübergreifende Korrelation: { $value } (zu wenig Historie für das rollierende { $days }-Tage-Diagramm)
+corr-rolling-average = Durchschnittliche sektorübergreifende { $days }-Tage-Korrelation: { $value }
+corr-full-sample-value = (gesamter Zeitraum: { $value })
+corr-avg-corr = Ø Korr.
+corr-rolling-height = Höhe des rollierenden Korrelationsdiagramms
+corr-axis-avg = Ø Korrelation
+corr-bands = Bereiche:
+corr-dispersion-heading = Querschnittsstreuung vs. Korrelation
+corr-dispersion-summary = Durchschnittliche { $days }-Tage-Streuung: { $dispersion } | durchschnittliche Korrelation: { $correlation }
+corr-dispersion = Streuung
+corr-dispersion-avg = Ø Streuung
+corr-axis-dispersion = Streuung (%)
+corr-dispersion-height = Höhe des Streuungsdiagramms
+corr-daily-dispersion = Tägliche Streuung
+corr-avg-dispersion = Ø { $days }-Tage-Streuung
+corr-avg-right-axis = Ø Korrelation (rechte Achse)
+corr-dispersion-note = Die Streuung ist die Standardabweichung der täglichen Log-Renditen über die Sektoren. Hohe Korrelation bei geringer Streuung heißt, dass sich die Sektoren gleichlaufend bewegen.
+
+## Kurtosis
+
+col-metric = Kennzahl
+col-value = Wert
+axis-trading-days = Handelstage
+unit-day-window = { $days } Tage
+kurt-heading = Kurtosis- und Renditeverteilungsanalyse
+kurt-no-data = Zuerst Marktdaten laden, um Verteilungsstatistiken zu berechnen.
+kurt-sector = Sektor:
+kurt-stats-heading = { $symbol } – Verteilungsstatistik
+kurt-mean = Mittelwert
+kurt-std-dev = Std.-Abw.
+kurt-excess = Exzess-Kurtosis
+kurt-skewness = Schiefe
+kurt-jarque-bera = Jarque-Bera
+kurt-tail-type = Randtyp
+kurt-leptokurtic = Leptokurtisch (breite Ränder)
+kurt-platykurtic = Platykurtisch (schmale Ränder)
+kurt-mesokurtic = Mesokurtisch (annähernd normal)
+kurt-distribution-heading = Renditeverteilung (KDE vs. Normalverteilung)
+kurt-empirical-kde = Empirische KDE
+kurt-normal-fit = Normalverteilung
+kurt-distribution-height = Höhe des Verteilungsdiagramms
+kurt-axis-log-return = Tägliche Log-Rendite (%)
+kurt-axis-density = Dichte
+kurt-empirical-series = Empirisch (KDE)
+kurt-distribution-note = Blau = empirische Dichte (Gauß-KDE). Rot gestrichelt = angepasste Normalverteilung. Breite Ränder zeigen sich dort, wo die blaue Kurve über die rote hinausreicht.
+kurt-rolling-heading = Rollierende Exzess-Kurtosis ({ $days }-Tage-Fenster)
+kurt-rolling = Rollierende Kurtosis
+kurt-rolling-height = Höhe des Kurtosisdiagramms
+kurt-normal-zero = Normal (0)
+kurt-accel-heading = Beschleunigung / Verlangsamung der Kurtosis
+kurt-velocity = Geschwindigkeit
+kurt-acceleration = Beschleunigung
+kurt-accel-height = Höhe des Beschleunigungsdiagramms
+kurt-axis-observation = Beobachtung
+kurt-axis-rate = Änderungsrate
+kurt-velocity-trend = Geschwindigkeit (Trend)
+kurt-zero = Null
+kurt-projected-heading = Projizierte Kennzahlen (lineare Extrapolation um einen Schritt)
+kurt-trend = Kurtosis-Trend (Geschwindigkeit)
+kurt-projected-accel = Projizierte Beschleunigung
+kurt-projected-kurtosis = Projizierte Kurtosis
+kurt-accel-note = Geschwindigkeit = erste Differenz der rollierenden Kurtosis (positiv = steigend). Beschleunigung = zweite Differenz (positiv = beschleunigend). Projektionen extrapolieren linear um einen Schritt.
+kurt-skew-heading = Rollierende Schiefe ({ $days }-Tage-Fenster)
+kurt-rolling-skew = Rollierende Schiefe
+kurt-skew-height = Höhe des Schiefediagramms
+kurt-symmetric-zero = Symmetrie (0)
+kurt-comparison-heading = Kurtosis-Vergleich der Sektoren
+kurt-fat-tails = Breite Ränder
+kurt-thin-tails = Schmale Ränder
+kurt-normal-like = Annähernd normal
+kurt-footer-note = Eine Exzess-Kurtosis > 0 bedeutet breitere Ränder als bei einer Normalverteilung (leptokurtisch). Die Jarque-Bera-Teststatistik misst die Abweichung von der Normalverteilung (kritischer Wert ~5,99 bei 5 % Signifikanz).
+
+## Neuronales Netz
+
+nn-heading = Neuronales Netz – Prognose des Volatilitätsregimes
+nn-no-data = Vor dem Training des neuronalen Netzes zuerst Marktdaten laden.
+nn-architecture = Modellarchitektur: LSTM (hidden={ $hidden }) -> Linear
+nn-input = Eingabe: { $count } Merkmale (Renditen, Kreuzkorrelation, Spread, Steigung, VIX-Proxy + aktivierte Gruppen: Vols, Zufälligkeit, Kurtosis)
+nn-output = Ausgabe: { $target } in 5 Tagen + Entropie + Kurtosis/Schiefe je Sektor
+nn-lookback = Rückblick: { $lookback } Handelstage je Stichprobe | LR: { $lr } | Epochen: { $epochs } | Verlust: { $loss }
+nn-target-sector-average = Durchschnittliche Sektorvol
+nn-target-sector = Vola von { $symbol }
+nn-target-benchmark = Benchmark-Vola ({ $symbol })
+nn-target-vol-change = Vola-Änderung
+nn-target-log-vol = Log-Vola
+nn-cross-entropy = Kreuzentropie
+nn-model-saved = Modell gespeichert und von der Festplatte geladen (trainiert { $trained }; Verlust: { $loss }).
+nn-model-save-failed = Das Modell konnte nicht gespeichert oder von der Festplatte geladen werden.
+nn-model-loaded = Modell von der Festplatte geladen (trainiert { $trained }; Verlust: { $loss })
+nn-warning = Warnung: { $message }
+nn-training-device = Trainingsgerät:
+nn-cpu-device = CPU (NdArray)
+nn-detected = Erkannt: { $name }
+nn-detected-many = Erkannt: { $count } Adapter (Auswahl in den Einstellungen)
+nn-no-gpu = Keine GPU erkannt (WGPU fand keine Adapter)
+nn-train = Modell trainieren
+nn-sweep-running = Eine Hyperparametersuche läuft
+nn-predict = Prognostizieren
+nn-predict-hover = Das zuletzt gespeicherte Modell laden und aus den aktuellen Marktdaten prognostizieren
+nn-run-backtest = Backtest ausführen
+nn-saliency = Salienz
+nn-saliency-hover = Welche Rückblicktage und Merkmale die letzte Prognose bestimmt haben
+nn-export-features = Merkmale exportieren
+nn-export-features-hover = Merkmalsmatrix und Zielwerte als Parquet speichern, um sie anderswo zu modellieren
+nn-export-features-done = Merkmalsdatensatz gespeichert: { $path }
+nn-export-features-failed = Merkmalsexport fehlgeschlagen: { $error }
+nn-export-features-no-data = Zu wenige Marktdaten für den Merkmalsdatensatz
+nn-retrain-failed = Neutraining fehlgeschlagen: { $error }
+nn-training-interrupted = Nach { $epochs }/{ $total } Epochen unterbrochen
+nn-training-epoch = Training … Epoche { $epoch }/{ $total } | Verlust: { $loss }
+nn-pause = Pausieren
+nn-paused-epoch = Pausiert bei Epoche { $epoch }/{ $total } | Verlust: { $loss }
+nn-resume = Fortsetzen
+nn-stop = Stoppen
+nn-training-complete = Training abgeschlossen! Endverlust: { $loss }
+nn-retrain = Neu trainieren
+nn-error = Fehler: { $error }
+nn-retry = Erneut versuchen
+nn-predictions-heading = Prognosen für die nächsten 5 Tage
+nn-all-disabled = Alle Merkmalsgruppen sind deaktiviert. Mindestens ein Merkmal in den Einstellungen aktivieren, um Prognosen zu sehen.
+nn-volatility = Volatilität
+nn-col-vol = Vola (%)
+nn-col-recent-runs = Letzte Läufe
+nn-predicted-vol = Prognostizierte Vola
+nn-randomness = Zufälligkeit
+nn-predicted-entropy = Prognostizierte Entropie
+nn-kurtosis = Kurtosis
+nn-col-kurt = Kurt.
+nn-col-skew = Schiefe
+nn-predicted-kurtosis = Prognostizierte Kurtosis
+nn-predicted-skew = Prognostizierte Schiefe
+nn-no-predictions = Noch keine Prognosen. Das Modell trainieren, um Prognosen zu erzeugen.
+nn-footer = Das neuronale Netz basiert auf dem Deep-Learning-Framework Burn.
+nn-regime-heading = Vola-Regime in 5 Tagen
+nn-regime-low = Niedrig
+nn-regime-normal = Normal
+nn-regime-high = Hoch
+nn-most-likely = Wahrscheinlichstes
+nn-low-regime = Niedriges Regime
+nn-normal-regime = Normales Regime
+nn-high-regime = Hohes Regime
+nn-most-likely-regime = Wahrscheinlichstes Regime
+nn-regime-note = Die Regime sind Terzile der künftigen Vola jedes Sektors über den Trainingszeitraum.
+nn-sweep-heading = Hyperparametersuche
+nn-sweep-hidden = Hidden-Größen:
+nn-sweep-lookbacks = Rückblicke (Tage):
+nn-sweep-lrs = Lernraten:
+nn-sweep-epochs = Epochen je Versuch:
+nn-sweep-mode = Modus:
+nn-sweep-grid = Raster
+nn-sweep-random = Zufällig
+nn-sweep-trials = Versuche
+nn-sweep-start = Suche starten ({ $count } Läufe)
+nn-sweep-epoch = Epoche { $epoch }/{ $total }
+nn-sweep-running-status = Suche läuft: { $done }/{ $total } Läufe beendet
+nn-cancel = Abbrechen
+nn-sweep-finished = Suche beendet: { $done }/{ $total } Läufe
+nn-clear = Leeren
+nn-leaderboard = Rangliste (niedrigster Validierungsverlust zuerst)
+nn-col-hidden = Hidden
+nn-col-lookback = Rückblick
+nn-col-lr = LR
+nn-col-val-loss-of = Val.-Verlust ({ $loss })
+nn-col-train-loss-of = Trainingsverlust ({ $loss })
+nn-col-status = Status
+nn-status-pending = Ausstehend
+nn-status-running = Läuft
+nn-status-done = Fertig
+nn-status-failed = Fehlgeschlagen
+nn-status-complete = Abgeschlossen
+nn-status-stopped = Gestoppt
+nn-status-paused = Pausiert
+nn-status-idle = Bereit
+nn-sweep-use = Übernehmen
+nn-sweep-use-hover = Diese Hyperparameter übernehmen (Epochen unverändert); „Modell trainieren“ trainiert und speichert
+nn-sweep-applied = Suchkonfiguration übernommen: hidden={ $hidden }, lookback={ $lookback }, lr={ $lr }
+nn-task-trials = { $done }/{ $total } Versuche
+nn-task-sweep = Hyperparametersuche
+nn-backtest-heading = Backtest: prognostizierte vs. realisierte 5-Tage-Vola
+nn-col-period = Zeitraum
+nn-col-samples = Stichproben
+nn-col-hit-rate = Trefferquote
+nn-all-history = Gesamte Historie
+nn-out-of-sample = Out-of-Sample
+nn-in-sample = In-Sample
+nn-backtest-note = Out-of-Sample = die jüngsten 20 % der Fenster, vom Training ausgenommen. Trefferquote = richtig prognostizierte Vola-Richtung (ggü. der Vola am Fensterende).
+nn-backtest-scatter-height = Höhe des Backtest-Streudiagramms
+nn-axis-realized-vol = Realisierte Vola (%)
+nn-axis-predicted-vol = Prognostizierte Vola (%)
+nn-perfect-forecast = Perfekte Prognose
+nn-loss-heading = Trainingsverlust
+nn-log-scale = Log-Skala
+nn-log-scale-hover = log10(Verlust) darstellen, damit späte Verbesserungen sichtbar bleiben
+nn-loss-name = { $loss }-Verlust
+nn-loss-log = { $loss } (log)
+nn-train-series = Training
+nn-validation-series = Validierung
+nn-loss-height = Höhe des Verlustdiagramms
+nn-axis-epoch = Epoche
+nn-best-epoch = Beste Epoche ({ $epoch })
+nn-sparkline-short = Benötigt mindestens zwei Aktualisierungen mit Prognosen
+nn-sparkline-range = Letzte { $count } Läufe: { $low } bis { $high }
+nn-predicted = Prognostiziert
+nn-realized = Realisiert
+nn-backtest-timeline-height = Höhe der Backtest-Zeitleiste
+nn-axis-window = Fenster (chronologisch)
+nn-axis-5d-vol = 5-Tage-Vola (%)
+nn-oos-start = Beginn Out-of-Sample
+nn-residuals-heading = Residuendiagnose (Out-of-Sample)
+nn-bias = Verzerrung (Prognose − realisiert):
+nn-residual-std = Std.-Abw. der Residuen:
+nn-lag1-acf = Autokorrelation Lag 1:
+nn-acf-band = { $value } (95-%-Band ±{ $band })
+nn-top-quintile-bias = Verzerrung im obersten Vola-Quintil:
+nn-bias-at-level = { $bias } bei { $level } realisierter Vola
+nn-residuals-note = Negative Verzerrung im obersten Quintil heißt, dass Vola-Spitzen unterschätzt werden. Überlappende 5-Tage-Ziele machen die ersten Lags auch bei einem guten Modell positiv.
+nn-residuals-height = Höhe der Residuendiagramme
+nn-residual-acf = Autokorrelation der Residuen
+nn-axis-lag = Lag (Fenster)
+nn-axis-autocorrelation = Autokorrelation
+nn-error-vs-level = Fehler vs. realisiertes Vola-Niveau
+nn-axis-residual = Prognose − realisiert (%)
+nn-oos-mean = OOS-Mittel je Quintil
+nn-drift-exceeded = Warnung: Prognosedrift – der rollierende Fehler von { $error } über die letzten { $count } ausgewerteten Prognosen übersteigt { $threshold }. Ein neues Training wird empfohlen.
+nn-drift-tracking = Live-Verfolgung: rollierender Fehler { $error } über { $count } ausgewertete Prognosen ({ $pending } ausstehend, Schwelle { $threshold })
+nn-drift-pending = Live-Verfolgung: { $count } Prognosen erfasst, ausgewertet sobald { $days } weitere Handelstage vorliegen
+nn-gpu-diagnostics = GPU-Diagnose
+nn-run-diagnostics = Diagnose ausführen
+nn-benchmarking = Benchmark läuft (etwa 10 s) …
+nn-diagnostics-note = Korrektheitsprüfung, Matmul-TFLOPS, Speicherbandbreite und gemessene Trainingsschritte.
+nn-diag-adapter = Adapter:
+nn-diag-matmul = Matmul (f32):
+nn-diag-tflops = { $peak } TFLOPS Spitze, { $sustained } dauerhaft
+nn-diag-bandwidth = Speicherbandbreite:
+nn-diag-gbs = { $value } GB/s
+nn-diag-step = Trainingsschritt:
+nn-diag-step-time = { $ms } ms (Batch { $batch })
+nn-diag-epoch = Geschätzte Epoche:
+nn-diag-epoch-time = { $seconds } s; { $epochs } Epochen ≈ { $minutes } min
+nn-diagnostics-failed = Diagnose fehlgeschlagen: { $error }
+nn-task-gpu-diagnostics = GPU-Diagnose
+nn-saliency-heading = Eingabesalienz
+nn-saliency-note = |Gradient × Eingabe| der { $target } für das jüngste Fenster. Dunkler = mehr Einfluss.
+nn-saliency-target-vol = prognostizierten 5-Tage-Vola
+nn-saliency-target-regime = Log-Wahrscheinlichkeit des Hoch-Vola-Regimes
+nn-saliency-cell = { $feature } an t-{ $day }: { $value }
+nn-saliency-summary = Einflussreichster Tag: t-{ $day } | Wichtigste Merkmale: { $features }
+nn-saliency-done = Salienz der { $target } berechnet.
+nn-saliency-failed = Salienz fehlgeschlagen: { $error }
+nn-history-heading = Laufhistorie ({ $count })
+nn-history-note = A und B ankreuzen, um die Verlustkurven zweier Läufe zu vergleichen.
+nn-col-started = Gestartet
+nn-col-objective = Zielfunktion
+nn-col-epochs = Epochen
+nn-col-train-loss = Trainingsverlust
+nn-col-val-loss = Val.-Verlust
+nn-col-outcome = Ergebnis
+nn-clear-history = Historie leeren
+nn-compare-heading = A: { $a }  vs.  B: { $b }
+nn-diff-hidden = hidden { $from } → { $to }
+nn-diff-lookback = Rückblick { $from } → { $to }
+nn-diff-lr = LR { $from } → { $to }
+nn-diff-objective = Zielfunktion { $from } → { $to }
+nn-diff-target = Ziel { $from } → { $to }
+nn-diff-augmentation = Augmentierung unterschiedlich
+nn-diff-grad-clip = Gradient-Clipping { $from } → { $to }
+nn-diff-features = Merkmale unterschiedlich
+nn-same-config = Gleiche Konfiguration.
+nn-changes = Änderungen: { $changes }
+nn-run-train = { $run } Training
+nn-run-val = { $run } Validierung
+nn-compare-height = Höhe des Laufvergleichs
+nn-axis-loss = Verlust
+nn-no-saved-model = Kein gespeichertes Modell gefunden. Zuerst ein Modell trainieren.
+nn-predictions-generated = Prognosen aus dem am { $trained } trainierten Modell erzeugt ({ $precision }-Gewichte).
+nn-prediction-short = Prognose fehlgeschlagen: zu wenige Marktdaten für den Rückblick des Modells.
+nn-backtest-regression-only = Der Backtest ist nur für Regressionsmodelle verfügbar.
+nn-backtest-failed = Backtest fehlgeschlagen: { $error }
+nn-backtest-complete = Backtest abgeschlossen: { $count } Fenster, Out-of-Sample-MAE { $mae }
+nn-backtest-short = Backtest fehlgeschlagen: zu wenige Daten für den Datensatz.
+nn-compute-heading = Rechenstatistik
+nn-system-training = System & Training
+nn-backend = Backend:
+nn-parameters = Parameter:
+nn-batch-size = Batchgröße:
+nn-epoch-time = Epochendauer:
+nn-throughput = Durchsatz:
+nn-samples-per-sec = { $value } Stichproben/s
+nn-status = Status:
+nn-no-nvidia = Keine NVIDIA-GPU erkannt
+nn-device = Gerät:
+nn-training-on = Training auf:
+nn-cpu-gpu-idle = CPU (GPU untätig)
+nn-utilization = Auslastung:
+nn-temperature = Temperatur:
+nn-stats = Statistik:
+nn-stats-unavailable = Nicht verfügbar (nvidia-smi / rocm-smi / amd-smi nicht gefunden)
+nn-gigabytes = { $used } / { $total } GB
+nn-load-data-first = Vor dem Training Marktdaten laden
+nn-already-training = Das Training läuft bereits
+nn-task-training = Modelltraining
+nn-task-epoch = Epoche { $epoch }/{ $total }
+
+## Zinsen
+
+rates-heading = Treasury-Zinskurve
+rates-play = ▶ Abspielen
+rates-pause = ⏸ Pause
+rates-date = Datum:
+rates-latest = Neueste
+rates-speed = Tempo:
+rates-days-per-sec = Tage/s
+rates-overlay-from = Kurve überlagern von
+rates-observations-earlier = Beobachtungen früher
+rates-on-date = Datum:
+rates-maturity = Laufzeit
+rates-higher-yield = Höhere Rendite
+rates-lower-yield = Niedrigere Rendite
+rates-versus = (ggü. { $date }: { $spread } / { $slope } bps)
+rates-inverted = Invertiert
+rates-history-heading = Laufzeitspreads & Rezessionswahrscheinlichkeit
+rates-history-short = Zu wenig Treasury-Historie mit 2J- und 10J-Renditen für das Spread-Diagramm.
+rates-recession-prob = Rezessionswahrsch.
+rates-history-height = Höhe des Spread-Verlaufsdiagramms
+rates-axis-spread = Spread (pp)
+rates-axis-recession = Rezessionswahrscheinlichkeit (%)
+rates-observation = Beobachtung
+rates-inverted-periods = Invertiert (10J < 2J)
+rates-recession-line = Rezessionswahrscheinlichkeit (12M, rechte Achse)
+rates-recession-note = Rezessionswahrscheinlichkeit: Probit-Modell der NY Fed auf den 10J-3M-Spread für die nächsten 12 Monate. Es ist auf Monatsdurchschnitte angepasst, daher schwanken Tageswerte stärker.
+rates-changes = Veränderung nach Laufzeit ({ $from } → { $to })
+
+## Anleihen
+
+bond-heading = Anleihespreads & Zinskurve
+bond-no-data = Keine Treasury-Zinsdaten vorhanden. Zuerst Marktdaten laden.
+bond-current-curve = Aktuelle Zinskurve
+bond-date = Datum: { $date }
+bond-yield = Rendite
+bond-curve-height = Höhe des Zinskurvendiagramms
+bond-axis-yield = Rendite (%)
+bond-yield-curve = Zinskurve
+bond-term-spread-heading = 10J-2J-Laufzeitspread im Zeitverlauf
+bond-spread = 10J-2J-Spread
+bond-spread-height = Höhe des Laufzeitspread-Diagramms
+bond-axis-spread = Spread (Prozentpunkte)
+bond-zero = Null
+bond-slope-heading = Kurvensteigung (30J - 3M)
+bond-slope = 30J-3M-Steigung
+bond-slope-height = Höhe des Kurvensteigungsdiagramms
+bond-axis-slope = Steigung (Prozentpunkte)
+bond-latest = Zuletzt ({ $date }) | 10J-2J: { $spread } pp | Kurvensteigung: { $slope } pp | { $shape }
+bond-inverted = INVERTIERT
+bond-normal = Normal
+bond-inversions = Warnung: { $count } Inversion(en) der Zinskurve in den Daten erkannt
+
+## Risiko
+
+risk-heading = Sektorrisiko-Übersicht
+risk-intro = Historischer Ein-Tages-VaR und Expected Shortfall bei { $confidence } Konfidenz, maximaler Drawdown über die geladene Historie, Hill-Tail-Index der schlechtesten { $tail } der Tagesverluste und wo die aktuelle { $days }T-Vola in der eigenen Spanne jedes Sektors liegt. Einen Sektor anklicken, um ihn unter Sektor-Vola zu öffnen.
+risk-col-var = VaR { $confidence }
+risk-col-es = ES { $confidence }
+risk-col-drawdown = Max. Drawdown
+risk-col-tail-index = Tail-Index α
+risk-col-percentile = Vola-Perzentil
+risk-unknown = Unbekannt
+risk-open-sector = { $name } - unter Sektor-Vola öffnen
+risk-percentile = { $rank }.
+risk-tail-note = Tail-Index: kleineres α bedeutet breitere Ränder (unter 3 ist schwerer als bei typischen Aktienrenditen).
+risk-beta-heading = Rollierendes { $days }T-Beta ggü. { $benchmark }
+risk-beta-no-benchmark = Für das Beta werden Benchmark-Daten benötigt. Zum Laden aktualisieren.
+risk-sector = Sektor:
+risk-beta-short = Zu wenig Historie für das rollierende { $days }-Tage-Beta.
+risk-beta-latest = Aktuelles Beta: { $beta } - eine Bewegung von { $one } in { $benchmark } ging zuletzt mit einer Bewegung von { $move } in { $symbol } einher.
+risk-beta-height = Höhe des Beta-Diagramms
+risk-beta-note = Gestrichelte Linie: Beta von 1 (bewegt sich eins zu eins mit der Benchmark).
+risk-map-heading = Risiko-/Renditekarte
+risk-trailing-window = Zurückliegendes Fenster:
+risk-window-1m = 1M
+risk-window-3m = 3M
+risk-window-6m = 6M
+risk-window-1y = 1J
+risk-map-short = Zu wenig Historie für ein { $days }-Tage-Fenster.
+risk-map-height = Höhe des Streudiagramms
+risk-axis-vol = Realisierte Vola (annualisiert, %)
+risk-axis-return = { $days }T-Rendite (%)
+risk-map-point =
+    { $name }
+    Vola: { $vol }
+    Rendite: { $return }
+risk-avg-return = Ø Sektorrendite
+risk-map-note = Die Punktgröße skaliert mit dem Verhältnis kurz-/langfristiger Vola: größere Punkte sind Sektoren mit steigender Vola.
+
+## Vergleich
+
+compare-heading = Sektorvergleich
+compare-sectors = Sektoren:
+compare-all = Alle
+compare-none = Keine
+compare-metric = Kennzahl:
+compare-normalized-price = Normierter Kurs
+compare-short-vol = { $days }T-Vola
+compare-select-sectors = Einen oder mehrere Sektoren zum Überlagern auswählen.
+compare-axis-rebased = Kurs (auf 100 normiert)
+compare-axis-vol = Annualisierte Vola (%)
+compare-chart-height = Höhe des Vergleichsdiagramms
+compare-start = Anfang
+compare-end = Ende
+compare-change = Veränderung
+unit-pts = { $value } Pkt.
+unit-percent = { $value } %
+
+## Beobachtungsliste
+
+watchlist-add-symbol = Symbol hinzufügen:
+watchlist-add-hint = z. B. AAPL, ^VIX, BTC-USD
+watchlist-add = Hinzufügen
+watchlist-refresh-all = Alle aktualisieren
+watchlist-fetching = Lade { $symbols }…
+watchlist-intro = Symbole der Beobachtungsliste erhalten die üblichen { $short }T/{ $long }T-Vola-Kennzahlen, bleiben aber außerhalb der Sektoranalyse und des neuronalen Netzes.
+watchlist-empty = Oben ein Kürzel eingeben, um es zu beobachten.
+watchlist-remove = Von der Beobachtungsliste entfernen
+watchlist-save-failed = Beobachtungsliste konnte nicht gespeichert werden: { $error }
+watchlist-vols = { $short }T-Vola { $short_vol } | { $long }T-Vola { $long_vol }
+watchlist-ratio = Verhältnis { $ratio }
+watchlist-percentile = | Vola-Perzentil { $rank }.
+watchlist-task = Beobachtungsliste { $symbol }
+watchlist-short-history = Nur { $count } Kursbalken Historie; zu wenig für Vola-Kennzahlen
+
+## Alarme
+
+alerts-heading = Alarme
+alerts-intro = Regeln werden nach jeder Datenaktualisierung geprüft und lösen aus, sobald ihre Bedingung zutrifft.
+alerts-history = Verlauf
+alerts-clear = Leeren
+alerts-history-empty = Bisher wurden keine Alarme ausgelöst.
+alerts-no-rules = Noch keine Alarmregeln.
+alerts-enabled = Aktiv
+alerts-holds = Bedingung trifft derzeit zu
+alerts-delete-rule = Regel löschen
+alerts-any-sector = Beliebiger Sektor
+alerts-add-rule = Regel hinzufügen
+alerts-fired-sector = { $rule }: { $symbol } liegt bei { $value }
+alerts-fired = { $rule }: jetzt { $value }
+alert-metric-vol-ratio = Vola-Verhältnis
+alert-metric-short-vol = Kurzfristige Vola
+alert-metric-avg-correlation = Ø Korrelation
+alert-metric-term-spread = 10J-2J-Spread
+alert-metric-predicted-vol = Prognostizierte Vola
+
+## Daten
+
+data-intro = Jede Kursreihe, die die App lädt. Ausgeschlossene Sektoren bleiben bei Analyse und NN-Training außen vor.
+data-col-name = Name
+data-col-from = Von
+data-col-to = Bis
+data-col-source = Quelle
+data-col-cache-age = Cache-Alter
+data-col-include = Einbeziehen
+data-col-item = Eintrag
+data-col-status = Status
+data-col-attempts = Versuche
+data-col-detail = Details
+data-not-loaded = Nicht geladen
+data-no-cache = Kein Cache
+data-benchmark-always = Die Benchmark wird immer geladen
+data-refetch-hover = Erneut aus dem Netz laden
+data-purge-hover = Zwischengespeicherte Kopie löschen
+data-purged = Cache für { $symbol } geleert
+data-purge-failed = Cache für { $symbol } konnte nicht geleert werden: { $error }
+data-save-excluded-failed = Ausgeschlossene Symbole konnten nicht gespeichert werden: { $error }
+data-source-cache = Yahoo (Cache)
+data-source-network = Yahoo (Netz)
+data-failed-kept = Fehlgeschlagen, vorherige behalten
+data-attempts = { $count } Versuche
+data-last-refresh = Letzte Aktualisierung
+data-finished = Beendet { $time }: { $summary }
+data-cached = Aus dem Cache
+data-downloaded = Heruntergeladen
+data-retry-failed = { $count } fehlgeschlagene Reihen erneut laden
+data-retry-failed-hover = Die fehlgeschlagenen Symbole erneut aus dem Netz laden
+data-other-failures = Fehlgeschlagene Zins- und Stimmungsquellen werden bei der nächsten vollständigen Aktualisierung erneut abgerufen.
+
+## Aufträge
+
+jobs-title = Aufträge
+jobs-next = Nächster Auftrag: { $name } am { $time }
+jobs-none-enabled = Keine Aufträge aktiv
+jobs-col-on = An
+jobs-col-name = Name
+jobs-col-schedule = Zeitplan
+jobs-col-zone = Zone
+jobs-col-action = Aktion
+jobs-col-next-run = Nächster Lauf
+jobs-col-last-result = Letztes Ergebnis
+jobs-run-now = Jetzt ausführen
+jobs-remove = Entfernen
+jobs-add = Auftrag hinzufügen
+jobs-help = Der Zeitplan lautet Minute Stunde Monatstag Monat Wochentag, z. B. `30 16 * * 1-5` für 16:30 an Werktagen oder `0 10 * * sun`. Berichte und Bildschirmfotos landen im Ausgabeordner unter Einstellungen → Geplante Aufnahme; die Auto-Aktualisierung in der Statusleiste richtet den Auftrag „Auto-refresh“ ein. Aufträge warten auf eine laufende Aktualisierung; ein Lauf, der bei geschlossener App verpasst wurde, wird beim nächsten Start einmal nachgeholt.
+jobs-save-failed = Aufträge konnten nicht gespeichert werden: { $error }
+jobs-due-now = Jetzt fällig
+jobs-in = in { $wait }
+jobs-not-run = Noch nicht ausgeführt
+job-zone-local = Lokal
+job-zone-new-york = New York
+job-action-refresh = Daten aktualisieren
+job-action-retrain = Modell neu trainieren
+job-action-report = HTML-Bericht speichern
+job-action-email-report = Bericht per E-Mail
+job-action-screenshot = Bildschirmfoto der Übersicht
+job-refresh-busy = Eine Aktualisierung läuft bereits
+job-refresh-started = Aktualisierung gestartet
+job-training-started = Training gestartet
+job-saved = { $path } gespeichert
+job-emailing = E-Mail an { $count } Empfänger wird gesendet
+job-capturing = Aufnahme nach { $path }
+job-notice = Auftrag „{ $name }“: { $message }
+
+## Diagnose
+
+diag-title = Diagnose
+diag-indicator-hover = Frame-Zeit; klicken für Speicher- und Leistungsdiagnose
+diag-memory = Speicher
+diag-heap = Heap
+diag-with-peak = { $now } (Spitze { $peak })
+diag-not-counted = Nicht gezählt
+diag-not-counted-hover = Mit `--features diagnostics` bauen, um Heap-Allokationen zu zählen
+diag-resident = Resident
+diag-resident-hover = Prozessspeicher laut Betriebssystem, einschließlich GPU-Treiber- und UI-Puffern. Die Spitze gilt für die Zeit, in der dieses Fenster offen war.
+diag-price-data = Kursdaten
+diag-series = { $size } ({ $count } Reihen)
+diag-analysis = Analyse
+diag-nn-dataset = NN-Datensatz
+diag-not-built = In dieser Sitzung nicht erstellt
+diag-chart-cache = Diagramm-Cache
+diag-charts = { $count } Diagramme
+diag-disk-cache = Festplatten-Cache
+diag-estimates = Die Größen der Teilsysteme sind aus der Datenlänge geschätzt.
+diag-frame-time = Frame-Zeit
+diag-frame-stats = Zuletzt { $last }  ·  Mittel { $mean }  ·  Max. { $max }
+diag-cpu-ms = CPU ms
+diag-timings = Zeiten
+diag-refresh-stage = Aktualisierung: { $stage }
+diag-last-refresh = Letzte Aktualisierung beendet { $time }
+diag-no-refresh = In dieser Sitzung keine Aktualisierung
+unit-ms = { $value } ms
+unit-seconds = { $value } s
+
+## Aktualisierungsfortschritt
+
+refresh-loading = Lade { $done }/{ $total }
+refresh-failed = ⚠ { $count } fehlgeschlagen
+refresh-hover = Aktualisierungsfortschritt je Symbol
+refresh-fetching = Marktdaten werden geladen...
+refresh-task = Marktdaten-Aktualisierung
+refresh-window = Aktualisierungsfortschritt
+refresh-retry = Erneut versuchen
+refresh-status-pending = Ausstehend
+refresh-status-fetching = Wird geladen
+refresh-status-cached = Aus Cache
+refresh-status-downloaded = Heruntergeladen
+refresh-status-failed = Fehlgeschlagen
+refresh-refetched = { $symbol } erneut abgerufen
+refresh-refetch-failed = Erneuter Abruf von { $symbol } fehlgeschlagen: { $error }
+refresh-cache-loaded = { $count } Sektoren aus dem Cache geladen
+refresh-source-failed = { $source } fehlgeschlagen: { $error }
+refresh-source-failed-kept = { $source } fehlgeschlagen (bisherige Daten behalten): { $error }
+refresh-loaded = { $sectors } Sektoren und { $rates } Zinseinträge geladen ({ $summary }). Letzte Aktualisierung: { $time }
+refresh-never = k. A.
+
+## Plugins
+
+plugins-heading = Analyse-Plugins
+plugins-none = Es sind keine Analyse-Plugins registriert.
+plugins-failed = Plugin fehlgeschlagen: { $error }
+plugins-height = Höhe des Plugin-Diagramms
+
+## Tastenkürzel
+
+shortcuts-title = Tastenkürzel
+shortcuts-hint = Tastenkürzel ({ $key })
+shortcut-tab = Reiter { $tab }
+shortcut-switch-tab = Reiter wechseln
+shortcut-refresh = Daten aktualisieren
+shortcut-screenshot = Bildschirmfoto aufnehmen
+shortcut-copy-screenshot = Bildschirmfoto in die Zwischenablage kopieren
+shortcut-toggle-training = NN-Training starten / stoppen
+shortcut-next-sector = Nächster Sektor
+shortcut-prev-sector = Vorheriger Sektor
+shortcut-focus-search = Schnellsuche fokussieren
+shortcut-toggle-overlay = Diese Liste ein-/ausblenden
+
+## Erklärmodus
+
+metric-parkinson-name = Parkinson-Volatilität
+metric-parkinson-definition = Annualisierte Volatilität, geschätzt aus der Hoch-Tief-Spanne jedes Tages statt aus Schlusskurs-Renditen. Sie nutzt Intraday-Information und braucht daher weniger Tage für dieselbe Genauigkeit.
+metric-parkinson-interpretation = Liest sich wie Schlusskurs-Vola. Liegt sie deutlich darüber, gibt es große Intraday-Schwankungen, die sich bis zum Schluss größtenteils umkehren; deutlich darunter treiben eher Kurslücken über Nacht die Bewegungen.
+metric-vol-ratio-name = Vola-Verhältnis
+metric-vol-ratio-definition = Realisierte Vola des kurzen Fensters geteilt durch die des langen Fensters.
+metric-vol-ratio-interpretation = Über 1,0 ist die jüngste Vola höher als die längere Basis (steigendes Vola-Regime); unter 1,0 beruhigen sich die Märkte gegenüber der jüngsten Vergangenheit.
+metric-term-spread-name = Zinsdifferenz 10J-2J
+metric-term-spread-definition = Rendite zehnjähriger minus zweijähriger US-Staatsanleihen, ein Maß für die Steigung der Zinskurve.
+metric-term-spread-interpretation = Positiv ist eine normale, steigende Kurve. Negativ (invers) heißt, dass die Märkte Zinssenkungen erwarten; das ging historisch Rezessionen voraus, meist mit langer Verzögerung.
+metric-avg-correlation-name = Durchschnittliche Sektorkorrelation
+metric-avg-correlation-definition = Mittlere paarweise Pearson-Korrelation der täglichen Log-Renditen über alle Sektor-ETFs.
+metric-avg-correlation-interpretation = Hohe Werte bedeuten, dass sich die Sektoren gemeinsam bewegen (makrogetriebene Risk-on/Risk-off-Märkte mit wenig Diversifikation); niedrige Werte bedeuten, dass Einzeltitelauswahl und Sektorrotation dominieren.
+metric-window = { $days }T-Fenster
+metric-window-pair = { $short }T / { $long }T
+metric-rolling-window = rollierendes { $days }T-Fenster
+
+## Infobereich
+
+tray-show = Fenster anzeigen
+tray-refresh = Jetzt aktualisieren
+tray-quit = Beenden
+tray-alert = Alarm von Volume Analysis
@@ -0,0 +1,1144 @@
+# English UI text. Message ids are shared by every catalog in this folder; text in
+# { $name } is filled in by the app. A message missing from another language's
+# catalog falls back to the text here.
+
+## Common
+
+cancel = Cancel
+copy = Copy
+dismiss = Dismiss
+reset = Reset
+sort-by-column = Sort by this column
+
+## Tabs
+
+tab-dashboard = Dashboard
+tab-sector-vol = Sector Vol
+tab-correlations = Correlations
+tab-bonds = Bonds
+tab-rates = Rates
+tab-kurtosis = Kurtosis
+tab-risk = Risk
+tab-compare = Compare
+tab-watchlist = Watchlist
+tab-plugins = Plugins
+tab-data = Data
+tab-neural-net = Neural Net
+tab-settings = Settings
+tab-detached = { $tab } is open in a separate window.
+reattach = Reattach
+
+## Top bar
+
+refresh-data = Refresh Data
+report-hover = Save an HTML market report (print it from a browser for PDF; right-click to email it)
+email-report = Email report
+email-report-disabled = Set up the SMTP server and recipients in Settings
+workbook-hover = Export the complete analysis as an Excel workbook (right-click for Arrow)
+export-arrow = Export as Arrow IPC (polars/pandas)
+export-arrow-hover = Bar and vol series plus spreads as .arrow (Feather) files
+report-saved = Report saved: { $path }
+report-failed = Report failed: { $error }
+report-emailing = Emailing the report to { $count } recipient(s)…
+report-email-failed = Report email failed: { $error }
+workbook-saved = Workbook saved: { $path }
+workbook-failed = Workbook export failed: { $error }
+export-arrow-saved = Arrow files saved: { $paths }
+export-arrow-failed = Arrow export failed: { $error }
+detach-tab-hover = Open this tab in a separate window
+screenshot-hover = Take screenshot (right-click for scope and clipboard)
+screenshot-save-file = Save to file
+screenshot-copy = Copy to clipboard
+screenshot-scope-window = Whole window
+screenshot-scope-active-tab = Active tab
+screenshot-scope-region = Select region…
+screenshot-region-hint = Drag to select the screenshot region (Esc to cancel)
+screenshot-saved = Screenshot saved: { $path }
+screenshot-failed = Screenshot failed: { $error }
+screenshot-copied = Screenshot copied to clipboard.
+screenshot-copy-failed = Screenshot copy failed: { $error }
+
+## Status bar
+
+fixtures-hover = Provider responses come from the { $folder }/ folder
+status-errors = Errors: { $count }
+status-cache = Cache { $hits } hit / { $misses } miss
+source-data-as-of = Data as of { $time }
+source-not-fetched = Not fetched this session
+source-cache = Cache hits: { $hits }  misses: { $misses }
+source-last-error = Last error: { $error }
+auto-refresh-off = Off
+auto-refresh-15m = 15 min
+auto-refresh-30m = 30 min
+auto-refresh-1h = 1 hour
+auto-refresh-4h = 4 hours
+auto-refresh-custom = Custom
+auto-refresh-label = Auto-refresh:
+auto-refresh-now = Auto-refresh: now
+auto-refresh-next = Next refresh in { $wait }
+auto-refresh-save-failed = Failed to save auto-refresh interval: { $error }
+
+## Notifications
+
+notices-title = Notifications
+notices-placeholder = Ready. Click 'Refresh Data' to fetch market data.
+notices-latest-hover = { $time } · { $source } · click for all notifications
+notices-unread = ⚠ { $count } new
+notices-hover = Errors and messages from this session
+notices-min-severity = { $severity } and above
+notices-all-sources = All sources
+notices-counts = { $errors } errors, { $warnings } warnings
+notices-empty = Nothing to show.
+notices-retry-busy = Wait for the refresh to finish
+notices-copy-hover = Copy the listed notices as text
+notices-clear-all = Clear all
+session-restored = Restored the previous session from { $time } after an abnormal exit.
+session-training-interrupted = Training was interrupted after { $epochs }/{ $total } epochs; the partial run is in the run history.
+severity-info = Info
+severity-warning = Warning
+severity-error = Error
+source-data = Data
+source-analysis = Analysis
+source-training = Training
+source-export = Export
+source-settings = Settings
+source-jobs = Jobs
+retry-refresh = Refresh
+retry-refetch = Refetch { $symbol }
+retry-train = Retrain
+notice-analysis-failed = { $name } failed: { $error }
+
+## Tasks
+
+tasks-title = Tasks
+tasks-running = { $count } running
+tasks-failed = ⚠ { $count } failed
+tasks-hover = Background fetches, training and benchmarks
+tasks-empty = No background tasks this session.
+tasks-clear-finished = Clear finished
+task-running = Running
+task-cancelling = Cancelling…
+task-done = Done
+task-cancelled = Cancelled
+task-failed = Failed
+task-finished-in = Finished in { $seconds }s
+task-symbols = { $done }/{ $total } symbols
+task-failed-notice = { $task } failed: { $error }
+task-cancelled-notice = { $task } cancelled
+task-kind-startup = Startup
+task-kind-refresh = Refresh
+task-kind-refetch = Refetch
+task-kind-watchlist = Watchlist
+task-kind-api-test = API Test
+task-kind-training = Training
+task-kind-sweep = Sweep
+task-kind-gpu-diagnostics = GPU Diagnostics
+task-kind-webhook = Webhook
+task-kind-email = Email
+task-kind-grpc = gRPC
+task-load-cache = Load cached data
+task-webhook-one = Post alerts to { $name }
+task-webhook-many = Post alerts to { $count } webhooks
+task-email = Email report to { $count } recipient(s)
+task-grpc = gRPC server on { $address }
+
+## Dashboard
+
+dashboard-heading = Market Structure Dashboard
+dashboard-no-data = No data loaded. Click 'Refresh Data' to fetch market data.
+card-sectors-loaded = Sectors Loaded
+card-benchmark-last-close = { $symbol } Last Close
+card-avg-correlation = Avg Cross-Correlation
+card-term-spread = 10Y-2Y Spread
+card-treasury-points = Treasury Data Points
+unit-bps = { $value } bps
+dashboard-heatmap-heading = Sector Volatility Heatmap
+dashboard-search-matches = Showing { $count } sector(s) matching "{ $query }"
+dashboard-open-sector = Open { $symbol } in Sector Vol
+dashboard-sector-performance-heading = FMP Sector Performance (Real-Time)
+col-sector = Sector
+col-symbol = Symbol
+col-last-close = Last Close
+col-short-vol = 21D Vol
+col-long-vol = 63D Vol
+col-vol-ratio = Vol Ratio
+col-beta = Beta
+col-bars = Bars
+col-change-pct = Change %
+col-entropy = Entropy
+col-hurst = Hurst Exp.
+col-behavior = Behavior
+dashboard-put-call-heading = Put/Call Ratio & SKEW
+dashboard-put-call-empty = No Put/Call or SKEW data. Data is fetched with Refresh Data.
+dashboard-put-call-height = P/C Ratio & SKEW Chart Height
+axis-trading-day = Trading Day (recent -> past)
+axis-pc-ratio = P/C Ratio
+series-total-pc-ratio = Total P/C Ratio
+dashboard-randomness-heading = Market Randomness - 3D Joint Return Distribution
+randomness-sector-x = Sector X:
+randomness-sector-y = Sector Y:
+randomness-pitch = Pitch:
+randomness-yaw = Yaw:
+randomness-rendering = Rendering 3D distribution...
+randomness-caption = Joint Distribution: { $x } vs { $y }
+randomness-metrics-heading = Sector Randomness Metrics
+randomness-legend = Entropy: higher = more random. Hurst: 0.5 = random walk, >0.5 = trending, <0.5 = mean-reverting. AC = autocorrelation (near 0 = random).
+behavior-random-walk = Random Walk
+behavior-trending = Trending
+behavior-mean-reverting = Mean-Reverting
+
+## Settings
+
+settings-profiles = Profiles
+settings-workspace = Workspace
+settings-screenshot = Screenshot
+settings-scheduled-capture = Scheduled Capture
+settings-email = Email Reports
+settings-background = Background Mode
+settings-grpc = gRPC Server
+settings-webhooks = Alert Webhooks
+settings-display = Display
+settings-api-keys = API Keys
+settings-logging = Logging
+settings-analysis-windows = Analysis Windows
+settings-custom-indicators = Custom Indicators
+settings-nn-training = Neural Network Training
+settings-language = Language:
+settings-language-hover = Language of the interface and of number and date formats.
+settings-language-save-failed = Failed to save language: { $error }
+settings-zoom = UI Zoom:
+settings-zoom-hover = Scales the whole interface, e.g. for high-DPI monitors or presentations.
+settings-font-size = Font Size:
+settings-display-save-failed = Failed to save display settings: { $error }
+settings-palette = Color Palette:
+settings-palette-hover = Colors used for calm vs stressed readings in the dashboard, heatmap and correlation views.
+settings-palette-save-failed = Failed to save color palette: { $error }
+palette-calm = Calm / gain
+palette-normal = Normal
+palette-stressed = Stressed / loss
+settings-explain-metrics = Explain metrics on hover
+settings-explain-metrics-hover = Hovering Parkinson vol, vol ratio, the 10Y-2Y spread or average correlation shows its definition, formula and how to read it.
+settings-explain-save-failed = Failed to save explain mode: { $error }
+settings-save = Save
+settings-delete = Delete
+settings-overwrite = Overwrite
+settings-select = Select…
+settings-test = Test
+settings-save-settings = Save Settings
+settings-profile = Profile:
+settings-load = Load
+settings-profile-save-as = Save current as:
+settings-profile-hint = e.g. Macro weekly
+settings-profile-saved = Saved profile "{ $name }".
+settings-profile-loaded = Loaded profile "{ $name }".
+settings-profile-help = A profile bundles the sector universe, watchlist, analysis windows, NN hyperparameters and feature flags, and the color palette and display settings.
+settings-workspace-label = Workspace:
+settings-workspace-open = Open workspace
+settings-workspace-open-hover = Replaces the loaded data, analysis settings, layout and saved model
+settings-workspace-save-as = Save workspace as:
+settings-workspace-hint = e.g. Q3 review
+settings-workspace-save = Save workspace
+settings-workspace-saved = Workspace saved: { $path }
+settings-workspace-opened = Opened workspace "{ $name }".
+settings-workspace-help = A workspace bundles the market data, analysis settings, custom indicators, layout, NN predictions and trained model into one file under { $dir }/, to archive a session or share it. Analysis is recomputed from the bundled data on open. Webhooks, email, jobs, gRPC and other machine settings are not included.
+settings-save-path = Save Path:
+settings-browse = Browse…
+settings-file-type = File Type:
+settings-tiff-hover = TIFF compression level is informational only
+settings-compression = Compression:
+settings-compression-none = None
+settings-compression-low = Low
+settings-compression-high = High
+settings-capture = Capture:
+settings-screenshot-saved = Screenshot settings saved.
+settings-screenshot-save-failed = Failed to save screenshot settings: { $error }
+settings-screenshot-help = Use the 📷 camera button in the tab bar to capture a screenshot; right-click it to copy to the clipboard instead.
+settings-output-folder = Output folder:
+settings-open-jobs = Open Jobs
+settings-jobs-help = Report, screenshot and email jobs, and when they run, are set up in the Jobs window.
+settings-output-help = Each run writes into a YYYY-MM-DD subfolder. A job missed while the app was closed runs once at the next start; the screenshot is of the Dashboard tab.
+settings-output-save-failed = Failed to save output folder: { $error }
+settings-smtp-server = SMTP server:
+settings-port = Port:
+settings-security = Security:
+settings-security-none = None (local relay)
+settings-username = Username:
+settings-from = From:
+settings-from-hint = Same as username
+settings-recipients = Recipients:
+settings-smtp-password-missing = Set the SMTP password under API Keys.
+settings-send-report = Send report now
+settings-send-report-disabled = Enter the SMTP server and at least one recipient
+settings-email-help = The report is sent as HTML with its charts embedded. To send it on a schedule, add an "Email report" job in the Jobs window.
+settings-email-save-failed = Failed to save email settings: { $error }
+settings-tray-close-hint = Closing the window hides it to the system tray; auto-refresh and alerts keep running. Quit from the tray menu.
+settings-no-tray-close-hint = This build has no tray icon (`tray` feature), so closing the window minimizes it; auto-refresh and alerts keep running.
+settings-keep-running = Keep running when the window is closed
+settings-desktop-notifications = Desktop notifications for alerts while hidden
+settings-tray-unavailable = Tray icon unavailable: { $error }
+settings-test-notification = Send test notification
+settings-test-notification-body = Alert notifications are working.
+settings-background-save-failed = Failed to save background mode settings: { $error }
+settings-grpc-unavailable = This build has no gRPC server. Rebuild with `cargo build --release --features grpc` to enable it.
+settings-grpc-enable = Serve analysis and predictions over gRPC
+settings-grpc-enable-hover = Service definition: proto/vol_analysis.proto
+settings-grpc-address = Listen address:
+settings-grpc-address-hover = Use 0.0.0.0:<port> to accept connections from other machines
+settings-grpc-listening = Listening on { $address }
+settings-grpc-stopped = Stopped.
+settings-grpc-not-started = gRPC server not started: { $error }
+settings-grpc-start = Start
+settings-grpc-save-failed = Failed to save gRPC settings: { $error }
+settings-webhooks-empty = No webhooks yet. Fired alerts are posted to every enabled webhook.
+settings-webhook-name = Name
+settings-webhook-test-hover = Post a sample alert to this webhook
+settings-webhook-delete = Delete webhook
+settings-webhook-add = Add Webhook
+settings-webhooks-help = Slack and Discord: paste the channel's incoming webhook URL. JSON posts {"text", "alerts"} to any URL. Failed posts show in the Tasks window.
+settings-webhook-testing = Sending a test alert to { $webhook }…
+settings-webhooks-save-failed = Failed to save webhooks: { $error }
+settings-api-used-for = Used for: { $uses }
+settings-api-not-set = Not set
+settings-api-new-key = New key
+settings-api-key-saved = { $provider } key saved; it is used from the next refresh.
+settings-api-key-save-failed = Failed to save { $provider } key: { $error }
+settings-api-clear = Clear
+settings-api-clear-hover = Forget the saved key; { $var } in .env or the environment still applies on the next launch
+settings-api-key-cleared = { $provider } key cleared.
+settings-api-key-clear-failed = Failed to clear { $provider } key: { $error }
+settings-api-test = Test connection
+settings-api-show-keys = Show keys
+settings-api-help = Keys are saved per environment to cache/{ $file } and take precedence over .env; a key set in the process environment wins over both at startup. Other environments cache their downloads under cache/env/.
+settings-api-environment = Environment:
+settings-api-env-remove = Remove
+settings-api-env-remove-hover = Delete this environment's keys; its cached data stays on disk
+settings-api-env-removed = Removed the { $name } API environment.
+settings-api-env-remove-failed = Failed to remove the { $name } API environment: { $error }
+settings-api-env-new = New environment
+settings-api-env-add = Add
+settings-api-env-add-failed = Failed to add API environment: { $error }
+settings-api-env-switched = Switched to the { $name } API environment; refresh to load its data.
+settings-api-env-switch-failed = Failed to switch API environment: { $error }
+settings-fmp-endpoint = FMP endpoint:
+settings-fmp-endpoint-hover = Send this environment's FMP requests here, e.g. a sandbox; leave empty for the live API
+settings-fmp-endpoint-saved = FMP endpoint saved; it is used from the next refresh.
+settings-fmp-endpoint-save-failed = Failed to save FMP endpoint: { $error }
+settings-api-test-task = { $provider } connection test
+settings-log-level = Log Level:
+settings-log-level-hover = Debug and Trace include per-request and per-epoch detail and grow the log files quickly.
+settings-log-level-save-failed = Failed to save log level: { $error }
+settings-log-help = Logs are written to { $dir }/ with one file per day; the last { $days } days are kept.
+settings-short-vol = Short Vol Window:
+settings-days = days
+settings-long-vol = Long Vol Window:
+settings-lookback = History Lookback:
+settings-calendar-days = calendar days
+settings-lookback-hover = How much history each refresh downloads
+settings-presets = Presets:
+settings-preset = { $short }D / { $long }D
+settings-defaults = Defaults
+settings-apply = Apply
+settings-windows-save-failed = Failed to save analysis windows: { $error }
+settings-windows-applied-lookback = Analysis windows applied; the new lookback takes effect on the next refresh.
+settings-windows-applied = Analysis windows applied.
+settings-revert = Revert
+settings-windows-help = Vol windows drive the dashboard, sector, risk, compare and watchlist views. The neural net keeps its built-in windows so saved models stay valid.
+settings-indicator-enabled-hover = Chart in Sector Vol
+settings-edit = Edit
+settings-indicator-new = New indicator
+settings-indicator-name = Name:
+settings-indicator-test-result = { $symbol }: { $count } values, latest { $latest }
+settings-indicator-duplicate = Name already used
+settings-indicators-help = Rhai scripts over the sector shown in Sector Vol: open, high, low, close, volume, returns, vol_short, vol_long and parkinson are arrays (oldest first); sma(a, n), ema(a, n) and stdev(a, n) are available. End with an array of numbers; it is aligned to the latest bar. Mark an indicator under Neural Network Training to use it as a feature.
+settings-nn-features = Select input features to use during model training:
+settings-nn-sector-vol = Sector Volatility (11 features)
+settings-nn-randomness = Market Randomness (22 features)
+settings-nn-kurtosis = Kurtosis (22 features)
+settings-nn-custom = Custom: { $name } ({ $count } features)
+settings-nn-feature-count = Input features per day: { $count } ({ $base } base + enabled groups)
+settings-nn-hyperparameters = Hyperparameters:
+settings-nn-hidden-size = Hidden Size:
+settings-nn-lookback = Lookback:
+settings-nn-learning-rate = Learning Rate:
+settings-nn-epochs = Epochs:
+settings-nn-target = Target:
+settings-nn-target-level = Vol level (MSE)
+settings-nn-target-regime = Vol regime (low / normal / high)
+settings-nn-forecast = Forecast:
+settings-nn-forecast-hover = Series the vol output predicts. Change and log targets are converted back to vol levels for predictions and backtests.
+settings-nn-loss = Loss Function:
+settings-nn-loss-mse = (MSE for this target)
+settings-nn-grad-clip = Gradient Clipping:
+settings-nn-max-norm = Max norm
+settings-nn-precision = Precision:
+settings-nn-half-precision = Half precision (f16) on GPU
+settings-nn-half-precision-hover = Lower VRAM use and faster on consumer GPUs; uses dynamic loss scaling. Falls back to f32 if unsupported.
+settings-nn-batch-size = Batch Size:
+settings-nn-batch-auto = Auto
+settings-nn-batch-auto-hover = GPU: largest batch that fits in free VRAM. CPU: 32.
+settings-nn-augmentation = Augmentation:
+settings-nn-augmentation-hover = Random perturbations of training windows to reduce overfitting. 0 disables each.
+settings-nn-jitter = Jitter σ
+settings-nn-warp = Warp ±
+settings-nn-feature-dropout = Feature dropout
+settings-nn-metrics-export = Metrics Export:
+settings-nn-metrics-csv-hover = Per-epoch loss, LR, and gradient norm in cache/training_runs/
+settings-nn-tensorboard = TensorBoard events
+settings-nn-gpu-adapter = GPU Adapter:
+settings-nn-gpu-default = Default
+settings-nn-gpu-save-failed = Failed to save GPU adapter: { $error }
+settings-nn-inference-precision = Inference Precision:
+settings-nn-inference-precision-hover = Quantized weights make prediction refreshes near-instant on CPU, at a small accuracy cost. Training is unaffected.
+settings-nn-inference-save-failed = Failed to save inference precision: { $error }
+settings-nn-drift = Drift Warning:
+settings-nn-drift-hover = Live predictions are scored once realized vol is known; warn when their rolling error exceeds the threshold.
+settings-nn-drift-window = Rolling error over last
+settings-nn-drift-threshold = predictions above
+settings-saved = Settings saved successfully.
+settings-save-failed = Failed to save settings: { $error }
+settings-nn-apply-help = Settings are applied when you start a new training session.
+palette-standard = Standard (red/green)
+palette-colorblind = Colorblind-safe (blue/orange)
+
+## Charts and tables
+
+chart-drag-to-resize = drag to resize ·
+export-csv = Export CSV
+export-csv-hover = Save the series shown here as a CSV file
+export-csv-done = Exported { $path }
+export-csv-failed = CSV export failed: { $error }
+copy-table = Copy table
+copy-table-done = Copied { $count } rows to the clipboard
+no-data-loaded = No data loaded. Click 'Refresh Data' to fetch market data.
+axis-trading-day-plain = Trading Day
+
+## Sectors
+
+event-fomc = FOMC
+event-cpi = CPI
+event-earnings = Earnings
+event-inversion = Curve Inversion
+event-note = Note
+sector-heading = Sector Volatility Analysis
+sector-select = Select Sector:
+sector-unknown = Unknown
+sector-select-placeholder = Select...
+sector-overlay = Overlay { $symbol }
+sector-overlay-hover = Benchmark price rebased to the sector's first close, and its short-window vol
+sector-period = Period:
+sector-period-range = { $from } to { $to } ({ $days } trading days)
+sector-full-history = Full History
+sector-chart-help = Drag or Ctrl+scroll a chart to pan/zoom time on all charts below; Shift+drag to box-zoom; right-click or double-click to reset.
+sector-rebased = { $symbol } (rebased)
+sector-vol-series = { $days }D Vol
+sector-price-chart = Price Chart
+sector-price-height = Price Chart Height
+sector-axis-price = Price ($)
+sector-volume-height = Volume Chart Height
+sector-volume-avg = { $days }D Avg
+sector-volume = Volume
+sector-axis-volume = Volume (M)
+sector-drawdown-height = Drawdown Chart Height
+sector-axis-drawdown = Drawdown (%)
+sector-max-dd = Max DD { $depth }
+sector-not-recovered = not yet recovered
+sector-recovered = recovered { $date }
+sector-drawdown-summary = { $symbol } max drawdown { $depth }: peak { $peak } → trough { $trough }, { $recovery }
+sector-return-distribution = Return Distribution
+sector-qq-plot = QQ Plot
+sector-qq-window = Uses the Return Distribution window.
+sector-showing-vol = Showing { $short }-day and { $long }-day rolling volatility
+sector-parkinson = Parkinson Vol
+sector-vol-height = Volatility Chart Height
+sector-axis-annualized-vol = Annualized Vol (%)
+sector-ratio-heading = Volatility Ratio (Short / Long) - above 1.0 indicates rising vol regime
+sector-ratio-height = Vol Ratio Chart Height
+sector-baseline = Baseline (1.0)
+sector-latest-vol = Latest: { $short }D Vol = { $short_vol } | { $long }D Vol = { $long_vol } | Ratio = { $ratio }
+sector-no-vol = No volatility data computed for this sector yet.
+sector-custom-indicators = Custom Indicators
+sector-custom-height = Custom Indicator Chart Height
+sector-window-all = All
+sector-not-enough-returns = Not enough returns in this window.
+sector-empirical = Empirical
+sector-normal = Normal
+sector-histogram-height = Return Histogram Height
+sector-return-count = { $count } returns
+sector-mean = Mean: { $value }
+sector-std-dev = Std Dev: { $value }
+sector-skew = Skew: { $value }
+sector-excess-kurtosis = Excess Kurtosis: { $value }
+sector-qq-sample = Sample (σ)
+sector-qq-height = QQ Plot Height
+sector-axis-normal-quantile = Normal Quantile (σ)
+sector-axis-sample-quantile = Sample Quantile (σ)
+sector-qq-reference = Normal (y = x)
+sector-qq-note = Points bending away from the line at either end mark fatter-than-normal tails.
+sector-events = Events & Annotations
+sector-events-show = Show:
+sector-events-note = Built-in markers cover FOMC decisions, CPI releases and 10Y-2Y inversions. Hover a chart on a marked day to see its events.
+sector-events-add-label = Add:
+sector-events-label-hint = Label
+sector-events-add = Add
+sector-events-need-label = Enter a label for the annotation
+sector-events-bad-date = Date must be YYYY-MM-DD
+sector-events-delete = Delete annotation
+sector-ribbon-vol = Vol
+sector-ribbon-corr = Corr
+sector-regime-since = { $regime } since { $date }
+
+## Correlation
+
+regime-low-vol = Low Vol
+regime-normal-vol = Normal Vol
+regime-high-vol = High Vol
+regime-dispersed = Dispersed
+regime-normal = Normal
+regime-herding = Herding
+unit-days-short = { $days }D
+corr-heading = Cross-Sector Correlation Matrix
+corr-no-data = No correlation data available. Load market data first.
+corr-cell-hover = { $a } vs { $b }: click for pair details, right-click to copy the table
+corr-legend = Legend:
+corr-less-correlated = { $value } (less correlated)
+corr-more-correlated = { $value } (more correlated)
+corr-network-heading = Correlation Network (Minimum Spanning Tree)
+corr-reset-layout = Reset layout
+corr-network-height = Network Height
+corr-node-hover = { $symbol }: { $days }-day vol { $vol }
+corr-network-note = Edges link each sector to its most correlated neighbour (distance sqrt(2(1 - rho))). Node size and color: { $days }-day vol. Drag nodes to rearrange.
+corr-pair-heading = { $a } vs { $b }
+corr-close-pair = Close the pair panel
+corr-pair-summary = Full-sample correlation: { $correlation } | Beta of { $b } on { $a }: { $beta }
+corr-pair-short = Not enough history for the { $days }-day rolling correlation.
+corr-pair-rolling = { $days }-Day Rolling Correlation
+corr-correlation = Correlation
+corr-pair-height = Pair Correlation Chart Height
+corr-daily-returns = Daily Log Returns (%)
+corr-scatter-height = Pair Scatter Height
+corr-daily-returns-series = Daily returns
+corr-window = Window:
+corr-full-sample = Full Sample
+corr-ending = Ending:
+corr-days-ago = days ago
+corr-show-change = Show change vs
+corr-days-earlier = trading days earlier
+corr-sample = Sample: { $from } to { $to } ({ $days } trading days)
+corr-change-versus = Change versus the window ending { $date }: average correlation { $change }
+corr-window-average = Average correlation in window: { $value }
+corr-average-short = Average cross-sector correlation: { $value } (not enough history for the { $days }-day rolling chart)
+corr-rolling-average = { $days }-day average cross-sector correlation: { $value }
+corr-full-sample-value = (full sample: { $value })
+corr-avg-corr = Avg Corr
+corr-rolling-height = Rolling Correlation Chart Height
+corr-axis-avg = Avg Correlation
+corr-bands = Bands:
+corr-dispersion-heading = Cross-Sectional Dispersion vs Correlation
+corr-dispersion-summary = { $days }-day average dispersion: { $dispersion } | average correlation: { $correlation }
+corr-dispersion = Dispersion
+corr-dispersion-avg = Disp. avg
+corr-axis-dispersion = Dispersion (%)
+corr-dispersion-height = Dispersion Chart Height
+corr-daily-dispersion = Daily dispersion
+corr-avg-dispersion = { $days }-day avg dispersion
+corr-avg-right-axis = Avg correlation (right axis)
+corr-dispersion-note = Dispersion is the cross-sector standard deviation of each day's log returns. High correlation with low dispersion means sectors are moving as one.
+
+## Kurtosis
+
+col-metric = Metric
+col-value = Value
+axis-trading-days = Trading Days
+unit-day-window = { $days }-Day
+kurt-heading = Kurtosis & Return Distribution Analysis
+kurt-no-data = Load market data first to compute distribution statistics.
+kurt-sector = Sector:
+kurt-stats-heading = { $symbol } - Distribution Statistics
+kurt-mean = Mean
+kurt-std-dev = Std Dev
+kurt-excess = Excess Kurtosis
+kurt-skewness = Skewness
+kurt-jarque-bera = Jarque-Bera
+kurt-tail-type = Tail Type
+kurt-leptokurtic = Leptokurtic (fat tails)
+kurt-platykurtic = Platykurtic (thin tails)
+kurt-mesokurtic = Mesokurtic (near normal)
+kurt-distribution-heading = Return Distribution (KDE vs Normal Fit)
+kurt-empirical-kde = Empirical KDE
+kurt-normal-fit = Normal Fit
+kurt-distribution-height = Distribution Plot Height
+kurt-axis-log-return = Daily Log Return (%)
+kurt-axis-density = Density
+kurt-empirical-series = Empirical (KDE)
+kurt-distribution-note = Blue = empirical density (Gaussian KDE). Red dashed = fitted normal distribution. Fat tails appear where the blue curve extends beyond the red.
+kurt-rolling-heading = Rolling Excess Kurtosis ({ $days }-day window)
+kurt-rolling = Rolling Kurtosis
+kurt-rolling-height = Rolling Kurtosis Chart Height
+kurt-normal-zero = Normal (0)
+kurt-accel-heading = Kurtosis Acceleration / Deceleration
+kurt-velocity = Velocity
+kurt-acceleration = Acceleration
+kurt-accel-height = Acceleration Chart Height
+kurt-axis-observation = Observation
+kurt-axis-rate = Rate of Change
+kurt-velocity-trend = Velocity (trend)
+kurt-zero = Zero
+kurt-projected-heading = Projected Metrics (one-step-ahead linear extrapolation)
+kurt-trend = Kurtosis Trend (velocity)
+kurt-projected-accel = Projected Accel/Decel
+kurt-projected-kurtosis = Projected Kurtosis
+kurt-accel-note = Velocity = first difference of rolling kurtosis (positive = rising). Acceleration = second difference (positive = accelerating). Projections use linear extrapolation one step ahead.
+kurt-skew-heading = Rolling Skewness ({ $days }-day window)
+kurt-rolling-skew = Rolling Skewness
+kurt-skew-height = Rolling Skewness Chart Height
+kurt-symmetric-zero = Symmetricity (0)
+kurt-comparison-heading = Cross-Sector Kurtosis Comparison
+kurt-fat-tails = Fat tails
+kurt-thin-tails = Thin tails
+kurt-normal-like = Normal-like
+kurt-footer-note = Excess kurtosis > 0 indicates heavier tails than a normal distribution (leptokurtic). The Jarque-Bera test statistic measures departure from normality (critical value ~5.99 at 5% significance).
+
+## Neural network
+
+nn-heading = Neural Network - Volatility Regime Prediction
+nn-no-data = Load market data first before training the neural network.
+nn-architecture = Model Architecture: LSTM (hidden={ $hidden }) -> Linear
+nn-input = Input: { $count } features (returns, cross-corr, spread, slope, VIX-proxy + enabled groups: vols, randomness, kurtosis)
+nn-output = Output: { $target } 5 days forward + entropy + kurtosis/skewness per sector
+nn-lookback = Lookback: { $lookback } trading days per sample | LR: { $lr } | Epochs: { $epochs } | Loss: { $loss }
+nn-target-sector-average = Sector average vol
+nn-target-sector = { $symbol } vol
+nn-target-benchmark = Benchmark ({ $symbol }) vol
+nn-target-vol-change = Vol change
+nn-target-log-vol = Log vol
+nn-cross-entropy = Cross-Entropy
+nn-model-saved = Model saved and loaded from disk (trained { $trained }; loss: { $loss }).
+nn-model-save-failed = model could not be saved or loaded from disk.
+nn-model-loaded = Model loaded from disk (trained { $trained }; loss: { $loss })
+nn-warning = Warning: { $message }
+nn-training-device = Training Device:
+nn-cpu-device = CPU (NdArray)
+nn-detected = Detected: { $name }
+nn-detected-many = Detected: { $count } adapters (choose in Settings)
+nn-no-gpu = No GPU detected (WGPU found no adapters)
+nn-train = Train Model
+nn-sweep-running = A hyperparameter sweep is running
+nn-predict = Predict
+nn-predict-hover = Load the last saved model and predict from current market data
+nn-run-backtest = Run Backtest
+nn-saliency = Saliency
+nn-saliency-hover = Which lookback days and features drove the latest prediction
+nn-export-features = Export Features
+nn-export-features-hover = Save the engineered feature matrix and targets as Parquet, for modeling elsewhere
+nn-export-features-done = Feature dataset saved: { $path }
+nn-export-features-failed = Feature export failed: { $error }
+nn-export-features-no-data = Not enough market data to build the feature dataset
+nn-retrain-failed = Retraining failed: { $error }
+nn-training-interrupted = Interrupted after { $epochs }/{ $total } epochs
+nn-training-epoch = Training... Epoch { $epoch }/{ $total } | Loss: { $loss }
+nn-pause = Pause
+nn-paused-epoch = Paused at Epoch { $epoch }/{ $total } | Loss: { $loss }
+nn-resume = Resume
+nn-stop = Stop
+nn-training-complete = Training complete! Final loss: { $loss }
+nn-retrain = Retrain
+nn-error = Error: { $error }
+nn-retry = Retry
+nn-predictions-heading = 5-Day Forward Predictions
+nn-all-disabled = All feature groups are disabled. Enable at least one feature in Settings to see predictions.
+nn-volatility = Volatility
+nn-col-vol = Vol (%)
+nn-col-recent-runs = Recent Runs
+nn-predicted-vol = Predicted vol
+nn-randomness = Randomness
+nn-predicted-entropy = Predicted entropy
+nn-kurtosis = Kurtosis
+nn-col-kurt = Kurt
+nn-col-skew = Skew
+nn-predicted-kurtosis = Predicted kurtosis
+nn-predicted-skew = Predicted skew
+nn-no-predictions = No predictions yet. Train the model to generate predictions.
+nn-footer = Neural network powered by the Burn deep learning framework.
+nn-regime-heading = 5-Day Forward Vol Regime
+nn-regime-low = Low
+nn-regime-normal = Normal
+nn-regime-high = High
+nn-most-likely = Most likely
+nn-low-regime = Low regime
+nn-normal-regime = Normal regime
+nn-high-regime = High regime
+nn-most-likely-regime = Most likely regime
+nn-regime-note = Regimes are terciles of each sector's forward vol over the training period.
+nn-sweep-heading = Hyperparameter Search
+nn-sweep-hidden = Hidden sizes:
+nn-sweep-lookbacks = Lookbacks (days):
+nn-sweep-lrs = Learning rates:
+nn-sweep-epochs = Epochs per trial:
+nn-sweep-mode = Mode:
+nn-sweep-grid = Grid
+nn-sweep-random = Random
+nn-sweep-trials = trials
+nn-sweep-start = Start Sweep ({ $count } runs)
+nn-sweep-epoch = epoch { $epoch }/{ $total }
+nn-sweep-running-status = Sweep running: { $done }/{ $total } runs finished
+nn-cancel = Cancel
+nn-sweep-finished = Sweep finished: { $done }/{ $total } runs
+nn-clear = Clear
+nn-leaderboard = Leaderboard (lowest validation loss first)
+nn-col-hidden = Hidden
+nn-col-lookback = Lookback
+nn-col-lr = LR
+nn-col-val-loss-of = Val Loss ({ $loss })
+nn-col-train-loss-of = Train Loss ({ $loss })
+nn-col-status = Status
+nn-status-pending = Pending
+nn-status-running = Running
+nn-status-done = Done
+nn-status-failed = Failed
+nn-status-complete = Complete
+nn-status-stopped = Stopped
+nn-status-paused = Paused
+nn-status-idle = Idle
+nn-sweep-use = Use
+nn-sweep-use-hover = Apply these hyperparameters (epochs unchanged); click Train Model to train and save
+nn-sweep-applied = Applied sweep config: hidden={ $hidden }, lookback={ $lookback }, lr={ $lr }
+nn-task-trials = { $done }/{ $total } trials
+nn-task-sweep = Hyperparameter sweep
+nn-backtest-heading = Backtest: Predicted vs Realized 5-Day Vol
+nn-col-period = Period
+nn-col-samples = Samples
+nn-col-hit-rate = Hit Rate
+nn-all-history = All history
+nn-out-of-sample = Out-of-sample
+nn-in-sample = In-sample
+nn-backtest-note = Out-of-sample = the most recent 20% of windows, held out from training. Hit rate = vol direction (vs. vol at window end) predicted correctly.
+nn-backtest-scatter-height = Backtest Scatter Height
+nn-axis-realized-vol = Realized Vol (%)
+nn-axis-predicted-vol = Predicted Vol (%)
+nn-perfect-forecast = Perfect forecast
+nn-loss-heading = Training Loss
+nn-log-scale = Log scale
+nn-log-scale-hover = Plot log10(loss) so late-epoch improvements stay visible
+nn-loss-name = { $loss } Loss
+nn-loss-log = { $loss } (log)
+nn-train-series = Train
+nn-validation-series = Validation
+nn-loss-height = Loss Chart Height
+nn-axis-epoch = Epoch
+nn-best-epoch = Best epoch ({ $epoch })
+nn-sparkline-short = Needs at least two refreshes with predictions
+nn-sparkline-range = Last { $count } runs: { $low } to { $high }
+nn-predicted = Predicted
+nn-realized = Realized
+nn-backtest-timeline-height = Backtest Timeline Height
+nn-axis-window = Window (chronological)
+nn-axis-5d-vol = 5-Day Vol (%)
+nn-oos-start = Out-of-sample start
+nn-residuals-heading = Residual Diagnostics (out-of-sample)
+nn-bias = Bias (pred − realized):
+nn-residual-std = Residual Std Dev:
+nn-lag1-acf = Lag-1 Autocorrelation:
+nn-acf-band = { $value } (95% band ±{ $band })
+nn-top-quintile-bias = Bias in Top Vol Quintile:
+nn-bias-at-level = { $bias } at { $level } realized vol
+nn-residuals-note = Negative bias in the top quintile means vol spikes are under-predicted. Overlapping 5-day targets make the first few lags positive even for a good model.
+nn-residuals-height = Residual Charts Height
+nn-residual-acf = Residual Autocorrelation
+nn-axis-lag = Lag (windows)
+nn-axis-autocorrelation = Autocorrelation
+nn-error-vs-level = Error vs Realized Vol Level
+nn-axis-residual = Pred − Realized (%)
+nn-oos-mean = OOS mean by quintile
+nn-drift-exceeded = Warning: prediction drift -- rolling error { $error } over the last { $count } resolved predictions exceeds { $threshold }. Consider retraining.
+nn-drift-tracking = Live tracking: rolling error { $error } over { $count } resolved predictions ({ $pending } pending, threshold { $threshold })
+nn-drift-pending = Live tracking: { $count } predictions logged, scored once { $days } more trading days are available
+nn-gpu-diagnostics = GPU Diagnostics
+nn-run-diagnostics = Run Diagnostics
+nn-benchmarking = Benchmarking (about 10 s)...
+nn-diagnostics-note = Correctness check, matmul TFLOPS, memory bandwidth, and timed training steps.
+nn-diag-adapter = Adapter:
+nn-diag-matmul = Matmul (f32):
+nn-diag-tflops = { $peak } TFLOPS peak, { $sustained } sustained
+nn-diag-bandwidth = Memory Bandwidth:
+nn-diag-gbs = { $value } GB/s
+nn-diag-step = Training Step:
+nn-diag-step-time = { $ms } ms (batch { $batch })
+nn-diag-epoch = Estimated Epoch:
+nn-diag-epoch-time = { $seconds } s; { $epochs } epochs ≈ { $minutes } min
+nn-diagnostics-failed = Diagnostics failed: { $error }
+nn-task-gpu-diagnostics = GPU diagnostics
+nn-saliency-heading = Input Saliency
+nn-saliency-note = |gradient × input| of the { $target } for the most recent window. Darker = more influence.
+nn-saliency-target-vol = predicted 5-day vol
+nn-saliency-target-regime = high-vol regime log-probability
+nn-saliency-cell = { $feature } on t-{ $day }: { $value }
+nn-saliency-summary = Most influential day: t-{ $day } | Top features: { $features }
+nn-saliency-done = Saliency computed for the { $target }.
+nn-saliency-failed = Saliency failed: { $error }
+nn-history-heading = Run History ({ $count })
+nn-history-note = Tick A and B to compare two runs' loss curves.
+nn-col-started = Started
+nn-col-objective = Objective
+nn-col-epochs = Epochs
+nn-col-train-loss = Train Loss
+nn-col-val-loss = Val Loss
+nn-col-outcome = Outcome
+nn-clear-history = Clear History
+nn-compare-heading = A: { $a }  vs  B: { $b }
+nn-diff-hidden = hidden { $from } → { $to }
+nn-diff-lookback = lookback { $from } → { $to }
+nn-diff-lr = LR { $from } → { $to }
+nn-diff-objective = objective { $from } → { $to }
+nn-diff-target = target { $from } → { $to }
+nn-diff-augmentation = augmentation differs
+nn-diff-grad-clip = grad clip { $from } → { $to }
+nn-diff-features = feature flags differ
+nn-same-config = Same configuration.
+nn-changes = Changes: { $changes }
+nn-run-train = { $run } train
+nn-run-val = { $run } val
+nn-compare-height = Run Comparison Height
+nn-axis-loss = Loss
+nn-no-saved-model = No saved model found. Train a model first.
+nn-predictions-generated = Predictions generated from model trained { $trained } ({ $precision } weights).
+nn-prediction-short = Prediction failed: not enough market data for the model's lookback.
+nn-backtest-regression-only = Backtest is only available for regression models.
+nn-backtest-failed = Backtest failed: { $error }
+nn-backtest-complete = Backtest complete: { $count } windows, out-of-sample MAE { $mae }
+nn-backtest-short = Backtest failed: not enough data to build the dataset.
+nn-compute-heading = Compute Statistics
+nn-system-training = System & Training
+nn-backend = Backend:
+nn-parameters = Parameters:
+nn-batch-size = Batch Size:
+nn-epoch-time = Epoch Time:
+nn-throughput = Throughput:
+nn-samples-per-sec = { $value } samples/sec
+nn-status = Status:
+nn-no-nvidia = No NVIDIA GPU detected
+nn-device = Device:
+nn-training-on = Training on:
+nn-cpu-gpu-idle = CPU (GPU idle)
+nn-utilization = Utilization:
+nn-temperature = Temperature:
+nn-stats = Stats:
+nn-stats-unavailable = Unavailable (nvidia-smi / rocm-smi / amd-smi not found)
+nn-gigabytes = { $used } / { $total } GB
+nn-load-data-first = Load market data before training
+nn-already-training = Training is already running
+nn-task-training = Model training
+nn-task-epoch = Epoch { $epoch }/{ $total }
+
+## Rates
+
+rates-heading = Treasury Yield Curve
+rates-play = ▶ Play
+rates-pause = ⏸ Pause
+rates-date = Date:
+rates-latest = Latest
+rates-speed = Speed:
+rates-days-per-sec = days/s
+rates-overlay-from = Overlay curve from
+rates-observations-earlier = observations earlier
+rates-on-date = date:
+rates-maturity = Maturity
+rates-higher-yield = Higher yield
+rates-lower-yield = Lower yield
+rates-versus = (vs { $date }: { $spread } / { $slope } bps)
+rates-inverted = Inverted
+rates-history-heading = Term Spreads & Recession Probability
+rates-history-short = Not enough treasury history with 2Y and 10Y yields for the spread chart.
+rates-recession-prob = Recession prob.
+rates-history-height = Spread History Chart Height
+rates-axis-spread = Spread (pp)
+rates-axis-recession = Recession Probability (%)
+rates-observation = Observation
+rates-inverted-periods = Inverted (10Y < 2Y)
+rates-recession-line = Recession probability (12M, right axis)
+rates-recession-note = Recession probability: NY Fed probit on the 10Y-3M spread, for the next 12 months. It is fitted on monthly averages, so daily readings are noisier.
+rates-changes = Change by maturity ({ $from } → { $to })
+
+## Bonds
+
+bond-heading = Bond Spreads & Yield Curve
+bond-no-data = No treasury rate data available. Load market data first.
+bond-current-curve = Current Yield Curve
+bond-date = Date: { $date }
+bond-yield = Yield
+bond-curve-height = Yield Curve Chart Height
+bond-axis-yield = Yield (%)
+bond-yield-curve = Yield Curve
+bond-term-spread-heading = 10Y-2Y Term Spread Over Time
+bond-spread = 10Y-2Y Spread
+bond-spread-height = Term Spread Chart Height
+bond-axis-spread = Spread (percentage points)
+bond-zero = Zero
+bond-slope-heading = Curve Slope (30Y - 3M)
+bond-slope = 30Y-3M Slope
+bond-slope-height = Curve Slope Chart Height
+bond-axis-slope = Slope (percentage points)
+bond-latest = Latest ({ $date }) | 10Y-2Y: { $spread } pp | Curve Slope: { $slope } pp | { $shape }
+bond-inverted = INVERTED
+bond-normal = Normal
+bond-inversions = Warning: { $count } yield curve inversion(s) detected in data
+
+## Risk
+
+risk-heading = Sector Risk Dashboard
+risk-intro = Historical one-day VaR and Expected Shortfall at { $confidence } confidence, max drawdown over the loaded history, Hill tail index of the worst { $tail } of daily losses, and where the current { $days }D vol sits in each sector's own range. Click a sector to open it in Sector Vol.
+risk-col-var = VaR { $confidence }
+risk-col-es = ES { $confidence }
+risk-col-drawdown = Max Drawdown
+risk-col-tail-index = Tail Index α
+risk-col-percentile = Vol Percentile
+risk-unknown = Unknown
+risk-open-sector = { $name } - open in Sector Vol
+risk-percentile = { $rank }th
+risk-tail-note = Tail index: lower α means fatter tails (below 3 is heavier than typical equity returns).
+risk-beta-heading = Rolling { $days }D Beta vs { $benchmark }
+risk-beta-no-benchmark = Benchmark data is needed for beta. Refresh to load it.
+risk-sector = Sector:
+risk-beta-short = Not enough history for the { $days }-day rolling beta.
+risk-beta-latest = Latest beta: { $beta } - a { $one } move in { $benchmark } has recently come with a { $move } move in { $symbol }.
+risk-beta-height = Beta Chart Height
+risk-beta-note = Dashed line: beta of 1 (moves one-for-one with the benchmark).
+risk-map-heading = Risk / Return Map
+risk-trailing-window = Trailing window:
+risk-window-1m = 1M
+risk-window-3m = 3M
+risk-window-6m = 6M
+risk-window-1y = 1Y
+risk-map-short = Not enough history for a { $days }-day window.
+risk-map-height = Scatter Chart Height
+risk-axis-vol = Realized Vol (annualized %)
+risk-axis-return = { $days }D Return (%)
+risk-map-point =
+    { $name }
+    Vol: { $vol }
+    Return: { $return }
+risk-avg-return = Sector Avg Return
+risk-map-note = Point size scales with the short/long vol ratio: larger points are sectors whose vol is rising.
+
+## Compare
+
+compare-heading = Multi-Sector Comparison
+compare-sectors = Sectors:
+compare-all = All
+compare-none = None
+compare-metric = Metric:
+compare-normalized-price = Normalized Price
+compare-short-vol = { $days }D Vol
+compare-select-sectors = Select one or more sectors to overlay.
+compare-axis-rebased = Price (rebased to 100)
+compare-axis-vol = Annualized Vol (%)
+compare-chart-height = Comparison Chart Height
+compare-start = Start
+compare-end = End
+compare-change = Change
+unit-pts = { $value } pts
+unit-percent = { $value }%
+
+## Watchlist
+
+watchlist-add-symbol = Add symbol:
+watchlist-add-hint = e.g. AAPL, ^VIX, BTC-USD
+watchlist-add = Add
+watchlist-refresh-all = Refresh All
+watchlist-fetching = Fetching { $symbols }…
+watchlist-intro = Watchlist symbols get the standard { $short }D/{ $long }D vol metrics but stay out of the sector analysis and neural net.
+watchlist-empty = Add a ticker above to start tracking it.
+watchlist-remove = Remove from watchlist
+watchlist-save-failed = Failed to save watchlist: { $error }
+watchlist-vols = { $short }D Vol { $short_vol } | { $long }D Vol { $long_vol }
+watchlist-ratio = Ratio { $ratio }
+watchlist-percentile = | Vol pctile { $rank }th
+watchlist-task = Watchlist { $symbol }
+watchlist-short-history = Only { $count } bars of history; not enough for vol metrics
+
+## Alerts
+
+alerts-heading = Alerts
+alerts-intro = Rules are checked after each data refresh and fire when their condition starts to hold.
+alerts-history = History
+alerts-clear = Clear
+alerts-history-empty = No alerts have fired yet.
+alerts-no-rules = No alert rules yet.
+alerts-enabled = Enabled
+alerts-holds = Condition currently holds
+alerts-delete-rule = Delete rule
+alerts-any-sector = Any sector
+alerts-add-rule = Add Rule
+alerts-fired-sector = { $rule }: { $symbol } is { $value }
+alerts-fired = { $rule }: now { $value }
+alert-metric-vol-ratio = Vol ratio
+alert-metric-short-vol = Short vol
+alert-metric-avg-correlation = Avg correlation
+alert-metric-term-spread = 10Y-2Y spread
+alert-metric-predicted-vol = Predicted vol
+
+## Data
+
+data-intro = Every price series the app loads. Excluded sectors are left out of analysis and NN training.
+data-col-name = Name
+data-col-from = From
+data-col-to = To
+data-col-source = Source
+data-col-cache-age = Cache Age
+data-col-include = Include
+data-col-item = Item
+data-col-status = Status
+data-col-attempts = Attempts
+data-col-detail = Detail
+data-not-loaded = Not loaded
+data-no-cache = No cache
+data-benchmark-always = The benchmark is always loaded
+data-refetch-hover = Refetch from the network
+data-purge-hover = Delete the cached copy
+data-purged = Purged cache for { $symbol }
+data-purge-failed = Failed to purge cache for { $symbol }: { $error }
+data-save-excluded-failed = Failed to save excluded symbols: { $error }
+data-source-cache = Yahoo (cache)
+data-source-network = Yahoo (network)
+data-failed-kept = Failed, kept previous
+data-attempts = { $count } attempts
+data-last-refresh = Last Refresh
+data-finished = Finished { $time }: { $summary }
+data-cached = Cached
+data-downloaded = Downloaded
+data-retry-failed = Retry { $count } failed series
+data-retry-failed-hover = Refetch the failed symbols from the network
+data-other-failures = Failed rate and sentiment sources are retried by the next full refresh.
+
+## Jobs
+
+jobs-title = Jobs
+jobs-next = Next job: { $name } at { $time }
+jobs-none-enabled = No jobs enabled
+jobs-col-on = On
+jobs-col-name = Name
+jobs-col-schedule = Schedule
+jobs-col-zone = Zone
+jobs-col-action = Action
+jobs-col-next-run = Next run
+jobs-col-last-result = Last result
+jobs-run-now = Run now
+jobs-remove = Remove
+jobs-add = Add job
+jobs-help = Schedule is minute hour day-of-month month day-of-week, e.g. `30 16 * * 1-5` for 16:30 on weekdays or `0 10 * * sun`. Reports and screenshots go to the output folder under Settings → Scheduled Capture; the status bar's auto-refresh picker sets up the Auto-refresh job. Jobs wait for a refresh in progress; a run missed while the app was closed happens once at the next start.
+jobs-save-failed = Failed to save jobs: { $error }
+jobs-due-now = Due now
+jobs-in = in { $wait }
+jobs-not-run = Not run yet
+job-zone-local = Local
+job-zone-new-york = New York
+job-action-refresh = Refresh data
+job-action-retrain = Retrain model
+job-action-report = Save HTML report
+job-action-email-report = Email report
+job-action-screenshot = Dashboard screenshot
+job-refresh-busy = A refresh is already running
+job-refresh-started = Refresh started
+job-training-started = Training started
+job-saved = Saved { $path }
+job-emailing = Emailing { $count } recipient(s)
+job-capturing = Capturing to { $path }
+job-notice = Job "{ $name }": { $message }
+
+## Diagnostics
+
+diag-title = Diagnostics
+diag-indicator-hover = Frame time; click for memory and performance diagnostics
+diag-memory = Memory
+diag-heap = Heap
+diag-with-peak = { $now } (peak { $peak })
+diag-not-counted = Not counted
+diag-not-counted-hover = Build with `--features diagnostics` to count heap allocations
+diag-resident = Resident
+diag-resident-hover = Process memory as reported by the OS, including GPU driver and UI buffers. The peak covers the time this window was open.
+diag-price-data = Price data
+diag-series = { $size } ({ $count } series)
+diag-analysis = Analysis
+diag-nn-dataset = NN dataset
+diag-not-built = Not built this session
+diag-chart-cache = Chart cache
+diag-charts = { $count } charts
+diag-disk-cache = Disk cache
+diag-estimates = Subsystem sizes are estimates from the data's length.
+diag-frame-time = Frame time
+diag-frame-stats = Last { $last }  ·  mean { $mean }  ·  max { $max }
+diag-cpu-ms = CPU ms
+diag-timings = Timings
+diag-refresh-stage = Refresh: { $stage }
+diag-last-refresh = Last refresh finished { $time }
+diag-no-refresh = No refresh this session
+unit-ms = { $value } ms
+unit-seconds = { $value } s
+
+## Refresh progress
+
+refresh-loading = Loading { $done }/{ $total }
+refresh-failed = ⚠ { $count } failed
+refresh-hover = Per-symbol refresh progress
+refresh-fetching = Fetching market data...
+refresh-task = Market data refresh
+refresh-window = Refresh Progress
+refresh-retry = Retry
+refresh-status-pending = Pending
+refresh-status-fetching = Fetching
+refresh-status-cached = Cached
+refresh-status-downloaded = Downloaded
+refresh-status-failed = Failed
+refresh-refetched = Refetched { $symbol }
+refresh-refetch-failed = Refetch of { $symbol } failed: { $error }
+refresh-cache-loaded = Loaded { $count } sectors from the cache
+refresh-source-failed = { $source } failed: { $error }
+refresh-source-failed-kept = { $source } failed (kept previous data): { $error }
+refresh-loaded = Loaded { $sectors } sectors, { $rates } treasury rate entries ({ $summary }). Last refresh: { $time }
+refresh-never = N/A
+
+## Plugins
+
+plugins-heading = Analysis Plugins
+plugins-none = No analysis plugins are registered.
+plugins-failed = Plugin failed: { $error }
+plugins-height = Plugin Chart Height
+
+## Shortcuts
+
+shortcuts-title = Keyboard Shortcuts
+shortcuts-hint = Keyboard shortcuts ({ $key })
+shortcut-tab = { $tab } tab
+shortcut-switch-tab = Switch tab
+shortcut-refresh = Refresh data
+shortcut-screenshot = Take screenshot
+shortcut-copy-screenshot = Copy screenshot to clipboard
+shortcut-toggle-training = Start / stop NN training
+shortcut-next-sector = Next sector
+shortcut-prev-sector = Previous sector
+shortcut-focus-search = Focus quick search
+shortcut-toggle-overlay = Show / hide this list
+
+## Explain mode
+
+metric-parkinson-name = Parkinson Volatility
+metric-parkinson-definition = Annualized volatility estimated from each day's high-low range rather than close-to-close returns. It uses intraday information, so it needs fewer days for the same precision.
+metric-parkinson-interpretation = Reads like close-to-close vol. Running well above it means large intraday swings that mostly reverse by the close; well below it suggests overnight gaps are driving moves.
+metric-vol-ratio-name = Volatility Ratio
+metric-vol-ratio-definition = Short-window realized vol divided by long-window realized vol.
+metric-vol-ratio-interpretation = Above 1.0, recent vol is running hotter than the longer baseline (a rising-vol regime); below 1.0, markets are calming relative to the recent past.
+metric-term-spread-name = 10Y-2Y Term Spread
+metric-term-spread-definition = The 10-year Treasury yield minus the 2-year yield, a summary of the yield curve's slope.
+metric-term-spread-interpretation = Positive is a normal upward-sloping curve. Negative (inverted) means markets expect rate cuts and has historically preceded recessions, usually with a long lag.
+metric-avg-correlation-name = Average Cross-Sector Correlation
+metric-avg-correlation-definition = The mean pairwise Pearson correlation of daily log returns across all sector ETFs.
+metric-avg-correlation-interpretation = High values mean sectors move together (macro-driven, risk-on/risk-off markets with little diversification); low values mean stock picking and sector rotation dominate.
+metric-window = { $days }-day window
+metric-window-pair = { $short }-day / { $long }-day
+metric-rolling-window = { $days }-day rolling window
+
+## Tray
+
+tray-show = Show Window
+tray-refresh = Refresh Now
+tray-quit = Quit
+tray-alert = Volume Analysis alert
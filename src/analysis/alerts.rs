@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "native")]
+use crate::data::cache;
+use crate::data::models::{BondSpread, NnPredictions, VolatilityMetrics};
+
+#[cfg(feature = "native")]
+const RULES_FILENAME: &str = "alert_rules.json";
+#[cfg(feature = "native")]
+const HISTORY_FILENAME: &str = "alert_history.json";
+
+/// Oldest alerts are dropped beyond this many to keep the history file small
+pub const MAX_HISTORY: usize = 500;
+
+/// Quantity an alert rule watches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertMetric {
+    /// Short/long vol ratio of a sector (or any sector)
+    VolRatio,
+    /// Short-window annualized vol of a sector (or any sector), in percent
+    ShortVol,
+    /// Average pairwise cross-sector correlation
+    AvgCorrelation,
+    /// Latest 10Y-2Y term spread, in percentage points
+    TermSpread,
+    /// Loaded model's vol forecast, in percent
+    PredictedVol,
+}
+
+impl AlertMetric {
+    pub const ALL: [AlertMetric; 5] =
+        [Self::VolRatio, Self::ShortVol, Self::AvgCorrelation, Self::TermSpread, Self::PredictedVol];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::VolRatio => "Vol ratio",
+            Self::ShortVol => "Short vol",
+            Self::AvgCorrelation => "Avg correlation",
+            Self::TermSpread => "10Y-2Y spread",
+            Self::PredictedVol => "Predicted vol",
+        }
+    }
+
+    /// Unit suffix of values and thresholds
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::ShortVol | Self::PredictedVol => "%",
+            Self::TermSpread => " pp",
+            Self::VolRatio | Self::AvgCorrelation => "",
+        }
+    }
+
+    /// Whether the metric is computed per sector, so a rule can target one symbol
+    pub fn is_per_sector(&self) -> bool {
+        matches!(self, Self::VolRatio | Self::ShortVol)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertCondition {
+    Above,
+    Below,
+}
+
+impl AlertCondition {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Above => ">",
+            Self::Below => "<",
+        }
+    }
+
+    fn is_met(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Self::Above => value > threshold,
+            Self::Below => value < threshold,
+        }
+    }
+}
+
+/// A user-configured alert, e.g. "vol ratio > 1.3" or "10Y-2Y spread < 0"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub metric: AlertMetric,
+    /// Sector the rule watches for per-sector metrics; `None` = any sector
+    #[serde(default)]
+    pub symbol: Option<String>,
+    pub condition: AlertCondition,
+    /// In the metric's display unit (`AlertMetric::unit`)
+    pub threshold: f64,
+    pub enabled: bool,
+    /// Whether the condition held at the last evaluation; rules fire only on the
+    /// transition into the triggered state
+    #[serde(default)]
+    pub triggered: bool,
+}
+
+impl Default for AlertRule {
+    fn default() -> Self {
+        Self {
+            metric: AlertMetric::VolRatio,
+            symbol: None,
+            condition: AlertCondition::Above,
+            threshold: 1.3,
+            enabled: true,
+            triggered: false,
+        }
+    }
+}
+
+impl AlertRule {
+    pub fn description(&self) -> String {
+        let scope = match (&self.symbol, self.metric.is_per_sector()) {
+            (Some(symbol), true) => format!("{} ", symbol),
+            (None, true) => "Any sector ".to_string(),
+            _ => String::new(),
+        };
+        format!(
+            "{}{} {} {}{}",
+            scope,
+            self.metric.label().to_lowercase(),
+            self.condition.symbol(),
+            self.threshold,
+            self.metric.unit()
+        )
+    }
+}
+
+/// A fired alert, kept in the persisted history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertEvent {
+    /// Local time of the refresh that triggered it (`%Y-%m-%d %H:%M:%S`)
+    pub triggered_at: String,
+    pub rule: String,
+    /// Sector whose value met the condition, for per-sector rules
+    pub symbol: Option<String>,
+    pub value: f64,
+    pub unit: String,
+}
+
+impl AlertEvent {
+    pub fn message(&self) -> String {
+        match &self.symbol {
+            Some(symbol) => format!("{}: {} is {:.2}{}", self.rule, symbol, self.value, self.unit),
+            None => format!("{}: now {:.2}{}", self.rule, self.value, self.unit),
+        }
+    }
+}
+
+/// Latest analysis values alert rules are checked against
+pub struct AlertInputs<'a> {
+    pub volatility: &'a [VolatilityMetrics],
+    pub avg_correlation: Option<f64>,
+    pub bond_spreads: &'a [BondSpread],
+    pub predictions: &'a NnPredictions,
+}
+
+impl AlertInputs<'_> {
+    /// Current values of `metric` in its display unit, labelled with the sector for
+    /// per-sector metrics. Empty when the data isn't loaded.
+    fn values(&self, metric: AlertMetric, symbol: Option<&str>) -> Vec<(Option<String>, f64)> {
+        let per_sector = |series: fn(&VolatilityMetrics) -> &[f64], scale: f64| {
+            self.volatility
+                .iter()
+                .filter(|v| symbol.is_none_or(|s| s == v.symbol))
+                .filter_map(|v| Some((Some(v.symbol.clone()), series(v).last()? * scale)))
+                .collect()
+        };
+        let single = |value: Option<f64>| value.filter(|v| v.is_finite()).map(|v| (None, v)).into_iter().collect();
+        match metric {
+            AlertMetric::VolRatio => per_sector(|v| &v.vol_ratio, 1.0),
+            AlertMetric::ShortVol => per_sector(|v| &v.short_window_vol, 100.0),
+            AlertMetric::AvgCorrelation => single(self.avg_correlation),
+            AlertMetric::TermSpread => {
+                single(self.bond_spreads.iter().max_by_key(|s| s.date).map(|s| s.spread_10y_2y))
+            }
+            AlertMetric::PredictedVol => single(self.predictions.vol.first().map(|(_, v)| v * 100.0)),
+        }
+    }
+}
+
+/// Check every enabled rule against `inputs`, updating each rule's `triggered` state.
+/// Returns an event for each rule that newly triggered; rules already triggered at the
+/// last evaluation stay quiet until their condition clears.
+pub fn evaluate(rules: &mut [AlertRule], inputs: &AlertInputs, now: &str) -> Vec<AlertEvent> {
+    let mut fired = Vec::new();
+    for rule in rules.iter_mut().filter(|r| r.enabled) {
+        let symbol = rule.symbol.as_deref().filter(|_| rule.metric.is_per_sector());
+        let hit = inputs
+            .values(rule.metric, symbol)
+            .into_iter()
+            .filter(|(_, v)| rule.condition.is_met(*v, rule.threshold))
+            .max_by(|a, b| match rule.condition {
+                AlertCondition::Above => a.1.total_cmp(&b.1),
+                AlertCondition::Below => b.1.total_cmp(&a.1),
+            });
+        if let (Some((symbol, value)), false) = (&hit, rule.triggered) {
+            fired.push(AlertEvent {
+                triggered_at: now.to_string(),
+                rule: rule.description(),
+                symbol: symbol.clone(),
+                value: *value,
+                unit: rule.metric.unit().to_string(),
+            });
+        }
+        rule.triggered = hit.is_some();
+    }
+    fired
+}
+
+/// Append `events` to `history`, trimming it to `MAX_HISTORY`
+pub fn record(history: &mut Vec<AlertEvent>, events: &[AlertEvent]) {
+    history.extend_from_slice(events);
+    if history.len() > MAX_HISTORY {
+        let excess = history.len() - MAX_HISTORY;
+        history.drain(..excess);
+    }
+}
+
+/// Load alert rules from the cache. Empty if none have been saved.
+#[cfg(feature = "native")]
+pub fn load_rules() -> Vec<AlertRule> {
+    cache::load_json(RULES_FILENAME).unwrap_or_default()
+}
+
+#[cfg(feature = "native")]
+pub fn save_rules(rules: &[AlertRule]) -> Result<(), String> {
+    cache::save_json(RULES_FILENAME, &rules).map_err(|e| format!("Failed to save alert rules: {}", e))
+}
+
+/// Load fired alerts from the cache (oldest first). Empty if none have been saved.
+#[cfg(feature = "native")]
+pub fn load_history() -> Vec<AlertEvent> {
+    cache::load_json(HISTORY_FILENAME).unwrap_or_default()
+}
+
+#[cfg(feature = "native")]
+pub fn save_history(history: &[AlertEvent]) -> Result<(), String> {
+    cache::save_json(HISTORY_FILENAME, &history).map_err(|e| format!("Failed to save alert history: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn vol(symbol: &str, ratio: f64) -> VolatilityMetrics {
+        VolatilityMetrics {
+            symbol: symbol.to_string(),
+            dates: vec![],
+            short_window_vol: vec![0.2],
+            long_window_vol: vec![0.2 / ratio],
+            parkinson_vol: vec![],
+            vol_ratio: vec![ratio],
+        }
+    }
+
+    fn rule(metric: AlertMetric, condition: AlertCondition, threshold: f64) -> AlertRule {
+        AlertRule { metric, condition, threshold, ..AlertRule::default() }
+    }
+
+    #[test]
+    fn test_rules_fire_once_per_episode() {
+        let preds = NnPredictions::default();
+        let mut rules = vec![rule(AlertMetric::VolRatio, AlertCondition::Above, 1.3)];
+        let calm = [vol("XLK", 1.0), vol("XLE", 1.2)];
+        let stressed = [vol("XLK", 1.4), vol("XLE", 1.6)];
+        let inputs = |v| AlertInputs { volatility: v, avg_correlation: None, bond_spreads: &[], predictions: &preds };
+
+        assert!(evaluate(&mut rules, &inputs(&calm), "t0").is_empty());
+        let fired = evaluate(&mut rules, &inputs(&stressed), "t1");
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].symbol.as_deref(), Some("XLE"));
+        assert!(evaluate(&mut rules, &inputs(&stressed), "t2").is_empty());
+        assert!(evaluate(&mut rules, &inputs(&calm), "t3").is_empty());
+        assert_eq!(evaluate(&mut rules, &inputs(&stressed), "t4").len(), 1);
+
+        rules[0].symbol = Some("XLK".to_string());
+        rules[0].triggered = false;
+        assert_eq!(evaluate(&mut rules, &inputs(&stressed), "t5")[0].symbol.as_deref(), Some("XLK"));
+    }
+
+    #[test]
+    fn test_curve_inversion_uses_latest_spread() {
+        let preds = NnPredictions::default();
+        let day = |d: u32| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        let spreads = vec![
+            BondSpread { date: day(2), spread_10y_2y: -0.1, curve_slope: 0.0 },
+            BondSpread { date: day(1), spread_10y_2y: 0.3, curve_slope: 0.0 },
+        ];
+        let inputs =
+            AlertInputs { volatility: &[], avg_correlation: Some(0.5), bond_spreads: &spreads, predictions: &preds };
+        let mut rules = vec![
+            rule(AlertMetric::TermSpread, AlertCondition::Below, 0.0),
+            rule(AlertMetric::AvgCorrelation, AlertCondition::Above, 0.8),
+            rule(AlertMetric::PredictedVol, AlertCondition::Above, 30.0),
+        ];
+        let fired = evaluate(&mut rules, &inputs, "t0");
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].value, -0.1);
+        assert!(rules[0].triggered && !rules[1].triggered && !rules[2].triggered);
+    }
+
+    #[test]
+    fn test_record_trims_history() {
+        let event = AlertEvent {
+            triggered_at: String::new(),
+            rule: String::new(),
+            symbol: None,
+            value: 0.0,
+            unit: String::new(),
+        };
+        let mut history = vec![event.clone(); MAX_HISTORY];
+        record(&mut history, &[AlertEvent { value: 1.0, ..event }]);
+        assert_eq!(history.len(), MAX_HISTORY);
+        assert_eq!(history.last().unwrap().value, 1.0);
+    }
+}
@@ -1,7 +1,12 @@
 use chrono::NaiveDate;
 
+use crate::analysis::kurtosis::normal_cdf;
 use crate::data::models::{BondSpread, TreasuryRate};
 
+/// NY Fed yield-curve recession model (Estrella & Trubin): probit intercept and
+/// coefficient on the 10Y-3M spread in percentage points
+const RECESSION_PROBIT: (f64, f64) = (-0.5333, -0.6330);
+
 /// Compute term spread (10Y - 2Y) and curve slope (30Y - 3M) from treasury rate data
 pub fn compute_term_spreads(rates: &[TreasuryRate]) -> Vec<BondSpread> {
     rates
@@ -39,6 +44,43 @@ pub fn detect_inversions(rates: &[TreasuryRate]) -> Vec<NaiveDate> {
         .collect()
 }
 
+/// Contiguous runs of an inverted 10Y-2Y spread as inclusive (first, last) dates.
+/// `spreads` must be sorted by date.
+pub fn inversion_periods(spreads: &[BondSpread]) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut periods: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+    let mut prev_inverted = false;
+    for s in spreads {
+        let inverted = s.spread_10y_2y < 0.0;
+        if inverted {
+            match periods.last_mut() {
+                Some((_, end)) if prev_inverted => *end = s.date,
+                _ => periods.push((s.date, s.date)),
+            }
+        }
+        prev_inverted = inverted;
+    }
+    periods
+}
+
+/// Probability of a US recession twelve months ahead implied by the 10Y-3M spread
+/// (percentage points), using the NY Fed probit model. The model is fitted on monthly
+/// average spreads, so daily readings are noisier than the published series.
+pub fn recession_probability(spread_10y_3m: f64) -> f64 {
+    let (alpha, beta) = RECESSION_PROBIT;
+    normal_cdf(alpha + beta * spread_10y_3m)
+}
+
+/// Recession probability per date from rates quoting both the 10Y and 3M yields
+pub fn recession_probabilities(rates: &[TreasuryRate]) -> Vec<(NaiveDate, f64)> {
+    rates
+        .iter()
+        .filter_map(|r| Some((r.parsed_date()?, recession_probability(r.year10? - r.month3?))))
+        .collect()
+}
+
+/// Every maturity label `yield_curve_for_date` can emit, shortest first
+pub const MATURITY_LABELS: [&str; 12] = ["1M", "2M", "3M", "6M", "1Y", "2Y", "3Y", "5Y", "7Y", "10Y", "20Y", "30Y"];
+
 /// Extract the yield curve for a specific date as ordered (maturity_label, rate) pairs
 pub fn yield_curve_for_date(rate: &TreasuryRate) -> Vec<(&'static str, f64)> {
     let mut curve = Vec::new();
@@ -143,6 +185,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_inversion_periods() {
+        let spread = |day: u32, s: f64| BondSpread {
+            date: NaiveDate::from_ymd_opt(2025, 1, day).unwrap(),
+            spread_10y_2y: s,
+            curve_slope: 0.0,
+        };
+        let spreads = vec![spread(1, 0.2), spread(2, -0.1), spread(3, -0.3), spread(4, 0.1), spread(5, -0.2)];
+        let date = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        assert_eq!(inversion_periods(&spreads), vec![(date(2), date(3)), (date(5), date(5))]);
+        assert!(inversion_periods(&spreads[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_recession_probability() {
+        // A flat curve maps to Φ(-0.5333) ≈ 29.7%; inversion raises it, steepening lowers it
+        assert!((recession_probability(0.0) - 0.2969).abs() < 1e-3);
+        assert!(recession_probability(-1.0) > 0.5);
+        assert!(recession_probability(2.0) < 0.05);
+        let probs = recession_probabilities(&[make_rate("2025-01-01", 3.5, 4.2, 4.8, 3.6)]);
+        assert_eq!(probs.len(), 1);
+        assert!((probs[0].1 - recession_probability(0.6)).abs() < 1e-12);
+    }
+
     #[test]
     fn test_yield_curve_for_date() {
         let rate = make_rate("2025-01-01", 3.5, 4.2, 4.8, 3.6);
@@ -1,7 +1,10 @@
+use rayon::prelude::*;
+
+use crate::analysis::rolling;
 use crate::data::models::CorrelationMatrix;
 
 /// Compute Pearson correlation between two equal-length slices
-fn pearson_correlation(a: &[f64], b: &[f64]) -> f64 {
+pub fn pearson_correlation(a: &[f64], b: &[f64]) -> f64 {
     let n = a.len().min(b.len());
     if n < 2 {
         return 0.0;
@@ -31,15 +34,15 @@ fn pearson_correlation(a: &[f64], b: &[f64]) -> f64 {
 }
 
 /// Compute pairwise Pearson correlation matrix for multiple return series
-pub fn compute_correlation_matrix(
+pub fn compute_correlation_matrix<R: AsRef<[f64]>>(
     symbols: &[String],
-    returns: &[Vec<f64>],
+    returns: &[R],
 ) -> CorrelationMatrix {
     let n = symbols.len();
     let mut matrix = vec![vec![0.0; n]; n];
 
     // Align all series to the same length (shortest)
-    let min_len = returns.iter().map(|r| r.len()).min().unwrap_or(0);
+    let min_len = returns.iter().map(|r| r.as_ref().len()).min().unwrap_or(0);
     if min_len < 2 {
         return CorrelationMatrix {
             symbols: symbols.to_vec(),
@@ -49,13 +52,17 @@ pub fn compute_correlation_matrix(
 
     let aligned: Vec<&[f64]> = returns
         .iter()
-        .map(|r| &r[r.len() - min_len..])
+        .map(|r| &r.as_ref()[r.as_ref().len() - min_len..])
         .collect();
 
-    for i in 0..n {
+    // Upper triangle in parallel, one row per task, then mirrored
+    let upper: Vec<Vec<f64>> = (0..n)
+        .into_par_iter()
+        .map(|i| ((i + 1)..n).map(|j| pearson_correlation(aligned[i], aligned[j])).collect())
+        .collect();
+    for (i, row) in upper.into_iter().enumerate() {
         matrix[i][i] = 1.0;
-        for j in (i + 1)..n {
-            let corr = pearson_correlation(aligned[i], aligned[j]);
+        for (j, corr) in ((i + 1)..n).zip(row) {
             matrix[i][j] = corr;
             matrix[j][i] = corr;
         }
@@ -78,16 +85,83 @@ pub fn rolling_correlation(
         return vec![];
     }
 
-    (0..=(n - window))
-        .map(|i| {
-            pearson_correlation(
-                &returns_a[i..i + window],
-                &returns_b[i..i + window],
-            )
+    rolling::co_moments(returns_a, returns_b, window, |c| c.correlation())
+}
+
+/// Rolling average pairwise correlation across all return series, aligned on their
+/// most recent values. Element `i` covers aligned returns `i..i + window`.
+pub fn rolling_average_correlation(returns: &[Vec<f64>], window: usize) -> Vec<f64> {
+    let min_len = returns.iter().map(|r| r.len()).min().unwrap_or(0);
+    if returns.len() < 2 || min_len < window || window < 2 {
+        return vec![];
+    }
+    let aligned: Vec<&[f64]> = returns.iter().map(|r| &r[r.len() - min_len..]).collect();
+
+    let pairs: Vec<(usize, usize)> =
+        (0..aligned.len()).flat_map(|i| ((i + 1)..aligned.len()).map(move |j| (i, j))).collect();
+    let sum = pairs
+        .par_iter()
+        .map(|&(i, j)| rolling_correlation(aligned[i], aligned[j], window))
+        .reduce(
+            || vec![0.0; min_len - window + 1],
+            |mut acc, corr| {
+                for (s, c) in acc.iter_mut().zip(corr) {
+                    *s += c;
+                }
+                acc
+            },
+        );
+    sum.iter().map(|s| s / pairs.len() as f64).collect()
+}
+
+/// Daily cross-sectional dispersion: the standard deviation of all series' returns on
+/// each day, aligned on their most recent values. Low dispersion alongside high
+/// correlation means sectors are moving as one.
+pub fn cross_sectional_dispersion(returns: &[Vec<f64>]) -> Vec<f64> {
+    let min_len = returns.iter().map(|r| r.len()).min().unwrap_or(0);
+    if returns.len() < 2 {
+        return vec![];
+    }
+    let aligned: Vec<&[f64]> = returns.iter().map(|r| &r[r.len() - min_len..]).collect();
+    let n = aligned.len() as f64;
+    (0..min_len)
+        .map(|k| {
+            let mean = aligned.iter().map(|r| r[k]).sum::<f64>() / n;
+            let var = aligned.iter().map(|r| (r[k] - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            var.sqrt()
         })
         .collect()
 }
 
+/// OLS beta of `asset` on `market` (cov / market variance) over their common trailing
+/// values. `None` with fewer than two points or a flat market series.
+pub fn beta(asset: &[f64], market: &[f64]) -> Option<f64> {
+    let n = asset.len().min(market.len());
+    if n < 2 {
+        return None;
+    }
+    let (a, m) = (&asset[asset.len() - n..], &market[market.len() - n..]);
+    let mean_a = a.iter().sum::<f64>() / n as f64;
+    let mean_m = m.iter().sum::<f64>() / n as f64;
+    let (mut cov, mut var_m) = (0.0, 0.0);
+    for (x, y) in a.iter().zip(m) {
+        cov += (x - mean_a) * (y - mean_m);
+        var_m += (y - mean_m) * (y - mean_m);
+    }
+    (var_m > 1e-15).then(|| cov / var_m)
+}
+
+/// Rolling beta of `asset` on `market` over their common trailing values. Element `i`
+/// covers aligned returns `i..i + window`; flat windows yield NaN.
+pub fn rolling_beta(asset: &[f64], market: &[f64], window: usize) -> Vec<f64> {
+    let n = asset.len().min(market.len());
+    if n < window || window < 2 {
+        return vec![];
+    }
+    let (a, m) = (&asset[asset.len() - n..], &market[market.len() - n..]);
+    rolling::co_moments(a, m, window, |c| c.beta().unwrap_or(f64::NAN))
+}
+
 /// Compute average cross-sector correlation from a correlation matrix
 pub fn average_cross_correlation(matrix: &CorrelationMatrix) -> f64 {
     let n = matrix.symbols.len();
@@ -105,6 +179,55 @@ pub fn average_cross_correlation(matrix: &CorrelationMatrix) -> f64 {
     if count == 0 { 0.0 } else { sum / count as f64 }
 }
 
+/// Element-wise `current - earlier` for two matrices over the same symbols; positive
+/// cells are pairs that have become more correlated
+pub fn correlation_change(current: &CorrelationMatrix, earlier: &CorrelationMatrix) -> CorrelationMatrix {
+    let matrix = current
+        .matrix
+        .iter()
+        .zip(&earlier.matrix)
+        .map(|(now, then)| now.iter().zip(then).map(|(a, b)| a - b).collect())
+        .collect();
+    CorrelationMatrix {
+        symbols: current.symbols.clone(),
+        matrix,
+    }
+}
+
+/// Minimum spanning tree of a correlation matrix under the Mantegna distance
+/// `sqrt(2 (1 - rho))`, as `(i, j, correlation)` edges in the order Prim's algorithm
+/// adds them. Keeps the strongest link holding each sector to the rest of the market.
+pub fn minimum_spanning_tree(matrix: &CorrelationMatrix) -> Vec<(usize, usize, f64)> {
+    let n = matrix.symbols.len();
+    if n < 2 {
+        return vec![];
+    }
+    let dist = |i: usize, j: usize| (2.0 * (1.0 - matrix.matrix[i][j])).max(0.0).sqrt();
+
+    let mut in_tree = vec![false; n];
+    // Closest tree node and its distance for every node outside the tree
+    let mut best: Vec<(usize, f64)> = (0..n).map(|j| (0, dist(0, j))).collect();
+    in_tree[0] = true;
+    let mut edges = Vec::with_capacity(n - 1);
+    for _ in 1..n {
+        let Some(next) = (0..n)
+            .filter(|&j| !in_tree[j])
+            .min_by(|&a, &b| best[a].1.total_cmp(&best[b].1))
+        else {
+            break;
+        };
+        let from = best[next].0;
+        edges.push((from, next, matrix.matrix[from][next]));
+        in_tree[next] = true;
+        for j in 0..n {
+            if !in_tree[j] && dist(next, j) < best[j].1 {
+                best[j] = (next, dist(next, j));
+            }
+        }
+    }
+    edges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +281,50 @@ mod tests {
         assert_eq!(rc.len(), 5);
     }
 
+    #[test]
+    fn test_rolling_average_correlation() {
+        let a = vec![0.01, -0.02, 0.03, 0.01, -0.01, 0.02];
+        let b: Vec<f64> = a.iter().map(|r| r * 2.0).collect();
+        let c: Vec<f64> = a.iter().map(|r| -r).collect();
+        // Pairs: (a, b) = 1, (a, c) = -1, (b, c) = -1
+        let avg = rolling_average_correlation(&[a.clone(), b, c], 3);
+        assert_eq!(avg.len(), 4);
+        assert!(avg.iter().all(|v| (v + 1.0 / 3.0).abs() < 1e-10));
+        assert!(rolling_average_correlation(&[a], 3).is_empty());
+    }
+
+    #[test]
+    fn test_cross_sectional_dispersion() {
+        // Day 0: identical returns; day 1: 0.01 and -0.01 around a zero mean
+        let a = vec![0.5, 0.02, 0.01];
+        let b = vec![0.02, -0.01];
+        let d = cross_sectional_dispersion(&[a, b]);
+        assert_eq!(d.len(), 2);
+        assert!(d[0].abs() < 1e-12);
+        assert!((d[1] - 0.0002_f64.sqrt()).abs() < 1e-12);
+        assert!(cross_sectional_dispersion(&[vec![0.01, 0.02]]).is_empty());
+    }
+
+    #[test]
+    fn test_beta() {
+        let market = vec![0.01, -0.02, 0.03, 0.01, -0.01];
+        let asset: Vec<f64> = market.iter().map(|r| 1.5 * r + 0.001).collect();
+        assert!((beta(&asset, &market).unwrap() - 1.5).abs() < 1e-10);
+        assert_eq!(beta(&asset, &[0.01; 5]), None);
+        assert_eq!(beta(&[0.01], &[0.02]), None);
+    }
+
+    #[test]
+    fn test_rolling_beta_aligns_trailing_values() {
+        let market = vec![0.01, -0.02, 0.03, 0.01, -0.01, 0.02];
+        // One extra leading value on the asset is ignored
+        let asset: Vec<f64> = std::iter::once(0.5).chain(market.iter().map(|r| -r)).collect();
+        let rb = rolling_beta(&asset, &market, 3);
+        assert_eq!(rb.len(), 4);
+        assert!(rb.iter().all(|b| (b + 1.0).abs() < 1e-10));
+        assert!(rolling_beta(&asset, &market, 10).is_empty());
+    }
+
     #[test]
     fn test_average_cross_correlation() {
         let cm = CorrelationMatrix {
@@ -172,4 +339,30 @@ mod tests {
         let expected = (0.8 + 0.6 + 0.7) / 3.0;
         assert!((avg - expected).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_minimum_spanning_tree_keeps_strongest_links() {
+        let cm = CorrelationMatrix {
+            symbols: vec!["A".to_string(), "B".to_string(), "C".to_string(), "D".to_string()],
+            matrix: vec![
+                vec![1.0, 0.9, 0.2, 0.1],
+                vec![0.9, 1.0, 0.8, 0.3],
+                vec![0.2, 0.8, 1.0, 0.7],
+                vec![0.1, 0.3, 0.7, 1.0],
+            ],
+        };
+        let mst = minimum_spanning_tree(&cm);
+        assert_eq!(mst, vec![(0, 1, 0.9), (1, 2, 0.8), (2, 3, 0.7)]);
+        assert!(minimum_spanning_tree(&CorrelationMatrix { symbols: vec!["A".to_string()], matrix: vec![vec![1.0]] }).is_empty());
+    }
+
+    #[test]
+    fn test_correlation_change() {
+        let symbols = vec!["A".to_string(), "B".to_string()];
+        let earlier = CorrelationMatrix { symbols: symbols.clone(), matrix: vec![vec![1.0, 0.2], vec![0.2, 1.0]] };
+        let current = CorrelationMatrix { symbols, matrix: vec![vec![1.0, 0.7], vec![0.7, 1.0]] };
+        let delta = correlation_change(&current, &earlier);
+        assert!((delta.matrix[0][1] - 0.5).abs() < 1e-12);
+        assert_eq!(delta.matrix[0][0], 0.0);
+    }
 }
@@ -0,0 +1,182 @@
+//! User-defined indicators written as Rhai scripts. A script sees one sector's series
+//! as arrays (oldest first) and returns an array of numbers, drawn as an extra chart
+//! in Sector Vol and optionally fed to the NN as a per-sector input feature.
+//!
+//! Script inputs: `open`, `high`, `low`, `close`, `volume` (one value per bar),
+//! `returns` (daily log returns), `vol_short`, `vol_long` (annualized close-to-close
+//! vol) and `parkinson`. Helpers: `sma(a, n)`, `ema(a, n)`, `stdev(a, n)`. The result
+//! is aligned to the latest bar, so it may be shorter than the bars.
+
+use rhai::{Array, Dynamic, Engine, Scope};
+
+use crate::analysis::volatility;
+#[cfg(feature = "native")]
+use crate::data::cache;
+use crate::data::models::{CustomIndicator, SectorTimeSeries};
+
+/// Cache file the indicator definitions are persisted to
+pub const CUSTOM_INDICATORS_FILENAME: &str = "custom_indicators.json";
+
+/// Operations one evaluation may run before it is stopped, so a runaway loop can't
+/// hang the UI
+const MAX_OPERATIONS: u64 = 20_000_000;
+
+/// One indicator evaluated for one sector, aligned to the sector's latest bar
+#[derive(Debug, Clone)]
+pub struct IndicatorSeries {
+    pub name: String,
+    pub symbol: String,
+    pub values: Vec<f64>,
+}
+
+#[cfg(feature = "native")]
+pub fn load() -> Vec<CustomIndicator> {
+    cache::load_json(CUSTOM_INDICATORS_FILENAME).unwrap_or_default()
+}
+
+#[cfg(feature = "native")]
+pub fn save(indicators: &[CustomIndicator]) -> Result<(), String> {
+    cache::save_json(CUSTOM_INDICATORS_FILENAME, &indicators).map_err(|e| format!("Failed to save custom indicators: {}", e))
+}
+
+/// Run `script` on one sector with the given vol windows
+pub fn evaluate(script: &str, sector: &SectorTimeSeries, short_window: usize, long_window: usize) -> Result<Vec<f64>, String> {
+    let returns = sector.log_returns();
+    let highs = sector.highs();
+    let lows = sector.lows();
+
+    let mut scope = Scope::new();
+    scope.push("open", to_array(sector.bars.iter().map(|b| b.open)));
+    scope.push("high", to_array(highs.iter().copied()));
+    scope.push("low", to_array(lows.iter().copied()));
+    scope.push("close", to_array(sector.bars.iter().map(|b| b.close)));
+    scope.push("volume", to_array(sector.bars.iter().map(|b| b.volume as f64)));
+    scope.push("vol_short", to_array(volatility::rolling_volatility(&returns, short_window)));
+    scope.push("vol_long", to_array(volatility::rolling_volatility(&returns, long_window)));
+    scope.push("parkinson", to_array(volatility::parkinson_volatility(&highs, &lows, short_window)));
+    scope.push("returns", to_array(returns));
+
+    let result: Dynamic = engine().eval_with_scope(&mut scope, script).map_err(|e| e.to_string())?;
+    let array = result
+        .try_cast::<Array>()
+        .ok_or("The script must end with an array of numbers")?;
+    let values = array
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| number(&v).ok_or_else(|| format!("Element {} of the result is not a number", i)))
+        .collect::<Result<Vec<f64>, String>>()?;
+    if values.len() > sector.bars.len() {
+        return Err(format!("The result has {} values but there are only {} bars", values.len(), sector.bars.len()));
+    }
+    Ok(values)
+}
+
+/// Evaluate every enabled indicator for every sector. Failures are returned as
+/// `(indicator, error)`, reported once per indicator.
+pub fn evaluate_all(
+    indicators: &[CustomIndicator],
+    sectors: &[SectorTimeSeries],
+    short_window: usize,
+    long_window: usize,
+) -> (Vec<IndicatorSeries>, Vec<(String, String)>) {
+    let mut series = Vec::new();
+    let mut errors = Vec::new();
+    for indicator in indicators.iter().filter(|i| i.enabled) {
+        for sector in sectors {
+            match evaluate(&indicator.script, sector, short_window, long_window) {
+                Ok(values) => series.push(IndicatorSeries {
+                    name: indicator.name.clone(),
+                    symbol: sector.symbol.clone(),
+                    values,
+                }),
+                Err(e) => {
+                    errors.push((indicator.name.clone(), format!("{}: {}", sector.symbol, e)));
+                    break;
+                }
+            }
+        }
+    }
+    (series, errors)
+}
+
+fn engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.register_fn("sma", |a: Array, n: i64| rolling(&a, n, |w| w.iter().sum::<f64>() / w.len() as f64));
+    engine.register_fn("stdev", |a: Array, n: i64| {
+        rolling(&a, n, |w| {
+            let mean = w.iter().sum::<f64>() / w.len() as f64;
+            (w.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (w.len() - 1).max(1) as f64).sqrt()
+        })
+    });
+    engine.register_fn("ema", |a: Array, n: i64| {
+        let alpha = 2.0 / (n.max(1) as f64 + 1.0);
+        let mut ema = None;
+        to_array(values(&a).into_iter().map(|v| {
+            let next = ema.map_or(v, |prev: f64| prev + alpha * (v - prev));
+            ema = Some(next);
+            next
+        }))
+    });
+    engine
+}
+
+/// Trailing `n`-value window statistic; the result starts at the first full window
+fn rolling(a: &Array, n: i64, stat: impl Fn(&[f64]) -> f64) -> Array {
+    let n = n.max(1) as usize;
+    to_array(values(a).windows(n).map(stat))
+}
+
+fn values(a: &Array) -> Vec<f64> {
+    a.iter().filter_map(number).collect()
+}
+
+fn number(v: &Dynamic) -> Option<f64> {
+    v.as_float().ok().or_else(|| v.as_int().ok().map(|i| i as f64))
+}
+
+fn to_array(values: impl IntoIterator<Item = f64>) -> Array {
+    values.into_iter().map(Dynamic::from_float).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::OhlcvBar;
+    use chrono::NaiveDate;
+
+    fn sector(closes: &[f64]) -> SectorTimeSeries {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        SectorTimeSeries {
+            symbol: "XLK".to_string(),
+            name: "Technology".to_string(),
+            bars: closes
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| OhlcvBar {
+                    date: start + chrono::Duration::days(i as i64),
+                    open: c,
+                    high: c * 1.01,
+                    low: c * 0.99,
+                    close: c,
+                    volume: 1000,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_helpers() {
+        let s = sector(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(evaluate("sma(close, 2)", &s, 2, 3).unwrap(), vec![1.5, 2.5, 3.5, 4.5]);
+        assert_eq!(evaluate("[1, 2.5]", &s, 2, 3).unwrap(), vec![1.0, 2.5]);
+        assert_eq!(evaluate("returns.len()", &s, 2, 3).unwrap_err(), "The script must end with an array of numbers");
+    }
+
+    #[test]
+    fn test_evaluate_stops_runaway_scripts() {
+        let s = sector(&[1.0, 2.0]);
+        assert!(evaluate("loop { }", &s, 2, 3).is_err());
+        assert!(evaluate("let x = ;", &s, 2, 3).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+/// Drawdown of each close from its running peak, as a fraction: 0 at a new high,
+/// negative below it (-0.2 = 20% under the peak).
+pub fn drawdown_series(closes: &[f64]) -> Vec<f64> {
+    let mut peak = f64::NEG_INFINITY;
+    closes
+        .iter()
+        .map(|&c| {
+            peak = peak.max(c);
+            if peak > 0.0 { c / peak - 1.0 } else { 0.0 }
+        })
+        .collect()
+}
+
+/// Deepest peak-to-trough decline of a price series
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxDrawdown {
+    /// Fractional decline (negative)
+    pub depth: f64,
+    pub peak_idx: usize,
+    pub trough_idx: usize,
+    /// First index back at or above the peak, if the series has recovered
+    pub recovery_idx: Option<usize>,
+}
+
+/// Maximum drawdown of `closes`, or `None` if the series never falls below a prior peak.
+pub fn max_drawdown(closes: &[f64]) -> Option<MaxDrawdown> {
+    let drawdowns = drawdown_series(closes);
+    let (trough_idx, &depth) = drawdowns
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))?;
+    if depth >= 0.0 {
+        return None;
+    }
+    let peak_idx = drawdowns[..trough_idx].iter().rposition(|&d| d >= 0.0).unwrap_or(0);
+    let recovery_idx = drawdowns[trough_idx..]
+        .iter()
+        .position(|&d| d >= 0.0)
+        .map(|i| trough_idx + i);
+    Some(MaxDrawdown { depth, peak_idx, trough_idx, recovery_idx })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drawdown_series() {
+        let dd = drawdown_series(&[100.0, 110.0, 99.0, 121.0]);
+        assert_eq!(dd[0], 0.0);
+        assert_eq!(dd[1], 0.0);
+        assert!((dd[2] + 0.1).abs() < 1e-12);
+        assert_eq!(dd[3], 0.0);
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        let closes = [100.0, 120.0, 90.0, 110.0, 60.0, 80.0, 125.0, 100.0];
+        let mdd = max_drawdown(&closes).unwrap();
+        assert!((mdd.depth + 0.5).abs() < 1e-12);
+        assert_eq!((mdd.peak_idx, mdd.trough_idx, mdd.recovery_idx), (1, 4, Some(6)));
+
+        let unrecovered = max_drawdown(&[100.0, 80.0, 90.0]).unwrap();
+        assert_eq!(unrecovered.recovery_idx, None);
+        assert!(max_drawdown(&[1.0, 2.0, 3.0]).is_none());
+    }
+}
@@ -1,4 +1,5 @@
 use chrono::NaiveDate;
+use crate::analysis::rolling;
 use crate::data::models::{KurtosisAccelMetrics, KurtosisMetrics};
 
 /// Compute the mean of a slice
@@ -65,9 +66,7 @@ pub fn rolling_kurtosis(data: &[f64], window: usize) -> Vec<f64> {
     if data.len() < window || window < 4 {
         return vec![];
     }
-    (0..=(data.len() - window))
-        .map(|i| excess_kurtosis(&data[i..i + window]))
-        .collect()
+    rolling::power_sums(data, window, |p| p.excess_kurtosis())
 }
 
 /// Compute rolling skewness over a given window
@@ -75,9 +74,7 @@ pub fn rolling_skewness(data: &[f64], window: usize) -> Vec<f64> {
     if data.len() < window || window < 3 {
         return vec![];
     }
-    (0..=(data.len() - window))
-        .map(|i| skewness(&data[i..i + window]))
-        .collect()
+    rolling::power_sums(data, window, |p| p.skewness())
 }
 
 /// First differences of a rolling kurtosis series (trend/velocity)
@@ -182,6 +179,105 @@ pub fn normal_pdf_curve(data: &[f64], n_points: usize) -> Vec<[f64; 2]> {
         .collect()
 }
 
+/// Histogram of `data` over `bins` equal-width bins spanning its range.
+/// Returns `[bin center, density]` per bin, normalized so the bars integrate to one
+/// and can be overlaid on a PDF.
+pub fn histogram(data: &[f64], bins: usize) -> Vec<[f64; 2]> {
+    let finite: Vec<f64> = data.iter().copied().filter(|x| x.is_finite()).collect();
+    if finite.is_empty() || bins == 0 {
+        return vec![];
+    }
+    let lo = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = (hi - lo) / bins as f64;
+    if width <= 0.0 {
+        return vec![];
+    }
+
+    let mut counts = vec![0usize; bins];
+    for x in &finite {
+        let idx = (((x - lo) / width) as usize).min(bins - 1);
+        counts[idx] += 1;
+    }
+    let n = finite.len() as f64;
+    counts
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| [lo + (i as f64 + 0.5) * width, c as f64 / (n * width)])
+        .collect()
+}
+
+/// Inverse standard normal CDF (Acklam's rational approximation, relative error < 1.2e-9)
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01, 2.209460984245205e+02, -2.759285104469687e+02,
+        1.38357751867269e+02, -3.066479806614716e+01, 2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01, 1.615858368580409e+02, -1.556989798598866e+02,
+        6.680131188771972e+01, -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03, -3.223964580411365e-01, -2.400758277161838e+00,
+        -2.549732539343734e+00, 4.374664141464968e+00, 2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03, 3.224671290700398e-01, 2.445134137142996e+00, 3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Standard normal CDF (Abramowitz & Stegun 7.1.26 erf approximation, abs error < 1.5e-7)
+pub fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.3275911 * z);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+    if x >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
+
+/// QQ plot data against the normal distribution: `[theoretical quantile, sample
+/// quantile]` per observation, with the sample standardized so a normal sample lies
+/// on the line y = x. Points above the line in the right tail (below it in the left)
+/// indicate fatter-than-normal tails.
+pub fn qq_points(data: &[f64]) -> Vec<[f64; 2]> {
+    let m = mean(data);
+    let s = std_dev(data);
+    if s <= 0.0 {
+        return vec![];
+    }
+    let mut sorted: Vec<f64> = data.iter().map(|x| (x - m) / s).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let n = sorted.len() as f64;
+    sorted
+        .iter()
+        .enumerate()
+        // Blom plotting positions
+        .map(|(i, &z)| [normal_quantile((i as f64 + 1.0 - 0.375) / (n + 0.25)), z])
+        .collect()
+}
+
 /// Compute full kurtosis metrics for a sector
 pub fn compute_sector_kurtosis(
     symbol: &str,
@@ -253,6 +349,43 @@ mod tests {
         assert!(s.abs() < 0.01, "Symmetric data should have ~0 skewness, got {}", s);
     }
 
+    #[test]
+    fn test_normal_quantile() {
+        assert!((normal_quantile(0.5)).abs() < 1e-9);
+        assert!((normal_quantile(0.975) - 1.959964).abs() < 1e-5);
+        assert!((normal_quantile(0.001) + 3.090232).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_normal_cdf_inverts_quantile() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-9);
+        for p in [0.001, 0.05, 0.3, 0.8, 0.975] {
+            assert!((normal_cdf(normal_quantile(p)) - p).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_qq_points_of_normal_quantiles_lie_on_diagonal() {
+        let n = 500;
+        let data: Vec<f64> = (1..=n).map(|i| normal_quantile(i as f64 / (n + 1) as f64)).collect();
+        let qq = qq_points(&data);
+        assert_eq!(qq.len(), n);
+        assert!(qq.windows(2).all(|w| w[0][0] < w[1][0] && w[0][1] <= w[1][1]));
+        let mid = &qq[n / 2];
+        assert!((mid[0] - mid[1]).abs() < 0.05, "center should sit on y = x: {:?}", mid);
+    }
+
+    #[test]
+    fn test_histogram_integrates_to_one() {
+        let data: Vec<f64> = (0..1000).map(|i| ((i as f64) * 0.37).sin()).collect();
+        let hist = histogram(&data, 25);
+        assert_eq!(hist.len(), 25);
+        let width = hist[1][0] - hist[0][0];
+        let area: f64 = hist.iter().map(|b| b[1] * width).sum();
+        assert!((area - 1.0).abs() < 1e-9, "Histogram area should be 1, got {}", area);
+        assert!(histogram(&[1.0, 1.0], 10).is_empty());
+    }
+
     #[test]
     fn test_kde_integrates_to_one() {
         let data: Vec<f64> = (0..100).map(|i| i as f64 / 100.0).collect();
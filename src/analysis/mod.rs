@@ -1,5 +1,228 @@
+//! Market analysis computed from the fetched data. `compute_all` runs the built-in
+//! metrics on rayon's thread pool: sectors are independent, and so are the pairs of
+//! the correlation matrix.
+
+pub mod alerts;
 pub mod bond_spreads;
 pub mod cross_sector;
+pub mod custom_indicators;
+pub mod drawdown;
 pub mod kurtosis;
+pub mod plugin;
 pub mod randomness;
+pub mod regimes;
+pub mod rolling;
+pub mod tail_risk;
 pub mod volatility;
+
+use rayon::prelude::*;
+
+use crate::config;
+use crate::data::models::{
+    AnalysisWindows, BondSpread, CorrelationMatrix, KurtosisMetrics, MarketData, SectorTimeSeries, VolatilityMetrics,
+};
+use custom_indicators::IndicatorSeries;
+use plugin::PluginResult;
+use randomness::SectorRandomness;
+use tail_risk::SectorRisk;
+
+/// Minimum returns for the randomness tests to be meaningful
+const MIN_RANDOMNESS_RETURNS: usize = 20;
+
+/// Computed analysis results (derived from MarketData)
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisResults {
+    pub volatility: Vec<VolatilityMetrics>,
+    pub correlation: Option<CorrelationMatrix>,
+    pub bond_spreads: Vec<BondSpread>,
+    pub avg_cross_correlation: f64,
+    /// Rolling (`ROLLING_CORR_WINDOW`) average cross-sector correlation, oldest first
+    pub rolling_avg_correlation: Vec<f64>,
+    /// Daily cross-sectional dispersion of sector returns, oldest first
+    pub dispersion: Vec<f64>,
+    /// Rolling (`ROLLING_BETA_WINDOW`) beta of each sector vs the benchmark, oldest
+    /// first and aligned to the sector's latest bar; empty without benchmark data
+    pub rolling_beta: Vec<(String, Vec<f64>)>,
+    pub kurtosis: Vec<KurtosisMetrics>,
+    pub randomness: Vec<SectorRandomness>,
+    pub risk: Vec<SectorRisk>,
+    /// Enabled custom indicators evaluated for each sector
+    pub custom: Vec<IndicatorSeries>,
+    /// Custom indicators whose script failed, with the error
+    pub custom_errors: Vec<(String, String)>,
+    /// Output of each registered analysis plugin
+    pub plugins: Vec<PluginResult>,
+}
+
+/// Every built-in metric for `data`. Custom indicators and plugins are left empty:
+/// they depend on user scripts and the plugin registry, which the caller owns.
+pub fn compute_all(data: &MarketData, windows: AnalysisWindows, kurtosis_window: usize) -> AnalysisResults {
+    let sectors = &data.sectors;
+    let returns: Vec<Vec<f64>> = sectors.par_iter().map(|s| s.log_returns()).collect();
+
+    let symbols: Vec<String> = sectors.iter().map(|s| s.symbol.clone()).collect();
+    let correlation = cross_sector::compute_correlation_matrix(&symbols, &returns);
+    let rolling_avg_correlation = cross_sector::rolling_average_correlation(&returns, config::ROLLING_CORR_WINDOW);
+    let (per_sector, kurtosis) = rayon::join(
+        || per_sector_metrics(sectors, &returns, windows),
+        || compute_kurtosis(sectors, kurtosis_window),
+    );
+
+    let mut volatility = Vec::new();
+    let mut randomness = Vec::new();
+    let mut risk = Vec::new();
+    for (vol, rand, sector_risk) in per_sector {
+        volatility.extend(vol);
+        randomness.extend(rand);
+        risk.push(sector_risk);
+    }
+
+    AnalysisResults {
+        volatility,
+        avg_cross_correlation: cross_sector::average_cross_correlation(&correlation),
+        correlation: Some(correlation),
+        bond_spreads: bond_spreads::compute_term_spreads(&data.treasury_rates),
+        rolling_avg_correlation,
+        dispersion: cross_sector::cross_sectional_dispersion(&returns),
+        rolling_beta: rolling_betas(data, &returns),
+        kurtosis,
+        randomness,
+        risk,
+        ..Default::default()
+    }
+}
+
+/// Kurtosis of every sector with at least `window` returns
+pub fn compute_kurtosis(sectors: &[SectorTimeSeries], window: usize) -> Vec<KurtosisMetrics> {
+    sectors
+        .par_iter()
+        .filter_map(|sector| {
+            let log_ret = sector.log_returns();
+            if log_ret.len() < window {
+                return None;
+            }
+            let dates = sector.dates();
+            // Use dates offset by 1 to align with log returns
+            let ret_dates = if dates.len() > 1 { &dates[1..] } else { &dates };
+            Some(kurtosis::compute_sector_kurtosis(&sector.symbol, ret_dates, &log_ret, window))
+        })
+        .collect()
+}
+
+/// Volatility, randomness and tail risk of each sector, in sector order
+fn per_sector_metrics(
+    sectors: &[SectorTimeSeries],
+    returns: &[Vec<f64>],
+    windows: AnalysisWindows,
+) -> Vec<(Option<VolatilityMetrics>, Option<SectorRandomness>, SectorRisk)> {
+    sectors
+        .par_iter()
+        .zip(returns)
+        .map(|(sector, log_ret)| sector_metrics(sector, log_ret, windows))
+        .collect()
+}
+
+/// Volatility, randomness and tail risk of one sector, which need no other sector's
+/// data. Volatility and randomness are skipped for sectors with too little history.
+pub fn sector_metrics(
+    sector: &SectorTimeSeries,
+    log_ret: &[f64],
+    windows: AnalysisWindows,
+) -> (Option<VolatilityMetrics>, Option<SectorRandomness>, SectorRisk) {
+    let vol = (sector.bars.len() >= windows.long_vol + 2).then(|| {
+        volatility::compute_sector_volatility(
+            &sector.symbol,
+            &sector.dates(),
+            log_ret,
+            &sector.highs(),
+            &sector.lows(),
+            windows.short_vol,
+            windows.long_vol,
+        )
+    });
+    let rand = (log_ret.len() >= MIN_RANDOMNESS_RETURNS)
+        .then(|| randomness::compute_sector_randomness(&sector.symbol, log_ret));
+    let short_vol = vol.as_ref().map_or(&[][..], |v| v.short_window_vol.as_slice());
+    let risk = tail_risk::compute_sector_risk(
+        &sector.symbol,
+        &sector.close_prices(),
+        log_ret,
+        short_vol,
+        config::VAR_CONFIDENCE,
+        config::TAIL_INDEX_FRACTION,
+    );
+    (vol, rand, risk)
+}
+
+/// Rolling beta of each sector against the benchmark; empty without benchmark data
+fn rolling_betas(data: &MarketData, returns: &[Vec<f64>]) -> Vec<(String, Vec<f64>)> {
+    let Some(bench) = &data.benchmark else {
+        return Vec::new();
+    };
+    let bench_returns = bench.log_returns();
+    data.sectors
+        .par_iter()
+        .zip(returns)
+        .map(|(sector, r)| {
+            let beta = cross_sector::rolling_beta(r, &bench_returns, config::ROLLING_BETA_WINDOW);
+            (sector.symbol.clone(), beta)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::OhlcvBar;
+    use chrono::NaiveDate;
+    use rand::{Rng, SeedableRng};
+
+    /// `n` symbols of random-walk daily bars, seeded so runs are comparable
+    fn market(n: usize, days: usize) -> MarketData {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let start = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+        let mut walk = |symbol: String| {
+            let mut close = 100.0;
+            let bars = (0..days)
+                .map(|i| {
+                    close *= 1.0 + rng.gen_range(-0.02..0.02);
+                    OhlcvBar {
+                        date: start + chrono::Duration::days(i as i64),
+                        open: close,
+                        high: close * 1.01,
+                        low: close * 0.99,
+                        close,
+                        volume: 1_000,
+                    }
+                })
+                .collect();
+            SectorTimeSeries { name: symbol.clone(), symbol, bars }
+        };
+        let sectors = (0..n).map(|i| walk(format!("XL{}", i))).collect();
+        MarketData { sectors, benchmark: Some(walk("SPY".to_string())), ..Default::default() }
+    }
+
+    fn single_threaded<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+        rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap().install(f)
+    }
+
+    #[test]
+    fn test_compute_all_matches_single_threaded() {
+        let data = market(6, 300);
+        let windows = AnalysisWindows::default();
+        let parallel = compute_all(&data, windows, 60);
+        let serial = single_threaded(|| compute_all(&data, windows, 60));
+
+        assert_eq!(parallel.volatility.len(), 6);
+        let symbols = |r: &AnalysisResults| r.risk.iter().map(|r| r.symbol.clone()).collect::<Vec<_>>();
+        assert_eq!(symbols(&parallel), symbols(&serial));
+        assert_eq!(parallel.correlation.unwrap().matrix, serial.correlation.unwrap().matrix);
+        assert_eq!(parallel.kurtosis.len(), serial.kurtosis.len());
+        assert_eq!(parallel.rolling_beta.len(), 6);
+        // Pair sums are added in a different order, so allow rounding differences
+        assert_eq!(parallel.rolling_avg_correlation.len(), serial.rolling_avg_correlation.len());
+        for (a, b) in parallel.rolling_avg_correlation.iter().zip(&serial.rolling_avg_correlation) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Extension point for analysis modules. A plugin takes the loaded `MarketData` and
+//! returns named series and tables, which the Plugins tab draws generically, so a new
+//! indicator needs no view code of its own.
+//!
+//! Plugins from other crates implement [`AnalysisPlugin`] and are added to the
+//! registry in [`registry`] (or with [`PluginRegistry::register`] at startup).
+
+use chrono::NaiveDate;
+
+use crate::data::export::CsvTable;
+use crate::data::models::MarketData;
+
+/// An analysis module run on every data refresh and analysis recompute
+pub trait AnalysisPlugin: Send + Sync {
+    /// Unique name, shown as the section heading in the Plugins tab
+    fn name(&self) -> &str;
+
+    /// One-line summary shown under the heading
+    fn description(&self) -> &str {
+        ""
+    }
+
+    fn run(&self, data: &MarketData) -> Result<PluginOutput, String>;
+}
+
+/// A dated line series; `dates` and `values` have the same length, oldest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginSeries {
+    pub name: String,
+    pub dates: Vec<NaiveDate>,
+    pub values: Vec<f64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginTable {
+    pub name: String,
+    pub table: CsvTable,
+}
+
+/// Everything a plugin produced: series are drawn on one chart, tables as grids
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PluginOutput {
+    pub series: Vec<PluginSeries>,
+    pub tables: Vec<PluginTable>,
+}
+
+/// One plugin's output, or the error it returned
+#[derive(Debug, Clone)]
+pub struct PluginResult {
+    pub name: String,
+    pub description: String,
+    pub output: Result<PluginOutput, String>,
+}
+
+/// Registered plugins, run in registration order
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Box<dyn AnalysisPlugin>>,
+}
+
+impl PluginRegistry {
+    /// Add a plugin. A plugin with the same name as one already registered replaces it.
+    pub fn register(&mut self, plugin: impl AnalysisPlugin + 'static) {
+        self.plugins.retain(|p| p.name() != plugin.name());
+        self.plugins.push(Box::new(plugin));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Run every plugin on `data`. A failing or panicking plugin yields an error
+    /// result without affecting the others.
+    pub fn run_all(&self, data: &MarketData) -> Vec<PluginResult> {
+        self.plugins
+            .iter()
+            .map(|plugin| {
+                let output = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| plugin.run(data)))
+                    .unwrap_or_else(|_| Err("The plugin panicked".to_string()));
+                PluginResult {
+                    name: plugin.name().to_string(),
+                    description: plugin.description().to_string(),
+                    output,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The plugins available at startup
+pub fn registry() -> PluginRegistry {
+    let mut registry = PluginRegistry::default();
+    registry.register(SectorBreadth);
+    registry
+}
+
+/// Built-in example: share of sectors closing up each day, and each sector's
+/// up-day ratio
+struct SectorBreadth;
+
+impl AnalysisPlugin for SectorBreadth {
+    fn name(&self) -> &str {
+        "Sector Breadth"
+    }
+
+    fn description(&self) -> &str {
+        "Share of sectors that closed higher each day, and each sector's share of up days."
+    }
+
+    fn run(&self, data: &MarketData) -> Result<PluginOutput, String> {
+        if data.sectors.is_empty() {
+            return Err("No sector data loaded".to_string());
+        }
+
+        // Count up and total sectors per date across the whole universe
+        let mut days: std::collections::BTreeMap<NaiveDate, (usize, usize)> = Default::default();
+        let mut table = CsvTable {
+            headers: vec!["Symbol".to_string(), "Up Days (%)".to_string()],
+            rows: Vec::new(),
+        };
+        for sector in &data.sectors {
+            let mut up = 0;
+            for pair in sector.bars.windows(2) {
+                let day = days.entry(pair[1].date).or_default();
+                day.1 += 1;
+                if pair[1].close > pair[0].close {
+                    day.0 += 1;
+                    up += 1;
+                }
+            }
+            let total = sector.bars.len().saturating_sub(1);
+            let ratio = if total > 0 { format!("{:.1}", up as f64 / total as f64 * 100.0) } else { "-".to_string() };
+            table.rows.push(vec![sector.symbol.clone(), ratio]);
+        }
+
+        let (dates, values) = days
+            .into_iter()
+            .map(|(date, (up, total))| (date, up as f64 / total as f64 * 100.0))
+            .unzip();
+        Ok(PluginOutput {
+            series: vec![PluginSeries { name: "Sectors Up (%)".to_string(), dates, values }],
+            tables: vec![PluginTable { name: "Up-Day Ratio".to_string(), table }],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::{OhlcvBar, SectorTimeSeries};
+
+    struct Failing;
+
+    impl AnalysisPlugin for Failing {
+        fn name(&self) -> &str {
+            "Failing"
+        }
+
+        fn run(&self, _data: &MarketData) -> Result<PluginOutput, String> {
+            panic!("boom")
+        }
+    }
+
+    fn sector(symbol: &str, closes: &[f64]) -> SectorTimeSeries {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        SectorTimeSeries {
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            bars: closes
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| OhlcvBar {
+                    date: start + chrono::Duration::days(i as i64),
+                    open: c,
+                    high: c,
+                    low: c,
+                    close: c,
+                    volume: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_sector_breadth() {
+        let data = MarketData {
+            sectors: vec![sector("XLK", &[1.0, 2.0, 3.0]), sector("XLF", &[3.0, 2.0, 4.0])],
+            ..Default::default()
+        };
+        let output = SectorBreadth.run(&data).unwrap();
+        assert_eq!(output.series[0].values, vec![50.0, 100.0]);
+        assert_eq!(output.tables[0].table.rows, vec![vec!["XLK", "100.0"], vec!["XLF", "50.0"]]);
+    }
+
+    #[test]
+    fn test_failing_plugin_is_isolated() {
+        let mut registry = registry();
+        registry.register(Failing);
+        registry.register(Failing);
+        let results = registry.run_all(&MarketData::default());
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].output, Err("No sector data loaded".to_string()));
+        assert_eq!(results[1].output, Err("The plugin panicked".to_string()));
+    }
+}
@@ -0,0 +1,107 @@
+/// Volatility regime of a sector relative to its own history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolRegime {
+    Low,
+    Normal,
+    High,
+}
+
+impl VolRegime {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Low => "Low Vol",
+            Self::Normal => "Normal Vol",
+            Self::High => "High Vol",
+        }
+    }
+}
+
+/// Average correlation below which sectors are moving independently
+pub const DISPERSED_CORR: f64 = 0.3;
+/// Average correlation above which sectors are moving together (herding / stress)
+pub const HERDING_CORR: f64 = 0.6;
+
+/// Cross-sector correlation regime from the average pairwise correlation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationRegime {
+    Dispersed,
+    Normal,
+    Herding,
+}
+
+impl CorrelationRegime {
+    pub fn classify(avg_corr: f64) -> Self {
+        if avg_corr < DISPERSED_CORR {
+            Self::Dispersed
+        } else if avg_corr <= HERDING_CORR {
+            Self::Normal
+        } else {
+            Self::Herding
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Dispersed => "Dispersed",
+            Self::Normal => "Normal",
+            Self::Herding => "Herding",
+        }
+    }
+}
+
+/// Classify each vol against the terciles of the whole series (the same split the
+/// NN regime classifier uses for forward vol).
+pub fn classify_vol_regimes(vols: &[f64]) -> Vec<VolRegime> {
+    let mut sorted: Vec<f64> = vols.iter().copied().filter(|v| v.is_finite()).collect();
+    if sorted.is_empty() {
+        return vec![];
+    }
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let quantile = |q: f64| sorted[((sorted.len() - 1) as f64 * q).round() as usize];
+    let (low, high) = (quantile(1.0 / 3.0), quantile(2.0 / 3.0));
+    vols.iter()
+        .map(|&v| {
+            if v <= low {
+                VolRegime::Low
+            } else if v < high {
+                VolRegime::Normal
+            } else {
+                VolRegime::High
+            }
+        })
+        .collect()
+}
+
+/// Run-length segments `(regime, start, end)` of a regime sequence, `end` exclusive
+pub fn regime_spans<R: Copy + PartialEq>(regimes: &[R]) -> Vec<(R, usize, usize)> {
+    let mut spans: Vec<(R, usize, usize)> = Vec::new();
+    for (i, &r) in regimes.iter().enumerate() {
+        match spans.last_mut() {
+            Some(last) if last.0 == r => last.2 = i + 1,
+            _ => spans.push((r, i, i + 1)),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_vol_regimes_terciles() {
+        let vols: Vec<f64> = (1..=9).map(|v| v as f64 / 100.0).collect();
+        let regimes = classify_vol_regimes(&vols);
+        assert_eq!(regimes[0], VolRegime::Low);
+        assert_eq!(regimes[4], VolRegime::Normal);
+        assert_eq!(regimes[8], VolRegime::High);
+    }
+
+    #[test]
+    fn test_regime_spans() {
+        use CorrelationRegime::*;
+        let spans = regime_spans(&[Normal, Normal, Herding, Normal]);
+        assert_eq!(spans, vec![(Normal, 0, 2), (Herding, 2, 3), (Normal, 3, 4)]);
+        assert!(regime_spans::<VolRegime>(&[]).is_empty());
+    }
+}
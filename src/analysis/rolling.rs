@@ -0,0 +1,296 @@
+//! Sliding-window kernels behind the rolling statistics. Each window's statistics are
+//! updated from the previous window's as one value leaves and one enters, so a series of
+//! n values costs O(n) whatever the window, rather than O(n·w) from recomputing every
+//! window. The state is rebuilt from scratch every [`RESYNC_INTERVAL`] windows so
+//! rounding from the removals can't build up over long (intraday) series.
+
+/// Windows between full recomputations of the running state
+const RESYNC_INTERVAL: usize = 1024;
+
+/// Running state updated as values enter and leave the window
+trait Accumulator {
+    type Item;
+    fn add(&mut self, item: Self::Item);
+    fn remove(&mut self, item: Self::Item);
+}
+
+/// Evaluate `read` on every window of `len` items, sliding `init()`'s state along
+fn slide<A: Accumulator, T>(
+    len: usize,
+    window: usize,
+    init: impl Fn() -> A,
+    item: impl Fn(usize) -> A::Item,
+    read: impl Fn(&A) -> T,
+) -> Vec<T> {
+    if window == 0 || len < window {
+        return vec![];
+    }
+    let mut state = init();
+    (0..window).for_each(|i| state.add(item(i)));
+    let mut out = Vec::with_capacity(len - window + 1);
+    out.push(read(&state));
+    for end in window..len {
+        let start = end + 1 - window;
+        if start.is_multiple_of(RESYNC_INTERVAL) {
+            state = init();
+            (start..=end).for_each(|i| state.add(item(i)));
+        } else {
+            state.remove(item(start - 1));
+            state.add(item(end));
+        }
+        out.push(read(&state));
+    }
+    out
+}
+
+/// Mean and sum of squared deviations of the values in the window (Welford)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Moments {
+    n: f64,
+    pub mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    /// Sample variance (n - 1 denominator)
+    pub fn sample_variance(&self) -> f64 {
+        if self.n < 2.0 { 0.0 } else { self.m2.max(0.0) / (self.n - 1.0) }
+    }
+}
+
+impl Accumulator for Moments {
+    type Item = f64;
+
+    fn add(&mut self, x: f64) {
+        self.n += 1.0;
+        let d = x - self.mean;
+        self.mean += d / self.n;
+        self.m2 += d * (x - self.mean);
+    }
+
+    fn remove(&mut self, x: f64) {
+        self.n -= 1.0;
+        if self.n == 0.0 {
+            *self = Self::default();
+            return;
+        }
+        let d = x - self.mean;
+        self.mean -= d / self.n;
+        self.m2 -= d * (x - self.mean);
+    }
+}
+
+/// Means, sums of squared deviations and co-moment of paired values in the window
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CoMoments {
+    n: f64,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c: f64,
+}
+
+impl CoMoments {
+    /// Pearson correlation; 0 when either side is flat, as [`pearson_correlation`]
+    ///
+    /// [`pearson_correlation`]: crate::analysis::cross_sector::pearson_correlation
+    pub fn correlation(&self) -> f64 {
+        let denom = (self.m2_x.max(0.0) * self.m2_y.max(0.0)).sqrt();
+        if denom < 1e-15 { 0.0 } else { self.c / denom }
+    }
+
+    /// OLS slope of x on y (cov / var y); `None` when y is flat, as [`beta`]
+    ///
+    /// [`beta`]: crate::analysis::cross_sector::beta
+    pub fn beta(&self) -> Option<f64> {
+        (self.m2_y > 1e-15).then(|| self.c / self.m2_y)
+    }
+}
+
+impl Accumulator for CoMoments {
+    type Item = (f64, f64);
+
+    fn add(&mut self, (x, y): (f64, f64)) {
+        self.n += 1.0;
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / self.n;
+        self.mean_y += dy / self.n;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.c += dx * (y - self.mean_y);
+    }
+
+    fn remove(&mut self, (x, y): (f64, f64)) {
+        self.n -= 1.0;
+        if self.n == 0.0 {
+            *self = Self::default();
+            return;
+        }
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x -= dx / self.n;
+        self.mean_y -= dy / self.n;
+        self.m2_x -= dx * (x - self.mean_x);
+        self.m2_y -= dy * (y - self.mean_y);
+        self.c -= dx * (y - self.mean_y);
+    }
+}
+
+/// Power sums of the window's values, shifted by the series mean to keep the
+/// expansion into central moments well conditioned
+#[derive(Debug, Clone, Copy)]
+pub struct PowerSums {
+    shift: f64,
+    /// Mean square of the whole shifted series: windows whose variance is a vanishing
+    /// fraction of it count as flat
+    scale: f64,
+    n: f64,
+    s: [f64; 4],
+}
+
+impl PowerSums {
+    /// Second, third and fourth central moments (population); `None` for a flat window
+    fn central(&self) -> Option<(f64, f64, f64)> {
+        let [s1, s2, s3, s4] = self.s.map(|s| s / self.n);
+        let m2 = s2 - s1 * s1;
+        if m2 <= 1e-12 * self.scale {
+            return None;
+        }
+        let m3 = s3 - 3.0 * s1 * s2 + 2.0 * s1.powi(3);
+        let m4 = s4 - 4.0 * s1 * s3 + 6.0 * s1 * s1 * s2 - 3.0 * s1.powi(4);
+        Some((m2, m3, m4))
+    }
+
+    /// Skewness; 0 for a flat window, as [`skewness`](crate::analysis::kurtosis::skewness)
+    pub fn skewness(&self) -> f64 {
+        self.central().map_or(0.0, |(m2, m3, _)| m3 / m2.powf(1.5))
+    }
+
+    /// Excess kurtosis; 0 for a flat window, as
+    /// [`excess_kurtosis`](crate::analysis::kurtosis::excess_kurtosis)
+    pub fn excess_kurtosis(&self) -> f64 {
+        self.central().map_or(0.0, |(m2, _, m4)| m4 / (m2 * m2) - 3.0)
+    }
+}
+
+impl Accumulator for PowerSums {
+    type Item = f64;
+
+    fn add(&mut self, x: f64) {
+        let y = x - self.shift;
+        self.n += 1.0;
+        self.s[0] += y;
+        self.s[1] += y * y;
+        self.s[2] += y * y * y;
+        self.s[3] += y * y * y * y;
+    }
+
+    fn remove(&mut self, x: f64) {
+        let y = x - self.shift;
+        self.n -= 1.0;
+        self.s[0] -= y;
+        self.s[1] -= y * y;
+        self.s[2] -= y * y * y;
+        self.s[3] -= y * y * y * y;
+    }
+}
+
+/// `read` of each window's [`Moments`]
+pub fn moments<T>(data: &[f64], window: usize, read: impl Fn(&Moments) -> T) -> Vec<T> {
+    slide(data.len(), window, Moments::default, |i| data[i], read)
+}
+
+/// `read` of each window's [`CoMoments`] over `x` and `y`'s first `min(len)` values
+pub fn co_moments<T>(x: &[f64], y: &[f64], window: usize, read: impl Fn(&CoMoments) -> T) -> Vec<T> {
+    let n = x.len().min(y.len());
+    slide(n, window, CoMoments::default, |i| (x[i], y[i]), read)
+}
+
+/// `read` of each window's [`PowerSums`]
+pub fn power_sums<T>(data: &[f64], window: usize, read: impl Fn(&PowerSums) -> T) -> Vec<T> {
+    if data.is_empty() {
+        return vec![];
+    }
+    let shift = data.iter().sum::<f64>() / data.len() as f64;
+    let scale = data.iter().map(|x| (x - shift).powi(2)).sum::<f64>() / data.len() as f64;
+    let init = || PowerSums { shift, scale, n: 0.0, s: [0.0; 4] };
+    slide(data.len(), window, init, |i| data[i], read)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{cross_sector, kurtosis, volatility};
+    use rand::{Rng, SeedableRng};
+
+    /// Fat-tailed random returns, with a flat stretch in the middle
+    fn returns(n: usize, seed: u64) -> Vec<f64> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|i| {
+                if (n / 2..n / 2 + 40).contains(&i) {
+                    return 0.0;
+                }
+                let r: f64 = rng.gen_range(-0.01..0.01);
+                if rng.gen_bool(0.05) { r * 5.0 } else { r }
+            })
+            .collect()
+    }
+
+    fn assert_close(fast: &[f64], naive: &[f64], what: &str) {
+        assert_eq!(fast.len(), naive.len(), "{} length", what);
+        for (i, (a, b)) in fast.iter().zip(naive).enumerate() {
+            let ok = (a.is_nan() && b.is_nan()) || (a - b).abs() <= 1e-8 * b.abs().max(1.0);
+            assert!(ok, "{} window {}: {} vs {}", what, i, a, b);
+        }
+    }
+
+    fn naive_volatility(data: &[f64], window: usize) -> Vec<f64> {
+        data.windows(window)
+            .map(|w| {
+                let mean = w.iter().sum::<f64>() / w.len() as f64;
+                let var = w.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (w.len() - 1) as f64;
+                (var * 252.0).sqrt()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kernels_match_per_window_computation() {
+        // Longer than RESYNC_INTERVAL so the resync path runs too
+        let a = returns(3000, 1);
+        let b = returns(3000, 2);
+        for window in [5, 21, 63] {
+            assert_close(&volatility::rolling_volatility(&a, window), &naive_volatility(&a, window), "volatility");
+            let correlation: Vec<f64> =
+                a.windows(window).zip(b.windows(window)).map(|(x, y)| cross_sector::pearson_correlation(x, y)).collect();
+            assert_close(&cross_sector::rolling_correlation(&a, &b, window), &correlation, "correlation");
+            let beta: Vec<f64> = a
+                .windows(window)
+                .zip(b.windows(window))
+                .map(|(x, y)| cross_sector::beta(x, y).unwrap_or(f64::NAN))
+                .collect();
+            assert_close(&cross_sector::rolling_beta(&a, &b, window), &beta, "beta");
+            let skew: Vec<f64> = a.windows(window).map(kurtosis::skewness).collect();
+            assert_close(&kurtosis::rolling_skewness(&a, window), &skew, "skewness");
+            let kurt: Vec<f64> = a.windows(window).map(kurtosis::excess_kurtosis).collect();
+            assert_close(&kurtosis::rolling_kurtosis(&a, window), &kurt, "kurtosis");
+        }
+    }
+
+    #[test]
+    fn test_flat_windows() {
+        let data = returns(200, 3);
+        let flat = 100..140;
+        let vol = volatility::rolling_volatility(&data, 10);
+        assert!(vol[flat.start..flat.end - 9].iter().all(|v| v.abs() < 1e-6));
+        let kurt = kurtosis::rolling_kurtosis(&data, 10);
+        assert!(kurt[flat.start..flat.end - 9].iter().all(|k| *k == 0.0));
+        let beta = cross_sector::rolling_beta(&data, &data, 10);
+        assert!(beta[flat.start..flat.end - 9].iter().all(|b| b.is_nan()));
+        assert!(moments(&data, 0, |m| m.mean).is_empty());
+        assert!(power_sums(&[], 3, |p| p.skewness()).is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::analysis::drawdown;
+
+/// Historical one-day Value at Risk at `confidence` (e.g. 0.95): the loss exceeded on
+/// only `1 - confidence` of days, as a positive fraction of log return.
+pub fn historical_var(returns: &[f64], confidence: f64) -> Option<f64> {
+    let sorted = sorted_finite(returns);
+    let idx = tail_count(sorted.len(), confidence)?.saturating_sub(1);
+    Some(-sorted[idx])
+}
+
+/// Historical Expected Shortfall (CVaR): the mean loss on the days at or beyond the VaR.
+pub fn expected_shortfall(returns: &[f64], confidence: f64) -> Option<f64> {
+    let sorted = sorted_finite(returns);
+    let k = tail_count(sorted.len(), confidence)?;
+    Some(-sorted[..k].iter().sum::<f64>() / k as f64)
+}
+
+/// Hill estimator of the left-tail index α from the largest `tail_fraction` of losses.
+/// Smaller α means fatter tails (α ≈ 3 is typical for daily equity returns).
+pub fn hill_tail_index(returns: &[f64], tail_fraction: f64) -> Option<f64> {
+    let mut losses: Vec<f64> = returns.iter().map(|r| -r).filter(|l| l.is_finite() && *l > 0.0).collect();
+    losses.sort_by(|a, b| b.total_cmp(a));
+    let k = ((losses.len() as f64 * tail_fraction) as usize).min(losses.len().saturating_sub(1));
+    if k < 2 {
+        return None;
+    }
+    let threshold = losses[k];
+    let mean_log_excess = losses[..k].iter().map(|l| (l / threshold).ln()).sum::<f64>() / k as f64;
+    (mean_log_excess > 0.0).then(|| 1.0 / mean_log_excess)
+}
+
+/// Percentage of `history` at or below `value` (0–100)
+pub fn percentile_rank(history: &[f64], value: f64) -> Option<f64> {
+    let finite: Vec<f64> = history.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() {
+        return None;
+    }
+    Some(finite.iter().filter(|v| **v <= value).count() as f64 / finite.len() as f64 * 100.0)
+}
+
+fn sorted_finite(data: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = data.iter().copied().filter(|v| v.is_finite()).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted
+}
+
+/// Number of observations in the `1 - confidence` left tail (at least one)
+fn tail_count(n: usize, confidence: f64) -> Option<usize> {
+    if n == 0 || !(0.0..1.0).contains(&confidence) {
+        return None;
+    }
+    // The epsilon keeps e.g. 0.1 * 20 from flooring to 1 through rounding error
+    Some((((1.0 - confidence) * n as f64 + 1e-9).floor() as usize).clamp(1, n))
+}
+
+/// Compute all tail-risk metrics for a sector. `short_vol` is its rolling short-window
+/// vol series; the latest value is ranked against the rest.
+pub fn compute_sector_risk(
+    symbol: &str,
+    closes: &[f64],
+    log_returns: &[f64],
+    short_vol: &[f64],
+    confidence: f64,
+    tail_fraction: f64,
+) -> SectorRisk {
+    let current_vol = short_vol.last().copied();
+    SectorRisk {
+        symbol: symbol.to_string(),
+        var: historical_var(log_returns, confidence),
+        expected_shortfall: expected_shortfall(log_returns, confidence),
+        max_drawdown: drawdown::max_drawdown(closes).map(|m| m.depth),
+        tail_index: hill_tail_index(log_returns, tail_fraction),
+        current_vol,
+        vol_percentile: current_vol.and_then(|v| percentile_rank(short_vol, v)),
+    }
+}
+
+/// Per-sector tail-risk summary for the Risk tab
+#[derive(Debug, Clone)]
+pub struct SectorRisk {
+    pub symbol: String,
+    /// One-day historical VaR (positive loss fraction)
+    pub var: Option<f64>,
+    pub expected_shortfall: Option<f64>,
+    /// Deepest peak-to-trough decline (negative fraction)
+    pub max_drawdown: Option<f64>,
+    /// Hill tail index α of daily losses
+    pub tail_index: Option<f64>,
+    /// Latest short-window annualized vol
+    pub current_vol: Option<f64>,
+    /// Where `current_vol` ranks in the sector's own history (0–100)
+    pub vol_percentile: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_var_and_expected_shortfall() {
+        // -0.10, -0.09, ..., +0.09: the worst 10% are -0.10 and -0.09
+        let returns: Vec<f64> = (0..20).map(|i| (i as f64 - 10.0) / 100.0).collect();
+        assert!((historical_var(&returns, 0.90).unwrap() - 0.09).abs() < 1e-12);
+        assert!((expected_shortfall(&returns, 0.90).unwrap() - 0.095).abs() < 1e-12);
+        assert_eq!(historical_var(&[], 0.95), None);
+    }
+
+    #[test]
+    fn test_hill_tail_index_recovers_pareto_alpha() {
+        // Deterministic Pareto(α = 3) quantiles as losses
+        let alpha = 3.0;
+        let n = 5000;
+        let returns: Vec<f64> = (1..=n)
+            .map(|i| -0.01 * (1.0 - i as f64 / (n + 1) as f64).powf(-1.0 / alpha))
+            .collect();
+        let est = hill_tail_index(&returns, 0.1).unwrap();
+        assert!((est - alpha).abs() < 0.2, "estimated α = {}", est);
+    }
+
+    #[test]
+    fn test_percentile_rank() {
+        let history = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(percentile_rank(&history, 4.0), Some(100.0));
+        assert_eq!(percentile_rank(&history, 2.0), Some(50.0));
+        assert_eq!(percentile_rank(&[], 1.0), None);
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 
+use crate::analysis::rolling;
 use crate::data::models::VolatilityMetrics;
 
 const TRADING_DAYS_PER_YEAR: f64 = 252.0;
@@ -9,15 +10,7 @@ pub fn rolling_volatility(log_returns: &[f64], window: usize) -> Vec<f64> {
     if log_returns.len() < window || window < 2 {
         return vec![];
     }
-    log_returns
-        .windows(window)
-        .map(|w| {
-            let mean = w.iter().sum::<f64>() / w.len() as f64;
-            let variance =
-                w.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (w.len() - 1) as f64;
-            variance.sqrt() * TRADING_DAYS_PER_YEAR.sqrt()
-        })
-        .collect()
+    rolling::moments(log_returns, window, |m| m.sample_variance().sqrt() * TRADING_DAYS_PER_YEAR.sqrt())
 }
 
 /// Parkinson volatility estimator using high/low range (more efficient than close-to-close)
@@ -38,13 +31,7 @@ pub fn parkinson_volatility(highs: &[f64], lows: &[f64], window: usize) -> Vec<f
         .collect();
 
     let factor = 1.0 / (4.0 * std::f64::consts::LN_2);
-    hl_log_sq
-        .windows(window)
-        .map(|w| {
-            let avg = w.iter().sum::<f64>() / w.len() as f64;
-            (factor * avg).sqrt() * TRADING_DAYS_PER_YEAR.sqrt()
-        })
-        .collect()
+    rolling::moments(&hl_log_sq, window, |m| (factor * m.mean).max(0.0).sqrt() * TRADING_DAYS_PER_YEAR.sqrt())
 }
 
 /// Compute volatility ratio (short-term / long-term) aligned by their trailing ends
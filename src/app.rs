@@ -1,40 +1,364 @@
+use std::collections::BTreeMap;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use eframe::egui;
+use serde::{Deserialize, Serialize};
 
 use crate::analysis;
+use crate::analysis::AnalysisResults;
+use crate::analysis::alerts::{AlertEvent, AlertRule};
 use crate::config;
-use crate::analysis::randomness::SectorRandomness;
 use crate::data::models::{
-    BondSpread, ComputeStats, CorrelationMatrix, GpuAdapterInfo, KurtosisMetrics, MarketData,
-    NnFeatureFlags, NnPredictions, ScreenshotSettings, TrainingStatus, VolatilityMetrics,
+    AnalysisWindows, ChartEvent, CustomIndicator, ComputeStats, DisplaySettings, EventKind, GpuAdapterInfo, InferencePrecision,
+    MarketData, NnDriftSettings, NnFeatureFlags, NnPredictions, NnTrainingConfig,
+    ScreenshotScope, ScreenshotSettings, SectorTimeSeries, TrainingStatus, VolatilityMetrics,
 };
+use crate::data::api_keys::{ApiEnvironments, ApiProvider};
+use crate::data::autosave;
+use crate::data::cache::Fetched;
+use crate::data::config_file::ConfigFile;
+use crate::data::email::EmailSettings;
+use crate::data::jobs::{JobAction, JobResult, JobsState};
+use crate::data::startup::StartupLoad;
+use crate::data::webhooks::Webhook;
+use crate::data::health::{DataHealth, DataSource, FetchOutcome};
+use crate::data::yahoo::FetchProgress;
+use crate::diagnostics::Diagnostics;
+use crate::nn::backtest::BacktestResult;
+use crate::nn::drift::{DriftStatus, LivePrediction};
+use crate::nn::gpu::GpuDiagnostics;
+use crate::nn::saliency::Saliency;
+use crate::nn::history::TrainingRun;
 use crate::nn::persistence::ModelMetadata;
-use crate::nn::training::TrainingProgress;
+use crate::nn::prediction_history::PredictionSnapshot;
+use crate::nn::sweep::{SweepMode, SweepProgress, SweepSpace};
+use crate::nn::training::{ComputeDevice, TrainingProgress};
+use crate::logging::LogLevel;
+use crate::notices::{Notice, NoticeSource, Notices, RetryAction, Severity};
 use crate::nn::LoadedModel;
+use crate::tasks::{TaskHooks, TaskId, TaskKind, TaskManager, TaskProgress, TaskStatus};
+use crate::ui::chart_utils::PlotCache;
+use crate::ui::profiles::SettingsProfile;
+use crate::ui::screenshot::ScreenshotTarget;
+use crate::ui::tray::{TrayCommand, TrayState};
+use crate::grpc::GrpcState;
 use crate::ui;
+use crate::ui::i18n::{self, tr, tr_args, Locale};
+use crate::ui::palette::PaletteMode;
+
+/// Shared result slot written by the GPU diagnostics thread
+pub type GpuDiagnosticsSlot = Arc<Mutex<Option<Result<GpuDiagnostics, String>>>>;
 
 /// Active tab in the main UI
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Tab {
+    #[default]
     Dashboard,
     SectorVol,
     Correlations,
     Bonds,
+    Rates,
     Kurtosis,
+    Risk,
+    Compare,
+    Watchlist,
+    Plugins,
+    Data,
     NeuralNet,
     Settings,
 }
 
-/// Computed analysis results (derived from MarketData)
+impl Tab {
+    pub const ALL: [Tab; 13] = [
+        Self::Dashboard,
+        Self::SectorVol,
+        Self::Correlations,
+        Self::Bonds,
+        Self::Rates,
+        Self::Kurtosis,
+        Self::Risk,
+        Self::Compare,
+        Self::Watchlist,
+        Self::Plugins,
+        Self::Data,
+        Self::NeuralNet,
+        Self::Settings,
+    ];
+
+    /// Catalog id of the tab's name; also keys its detached window, so it stays put
+    /// when the language changes
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            Self::Dashboard => "tab-dashboard",
+            Self::SectorVol => "tab-sector-vol",
+            Self::Correlations => "tab-correlations",
+            Self::Bonds => "tab-bonds",
+            Self::Rates => "tab-rates",
+            Self::Kurtosis => "tab-kurtosis",
+            Self::Risk => "tab-risk",
+            Self::Compare => "tab-compare",
+            Self::Watchlist => "tab-watchlist",
+            Self::Plugins => "tab-plugins",
+            Self::Data => "tab-data",
+            Self::NeuralNet => "tab-neural-net",
+            Self::Settings => "tab-settings",
+        }
+    }
+
+    pub fn label(&self) -> String {
+        tr(self.message_id())
+    }
+
+    /// Draw this tab's view into `ui`
+    fn render(&self, ui: &mut egui::Ui, state: &mut AppState) {
+        match self {
+            Self::Dashboard => ui::dashboard::render(ui, state),
+            Self::SectorVol => ui::sector_view::render(ui, state),
+            Self::Correlations => ui::correlation_view::render(ui, state),
+            Self::Bonds => ui::bond_view::render(ui, state),
+            Self::Rates => ui::rates_view::render(ui, state),
+            Self::Kurtosis => ui::kurtosis_view::render(ui, state),
+            Self::Risk => ui::risk_view::render(ui, state),
+            Self::Compare => ui::compare_view::render(ui, state),
+            Self::Watchlist => ui::watchlist_view::render(ui, state),
+            Self::Plugins => ui::plugins_view::render(ui, state),
+            Self::Data => ui::data_view::render(ui, state),
+            Self::NeuralNet => ui::nn_view::render(ui, state),
+            Self::Settings => ui::settings_view::render(ui, state),
+        }
+    }
+}
+
+/// Date selection and playback for the Rates tab yield curve
+#[derive(Debug, Clone)]
+pub struct RatesState {
+    /// Index into the date-sorted treasury history; `None` follows the latest date
+    pub date_idx: Option<usize>,
+    /// Overlay a second curve: from `compare_date` when pinned, otherwise from
+    /// `compare_lag` observations before the selected date
+    pub compare_enabled: bool,
+    pub compare_lag: usize,
+    /// Fixed comparison date; the latest observation on or before it is used
+    pub compare_date: Option<chrono::NaiveDate>,
+    pub playing: bool,
+    /// Observations advanced per second while playing
+    pub play_speed: f64,
+    /// Time of the last playback step (`egui` input time, seconds)
+    pub last_step: Option<f64>,
+}
+
+impl Default for RatesState {
+    fn default() -> Self {
+        Self {
+            date_idx: None,
+            compare_enabled: true,
+            compare_lag: 21,
+            compare_date: None,
+            playing: false,
+            play_speed: 20.0,
+            last_step: None,
+        }
+    }
+}
+
+/// Series overlaid on the Compare tab chart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompareMetric {
+    /// Closes rebased to 100 at the start of the common window
+    #[default]
+    NormalizedPrice,
+    /// Short-window (21D) annualized volatility
+    ShortVol,
+}
+
+/// Sector selection and metric for the Compare tab
+#[derive(Debug, Clone, Default)]
+pub struct CompareState {
+    /// Symbols overlaid on the chart, in sector order
+    pub symbols: Vec<String>,
+    pub metric: CompareMetric,
+}
+
+/// Finished watchlist fetches, drained by the Watchlist view
+pub type WatchlistSlot = Arc<Mutex<Vec<(String, Result<SectorTimeSeries, String>)>>>;
+
+/// A fetched watchlist symbol with its standard vol metrics
+#[derive(Debug, Clone)]
+pub struct WatchlistEntry {
+    pub series: SectorTimeSeries,
+    pub volatility: VolatilityMetrics,
+}
+
+/// User-chosen symbols tracked outside the sector/NN pipeline
+#[derive(Debug, Clone, Default)]
+pub struct WatchlistState {
+    /// Tickers in display order; persisted
+    pub symbols: Vec<String>,
+    pub entries: Vec<WatchlistEntry>,
+    /// Last fetch error per symbol
+    pub errors: Vec<(String, String)>,
+    /// Symbols with a fetch in flight
+    pub pending: Vec<String>,
+    pub results: WatchlistSlot,
+    /// Ticker being typed into the add box
+    pub input: String,
+    /// Symbol to highlight after jumping here from search
+    pub highlight: Option<String>,
+}
+
+/// Named settings profiles and the Settings controls that manage them
+#[derive(Debug, Clone, Default)]
+pub struct ProfilesState {
+    /// Saved profiles; persisted
+    pub profiles: Vec<SettingsProfile>,
+    /// Profile picked in the dropdown
+    pub selected: Option<String>,
+    /// Name typed for "Save current as"
+    pub name_input: String,
+}
+
+/// Settings controls for saving and opening workspaces
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceState {
+    /// Saved workspace picked in the dropdown
+    pub selected: Option<String>,
+    /// Name typed for "Save workspace as"
+    pub name_input: String,
+}
+
+/// Custom indicator scripts and the Settings editor for them
 #[derive(Debug, Clone, Default)]
-pub struct AnalysisResults {
-    pub volatility: Vec<VolatilityMetrics>,
-    pub correlation: Option<CorrelationMatrix>,
-    pub bond_spreads: Vec<BondSpread>,
-    pub avg_cross_correlation: f64,
-    pub kurtosis: Vec<KurtosisMetrics>,
-    pub randomness: Vec<SectorRandomness>,
+pub struct CustomIndicatorsState {
+    /// Saved indicators; persisted
+    pub indicators: Vec<CustomIndicator>,
+    /// Index of the indicator open in the editor; `None` with a draft is a new one
+    pub editing: Option<usize>,
+    /// Indicator being edited, saved on "Save"
+    pub draft: Option<CustomIndicator>,
+    /// Outcome of "Test" on the selected sector: a summary or the script error
+    pub test_result: Option<Result<String, String>>,
+}
+
+impl CustomIndicatorsState {
+    /// Indicators marked as NN features, for `NnFeatureFlags::custom_indicators`
+    pub fn nn_features(&self) -> Vec<CustomIndicator> {
+        self.indicators.iter().filter(|i| i.nn_feature).cloned().collect()
+    }
+}
+
+/// Finished "Test connection" checks, drained by the settings view
+pub type ApiTestSlot = Arc<Mutex<Vec<(ApiProvider, Result<String, String>)>>>;
+
+/// Key entry and connection tests in the Settings API key section
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeysState {
+    /// Key being typed, per provider
+    pub drafts: BTreeMap<ApiProvider, String>,
+    /// Show keys in plain text instead of masked
+    pub reveal: bool,
+    /// Providers with a connection test in flight
+    pub testing: Vec<ApiProvider>,
+    /// Outcome of the last connection test, per provider
+    pub results: BTreeMap<ApiProvider, Result<String, String>>,
+    pub slot: ApiTestSlot,
+    /// Saved credential sets and the active one
+    pub environments: ApiEnvironments,
+    /// Name typed for a new environment
+    pub new_environment: String,
+    /// FMP endpoint being edited, and the environment it was loaded from
+    pub endpoint_draft: String,
+    pub endpoint_for: String,
+}
+
+/// Finished single-symbol refetches, drained by `AppState::collect_refetches`
+pub type RefetchSlot = Arc<Mutex<Vec<(String, anyhow::Result<Fetched<SectorTimeSeries>>)>>>;
+
+/// Per-symbol data management: exclusions and single-symbol refetches
+#[derive(Debug, Clone, Default)]
+pub struct DataManagerState {
+    /// Sector symbols left out of analysis and training; persisted
+    pub excluded: Vec<String>,
+    /// Loaded series of excluded sectors, held out of `market_data.sectors`
+    pub set_aside: Vec<SectorTimeSeries>,
+    /// Symbols with a refetch in flight
+    pub pending: Vec<String>,
+    pub results: RefetchSlot,
+}
+
+/// A fired alert shown as a toast notification until it expires
+#[derive(Debug, Clone)]
+pub struct AlertToast {
+    pub message: String,
+    /// `egui` input time the toast was first drawn; `None` until then
+    pub shown_at: Option<f64>,
+}
+
+/// Alert rules, fired-alert history, and notification state
+#[derive(Debug, Clone, Default)]
+pub struct AlertsState {
+    /// Persisted
+    pub rules: Vec<AlertRule>,
+    /// Fired alerts, oldest first; persisted
+    pub history: Vec<AlertEvent>,
+    pub toasts: Vec<AlertToast>,
+    pub panel_open: bool,
+    /// Alerts fired since the panel was last opened
+    pub unread: usize,
+    /// Rule being composed in the panel
+    pub draft: AlertRule,
+    /// Endpoints fired alerts are posted to; persisted in `config.toml`
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Bottom status bar: per-source data freshness and API health beside the latest notice
+#[derive(Debug, Clone, Default)]
+pub struct StatusBar {
+    pub health: DataHealth,
+}
+
+/// Sortable columns of the dashboard's sector heatmap table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapColumn {
+    Sector,
+    Symbol,
+    LastClose,
+    ShortVol,
+    LongVol,
+    VolRatio,
+    Beta,
+    Bars,
+}
+
+/// Dashboard heatmap ordering; `None` keeps the configured sector order
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeatmapSort {
+    pub column: Option<HeatmapColumn>,
+    pub descending: bool,
+}
+
+/// Event marker visibility and the annotation being entered in the sector view
+#[derive(Debug, Clone)]
+pub struct EventsState {
+    /// Event kinds not drawn on charts
+    pub hidden: Vec<EventKind>,
+    pub draft_date: String,
+    pub draft_kind: EventKind,
+    pub draft_label: String,
+    /// Validation error for the draft, if the last add attempt failed
+    pub draft_error: Option<String>,
+}
+
+impl Default for EventsState {
+    fn default() -> Self {
+        Self {
+            hidden: Vec::new(),
+            draft_date: String::new(),
+            draft_kind: EventKind::Note,
+            draft_label: String::new(),
+            draft_error: None,
+        }
+    }
 }
 
 /// State for the 3D probability distribution plot on the dashboard
@@ -61,37 +385,124 @@ impl Default for Plot3DState {
 }
 
 /// Per-chart height overrides (pixels), adjustable by the user at runtime
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ChartHeights {
     pub sector_price: f32,
+    pub sector_volume: f32,
+    pub sector_drawdown: f32,
     pub sector_vol: f32,
     pub sector_ratio: f32,
+    pub sector_custom: f32,
     pub bond_yield_curve: f32,
     pub bond_term_spread: f32,
     pub bond_curve_slope: f32,
     pub nn_loss: f32,
+    pub nn_backtest_timeline: f32,
+    pub nn_backtest_scatter: f32,
+    pub nn_backtest_residuals: f32,
+    pub nn_run_compare: f32,
     pub kurtosis_distribution: f32,
     pub kurtosis_rolling_kurtosis: f32,
     pub kurtosis_rolling_skewness: f32,
     pub kurtosis_accel_chart: f32,
     pub put_call_skew: f32,
+    pub compare_overlay: f32,
+    pub rates_curve: f32,
+    pub rates_spread_history: f32,
+    pub rolling_correlation: f32,
+    pub dispersion: f32,
+    pub corr_network: f32,
+    pub pair_correlation: f32,
+    pub pair_scatter: f32,
+    pub rolling_beta: f32,
+    pub risk_return_scatter: f32,
+    pub return_histogram: f32,
+    pub qq_plot: f32,
+    pub plugin_series: f32,
 }
 
 impl Default for ChartHeights {
     fn default() -> Self {
         Self {
             sector_price: 200.0,
+            sector_volume: 100.0,
+            sector_drawdown: 150.0,
             sector_vol: 250.0,
             sector_ratio: 150.0,
+            sector_custom: 200.0,
             bond_yield_curve: 200.0,
             bond_term_spread: 200.0,
             bond_curve_slope: 180.0,
             nn_loss: 200.0,
+            nn_backtest_timeline: 220.0,
+            nn_backtest_scatter: 300.0,
+            nn_backtest_residuals: 220.0,
+            nn_run_compare: 250.0,
             kurtosis_distribution: 280.0,
             kurtosis_rolling_kurtosis: 200.0,
             kurtosis_rolling_skewness: 200.0,
             kurtosis_accel_chart: 220.0,
             put_call_skew: 200.0,
+            compare_overlay: 350.0,
+            rates_curve: 300.0,
+            rates_spread_history: 260.0,
+            rolling_correlation: 200.0,
+            dispersion: 220.0,
+            corr_network: 380.0,
+            pair_correlation: 180.0,
+            pair_scatter: 260.0,
+            rolling_beta: 200.0,
+            risk_return_scatter: 350.0,
+            return_histogram: 250.0,
+            qq_plot: 300.0,
+            plugin_series: 250.0,
+        }
+    }
+}
+
+/// Cache file the window geometry and view state are persisted to
+pub const UI_STATE_FILENAME: &str = "ui_state.json";
+
+/// Layout and view state restored on the next launch
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    /// Inner window size and outer position in logical pixels
+    pub window_size: Option<[f32; 2]>,
+    pub window_pos: Option<[f32; 2]>,
+    pub window_maximized: bool,
+    pub active_tab: Tab,
+    pub selected_sector_idx: usize,
+    pub chart_heights: ChartHeights,
+    /// Open (`true`) or collapsed state of collapsible sections, by key
+    pub open_sections: BTreeMap<String, bool>,
+    /// Series hidden through chart legends, by chart id
+    pub hidden_series: ui::chart_utils::HiddenSeries,
+}
+
+impl UiState {
+    pub fn load() -> Self {
+        crate::data::cache::load_json(UI_STATE_FILENAME).unwrap_or_default()
+    }
+
+    /// Snapshot of the current layout. Window geometry is converted from UI points to
+    /// logical pixels so the UI zoom doesn't shrink or grow the restored window.
+    pub fn capture(state: &AppState, ctx: &egui::Context) -> Self {
+        let zoom = ctx.zoom_factor();
+        let (inner, outer, maximized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (viewport.inner_rect, viewport.outer_rect, viewport.maximized.unwrap_or(false))
+        });
+        Self {
+            window_size: inner.map(|r| [r.width() * zoom, r.height() * zoom]),
+            window_pos: outer.map(|r| [r.min.x * zoom, r.min.y * zoom]),
+            window_maximized: maximized,
+            active_tab: state.active_tab,
+            selected_sector_idx: state.selected_sector_idx,
+            chart_heights: state.chart_heights.clone(),
+            open_sections: state.open_sections.clone(),
+            hidden_series: ui::chart_utils::hidden_series(ctx),
         }
     }
 }
@@ -99,350 +510,944 @@ impl Default for ChartHeights {
 /// Shared application state
 pub struct AppState {
     pub active_tab: Tab,
-    pub market_data: MarketData,
+    /// Shared with training and export threads, which hold it for as long as they run;
+    /// edits go through `Arc::make_mut` and copy only while one does
+    pub market_data: Arc<MarketData>,
     pub analysis: AnalysisResults,
     pub selected_sector_idx: usize,
-    pub status_message: String,
+    pub heatmap_sort: HeatmapSort,
+    /// Quick-search text; also filters the dashboard heatmap table
+    pub search_query: String,
+    /// Whether the keyboard shortcuts overlay is shown
+    pub shortcuts_open: bool,
+    /// Tabs popped out into their own OS windows
+    pub detached_tabs: Vec<Tab>,
+    pub compare: CompareState,
+    pub rates: RatesState,
+    pub watchlist: WatchlistState,
+    pub data_manager: DataManagerState,
+    pub alerts: AlertsState,
+    /// Trailing window (trading days) the correlation matrix covers; `None` = full sample
+    pub corr_window: Option<usize>,
+    /// Trading days between the latest return and the end of the correlation window
+    pub corr_end_offset: usize,
+    /// Show the matrix's change versus the same window ending this many trading days
+    /// earlier; `None` shows correlation levels
+    pub corr_delta_lag: Option<usize>,
+    /// Pair of sector symbols drilled into from a correlation matrix cell
+    pub corr_pair: Option<(String, String)>,
+    /// Node positions of the correlation network in unit coordinates, by symbol;
+    /// empty until the first layout, then kept so dragged nodes stay put
+    pub corr_network: BTreeMap<String, egui::Pos2>,
+    /// Sector shown in the Risk tab's rolling beta panel
+    pub beta_sector_idx: usize,
+    /// Trailing window (trading days) of the sector view's return distribution; `None` = all
+    pub return_dist_window: Option<usize>,
+    /// Overlay the benchmark's rebased price and short-window vol on the sector charts
+    pub sector_benchmark_overlay: bool,
+    /// Sub-period (first and last date) the sector view is restricted to; `None` = full history
+    pub sector_range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    /// Trailing window (trading days) for the Risk tab's risk/return scatter
+    pub risk_return_window: usize,
+    pub status: StatusBar,
+    /// Fetch results, saves and errors from every view, newest shown in the status bar
+    pub notices: Notices,
     pub is_loading: bool,
+    /// Per-symbol status of the latest price refresh (kept afterwards for retries)
+    pub refresh_progress: Option<FetchProgress>,
+    /// Whether the refresh progress window is shown
+    pub refresh_progress_open: bool,
+    /// Every background fetch, training run and benchmark
+    pub tasks: TaskManager,
+    /// Whether the Tasks window is shown
+    pub tasks_open: bool,
+    /// Frame times, analysis timing and memory readings for the Diagnostics window
+    pub diagnostics: Diagnostics,
+    /// Level of terminal and file logging; persisted
+    pub log_level: LogLevel,
+    /// Minimize-to-tray settings and whether the window is hidden
+    pub tray: TrayState,
+    /// gRPC server settings and the running server
+    pub grpc: GrpcState,
+    /// Banner text shown after the previous session was restored from its autosave
+    pub restored_session: Option<String>,
     pub training_status: TrainingStatus,
     pub training_losses: Vec<f64>,
+    /// Per-epoch loss on the held-out validation split, synced with `training_losses`
+    pub validation_losses: Vec<f64>,
+    /// Plot the loss chart on a log10 Y axis
+    pub loss_log_scale: bool,
     pub nn_predictions: NnPredictions,
     pub compute_stats: ComputeStats,
     pub use_gpu: bool,
     pub training_progress: Option<TrainingProgress>,
     pub plot_3d: Plot3DState,
+    /// Chart series built from `analysis`, reused across frames until it is recomputed
+    pub plot_cache: PlotCache,
     pub chart_heights: ChartHeights,
+    /// Persisted open state of collapsible sections (see `chart_utils::section_open`)
+    pub open_sections: BTreeMap<String, bool>,
     /// Loaded model from disk (avoids retraining on each launch)
     pub loaded_model: Option<LoadedModel>,
     pub model_metadata: Option<ModelMetadata>,
-    /// Feedback message from the last model save/load attempt, shown in the Neural Net tab
-    pub persistence_message: Option<String>,
+    /// Feedback from the last model save/load attempt, shown in the Neural Net tab; `Err`
+    /// is shown as a warning
+    pub persistence_message: Option<Result<String, String>>,
+    /// Result of the last historical backtest of the loaded model
+    pub backtest: Option<BacktestResult>,
+    /// Input attribution of the loaded model's latest prediction
+    pub saliency: Option<Saliency>,
     /// WGPU-capable adapters (NVIDIA, AMD, Intel) detected at startup
     pub available_gpus: Vec<GpuAdapterInfo>,
+    /// Adapter name GPU training binds to (`None` = WGPU default device); persisted
+    pub gpu_adapter: Option<String>,
+    /// Weight precision for inference-only predictions; persisted
+    pub inference_precision: InferencePrecision,
+    /// Red/green or colorblind-safe semantic colors; persisted
+    pub palette_mode: PaletteMode,
+    /// Language of UI text and number and date formats; persisted
+    pub locale: Locale,
+    /// UI zoom and font size; persisted
+    pub display_settings: DisplaySettings,
+    /// Vol windows and lookback used by analysis and fetches; persisted
+    pub analysis_windows: AnalysisWindows,
+    /// Windows being edited in Settings, applied on "Apply"
+    pub analysis_windows_draft: AnalysisWindows,
+    pub api_keys: ApiKeysState,
+    pub profiles: ProfilesState,
+    pub workspaces: WorkspaceState,
+    pub custom_indicators: CustomIndicatorsState,
+    /// Analysis plugins, run with every analysis recompute
+    pub plugins: analysis::plugin::PluginRegistry,
+    /// Show definitions and formulas when hovering headline metrics; persisted
+    pub explain_metrics: bool,
     /// Shared channel for async data loading results
     pub data_receiver: Option<Arc<Mutex<Option<MarketData>>>>,
+    /// Cached data, saved model and GPU list still arriving from the startup load
+    pub startup_load: Option<StartupLoad>,
     /// NN training feature flags
     pub nn_feature_flags: NnFeatureFlags,
-    /// Screenshot capture settings (save path, format, compression)
+    /// NN training hyperparameters (hidden size, lookback, learning rate, epochs)
+    pub nn_training_config: NnTrainingConfig,
+    /// Hyperparameter search space and mode for the sweep runner
+    pub sweep_space: SweepSpace,
+    pub sweep_mode: SweepMode,
+    /// Shared progress of the active (or last finished) hyperparameter sweep
+    pub sweep_progress: Option<SweepProgress>,
+    /// Recorded training runs (oldest first), persisted to the cache
+    pub run_history: Vec<TrainingRun>,
+    /// Record for the training run in progress; moved into `run_history` when it ends
+    pub active_run: Option<TrainingRun>,
+    /// Indices into `run_history` of the two runs whose loss curves are compared
+    pub history_compare: [Option<usize>; 2],
+    /// Screenshot capture settings (save path, format, compression, scope)
     pub screenshot_settings: ScreenshotSettings,
+    /// Screenshot region being dragged out, while the selection overlay is shown
+    pub screenshot_region: Option<ui::screenshot::RegionSelect>,
+    /// Central panel area last frame, for active-tab screenshots
+    pub central_rect: Option<egui::Rect>,
+    /// Kept open once used so copied screenshots outlive the copy call
+    pub clipboard: Option<arboard::Clipboard>,
+    /// Cron-style jobs and their output folder (persisted), and the Jobs window
+    pub jobs: JobsState,
+    /// SMTP server and recipients for emailed reports. Persisted
+    pub email: EmailSettings,
     /// Result slot for the async native folder-picker dialog
     pub folder_picker_result: Option<Arc<Mutex<Option<String>>>>,
+    /// Result slot for the GPU diagnostic suite (`None` inside while it runs)
+    pub gpu_diagnostics: Option<GpuDiagnosticsSlot>,
+    /// Live vol forecasts, scored as realized vol becomes known; persisted
+    pub prediction_log: Vec<LivePrediction>,
+    /// Per-sector vol forecasts of recent refreshes, for the prediction sparklines; persisted
+    pub prediction_history: Vec<PredictionSnapshot>,
+    /// Rolling window and error threshold for the retraining warning; persisted
+    pub drift_settings: NnDriftSettings,
+    /// User chart annotations; persisted
+    pub annotations: Vec<ChartEvent>,
+    pub events: EventsState,
     /// Rolling window size for kurtosis analysis (30 or 60 trading days)
     pub kurtosis_window: usize,
 }
 
 impl Default for AppState {
     fn default() -> Self {
-        let (loaded_model, model_metadata) = match crate::nn::persistence::load_model() {
-            Some((model, meta)) => {
-                tracing::info!("Loaded saved model (trained {})", meta.trained_at);
-                (Some(model), Some(meta))
-            }
-            None => (None, None),
+        let ui_state = UiState::load();
+        let config = ConfigFile::load();
+        config::set_universe(config.universe.universe());
+        crate::data::cache::set_max_age_hours(config.cache.max_age_hours);
+        crate::logging::set_level(config.logging.level);
+        let analysis_windows = Some(config.windows)
+            .filter(|w| w.validate().is_ok())
+            .unwrap_or_default();
+        let custom_indicators = CustomIndicatorsState {
+            indicators: analysis::custom_indicators::load(),
+            ..Default::default()
         };
 
-        let available_gpus = crate::nn::gpu::detect_wgpu_adapters();
-        let use_gpu = !available_gpus.is_empty();
-
-        Self {
-            active_tab: Tab::Dashboard,
-            market_data: MarketData::default(),
+        let mut state = Self {
+            active_tab: ui_state.active_tab,
+            market_data: Arc::default(),
             analysis: AnalysisResults::default(),
-            selected_sector_idx: 0,
-            status_message: "Ready. Click 'Refresh Data' to fetch market data.".to_string(),
+            selected_sector_idx: ui_state.selected_sector_idx,
+            heatmap_sort: HeatmapSort::default(),
+            search_query: String::new(),
+            shortcuts_open: false,
+            detached_tabs: Vec::new(),
+            compare: CompareState::default(),
+            rates: RatesState::default(),
+            watchlist: WatchlistState {
+                symbols: config.universe.watchlist,
+                ..WatchlistState::default()
+            },
+            data_manager: DataManagerState {
+                excluded: config.universe.excluded,
+                ..DataManagerState::default()
+            },
+            alerts: AlertsState {
+                rules: crate::analysis::alerts::load_rules(),
+                history: crate::analysis::alerts::load_history(),
+                webhooks: config.alerts.webhooks.clone(),
+                ..AlertsState::default()
+            },
+            corr_window: None,
+            corr_end_offset: 0,
+            corr_delta_lag: None,
+            corr_pair: None,
+            corr_network: BTreeMap::new(),
+            beta_sector_idx: 0,
+            return_dist_window: Some(252),
+            sector_benchmark_overlay: false,
+            sector_range: None,
+            risk_return_window: 63,
+            status: StatusBar::default(),
+            notices: Notices::default(),
             is_loading: false,
+            refresh_progress: None,
+            refresh_progress_open: false,
+            tasks: TaskManager::default(),
+            tasks_open: false,
+            diagnostics: Diagnostics::default(),
+            log_level: config.logging.level,
+            tray: TrayState { settings: config.tray.clone(), ..Default::default() },
+            grpc: GrpcState::new(config.grpc.clone()),
+            restored_session: None,
             training_status: TrainingStatus::Idle,
             training_losses: vec![],
+            validation_losses: vec![],
+            loss_log_scale: false,
             nn_predictions: NnPredictions::default(),
             compute_stats: ComputeStats::default(),
-            use_gpu,
+            use_gpu: false,
             training_progress: None,
             plot_3d: Plot3DState::default(),
-            chart_heights: ChartHeights::default(),
-            loaded_model,
-            model_metadata,
+            plot_cache: PlotCache::default(),
+            chart_heights: ui_state.chart_heights,
+            open_sections: ui_state.open_sections,
+            loaded_model: None,
+            model_metadata: None,
             persistence_message: None,
-            available_gpus,
+            backtest: None,
+            saliency: None,
+            available_gpus: Vec::new(),
+            gpu_adapter: config.nn.gpu_adapter,
+            inference_precision: config.nn.inference_precision,
+            palette_mode: config.theme.palette,
+            locale: config.theme.locale,
+            display_settings: config.theme.display,
+            analysis_windows,
+            analysis_windows_draft: analysis_windows,
+            api_keys: ApiKeysState { environments: crate::data::api_keys::load_saved(), ..ApiKeysState::default() },
+            profiles: ProfilesState {
+                profiles: ui::profiles::load_profiles(),
+                ..Default::default()
+            },
+            workspaces: WorkspaceState::default(),
+            nn_feature_flags: NnFeatureFlags {
+                custom_indicators: custom_indicators.nn_features(),
+                ..config.nn.features
+            },
+            custom_indicators,
+            plugins: analysis::plugin::registry(),
+            explain_metrics: config.theme.explain_metrics,
             data_receiver: None,
-            nn_feature_flags: NnFeatureFlags::default(),
-            screenshot_settings: crate::data::cache::load_json("screenshot_settings.json")
-                .unwrap_or_default(),
+            startup_load: None,
+            nn_training_config: config.nn.training,
+            sweep_space: SweepSpace::default(),
+            sweep_mode: SweepMode::Grid,
+            sweep_progress: None,
+            run_history: crate::nn::history::load_history(),
+            active_run: None,
+            history_compare: [None, None],
+            screenshot_settings: config.screenshot,
+            screenshot_region: None,
+            central_rect: None,
+            clipboard: None,
+            jobs: JobsState { jobs: config.scheduler.jobs, output_dir: config.scheduler.output_dir, open: false },
+            email: config.email,
             folder_picker_result: None,
+            gpu_diagnostics: None,
+            prediction_log: crate::nn::drift::load_log(),
+            prediction_history: crate::nn::prediction_history::load_history(),
+            drift_settings: config.nn.drift,
+            annotations: crate::data::events::load_annotations(),
+            events: EventsState::default(),
             kurtosis_window: 30,
-        }
+        };
+        state.startup_load = Some(StartupLoad::spawn(&mut state.tasks));
+        state
     }
 }
 
 impl AppState {
-    /// Recompute all analysis from current market data
-    pub fn recompute_analysis(&mut self) {
-        let mut vol_metrics = Vec::new();
-        for sector in &self.market_data.sectors {
-            if sector.bars.len() < config::LONG_VOL_WINDOW + 2 {
-                continue;
-            }
-            let dates = sector.dates();
-            let log_ret = sector.log_returns();
-            let highs = sector.highs();
-            let lows = sector.lows();
-
-            let vm = analysis::volatility::compute_sector_volatility(
-                &sector.symbol,
-                &dates,
-                &log_ret,
-                &highs,
-                &lows,
-                config::SHORT_VOL_WINDOW,
-                config::LONG_VOL_WINDOW,
-            );
-            vol_metrics.push(vm);
+    /// Write every runtime setting to `config.toml`
+    pub fn save_config(&self) -> Result<(), String> {
+        ConfigFile::capture(self).save()
+    }
+
+    /// Move excluded sectors' series out of `market_data.sectors` into the data manager's
+    /// set-aside list, and re-included ones back, keeping config order. Returns whether
+    /// any series moved (the caller then recomputes analysis).
+    pub fn apply_exclusions(&mut self) -> bool {
+        let before: Vec<String> = self.market_data.sectors.iter().map(|s| s.symbol.clone()).collect();
+        let market_data = Arc::make_mut(&mut self.market_data);
+        let mut all: Vec<SectorTimeSeries> = std::mem::take(&mut market_data.sectors);
+        all.append(&mut self.data_manager.set_aside);
+        let order = |s: &SectorTimeSeries| config::sector_etfs().iter().position(|etf| etf.symbol == s.symbol);
+        all.sort_by_key(order);
+        let (set_aside, sectors) = all.into_iter().partition(|s| self.data_manager.excluded.contains(&s.symbol));
+        market_data.sectors = sectors;
+        self.data_manager.set_aside = set_aside;
+        let moved = self.market_data.sectors.iter().map(|s| &s.symbol).ne(before.iter());
+        if moved {
+            self.selected_sector_idx = self.selected_sector_idx.min(self.market_data.sectors.len().saturating_sub(1));
         }
+        moved
+    }
 
-        // Correlation matrix
-        let symbols: Vec<String> = self
-            .market_data
-            .sectors
-            .iter()
-            .map(|s| s.symbol.clone())
-            .collect();
-        let returns: Vec<Vec<f64>> = self
+    /// Write the full analysis (per-sector bars and vols, correlation, spreads,
+    /// predictions) as a multi-sheet Excel workbook
+    pub fn export_workbook(&self) -> Result<std::path::PathBuf, String> {
+        let sheets = crate::data::export::analysis_sheets(
+            &self.market_data.sectors,
+            &self.analysis.volatility,
+            self.analysis.correlation.as_ref(),
+            &self.analysis.bond_spreads,
+            &self.nn_predictions,
+        );
+        crate::data::export::write_xlsx("analysis", &sheets)
+    }
+
+    /// Write the per-sector bar and vol series, and the spread history, as Arrow IPC
+    /// files for polars/pandas. Returns the paths written.
+    pub fn export_arrow(&self) -> Result<Vec<std::path::PathBuf>, String> {
+        use crate::data::export;
+        let series = self
             .market_data
             .sectors
             .iter()
-            .map(|s| s.log_returns())
+            .map(|s| export::sector_series_batch(s, self.analysis.volatility.iter().find(|v| v.symbol == s.symbol)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut paths = vec![export::write_arrow("sector_series", &series)?];
+        if !self.analysis.bond_spreads.is_empty() {
+            paths.push(export::write_arrow("spreads", &[export::spreads_batch(&self.analysis.bond_spreads)?])?);
+        }
+        Ok(paths)
+    }
+
+    /// Write the NN feature matrix and targets, built with the current lookback and
+    /// feature set exactly as training sees them, to a Parquet file
+    pub fn export_feature_dataset(&self) -> Result<std::path::PathBuf, String> {
+        let dataset = crate::nn::dataset::build_dataset(
+            &self.market_data,
+            self.nn_training_config.lookback,
+            crate::config::NN_FORWARD_DAYS,
+            &self.nn_feature_flags,
+        );
+        if dataset.samples.is_empty() {
+            return Err(tr("nn-export-features-no-data"));
+        }
+        let feature_names = crate::nn::dataset::feature_schema(&self.nn_feature_flags);
+        crate::data::export::write_feature_parquet("nn_features", &dataset, &feature_names)
+    }
+
+    /// Pick up finished background tasks and report failures as notices. A
+    /// refresh that failed or was cancelled ends the loading state.
+    pub fn poll_tasks(&mut self, ctx: &egui::Context) {
+        let finished: Vec<(TaskId, TaskKind, String, TaskStatus, Duration)> = self
+            .tasks
+            .poll(ctx)
+            .into_iter()
+            .map(|t| (t.id, t.kind, t.label.clone(), t.status.clone(), t.elapsed()))
             .collect();
-        let corr = analysis::cross_sector::compute_correlation_matrix(&symbols, &returns);
-        let avg_corr = analysis::cross_sector::average_cross_correlation(&corr);
-
-        // Bond spreads
-        let spreads = analysis::bond_spreads::compute_term_spreads(&self.market_data.treasury_rates);
-
-        // Kurtosis
-        let mut kurtosis_metrics = Vec::new();
-        for sector in &self.market_data.sectors {
-            let log_ret = sector.log_returns();
-            if log_ret.len() < self.kurtosis_window {
-                continue;
+        for (id, kind, label, status, elapsed) in finished {
+            self.record_job_result(id, &status, elapsed);
+            match &status {
+                // Refetches report their own outcome when they are swapped in
+                _ if kind == TaskKind::Refetch => {}
+                TaskStatus::Failed(e) => {
+                    let message = tr_args("task-failed-notice", &[("task", label), ("error", e.clone())]);
+                    let mut notice = Notice::new(Severity::Error, notice_source(kind), message);
+                    match kind {
+                        TaskKind::Refresh => notice = notice.with_retry(RetryAction::Refresh),
+                        TaskKind::Training => notice = notice.with_retry(RetryAction::Train),
+                        _ => {}
+                    }
+                    self.notices.push(notice);
+                }
+                TaskStatus::Cancelled => {
+                    self.notices.info(notice_source(kind), tr_args("task-cancelled-notice", &[("task", label)]))
+                }
+                TaskStatus::Done | TaskStatus::Running => {}
+            }
+            if kind == TaskKind::Refresh && status != TaskStatus::Done {
+                self.is_loading = false;
+                self.data_receiver = None;
             }
-            let dates = sector.dates();
-            // Use dates offset by 1 to align with log returns
-            let ret_dates = if dates.len() > 1 { &dates[1..] } else { &dates };
-            let km = analysis::kurtosis::compute_sector_kurtosis(
-                &sector.symbol,
-                ret_dates,
-                &log_ret,
-                self.kurtosis_window,
-            );
-            kurtosis_metrics.push(km);
         }
+    }
 
-        // Randomness metrics
-        let mut randomness_metrics = Vec::new();
-        for sector in &self.market_data.sectors {
-            let log_ret = sector.log_returns();
-            if log_ret.len() >= 20 {
-                randomness_metrics.push(
-                    analysis::randomness::compute_sector_randomness(&sector.symbol, &log_ret),
-                );
+    /// Record the outcome of the job run whose background task `id` just ended
+    fn record_job_result(&mut self, id: TaskId, status: &TaskStatus, elapsed: Duration) {
+        let Some(job) = self.jobs.jobs.iter_mut().find(|j| j.task == Some(id)) else {
+            return;
+        };
+        let (ok, message) = match status {
+            TaskStatus::Done => {
+                (true, tr_args("task-finished-in", &[("seconds", i18n::number(elapsed.as_secs_f64(), 0))]))
             }
+            TaskStatus::Failed(e) => (false, e.clone()),
+            TaskStatus::Cancelled => (false, tr("task-cancelled")),
+            TaskStatus::Running => return,
+        };
+        job.task = None;
+        job.last_result = Some(JobResult { at: chrono::Local::now().naive_local(), ok, message });
+        if let Err(e) = self.save_config() {
+            tracing::warn!("Failed to save job result: {}", e);
         }
+    }
 
-        self.analysis = AnalysisResults {
-            volatility: vol_metrics,
-            correlation: Some(corr),
-            bond_spreads: spreads,
-            avg_cross_correlation: avg_corr,
-            kurtosis: kurtosis_metrics,
-            randomness: randomness_metrics,
+    /// Swap finished single-symbol refetches into the market data and recompute analysis
+    pub fn collect_refetches(&mut self) {
+        let finished: Vec<_> = match self.data_manager.results.lock() {
+            Ok(mut results) => results.drain(..).collect(),
+            Err(_) => return,
         };
+        if finished.is_empty() {
+            return;
+        }
+        let mut updated = false;
+        for (symbol, result) in finished {
+            self.data_manager.pending.retain(|s| *s != symbol);
+            if let Some(progress) = &self.refresh_progress {
+                progress.record(&symbol, &result);
+            }
+            let outcome = FetchOutcome::of(&result);
+            self.status.health.record_refresh(&[(DataSource::Yahoo, outcome.clone())]);
+            let market_data = Arc::make_mut(&mut self.market_data);
+            market_data.refresh_report.record(DataSource::Yahoo, &symbol, outcome, 1);
+            match result {
+                Ok(fetched) if symbol == config::benchmark_symbol() => {
+                    market_data.benchmark = Some(fetched.data);
+                    updated = true;
+                    self.notices.info(NoticeSource::Data, tr_args("refresh-refetched", &[("symbol", symbol.clone())]));
+                }
+                Ok(fetched) => {
+                    market_data.sectors.retain(|s| s.symbol != symbol);
+                    self.data_manager.set_aside.retain(|s| s.symbol != symbol);
+                    market_data.sectors.push(fetched.data);
+                    self.apply_exclusions();
+                    updated = true;
+                    self.notices.info(NoticeSource::Data, tr_args("refresh-refetched", &[("symbol", symbol.clone())]));
+                }
+                Err(e) => {
+                    let message =
+                        tr_args("refresh-refetch-failed", &[("symbol", symbol.clone()), ("error", format!("{:#}", e))]);
+                    self.notices.push(
+                        Notice::new(Severity::Error, NoticeSource::Data, message).with_retry(RetryAction::Refetch(symbol)),
+                    );
+                }
+            }
+        }
+        if updated {
+            self.recompute_analysis();
+        }
+    }
+
+    /// Email the report to the configured recipients in the background. Returns the
+    /// number of recipients and the sending task.
+    pub fn email_report(&mut self) -> Result<(usize, TaskId), String> {
+        let recipients = self.email.recipient_list()?.len();
+        let (html, images) = ui::report::render_email(self)?;
+        let task = crate::data::email::send_report(&mut self.tasks, &self.email, html, images)?;
+        Ok((recipients, task))
+    }
+
+    /// Device for the next training run, from the CPU/GPU toggle and the selected adapter
+    pub fn compute_device(&self) -> ComputeDevice {
+        if self.use_gpu {
+            ComputeDevice::Gpu { adapter: self.gpu_adapter.clone() }
+        } else {
+            ComputeDevice::Cpu
+        }
+    }
+
+    /// Forecast the latest market data with the loaded model (avoids retraining), unless
+    /// its feature flags no longer match the current ones
+    pub fn run_model_inference(&mut self) {
+        let flags_check = self
+            .model_metadata
+            .as_ref()
+            .map_or(Ok(()), |m| m.check_feature_flags(&self.nn_feature_flags));
+        let mut predicted = false;
+        if let Err(msg) = flags_check {
+            self.persistence_message = Some(Err(msg));
+        } else if let Some(preds) = self.latest_predictions() {
+            if !preds.is_empty() {
+                predicted = true;
+                self.nn_predictions = preds;
+                self.record_prediction_history();
+                if let Some(ref meta) = self.model_metadata {
+                    self.training_status =
+                        crate::data::models::TrainingStatus::Complete { final_loss: meta.final_loss };
+                }
+            }
+        }
+        self.track_live_predictions(predicted);
+    }
+
+    /// Take whatever the startup load has read since the last frame. Each cached sector
+    /// joins the market data with its own metrics right away, so the dashboard fills in
+    /// as files arrive; the full analysis runs once the last one is in.
+    pub fn collect_startup_load(&mut self) {
+        let Some(load) = &self.startup_load else {
+            return;
+        };
+        let wants_data = load.wants_data;
+        let batch = load.take();
+
+        if wants_data && (!batch.series.is_empty() || batch.extras.is_some()) {
+            let market_data = Arc::make_mut(&mut self.market_data);
+            market_data.refresh_report.items.extend(batch.report.items);
+            for series in batch.series {
+                if series.symbol == config::benchmark_symbol() {
+                    market_data.benchmark = Some(series);
+                } else if self.data_manager.excluded.contains(&series.symbol) {
+                    self.data_manager.set_aside.push(series);
+                } else {
+                    let (vol, randomness, risk) =
+                        analysis::sector_metrics(&series, &series.log_returns(), self.analysis_windows);
+                    self.analysis.volatility.extend(vol);
+                    self.analysis.randomness.extend(randomness);
+                    self.analysis.risk.push(risk);
+                    market_data.sectors.push(series);
+                }
+            }
+            if let Some(extras) = batch.extras {
+                market_data.treasury_rates = extras.treasury_rates;
+                market_data.sector_performance = extras.sector_performance;
+                market_data.put_call_ratio = extras.put_call_ratio;
+                market_data.skew_history = extras.skew_history;
+            }
+            self.plot_cache.invalidate();
+        }
+        if wants_data && batch.data_done && !self.market_data.sectors.is_empty() {
+            self.recompute_analysis();
+            let message = tr_args("refresh-cache-loaded", &[("count", i18n::integer(self.market_data.sectors.len()))]);
+            self.notices.info(NoticeSource::Data, message);
+        }
+
+        if let Some((model, metadata)) = batch.model.filter(|_| self.loaded_model.is_none()) {
+            self.loaded_model = Some(model);
+            self.model_metadata = Some(metadata);
+            // A refresh that landed before the model made no forecasts
+            if self.market_data.last_refresh.is_some() {
+                self.run_model_inference();
+            }
+        }
+        if let Some(gpus) = batch.gpus {
+            self.use_gpu = !gpus.is_empty();
+            self.available_gpus = gpus;
+            self.startup_load = None;
+        }
+    }
+
+    /// Predictions for the latest market data from the loaded model, using the quantized
+    /// checkpoint when a reduced inference precision is selected.
+    pub fn latest_predictions(&self) -> Option<NnPredictions> {
+        if self.inference_precision != InferencePrecision::Full {
+            if let Some((model, meta)) = crate::nn::persistence::load_quantized_model(self.inference_precision) {
+                return Some(crate::nn::training::run_quantized_inference(
+                    &model,
+                    &self.market_data,
+                    &self.nn_feature_flags,
+                    Some(&meta),
+                ));
+            }
+        }
+        let model = self.loaded_model.as_ref()?;
+        Some(crate::nn::training::run_inference(
+            model,
+            &self.market_data,
+            &self.nn_feature_flags,
+            self.model_metadata.as_ref(),
+        ))
+    }
+
+    /// Score pending entries of the prediction log against the current market data and,
+    /// if `record_current`, log the loaded model's current vol forecast.
+    pub fn track_live_predictions(&mut self, record_current: bool) {
+        let mut changed = crate::nn::drift::resolve(&mut self.prediction_log, &self.market_data) > 0;
+        if let Some(meta) = self.model_metadata.as_ref().filter(|_| record_current) {
+            if let Some(entry) = crate::nn::drift::live_prediction(
+                &self.nn_predictions,
+                &self.market_data,
+                meta.training_config.vol_target,
+                &meta.trained_at,
+            ) {
+                crate::nn::drift::record(&mut self.prediction_log, entry);
+                crate::nn::drift::resolve(&mut self.prediction_log, &self.market_data);
+                changed = true;
+            }
+        }
+        if changed {
+            if let Err(e) = crate::nn::drift::save_log(&self.prediction_log) {
+                tracing::warn!("{}", e);
+            }
+        }
+    }
+
+    /// Append the current per-sector vol forecasts to the prediction history
+    pub fn record_prediction_history(&mut self) {
+        let now = chrono::Local::now().to_rfc3339();
+        if crate::nn::prediction_history::record(&mut self.prediction_history, &self.nn_predictions, now) {
+            if let Err(e) = crate::nn::prediction_history::save_history(&self.prediction_history) {
+                tracing::warn!("{}", e);
+            }
+        }
+    }
+
+    /// Rolling live error of the loaded model's logged predictions
+    pub fn drift_status(&self) -> Option<DriftStatus> {
+        let meta = self.model_metadata.as_ref()?;
+        Some(crate::nn::drift::drift_status(&self.prediction_log, &meta.trained_at, &self.drift_settings))
+    }
+
+    /// Check alert rules against the latest analysis and predictions, recording and
+    /// queueing a toast for each newly triggered one.
+    pub fn evaluate_alerts(&mut self) {
+        let inputs = crate::analysis::alerts::AlertInputs {
+            volatility: &self.analysis.volatility,
+            avg_correlation: self.analysis.correlation.as_ref().map(|_| self.analysis.avg_cross_correlation),
+            bond_spreads: &self.analysis.bond_spreads,
+            predictions: &self.nn_predictions,
+        };
+        let was_triggered: Vec<bool> = self.alerts.rules.iter().map(|r| r.triggered).collect();
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let fired = crate::analysis::alerts::evaluate(&mut self.alerts.rules, &inputs, &now);
+
+        if self.alerts.rules.iter().map(|r| r.triggered).ne(was_triggered) {
+            if let Err(e) = crate::analysis::alerts::save_rules(&self.alerts.rules) {
+                tracing::warn!("{}", e);
+            }
+        }
+        if fired.is_empty() {
+            return;
+        }
+        crate::analysis::alerts::record(&mut self.alerts.history, &fired);
+        if let Err(e) = crate::analysis::alerts::save_history(&self.alerts.history) {
+            tracing::warn!("{}", e);
+        }
+        self.alerts.unread += fired.len();
+        self.alerts
+            .toasts
+            .extend(fired.iter().map(|e| AlertToast { message: e.message(), shown_at: None }));
+        if self.tray.hidden && self.tray.settings.native_notifications {
+            for event in &fired {
+                ui::tray::notify(&tr("tray-alert"), &event.message());
+            }
+        }
+        crate::data::webhooks::deliver(&mut self.tasks, &self.alerts.webhooks, &fired);
+    }
+
+    /// Built-in calendar events, detected curve inversions and user annotations whose
+    /// kind isn't hidden, for drawing as chart markers
+    pub fn chart_events(&self) -> Vec<ChartEvent> {
+        let mut events = crate::data::events::builtin_events();
+        events.extend(crate::data::events::inversion_events(&self.analysis.bond_spreads));
+        events.extend(self.annotations.iter().cloned());
+        events.retain(|e| !self.events.hidden.contains(&e.kind));
+        events
+    }
+
+    /// Display name of the adapter GPU training will use
+    pub fn gpu_adapter_name(&self) -> Option<&str> {
+        self.gpu_adapter
+            .as_deref()
+            .or_else(|| self.available_gpus.first().map(|a| a.name.as_str()))
+    }
+
+    /// Recompute all analysis from current market data
+    pub fn recompute_analysis(&mut self) {
+        let started = Instant::now();
+        let windows = self.analysis_windows;
+        let mut results = analysis::compute_all(&self.market_data, windows, self.kurtosis_window);
+        (results.custom, results.custom_errors) = analysis::custom_indicators::evaluate_all(
+            &self.custom_indicators.indicators,
+            &self.market_data.sectors,
+            windows.short_vol,
+            windows.long_vol,
+        );
+        results.plugins = self.plugins.run_all(&self.market_data);
+        // Report each indicator or plugin failure once, not again on every recompute
+        let previous_errors = analysis_errors(&self.analysis);
+        self.analysis = results;
+        for (name, e) in analysis_errors(&self.analysis) {
+            if !previous_errors.contains(&(name.clone(), e.clone())) {
+                let message = tr_args("notice-analysis-failed", &[("name", name), ("error", e)]);
+                self.notices.error(NoticeSource::Analysis, message);
+            }
+        }
+        self.diagnostics.last_analysis = Some(started.elapsed());
+        self.plot_cache.invalidate();
 
         // Signal the 3D plot needs a redraw with new data
         self.plot_3d.needs_redraw = true;
+        crate::grpc::publish(self);
     }
 
     /// Recompute only kurtosis metrics using the current `kurtosis_window`.
     /// Much faster than `recompute_analysis()` — avoids recalculating vol, bonds, correlations.
     pub fn recompute_kurtosis(&mut self) {
-        let mut kurtosis_metrics = Vec::new();
-        for sector in &self.market_data.sectors {
-            let log_ret = sector.log_returns();
-            if log_ret.len() < self.kurtosis_window {
-                continue;
-            }
-            let dates = sector.dates();
-            let ret_dates = if dates.len() > 1 { &dates[1..] } else { &dates };
-            let km = analysis::kurtosis::compute_sector_kurtosis(
-                &sector.symbol,
-                ret_dates,
-                &log_ret,
-                self.kurtosis_window,
-            );
-            kurtosis_metrics.push(km);
+        self.analysis.kurtosis = analysis::compute_kurtosis(&self.market_data.sectors, self.kurtosis_window);
+    }
+}
+
+/// Custom indicator and plugin failures of an analysis, as (name, error)
+fn analysis_errors(analysis: &AnalysisResults) -> Vec<(String, String)> {
+    let plugins = analysis.plugins.iter().filter_map(|p| p.output.as_ref().err().map(|e| (p.name.clone(), e.clone())));
+    analysis.custom_errors.iter().cloned().chain(plugins).collect()
+}
+
+/// Task progress of a price refresh: symbols done out of all symbols
+fn fetch_progress(progress: FetchProgress) -> impl Fn() -> TaskProgress {
+    move || {
+        let entries = progress.snapshot();
+        let done = entries.iter().filter(|(_, s)| s.is_done()).count();
+        TaskProgress {
+            fraction: (!entries.is_empty()).then(|| done as f32 / entries.len() as f32),
+            detail: tr_args("task-symbols", &[("done", i18n::integer(done)), ("total", i18n::integer(entries.len()))]),
         }
-        self.analysis.kurtosis = kurtosis_metrics;
+    }
+}
+
+/// Part of the app a background task's failure is reported under
+fn notice_source(kind: TaskKind) -> NoticeSource {
+    match kind {
+        TaskKind::Startup | TaskKind::Refresh | TaskKind::Refetch | TaskKind::Watchlist => NoticeSource::Data,
+        TaskKind::Training | TaskKind::Sweep | TaskKind::GpuDiagnostics => NoticeSource::Training,
+        TaskKind::Email => NoticeSource::Export,
+        TaskKind::ApiTest | TaskKind::Webhook | TaskKind::Grpc => NoticeSource::Settings,
     }
 }
 
 /// Main application struct for eframe
 pub struct MktNoiseApp {
     pub state: AppState,
-    pub tokio_rt: tokio::runtime::Runtime,
-}
-
-/// Encode and write a screenshot to disk under `settings.save_path`.
-///
-/// The filename is `YYYYMMDD_HHMMSS.{ext}`. Returns the full path on success.
-fn save_screenshot(
-    image: &egui::ColorImage,
-    settings: &ScreenshotSettings,
-) -> Result<String, String> {
-    use std::io::BufWriter;
-    use crate::data::models::{ScreenshotCompression, ScreenshotFileType};
-
-    std::fs::create_dir_all(&settings.save_path)
-        .map_err(|e| format!("Failed to create directory '{}': {}", settings.save_path, e))?;
-
-    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
-    let ext = match settings.file_type {
-        ScreenshotFileType::Png => "png",
-        ScreenshotFileType::Jpeg => "jpg",
-        ScreenshotFileType::Tiff => "tif",
-    };
-    let path = std::path::Path::new(&settings.save_path).join(format!("{timestamp}.{ext}"));
-
-    let width = image.size[0] as u32;
-    let height = image.size[1] as u32;
-    let pixels: Vec<u8> = image
-        .pixels
-        .iter()
-        .flat_map(|c| [c.r(), c.g(), c.b(), c.a()])
-        .collect();
-    let rgba = image::RgbaImage::from_raw(width, height, pixels)
-        .ok_or_else(|| "Failed to create image buffer from pixel data".to_string())?;
-
-    let file = std::fs::File::create(&path)
-        .map_err(|e| format!("Failed to create file '{}': {}", path.display(), e))?;
-    let mut writer = BufWriter::new(file);
-
-    match settings.file_type {
-        ScreenshotFileType::Png => {
-            use image::codecs::png::{CompressionType, FilterType, PngEncoder};
-            let compression = match settings.compression {
-                ScreenshotCompression::None => CompressionType::Fast,
-                ScreenshotCompression::Low => CompressionType::Default,
-                ScreenshotCompression::High => CompressionType::Best,
-            };
-            let encoder = PngEncoder::new_with_quality(&mut writer, compression, FilterType::Sub);
-            image::DynamicImage::ImageRgba8(rgba)
-                .write_with_encoder(encoder)
-                .map_err(|e| format!("PNG encode failed: {e}"))?;
-        }
-        ScreenshotFileType::Jpeg => {
-            use image::codecs::jpeg::JpegEncoder;
-            let quality: u8 = match settings.compression {
-                ScreenshotCompression::None => 100,
-                ScreenshotCompression::Low => 80,
-                ScreenshotCompression::High => 50,
-            };
-            // JPEG does not support an alpha channel — convert to RGB first
-            let rgb = image::DynamicImage::ImageRgba8(rgba).to_rgb8();
-            let encoder = JpegEncoder::new_with_quality(&mut writer, quality);
-            image::DynamicImage::ImageRgb8(rgb)
-                .write_with_encoder(encoder)
-                .map_err(|e| format!("JPEG encode failed: {e}"))?;
-        }
-        ScreenshotFileType::Tiff => {
-            use image::codecs::tiff::TiffEncoder;
-            // TIFF compression is not directly controllable via this encoder; the
-            // compression setting is informational (visible in the settings tooltip).
-            let encoder = TiffEncoder::new(&mut writer);
-            image::DynamicImage::ImageRgba8(rgba)
-                .write_with_encoder(encoder)
-                .map_err(|e| format!("TIFF encode failed: {e}"))?;
-        }
-    }
-
-    Ok(path.to_string_lossy().into_owned())
+    /// Whether the persisted display settings have been applied to the egui context
+    display_applied: bool,
+    /// Layout as last written to disk, and when (`egui` input time, seconds)
+    saved_ui_state: UiState,
+    ui_state_saved_at: f64,
+    /// `egui` input time of the last session autosave
+    autosaved_at: f64,
 }
 
 impl Default for MktNoiseApp {
     fn default() -> Self {
-        let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
         Self {
             state: AppState::default(),
-            tokio_rt: rt,
+            display_applied: false,
+            saved_ui_state: UiState::load(),
+            ui_state_saved_at: 0.0,
+            autosaved_at: 0.0,
         }
     }
 }
 
 impl MktNoiseApp {
-    fn start_data_fetch(&mut self) {
-        if self.state.is_loading {
-            return;
+    /// Draw each detached tab in its own OS window. Closing a window reattaches the tab.
+    fn show_detached_tabs(&mut self, ctx: &egui::Context) {
+        let mut closed = Vec::new();
+        for tab in self.state.detached_tabs.clone() {
+            let id = egui::ViewportId::from_hash_of(("detached_tab", tab.message_id()));
+            let builder = egui::ViewportBuilder::default()
+                .with_title(format!("Volume Analysis - {}", tab.label()))
+                .with_inner_size([1000.0, 750.0]);
+            ctx.show_viewport_immediate(id, builder, |ctx, _class| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    egui::ScrollArea::vertical()
+                        .auto_shrink(false)
+                        .show(ui, |ui| tab.render(ui, &mut self.state));
+                });
+                if ctx.input(|i| i.viewport().close_requested()) {
+                    closed.push(tab);
+                }
+            });
         }
-        self.state.is_loading = true;
-        self.state.status_message = "Fetching market data...".to_string();
-
-        let result_slot: Arc<Mutex<Option<MarketData>>> = Arc::new(Mutex::new(None));
-        self.state.data_receiver = Some(result_slot.clone());
+        self.state.detached_tabs.retain(|t| !closed.contains(t));
+    }
 
-        self.tokio_rt.spawn(async move {
-            let mut market_data = MarketData::default();
+    /// Write the layout to the cache when it changed, at most once per second so
+    /// dragging the window or a height control doesn't write every frame
+    fn persist_ui_state(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        if now - self.ui_state_saved_at < 1.0 {
+            return;
+        }
+        let current = UiState::capture(&self.state, ctx);
+        if current == self.saved_ui_state {
+            return;
+        }
+        if let Err(e) = crate::data::cache::save_json(UI_STATE_FILENAME, &current) {
+            tracing::warn!("Failed to save UI state: {}", e);
+        }
+        self.saved_ui_state = current;
+        self.ui_state_saved_at = now;
+    }
 
-            // Fetch sector ETFs
-            let results = crate::data::yahoo::fetch_all_sectors(
-                config::SECTOR_ETFS,
-                config::DEFAULT_LOOKBACK_DAYS,
-            )
-            .await;
+    /// Save the session essentials for crash recovery every `AUTOSAVE_INTERVAL_SECS`
+    fn autosave_session(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        if now - self.autosaved_at < autosave::AUTOSAVE_INTERVAL_SECS {
+            return;
+        }
+        if let Err(e) = autosave::SessionSnapshot::capture(&self.state, ctx).save() {
+            tracing::warn!("{}", e);
+        }
+        self.autosaved_at = now;
+    }
 
-            for (sym, result) in results {
-                match result {
-                    Ok(series) => market_data.sectors.push(series),
-                    Err(e) => tracing::warn!("Failed to fetch {}: {}", sym, e),
-                }
+    /// Start the jobs that are due, auto-refresh included, and wake up again when the
+    /// next one is
+    fn run_scheduler(&mut self, ctx: &egui::Context) {
+        // Jobs wait for any refresh in progress so they see fresh data
+        if self.state.is_loading {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+            return;
+        }
+        let now = chrono::Local::now().naive_local();
+        let mut due = Vec::new();
+        for (i, job) in self.state.jobs.jobs.iter().enumerate() {
+            match job.due_in(now) {
+                Some(wait) if wait.is_zero() => due.push(i),
+                Some(wait) => ctx.request_repaint_after(wait),
+                None => {}
             }
+        }
+        for i in due {
+            self.run_job(ctx, i);
+        }
+    }
 
-            // Fetch benchmark
-            match crate::data::yahoo::fetch_symbol_history(
-                config::BENCHMARK_SYMBOL,
-                "S&P 500",
-                config::DEFAULT_LOOKBACK_DAYS,
-            )
-            .await
-            {
-                Ok(bench) => market_data.benchmark = Some(bench),
-                Err(e) => tracing::warn!("Failed to fetch benchmark: {}", e),
+    /// Run job `i` now and record its result. Work that continues in the background
+    /// records its final result when its task ends.
+    fn run_job(&mut self, ctx: &egui::Context, i: usize) {
+        let Some(job) = self.state.jobs.jobs.get(i) else {
+            return;
+        };
+        let name = job.name.clone();
+        let dir = self.state.jobs.dated_dir(chrono::Local::now().date_naive());
+        let outcome: Result<(Option<TaskId>, String), String> = match job.action {
+            JobAction::Refresh if self.state.is_loading => Err(tr("job-refresh-busy")),
+            JobAction::Refresh => Ok((self.spawn_data_fetch(), tr("job-refresh-started"))),
+            JobAction::Retrain => {
+                ui::nn_view::start_scheduled_training(&mut self.state).map(|id| (Some(id), tr("job-training-started")))
             }
-
-            // Fetch treasury rates
-            match crate::data::fmp::fetch_treasury_rates(&config::fmp_api_key()).await {
-                Ok(rates) => market_data.treasury_rates = rates,
-                Err(e) => tracing::warn!("Failed to fetch treasury rates: {:?}", e),
+            JobAction::Report => ui::report::generate_in(&self.state, &dir)
+                .map(|path| (None, tr_args("job-saved", &[("path", path.display().to_string())]))),
+            JobAction::EmailReport => self
+                .state
+                .email_report()
+                .map(|(n, id)| (Some(id), tr_args("job-emailing", &[("count", i18n::integer(n))]))),
+            JobAction::Screenshot => {
+                ui::screenshot::capture_dashboard_to(ctx, &mut self.state, dir.clone());
+                Ok((None, tr_args("job-capturing", &[("path", dir.display().to_string())])))
             }
+        };
 
-            // Fetch sector performance
-            match crate::data::fmp::fetch_sector_performance(&config::fmp_api_key()).await {
-                Ok(perf) => market_data.sector_performance = perf,
-                Err(e) => tracing::warn!("Failed to fetch sector performance: {}", e),
-            }
+        let now = chrono::Local::now().naive_local();
+        let (task, result) = match outcome {
+            Ok((task, message)) => (task, JobResult { at: now, ok: true, message }),
+            Err(message) => (None, JobResult { at: now, ok: false, message }),
+        };
+        let message = tr_args("job-notice", &[("name", name), ("message", result.message.clone())]);
+        if result.ok {
+            self.state.notices.info(NoticeSource::Jobs, message);
+        } else {
+            self.state.notices.error(NoticeSource::Jobs, message);
+        }
+        let job = &mut self.state.jobs.jobs[i];
+        job.last_run = Some(now);
+        job.task = task;
+        job.last_result = Some(result);
+        if let Err(e) = self.state.save_config() {
+            tracing::warn!("Failed to save job run: {}", e);
+        }
+    }
+
+    fn start_data_fetch(&mut self) {
+        self.spawn_data_fetch();
+    }
 
-            // Fetch CBOE put/call ratio and SKEW
-            match crate::data::cboe::fetch_put_call_ratio().await {
-                Ok(records) => market_data.put_call_ratio = records,
-                Err(e) => tracing::warn!("Failed to fetch CBOE put/call ratio: {:?}", e),
+    /// Start again the work a notice in the Notifications window reported as failed
+    fn retry(&mut self, action: RetryAction) {
+        match action {
+            RetryAction::Refresh => self.start_data_fetch(),
+            RetryAction::Refetch(symbol) => {
+                if let Some(progress) = &self.state.refresh_progress {
+                    progress.set(&symbol, crate::data::yahoo::SymbolStatus::Fetching);
+                }
+                let name = ui::data_view::series_name(&symbol).to_string();
+                ui::data_view::start_refetch(
+                    &mut self.state.tasks,
+                    &mut self.state.data_manager,
+                    &symbol,
+                    &name,
+                    self.state.analysis_windows.lookback_days,
+                );
             }
-            match crate::data::cboe::fetch_skew_history().await {
-                Ok(records) => market_data.skew_history = records,
-                Err(e) => tracing::warn!("Failed to fetch CBOE SKEW: {:?}", e),
+            RetryAction::Train => {
+                if let Err(e) = ui::nn_view::start_scheduled_training(&mut self.state) {
+                    self.state.notices.error(NoticeSource::Training, tr_args("nn-retrain-failed", &[("error", e)]));
+                }
             }
+        }
+    }
 
-            market_data.last_refresh = Some(chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+    /// Start a market data refresh; `None` when one is already running
+    fn spawn_data_fetch(&mut self) -> Option<TaskId> {
+        if self.state.is_loading {
+            return None;
+        }
+        self.state.is_loading = true;
+        self.state.notices.info(NoticeSource::Data, tr("refresh-fetching"));
 
+        let result_slot: Arc<Mutex<Option<MarketData>>> = Arc::new(Mutex::new(None));
+        self.state.data_receiver = Some(result_slot.clone());
+        let progress = FetchProgress::new(
+            config::sector_etfs().iter().map(|s| s.symbol.as_str()).chain(std::iter::once(config::benchmark_symbol())),
+        );
+        self.state.refresh_progress = Some(progress.clone());
+
+        let lookback_days = self.state.analysis_windows.lookback_days;
+        let hooks = TaskHooks { cancel: None, progress: Some(Box::new(fetch_progress(progress.clone()))) };
+        Some(self.state.tasks.spawn(TaskKind::Refresh, tr("refresh-task"), hooks, async move {
+            let market_data = crate::data::refresh::fetch_market_data(lookback_days, &progress).await;
             if let Ok(mut slot) = result_slot.lock() {
                 *slot = Some(market_data);
             }
-        });
+            Ok(())
+        }))
     }
 
     fn check_data_ready(&mut self) {
@@ -452,52 +1457,79 @@ impl MktNoiseApp {
             .as_ref()
             .and_then(|receiver| receiver.lock().ok()?.take());
 
-        if let Some(data) = maybe_data {
+        if let Some(mut data) = maybe_data {
+            crate::data::refresh::keep_previous_on_failure(&mut data, &self.state.market_data, &self.state.data_manager.set_aside);
             let n_sectors = data.sectors.len();
             let n_rates = data.treasury_rates.len();
-            self.state.market_data = data;
-            self.state.available_gpus = crate::nn::gpu::detect_wgpu_adapters();
-            if self.state.available_gpus.is_empty() {
-                self.state.use_gpu = false;
+            let summary = data.refresh_report.summary();
+            self.state.status.health.record_refresh(&data.refresh_report.fetch_log());
+            self.state.market_data = Arc::new(data);
+            self.state.data_manager.set_aside.clear();
+            self.state.apply_exclusions();
+            if let Some(load) = &mut self.state.startup_load {
+                load.wants_data = false;
             }
             self.state.recompute_analysis();
             self.state.is_loading = false;
-            self.state.status_message = format!(
-                "Loaded {} sectors, {} treasury rate entries. Last refresh: {}",
-                n_sectors,
-                n_rates,
-                self.state
-                    .market_data
-                    .last_refresh
-                    .as_deref()
-                    .unwrap_or("N/A")
+            for item in self.state.market_data.refresh_report.failures() {
+                let FetchOutcome::Failed(e) = &item.outcome else { continue };
+                let id = if item.kept_previous { "refresh-source-failed-kept" } else { "refresh-source-failed" };
+                let message = tr_args(id, &[("source", item.name.clone()), ("error", e.to_string())]);
+                let retry = match item.source {
+                    DataSource::Yahoo => RetryAction::Refetch(item.name.clone()),
+                    _ => RetryAction::Refresh,
+                };
+                self.state.notices.push(
+                    Notice::new(Severity::Error, NoticeSource::Data, message).with_retry(retry),
+                );
+            }
+            let last_refresh = self.state.market_data.last_refresh.clone().unwrap_or_else(|| tr("refresh-never"));
+            let message = tr_args(
+                "refresh-loaded",
+                &[
+                    ("sectors", i18n::integer(n_sectors)),
+                    ("rates", i18n::integer(n_rates)),
+                    ("summary", summary),
+                    ("time", last_refresh),
+                ],
             );
+            match self.state.market_data.refresh_report.failures().next() {
+                Some(_) => self.state.notices.warn(NoticeSource::Data, message),
+                None => self.state.notices.info(NoticeSource::Data, message),
+            }
             self.state.data_receiver = None;
 
-            // Run inference with loaded model if available (avoids retraining)
-            if let Some(ref model) = self.state.loaded_model {
-                let preds = crate::nn::training::run_inference(model, &self.state.market_data, &self.state.nn_feature_flags);
-                if !preds.is_empty() {
-                    self.state.nn_predictions = preds.clone();
-                    if let Some(ref meta) = self.state.model_metadata {
-                        self.state.training_status =
-                            crate::data::models::TrainingStatus::Complete {
-                                final_loss: meta.final_loss,
-                            };
-                    }
-                }
-            }
+            self.state.run_model_inference();
+            self.state.evaluate_alerts();
         }
     }
 }
 
 impl eframe::App for MktNoiseApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        crate::ui::palette::set_mode(self.state.palette_mode);
+        i18n::set_locale(self.state.locale);
+        if let Some(cpu_secs) = frame.info().cpu_usage {
+            self.state.diagnostics.frames.record(Duration::from_secs_f32(cpu_secs));
+        }
+        if !self.display_applied {
+            ui::settings_view::apply_display_settings(ctx, &self.state.display_settings);
+            ui::chart_utils::set_hidden_series(ctx, self.saved_ui_state.hidden_series.clone());
+            self.display_applied = true;
+            if let Some(snapshot) = autosave::take_crashed() {
+                self.state.restored_session = Some(snapshot.apply(&mut self.state, ctx));
+            }
+        }
+
         // Poll for async data
-        self.check_data_ready();
-        if self.state.is_loading {
-            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        self.state.poll_tasks(ctx);
+        self.state.collect_startup_load();
+        if self.state.startup_load.is_some() {
+            ctx.request_repaint_after(Duration::from_millis(50));
         }
+        self.check_data_ready();
+        self.state.collect_refetches();
+        self.run_scheduler(ctx);
 
         // Drain the folder-picker result (written by background thread after dialog closes)
         let picked = self
@@ -513,72 +1545,209 @@ impl eframe::App for MktNoiseApp {
         // Handle screenshot events from ViewportCommand::Screenshot (arrives on next frame)
         let events: Vec<egui::Event> = ctx.input(|i| i.events.clone());
         for event in &events {
-            if let egui::Event::Screenshot { image, .. } = event {
-                match save_screenshot(image, &self.state.screenshot_settings) {
-                    Ok(path) => {
-                        self.state.status_message = format!("Screenshot saved: {}", path);
-                    }
-                    Err(e) => {
-                        self.state.status_message = format!("Screenshot failed: {}", e);
-                    }
+            if let egui::Event::Screenshot { image, user_data, .. } = event {
+                let result = ui::screenshot::handle_event(ctx, &mut self.state, image, user_data);
+                self.state.notices.report(NoticeSource::Export, result);
+            }
+        }
+
+        for command in ui::shortcuts::handle(ctx, &mut self.state) {
+            match command {
+                ui::shortcuts::Command::Refresh if !self.state.is_loading => self.start_data_fetch(),
+                ui::shortcuts::Command::Refresh => {}
+                ui::shortcuts::Command::Screenshot => {
+                    ui::screenshot::capture(ctx, &mut self.state, ScreenshotTarget::File);
                 }
+                ui::shortcuts::Command::CopyScreenshot => {
+                    ui::screenshot::capture(ctx, &mut self.state, ScreenshotTarget::Clipboard);
+                }
+            }
+        }
+
+        for command in ui::tray::update(ctx, &mut self.state) {
+            if command == TrayCommand::Refresh && !self.state.is_loading {
+                self.start_data_fetch();
             }
         }
+        if crate::grpc::update(&mut self.state) && !self.state.is_loading {
+            self.start_data_fetch();
+        }
 
         // Top panel with tabs
         egui::TopBottomPanel::top("tab_bar").show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.selectable_value(&mut self.state.active_tab, Tab::Dashboard, "Dashboard");
-                ui.selectable_value(&mut self.state.active_tab, Tab::SectorVol, "Sector Vol");
-                ui.selectable_value(
-                    &mut self.state.active_tab,
-                    Tab::Correlations,
-                    "Correlations",
-                );
-                ui.selectable_value(&mut self.state.active_tab, Tab::Bonds, "Bonds");
-                ui.selectable_value(&mut self.state.active_tab, Tab::Kurtosis, "Kurtosis");
-                ui.selectable_value(&mut self.state.active_tab, Tab::NeuralNet, "Neural Net");
-                ui.selectable_value(&mut self.state.active_tab, Tab::Settings, "Settings");
+                for tab in Tab::ALL {
+                    // Detached tabs are marked so it's clear their view lives in another window
+                    let label = if self.state.detached_tabs.contains(&tab) {
+                        format!("{} ⧉", tab.label())
+                    } else {
+                        tab.label()
+                    };
+                    ui.selectable_value(&mut self.state.active_tab, tab, label);
+                }
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    if self.state.is_loading {
-                        ui.spinner();
-                        ui.label("Loading...");
-                    } else if ui.button("Refresh Data").clicked() {
+                    if !self.state.is_loading && ui.button(tr("refresh-data")).clicked() {
                         self.start_data_fetch();
                     }
+                    ui::refresh_progress::render_indicator(ui, &mut self.state);
 
                     ui.separator();
 
-                    if ui.button("📷").on_hover_text("Take screenshot").clicked() {
-                        ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(
-                            egui::UserData::default(),
-                        ));
+                    let has_data = !self.state.market_data.sectors.is_empty();
+                    let report = ui
+                        .add_enabled(has_data, egui::Button::new("📄"))
+                        .on_hover_text(tr("report-hover"));
+                    if report.clicked() {
+                        let result = ui::report::generate(&self.state)
+                            .map(|path| tr_args("report-saved", &[("path", path.display().to_string())]))
+                            .map_err(|e| tr_args("report-failed", &[("error", e.to_string())]));
+                        self.state.notices.report(NoticeSource::Export, result);
                     }
+                    report.context_menu(|ui| {
+                        if ui
+                            .add_enabled(self.state.email.is_configured(), egui::Button::new(tr("email-report")))
+                            .on_disabled_hover_text(tr("email-report-disabled"))
+                            .clicked()
+                        {
+                            let result = self
+                                .state
+                                .email_report()
+                                .map(|(n, _)| tr_args("report-emailing", &[("count", i18n::integer(n))]))
+                                .map_err(|e| tr_args("report-email-failed", &[("error", e)]));
+                            self.state.notices.report(NoticeSource::Export, result);
+                            ui.close_menu();
+                        }
+                    });
+                    let export = ui
+                        .add_enabled(has_data, egui::Button::new("📊"))
+                        .on_hover_text(tr("workbook-hover"));
+                    if export.clicked() {
+                        let result = self
+                            .state
+                            .export_workbook()
+                            .map(|path| tr_args("workbook-saved", &[("path", path.display().to_string())]))
+                            .map_err(|e| tr_args("workbook-failed", &[("error", e.to_string())]));
+                        self.state.notices.report(NoticeSource::Export, result);
+                    }
+                    export.context_menu(|ui| {
+                        if ui
+                            .button(tr("export-arrow"))
+                            .on_hover_text(tr("export-arrow-hover"))
+                            .clicked()
+                        {
+                            let result = self
+                                .state
+                                .export_arrow()
+                                .map(|paths| {
+                                    let paths = paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ");
+                                    tr_args("export-arrow-saved", &[("paths", paths)])
+                                })
+                                .map_err(|e| tr_args("export-arrow-failed", &[("error", e.to_string())]));
+                            self.state.notices.report(NoticeSource::Export, result);
+                            ui.close_menu();
+                        }
+                    });
+
+                    let active = self.state.active_tab;
+                    if ui
+                        .add_enabled(!self.state.detached_tabs.contains(&active), egui::Button::new("⧉"))
+                        .on_hover_text(tr("detach-tab-hover"))
+                        .clicked()
+                    {
+                        self.state.detached_tabs.push(active);
+                        self.state.active_tab = Tab::Dashboard;
+                    }
+
+                    let camera = ui
+                        .button("📷")
+                        .on_hover_text(tr("screenshot-hover"));
+                    if camera.clicked() {
+                        ui::screenshot::capture(ctx, &mut self.state, ScreenshotTarget::File);
+                    }
+                    camera.context_menu(|ui| {
+                        for scope in ScreenshotScope::ALL {
+                            ui.radio_value(&mut self.state.screenshot_settings.scope, scope, ui::screenshot::scope_label(scope));
+                        }
+                        ui.separator();
+                        if ui.button(tr("screenshot-save-file")).clicked() {
+                            ui::screenshot::capture(ctx, &mut self.state, ScreenshotTarget::File);
+                            ui.close_menu();
+                        }
+                        if ui.button(tr("screenshot-copy")).clicked() {
+                            ui::screenshot::capture(ctx, &mut self.state, ScreenshotTarget::Clipboard);
+                            ui.close_menu();
+                        }
+                    });
+
+                    ui::shortcuts::render_button(ui, &mut self.state);
+
+                    ui.separator();
+                    ui::alerts_view::render_bell(ui, &mut self.state);
+
+                    ui.separator();
+                    ui::search::render_search_box(ui, &mut self.state);
                 });
             });
         });
 
+        if let Some(message) = self.state.restored_session.clone() {
+            egui::TopBottomPanel::top("restored_session_banner").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("⟲ {}", message));
+                    if ui.small_button(tr("dismiss")).clicked() {
+                        self.state.restored_session = None;
+                    }
+                });
+            });
+        }
+
         // Bottom status bar
         egui::TopBottomPanel::bottom("status_bar").show(ctx, |ui| {
-            ui.horizontal(|ui| {
-                ui.label(&self.state.status_message);
-            });
+            ui::status_bar::render(ui, &mut self.state);
         });
 
+        ui::alerts_view::render_panel(ctx, &mut self.state);
+        ui::alerts_view::render_toasts(ctx, &mut self.state);
+        ui::shortcuts::render_overlay(ctx, &mut self.state);
+        ui::refresh_progress::render_window(ctx, &mut self.state);
+        ui::tasks_view::render_window(ctx, &mut self.state);
+        ui::diagnostics_view::render_window(ctx, &mut self.state);
+        if let Some(action) = ui::notices_view::render_window(ctx, &mut self.state) {
+            self.retry(action);
+        }
+        if let Some(i) = ui::jobs_view::render_window(ctx, &mut self.state) {
+            self.run_job(ctx, i);
+        }
+
+        self.show_detached_tabs(ctx);
+
         // Central panel with active tab content (scrollable when content overflows)
-        egui::CentralPanel::default().show(ctx, |ui| {
+        let central = egui::CentralPanel::default().show(ctx, |ui| {
+            let tab = self.state.active_tab;
+            if self.state.detached_tabs.contains(&tab) {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(40.0);
+                    ui.label(tr_args("tab-detached", &[("tab", tab.label())]));
+                    if ui.button(tr("reattach")).clicked() {
+                        self.state.detached_tabs.retain(|t| *t != tab);
+                    }
+                });
+                return;
+            }
             egui::ScrollArea::vertical()
                 .auto_shrink(false)
-                .show(ui, |ui| match self.state.active_tab {
-                    Tab::Dashboard => ui::dashboard::render(ui, &mut self.state),
-                    Tab::SectorVol => ui::sector_view::render(ui, &mut self.state),
-                    Tab::Correlations => ui::correlation_view::render(ui, &mut self.state),
-                    Tab::Bonds => ui::bond_view::render(ui, &mut self.state),
-                    Tab::Kurtosis => ui::kurtosis_view::render(ui, &mut self.state),
-                    Tab::NeuralNet => ui::nn_view::render(ui, &mut self.state),
-                    Tab::Settings => ui::settings_view::render(ui, &mut self.state),
-                });
+                .show(ui, |ui| tab.render(ui, &mut self.state));
         });
+        self.state.central_rect = Some(central.response.rect);
+        ui::screenshot::render_region_overlay(ctx, &mut self.state);
+
+        self.persist_ui_state(ctx);
+        self.autosave_session(ctx);
+    }
+
+    /// A clean exit leaves no autosave behind, so the next launch starts normally
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        autosave::clear();
     }
 }
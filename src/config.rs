@@ -1,5 +1,23 @@
-/// Sector ETF ticker symbols (SPDR S&P 500 sector ETFs)
-pub const SECTOR_ETFS: &[(&str, &str)] = &[
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+/// A ticker in the analysis universe and its display name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UniverseSymbol {
+    pub symbol: String,
+    pub name: String,
+}
+
+impl UniverseSymbol {
+    fn new(symbol: &str, name: &str) -> Self {
+        Self { symbol: symbol.to_string(), name: name.to_string() }
+    }
+}
+
+/// Default sector ETFs (SPDR S&P 500 sector ETFs); `[universe]` in config.toml can
+/// name others
+pub const DEFAULT_SECTOR_ETFS: &[(&str, &str)] = &[
     ("XLK", "Technology"),
     ("XLF", "Financials"),
     ("XLE", "Energy"),
@@ -13,11 +31,70 @@ pub const SECTOR_ETFS: &[(&str, &str)] = &[
     ("XLB", "Materials"),
 ];
 
-/// Market benchmark
-pub const BENCHMARK_SYMBOL: &str = "SPY";
+/// Default market benchmark
+pub const DEFAULT_BENCHMARK: (&str, &str) = ("SPY", "S&P 500");
+
+/// Sector slots in the neural network's inputs and outputs; a longer sector list is
+/// cut to its first `MAX_SECTORS` entries
+pub const MAX_SECTORS: usize = 11;
+
+/// Sector ETFs and benchmark every view, fetch and model works on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Universe {
+    pub sectors: Vec<UniverseSymbol>,
+    pub benchmark: UniverseSymbol,
+}
+
+impl Universe {
+    pub fn default_sectors() -> Vec<UniverseSymbol> {
+        DEFAULT_SECTOR_ETFS.iter().map(|(symbol, name)| UniverseSymbol::new(symbol, name)).collect()
+    }
+
+    pub fn default_benchmark() -> UniverseSymbol {
+        UniverseSymbol::new(DEFAULT_BENCHMARK.0, DEFAULT_BENCHMARK.1)
+    }
+}
+
+impl Default for Universe {
+    fn default() -> Self {
+        Self { sectors: Self::default_sectors(), benchmark: Self::default_benchmark() }
+    }
+}
+
+static UNIVERSE: OnceLock<Universe> = OnceLock::new();
+
+/// Fix the universe for this session; from `config.toml` at startup, before anything
+/// reads it. An empty sector list keeps the defaults. Later calls are ignored, since
+/// loaded data, caches and models are all keyed to the first universe.
+pub fn set_universe(mut universe: Universe) {
+    if universe.sectors.is_empty() {
+        universe.sectors = Universe::default_sectors();
+    }
+    if universe.sectors.len() > MAX_SECTORS {
+        tracing::warn!("Universe lists {} sectors; using the first {}", universe.sectors.len(), MAX_SECTORS);
+        universe.sectors.truncate(MAX_SECTORS);
+    }
+    if UNIVERSE.get_or_init(|| universe.clone()) != &universe {
+        tracing::warn!("Universe already in use; the new one applies after a restart");
+    }
+}
+
+pub fn universe() -> &'static Universe {
+    UNIVERSE.get_or_init(Universe::default)
+}
+
+/// Sector ETFs of the universe, in display order
+pub fn sector_etfs() -> &'static [UniverseSymbol] {
+    &universe().sectors
+}
+
+/// Market benchmark symbol
+pub fn benchmark_symbol() -> &'static str {
+    &universe().benchmark.symbol
+}
 
 /// Financial Modeling Prep API key.
-/// Reads FMP_API_KEY from the environment (set in a gitignored .env file).
+/// Reads FMP_API_KEY from the environment (set in Settings or a gitignored .env file).
 /// Call `load_env()` once at startup to populate the environment from .env.
 pub fn fmp_api_key() -> String {
     dotenvy::var("FMP_API_KEY").unwrap_or_default()
@@ -44,13 +121,29 @@ pub fn load_env() {
     }
 }
 
-/// Default historical lookback in calendar days (~2 years)
+/// Default historical lookback in calendar days (~2 years); user-adjustable via
+/// `AnalysisWindows` in Settings
 pub const DEFAULT_LOOKBACK_DAYS: u32 = 730;
 
-/// Rolling volatility window sizes (trading days)
+/// Default rolling volatility window sizes (trading days). The analysis views use the
+/// user's `AnalysisWindows`; the NN feature pipeline always uses these.
 pub const SHORT_VOL_WINDOW: usize = 21;  // ~1 month
 pub const LONG_VOL_WINDOW: usize = 63;   // ~3 months
 
+/// Rolling window for the average cross-sector correlation chart (trading days)
+pub const ROLLING_CORR_WINDOW: usize = 63;
+
+/// Rolling window for each sector's beta vs the benchmark (trading days)
+pub const ROLLING_BETA_WINDOW: usize = 63;
+
+/// Rolling average window for the volume subchart (trading days)
+pub const VOLUME_AVG_WINDOW: usize = 20;
+
+/// Confidence level for historical VaR / Expected Shortfall on the Risk tab
+pub const VAR_CONFIDENCE: f64 = 0.95;
+/// Fraction of the largest daily losses used by the Hill tail-index estimator
+pub const TAIL_INDEX_FRACTION: f64 = 0.1;
+
 /// Neural network configuration
 pub const NN_LOOKBACK_DAYS: usize = 60;
 pub const NN_FORWARD_DAYS: usize = 5;
@@ -58,3 +151,6 @@ pub const NN_HIDDEN_SIZE: usize = 64;
 pub const NN_LEARNING_RATE: f64 = 1e-3;
 pub const NN_EPOCHS: usize = 1000;
 pub const NN_BATCH_SIZE: usize = 32;
+pub const NN_HUBER_DELTA: f64 = 0.1;
+pub const NN_GRAD_CLIP_NORM: f64 = 1.0;
+pub const NN_LOG_VOL_FLOOR: f64 = 1e-4;  // floor before ln() for the log-vol target
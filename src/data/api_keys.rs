@@ -0,0 +1,275 @@
+//! API keys entered in Settings, grouped into named environments (say personal and
+//! team keys, or sandbox and live endpoints) of which one is active. The active
+//! environment's keys are applied to the process environment at startup and on each
+//! switch, so everything that reads keys through `config` picks them up unchanged;
+//! provider data is cached per environment.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::data::cache;
+
+/// Cache file the keys entered in Settings are saved to (gitignored)
+pub const API_KEYS_FILENAME: &str = "api_keys.json";
+
+/// A data provider that needs an API key, or another service secret kept alongside
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ApiProvider {
+    Fmp,
+    /// Password of the SMTP account emailed reports are sent from
+    Smtp,
+}
+
+impl ApiProvider {
+    pub const ALL: [ApiProvider; 2] = [Self::Fmp, Self::Smtp];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Fmp => "Financial Modeling Prep",
+            Self::Smtp => "SMTP password",
+        }
+    }
+
+    /// Environment variable (or `.env` entry) the key is read from
+    pub fn env_var(&self) -> &'static str {
+        match self {
+            Self::Fmp => "FMP_API_KEY",
+            Self::Smtp => "SMTP_PASSWORD",
+        }
+    }
+
+    /// What the app loses without this key
+    pub fn used_for(&self) -> &'static str {
+        match self {
+            Self::Fmp => "Treasury rates and sector performance",
+            Self::Smtp => "Emailed reports (server settings under Email Reports)",
+        }
+    }
+}
+
+/// Environment that always exists; its data is cached in the cache root
+pub const DEFAULT_ENVIRONMENT: &str = "Default";
+
+/// A named set of provider credentials and endpoints, e.g. a personal key against a
+/// team key, or a sandbox endpoint against the live one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiEnvironment {
+    pub name: String,
+    #[serde(default)]
+    pub keys: BTreeMap<ApiProvider, String>,
+    /// FMP API root; empty = the live endpoint
+    #[serde(default)]
+    pub fmp_base_url: String,
+}
+
+impl ApiEnvironment {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), keys: BTreeMap::new(), fmp_base_url: String::new() }
+    }
+
+    /// Cache subfolder provider data fetched with this environment is kept in; empty
+    /// for the default environment
+    pub fn cache_namespace(&self) -> String {
+        if self.name == DEFAULT_ENVIRONMENT {
+            return String::new();
+        }
+        self.name
+            .trim()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c.to_ascii_lowercase() } else { '_' })
+            .collect()
+    }
+}
+
+/// Every saved environment and the one in use, as saved to [`API_KEYS_FILENAME`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiEnvironments {
+    pub active: String,
+    pub environments: Vec<ApiEnvironment>,
+}
+
+impl Default for ApiEnvironments {
+    fn default() -> Self {
+        Self { active: DEFAULT_ENVIRONMENT.to_string(), environments: vec![ApiEnvironment::new(DEFAULT_ENVIRONMENT)] }
+    }
+}
+
+impl ApiEnvironments {
+    pub fn active(&self) -> &ApiEnvironment {
+        self.environments.iter().find(|e| e.name == self.active).unwrap_or(&self.environments[0])
+    }
+
+    fn active_mut(&mut self) -> &mut ApiEnvironment {
+        let i = self.environments.iter().position(|e| e.name == self.active).unwrap_or(0);
+        &mut self.environments[i]
+    }
+
+    /// Save `key` for `provider` in the active environment (an empty key removes it)
+    /// and use it from now on
+    pub fn set_key(&mut self, provider: ApiProvider, key: &str) -> Result<()> {
+        let key = key.trim();
+        let keys = &mut self.active_mut().keys;
+        if key.is_empty() {
+            keys.remove(&provider);
+            unsafe { std::env::remove_var(provider.env_var()); }
+        } else {
+            keys.insert(provider, key.to_string());
+            unsafe { std::env::set_var(provider.env_var(), key); }
+        }
+        self.save()
+    }
+
+    /// Point the active environment's FMP requests at `url` (empty = live endpoint)
+    pub fn set_fmp_base_url(&mut self, url: &str) -> Result<()> {
+        self.active_mut().fmp_base_url = url.trim().trim_end_matches('/').to_string();
+        apply(self.active());
+        self.save()
+    }
+
+    /// Add an empty environment named `name`
+    pub fn add(&mut self, name: &str) -> Result<()> {
+        let environment = ApiEnvironment::new(name.trim());
+        if environment.name.is_empty() {
+            anyhow::bail!("Enter a name for the environment");
+        }
+        let namespace = environment.cache_namespace();
+        if self.environments.iter().any(|e| e.name == environment.name || e.cache_namespace() == namespace) {
+            anyhow::bail!("An environment named \"{}\" already exists", environment.name);
+        }
+        self.environments.push(environment);
+        self.save()
+    }
+
+    /// Delete environment `name`, switching to the default one if it was in use. Its
+    /// cached data is left on disk.
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        if name == DEFAULT_ENVIRONMENT {
+            anyhow::bail!("The default environment can't be removed");
+        }
+        self.environments.retain(|e| e.name != name);
+        if self.active == name {
+            return self.activate(DEFAULT_ENVIRONMENT);
+        }
+        self.save()
+    }
+
+    /// Use environment `name`'s keys, endpoints and cache from now on
+    pub fn activate(&mut self, name: &str) -> Result<()> {
+        if !self.environments.iter().any(|e| e.name == name) {
+            anyhow::bail!("No environment named \"{}\"", name);
+        }
+        self.active = name.to_string();
+        apply(self.active());
+        // Keys the environment doesn't set fall back to .env again
+        crate::config::load_env();
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        cache::save_json(API_KEYS_FILENAME, self)
+    }
+}
+
+/// Saved environments; the default one alone when nothing is saved
+pub fn load_saved() -> ApiEnvironments {
+    cache::load_json::<serde_json::Value>(API_KEYS_FILENAME).map(from_saved).unwrap_or_default()
+}
+
+/// Environments from the saved JSON. A file from before environments existed holds
+/// plain keys, which become the default environment's.
+fn from_saved(json: serde_json::Value) -> ApiEnvironments {
+    if let Ok(environments) = serde_json::from_value::<ApiEnvironments>(json.clone()) {
+        if !environments.environments.is_empty() {
+            return environments;
+        }
+    }
+    let mut environments = ApiEnvironments::default();
+    if let Ok(keys) = serde_json::from_value::<BTreeMap<ApiProvider, String>>(json) {
+        environments.environments[0].keys = keys;
+    }
+    environments
+}
+
+/// Providers whose key was set in the process environment at launch; those keys win
+/// over every saved environment's
+static EXTERNAL_KEYS: OnceLock<Vec<ApiProvider>> = OnceLock::new();
+
+/// Put the active environment's keys into the process environment and use its
+/// endpoints and cache. Call before `config::load_env()`; a key already set in the
+/// process environment wins over the saved one, and the saved one wins over `.env`.
+pub fn apply_saved() {
+    EXTERNAL_KEYS.get_or_init(|| {
+        ApiProvider::ALL.into_iter().filter(|p| std::env::var(p.env_var()).is_ok()).collect()
+    });
+    apply(load_saved().active());
+}
+
+fn apply(environment: &ApiEnvironment) {
+    let external = EXTERNAL_KEYS.get().map_or(&[][..], Vec::as_slice);
+    for provider in ApiProvider::ALL {
+        if external.contains(&provider) {
+            continue;
+        }
+        match environment.keys.get(&provider).filter(|k| !k.is_empty()) {
+            Some(key) => unsafe { std::env::set_var(provider.env_var(), key) },
+            None => unsafe { std::env::remove_var(provider.env_var()) },
+        }
+    }
+    crate::data::fmp::set_base_url(&environment.fmp_base_url);
+    cache::set_namespace(&environment.cache_namespace());
+}
+
+/// Key with all but its first and last four characters hidden
+pub fn mask(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "•".repeat(chars.len());
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}{}{}", head, "•".repeat(chars.len() - 8), tail)
+}
+
+/// Make one uncached request with `key`, returning a short success summary
+pub async fn test_connection(provider: ApiProvider, key: &str) -> Result<String> {
+    match provider {
+        ApiProvider::Fmp => crate::data::fmp::test_connection(key).await,
+        ApiProvider::Smtp => crate::data::email::test_connection(key).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("abcd1234wxyz"), "abcd••••wxyz");
+        assert_eq!(mask("short"), "•••••");
+        assert_eq!(mask(""), "");
+    }
+
+    #[test]
+    fn test_cache_namespace() {
+        assert_eq!(ApiEnvironment::new(DEFAULT_ENVIRONMENT).cache_namespace(), "");
+        assert_eq!(ApiEnvironment::new("Team Sandbox").cache_namespace(), "team_sandbox");
+        assert_eq!(ApiEnvironment::new("live-2").cache_namespace(), "live-2");
+    }
+
+    #[test]
+    fn test_legacy_keys_become_default_environment() {
+        let environments = from_saved(serde_json::json!({ "Fmp": "abc" }));
+        assert_eq!(environments.active().name, DEFAULT_ENVIRONMENT);
+        assert_eq!(environments.active().keys.get(&ApiProvider::Fmp).map(String::as_str), Some("abc"));
+
+        let mut saved = ApiEnvironments::default();
+        saved.environments.push(ApiEnvironment::new("Team"));
+        saved.active = "Team".to_string();
+        let reloaded = from_saved(serde_json::to_value(&saved).unwrap());
+        assert_eq!(reloaded, saved);
+        assert_eq!(reloaded.active().name, "Team");
+    }
+}
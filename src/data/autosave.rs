@@ -0,0 +1,146 @@
+//! Crash recovery. While the app runs, the session essentials that `config.toml`
+//! doesn't keep (selections, layout, predictions, and the training run in progress)
+//! are saved to the cache every minute; a clean exit deletes the file. Finding it at
+//! startup means the last session ended abnormally, and it is restored.
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{AppState, UiState};
+use crate::data::cache;
+use crate::data::models::{NnPredictions, TrainingStatus};
+use crate::nn::history::{self, RunOutcome, TrainingRun};
+use crate::ui::i18n::{self, tr_args};
+
+/// Cache file the running session is saved to
+pub const AUTOSAVE_FILENAME: &str = "session_autosave.json";
+
+/// Seconds between autosaves
+pub const AUTOSAVE_INTERVAL_SECS: f64 = 60.0;
+
+/// Local time format of `SessionSnapshot::saved_at`
+const SAVED_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub saved_at: String,
+    pub ui_state: UiState,
+    pub nn_predictions: NnPredictions,
+    /// Training run in progress at the time of the save, with the losses so far
+    pub training: Option<TrainingRun>,
+}
+
+impl SessionSnapshot {
+    pub fn capture(state: &AppState, ctx: &egui::Context) -> Self {
+        let training = state.active_run.clone().map(|run| {
+            let (losses, val_losses) = state
+                .training_progress
+                .as_ref()
+                .map(|p| {
+                    (
+                        p.losses.lock().map(|l| l.clone()).unwrap_or_default(),
+                        p.val_losses.lock().map(|l| l.clone()).unwrap_or_default(),
+                    )
+                })
+                .unwrap_or_default();
+            run.finish(
+                RunOutcome::Failed("Interrupted by an abnormal exit".to_string()),
+                &losses,
+                &val_losses,
+                &state.nn_predictions,
+            )
+        });
+        Self {
+            saved_at: chrono::Local::now().format(SAVED_AT_FORMAT).to_string(),
+            ui_state: UiState::capture(state, ctx),
+            nn_predictions: state.nn_predictions.clone(),
+            training,
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        cache::save_json(AUTOSAVE_FILENAME, self).map_err(|e| format!("Failed to autosave session: {}", e))
+    }
+
+    /// Restore the saved session and return the banner text describing it. Settings
+    /// are left as `config.toml` has them, since it is saved on every change and may be
+    /// newer than the snapshot. An interrupted training run is added to the run history.
+    pub fn apply(self, state: &mut AppState, ctx: &egui::Context) -> String {
+        let ui_state = self.ui_state;
+        state.active_tab = ui_state.active_tab;
+        state.selected_sector_idx = ui_state.selected_sector_idx.min(state.market_data.sectors.len().saturating_sub(1));
+        state.chart_heights = ui_state.chart_heights;
+        state.open_sections = ui_state.open_sections;
+        crate::ui::chart_utils::set_hidden_series(ctx, ui_state.hidden_series);
+
+        state.nn_predictions = self.nn_predictions;
+
+        let saved_at = chrono::NaiveDateTime::parse_from_str(&self.saved_at, SAVED_AT_FORMAT)
+            .ok()
+            .and_then(|at| at.and_local_timezone(chrono::Local).single())
+            .map_or(self.saved_at, i18n::date_time);
+        let mut message = tr_args("session-restored", &[("time", saved_at)]);
+        if let Some(run) = self.training {
+            let epochs =
+                &[("epochs", i18n::integer(run.epochs_completed())), ("total", i18n::integer(run.config.epochs))];
+            message.push_str(&format!(" {}", tr_args("session-training-interrupted", epochs)));
+            state.training_losses = run.train_losses.iter().map(|l| l.unwrap_or(f64::NAN)).collect();
+            state.validation_losses = run.val_losses.iter().map(|l| l.unwrap_or(f64::NAN)).collect();
+            state.training_status = TrainingStatus::Error(tr_args("nn-training-interrupted", epochs));
+            if let Err(e) = history::append_run(&mut state.run_history, run) {
+                tracing::warn!("{}", e);
+            }
+        }
+        message
+    }
+}
+
+/// The session left behind by an abnormal exit, if any. The file is removed so the
+/// same session isn't restored twice.
+pub fn take_crashed() -> Option<SessionSnapshot> {
+    cache::modified_time(AUTOSAVE_FILENAME)?;
+    let snapshot = match cache::load_json(AUTOSAVE_FILENAME) {
+        Ok(snapshot) => Some(snapshot),
+        Err(e) => {
+            tracing::warn!("Discarding unreadable session autosave: {}", e);
+            None
+        }
+    };
+    clear();
+    snapshot
+}
+
+/// Delete the autosave, e.g. on a clean exit
+pub fn clear() {
+    if let Err(e) = cache::remove(AUTOSAVE_FILENAME) {
+        tracing::warn!("Failed to remove session autosave: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::{NnFeatureFlags, NnTrainingConfig};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let run = TrainingRun::start(&NnTrainingConfig::default(), &NnFeatureFlags::default()).finish(
+            RunOutcome::Failed("Interrupted by an abnormal exit".to_string()),
+            &[0.5, f64::NAN],
+            &[0.6],
+            &NnPredictions::default(),
+        );
+        let snapshot = SessionSnapshot {
+            saved_at: "2025-01-06 17:00:00".to_string(),
+            ui_state: UiState::default(),
+            nn_predictions: NnPredictions { vol: vec![("XLK".to_string(), 0.2)], ..Default::default() },
+            training: Some(run),
+        };
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: SessionSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.nn_predictions.vol, snapshot.nn_predictions.vol);
+        let training = loaded.training.unwrap();
+        assert_eq!(training.train_losses, vec![Some(0.5), None]);
+        assert_eq!(training.epochs_completed(), 2);
+    }
+}
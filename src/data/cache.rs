@@ -1,9 +1,50 @@
 use anyhow::Result;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
 
-/// Get the cache directory path, creating it if needed
+use crate::data::fixtures::{self, FixtureMode};
+
+/// Default age (hours) under which price and treasury caches are reused
+pub const DEFAULT_MAX_AGE_HOURS: u64 = 12;
+
+static MAX_AGE_HOURS: AtomicU64 = AtomicU64::new(DEFAULT_MAX_AGE_HOURS);
+
+/// Set the age under which price and treasury caches are reused; from `config.toml`
+pub fn set_max_age_hours(hours: u64) {
+    MAX_AGE_HOURS.store(hours, Ordering::Relaxed);
+}
+
+pub fn max_age_hours() -> u64 {
+    MAX_AGE_HOURS.load(Ordering::Relaxed)
+}
+
+/// Subfolder of the active API environment's provider data; empty = the cache root
+static NAMESPACE: RwLock<String> = RwLock::new(String::new());
+
+/// Keep provider data in `namespace` from now on (see [`provider_file`])
+pub fn set_namespace(namespace: &str) {
+    if let Ok(mut current) = NAMESPACE.write() {
+        *current = namespace.to_string();
+    }
+}
+
+/// Cache file name for provider data (prices, rates, sentiment), inside the active API
+/// environment's namespace so environments never serve each other's downloads
+pub fn provider_file(filename: &str) -> String {
+    match NAMESPACE.read() {
+        Ok(namespace) if !namespace.is_empty() => format!("env/{}/{}", namespace, filename),
+        _ => filename.to_string(),
+    }
+}
+
+/// Get the cache directory path, creating it if needed. Replayed fixtures are cached
+/// apart so they never replace real downloads.
 pub fn cache_dir() -> Result<PathBuf> {
-    let dir = std::env::current_dir()?.join("cache");
+    let mut dir = std::env::current_dir()?.join("cache");
+    if fixtures::mode() == FixtureMode::Replay {
+        dir.push("replay");
+    }
     std::fs::create_dir_all(&dir)?;
     Ok(dir)
 }
@@ -11,6 +52,9 @@ pub fn cache_dir() -> Result<PathBuf> {
 /// Save data to a JSON cache file
 pub fn save_json<T: serde::Serialize>(filename: &str, data: &T) -> Result<()> {
     let path = cache_dir()?.join(filename);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
     let json = serde_json::to_string_pretty(data)?;
     std::fs::write(path, json)?;
     Ok(())
@@ -24,8 +68,46 @@ pub fn load_json<T: serde::de::DeserializeOwned>(filename: &str) -> Result<T> {
     Ok(data)
 }
 
-/// Check if a cache file exists and is recent (within max_age_hours)
+/// Delete a cache file; a file that doesn't exist counts as removed
+pub fn remove(filename: &str) -> Result<()> {
+    match std::fs::remove_file(cache_dir()?.join(filename)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Data returned by a fetcher, tagged with whether it was served from the cache
+#[derive(Debug, Clone)]
+pub struct Fetched<T> {
+    pub data: T,
+    pub from_cache: bool,
+    /// When the data came off the network (the cache file's write time for cache hits)
+    pub fetched_at: chrono::DateTime<chrono::Local>,
+}
+
+impl<T> Fetched<T> {
+    pub fn network(data: T) -> Self {
+        Self { data, from_cache: false, fetched_at: chrono::Local::now() }
+    }
+
+    pub fn cached(data: T, filename: &str) -> Self {
+        let fetched_at = modified_time(filename).unwrap_or_else(chrono::Local::now);
+        Self { data, from_cache: true, fetched_at }
+    }
+}
+
+/// Last write time of a cache file
+pub fn modified_time(filename: &str) -> Option<chrono::DateTime<chrono::Local>> {
+    let modified = std::fs::metadata(cache_dir().ok()?.join(filename)).ok()?.modified().ok()?;
+    Some(modified.into())
+}
+
+/// Check if a cache file exists and is recent (within max_age_hours). Never while
+/// recording fixtures, so every source is requested.
 pub fn is_cache_fresh(filename: &str, max_age_hours: u64) -> bool {
+    if fixtures::mode() == FixtureMode::Record {
+        return false;
+    }
     let path = match cache_dir() {
         Ok(dir) => dir.join(filename),
         Err(_) => return false,
@@ -2,7 +2,8 @@ use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use std::io::Cursor;
 
-use crate::data::cache;
+use crate::data::cache::{self, Fetched};
+use crate::data::fixtures;
 use crate::data::models::{PutCallRecord, SkewRecord};
 
 const TOTALPC_URL: &str =
@@ -11,6 +12,14 @@ const SKEW_URL: &str =
     "https://cdn.cboe.com/api/global/us_indices/daily_prices/SKEW_History.csv";
 const CACHE_AGE_HOURS: u64 = 12;
 
+/// Cache files of the parsed put/call ratio and SKEW history
+pub const PUT_CALL_CACHE_FILE: &str = "cboe_put_call.json";
+pub const SKEW_CACHE_FILE: &str = "cboe_skew.json";
+
+/// Fixtures of the raw CSV downloads
+pub const PUT_CALL_FIXTURE: &str = "cboe_totalpc.csv";
+pub const SKEW_FIXTURE: &str = "cboe_SKEW_History.csv";
+
 /// Parse date from various formats (YYYY-MM-DD, M/D/YYYY, etc.)
 fn parse_date(s: &str) -> Option<NaiveDate> {
     NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
@@ -20,27 +29,24 @@ fn parse_date(s: &str) -> Option<NaiveDate> {
 }
 
 /// Fetch and parse CBOE Total Put/Call ratio from totalpc.csv
-pub async fn fetch_put_call_ratio() -> Result<Vec<PutCallRecord>> {
-    let cache_file = "cboe_put_call.json";
+pub async fn fetch_put_call_ratio() -> Result<Fetched<Vec<PutCallRecord>>> {
+    let cache_file = &cache::provider_file(PUT_CALL_CACHE_FILE);
     if cache::is_cache_fresh(cache_file, CACHE_AGE_HOURS) {
         if let Ok(cached) = cache::load_json::<Vec<PutCallRecord>>(cache_file) {
             tracing::info!("Using cached CBOE put/call ratio");
-            return Ok(cached);
+            return Ok(Fetched::cached(cached, cache_file));
         }
     }
 
     tracing::info!("Fetching CBOE put/call ratio from totalpc.csv");
-    let text = match reqwest::get(TOTALPC_URL).await {
-        Ok(resp) => resp
-            .text()
-            .await
-            .context("Failed to read totalpc.csv response")?,
+    let text = match fixtures::get(PUT_CALL_FIXTURE, TOTALPC_URL).await {
+        Ok(text) => text,
         Err(e) => {
-            tracing::warn!("Failed to fetch totalpc.csv: {} - trying cache", e);
+            tracing::warn!("Failed to fetch totalpc.csv: {:#} - trying cache", e);
             if let Ok(cached) = cache::load_json::<Vec<PutCallRecord>>(cache_file) {
-                return Ok(cached);
+                return Ok(Fetched::cached(cached, cache_file));
             }
-            return Err(e.into());
+            return Err(e);
         }
     };
 
@@ -51,14 +57,14 @@ pub async fn fetch_put_call_ratio() -> Result<Vec<PutCallRecord>> {
         tracing::warn!("Failed to cache put/call ratio: {}", e);
     }
 
-    Ok(records)
+    Ok(Fetched::network(records))
 }
 
 /// Parse totalpc.csv. Supports two formats:
 /// 1. Column-based: Date, Call Volume, Put Volume, P/C Ratio as column headers
 /// 2. Row-based (transposed): "DATE" row has dates across columns,
 ///    "P/C Ratio" or "TOTAL PUT/CALL RATIO" row has values
-fn parse_totalpc_csv(text: &str) -> Result<Vec<PutCallRecord>> {
+pub fn parse_totalpc_csv(text: &str) -> Result<Vec<PutCallRecord>> {
     // Try column-based first (standard CSV)
     if let Ok(records) = parse_totalpc_columns(text) {
         if !records.is_empty() {
@@ -196,27 +202,24 @@ fn parse_totalpc_transposed(text: &str) -> Result<Vec<PutCallRecord>> {
 }
 
 /// Fetch and parse CBOE SKEW index history from SKEW_History.csv
-pub async fn fetch_skew_history() -> Result<Vec<SkewRecord>> {
-    let cache_file = "cboe_skew.json";
+pub async fn fetch_skew_history() -> Result<Fetched<Vec<SkewRecord>>> {
+    let cache_file = &cache::provider_file(SKEW_CACHE_FILE);
     if cache::is_cache_fresh(cache_file, CACHE_AGE_HOURS) {
         if let Ok(cached) = cache::load_json::<Vec<SkewRecord>>(cache_file) {
             tracing::info!("Using cached CBOE SKEW history");
-            return Ok(cached);
+            return Ok(Fetched::cached(cached, cache_file));
         }
     }
 
     tracing::info!("Fetching CBOE SKEW from SKEW_History.csv");
-    let text = match reqwest::get(SKEW_URL).await {
-        Ok(resp) => resp
-            .text()
-            .await
-            .context("Failed to read SKEW_History.csv response")?,
+    let text = match fixtures::get(SKEW_FIXTURE, SKEW_URL).await {
+        Ok(text) => text,
         Err(e) => {
-            tracing::warn!("Failed to fetch SKEW_History.csv: {} - trying cache", e);
+            tracing::warn!("Failed to fetch SKEW_History.csv: {:#} - trying cache", e);
             if let Ok(cached) = cache::load_json::<Vec<SkewRecord>>(cache_file) {
-                return Ok(cached);
+                return Ok(Fetched::cached(cached, cache_file));
             }
-            return Err(e.into());
+            return Err(e);
         }
     };
 
@@ -227,11 +230,11 @@ pub async fn fetch_skew_history() -> Result<Vec<SkewRecord>> {
         tracing::warn!("Failed to cache SKEW history: {}", e);
     }
 
-    Ok(records)
+    Ok(Fetched::network(records))
 }
 
 /// Parse SKEW_History.csv: Date, Open, High, Low, Close (or Price), Volume, Change %
-fn parse_skew_csv(text: &str) -> Result<Vec<SkewRecord>> {
+pub fn parse_skew_csv(text: &str) -> Result<Vec<SkewRecord>> {
     let mut reader = csv::ReaderBuilder::new()
         .flexible(true)
         .from_reader(Cursor::new(text));
@@ -0,0 +1,461 @@
+//! `config.toml`: every user-adjustable runtime setting in one hand-editable file next
+//! to `.env`. Loaded once at startup and rewritten whenever a setting changes in the UI.
+//! On first run the settings are migrated from the per-setting JSON files that used to
+//! live in the cache directory.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::config::{self, Universe, UniverseSymbol};
+use crate::data::cache;
+use crate::data::email::EmailSettings;
+use crate::data::jobs::{self, Job, JobAction, JobTimezone, SchedulerConfig};
+use crate::data::models::{
+    AnalysisWindows, DisplaySettings, InferencePrecision, NnDriftSettings, NnFeatureFlags, NnTrainingConfig,
+    ScreenshotSettings,
+};
+use crate::data::webhooks::Webhook;
+use crate::logging::{self, LogLevel};
+use crate::ui::i18n::Locale;
+use crate::ui::palette::PaletteMode;
+use crate::ui::tray::TraySettings;
+use crate::grpc::GrpcSettings;
+
+/// Settings file, in the working directory
+pub const CONFIG_FILENAME: &str = "config.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    pub universe: UniverseConfig,
+    pub windows: AnalysisWindows,
+    pub nn: NnConfig,
+    pub cache: CacheConfig,
+    pub theme: ThemeConfig,
+    pub screenshot: ScreenshotSettings,
+    /// Cron-style jobs, auto-refresh and the daily capture included
+    pub scheduler: SchedulerConfig,
+    pub logging: LoggingConfig,
+    pub tray: TraySettings,
+    pub alerts: AlertsConfig,
+    pub email: EmailSettings,
+    pub grpc: GrpcSettings,
+    /// Auto-refresh and the daily capture from before they were jobs; read once, folded
+    /// into `scheduler` by [`ConfigFile::migrate`] and never written back
+    #[serde(skip_serializing)]
+    refresh: Option<LegacyRefresh>,
+    #[serde(skip_serializing)]
+    schedule: Option<LegacySchedule>,
+}
+
+/// The part of the settings that describes an analysis rather than this machine, and
+/// so can travel in a workspace. Webhooks, email, jobs, gRPC, tray, logging, cache and
+/// capture settings stay in `config.toml` and are never read from someone else's file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SharedSettings {
+    pub universe: UniverseConfig,
+    pub windows: AnalysisWindows,
+    /// The GPU adapter is left out; it names hardware on the machine that saved it
+    pub nn: NnConfig,
+    pub theme: ThemeConfig,
+}
+
+impl SharedSettings {
+    pub fn capture(state: &AppState) -> Self {
+        Self {
+            universe: UniverseConfig {
+                sectors: config::sector_etfs().to_vec(),
+                benchmark: config::universe().benchmark.clone(),
+                excluded: state.data_manager.excluded.clone(),
+                watchlist: state.watchlist.symbols.clone(),
+            },
+            windows: state.analysis_windows,
+            nn: NnConfig {
+                training: state.nn_training_config.clone(),
+                features: NnFeatureFlags { custom_indicators: Vec::new(), ..state.nn_feature_flags.clone() },
+                drift: state.drift_settings,
+                inference_precision: state.inference_precision,
+                gpu_adapter: None,
+            },
+            theme: ThemeConfig {
+                palette: state.palette_mode,
+                locale: state.locale,
+                display: state.display_settings,
+                explain_metrics: state.explain_metrics,
+            },
+        }
+    }
+
+    /// Make these the current analysis settings. The caller recomputes analysis and
+    /// applies the display settings. The sector list and benchmark stay those of this
+    /// session; they are read from `config.toml` at startup only.
+    pub fn apply(self, state: &mut AppState) {
+        state.data_manager.excluded = self.universe.excluded;
+        let watchlist = self.universe.watchlist;
+        state.watchlist.entries.retain(|e| watchlist.contains(&e.series.symbol));
+        state.watchlist.errors.retain(|(s, _)| watchlist.contains(s));
+        state.watchlist.symbols = watchlist;
+        if self.windows.validate().is_ok() {
+            state.analysis_windows = self.windows;
+            state.analysis_windows_draft = self.windows;
+        }
+        state.nn_training_config = self.nn.training;
+        state.nn_feature_flags = NnFeatureFlags {
+            custom_indicators: state.custom_indicators.nn_features(),
+            ..self.nn.features
+        };
+        state.drift_settings = self.nn.drift;
+        state.inference_precision = self.nn.inference_precision;
+        state.palette_mode = self.theme.palette;
+        state.locale = self.theme.locale;
+        state.display_settings = self.theme.display;
+        state.explain_metrics = self.theme.explain_metrics;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UniverseConfig {
+    /// Sector ETFs to fetch and analyze, at most `config::MAX_SECTORS`
+    pub sectors: Vec<UniverseSymbol>,
+    /// Market index the sectors are compared against
+    pub benchmark: UniverseSymbol,
+    /// Sector symbols left out of analysis and training
+    pub excluded: Vec<String>,
+    pub watchlist: Vec<String>,
+}
+
+impl Default for UniverseConfig {
+    fn default() -> Self {
+        Self {
+            sectors: Universe::default_sectors(),
+            benchmark: Universe::default_benchmark(),
+            excluded: Vec::new(),
+            watchlist: Vec::new(),
+        }
+    }
+}
+
+impl UniverseConfig {
+    pub fn universe(&self) -> Universe {
+        Universe { sectors: self.sectors.clone(), benchmark: self.benchmark.clone() }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NnConfig {
+    pub training: NnTrainingConfig,
+    /// Built-in feature groups; custom indicator features are kept with the indicators
+    pub features: NnFeatureFlags,
+    pub drift: NnDriftSettings,
+    pub inference_precision: InferencePrecision,
+    /// Preferred GPU adapter name; unset picks the first one found
+    pub gpu_adapter: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Price and treasury caches younger than this are used instead of refetching
+    pub max_age_hours: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self { max_age_hours: cache::DEFAULT_MAX_AGE_HOURS }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub palette: PaletteMode,
+    /// Language of UI text and number and date formats
+    pub locale: Locale,
+    pub display: DisplaySettings,
+    /// Show definitions and formulas when hovering headline metrics
+    pub explain_metrics: bool,
+}
+
+/// `[refresh]`: the auto-refresh interval, now the auto-refresh job
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+struct LegacyRefresh {
+    auto_refresh_minutes: Option<u64>,
+}
+
+/// `[schedule]`: a report and/or screenshot at a local time each weekday, now one job
+/// per output
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+struct LegacySchedule {
+    enabled: bool,
+    hour: u32,
+    minute: u32,
+    action: LegacyScheduleAction,
+    output_dir: String,
+    email_report: bool,
+    last_run: Option<chrono::NaiveDate>,
+}
+
+impl Default for LegacySchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hour: 16,
+            minute: 30,
+            action: LegacyScheduleAction::Report,
+            output_dir: jobs::default_output_dir(),
+            email_report: false,
+            last_run: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+enum LegacyScheduleAction {
+    Report,
+    Screenshot,
+    Both,
+}
+
+impl LegacySchedule {
+    /// The jobs producing what this schedule did, at the same time on weekdays
+    fn jobs(&self) -> Vec<Job> {
+        let cron = format!("{} {} * * 1-5", self.minute.min(59), self.hour.min(23));
+        let last_run = self
+            .last_run
+            .and_then(|date| date.and_hms_opt(self.hour.min(23), self.minute.min(59), 0));
+        let report = matches!(self.action, LegacyScheduleAction::Report | LegacyScheduleAction::Both);
+        let screenshot = matches!(self.action, LegacyScheduleAction::Screenshot | LegacyScheduleAction::Both);
+        [
+            (report, "Daily report", JobAction::Report),
+            (screenshot, "Daily screenshot", JobAction::Screenshot),
+            (report && self.email_report, "Daily report email", JobAction::EmailReport),
+        ]
+        .into_iter()
+        .filter(|(wanted, _, _)| *wanted)
+        .map(|(_, name, action)| Job {
+            name: name.to_string(),
+            enabled: self.enabled,
+            cron: cron.clone(),
+            timezone: JobTimezone::Local,
+            action,
+            last_run,
+            ..Default::default()
+        })
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+    pub level: LogLevel,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertsConfig {
+    /// Slack/Discord/JSON endpoints fired alerts are posted to
+    pub webhooks: Vec<Webhook>,
+}
+
+impl ConfigFile {
+    /// Read `config.toml`, or migrate the legacy JSON settings when it doesn't exist
+    /// yet. A file that fails to parse is reported and replaced by defaults for this
+    /// session; it is left on disk untouched until a setting is changed.
+    pub fn load() -> Self {
+        Self::load_from(Path::new(CONFIG_FILENAME)).unwrap_or_else(|e| {
+            tracing::warn!("{}", e);
+            Self::default()
+        })
+    }
+
+    fn load_from(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text)
+                .map(Self::migrate)
+                .map_err(|e| format!("Invalid {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let migrated = Self::from_legacy_json().migrate();
+                if let Err(e) = migrated.save_to(path) {
+                    tracing::warn!("{}", e);
+                }
+                Ok(migrated)
+            }
+            Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Settings as they were stored before `config.toml`: one JSON file per setting
+    fn from_legacy_json() -> Self {
+        fn load<T: serde::de::DeserializeOwned + Default>(filename: &str) -> T {
+            cache::load_json(filename).unwrap_or_default()
+        }
+        Self {
+            universe: UniverseConfig {
+                excluded: load("excluded_symbols.json"),
+                watchlist: load("watchlist.json"),
+                ..UniverseConfig::default()
+            },
+            windows: load("analysis_windows.json"),
+            nn: NnConfig {
+                training: load("nn_training_config.json"),
+                features: load("nn_feature_flags.json"),
+                drift: load("nn_drift_settings.json"),
+                inference_precision: load("nn_inference_precision.json"),
+                gpu_adapter: load("gpu_adapter.json"),
+            },
+            cache: CacheConfig::default(),
+            theme: ThemeConfig {
+                palette: load("palette_mode.json"),
+                locale: Locale::default(),
+                display: load("display_settings.json"),
+                explain_metrics: load("explain_mode.json"),
+            },
+            screenshot: load("screenshot_settings.json"),
+            scheduler: SchedulerConfig::default(),
+            logging: LoggingConfig::default(),
+            tray: TraySettings::default(),
+            alerts: AlertsConfig::default(),
+            email: EmailSettings::default(),
+            grpc: GrpcSettings::default(),
+            refresh: Some(LegacyRefresh { auto_refresh_minutes: load("auto_refresh.json") }),
+            schedule: Some(load("scheduled_capture.json")),
+        }
+    }
+
+    /// Fold the auto-refresh interval and the daily capture of older files into jobs.
+    /// A capture left at its defaults adds nothing.
+    pub fn migrate(mut self) -> Self {
+        if let Some(schedule) = self.schedule.take() {
+            if schedule != LegacySchedule::default() {
+                self.scheduler.jobs.extend(schedule.jobs());
+                self.scheduler.output_dir = schedule.output_dir;
+            }
+        }
+        if let Some(minutes) = self.refresh.take().and_then(|r| r.auto_refresh_minutes) {
+            let mut state = jobs::JobsState { jobs: std::mem::take(&mut self.scheduler.jobs), ..Default::default() };
+            state.set_auto_refresh(Some(minutes));
+            self.scheduler.jobs = state.jobs;
+        }
+        self
+    }
+
+    /// Snapshot of the current settings
+    pub fn capture(state: &AppState) -> Self {
+        let SharedSettings { universe, windows, mut nn, theme } = SharedSettings::capture(state);
+        nn.gpu_adapter = state.gpu_adapter.clone();
+        Self {
+            universe,
+            windows,
+            nn,
+            theme,
+            cache: CacheConfig { max_age_hours: cache::max_age_hours() },
+            screenshot: state.screenshot_settings.clone(),
+            scheduler: SchedulerConfig { jobs: state.jobs.jobs.clone(), output_dir: state.jobs.output_dir.clone() },
+            logging: LoggingConfig { level: state.log_level },
+            tray: state.tray.settings.clone(),
+            alerts: AlertsConfig { webhooks: state.alerts.webhooks.clone() },
+            email: state.email.clone(),
+            grpc: state.grpc.settings.clone(),
+            refresh: None,
+            schedule: None,
+        }
+    }
+
+    /// Make these the current settings, as if they had been loaded at startup. The
+    /// caller recomputes analysis and applies the display settings. The gRPC server
+    /// settings are left alone: only `config.toml` at startup and the Settings tab may
+    /// start a server or change the address it binds to.
+    pub fn apply(self, state: &mut AppState) {
+        let config = self.migrate();
+        state.gpu_adapter = config.nn.gpu_adapter.clone();
+        SharedSettings { universe: config.universe, windows: config.windows, nn: config.nn, theme: config.theme }
+            .apply(state);
+        cache::set_max_age_hours(config.cache.max_age_hours);
+        state.screenshot_settings = config.screenshot;
+        state.jobs.jobs = config.scheduler.jobs;
+        state.jobs.output_dir = config.scheduler.output_dir;
+        state.log_level = config.logging.level;
+        logging::set_level(config.logging.level);
+        state.tray.settings = config.tray;
+        state.alerts.webhooks = config.alerts.webhooks;
+        state.email = config.email;
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        self.save_to(Path::new(CONFIG_FILENAME))
+    }
+
+    fn save_to(&self, path: &Path) -> Result<(), String> {
+        let text = toml::to_string_pretty(self).map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        std::fs::write(path, text).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut config = ConfigFile::default();
+        config.universe.watchlist = vec!["SMH".to_string()];
+        config.windows.short_vol = 10;
+        config.nn.gpu_adapter = Some("Test Adapter".to_string());
+        config.scheduler.jobs[0].last_run = chrono::NaiveDate::from_ymd_opt(2025, 1, 6).and_then(|d| d.and_hms_opt(16, 30, 0));
+        config.scheduler.output_dir = "./captures".to_string();
+        config.logging.level = LogLevel::Debug;
+
+        let path = std::env::temp_dir().join(format!("vol_analysis_config_{}.toml", std::process::id()));
+        config.save_to(&path).unwrap();
+        let loaded = ConfigFile::load_from(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded, Ok(config));
+    }
+
+    #[test]
+    fn test_migrate_refresh_and_schedule_to_jobs() {
+        let text = "[refresh]\nauto_refresh_minutes = 30\n\n[schedule]\nenabled = true\nhour = 9\nminute = 5\n\
+                    action = \"Both\"\noutput_dir = \"./captures\"\nlast_run = \"2025-01-06\"\n";
+        let config = toml::from_str::<ConfigFile>(text).unwrap().migrate();
+        let jobs = &config.scheduler.jobs[SchedulerConfig::default().jobs.len()..];
+        let actions: Vec<JobAction> = jobs.iter().map(|j| j.action).collect();
+        assert_eq!(actions, [JobAction::Report, JobAction::Screenshot, JobAction::Refresh]);
+        assert!(jobs.iter().all(|j| j.enabled));
+        assert_eq!(jobs[0].cron, "5 9 * * 1-5");
+        assert_eq!(jobs[0].last_run, chrono::NaiveDate::from_ymd_opt(2025, 1, 6).and_then(|d| d.and_hms_opt(9, 5, 0)));
+        assert_eq!(jobs[2].cron, "*/30 * * * *");
+        assert_eq!(config.scheduler.output_dir, "./captures");
+
+        // Written back without the old tables, so a second load adds nothing
+        let saved = toml::to_string_pretty(&config).unwrap();
+        assert!(!saved.contains("[refresh]") && !saved.contains("[schedule]"));
+        assert_eq!(toml::from_str::<ConfigFile>(&saved).unwrap().migrate(), config);
+    }
+
+    #[test]
+    fn test_partial_file_uses_defaults() {
+        let config: ConfigFile = toml::from_str("[windows]\nshort_vol = 5\n").unwrap();
+        assert_eq!(config.windows.short_vol, 5);
+        assert_eq!(config.windows.long_vol, AnalysisWindows::default().long_vol);
+        assert_eq!(config.cache, CacheConfig::default());
+        assert_eq!(config.universe.universe(), Universe::default());
+    }
+
+    #[test]
+    fn test_custom_universe() {
+        let text = "[universe]\nwatchlist = [\"SMH\"]\nbenchmark = { symbol = \"QQQ\", name = \"Nasdaq 100\" }\n\n\
+                    [[universe.sectors]]\nsymbol = \"XLK\"\nname = \"Technology\"\n";
+        let config: ConfigFile = toml::from_str(text).unwrap();
+        assert_eq!(config.universe.sectors.len(), 1);
+        assert_eq!(config.universe.benchmark.symbol, "QQQ");
+        assert_eq!(config.universe.watchlist, ["SMH"]);
+    }
+}
@@ -0,0 +1,209 @@
+//! Emailed reports: the market report is sent over SMTP to a recipient list, on demand
+//! or with the daily scheduled capture. The account password is kept with the API keys
+//! (`SMTP_PASSWORD`), everything else in `config.toml`.
+
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+
+use crate::data::api_keys::ApiProvider;
+use crate::tasks::{TaskHooks, TaskId, TaskKind, TaskManager};
+use crate::ui::i18n::{self, tr_args};
+use crate::ui::report::InlineImage;
+
+/// Seconds to wait for the SMTP server before giving up
+const TIMEOUT_SECS: u64 = 30;
+
+/// How the connection to the SMTP server is secured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS (usually port 587)
+    #[default]
+    StartTls,
+    /// TLS from the start (usually port 465)
+    Tls,
+    /// Unencrypted, for a local relay only
+    None,
+}
+
+impl SmtpSecurity {
+    pub const ALL: [SmtpSecurity; 3] = [Self::StartTls, Self::Tls, Self::None];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::StartTls => "STARTTLS",
+            Self::Tls => "TLS",
+            Self::None => "None (local relay)",
+        }
+    }
+
+    pub fn default_port(self) -> u16 {
+        match self {
+            Self::StartTls => 587,
+            Self::Tls => 465,
+            Self::None => 25,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailSettings {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    /// Login; empty sends without authenticating
+    pub username: String,
+    /// Sender address; the username is used when empty
+    pub from: String,
+    /// Comma- or semicolon-separated addresses
+    pub recipients: String,
+}
+
+impl Default for EmailSettings {
+    fn default() -> Self {
+        Self {
+            host: String::new(),
+            port: SmtpSecurity::default().default_port(),
+            security: SmtpSecurity::default(),
+            username: String::new(),
+            from: String::new(),
+            recipients: String::new(),
+        }
+    }
+}
+
+impl EmailSettings {
+    /// Server and recipients are filled in
+    pub fn is_configured(&self) -> bool {
+        !self.host.trim().is_empty() && !self.recipients.trim().is_empty()
+    }
+
+    pub fn recipient_list(&self) -> Result<Vec<Mailbox>, String> {
+        let list: Vec<Mailbox> = self
+            .recipients
+            .split([',', ';', '\n'])
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .map(|r| r.parse().map_err(|e| format!("Invalid recipient {}: {}", r, e)))
+            .collect::<Result<_, String>>()?;
+        if list.is_empty() {
+            return Err("No recipients configured".to_string());
+        }
+        Ok(list)
+    }
+
+    fn sender(&self) -> Result<Mailbox, String> {
+        let from = if self.from.trim().is_empty() { self.username.trim() } else { self.from.trim() };
+        if from.is_empty() {
+            return Err("No sender address configured".to_string());
+        }
+        from.parse().map_err(|e| format!("Invalid sender {}: {}", from, e))
+    }
+
+    fn transport(&self, password: &str) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+        let host = self.host.trim();
+        if host.is_empty() {
+            return Err("No SMTP server configured".to_string());
+        }
+        let builder = match self.security {
+            SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+            SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)),
+        }
+        .map_err(|e| format!("Invalid SMTP server {}: {}", host, e))?
+        .port(self.port)
+        .timeout(Some(std::time::Duration::from_secs(TIMEOUT_SECS)));
+        let builder = if self.username.trim().is_empty() {
+            builder
+        } else {
+            builder.credentials(Credentials::new(self.username.trim().to_string(), password.to_string()))
+        };
+        Ok(builder.build())
+    }
+}
+
+/// The report as an HTML email with its charts attached inline
+pub fn build_message(
+    settings: &EmailSettings,
+    subject: &str,
+    html: String,
+    images: Vec<InlineImage>,
+) -> Result<Message, String> {
+    let png = ContentType::parse("image/png").map_err(|e| e.to_string())?;
+    let body = images.into_iter().fold(MultiPart::related().singlepart(SinglePart::html(html)), |body, image| {
+        body.singlepart(Attachment::new_inline(image.content_id).body(image.png, png.clone()))
+    });
+    let mut builder = Message::builder().from(settings.sender()?).subject(subject);
+    for recipient in settings.recipient_list()? {
+        builder = builder.to(recipient);
+    }
+    builder.multipart(body).map_err(|e| format!("Failed to build email: {}", e))
+}
+
+fn password() -> String {
+    std::env::var(ApiProvider::Smtp.env_var()).unwrap_or_default()
+}
+
+/// Email the rendered report to the configured recipients as a background task
+pub fn send_report(
+    tasks: &mut TaskManager,
+    settings: &EmailSettings,
+    html: String,
+    images: Vec<InlineImage>,
+) -> Result<TaskId, String> {
+    let subject = format!("Market Structure Report {}", chrono::Local::now().format("%Y-%m-%d"));
+    let message = build_message(settings, &subject, html, images)?;
+    let transport = settings.transport(&password())?;
+    let n = settings.recipient_list()?.len();
+    let label = tr_args("task-email", &[("count", i18n::integer(n))]);
+    Ok(tasks.spawn(TaskKind::Email, label, TaskHooks::default(), async move {
+        transport.send(message).await.map(|_| ()).map_err(|e| format!("Failed to send report: {}", e))
+    }))
+}
+
+/// Log in to the SMTP server from `config.toml` with `password`, without sending
+pub async fn test_connection(password: &str) -> anyhow::Result<String> {
+    let settings = crate::data::config_file::ConfigFile::load().email;
+    let transport = settings.transport(password).map_err(anyhow::Error::msg)?;
+    transport.test_connection().await?;
+    Ok(format!("OK, connected to {}", settings.host.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> EmailSettings {
+        EmailSettings {
+            host: "smtp.example.com".to_string(),
+            username: "desk@example.com".to_string(),
+            recipients: "a@example.com; Trader B <b@example.com>,\n".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_recipient_list() {
+        let list = settings().recipient_list().unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[1].email.to_string(), "b@example.com");
+        let bad = EmailSettings { recipients: "not an address".to_string(), ..settings() };
+        assert!(bad.recipient_list().is_err());
+        assert!(EmailSettings::default().recipient_list().is_err());
+    }
+
+    #[test]
+    fn test_message_embeds_charts() {
+        let images = vec![InlineImage { content_id: "chart1".to_string(), png: vec![0x89, b'P', b'N', b'G'] }];
+        let message =
+            build_message(&settings(), "Report", "<img src=\"cid:chart1\">".to_string(), images).unwrap();
+        let raw = String::from_utf8(message.formatted()).unwrap();
+        assert!(raw.contains("From: desk@example.com"));
+        assert!(raw.contains("multipart/related"));
+        assert!(raw.contains("Content-ID: <chart1>"));
+    }
+}
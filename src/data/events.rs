@@ -0,0 +1,89 @@
+use chrono::NaiveDate;
+
+use crate::data::cache;
+use crate::data::models::{BondSpread, ChartEvent, EventKind};
+
+const ANNOTATIONS_FILENAME: &str = "chart_annotations.json";
+
+/// FOMC policy decision dates (second day of each scheduled meeting)
+const FOMC_DATES: &[&str] = &[
+    "2023-02-01", "2023-03-22", "2023-05-03", "2023-06-14", "2023-07-26", "2023-09-20", "2023-11-01", "2023-12-13",
+    "2024-01-31", "2024-03-20", "2024-05-01", "2024-06-12", "2024-07-31", "2024-09-18", "2024-11-07", "2024-12-18",
+    "2025-01-29", "2025-03-19", "2025-05-07", "2025-06-18", "2025-07-30", "2025-09-17", "2025-10-29", "2025-12-10",
+    "2026-01-28", "2026-03-18", "2026-04-29", "2026-06-17", "2026-07-29", "2026-09-16", "2026-10-28", "2026-12-09",
+];
+
+/// BLS CPI release dates
+const CPI_DATES: &[&str] = &[
+    "2023-01-12", "2023-02-14", "2023-03-14", "2023-04-12", "2023-05-10", "2023-06-13",
+    "2023-07-12", "2023-08-10", "2023-09-13", "2023-10-12", "2023-11-14", "2023-12-12",
+    "2024-01-11", "2024-02-13", "2024-03-12", "2024-04-10", "2024-05-15", "2024-06-12",
+    "2024-07-11", "2024-08-14", "2024-09-11", "2024-10-10", "2024-11-13", "2024-12-11",
+    "2025-01-15", "2025-02-12", "2025-03-12", "2025-04-10", "2025-05-13", "2025-06-11",
+    "2025-07-15", "2025-08-12", "2025-09-11", "2025-10-24",
+];
+
+/// Built-in macro calendar: FOMC decisions and CPI releases. Earnings and anything
+/// else can be added as user annotations.
+pub fn builtin_events() -> Vec<ChartEvent> {
+    let calendar = |dates: &[&str], kind: EventKind| -> Vec<ChartEvent> {
+        dates
+            .iter()
+            .filter_map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .map(|date| ChartEvent { date, kind, label: kind.label().to_string() })
+            .collect()
+    };
+    let mut events = calendar(FOMC_DATES, EventKind::Fomc);
+    events.extend(calendar(CPI_DATES, EventKind::Cpi));
+    events
+}
+
+/// One marker at the start of each 10Y-2Y inversion episode
+pub fn inversion_events(spreads: &[BondSpread]) -> Vec<ChartEvent> {
+    let mut sorted: Vec<&BondSpread> = spreads.iter().collect();
+    sorted.sort_by_key(|s| s.date);
+    let mut events = Vec::new();
+    let mut inverted = false;
+    for s in sorted {
+        let now_inverted = s.spread_10y_2y < 0.0;
+        if now_inverted && !inverted {
+            events.push(ChartEvent {
+                date: s.date,
+                kind: EventKind::Inversion,
+                label: format!("10Y-2Y inverts ({:+.2} pp)", s.spread_10y_2y),
+            });
+        }
+        inverted = now_inverted;
+    }
+    events
+}
+
+/// Load user annotations from the cache. Empty if none have been saved.
+pub fn load_annotations() -> Vec<ChartEvent> {
+    cache::load_json(ANNOTATIONS_FILENAME).unwrap_or_default()
+}
+
+pub fn save_annotations(annotations: &[ChartEvent]) -> Result<(), String> {
+    cache::save_json(ANNOTATIONS_FILENAME, &annotations).map_err(|e| format!("Failed to save annotations: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_calendar_parses() {
+        let events = builtin_events();
+        assert_eq!(events.len(), FOMC_DATES.len() + CPI_DATES.len());
+    }
+
+    #[test]
+    fn test_inversion_events_mark_episode_starts() {
+        let day = |d: u32| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        let spread = |d: u32, v: f64| BondSpread { date: day(d), spread_10y_2y: v, curve_slope: 0.0 };
+        // Newest first, as the treasury feed delivers them
+        let spreads = vec![spread(5, -0.1), spread(4, 0.2), spread(3, -0.3), spread(2, -0.2), spread(1, 0.1)];
+        let events = inversion_events(&spreads);
+        assert_eq!(events.iter().map(|e| e.date).collect::<Vec<_>>(), vec![day(2), day(5)]);
+    }
+}
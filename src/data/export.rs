@@ -0,0 +1,554 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow_array::types::Date32Type;
+#[cfg(feature = "native")]
+use arrow_array::UInt32Array;
+use arrow_array::{ArrayRef, Date32Array, Float64Array, RecordBatch, StringArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema};
+
+use crate::analysis::{bond_spreads, drawdown};
+#[cfg(feature = "native")]
+use crate::config;
+use crate::data::models::{
+    BondSpread, CorrelationMatrix, NnPredictions, SectorTimeSeries, TreasuryRate, VolatilityMetrics,
+};
+#[cfg(feature = "native")]
+use crate::nn::dataset::VolDataset;
+
+/// Directory (under the working directory) all exports are written to
+const EXPORT_DIR: &str = "exports";
+
+/// Longest sheet name Excel accepts
+const MAX_SHEET_NAME: usize = 31;
+
+/// Header row plus data rows, ready to be written as CSV
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CsvTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl CsvTable {
+    pub fn new(headers: &[&str]) -> Self {
+        Self { headers: headers.iter().map(|h| h.to_string()).collect(), rows: Vec::new() }
+    }
+
+    /// Tab-separated text, one line per row, which spreadsheets paste as cells. Tabs
+    /// and line breaks inside a cell become spaces.
+    pub fn to_tsv(&self) -> String {
+        let line = |cells: &[String]| {
+            cells.iter().map(|c| c.replace(['\t', '\n', '\r'], " ")).collect::<Vec<_>>().join("\t")
+        };
+        std::iter::once(&self.headers).chain(&self.rows).map(|r| line(r) + "\n").collect()
+    }
+}
+
+/// `exports/{name}_{YYYYMMDD_HHMMSS}.{extension}`, creating the folder
+fn export_path(name: &str, extension: &str) -> Result<PathBuf, String> {
+    let dir = std::env::current_dir().map_err(|e| e.to_string())?.join(EXPORT_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    Ok(dir.join(format!("{}_{}.{}", name, timestamp, extension)))
+}
+
+/// Write `table` to `exports/{name}_{YYYYMMDD_HHMMSS}.csv`. Returns the file path.
+pub fn write_csv(name: &str, table: &CsvTable) -> Result<PathBuf, String> {
+    let path = export_path(name, "csv")?;
+    let mut writer = csv::Writer::from_path(&path).map_err(|e| e.to_string())?;
+    writer.write_record(&table.headers).map_err(|e| e.to_string())?;
+    for row in &table.rows {
+        writer.write_record(row).map_err(|e| e.to_string())?;
+    }
+    writer.flush().map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Write each `(sheet name, table)` as a worksheet of `exports/{name}_{YYYYMMDD_HHMMSS}.xlsx`.
+/// Numeric cells are stored as numbers; the header row is bold and frozen. Returns the
+/// file path.
+pub fn write_xlsx(name: &str, sheets: &[(String, CsvTable)]) -> Result<PathBuf, String> {
+    let path = export_path(name, "xlsx")?;
+    let mut workbook = rust_xlsxwriter::Workbook::new();
+    let bold = rust_xlsxwriter::Format::new().set_bold();
+    for (sheet_name, table) in sheets {
+        let sheet = workbook.add_worksheet();
+        let sheet_name: String = sheet_name.chars().take(MAX_SHEET_NAME).collect();
+        sheet.set_name(&sheet_name).map_err(|e| format!("Invalid sheet name {}: {}", sheet_name, e))?;
+        write_sheet(sheet, table, &bold).map_err(|e| format!("Failed to write sheet {}: {}", sheet_name, e))?;
+    }
+    workbook.save(&path).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+fn write_sheet(
+    sheet: &mut rust_xlsxwriter::Worksheet,
+    table: &CsvTable,
+    bold: &rust_xlsxwriter::Format,
+) -> Result<(), rust_xlsxwriter::XlsxError> {
+    for (col, header) in table.headers.iter().enumerate() {
+        sheet.write_string_with_format(0, col as u16, header, bold)?;
+    }
+    for (row, cells) in table.rows.iter().enumerate() {
+        let row = row as u32 + 1;
+        for (col, cell) in cells.iter().enumerate() {
+            match cell.parse::<f64>() {
+                Ok(v) if v.is_finite() => sheet.write_number(row, col as u16, v)?,
+                _ if cell.is_empty() => continue,
+                _ => sheet.write_string(row, col as u16, cell)?,
+            };
+        }
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    sheet.autofit();
+    Ok(())
+}
+
+/// Write `batches` to `exports/{name}_{YYYYMMDD_HHMMSS}.arrow` in the Arrow IPC file
+/// format (Feather v2), one record batch at a time, for `polars.read_ipc` or
+/// `pandas.read_feather` to memory-map. All batches must share a schema. Returns the
+/// file path.
+pub fn write_arrow(name: &str, batches: &[RecordBatch]) -> Result<PathBuf, String> {
+    let schema = batches.first().ok_or("Nothing to export")?.schema();
+    let path = export_path(name, "arrow")?;
+    let file = std::fs::File::create(&path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let mut writer = arrow_ipc::writer::FileWriter::try_new(file, &schema).map_err(|e| e.to_string())?;
+    for batch in batches {
+        writer.write(batch).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+    writer.finish().map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Bars and vol series of one sector, typed, with the same alignment as
+/// `sector_table` plus a `symbol` column so all sectors stack into one table.
+/// Vol values are null before their series starts.
+pub fn sector_series_batch(series: &SectorTimeSeries, vol: Option<&VolatilityMetrics>) -> Result<RecordBatch, String> {
+    let n = series.bars.len();
+    let drawdowns = drawdown::drawdown_series(&series.close_prices());
+    // Trailing-aligned series padded with nulls at the front
+    let aligned = |values: &[f64]| -> ArrayRef {
+        Arc::new(Float64Array::from_iter(
+            (0..n).map(|i| (i + values.len()).checked_sub(n).and_then(|k| values.get(k)).copied()),
+        ))
+    };
+    let bar = |value: fn(&crate::data::models::OhlcvBar) -> f64| -> ArrayRef {
+        Arc::new(Float64Array::from_iter_values(series.bars.iter().map(value)))
+    };
+    let empty: &[f64] = &[];
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from_iter_values(std::iter::repeat_n(&series.symbol, n))),
+        Arc::new(Date32Array::from_iter_values(series.bars.iter().map(|b| Date32Type::from_naive_date(b.date)))),
+        bar(|b| b.open),
+        bar(|b| b.high),
+        bar(|b| b.low),
+        bar(|b| b.close),
+        Arc::new(UInt64Array::from_iter_values(series.bars.iter().map(|b| b.volume))),
+        aligned(&drawdowns),
+        aligned(vol.map_or(empty, |v| &v.short_window_vol)),
+        aligned(vol.map_or(empty, |v| &v.long_window_vol)),
+        aligned(vol.map_or(empty, |v| &v.parkinson_vol)),
+        aligned(vol.map_or(empty, |v| &v.vol_ratio)),
+    ];
+    RecordBatch::try_new(Arc::new(sector_series_schema()), columns)
+        .map_err(|e| format!("Invalid series table for {}: {}", series.symbol, e))
+}
+
+fn sector_series_schema() -> Schema {
+    let float = |name: &str| Field::new(name, DataType::Float64, true);
+    Schema::new(vec![
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("date", DataType::Date32, false),
+        Field::new("open", DataType::Float64, false),
+        Field::new("high", DataType::Float64, false),
+        Field::new("low", DataType::Float64, false),
+        Field::new("close", DataType::Float64, false),
+        Field::new("volume", DataType::UInt64, false),
+        float("drawdown"),
+        float("short_vol"),
+        float("long_vol"),
+        float("parkinson_vol"),
+        float("vol_ratio"),
+    ])
+}
+
+/// Typed `spreads_table`: term spread and curve slope history, oldest first
+pub fn spreads_batch(spreads: &[BondSpread]) -> Result<RecordBatch, String> {
+    let mut sorted: Vec<&BondSpread> = spreads.iter().collect();
+    sorted.sort_by_key(|s| s.date);
+    let schema = Schema::new(vec![
+        Field::new("date", DataType::Date32, false),
+        Field::new("spread_10y_2y", DataType::Float64, false),
+        Field::new("curve_slope_30y_3m", DataType::Float64, false),
+    ]);
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Date32Array::from_iter_values(sorted.iter().map(|s| Date32Type::from_naive_date(s.date)))),
+        Arc::new(Float64Array::from_iter_values(sorted.iter().map(|s| s.spread_10y_2y))),
+        Arc::new(Float64Array::from_iter_values(sorted.iter().map(|s| s.curve_slope))),
+    ];
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(|e| format!("Invalid spreads table: {}", e))
+}
+
+/// Write the NN feature dataset to `exports/{name}_{YYYYMMDD_HHMMSS}.parquet` (see
+/// `feature_batch` for the layout). Returns the file path.
+#[cfg(feature = "native")]
+pub fn write_feature_parquet(name: &str, dataset: &VolDataset, feature_names: &[String]) -> Result<PathBuf, String> {
+    let batch = feature_batch(dataset, feature_names)?;
+    let path = export_path(name, "parquet")?;
+    let file = std::fs::File::create(&path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let props = parquet::file::properties::WriterProperties::builder()
+        .set_compression(parquet::basic::Compression::SNAPPY)
+        .build();
+    let mut writer = parquet::arrow::ArrowWriter::try_new(file, batch.schema(), Some(props)).map_err(|e| e.to_string())?;
+    writer.write(&batch).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    writer.close().map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// The samples of `dataset` in long format, one row per (sample, time step): `sample`,
+/// `step` (0 = oldest day of the window) and `date`, the raw (unstandardized) feature
+/// columns named by `feature_names`, then the sample's targets repeated on every row
+/// of its window.
+#[cfg(feature = "native")]
+pub fn feature_batch(dataset: &VolDataset, feature_names: &[String]) -> Result<RecordBatch, String> {
+    let rows: Vec<(u32, u32)> = dataset
+        .samples
+        .iter()
+        .enumerate()
+        .flat_map(|(i, s)| (0..s.rows.len()).map(move |t| (i as u32, t as u32)))
+        .collect();
+    let sample = |&(i, _): &(u32, u32)| &dataset.samples[i as usize];
+    // Panel row of a (sample, step) row
+    let panel_row = |r: &(u32, u32)| sample(r).rows.start + r.1 as usize;
+
+    let mut fields = vec![
+        Field::new("sample", DataType::UInt32, false),
+        Field::new("step", DataType::UInt32, false),
+        Field::new("date", DataType::Date32, true),
+    ];
+    let mut columns: Vec<ArrayRef> = vec![
+        Arc::new(UInt32Array::from_iter_values(rows.iter().map(|r| r.0))),
+        Arc::new(UInt32Array::from_iter_values(rows.iter().map(|r| r.1))),
+        Arc::new(Date32Array::from_iter(
+            rows.iter().map(|r| dataset.dates.get(panel_row(r)).map(|d| Date32Type::from_naive_date(*d))),
+        )),
+    ];
+    let mut push_column = |name: String, values: Float64Array| {
+        fields.push(Field::new(name, DataType::Float64, true));
+        columns.push(Arc::new(values));
+    };
+
+    for (f, feature) in feature_names.iter().enumerate() {
+        let values = rows.iter().map(|r| dataset.panel.get((panel_row(r), f)).copied());
+        push_column(feature.clone(), Float64Array::from_iter(values));
+    }
+
+    let target = |value: fn(&crate::nn::dataset::VolSample) -> f64| {
+        Float64Array::from_iter_values(rows.iter().map(|r| value(sample(r))))
+    };
+    push_column("target_vol".into(), target(|s| s.target_vol));
+    push_column("current_vol".into(), target(|s| s.current_vol));
+    push_column("target_benchmark_vol".into(), target(|s| s.target_benchmark_vol));
+    push_column("current_benchmark_vol".into(), target(|s| s.current_benchmark_vol));
+
+    let per_sector = |values: &dyn Fn(&crate::nn::dataset::VolSample) -> Option<f64>| {
+        Float64Array::from_iter(rows.iter().map(|r| values(sample(r))))
+    };
+    for (i, symbol) in config::sector_etfs().iter().map(|s| &s.symbol).enumerate() {
+        push_column(format!("{}_target_vol", symbol), per_sector(&|s| s.target_sector_vols.get(i).copied()));
+        push_column(format!("{}_current_vol", symbol), per_sector(&|s| s.current_sector_vols.get(i).copied()));
+        push_column(format!("{}_target_entropy", symbol), per_sector(&|s| s.target_randomness.get(i).copied()));
+        push_column(format!("{}_target_kurtosis", symbol), per_sector(&|s| s.target_kurtosis.get(2 * i).copied()));
+        push_column(format!("{}_target_skew", symbol), per_sector(&|s| s.target_kurtosis.get(2 * i + 1).copied()));
+    }
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).map_err(|e| format!("Invalid feature table: {}", e))
+}
+
+/// Sheets of the full analysis workbook: one per sector (bars and vols), then the
+/// correlation matrix, spreads and NN predictions where available
+pub fn analysis_sheets(
+    sectors: &[SectorTimeSeries],
+    volatility: &[VolatilityMetrics],
+    correlation: Option<&CorrelationMatrix>,
+    spreads: &[BondSpread],
+    predictions: &NnPredictions,
+) -> Vec<(String, CsvTable)> {
+    let mut sheets: Vec<(String, CsvTable)> = sectors
+        .iter()
+        .map(|s| (s.symbol.clone(), sector_table(s, volatility.iter().find(|v| v.symbol == s.symbol))))
+        .collect();
+    if let Some(corr) = correlation {
+        sheets.push(("Correlation".to_string(), correlation_table(corr)));
+    }
+    if !spreads.is_empty() {
+        sheets.push(("Spreads".to_string(), spreads_table(spreads)));
+    }
+    if !predictions.is_empty() {
+        sheets.push(("Predictions".to_string(), predictions_table(predictions)));
+    }
+    sheets
+}
+
+/// One row per sector with every prediction the model made for it; blank where a
+/// prediction mode wasn't trained
+pub fn predictions_table(predictions: &NnPredictions) -> CsvTable {
+    let mut table = CsvTable::new(&[
+        "symbol",
+        "forward_vol",
+        "entropy",
+        "kurtosis",
+        "skewness",
+        "regime_low",
+        "regime_normal",
+        "regime_high",
+    ]);
+    let mut symbols: Vec<&str> = Vec::new();
+    let all = predictions
+        .vol
+        .iter()
+        .map(|(s, _)| s)
+        .chain(predictions.randomness.iter().map(|(s, _)| s))
+        .chain(predictions.kurtosis.iter().map(|(s, _, _)| s))
+        .chain(predictions.regime_probs.iter().map(|(s, _)| s));
+    for symbol in all {
+        if !symbols.contains(&symbol.as_str()) {
+            symbols.push(symbol);
+        }
+    }
+    let find = |values: &[(String, f64)], symbol: &str| {
+        values.iter().find(|(s, _)| s == symbol).map_or(String::new(), |(_, v)| v.to_string())
+    };
+    for symbol in symbols {
+        let kurtosis = predictions.kurtosis.iter().find(|(s, _, _)| s == symbol);
+        let regime = predictions.regime_probs.iter().find(|(s, _)| s == symbol);
+        let mut row = vec![
+            symbol.to_string(),
+            find(&predictions.vol, symbol),
+            find(&predictions.randomness, symbol),
+            kurtosis.map_or(String::new(), |k| k.1.to_string()),
+            kurtosis.map_or(String::new(), |k| k.2.to_string()),
+        ];
+        row.extend((0..3).map(|i| regime.map_or(String::new(), |(_, p)| p[i].to_string())));
+        table.rows.push(row);
+    }
+    table
+}
+
+/// One row per bar: price, volume, drawdown and the rolling vol series as charted in
+/// the sector view. Vol series end on the last bar; earlier rows are blank.
+pub fn sector_table(series: &SectorTimeSeries, vol: Option<&VolatilityMetrics>) -> CsvTable {
+    let mut table = CsvTable::new(&[
+        "date",
+        "close",
+        "volume",
+        "drawdown",
+        "short_vol",
+        "long_vol",
+        "parkinson_vol",
+        "vol_ratio",
+    ]);
+    let n = series.bars.len();
+    let drawdowns = drawdown::drawdown_series(&series.close_prices());
+    // Value of a trailing-aligned series at bar `i`, if it reaches back that far
+    let aligned = |values: &[f64], i: usize| {
+        (i + values.len())
+            .checked_sub(n)
+            .and_then(|k| values.get(k))
+            .map_or(String::new(), |v| v.to_string())
+    };
+    let empty: &[f64] = &[];
+    for (i, bar) in series.bars.iter().enumerate() {
+        table.rows.push(vec![
+            bar.date.to_string(),
+            bar.close.to_string(),
+            bar.volume.to_string(),
+            aligned(&drawdowns, i),
+            aligned(vol.map_or(empty, |v| &v.short_window_vol), i),
+            aligned(vol.map_or(empty, |v| &v.long_window_vol), i),
+            aligned(vol.map_or(empty, |v| &v.parkinson_vol), i),
+            aligned(vol.map_or(empty, |v| &v.vol_ratio), i),
+        ]);
+    }
+    table
+}
+
+/// The matrix with symbols as both the header row and the first column
+pub fn correlation_table(corr: &CorrelationMatrix) -> CsvTable {
+    let mut headers = vec!["symbol".to_string()];
+    headers.extend(corr.symbols.iter().cloned());
+    let rows = corr
+        .symbols
+        .iter()
+        .zip(&corr.matrix)
+        .map(|(symbol, row)| std::iter::once(symbol.clone()).chain(row.iter().map(|v| v.to_string())).collect())
+        .collect();
+    CsvTable { headers, rows }
+}
+
+/// Yield per maturity, one column per curve date; blank where a maturity isn't quoted
+pub fn yield_curve_table(curves: &[&TreasuryRate]) -> CsvTable {
+    let mut headers = vec!["maturity".to_string()];
+    headers.extend(curves.iter().map(|r| r.date.clone()));
+    let points: Vec<Vec<(&str, f64)>> = curves.iter().map(|r| bond_spreads::yield_curve_for_date(r)).collect();
+    let rows = bond_spreads::MATURITY_LABELS
+        .iter()
+        .map(|label| {
+            std::iter::once(label.to_string())
+                .chain(points.iter().map(|curve| {
+                    curve.iter().find(|(l, _)| l == label).map_or(String::new(), |(_, v)| v.to_string())
+                }))
+                .collect()
+        })
+        .collect();
+    CsvTable { headers, rows }
+}
+
+/// Term spread and curve slope history, oldest first
+pub fn spreads_table(spreads: &[BondSpread]) -> CsvTable {
+    let mut table = CsvTable::new(&["date", "spread_10y_2y", "curve_slope_30y_3m"]);
+    let mut sorted: Vec<&BondSpread> = spreads.iter().collect();
+    sorted.sort_by_key(|s| s.date);
+    table.rows = sorted
+        .into_iter()
+        .map(|s| vec![s.date.to_string(), s.spread_10y_2y.to_string(), s.curve_slope.to_string()])
+        .collect();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::OhlcvBar;
+    use arrow_array::Array;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_sector_table_aligns_vol_series_to_last_bar() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let bars = (0..4)
+            .map(|i| OhlcvBar {
+                date: start + chrono::Days::new(i),
+                open: 100.0,
+                high: 100.0,
+                low: 100.0,
+                close: 100.0 + i as f64,
+                volume: 10,
+            })
+            .collect();
+        let series = SectorTimeSeries { symbol: "XLK".into(), name: "Technology".into(), bars };
+        let vol = VolatilityMetrics {
+            symbol: "XLK".into(),
+            dates: vec![],
+            short_window_vol: vec![0.1, 0.2],
+            long_window_vol: vec![0.3],
+            parkinson_vol: vec![],
+            vol_ratio: vec![0.5],
+        };
+        let table = sector_table(&series, Some(&vol));
+        assert_eq!(table.rows.len(), 4);
+        assert_eq!(table.rows[0][4], "");
+        assert_eq!(table.rows[2][4], "0.1");
+        assert_eq!(table.rows[3][4..], ["0.2", "0.3", "", "0.5"]);
+        assert_eq!(table.rows[3][0], "2024-01-04");
+    }
+
+    #[test]
+    fn test_correlation_table_is_square_with_labels() {
+        let corr = CorrelationMatrix {
+            symbols: vec!["XLK".into(), "XLE".into()],
+            matrix: vec![vec![1.0, 0.25], vec![0.25, 1.0]],
+        };
+        let table = correlation_table(&corr);
+        assert_eq!(table.headers, ["symbol", "XLK", "XLE"]);
+        assert_eq!(table.rows[1], ["XLE", "0.25", "1"]);
+    }
+
+    #[test]
+    fn test_predictions_table_joins_modes_by_symbol() {
+        let predictions = NnPredictions {
+            vol: vec![("XLK".into(), 0.2)],
+            kurtosis: vec![("XLE".into(), 3.5, -0.5)],
+            ..Default::default()
+        };
+        let table = predictions_table(&predictions);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0][..3], ["XLK", "0.2", ""]);
+        assert_eq!(table.rows[1][3..5], ["3.5", "-0.5"]);
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_feature_batch_long_format() {
+        use crate::nn::dataset::VolSample;
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        // Two windows of three days, one day apart; their panel rows are kept apart
+        // so each holds its own values
+        let sample = |rows: std::ops::Range<usize>, value: f64| VolSample {
+            rows,
+            target_vol: value,
+            current_vol: 0.0,
+            target_sector_vols: vec![0.2; 11],
+            current_sector_vols: vec![0.1; 11],
+            target_benchmark_vol: 0.0,
+            current_benchmark_vol: 0.0,
+            target_randomness: vec![],
+            target_kurtosis: vec![],
+        };
+        let panel = ndarray::Array2::from_shape_fn((6, 2), |(t, f)| if t < 3 { 1.0 } else { 2.0 } * (f + 1) as f64);
+        let dates = [0, 1, 2, 1, 2, 3].into_iter().map(|d| start + chrono::Days::new(d)).collect();
+        let dataset =
+            VolDataset { panel: Arc::new(panel), dates, samples: vec![sample(0..3, 1.0), sample(3..6, 2.0)] };
+        let batch = feature_batch(&dataset, &["a".to_string(), "b".to_string()]).unwrap();
+
+        assert_eq!(batch.num_rows(), 6);
+        assert_eq!(batch.num_columns(), 3 + 2 + 4 + 5 * 11);
+        let dates = batch.column_by_name("date").unwrap().as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(dates.value_as_date(3), Some(start + chrono::Days::new(1)));
+        let b = batch.column_by_name("b").unwrap().as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(b.value(4), 4.0);
+        let target = batch.column_by_name("target_vol").unwrap().as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(target.value(2), 1.0);
+        let entropy = batch.column_by_name("XLK_target_entropy").unwrap();
+        assert_eq!(entropy.null_count(), 6);
+    }
+
+    #[test]
+    fn test_sector_series_batch_nulls_before_vol_starts() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let bars = (0..4)
+            .map(|i| OhlcvBar {
+                date: start + chrono::Days::new(i),
+                open: 100.0,
+                high: 101.0,
+                low: 99.0,
+                close: 100.0 + i as f64,
+                volume: 10,
+            })
+            .collect();
+        let series = SectorTimeSeries { symbol: "XLK".into(), name: "Technology".into(), bars };
+        let vol = VolatilityMetrics {
+            symbol: "XLK".into(),
+            dates: vec![],
+            short_window_vol: vec![0.1, 0.2],
+            long_window_vol: vec![],
+            parkinson_vol: vec![],
+            vol_ratio: vec![],
+        };
+        let batch = sector_series_batch(&series, Some(&vol)).unwrap();
+        assert_eq!(batch.num_rows(), 4);
+        let short = batch.column_by_name("short_vol").unwrap().as_any().downcast_ref::<Float64Array>().unwrap();
+        assert!(short.is_null(1));
+        assert_eq!(short.value(2), 0.1);
+        assert_eq!(batch.column_by_name("long_vol").unwrap().null_count(), 4);
+        let symbols = batch.column_by_name("symbol").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(symbols.value(3), "XLK");
+    }
+
+    #[test]
+    fn test_to_tsv_escapes_separators() {
+        let mut table = CsvTable::new(&["symbol", "note"]);
+        table.rows.push(vec!["XLK".to_string(), "a\tb\nc".to_string()]);
+        assert_eq!(table.to_tsv(), "symbol\tnote\nXLK\ta b c\n");
+    }
+}
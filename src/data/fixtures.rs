@@ -0,0 +1,180 @@
+//! Record and replay of provider responses, so tests and a demo mode can run offline.
+//!
+//! Every fetcher asks for its response body through [`text`] or [`json`] under a fixture
+//! name. Live (the default) just fetches. Recording (`VOL_FIXTURES=record`) also saves
+//! each body to `fixtures/`, bypassing the cache so every source is actually requested.
+//! Replaying (`VOL_FIXTURES=replay`, or `--demo`) reads the saved bodies and never
+//! touches the network; the cache moves to `cache/replay/` so replayed data can't
+//! overwrite real downloads.
+//!
+//! FMP and CBOE bodies are stored as received, so decoding them is exercised exactly as
+//! in production. Yahoo is reached through `yahoo_finance_api`, so its quotes are stored
+//! as the crate decodes them.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Folder fixtures are recorded to and replayed from, in the working directory
+pub const FIXTURE_DIR: &str = "fixtures";
+
+/// Environment variable selecting the mode: "record" or "replay"
+pub const MODE_ENV: &str = "VOL_FIXTURES";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FixtureMode {
+    #[default]
+    Live,
+    Record,
+    Replay,
+}
+
+impl FixtureMode {
+    /// From [`MODE_ENV`]; live when unset or unrecognized
+    pub fn from_env() -> Self {
+        match std::env::var(MODE_ENV).as_deref().map(str::trim) {
+            Ok("record") => Self::Record,
+            Ok("replay") => Self::Replay,
+            _ => Self::Live,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Live => "Live",
+            Self::Record => "Recording fixtures",
+            Self::Replay => "Replaying fixtures",
+        }
+    }
+}
+
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_mode(mode: FixtureMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn mode() -> FixtureMode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => FixtureMode::Record,
+        2 => FixtureMode::Replay,
+        _ => FixtureMode::Live,
+    }
+}
+
+/// Fixture folder path
+pub fn fixture_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join(FIXTURE_DIR)
+}
+
+/// Response body `name`: fetched by `live`, saved too when recording, or read from the
+/// fixture instead when replaying
+pub async fn text<Fut>(name: &str, live: impl FnOnce() -> Fut) -> Result<String>
+where
+    Fut: Future<Output = Result<String>>,
+{
+    respond(mode(), &fixture_dir(), name, live).await
+}
+
+/// A decoded provider response, recorded and replayed as JSON like [`text`]
+pub async fn json<T, Fut>(name: &str, live: impl FnOnce() -> Fut) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    Fut: Future<Output = Result<T>>,
+{
+    let body = text(name, || async { Ok(serde_json::to_string(&live().await?)?) }).await?;
+    serde_json::from_str(&body).with_context(|| format!("Failed to decode fixture {}", name))
+}
+
+/// Body of a GET request to `url`, as a fixture named `name`
+pub async fn get(name: &str, url: &str) -> Result<String> {
+    text(name, || async {
+        let resp = reqwest::get(url).await.with_context(|| format!("Request for {} failed", name))?;
+        resp.text().await.with_context(|| format!("Failed to read {} response", name))
+    })
+    .await
+}
+
+async fn respond<Fut>(mode: FixtureMode, dir: &Path, name: &str, live: impl FnOnce() -> Fut) -> Result<String>
+where
+    Fut: Future<Output = Result<String>>,
+{
+    match mode {
+        FixtureMode::Live => live().await,
+        FixtureMode::Record => {
+            let body = live().await?;
+            if let Err(e) = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(dir.join(name), &body)) {
+                tracing::warn!("Failed to record fixture {}: {}", name, e);
+            }
+            Ok(body)
+        }
+        FixtureMode::Replay => load(dir, name),
+    }
+}
+
+/// A recorded body from `dir`
+pub fn load(dir: &Path, name: &str) -> Result<String> {
+    let path = dir.join(name);
+    std::fs::read_to_string(&path).with_context(|| format!("No recorded response at {}", path.display()))
+}
+
+/// Offline decode tests of every bundled fixture, as each fetcher decodes it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+    use crate::data::{cboe, fmp, yahoo};
+
+    fn bundled(name: &str) -> String {
+        load(Path::new(FIXTURE_DIR), name).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let dir = std::env::temp_dir().join(format!("vol_fixtures_{}", std::process::id()));
+        let body = respond(FixtureMode::Record, &dir, "probe.txt", || async { Ok("recorded".to_string()) }).await;
+        assert_eq!(body.unwrap(), "recorded");
+
+        let replayed = respond(FixtureMode::Replay, &dir, "probe.txt", || async { anyhow::bail!("network used") }).await;
+        assert_eq!(replayed.unwrap(), "recorded");
+        let missing = respond(FixtureMode::Replay, &dir, "missing.txt", || async { Ok(String::new()) }).await;
+        assert!(missing.unwrap_err().to_string().contains("No recorded response"));
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_fmp_fixtures_decode() {
+        let rates = fmp::parse_treasury_rates(&bundled(fmp::TREASURY_FIXTURE)).unwrap();
+        assert!(rates.len() > 50);
+        assert!(rates.iter().all(|r| r.parsed_date().is_some() && r.year10.is_some()));
+
+        let sectors = fmp::parse_sector_performance(&bundled(fmp::SECTOR_PERFORMANCE_FIXTURE)).unwrap();
+        assert_eq!(sectors.len(), 11);
+    }
+
+    #[test]
+    fn test_cboe_fixtures_decode() {
+        let put_call = cboe::parse_totalpc_csv(&bundled(cboe::PUT_CALL_FIXTURE)).unwrap();
+        assert!(put_call.len() > 20);
+        let skew = cboe::parse_skew_csv(&bundled(cboe::SKEW_FIXTURE)).unwrap();
+        assert!(skew.len() > 20);
+        assert!(skew.iter().all(|r| r.skew > 100.0));
+    }
+
+    #[test]
+    fn test_yahoo_fixtures_decode() {
+        let symbols =
+            config::sector_etfs().iter().map(|s| s.symbol.as_str()).chain(std::iter::once(config::benchmark_symbol()));
+        for symbol in symbols {
+            let quotes: Vec<yahoo_finance_api::Quote> =
+                serde_json::from_str(&bundled(&yahoo::quotes_fixture(symbol))).unwrap();
+            let bars = yahoo::bars_from_quotes(&quotes);
+            assert_eq!(bars.len(), quotes.len(), "{}", symbol);
+            assert!(bars.windows(2).all(|w| w[0].date < w[1].date), "{} out of order", symbol);
+        }
+    }
+}
@@ -1,39 +1,99 @@
+use std::sync::RwLock;
+
 use anyhow::{Context, Result};
 
-use crate::data::cache;
+use crate::data::cache::{self, Fetched};
+use crate::data::fixtures;
 use crate::data::models::TreasuryRate;
 use crate::data::models::SectorPerformance;
 
+/// Cache files of the treasury rates and the sector performance snapshot
+pub const TREASURY_CACHE_FILE: &str = "fmp_treasury_rates.json";
+pub const SECTOR_PERFORMANCE_CACHE_FILE: &str = "fmp_sector_performance.json";
+
+/// Live API root, used unless the active API environment points elsewhere
+pub const DEFAULT_BASE_URL: &str = "https://financialmodelingprep.com/stable";
+
+static BASE_URL: RwLock<String> = RwLock::new(String::new());
+
+/// Send requests to `url` from now on; empty = [`DEFAULT_BASE_URL`]
+pub fn set_base_url(url: &str) {
+    if let Ok(mut base) = BASE_URL.write() {
+        *base = url.to_string();
+    }
+}
+
+fn base_url() -> String {
+    match BASE_URL.read() {
+        Ok(base) if !base.is_empty() => base.clone(),
+        _ => DEFAULT_BASE_URL.to_string(),
+    }
+}
+
+/// Fixtures of the raw treasury-rates and sector-performance response bodies
+pub const TREASURY_FIXTURE: &str = "fmp_treasury_rates.json";
+pub const SECTOR_PERFORMANCE_FIXTURE: &str = "fmp_sector_performance.json";
+
 /// Fetch treasury rates from FMP API
-pub async fn fetch_treasury_rates(api_key: &str) -> Result<Vec<TreasuryRate>> {
-    let cache_file = "fmp_treasury_rates.json";
-    if cache::is_cache_fresh(cache_file, 12) {
+pub async fn fetch_treasury_rates(api_key: &str) -> Result<Fetched<Vec<TreasuryRate>>> {
+    let cache_file = &cache::provider_file(TREASURY_CACHE_FILE);
+    if cache::is_cache_fresh(cache_file, cache::max_age_hours()) {
         if let Ok(cached) = cache::load_json::<Vec<TreasuryRate>>(cache_file) {
             tracing::info!("Using cached treasury rates");
-            return Ok(cached);
+            return Ok(Fetched::cached(cached, cache_file));
         }
     }
 
     tracing::info!("Fetching FMP treasury rates");
     let url = format!(
-        "https://financialmodelingprep.com/stable/treasury-rates?apikey={}",
+        "{}/treasury-rates?apikey={}",
+        base_url(),
         api_key
     );
 
-    let resp = reqwest::get(&url)
+    let body = fixtures::get(TREASURY_FIXTURE, &url)
         .await
         .context("Failed to fetch treasury rates")?;
-
-    let rates: Vec<TreasuryRate> = resp
-        .json()
-        .await
-        .context("Failed to parse treasury rates JSON")?;
+    let rates = parse_treasury_rates(&body)?;
 
     if let Err(e) = cache::save_json(cache_file, &rates) {
         tracing::warn!("Failed to cache treasury rates: {}", e);
     }
 
-    Ok(rates)
+    Ok(Fetched::network(rates))
+}
+
+/// Decode a treasury-rates response body
+pub fn parse_treasury_rates(body: &str) -> Result<Vec<TreasuryRate>> {
+    serde_json::from_str(body)
+        .with_context(|| format!("Failed to parse treasury rates JSON: {}", &body[..200.min(body.len())]))
+}
+
+/// Decode a sector-performance snapshot body, keeping the first entry per sector
+/// (typically NASDAQ)
+pub fn parse_sector_performance(body: &str) -> Result<Vec<SectorPerformance>> {
+    let perf: Vec<SectorPerformance> = serde_json::from_str(body)?;
+    let mut seen = std::collections::HashSet::new();
+    Ok(perf.into_iter().filter(|p| seen.insert(p.sector.clone())).collect())
+}
+
+/// Check `api_key` with one uncached treasury-rates request. FMP answers a bad key
+/// with a JSON error message, which is passed through.
+pub async fn test_connection(api_key: &str) -> Result<String> {
+    let url = format!(
+        "{}/treasury-rates?apikey={}",
+        base_url(),
+        api_key
+    );
+    let resp = reqwest::get(&url).await.context("Could not reach FMP")?;
+    let status = resp.status();
+    let text = resp.text().await.context("Failed to read FMP response")?;
+    if !status.is_success() {
+        anyhow::bail!("HTTP {}: {}", status, &text[..200.min(text.len())]);
+    }
+    let rates: Vec<TreasuryRate> = serde_json::from_str(&text)
+        .with_context(|| format!("Unexpected response: {}", &text[..200.min(text.len())]))?;
+    Ok(format!("OK, {} treasury rate rows", rates.len()))
 }
 
 /// Test for fetch_treasury_rates: fetches, prints JSON to debug terminal.
@@ -50,7 +110,7 @@ mod tests {
         let res = fetch_treasury_rates(&api_key).await;
         match res {
             Ok(rates) => {
-                let json = serde_json::to_string_pretty(&rates).unwrap();
+                let json = serde_json::to_string_pretty(&rates.data).unwrap();
                 // Print to debug terminal
                 println!("{}", json);
             }
@@ -61,12 +121,12 @@ mod tests {
 
 /// Fetch sector performance from FMP stable sector-performance-snapshot endpoint.
 /// Tries recent business days until data is found.
-pub async fn fetch_sector_performance(api_key: &str) -> Result<Vec<SectorPerformance>> {
-    let cache_file = "fmp_sector_performance.json";
+pub async fn fetch_sector_performance(api_key: &str) -> Result<Fetched<Vec<SectorPerformance>>> {
+    let cache_file = &cache::provider_file(SECTOR_PERFORMANCE_CACHE_FILE);
     if cache::is_cache_fresh(cache_file, 1) {
         if let Ok(cached) = cache::load_json::<Vec<SectorPerformance>>(cache_file) {
             tracing::info!("Using cached sector performance");
-            return Ok(cached);
+            return Ok(Fetched::cached(cached, cache_file));
         }
     }
 
@@ -78,37 +138,27 @@ pub async fn fetch_sector_performance(api_key: &str) -> Result<Vec<SectorPerform
         let date = today - chrono::Duration::days(offset);
         let date_str = date.format("%Y-%m-%d");
         let url = format!(
-            "https://financialmodelingprep.com/stable/sector-performance-snapshot?date={}&apikey={}",
-            date_str, api_key
+            "{}/sector-performance-snapshot?date={}&apikey={}",
+            base_url(),
+            date_str,
+            api_key
         );
 
-        let resp = match reqwest::get(&url).await {
-            Ok(r) => r,
+        let text = match fixtures::get(SECTOR_PERFORMANCE_FIXTURE, &url).await {
+            Ok(t) => t,
             Err(e) => {
-                tracing::debug!("Request failed for {}: {}", date_str, e);
+                tracing::debug!("Request failed for {}: {:#}", date_str, e);
                 continue;
             }
         };
 
-        let text = match resp.text().await {
-            Ok(t) => t,
-            Err(_) => continue,
-        };
-
         if text.contains("Error") || text.contains("error") {
             tracing::debug!("FMP error for {}: {}", date_str, &text[..200.min(text.len())]);
             continue;
         }
 
-        match serde_json::from_str::<Vec<SectorPerformance>>(&text) {
-            Ok(perf) if !perf.is_empty() => {
-                // Deduplicate by sector (keep first occurrence per sector — typically NASDAQ)
-                let mut seen = std::collections::HashSet::new();
-                let deduped: Vec<SectorPerformance> = perf
-                    .into_iter()
-                    .filter(|p| seen.insert(p.sector.clone()))
-                    .collect();
-
+        match parse_sector_performance(&text) {
+            Ok(deduped) if !deduped.is_empty() => {
                 tracing::info!(
                     "Got sector performance for {} ({} sectors)",
                     date_str,
@@ -118,7 +168,7 @@ pub async fn fetch_sector_performance(api_key: &str) -> Result<Vec<SectorPerform
                 if let Err(e) = cache::save_json(cache_file, &deduped) {
                     tracing::warn!("Failed to cache sector performance: {}", e);
                 }
-                return Ok(deduped);
+                return Ok(Fetched::network(deduped));
             }
             Ok(_) => continue,
             Err(e) => {
@@ -128,6 +178,5 @@ pub async fn fetch_sector_performance(api_key: &str) -> Result<Vec<SectorPerform
         }
     }
 
-    tracing::warn!("Could not fetch sector performance for any recent date");
-    Ok(vec![])
+    anyhow::bail!("Could not fetch sector performance for any recent date")
 }
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "native")]
+use crate::data::cache::Fetched;
+
+/// Upstream feed a market data refresh pulls from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DataSource {
+    /// Sector ETF and benchmark price history
+    Yahoo,
+    FmpTreasury,
+    FmpSectors,
+    CboePutCall,
+    CboeSkew,
+}
+
+impl DataSource {
+    pub const ALL: [DataSource; 5] =
+        [Self::Yahoo, Self::FmpTreasury, Self::FmpSectors, Self::CboePutCall, Self::CboeSkew];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Yahoo => "Yahoo",
+            Self::FmpTreasury => "FMP Rates",
+            Self::FmpSectors => "FMP Sectors",
+            Self::CboePutCall => "CBOE P/C",
+            Self::CboeSkew => "CBOE SKEW",
+        }
+    }
+}
+
+/// Result of one fetch from a source during a refresh
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FetchOutcome {
+    Fetched { from_cache: bool, fetched_at: DateTime<Local> },
+    Failed(String),
+}
+
+impl FetchOutcome {
+    #[cfg(feature = "native")]
+    pub fn of<T>(result: &anyhow::Result<Fetched<T>>) -> Self {
+        match result {
+            Ok(f) => Self::Fetched { from_cache: f.from_cache, fetched_at: f.fetched_at },
+            Err(e) => Self::Failed(format!("{:#}", e)),
+        }
+    }
+}
+
+/// Session totals and latest refresh state of one source
+#[derive(Debug, Clone, Default)]
+pub struct SourceHealth {
+    /// Oldest data timestamp among the source's fetches in the latest refresh
+    pub data_as_of: Option<DateTime<Local>>,
+    pub cache_hits: usize,
+    pub cache_misses: usize,
+    pub errors: usize,
+    pub last_error: Option<String>,
+    /// Whether any fetch from the source failed in the latest refresh
+    pub failing: bool,
+}
+
+/// Per-source fetch health, accumulated over the session
+#[derive(Debug, Clone, Default)]
+pub struct DataHealth {
+    sources: [SourceHealth; DataSource::ALL.len()],
+}
+
+impl DataHealth {
+    pub fn get(&self, source: DataSource) -> &SourceHealth {
+        &self.sources[source as usize]
+    }
+
+    /// Fold one refresh's fetch outcomes into the session totals
+    pub fn record_refresh(&mut self, log: &[(DataSource, FetchOutcome)]) {
+        for source in DataSource::ALL {
+            let outcomes: Vec<&FetchOutcome> = log.iter().filter(|(s, _)| *s == source).map(|(_, o)| o).collect();
+            if outcomes.is_empty() {
+                continue;
+            }
+            let health = &mut self.sources[source as usize];
+            health.failing = false;
+            let mut as_of: Option<DateTime<Local>> = None;
+            for outcome in outcomes {
+                match outcome {
+                    FetchOutcome::Fetched { from_cache, fetched_at } => {
+                        if *from_cache {
+                            health.cache_hits += 1;
+                        } else {
+                            health.cache_misses += 1;
+                        }
+                        as_of = Some(as_of.map_or(*fetched_at, |t| t.min(*fetched_at)));
+                    }
+                    FetchOutcome::Failed(e) => {
+                        health.errors += 1;
+                        health.last_error = Some(e.clone());
+                        health.failing = true;
+                    }
+                }
+            }
+            if as_of.is_some() {
+                health.data_as_of = as_of;
+            }
+        }
+    }
+
+    pub fn total_cache_hits(&self) -> usize {
+        self.sources.iter().map(|s| s.cache_hits).sum()
+    }
+
+    pub fn total_cache_misses(&self) -> usize {
+        self.sources.iter().map(|s| s.cache_misses).sum()
+    }
+
+    pub fn total_errors(&self) -> usize {
+        self.sources.iter().map(|s| s.errors).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_refresh_accumulates_and_keeps_oldest_timestamp() {
+        let old = Local::now() - chrono::Duration::hours(6);
+        let new = Local::now();
+        let mut health = DataHealth::default();
+        health.record_refresh(&[
+            (DataSource::Yahoo, FetchOutcome::Fetched { from_cache: true, fetched_at: old }),
+            (DataSource::Yahoo, FetchOutcome::Fetched { from_cache: false, fetched_at: new }),
+            (DataSource::FmpTreasury, FetchOutcome::Failed("timeout".into())),
+        ]);
+        let yahoo = health.get(DataSource::Yahoo);
+        assert_eq!((yahoo.cache_hits, yahoo.cache_misses, yahoo.errors), (1, 1, 0));
+        assert_eq!(yahoo.data_as_of, Some(old));
+        assert!(health.get(DataSource::FmpTreasury).failing);
+
+        // A later successful refresh clears the failing flag but keeps the error count
+        health.record_refresh(&[(DataSource::FmpTreasury, FetchOutcome::Fetched { from_cache: false, fetched_at: new })]);
+        let rates = health.get(DataSource::FmpTreasury);
+        assert!(!rates.failing);
+        assert_eq!((rates.errors, rates.data_as_of), (1, Some(new)));
+        assert_eq!(health.total_cache_hits() + health.total_cache_misses(), 3);
+        assert_eq!(health.get(DataSource::CboeSkew).data_as_of, None);
+    }
+}
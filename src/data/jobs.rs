@@ -0,0 +1,438 @@
+//! Cron-style job scheduler: jobs such as "fetch at 16:30 ET", "retrain on Sundays" or
+//! "report on Fridays" run at the minutes a five-field cron expression matches, in
+//! local or New York time. Auto-refresh is the job the status bar's interval picker
+//! controls; the Jobs window lists every job with its next run and last result.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::tasks::TaskId;
+
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const MONTH_NAMES: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+/// Name of the refresh job the status bar's auto-refresh picker sets up
+pub const AUTO_REFRESH_JOB: &str = "Auto-refresh";
+
+/// Days searched for the next matching minute; an expression such as `0 0 30 2 *`
+/// (30 February) never matches
+const SEARCH_DAYS: i64 = 366;
+
+/// A parsed cron expression: minute, hour, day of month, month and day of week. Each
+/// field takes `*`, a value, a range `a-b`, a step `*/n` or `a-b/n`, or a comma list;
+/// months and weekdays also take three-letter names, and Sunday is 0 or 7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Both day fields are restricted, so a day matching either one runs (as in cron)
+    either_day: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return Err(format!(
+                "Expected 5 fields (minute hour day month weekday), got {}",
+                fields.len()
+            ));
+        };
+        let mut weekdays = parse_field(weekday, 0, 7, &WEEKDAY_NAMES).map_err(|e| format!("Weekday: {}", e))?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, &[]).map_err(|e| format!("Minute: {}", e))?,
+            hours: parse_field(hour, 0, 23, &[]).map_err(|e| format!("Hour: {}", e))?,
+            days: parse_field(day, 1, 31, &[]).map_err(|e| format!("Day: {}", e))?,
+            months: parse_field(month, 1, 12, &MONTH_NAMES).map_err(|e| format!("Month: {}", e))?,
+            weekdays,
+            either_day: !day.starts_with('*') && !weekday.starts_with('*'),
+        })
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        let day = has(self.days, date.day());
+        let weekday = has(self.weekdays, date.weekday().num_days_from_sunday());
+        let day_ok = if self.either_day { day || weekday } else { day && weekday };
+        has(self.months, date.month()) && day_ok
+    }
+
+    /// First matching minute strictly after `after`
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let first_day = after.date();
+        (0..=SEARCH_DAYS)
+            .map(|offset| first_day + ChronoDuration::days(offset))
+            .filter(|date| self.matches_date(*date))
+            .find_map(|date| {
+                (0..24u32).filter(|h| has(self.hours, *h)).find_map(|hour| {
+                    (0..60u32)
+                        .filter(|m| has(self.minutes, *m))
+                        .map(|minute| date.and_time(NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or_default()))
+                        .find(|t| *t > after)
+                })
+            })
+    }
+}
+
+fn has(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+/// Bit set of the values `field` selects within `min..=max`
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let value = |s: &str| -> Result<u32, String> {
+        let lower = s.to_ascii_lowercase();
+        let v = match names.iter().position(|n| *n == lower) {
+            Some(i) => i as u32 + if min == 0 { 0 } else { 1 },
+            None => s.parse().map_err(|_| format!("invalid value {:?}", s))?,
+        };
+        if (min..=max).contains(&v) { Ok(v) } else { Err(format!("{} is outside {}-{}", v, min, max)) }
+    };
+    let mut bits = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().ok().filter(|s| *s > 0).ok_or(format!("invalid step {:?}", step))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (value(a)?, value(b)?)
+        } else {
+            // `5/15` runs from 5 to the end of the range
+            let v = value(range)?;
+            (v, if step > 1 { max } else { v })
+        };
+        if lo > hi {
+            return Err(format!("empty range {:?}", range));
+        }
+        for v in (lo..=hi).step_by(step as usize) {
+            bits |= 1 << v;
+        }
+    }
+    Ok(bits)
+}
+
+/// Clock a job's cron expression is read in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobTimezone {
+    #[default]
+    Local,
+    /// US Eastern time, for times relative to the exchange close
+    NewYork,
+}
+
+impl JobTimezone {
+    pub const ALL: [JobTimezone; 2] = [Self::Local, Self::NewYork];
+
+    /// Wall-clock time in this zone at local time `local`
+    fn wall_time(self, local: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            Self::Local => local,
+            Self::NewYork => match chrono::Local.from_local_datetime(&local).earliest() {
+                Some(t) => new_york_from_utc(t.naive_utc()),
+                None => local,
+            },
+        }
+    }
+
+    /// Local time at wall-clock time `wall` in this zone
+    fn local_time(self, wall: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            Self::Local => wall,
+            Self::NewYork => chrono::Local.from_utc_datetime(&new_york_to_utc(wall)).naive_local(),
+        }
+    }
+}
+
+/// US daylight saving time: from 02:00 on the second Sunday of March to 02:00 on the
+/// first Sunday of November, local time
+fn new_york_dst(utc: NaiveDateTime) -> bool {
+    let year = utc.year();
+    let sunday = |month: u32, n: u8| NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Sun, n);
+    match (sunday(3, 2), sunday(11, 1)) {
+        (Some(start), Some(end)) => {
+            // 02:00 EST and 02:00 EDT in UTC
+            let start = start.and_hms_opt(7, 0, 0).unwrap_or_default();
+            let end = end.and_hms_opt(6, 0, 0).unwrap_or_default();
+            utc >= start && utc < end
+        }
+        _ => false,
+    }
+}
+
+fn new_york_from_utc(utc: NaiveDateTime) -> NaiveDateTime {
+    utc - ChronoDuration::hours(if new_york_dst(utc) { 4 } else { 5 })
+}
+
+fn new_york_to_utc(wall: NaiveDateTime) -> NaiveDateTime {
+    let daylight = wall + ChronoDuration::hours(4);
+    if new_york_dst(daylight) { daylight } else { wall + ChronoDuration::hours(5) }
+}
+
+/// What a job does when it runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobAction {
+    #[default]
+    Refresh,
+    Retrain,
+    Report,
+    EmailReport,
+    Screenshot,
+}
+
+impl JobAction {
+    pub const ALL: [JobAction; 5] = [Self::Refresh, Self::Retrain, Self::Report, Self::EmailReport, Self::Screenshot];
+}
+
+/// Outcome of a job's latest run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobResult {
+    pub at: NaiveDateTime,
+    pub ok: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Job {
+    pub name: String,
+    pub enabled: bool,
+    /// Cron expression: minute hour day-of-month month day-of-week
+    pub cron: String,
+    pub timezone: JobTimezone,
+    pub action: JobAction,
+    /// Local time of the latest run, so a restart neither repeats nor skips it
+    pub last_run: Option<NaiveDateTime>,
+    pub last_result: Option<JobResult>,
+    /// Background task of the latest run, until it finishes
+    #[serde(skip)]
+    pub task: Option<TaskId>,
+}
+
+impl Default for Job {
+    fn default() -> Self {
+        Self {
+            name: "New job".to_string(),
+            enabled: false,
+            cron: "0 17 * * 1-5".to_string(),
+            timezone: JobTimezone::Local,
+            action: JobAction::Report,
+            last_run: None,
+            last_result: None,
+            task: None,
+        }
+    }
+}
+
+impl Job {
+    fn new(name: &str, cron: &str, timezone: JobTimezone, action: JobAction) -> Self {
+        Self { name: name.to_string(), cron: cron.to_string(), timezone, action, ..Default::default() }
+    }
+
+    /// Local time of the next run; `None` when disabled or the expression is invalid.
+    /// A run missed while the app was closed is due at once, but only one.
+    pub fn next_run(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if !self.enabled {
+            return None;
+        }
+        let schedule = CronSchedule::parse(&self.cron).ok()?;
+        // Without a previous run, the current minute still counts
+        let after = self.last_run.unwrap_or(now - ChronoDuration::minutes(1));
+        let next = schedule.next_after(self.timezone.wall_time(after))?;
+        Some(self.timezone.local_time(next))
+    }
+
+    /// Time until the next run (zero when it is due now)
+    pub fn due_in(&self, now: NaiveDateTime) -> Option<Duration> {
+        self.next_run(now).map(|next| (next - now).to_std().unwrap_or(Duration::ZERO))
+    }
+}
+
+/// Cron expression repeating every `minutes`: a divisor of an hour, or whole hours
+/// dividing a day
+pub fn interval_cron(minutes: u64) -> String {
+    match minutes {
+        0..=59 => format!("*/{} * * * *", minutes.max(1)),
+        60 => "0 * * * *".to_string(),
+        _ => format!("0 */{} * * *", (minutes / 60).min(23)),
+    }
+}
+
+/// The interval of a cron expression written by [`interval_cron`]; `None` for any other
+pub fn cron_interval(cron: &str) -> Option<u64> {
+    let fields: Vec<&str> = cron.split_whitespace().collect();
+    let minutes = match fields.as_slice() {
+        ["0", "*", "*", "*", "*"] => 60,
+        [minute, "*", "*", "*", "*"] => minute.strip_prefix("*/")?.parse().ok()?,
+        ["0", hour, "*", "*", "*"] => hour.strip_prefix("*/")?.parse::<u64>().ok()? * 60,
+        _ => return None,
+    };
+    (interval_cron(minutes) == cron).then_some(minutes)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchedulerConfig {
+    pub jobs: Vec<Job>,
+    /// Base folder job reports and screenshots are written to; each run writes into a
+    /// `YYYY-MM-DD` subfolder
+    pub output_dir: String,
+}
+
+impl Default for SchedulerConfig {
+    /// The usual routine, disabled until switched on
+    fn default() -> Self {
+        Self {
+            jobs: vec![
+                Job::new("Fetch after the close", "30 16 * * 1-5", JobTimezone::NewYork, JobAction::Refresh),
+                Job::new("Weekly retrain", "0 10 * * sun", JobTimezone::Local, JobAction::Retrain),
+                Job::new("Friday report", "0 17 * * fri", JobTimezone::NewYork, JobAction::Report),
+            ],
+            output_dir: default_output_dir(),
+        }
+    }
+}
+
+pub fn default_output_dir() -> String {
+    "./scheduled".to_string()
+}
+
+/// Jobs and the Jobs window
+#[derive(Debug, Clone, Default)]
+pub struct JobsState {
+    /// Persisted
+    pub jobs: Vec<Job>,
+    /// Persisted
+    pub output_dir: String,
+    /// Whether the Jobs window is shown
+    pub open: bool,
+}
+
+impl JobsState {
+    /// The job the status bar's auto-refresh picker controls, once it has been set up
+    pub fn auto_refresh(&self) -> Option<&Job> {
+        self.jobs.iter().find(|j| j.name == AUTO_REFRESH_JOB && j.action == JobAction::Refresh)
+    }
+
+    /// Refresh every `minutes`, or stop auto-refreshing with `None`
+    pub fn set_auto_refresh(&mut self, minutes: Option<u64>) {
+        let i = match self.jobs.iter().position(|j| j.name == AUTO_REFRESH_JOB && j.action == JobAction::Refresh) {
+            Some(i) => i,
+            None if minutes.is_none() => return,
+            None => {
+                self.jobs.push(Job::new(AUTO_REFRESH_JOB, "", JobTimezone::Local, JobAction::Refresh));
+                self.jobs.len() - 1
+            }
+        };
+        let job = &mut self.jobs[i];
+        job.enabled = minutes.is_some();
+        if let Some(minutes) = minutes {
+            job.cron = interval_cron(minutes);
+        }
+    }
+
+    /// Folder a run on `date` writes to
+    pub fn dated_dir(&self, date: NaiveDate) -> PathBuf {
+        PathBuf::from(&self.output_dir).join(date.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let cron = CronSchedule::parse("*/15 9-17 * * mon-fri").unwrap();
+        assert_eq!(cron.minutes, 1 | 1 << 15 | 1 << 30 | 1 << 45);
+        assert_eq!(cron.hours.count_ones(), 9);
+        assert_eq!(cron.weekdays, 0b0111110);
+        assert!(!cron.either_day);
+        assert_eq!(CronSchedule::parse("0 0 * * 7").unwrap().weekdays, 1);
+        assert_eq!(CronSchedule::parse("0 0 1,15 jan,jul *").unwrap().months, 1 << 1 | 1 << 7);
+        assert!(CronSchedule::parse("0 0 * *").is_err());
+        assert!(CronSchedule::parse("60 0 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * * funday").is_err());
+        assert!(CronSchedule::parse("*/0 0 * * *").is_err());
+    }
+
+    #[test]
+    fn test_next_after() {
+        // 2025-01-10 is a Friday
+        let weekdays = CronSchedule::parse("30 16 * * 1-5").unwrap();
+        assert_eq!(weekdays.next_after(at("2025-01-10 16:00")), Some(at("2025-01-10 16:30")));
+        assert_eq!(weekdays.next_after(at("2025-01-10 16:30")), Some(at("2025-01-13 16:30")));
+        // Day of month or weekday: the 1st, and every Sunday
+        let either = CronSchedule::parse("0 9 1 * 0").unwrap();
+        assert_eq!(either.next_after(at("2025-01-10 12:00")), Some(at("2025-01-12 09:00")));
+        assert_eq!(either.next_after(at("2025-01-26 12:00")), Some(at("2025-02-01 09:00")));
+        assert_eq!(CronSchedule::parse("0 0 30 2 *").unwrap().next_after(at("2025-01-01 00:00")), None);
+    }
+
+    #[test]
+    fn test_new_york_time() {
+        // EST in January, EDT in July
+        assert_eq!(new_york_from_utc(at("2025-01-10 21:30")), at("2025-01-10 16:30"));
+        assert_eq!(new_york_to_utc(at("2025-07-11 16:30")), at("2025-07-11 20:30"));
+        // DST starts on 9 March 2025 at 02:00 EST (07:00 UTC)
+        assert_eq!(new_york_from_utc(at("2025-03-09 06:59")), at("2025-03-09 01:59"));
+        assert_eq!(new_york_from_utc(at("2025-03-09 07:00")), at("2025-03-09 03:00"));
+    }
+
+    #[test]
+    fn test_job_due() {
+        let job = Job { enabled: true, ..Job::new("Report", "0 17 * * fri", JobTimezone::Local, JobAction::Report) };
+        assert_eq!(job.due_in(at("2025-01-10 16:00")), Some(Duration::from_secs(3600)));
+        assert_eq!(job.due_in(at("2025-01-10 17:00")), Some(Duration::ZERO));
+        // Missed last Friday while closed: due once, then a week after the catch-up run
+        let missed = Job { last_run: Some(at("2025-01-03 17:00")), ..job.clone() };
+        assert_eq!(missed.due_in(at("2025-01-13 09:00")), Some(Duration::ZERO));
+        let caught_up = Job { last_run: Some(at("2025-01-13 09:00")), ..job.clone() };
+        assert_eq!(caught_up.next_run(at("2025-01-13 09:01")), Some(at("2025-01-17 17:00")));
+        assert_eq!(Job { enabled: false, ..job.clone() }.due_in(at("2025-01-10 17:00")), None);
+        assert_eq!(Job { cron: "bad".to_string(), ..job }.due_in(at("2025-01-10 17:00")), None);
+    }
+
+    #[test]
+    fn test_interval_cron() {
+        for minutes in [15, 30, 60, 240] {
+            assert_eq!(cron_interval(&interval_cron(minutes)), Some(minutes));
+        }
+        assert_eq!(interval_cron(15), "*/15 * * * *");
+        assert_eq!(interval_cron(240), "0 */4 * * *");
+        assert_eq!(cron_interval("30 16 * * 1-5"), None);
+        assert_eq!(cron_interval("*/15 9-17 * * *"), None);
+    }
+
+    #[test]
+    fn test_set_auto_refresh() {
+        let mut state = JobsState::default();
+        state.set_auto_refresh(None);
+        assert!(state.jobs.is_empty());
+        state.set_auto_refresh(Some(30));
+        let job = state.auto_refresh().unwrap();
+        assert!(job.enabled);
+        assert_eq!(job.cron, "*/30 * * * *");
+        state.set_auto_refresh(None);
+        assert_eq!(state.jobs.len(), 1);
+        assert!(!state.auto_refresh().unwrap().enabled);
+    }
+}
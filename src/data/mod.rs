@@ -1,5 +1,32 @@
+#[cfg(feature = "native")]
+pub mod api_keys;
+#[cfg(feature = "native")]
+pub mod autosave;
+#[cfg(feature = "native")]
 pub mod cache;
+#[cfg(feature = "native")]
 pub mod cboe;
+#[cfg(feature = "native")]
+pub mod config_file;
+#[cfg(feature = "native")]
+pub mod email;
+#[cfg(feature = "native")]
+pub mod events;
+pub mod export;
+#[cfg(feature = "native")]
+pub mod fixtures;
+#[cfg(feature = "native")]
 pub mod fmp;
+pub mod health;
+#[cfg(feature = "native")]
+pub mod jobs;
 pub mod models;
+pub mod refresh;
+#[cfg(feature = "native")]
+pub mod startup;
+#[cfg(feature = "native")]
+pub mod webhooks;
+#[cfg(feature = "native")]
+pub mod workspace;
+#[cfg(feature = "native")]
 pub mod yahoo;
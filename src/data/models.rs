@@ -1,6 +1,8 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::data::refresh::RefreshReport;
+
 /// Single OHLCV bar for a given date
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OhlcvBar {
@@ -163,16 +165,21 @@ pub struct KurtosisMetrics {
 }
 
 /// NN predictions for vol, randomness (entropy), and kurtosis per sector
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct NnPredictions {
     pub vol: Vec<(String, f64)>,
     pub randomness: Vec<(String, f64)>,
     pub kurtosis: Vec<(String, f64, f64)>,
+    /// Regime classification mode: [low, normal, high] probabilities per sector
+    pub regime_probs: Vec<(String, [f64; 3])>,
 }
 
 impl NnPredictions {
     pub fn is_empty(&self) -> bool {
-        self.vol.is_empty() && self.randomness.is_empty() && self.kurtosis.is_empty()
+        self.vol.is_empty()
+            && self.randomness.is_empty()
+            && self.kurtosis.is_empty()
+            && self.regime_probs.is_empty()
     }
 }
 
@@ -212,6 +219,10 @@ pub struct ComputeStats {
     pub epoch_duration_ms: u64,
     pub samples_per_sec: f64,
     pub total_params: usize,
+    /// Batch size in use for the current run (auto-selected or overridden)
+    pub batch_size: usize,
+    /// Approximate memory held by the current run's feature dataset
+    pub dataset_bytes: usize,
     // GPU fields
     pub gpu_detected: bool,
     pub gpu_name: Option<String>,
@@ -228,12 +239,49 @@ impl Default for TrainingStatus {
     }
 }
 
+/// A user-defined indicator: a Rhai script evaluated per sector (see
+/// `analysis::custom_indicators`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomIndicator {
+    pub name: String,
+    pub script: String,
+    /// Computed and charted in Sector Vol
+    pub enabled: bool,
+    /// Included as an NN input feature (one value per sector per day)
+    #[serde(default)]
+    pub nn_feature: bool,
+}
+
+impl CustomIndicator {
+    /// Starting point for a new indicator
+    pub fn example() -> Self {
+        Self {
+            name: "Vol spread".to_string(),
+            script: "// Short minus long window vol, in vol points\n\
+                     let n = vol_long.len();\n\
+                     let out = [];\n\
+                     for i in 0..n {\n    \
+                         out.push((vol_short[vol_short.len() - n + i] - vol_long[i]) * 100.0);\n\
+                     }\n\
+                     out"
+                .to_string(),
+            enabled: true,
+            nn_feature: false,
+        }
+    }
+}
+
 /// Neural network feature flags for toggling input feature groups during training
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct NnFeatureFlags {
     pub sector_volatility: bool,
     pub market_randomness: bool,
     pub kurtosis: bool,
+    /// Custom indicators marked as NN features, one input per sector each. Kept here
+    /// (scripts included) so a saved model records exactly how its inputs were built.
+    #[serde(default)]
+    pub custom_indicators: Vec<CustomIndicator>,
 }
 
 impl Default for NnFeatureFlags {
@@ -242,6 +290,232 @@ impl Default for NnFeatureFlags {
             sector_volatility: true,
             market_randomness: true,
             kurtosis: true,
+            custom_indicators: Vec::new(),
+        }
+    }
+}
+
+/// What the network is trained to predict
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NnTargetMode {
+    /// Regress forward vol level plus entropy / kurtosis targets (MSE)
+    #[default]
+    Regression,
+    /// Classify each sector's forward vol into low / normal / high regimes (cross-entropy)
+    RegimeClassification,
+}
+
+/// Regression loss used for training and validation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NnLossFunction {
+    #[default]
+    Mse,
+    Mae,
+    /// Quadratic near zero, linear beyond `config::NN_HUBER_DELTA` (less dominated by spikes than MSE)
+    Huber,
+    /// QLIKE on the vol output (penalizes under-predicting vol), MSE on the auxiliary outputs
+    Qlike,
+}
+
+impl NnLossFunction {
+    pub const ALL: [NnLossFunction; 4] = [Self::Mse, Self::Mae, Self::Huber, Self::Qlike];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Mse => "MSE",
+            Self::Mae => "MAE",
+            Self::Huber => "Huber",
+            Self::Qlike => "QLIKE",
+        }
+    }
+}
+
+/// Series the regression model's primary (vol) output forecasts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NnVolTarget {
+    /// 5-day forward vol averaged across all sectors
+    #[default]
+    SectorAverage,
+    /// 5-day forward vol of one sector (index into `config::sector_etfs()`)
+    Sector(usize),
+    /// 5-day forward vol of the benchmark
+    Benchmark,
+    /// Change in average sector vol from the end of the input window to the forward period
+    VolChange,
+    /// Natural log of the average 5-day forward vol
+    LogVol,
+}
+
+impl NnVolTarget {
+    /// Every target except `Sector`, which is offered once per sector
+    pub const AGGREGATE: [NnVolTarget; 4] = [Self::SectorAverage, Self::Benchmark, Self::VolChange, Self::LogVol];
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::SectorAverage => "Sector average vol".to_string(),
+            Self::Sector(i) => format!(
+                "{} vol",
+                crate::config::sector_etfs().get(*i).map_or("Sector", |s| s.symbol.as_str())
+            ),
+            Self::Benchmark => format!("Benchmark ({}) vol", crate::config::benchmark_symbol()),
+            Self::VolChange => "Vol change".to_string(),
+            Self::LogVol => "Log vol".to_string(),
+        }
+    }
+
+    /// Whether the target is a (positive) vol level, which QLIKE requires
+    pub fn is_level(&self) -> bool {
+        matches!(self, Self::SectorAverage | Self::Sector(_) | Self::Benchmark)
+    }
+}
+
+/// Random perturbations applied to training windows (never to validation or inference).
+/// All values operate on standardized features; zero disables each one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct NnAugmentation {
+    /// Std dev of Gaussian noise added to every feature value
+    pub jitter_std: f64,
+    /// Max relative time-stretch of the window (0.2 = resampled at 0.8x to 1.2x speed)
+    pub warp_strength: f64,
+    /// Probability of zeroing each feature across the whole window
+    pub feature_dropout: f64,
+}
+
+impl NnAugmentation {
+    pub fn is_enabled(&self) -> bool {
+        self.jitter_std > 0.0 || self.warp_strength > 0.0 || self.feature_dropout > 0.0
+    }
+}
+
+/// Neural network training hyperparameters (defaults come from `config`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NnTrainingConfig {
+    pub hidden_size: usize,
+    pub lookback: usize,
+    pub learning_rate: f64,
+    pub epochs: usize,
+    #[serde(default)]
+    pub target_mode: NnTargetMode,
+    /// Forecast series for regression mode
+    #[serde(default)]
+    pub vol_target: NnVolTarget,
+    /// Ignored in regime classification mode, which always uses cross-entropy
+    #[serde(default)]
+    pub loss: NnLossFunction,
+    /// Clip gradients to this global L2 norm before each optimizer step (`None` disables clipping)
+    #[serde(default)]
+    pub grad_clip_norm: Option<f64>,
+    /// Train in half precision (f16) with dynamic loss scaling; GPU only, falls back to f32
+    /// if the adapter lacks f16 support
+    #[serde(default)]
+    pub mixed_precision: bool,
+    /// Fixed batch size; `None` picks one automatically (VRAM-aware on GPU, `NN_BATCH_SIZE` on CPU)
+    #[serde(default)]
+    pub batch_size: Option<usize>,
+    /// Write per-epoch metrics to `cache/training_runs/<timestamp>/metrics.csv`
+    #[serde(default)]
+    pub export_metrics: bool,
+    /// Also write a TensorBoard event file next to the CSV
+    #[serde(default)]
+    pub export_tensorboard: bool,
+    #[serde(default)]
+    pub augmentation: NnAugmentation,
+}
+
+impl Default for NnTrainingConfig {
+    fn default() -> Self {
+        Self {
+            hidden_size: crate::config::NN_HIDDEN_SIZE,
+            lookback: crate::config::NN_LOOKBACK_DAYS,
+            learning_rate: crate::config::NN_LEARNING_RATE,
+            epochs: crate::config::NN_EPOCHS,
+            target_mode: NnTargetMode::Regression,
+            vol_target: NnVolTarget::SectorAverage,
+            loss: NnLossFunction::Mse,
+            grad_clip_norm: Some(crate::config::NN_GRAD_CLIP_NORM),
+            mixed_precision: false,
+            batch_size: None,
+            export_metrics: false,
+            export_tensorboard: false,
+            augmentation: NnAugmentation::default(),
+        }
+    }
+}
+
+/// Weight precision for the inference-only prediction path
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InferencePrecision {
+    /// Full f32 burn model
+    #[default]
+    Full,
+    /// f16 weights, plain CPU forward pass
+    F16,
+    /// int8 weights with per-output scales, plain CPU forward pass
+    Int8,
+}
+
+impl InferencePrecision {
+    pub const ALL: [InferencePrecision; 3] = [Self::Full, Self::F16, Self::Int8];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Full => "Full (f32)",
+            Self::F16 => "f16",
+            Self::Int8 => "int8",
+        }
+    }
+}
+
+/// Category of a dated chart event marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EventKind {
+    Fomc,
+    Cpi,
+    Earnings,
+    /// Start of a 10Y-2Y yield curve inversion
+    Inversion,
+    /// Free-form note added by the user
+    Note,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 5] = [Self::Fomc, Self::Cpi, Self::Earnings, Self::Inversion, Self::Note];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Fomc => "FOMC",
+            Self::Cpi => "CPI",
+            Self::Earnings => "Earnings",
+            Self::Inversion => "Curve Inversion",
+            Self::Note => "Note",
+        }
+    }
+}
+
+/// A dated event drawn as a vertical marker on time-series charts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChartEvent {
+    pub date: NaiveDate,
+    pub kind: EventKind,
+    pub label: String,
+}
+
+/// Live prediction drift monitoring: how many resolved predictions the rolling error
+/// covers, and the error above which retraining is suggested
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NnDriftSettings {
+    pub window: usize,
+    /// Rolling mean absolute percentage error threshold, in percent
+    pub threshold_pct: f64,
+}
+
+impl Default for NnDriftSettings {
+    fn default() -> Self {
+        Self {
+            window: 20,
+            threshold_pct: 25.0,
         }
     }
 }
@@ -262,12 +536,30 @@ pub enum ScreenshotCompression {
     High,
 }
 
-/// Persisted screenshot settings (save path, format, compression)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Part of the window a screenshot covers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ScreenshotScope {
+    #[default]
+    Window,
+    /// The central panel showing the active tab, without the tab and status bars
+    ActiveTab,
+    /// A rectangle dragged out on screen before each capture
+    Region,
+}
+
+impl ScreenshotScope {
+    pub const ALL: [ScreenshotScope; 3] = [Self::Window, Self::ActiveTab, Self::Region];
+}
+
+/// Persisted screenshot settings (save path, format, compression, scope)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ScreenshotSettings {
     pub save_path: String,
     pub file_type: ScreenshotFileType,
     pub compression: ScreenshotCompression,
+    #[serde(default)]
+    pub scope: ScreenshotScope,
 }
 
 impl Default for ScreenshotSettings {
@@ -276,12 +568,72 @@ impl Default for ScreenshotSettings {
             save_path: "./screenshots".to_string(),
             file_type: ScreenshotFileType::Png,
             compression: ScreenshotCompression::None,
+            scope: ScreenshotScope::Window,
+        }
+    }
+}
+
+/// Persisted UI zoom and font size
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySettings {
+    /// Multiplier on the native pixels-per-point
+    pub zoom: f32,
+    /// Body text size in points; other text styles scale with it
+    pub font_size: f32,
+}
+
+impl DisplaySettings {
+    /// egui's default body text size
+    pub const DEFAULT_FONT_SIZE: f32 = 12.5;
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self { zoom: 1.0, font_size: Self::DEFAULT_FONT_SIZE }
+    }
+}
+
+/// Persisted rolling vol windows (trading days) and history lookback (calendar days)
+/// used by the analysis views. The NN feature pipeline keeps the `config` defaults so
+/// saved models stay comparable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnalysisWindows {
+    pub short_vol: usize,
+    pub long_vol: usize,
+    pub lookback_days: u32,
+}
+
+impl AnalysisWindows {
+    /// Why these windows can't be used, if they can't
+    pub fn validate(&self) -> Result<(), String> {
+        if self.short_vol < 2 {
+            return Err("Short vol window must be at least 2 days".to_string());
+        }
+        if self.long_vol <= self.short_vol {
+            return Err("Long vol window must be longer than the short window".to_string());
+        }
+        // Trading days are roughly 252/365 of calendar days
+        if (self.lookback_days as usize) * 252 / 365 < self.long_vol + 2 {
+            return Err("Lookback is too short for the long vol window".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl Default for AnalysisWindows {
+    fn default() -> Self {
+        Self {
+            short_vol: crate::config::SHORT_VOL_WINDOW,
+            long_vol: crate::config::LONG_VOL_WINDOW,
+            lookback_days: crate::config::DEFAULT_LOOKBACK_DAYS,
         }
     }
 }
 
 /// Application-wide market data state
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MarketData {
     pub sectors: Vec<SectorTimeSeries>,
     pub benchmark: Option<SectorTimeSeries>,
@@ -290,4 +642,7 @@ pub struct MarketData {
     pub put_call_ratio: Vec<PutCallRecord>,
     pub skew_history: Vec<SkewRecord>,
     pub last_refresh: Option<String>,
+    /// Per-item outcome of the refresh that produced this data
+    #[serde(default)]
+    pub refresh_report: RefreshReport,
 }
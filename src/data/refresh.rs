@@ -0,0 +1,295 @@
+//! Full market data refresh. Every source is fetched with retries and its outcome
+//! recorded in a [`RefreshReport`]. A source that still fails keeps the previous
+//! refresh's data where there is some, and shows up in the report (Data tab,
+//! Notifications) instead of as a silently missing series.
+
+#[cfg(feature = "native")]
+use std::future::Future;
+use std::time::Duration;
+#[cfg(feature = "native")]
+use std::time::Instant;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+use crate::data::health::{DataSource, FetchOutcome};
+use crate::data::models::{MarketData, SectorTimeSeries};
+#[cfg(feature = "native")]
+use crate::data::cache::Fetched;
+#[cfg(feature = "native")]
+use crate::data::yahoo::{self, FetchProgress, SymbolStatus};
+#[cfg(feature = "native")]
+use crate::data::{cboe, fmp};
+
+/// Attempts per fetch before it counts as failed
+pub const MAX_ATTEMPTS: u32 = 3;
+
+/// Wait before the first retry; doubled for each one after
+pub const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Outcome of one item of a refresh: a Yahoo symbol, or a whole non-price source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshItem {
+    pub source: DataSource,
+    /// Symbol for Yahoo series, the source label otherwise
+    pub name: String,
+    pub outcome: FetchOutcome,
+    /// Fetch attempts made, including the last one
+    pub attempts: u32,
+    /// The fetch failed and the previous refresh's data was kept in its place
+    pub kept_previous: bool,
+}
+
+impl RefreshItem {
+    pub fn failed(&self) -> bool {
+        matches!(self.outcome, FetchOutcome::Failed(_))
+    }
+}
+
+/// Per-item outcome of the refresh that produced a [`MarketData`], updated by
+/// single-symbol refetches afterwards
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RefreshReport {
+    pub finished_at: Option<DateTime<Local>>,
+    pub items: Vec<RefreshItem>,
+    /// How long each stage took: the sector series, the benchmark, each other source,
+    /// then the whole refresh
+    #[serde(default)]
+    pub timings: Vec<(String, Duration)>,
+}
+
+impl RefreshReport {
+    /// Record an item's outcome, replacing any earlier one for the same item
+    pub fn record(&mut self, source: DataSource, name: &str, outcome: FetchOutcome, attempts: u32) {
+        let item = RefreshItem { source, name: name.to_string(), outcome, attempts, kept_previous: false };
+        match self.items.iter_mut().find(|i| i.source == source && i.name == name) {
+            Some(existing) => *existing = item,
+            None => self.items.push(item),
+        }
+    }
+
+    /// The Yahoo item for `symbol`
+    pub fn series(&self, symbol: &str) -> Option<&RefreshItem> {
+        self.items.iter().find(|i| i.source == DataSource::Yahoo && i.name == symbol)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &RefreshItem> {
+        self.items.iter().filter(|i| i.failed())
+    }
+
+    /// Outcomes by source, as folded into the session's data health
+    pub fn fetch_log(&self) -> Vec<(DataSource, FetchOutcome)> {
+        self.items.iter().map(|i| (i.source, i.outcome.clone())).collect()
+    }
+
+    /// E.g. "16 of 17 fetches succeeded; failed: XLE (kept previous), FMP Rates"
+    pub fn summary(&self) -> String {
+        let failed: Vec<String> = self
+            .failures()
+            .map(|i| if i.kept_previous { format!("{} (kept previous)", i.name) } else { i.name.clone() })
+            .collect();
+        let total = self.items.len();
+        if failed.is_empty() {
+            format!("all {} fetches succeeded", total)
+        } else {
+            format!("{} of {} fetches succeeded; failed: {}", total - failed.len(), total, failed.join(", "))
+        }
+    }
+}
+
+/// Run `fetch` until it succeeds or [`MAX_ATTEMPTS`] are used up, waiting `backoff`
+/// (doubling) between attempts. Returns the last result and the attempts made.
+#[cfg(feature = "native")]
+pub async fn with_retries<T, Fut>(what: &str, backoff: Duration, mut fetch: impl FnMut() -> Fut) -> (anyhow::Result<T>, u32)
+where
+    Fut: Future<Output = anyhow::Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        match fetch().await {
+            Err(e) if attempt < MAX_ATTEMPTS => {
+                tracing::warn!("Fetching {} failed (attempt {} of {}), retrying: {:#}", what, attempt, MAX_ATTEMPTS, e);
+                tokio::time::sleep(backoff * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                tracing::warn!("Fetching {} failed after {} attempts: {:#}", what, attempt, e);
+                return (Err(e), attempt);
+            }
+            Ok(data) => return (Ok(data), attempt),
+        }
+    }
+}
+
+/// Fetch every source for a full refresh, reporting price series to `progress`.
+/// Nothing fails as a whole: each failure is an item of the data's `refresh_report`.
+#[cfg(feature = "native")]
+pub async fn fetch_market_data(lookback_days: u32, progress: &FetchProgress) -> MarketData {
+    let mut data = MarketData::default();
+    let mut report = RefreshReport::default();
+    let started = Instant::now();
+
+    let sectors = yahoo::fetch_all_sectors(config::sector_etfs(), lookback_days, progress).await;
+    for (symbol, result, attempts) in sectors {
+        report.record(DataSource::Yahoo, &symbol, FetchOutcome::of(&result), attempts);
+        if let Ok(series) = result {
+            data.sectors.push(series.data);
+        }
+    }
+    report.timings.push(("Sector series".to_string(), started.elapsed()));
+
+    let benchmark = &config::universe().benchmark;
+    progress.set(&benchmark.symbol, SymbolStatus::Fetching);
+    let benchmark_started = Instant::now();
+    let (result, attempts) = with_retries(&benchmark.symbol, RETRY_BACKOFF, || {
+        yahoo::fetch_symbol_history(&benchmark.symbol, &benchmark.name, lookback_days)
+    })
+    .await;
+    progress.record(&benchmark.symbol, &result);
+    report.record(DataSource::Yahoo, &benchmark.symbol, FetchOutcome::of(&result), attempts);
+    data.benchmark = result.ok().map(|f| f.data);
+    report.timings.push(("Benchmark".to_string(), benchmark_started.elapsed()));
+
+    let api_key = config::fmp_api_key();
+    data.treasury_rates = fetch_source(&mut report, DataSource::FmpTreasury, || fmp::fetch_treasury_rates(&api_key))
+        .await
+        .unwrap_or_default();
+    data.sector_performance = fetch_source(&mut report, DataSource::FmpSectors, || fmp::fetch_sector_performance(&api_key))
+        .await
+        .unwrap_or_default();
+    data.put_call_ratio = fetch_source(&mut report, DataSource::CboePutCall, cboe::fetch_put_call_ratio)
+        .await
+        .unwrap_or_default();
+    data.skew_history = fetch_source(&mut report, DataSource::CboeSkew, cboe::fetch_skew_history)
+        .await
+        .unwrap_or_default();
+
+    let now = Local::now();
+    report.timings.push(("Total".to_string(), started.elapsed()));
+    report.finished_at = Some(now);
+    data.refresh_report = report;
+    data.last_refresh = Some(now.format("%Y-%m-%d %H:%M:%S").to_string());
+    data
+}
+
+/// Fetch one non-price source with retries and record it under the source's label
+#[cfg(feature = "native")]
+async fn fetch_source<T, Fut>(report: &mut RefreshReport, source: DataSource, fetch: impl FnMut() -> Fut) -> Option<T>
+where
+    Fut: Future<Output = anyhow::Result<Fetched<T>>>,
+{
+    let started = Instant::now();
+    let (result, attempts) = with_retries(source.label(), RETRY_BACKOFF, fetch).await;
+    report.timings.push((source.label().to_string(), started.elapsed()));
+    report.record(source, source.label(), FetchOutcome::of(&result), attempts);
+    result.ok().map(|f| f.data)
+}
+
+/// Fill in whatever `data`'s refresh failed to fetch from `previous`, the data it
+/// replaces, and mark those items as kept. `set_aside` holds `previous`'s excluded sectors.
+pub fn keep_previous_on_failure(data: &mut MarketData, previous: &MarketData, set_aside: &[SectorTimeSeries]) {
+    fn keep<T: Clone>(current: &mut Vec<T>, previous: &[T]) -> bool {
+        *current = previous.to_vec();
+        !current.is_empty()
+    }
+
+    for item in data.refresh_report.items.iter_mut().filter(|i| i.failed()) {
+        item.kept_previous = match item.source {
+            DataSource::Yahoo if item.name == config::benchmark_symbol() => {
+                data.benchmark = previous.benchmark.clone();
+                data.benchmark.is_some()
+            }
+            DataSource::Yahoo => match previous.sectors.iter().chain(set_aside).find(|s| s.symbol == item.name) {
+                Some(series) => {
+                    data.sectors.push(series.clone());
+                    true
+                }
+                None => false,
+            },
+            DataSource::FmpTreasury => keep(&mut data.treasury_rates, &previous.treasury_rates),
+            DataSource::FmpSectors => keep(&mut data.sector_performance, &previous.sector_performance),
+            DataSource::CboePutCall => keep(&mut data.put_call_ratio, &previous.put_call_ratio),
+            DataSource::CboeSkew => keep(&mut data.skew_history, &previous.skew_history),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::TreasuryRate;
+
+    fn series(symbol: &str) -> SectorTimeSeries {
+        SectorTimeSeries { symbol: symbol.to_string(), name: String::new(), bars: Vec::new() }
+    }
+
+    fn fetched() -> FetchOutcome {
+        FetchOutcome::Fetched { from_cache: false, fetched_at: Local::now() }
+    }
+
+    #[cfg(feature = "native")]
+    #[tokio::test]
+    async fn test_with_retries_stops_at_first_success() {
+        let mut calls = 0;
+        let (result, attempts) = with_retries("test", Duration::ZERO, || {
+            calls += 1;
+            let outcome = if calls < 2 { Err(anyhow::anyhow!("timeout")) } else { Ok(calls) };
+            async move { outcome }
+        })
+        .await;
+        assert_eq!((result.unwrap(), attempts), (2, 2));
+
+        let (result, attempts) =
+            with_retries("test", Duration::ZERO, || async { Err::<(), _>(anyhow::anyhow!("down")) }).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, MAX_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_report_record_replaces_and_summarizes() {
+        let mut report = RefreshReport::default();
+        report.record(DataSource::Yahoo, "XLK", fetched(), 1);
+        report.record(DataSource::Yahoo, "XLE", FetchOutcome::Failed("timeout".into()), 3);
+        report.record(DataSource::FmpTreasury, "FMP Rates", fetched(), 2);
+        assert_eq!(report.summary(), "2 of 3 fetches succeeded; failed: XLE");
+
+        // A successful refetch replaces the failure
+        report.record(DataSource::Yahoo, "XLE", fetched(), 1);
+        assert_eq!(report.items.len(), 3);
+        assert_eq!(report.summary(), "all 3 fetches succeeded");
+        assert_eq!(report.series("XLE").map(|i| i.attempts), Some(1));
+    }
+
+    #[test]
+    fn test_keep_previous_on_failure() {
+        let previous = MarketData {
+            sectors: vec![series("XLK")],
+            benchmark: Some(series(config::benchmark_symbol())),
+            treasury_rates: vec![serde_json::from_str::<TreasuryRate>(r#"{"date": "2024-01-02"}"#).unwrap()],
+            ..Default::default()
+        };
+        let set_aside = [series("XLE")];
+        let mut data = MarketData { sectors: vec![series("XLF")], ..Default::default() };
+        let failed = || FetchOutcome::Failed("timeout".into());
+        for symbol in ["XLK", "XLE", "XLU", config::benchmark_symbol()] {
+            data.refresh_report.record(DataSource::Yahoo, symbol, failed(), MAX_ATTEMPTS);
+        }
+        data.refresh_report.record(DataSource::Yahoo, "XLF", fetched(), 1);
+        data.refresh_report.record(DataSource::FmpTreasury, "FMP Rates", failed(), MAX_ATTEMPTS);
+        data.refresh_report.record(DataSource::CboeSkew, "CBOE SKEW", failed(), MAX_ATTEMPTS);
+
+        keep_previous_on_failure(&mut data, &previous, &set_aside);
+
+        let symbols: Vec<&str> = data.sectors.iter().map(|s| s.symbol.as_str()).collect();
+        assert_eq!(symbols, ["XLF", "XLK", "XLE"]);
+        assert!(data.benchmark.is_some());
+        assert_eq!(data.treasury_rates.len(), 1);
+        let kept: Vec<&str> = data.refresh_report.items.iter().filter(|i| i.kept_previous).map(|i| i.name.as_str()).collect();
+        assert_eq!(kept, ["XLK", "XLE", config::benchmark_symbol(), "FMP Rates"]);
+        assert_eq!(
+            data.refresh_report.summary(),
+            "1 of 7 fetches succeeded; failed: XLK (kept previous), XLE (kept previous), XLU, SPY (kept previous), FMP Rates (kept previous), CBOE SKEW"
+        );
+    }
+}
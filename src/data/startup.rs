@@ -0,0 +1,127 @@
+//! Cold start. Rather than blocking the first frame, the cached market data, the saved
+//! model and the GPU adapter list are read on a background thread. Cached series are
+//! handed over one file at a time, so the dashboard fills in symbol by symbol; the
+//! cross-sector analysis waits until the last file is in.
+
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+
+use crate::config;
+use crate::data::cache::{self, Fetched};
+use crate::data::health::{DataSource, FetchOutcome};
+use crate::data::models::{GpuAdapterInfo, MarketData, SectorTimeSeries};
+use crate::data::refresh::RefreshReport;
+use crate::data::{cboe, fmp, yahoo};
+use crate::nn::persistence::ModelMetadata;
+use crate::nn::LoadedModel;
+use crate::tasks::{TaskHooks, TaskKind, TaskManager};
+use crate::ui::i18n::tr;
+
+/// What the startup load has read that the UI hasn't taken yet
+#[derive(Default)]
+pub struct StartupBatch {
+    /// Cached price series: sectors in config order, then the benchmark
+    pub series: Vec<SectorTimeSeries>,
+    /// Cached rates, sector performance and CBOE data, in an otherwise empty `MarketData`
+    pub extras: Option<MarketData>,
+    /// Cache age of each item read, for the Data tab
+    pub report: RefreshReport,
+    /// Every cached market data file has been read
+    pub data_done: bool,
+    pub model: Option<(LoadedModel, ModelMetadata)>,
+    /// Detected adapters; the last thing loaded
+    pub gpus: Option<Vec<GpuAdapterInfo>>,
+}
+
+/// Handle to the background startup load
+pub struct StartupLoad {
+    slot: Arc<Mutex<StartupBatch>>,
+    /// Cleared once a refresh or workspace has replaced the market data, after which
+    /// cached series still arriving are dropped
+    pub wants_data: bool,
+}
+
+impl StartupLoad {
+    pub fn spawn(tasks: &mut TaskManager) -> Self {
+        let slot = Arc::new(Mutex::new(StartupBatch::default()));
+        let writer = slot.clone();
+        tasks.spawn_blocking(TaskKind::Startup, tr("task-load-cache"), TaskHooks::default(), move || {
+            load(&writer);
+            Ok(())
+        });
+        Self { slot, wants_data: true }
+    }
+
+    /// Everything read since the last call
+    pub fn take(&self) -> StartupBatch {
+        self.slot.lock().map(|mut batch| std::mem::take(&mut *batch)).unwrap_or_default()
+    }
+}
+
+fn load(slot: &Mutex<StartupBatch>) {
+    let symbols =
+        config::sector_etfs().iter().map(|s| s.symbol.as_str()).chain(std::iter::once(config::benchmark_symbol()));
+    for symbol in symbols {
+        if let Some(fetched) = read::<SectorTimeSeries>(&yahoo::cache_filename(symbol)) {
+            update(slot, |batch| {
+                batch.report.record(DataSource::Yahoo, symbol, cached(fetched.fetched_at), 1);
+                batch.series.push(fetched.data);
+            });
+        }
+    }
+
+    let mut report = RefreshReport::default();
+    let extras = MarketData {
+        treasury_rates: read_source(&mut report, DataSource::FmpTreasury, &cache::provider_file(fmp::TREASURY_CACHE_FILE)),
+        sector_performance: read_source(&mut report, DataSource::FmpSectors, &cache::provider_file(fmp::SECTOR_PERFORMANCE_CACHE_FILE)),
+        put_call_ratio: read_source(&mut report, DataSource::CboePutCall, &cache::provider_file(cboe::PUT_CALL_CACHE_FILE)),
+        skew_history: read_source(&mut report, DataSource::CboeSkew, &cache::provider_file(cboe::SKEW_CACHE_FILE)),
+        ..Default::default()
+    };
+    update(slot, |batch| {
+        batch.report.items.append(&mut report.items);
+        batch.extras = Some(extras);
+        batch.data_done = true;
+    });
+
+    let model = crate::nn::persistence::load_model();
+    if let Some((_, meta)) = &model {
+        tracing::info!("Loaded saved model (trained {})", meta.trained_at);
+    }
+    update(slot, |batch| batch.model = model);
+
+    let gpus = crate::nn::gpu::detect_wgpu_adapters();
+    update(slot, |batch| batch.gpus = Some(gpus));
+}
+
+fn update(slot: &Mutex<StartupBatch>, f: impl FnOnce(&mut StartupBatch)) {
+    if let Ok(mut batch) = slot.lock() {
+        f(&mut batch);
+    }
+}
+
+/// A cache file's contents whatever its age: stale data beats an empty window until
+/// the first refresh lands
+fn read<T: DeserializeOwned>(filename: &str) -> Option<Fetched<T>> {
+    match cache::load_json(filename) {
+        Ok(data) => Some(Fetched::cached(data, filename)),
+        Err(e) => {
+            tracing::debug!("No cached {}: {:#}", filename, e);
+            None
+        }
+    }
+}
+
+/// A non-price source's cached records, empty when there is no cache
+fn read_source<T: DeserializeOwned>(report: &mut RefreshReport, source: DataSource, filename: &str) -> Vec<T> {
+    let Some(fetched) = read::<Vec<T>>(filename) else {
+        return Vec::new();
+    };
+    report.record(source, source.label(), cached(fetched.fetched_at), 1);
+    fetched.data
+}
+
+fn cached(fetched_at: chrono::DateTime<chrono::Local>) -> FetchOutcome {
+    FetchOutcome::Fetched { from_cache: true, fetched_at }
+}
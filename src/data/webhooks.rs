@@ -0,0 +1,174 @@
+//! Outbound alert delivery: fired alert rules are posted to Slack or Discord incoming
+//! webhooks, or as JSON to any other URL, on the task manager so a slow or failing
+//! endpoint never blocks the UI.
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::alerts::AlertEvent;
+use crate::tasks::{TaskHooks, TaskKind, TaskManager};
+use crate::ui::i18n::{self, tr_args};
+
+/// Discord rejects messages longer than this
+const DISCORD_MAX_CONTENT: usize = 2000;
+
+/// Seconds to wait for an endpoint before giving up
+const TIMEOUT_SECS: u64 = 15;
+
+/// Name the app posts as, where the service lets the sender choose
+const SENDER: &str = "Volume Analysis";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    #[default]
+    Slack,
+    Discord,
+    /// Plain JSON: the message text plus the structured events
+    Json,
+}
+
+impl WebhookKind {
+    pub const ALL: [WebhookKind; 3] = [Self::Slack, Self::Discord, Self::Json];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Slack => "Slack",
+            Self::Discord => "Discord",
+            Self::Json => "JSON",
+        }
+    }
+}
+
+/// A configured endpoint alerts are posted to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Webhook {
+    pub name: String,
+    pub kind: WebhookKind,
+    pub url: String,
+    pub enabled: bool,
+}
+
+impl Webhook {
+    pub fn label(&self) -> &str {
+        if self.name.trim().is_empty() { self.kind.label() } else { &self.name }
+    }
+}
+
+/// Request body for `events` in the format `kind` expects
+pub fn payload(kind: WebhookKind, events: &[AlertEvent]) -> serde_json::Value {
+    let title = match events.len() {
+        1 => "Volume Analysis alert".to_string(),
+        n => format!("Volume Analysis: {} alerts", n),
+    };
+    let lines: Vec<String> = events.iter().map(|e| format!("• {} ({})", e.message(), e.triggered_at)).collect();
+    match kind {
+        WebhookKind::Slack => serde_json::json!({
+            "text": format!("*{}*\n{}", title, lines.join("\n")),
+        }),
+        WebhookKind::Discord => {
+            let mut content = format!("**{}**\n{}", title, lines.join("\n"));
+            if content.chars().count() > DISCORD_MAX_CONTENT {
+                content = content.chars().take(DISCORD_MAX_CONTENT - 1).collect::<String>() + "…";
+            }
+            serde_json::json!({ "username": SENDER, "content": content })
+        }
+        WebhookKind::Json => serde_json::json!({
+            "text": format!("{}\n{}", title, lines.join("\n")),
+            "alerts": events,
+        }),
+    }
+}
+
+async fn post(client: &reqwest::Client, webhook: &Webhook, events: &[AlertEvent]) -> Result<(), String> {
+    let resp = client
+        .post(webhook.url.trim())
+        .json(&payload(webhook.kind, events))
+        .send()
+        .await
+        .map_err(|e| format!("{}: {}", webhook.label(), e))?;
+    let status = resp.status();
+    if !status.is_success() {
+        let text = resp.text().await.unwrap_or_default();
+        return Err(format!("{}: HTTP {} {}", webhook.label(), status, &text[..200.min(text.len())]));
+    }
+    Ok(())
+}
+
+/// Post `events` to every enabled webhook with a URL as one background task. The task
+/// fails, listing each endpoint's error, when any post fails.
+pub fn deliver(tasks: &mut TaskManager, webhooks: &[Webhook], events: &[AlertEvent]) {
+    let targets: Vec<Webhook> =
+        webhooks.iter().filter(|w| w.enabled && !w.url.trim().is_empty()).cloned().collect();
+    if targets.is_empty() || events.is_empty() {
+        return;
+    }
+    let events = events.to_vec();
+    let label = match targets.as_slice() {
+        [one] => tr_args("task-webhook-one", &[("name", one.label().to_string())]),
+        many => tr_args("task-webhook-many", &[("count", i18n::integer(many.len()))]),
+    };
+    tasks.spawn(TaskKind::Webhook, label, TaskHooks::default(), async move {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(TIMEOUT_SECS))
+            .build()
+            .map_err(|e| e.to_string())?;
+        let mut errors = Vec::new();
+        for webhook in &targets {
+            if let Err(e) = post(&client, webhook, &events).await {
+                tracing::warn!("Webhook delivery failed: {}", e);
+                errors.push(e);
+            }
+        }
+        if errors.is_empty() { Ok(()) } else { Err(errors.join("; ")) }
+    });
+}
+
+/// Post a sample alert to `webhook` alone, to check the URL
+pub fn send_test(tasks: &mut TaskManager, webhook: &Webhook) {
+    let event = AlertEvent {
+        triggered_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        rule: "Test message".to_string(),
+        symbol: None,
+        value: 0.0,
+        unit: String::new(),
+    };
+    let webhook = Webhook { enabled: true, ..webhook.clone() };
+    deliver(tasks, std::slice::from_ref(&webhook), &[event]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(symbol: Option<&str>) -> AlertEvent {
+        AlertEvent {
+            triggered_at: "2025-01-06 16:30:00".to_string(),
+            rule: "Any sector vol ratio > 1.3".to_string(),
+            symbol: symbol.map(String::from),
+            value: 1.42,
+            unit: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_payload_formats() {
+        let events = [event(Some("XLE"))];
+        let slack = payload(WebhookKind::Slack, &events);
+        assert_eq!(
+            slack["text"],
+            "*Volume Analysis alert*\n• Any sector vol ratio > 1.3: XLE is 1.42 (2025-01-06 16:30:00)"
+        );
+        let json = payload(WebhookKind::Json, &events);
+        assert_eq!(json["alerts"][0]["symbol"], "XLE");
+        assert!(payload(WebhookKind::Discord, &events)["content"].as_str().unwrap().starts_with("**Volume"));
+    }
+
+    #[test]
+    fn test_discord_content_is_truncated() {
+        let events = vec![event(None); 100];
+        let content = payload(WebhookKind::Discord, &events)["content"].as_str().unwrap().to_string();
+        assert_eq!(content.chars().count(), DISCORD_MAX_CONTENT);
+        assert!(content.ends_with('…'));
+    }
+}
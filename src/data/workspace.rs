@@ -0,0 +1,234 @@
+//! Workspaces: a whole analysis session in one gzip-compressed JSON file, to archive
+//! it or hand it to a colleague. A workspace holds the market data snapshot, the
+//! analysis settings and custom indicators, the UI layout, the NN predictions and the
+//! trained model. Analysis outputs are recomputed from the bundled data and settings on
+//! open, which reproduces them exactly. Machine-local settings (webhooks, email, jobs,
+//! gRPC and the like) are neither saved in a workspace nor replaced by opening one.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use eframe::egui;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{AppState, UiState};
+use crate::data::config_file::SharedSettings;
+use crate::data::models::{CustomIndicator, MarketData, NnPredictions};
+use crate::nn::persistence::{self, ModelMetadata};
+
+/// Folder workspaces are saved to and listed from, in the working directory
+pub const WORKSPACE_DIR: &str = "workspaces";
+
+/// File extension of a saved workspace
+pub const WORKSPACE_EXTENSION: &str = "vws";
+
+/// Bumped when a change makes older workspace files unreadable
+const WORKSPACE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    pub version: u32,
+    pub saved_at: String,
+    /// Includes series set aside by the data manager, so exclusions can be undone
+    pub market_data: MarketData,
+    /// Older workspaces bundled the whole `ConfigFile`; only the shared part is read
+    pub settings: SharedSettings,
+    pub custom_indicators: Vec<CustomIndicator>,
+    pub ui_state: UiState,
+    pub nn_predictions: NnPredictions,
+    pub model: Option<WorkspaceModel>,
+}
+
+/// The trained checkpoint: the compressed weights file as written by `save_model`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceModel {
+    pub metadata: ModelMetadata,
+    pub weights: Vec<u8>,
+}
+
+impl Workspace {
+    /// Snapshot of the current session
+    pub fn capture(state: &AppState, ctx: &egui::Context) -> Self {
+        let mut market_data = MarketData::clone(&state.market_data);
+        market_data.sectors.extend(state.data_manager.set_aside.iter().cloned());
+        Self {
+            version: WORKSPACE_VERSION,
+            saved_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            market_data,
+            settings: SharedSettings::capture(state),
+            custom_indicators: state.custom_indicators.indicators.clone(),
+            ui_state: UiState::capture(state, ctx),
+            nn_predictions: state.nn_predictions.clone(),
+            model: persistence::export_checkpoint().map(|(weights, metadata)| WorkspaceModel { metadata, weights }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_vec(self).map_err(|e| format!("Failed to serialize workspace: {}", e))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        let file = std::fs::File::create(path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let mut encoder = GzEncoder::new(file, flate2::Compression::default());
+        encoder
+            .write_all(&json)
+            .and_then(|_| encoder.finish().map(|_| ()))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let file = std::fs::File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        let mut json = Vec::new();
+        GzDecoder::new(file)
+            .read_to_end(&mut json)
+            .map_err(|e| format!("{} is not a workspace file: {}", path.display(), e))?;
+        let workspace: Self =
+            serde_json::from_slice(&json).map_err(|e| format!("Failed to read workspace {}: {}", path.display(), e))?;
+        if workspace.version != WORKSPACE_VERSION {
+            return Err(format!(
+                "{} was saved by an incompatible version (workspace format {})",
+                path.display(),
+                workspace.version
+            ));
+        }
+        Ok(workspace)
+    }
+
+    /// Replace the current session with this one. The bundled model, when there is
+    /// one, replaces the saved checkpoint.
+    pub fn apply(self, state: &mut AppState, ctx: &egui::Context) -> Result<(), String> {
+        state.custom_indicators.indicators = self.custom_indicators;
+        state.custom_indicators.draft = None;
+        state.custom_indicators.editing = None;
+        self.settings.apply(state);
+
+        state.market_data = std::sync::Arc::new(self.market_data);
+        if let Some(load) = &mut state.startup_load {
+            load.wants_data = false;
+        }
+        state.data_manager.set_aside.clear();
+        state.apply_exclusions();
+        state.recompute_analysis();
+        crate::ui::watchlist_view::recompute_entries(&mut state.watchlist, &state.analysis_windows);
+        crate::ui::settings_view::apply_display_settings(ctx, &state.display_settings);
+
+        let ui_state = self.ui_state;
+        state.active_tab = ui_state.active_tab;
+        state.selected_sector_idx = ui_state.selected_sector_idx.min(state.market_data.sectors.len().saturating_sub(1));
+        state.chart_heights = ui_state.chart_heights;
+        state.open_sections = ui_state.open_sections;
+        crate::ui::chart_utils::set_hidden_series(ctx, ui_state.hidden_series);
+
+        state.nn_predictions = self.nn_predictions;
+        state.backtest = None;
+        state.saliency = None;
+        let model = match self.model {
+            Some(m) => persistence::import_checkpoint(&m.weights, &m.metadata).map(|(model, metadata)| {
+                state.loaded_model = Some(model);
+                state.model_metadata = Some(metadata);
+            }),
+            None => Ok(()),
+        };
+
+        let saved = [
+            state.save_config(),
+            crate::analysis::custom_indicators::save(&state.custom_indicators.indicators),
+            model,
+        ];
+        saved.into_iter().collect()
+    }
+}
+
+/// Where a workspace named `name` is saved
+pub fn path_for(name: &str) -> PathBuf {
+    PathBuf::from(WORKSPACE_DIR).join(format!("{}.{}", name, WORKSPACE_EXTENSION))
+}
+
+/// Names of the workspaces in `WORKSPACE_DIR`, sorted
+pub fn list() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(WORKSPACE_DIR) else { return Vec::new() };
+    let mut names: Vec<String> = entries
+        .filter_map(|e| {
+            let path = e.ok()?.path();
+            if path.extension()? != WORKSPACE_EXTENSION {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::config_file::ConfigFile;
+    use crate::data::models::{OhlcvBar, SectorTimeSeries};
+    use crate::data::webhooks::Webhook;
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let bar = OhlcvBar {
+            date: chrono::NaiveDate::from_ymd_opt(2025, 1, 6).unwrap(),
+            open: 1.0,
+            high: 2.0,
+            low: 0.5,
+            close: 1.5,
+            volume: 100,
+        };
+        let workspace = Workspace {
+            version: WORKSPACE_VERSION,
+            saved_at: "2025-01-06 17:00:00".to_string(),
+            market_data: MarketData {
+                sectors: vec![SectorTimeSeries { symbol: "XLK".to_string(), name: "Technology".to_string(), bars: vec![bar] }],
+                ..Default::default()
+            },
+            settings: SharedSettings::default(),
+            custom_indicators: vec![CustomIndicator::example()],
+            ui_state: UiState::default(),
+            nn_predictions: NnPredictions { vol: vec![("XLK".to_string(), 0.2)], ..Default::default() },
+            model: None,
+        };
+
+        let path = std::env::temp_dir().join(format!("vol_analysis_workspace_{}.{}", std::process::id(), WORKSPACE_EXTENSION));
+        workspace.save(&path).unwrap();
+        let loaded = Workspace::load(&path);
+        std::fs::remove_file(&path).ok();
+        let loaded = loaded.unwrap();
+        assert_eq!(loaded.market_data.sectors[0].bars[0].close, 1.5);
+        assert_eq!(loaded.custom_indicators, workspace.custom_indicators);
+        assert_eq!(loaded.nn_predictions.vol, workspace.nn_predictions.vol);
+        assert_eq!(loaded.settings, workspace.settings);
+    }
+
+    #[test]
+    fn test_machine_settings_are_not_shared() {
+        let mut config = ConfigFile::default();
+        config.windows.short_vol = 10;
+        config.alerts.webhooks = vec![Webhook::default()];
+        config.grpc.enabled = true;
+        config.grpc.address = "0.0.0.0:50051".to_string();
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json.get("grpc").is_some());
+
+        // A workspace saved with the full settings only contributes the shared part
+        let settings: SharedSettings = serde_json::from_value(json).unwrap();
+        assert_eq!(settings.windows.short_vol, 10);
+        let json = serde_json::to_value(&settings).unwrap();
+        for key in ["grpc", "alerts", "email", "scheduler", "tray", "logging"] {
+            assert!(json.get(key).is_none(), "{} is shared", key);
+        }
+    }
+
+    #[test]
+    fn test_load_rejects_other_files() {
+        let path = std::env::temp_dir().join(format!("vol_analysis_not_workspace_{}.txt", std::process::id()));
+        std::fs::write(&path, "plain text").unwrap();
+        let loaded = Workspace::load(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(loaded.is_err());
+    }
+}
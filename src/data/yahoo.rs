@@ -1,42 +1,144 @@
+use std::sync::{Arc, Mutex};
+
 use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use time::OffsetDateTime;
 use yahoo_finance_api as yahoo;
 
-use crate::data::cache;
+use crate::data::cache::{self, Fetched};
+use crate::data::fixtures;
+use crate::data::refresh;
+use crate::config::UniverseSymbol;
 use crate::data::models::{OhlcvBar, SectorTimeSeries};
 
+/// Where one symbol is in a batch fetch
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolStatus {
+    Pending,
+    Fetching,
+    Cached,
+    Downloaded,
+    Failed(String),
+}
+
+impl SymbolStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Fetching => "Fetching",
+            Self::Cached => "Cached",
+            Self::Downloaded => "Downloaded",
+            Self::Failed(_) => "Failed",
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::Cached | Self::Downloaded | Self::Failed(_))
+    }
+}
+
+/// Per-symbol status of a batch fetch, updated by the fetch tasks and read by the UI
+#[derive(Debug, Clone, Default)]
+pub struct FetchProgress(Arc<Mutex<Vec<(String, SymbolStatus)>>>);
+
+impl FetchProgress {
+    /// Every symbol starts out pending
+    pub fn new<'a>(symbols: impl IntoIterator<Item = &'a str>) -> Self {
+        let entries = symbols.into_iter().map(|s| (s.to_string(), SymbolStatus::Pending)).collect();
+        Self(Arc::new(Mutex::new(entries)))
+    }
+
+    pub fn set(&self, symbol: &str, status: SymbolStatus) {
+        if let Ok(mut entries) = self.0.lock() {
+            match entries.iter_mut().find(|(s, _)| s == symbol) {
+                Some(entry) => entry.1 = status,
+                None => entries.push((symbol.to_string(), status)),
+            }
+        }
+    }
+
+    /// Mark `symbol` cached, downloaded or failed according to its fetch result
+    pub fn record<T>(&self, symbol: &str, result: &Result<Fetched<T>>) {
+        let status = match result {
+            Ok(f) if f.from_cache => SymbolStatus::Cached,
+            Ok(_) => SymbolStatus::Downloaded,
+            Err(e) => SymbolStatus::Failed(format!("{:#}", e)),
+        };
+        self.set(symbol, status);
+    }
+
+    pub fn snapshot(&self) -> Vec<(String, SymbolStatus)> {
+        self.0.lock().map(|entries| entries.clone()).unwrap_or_default()
+    }
+}
+
+/// Cache file a symbol's price history is stored in, under the active API environment
+pub fn cache_filename(symbol: &str) -> String {
+    cache::provider_file(&format!("yahoo_{}.json", symbol))
+}
+
 /// Fetch historical OHLCV data for a given symbol from Yahoo Finance
 pub async fn fetch_symbol_history(
     symbol: &str,
     name: &str,
     lookback_days: u32,
-) -> Result<SectorTimeSeries> {
-    let cache_file = format!("yahoo_{}.json", symbol);
-    if cache::is_cache_fresh(&cache_file, 12) {
+) -> Result<Fetched<SectorTimeSeries>> {
+    let cache_file = cache_filename(symbol);
+    if cache::is_cache_fresh(&cache_file, cache::max_age_hours()) {
         if let Ok(cached) = cache::load_json::<SectorTimeSeries>(&cache_file) {
             tracing::info!("Using cached data for {}", symbol);
-            return Ok(cached);
+            return Ok(Fetched::cached(cached, &cache_file));
         }
     }
+    download_symbol_history(symbol, name, lookback_days).await
+}
 
+/// Fetch a symbol's history from the network regardless of the cache, and update the cache
+pub async fn download_symbol_history(
+    symbol: &str,
+    name: &str,
+    lookback_days: u32,
+) -> Result<Fetched<SectorTimeSeries>> {
+    let cache_file = cache_filename(symbol);
     tracing::info!("Fetching Yahoo Finance data for {}", symbol);
-    let provider = yahoo::YahooConnector::new()
-        .context("Failed to create Yahoo connector")?;
+    let quotes: Vec<yahoo::Quote> = fixtures::json(&quotes_fixture(symbol), || async {
+        let provider = yahoo::YahooConnector::new()
+            .context("Failed to create Yahoo connector")?;
 
-    let now = OffsetDateTime::now_utc();
-    let start = now - time::Duration::days(lookback_days as i64);
+        let now = OffsetDateTime::now_utc();
+        let start = now - time::Duration::days(lookback_days as i64);
 
-    let resp = provider
-        .get_quote_history(symbol, start, now)
-        .await
-        .with_context(|| format!("Failed to fetch history for {}", symbol))?;
+        let resp = provider
+            .get_quote_history(symbol, start, now)
+            .await
+            .with_context(|| format!("Failed to fetch history for {}", symbol))?;
 
-    let quotes = resp
-        .quotes()
-        .with_context(|| format!("Failed to parse quotes for {}", symbol))?;
+        resp.quotes()
+            .with_context(|| format!("Failed to parse quotes for {}", symbol))
+    })
+    .await?;
 
-    let bars: Vec<OhlcvBar> = quotes
+    let series = SectorTimeSeries {
+        symbol: symbol.to_string(),
+        name: name.to_string(),
+        bars: bars_from_quotes(&quotes),
+    };
+
+    if let Err(e) = cache::save_json(&cache_file, &series) {
+        tracing::warn!("Failed to cache data for {}: {}", symbol, e);
+    }
+
+    Ok(Fetched::network(series))
+}
+
+/// Fixture the quotes of a symbol's history download are recorded in
+pub fn quotes_fixture(symbol: &str) -> String {
+    format!("yahoo_quotes_{}.json", symbol)
+}
+
+/// Daily bars of Yahoo quotes, dropping any whose timestamp isn't a valid date
+pub fn bars_from_quotes(quotes: &[yahoo::Quote]) -> Vec<OhlcvBar> {
+    quotes
         .iter()
         .filter_map(|q| {
             let dt = OffsetDateTime::from_unix_timestamp(q.timestamp as i64).ok()?;
@@ -54,47 +156,64 @@ pub async fn fetch_symbol_history(
                 volume: q.volume,
             })
         })
-        .collect();
-
-    let series = SectorTimeSeries {
-        symbol: symbol.to_string(),
-        name: name.to_string(),
-        bars,
-    };
-
-    if let Err(e) = cache::save_json(&cache_file, &series) {
-        tracing::warn!("Failed to cache data for {}: {}", symbol, e);
-    }
-
-    Ok(series)
+        .collect()
 }
 
-/// Fetch data for all sector ETFs concurrently
+/// Fetch data for all sector ETFs concurrently, with retries, reporting each symbol's
+/// status to `progress`. Returns each symbol's last result and the attempts it took.
 pub async fn fetch_all_sectors(
-    symbols: &[(&str, &str)],
+    symbols: &[UniverseSymbol],
     lookback_days: u32,
-) -> Vec<(String, Result<SectorTimeSeries>)> {
+    progress: &FetchProgress,
+) -> Vec<(String, Result<Fetched<SectorTimeSeries>>, u32)> {
     let mut handles = Vec::new();
 
-    for &(symbol, name) in symbols {
-        let sym = symbol.to_string();
-        let nm = name.to_string();
+    for UniverseSymbol { symbol, name } in symbols {
+        let sym = symbol.clone();
+        let nm = name.clone();
+        let progress = progress.clone();
         let handle = tokio::spawn(async move {
-            let result = fetch_symbol_history(&sym, &nm, lookback_days).await;
-            (sym, result)
+            progress.set(&sym, SymbolStatus::Fetching);
+            let (result, attempts) = refresh::with_retries(&sym, refresh::RETRY_BACKOFF, || {
+                fetch_symbol_history(&sym, &nm, lookback_days)
+            })
+            .await;
+            progress.record(&sym, &result);
+            (result, attempts)
         });
-        handles.push(handle);
+        handles.push((symbol.clone(), handle));
     }
 
     let mut results = Vec::new();
-    for handle in handles {
+    for (symbol, handle) in handles {
         match handle.await {
-            Ok(result) => results.push(result),
+            Ok((result, attempts)) => results.push((symbol, result, attempts)),
             Err(e) => {
                 tracing::error!("Task join error: {}", e);
+                let result = Err(anyhow::anyhow!("Fetch task failed: {}", e));
+                progress.record(&symbol, &result);
+                results.push((symbol, result, 1));
             }
         }
     }
 
     results
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_progress_records_outcomes_in_order() {
+        let progress = FetchProgress::new(["XLK", "XLE", "SPY"]);
+        progress.set("XLK", SymbolStatus::Fetching);
+        progress.record("XLE", &Ok(Fetched::network(())));
+        progress.record::<()>("SPY", &Err(anyhow::anyhow!("timeout")));
+        let entries = progress.snapshot();
+        assert_eq!(entries[0], ("XLK".to_string(), SymbolStatus::Fetching));
+        assert_eq!(entries[1].1, SymbolStatus::Downloaded);
+        assert_eq!(entries[2].1, SymbolStatus::Failed("timeout".into()));
+        assert!(!entries[0].1.is_done() && entries[2].1.is_done());
+    }
+}
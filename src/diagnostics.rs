@@ -0,0 +1,277 @@
+//! Memory and performance diagnostics for the Diagnostics window: the process's
+//! resident memory, live heap usage from a counting allocator when built with the
+//! `diagnostics` feature, rough per-subsystem sizes, frame times and how long the last
+//! refresh and analysis took. Lets regressions and big-universe configurations be
+//! measured in-app.
+
+#[cfg(feature = "diagnostics")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::VecDeque;
+use std::mem::size_of_val;
+#[cfg(feature = "diagnostics")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::analysis::AnalysisResults;
+use crate::data::cache;
+use crate::data::models::{MarketData, SectorTimeSeries};
+
+/// Frames kept for the frame time chart
+pub const FRAME_HISTORY: usize = 240;
+
+/// How often the process memory and disk cache size are re-read while the window is open
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+#[cfg(feature = "diagnostics")]
+static HEAP_BYTES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "diagnostics")]
+static PEAK_HEAP_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting live heap bytes (registered in `main.rs`). Every
+/// allocation pays two atomic updates, so it is only built in on request.
+#[cfg(feature = "diagnostics")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "diagnostics")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        HEAP_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            HEAP_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            grew(new_size);
+        }
+        new
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+fn grew(bytes: usize) {
+    let now = HEAP_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+    PEAK_HEAP_BYTES.fetch_max(now, Ordering::Relaxed);
+}
+
+/// Heap bytes currently allocated and the most allocated at once this session
+#[cfg(feature = "diagnostics")]
+pub fn heap_usage() -> Option<(usize, usize)> {
+    Some((HEAP_BYTES.load(Ordering::Relaxed), PEAK_HEAP_BYTES.load(Ordering::Relaxed)))
+}
+
+/// Heap usage is only counted with the `diagnostics` feature
+#[cfg(not(feature = "diagnostics"))]
+pub fn heap_usage() -> Option<(usize, usize)> {
+    None
+}
+
+/// CPU time of the most recent frames, oldest first
+#[derive(Debug, Default)]
+pub struct FrameTimes {
+    times: VecDeque<Duration>,
+}
+
+impl FrameTimes {
+    pub fn record(&mut self, time: Duration) {
+        if self.times.len() == FRAME_HISTORY {
+            self.times.pop_front();
+        }
+        self.times.push_back(time);
+    }
+
+    pub fn last(&self) -> Option<Duration> {
+        self.times.back().copied()
+    }
+
+    pub fn mean(&self) -> Option<Duration> {
+        let n = self.times.len() as u32;
+        (n > 0).then(|| self.times.iter().sum::<Duration>() / n)
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.times.iter().max().copied()
+    }
+
+    /// Frame times in milliseconds, oldest first
+    pub fn millis(&self) -> impl Iterator<Item = f64> + '_ {
+        self.times.iter().map(|t| t.as_secs_f64() * 1000.0)
+    }
+}
+
+/// Diagnostics window state and the measurements collected while the app runs
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    pub open: bool,
+    pub frames: FrameTimes,
+    /// Duration of the last full analysis recompute
+    pub last_analysis: Option<Duration>,
+    /// Resident memory of the process, from the OS
+    pub resident_bytes: Option<u64>,
+    /// Most resident memory seen while sampling
+    pub peak_resident_bytes: Option<u64>,
+    /// Size of the files in the cache folder
+    pub disk_cache_bytes: Option<u64>,
+    last_sample: Option<Instant>,
+}
+
+impl Diagnostics {
+    /// Re-read the process memory and disk cache size if they are due
+    pub fn sample(&mut self) {
+        if self.last_sample.is_some_and(|t| t.elapsed() < SAMPLE_INTERVAL) {
+            return;
+        }
+        self.last_sample = Some(Instant::now());
+        self.resident_bytes = resident_bytes();
+        self.peak_resident_bytes = self.peak_resident_bytes.max(self.resident_bytes);
+        self.disk_cache_bytes = cache::cache_dir().ok().map(|dir| dir_bytes(&dir));
+    }
+}
+
+fn resident_bytes() -> Option<u64> {
+    let pid = sysinfo::get_current_pid().ok()?;
+    let mut sys = sysinfo::System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+    sys.process(pid).map(|p| p.memory())
+}
+
+fn dir_bytes(dir: &std::path::Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.metadata() {
+            Ok(meta) if meta.is_dir() => dir_bytes(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Approximate heap bytes of a price series
+pub fn series_bytes(series: &SectorTimeSeries) -> usize {
+    series.symbol.len() + series.name.len() + size_of_val(&series.bars[..])
+}
+
+/// Approximate heap bytes of the loaded market data: price bars (including sectors set
+/// aside by exclusions) and the other sources' records
+pub fn market_data_bytes(data: &MarketData, set_aside: &[SectorTimeSeries]) -> usize {
+    let bars: usize = data.sectors.iter().chain(set_aside).chain(&data.benchmark).map(series_bytes).sum();
+    bars + size_of_val(&data.treasury_rates[..])
+        + size_of_val(&data.sector_performance[..])
+        + size_of_val(&data.put_call_ratio[..])
+        + size_of_val(&data.skew_history[..])
+}
+
+/// Approximate heap bytes of the analysis results' series; plugin output is not counted
+pub fn analysis_bytes(analysis: &AnalysisResults) -> usize {
+    let volatility: usize = analysis
+        .volatility
+        .iter()
+        .map(|v| {
+            size_of_val(&v.dates[..])
+                + size_of_val(&v.short_window_vol[..])
+                + size_of_val(&v.long_window_vol[..])
+                + size_of_val(&v.parkinson_vol[..])
+                + size_of_val(&v.vol_ratio[..])
+        })
+        .sum();
+    let kurtosis: usize = analysis
+        .kurtosis
+        .iter()
+        .map(|k| {
+            size_of_val(&k.rolling_dates[..])
+                + size_of_val(&k.rolling_kurtosis[..])
+                + size_of_val(&k.rolling_skewness[..])
+                + size_of_val(&k.empirical_density[..])
+                + size_of_val(&k.normal_density[..])
+                + k.accel_metrics.as_ref().map_or(0, |a| size_of_val(&a.velocity[..]) + size_of_val(&a.acceleration[..]))
+        })
+        .sum();
+    let correlation = analysis.correlation.as_ref().map_or(0, |c| c.matrix.iter().map(|row| size_of_val(&row[..])).sum());
+    let betas: usize = analysis.rolling_beta.iter().map(|(_, b)| size_of_val(&b[..])).sum();
+    let custom: usize = analysis.custom.iter().map(|c| size_of_val(&c.values[..])).sum();
+    volatility
+        + kurtosis
+        + correlation
+        + betas
+        + custom
+        + size_of_val(&analysis.bond_spreads[..])
+        + size_of_val(&analysis.rolling_avg_correlation[..])
+        + size_of_val(&analysis.dispersion[..])
+        + size_of_val(&analysis.randomness[..])
+        + size_of_val(&analysis.risk[..])
+}
+
+/// Byte count with a binary unit, e.g. "12.3 MiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::OhlcvBar;
+
+    #[test]
+    fn test_frame_times_keep_recent_history() {
+        let mut frames = FrameTimes::default();
+        assert_eq!(frames.mean(), None);
+        for ms in 0..FRAME_HISTORY as u64 + 10 {
+            frames.record(Duration::from_millis(ms));
+        }
+        assert_eq!(frames.millis().count(), FRAME_HISTORY);
+        assert_eq!(frames.millis().next(), Some(10.0));
+        assert_eq!(frames.last(), Some(Duration::from_millis(FRAME_HISTORY as u64 + 9)));
+        assert_eq!(frames.max(), frames.last());
+    }
+
+    #[test]
+    fn test_market_data_bytes_counts_bars() {
+        let bar = OhlcvBar { date: chrono::NaiveDate::MIN, open: 1.0, high: 1.0, low: 1.0, close: 1.0, volume: 0 };
+        let series = |symbol: &str| SectorTimeSeries {
+            symbol: symbol.to_string(),
+            name: String::new(),
+            bars: vec![bar.clone(); 100],
+        };
+        let data = MarketData { sectors: vec![series("XLK")], benchmark: Some(series("SPY")), ..Default::default() };
+        let bar_size = std::mem::size_of::<OhlcvBar>();
+        assert_eq!(market_data_bytes(&data, &[]), 2 * (3 + 100 * bar_size));
+        assert_eq!(market_data_bytes(&data, &[series("XLE")]), 3 * (3 + 100 * bar_size));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+}
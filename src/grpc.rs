@@ -0,0 +1,376 @@
+//! Optional gRPC server (`grpc` feature) for programmatic access: it serves the latest
+//! analysis and predictions defined in `proto/vol_analysis.proto`, streams a new
+//! snapshot after every data refresh or prediction, and can start a refresh. It runs
+//! as a task on the task manager, so it is listed in the Tasks window and stopping it
+//! there (or disabling it in Settings) shuts it down.
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::data::models::NnPredictions;
+use crate::notices::NoticeSource;
+use crate::tasks::TaskId;
+use crate::ui::i18n::tr_args;
+
+/// Whether this build includes the gRPC server
+pub const AVAILABLE: bool = cfg!(feature = "grpc");
+
+/// Local-only by default; bind 0.0.0.0 to serve other machines
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:50051";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GrpcSettings {
+    pub enabled: bool,
+    /// Socket address the server listens on
+    pub address: String,
+}
+
+impl Default for GrpcSettings {
+    fn default() -> Self {
+        Self { enabled: false, address: DEFAULT_ADDRESS.to_string() }
+    }
+}
+
+/// gRPC settings and the running server
+#[derive(Default)]
+pub struct GrpcState {
+    /// Persisted
+    pub settings: GrpcSettings,
+    /// The server task, while it runs
+    pub task: Option<TaskId>,
+    /// Address the running server is bound to
+    pub listening: Option<String>,
+    /// Start the server on the next frame if it is enabled. Set at launch and when the
+    /// settings change, so a server that failed to bind isn't retried every frame.
+    start_pending: bool,
+    /// Predictions in the last published snapshot
+    published_predictions: NnPredictions,
+    shared: server::Shared,
+}
+
+impl GrpcState {
+    pub fn new(settings: GrpcSettings) -> Self {
+        Self { settings, start_pending: true, ..Default::default() }
+    }
+
+    /// Stop the server and start it again with the current settings, if enabled
+    pub fn restart(&mut self, tasks: &mut crate::tasks::TaskManager) {
+        if let Some(id) = self.task.take() {
+            tasks.cancel(id);
+        }
+        self.listening = None;
+        self.start_pending = true;
+    }
+}
+
+/// Per-frame server handling: start or stop it to match the settings and publish a
+/// snapshot when the predictions change. Returns whether a client asked for a refresh.
+pub fn update(state: &mut AppState) -> bool {
+    let grpc = &mut state.grpc;
+    if let Some(id) = grpc.task {
+        if !state.tasks.running().any(|t| t.id == id) {
+            // Stopped from the Tasks window or failed; a failure is reported as a notice
+            grpc.task = None;
+            grpc.listening = None;
+        } else if !grpc.settings.enabled {
+            state.tasks.cancel(id);
+            grpc.task = None;
+            grpc.listening = None;
+        }
+    }
+    if AVAILABLE && grpc.settings.enabled && grpc.task.is_none() && std::mem::take(&mut grpc.start_pending) {
+        match server::start(&mut state.tasks, &grpc.settings.address, &grpc.shared) {
+            Ok(id) => {
+                grpc.task = Some(id);
+                grpc.listening = Some(grpc.settings.address.trim().to_string());
+                publish(state);
+            }
+            Err(e) => {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-grpc-not-started", &[("error", e)]))
+            }
+        }
+    }
+
+    let grpc = &state.grpc;
+    if grpc.task.is_some() && grpc.published_predictions != state.nn_predictions {
+        publish(state);
+    }
+    state.grpc.shared.take_refresh()
+}
+
+/// Send the current analysis and predictions to the server's clients
+pub fn publish(state: &mut AppState) {
+    if state.grpc.task.is_none() {
+        return;
+    }
+    server::publish(&state.grpc.shared, state);
+    state.grpc.published_predictions = state.nn_predictions.clone();
+}
+
+#[cfg(feature = "grpc")]
+mod server {
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use tokio::sync::watch;
+    use tokio_stream::{Stream, StreamExt};
+    use tonic::{Request, Response, Status};
+
+    use crate::analysis::AnalysisResults;
+    use crate::app::AppState;
+    use crate::data::models::{MarketData, NnPredictions};
+    use crate::tasks::{TaskHooks, TaskId, TaskKind, TaskManager};
+    use crate::ui::i18n::tr_args;
+
+    pub mod proto {
+        tonic::include_proto!("vol_analysis.v1");
+    }
+
+    use proto::vol_analysis_server::{VolAnalysis, VolAnalysisServer};
+
+    /// State the UI shares with the server: the latest snapshot and refresh requests
+    #[derive(Clone)]
+    pub struct Shared {
+        snapshot: watch::Sender<Arc<proto::Snapshot>>,
+        refresh: Arc<AtomicBool>,
+    }
+
+    impl Default for Shared {
+        fn default() -> Self {
+            Self {
+                snapshot: watch::Sender::new(Arc::new(proto::Snapshot::default())),
+                refresh: Arc::new(AtomicBool::new(false)),
+            }
+        }
+    }
+
+    impl Shared {
+        pub fn take_refresh(&self) -> bool {
+            self.refresh.swap(false, Ordering::SeqCst)
+        }
+    }
+
+    pub fn start(tasks: &mut TaskManager, address: &str, shared: &Shared) -> Result<TaskId, String> {
+        let addr: std::net::SocketAddr =
+            address.trim().parse().map_err(|e| format!("Invalid address {}: {}", address.trim(), e))?;
+        let service = VolAnalysisServer::new(Service { shared: shared.clone() });
+        let label = tr_args("task-grpc", &[("address", addr.to_string())]);
+        Ok(tasks.spawn(TaskKind::Grpc, label, TaskHooks::default(), async move {
+            tonic::transport::Server::builder()
+                .add_service(service)
+                .serve(addr)
+                .await
+                .map_err(|e| format!("gRPC server on {}: {}", addr, e))
+        }))
+    }
+
+    pub fn publish(shared: &Shared, state: &AppState) {
+        let snapshot = proto::Snapshot {
+            updated_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            analysis: Some(analysis(&state.market_data, &state.analysis)),
+            predictions: Some(predictions(&state.nn_predictions)),
+        };
+        shared.snapshot.send_replace(Arc::new(snapshot));
+    }
+
+    fn analysis(data: &MarketData, results: &AnalysisResults) -> proto::Analysis {
+        let sectors = data
+            .sectors
+            .iter()
+            .map(|sector| {
+                let symbol = sector.symbol.as_str();
+                let vol = results.volatility.iter().find(|v| v.symbol == symbol);
+                let kurtosis = results.kurtosis.iter().find(|k| k.symbol == symbol);
+                let randomness = results.randomness.iter().find(|r| r.symbol == symbol);
+                let risk = results.risk.iter().find(|r| r.symbol == symbol);
+                proto::SectorAnalysis {
+                    symbol: symbol.to_string(),
+                    short_vol: vol.and_then(|v| v.short_window_vol.last().copied()),
+                    long_vol: vol.and_then(|v| v.long_window_vol.last().copied()),
+                    parkinson_vol: vol.and_then(|v| v.parkinson_vol.last().copied()),
+                    vol_ratio: vol.and_then(|v| v.vol_ratio.last().copied()),
+                    excess_kurtosis: kurtosis.map(|k| k.excess_kurtosis),
+                    skewness: kurtosis.map(|k| k.skewness),
+                    jarque_bera: kurtosis.map(|k| k.jarque_bera),
+                    entropy: randomness.map(|r| r.entropy),
+                    hurst_exponent: randomness.map(|r| r.hurst_exponent),
+                    autocorrelation_lag1: randomness.map(|r| r.autocorrelation_lag1),
+                    var: risk.and_then(|r| r.var),
+                    expected_shortfall: risk.and_then(|r| r.expected_shortfall),
+                    max_drawdown: risk.and_then(|r| r.max_drawdown),
+                    tail_index: risk.and_then(|r| r.tail_index),
+                    vol_percentile: risk.and_then(|r| r.vol_percentile),
+                }
+            })
+            .collect();
+        proto::Analysis {
+            last_date: data
+                .sectors
+                .iter()
+                .filter_map(|s| s.bars.last().map(|b| b.date))
+                .max()
+                .map(|d| d.to_string())
+                .unwrap_or_default(),
+            sectors,
+            correlation: results.correlation.as_ref().map(|c| proto::CorrelationMatrix {
+                symbols: c.symbols.clone(),
+                values: c.matrix.iter().flatten().copied().collect(),
+            }),
+            avg_cross_correlation: results.avg_cross_correlation,
+            bond_spreads: results
+                .bond_spreads
+                .iter()
+                .map(|s| proto::BondSpread {
+                    date: s.date.to_string(),
+                    spread_10y_2y: s.spread_10y_2y,
+                    curve_slope: s.curve_slope,
+                })
+                .collect(),
+        }
+    }
+
+    /// One entry per sector, merging every prediction head, in the order sectors first appear
+    fn predictions(preds: &NnPredictions) -> proto::Predictions {
+        let mut sectors = Vec::new();
+        for (symbol, vol) in &preds.vol {
+            entry(&mut sectors, symbol).vol = Some(*vol);
+        }
+        for (symbol, entropy) in &preds.randomness {
+            entry(&mut sectors, symbol).entropy = Some(*entropy);
+        }
+        for (symbol, kurtosis, skew) in &preds.kurtosis {
+            let sector = entry(&mut sectors, symbol);
+            sector.kurtosis = Some(*kurtosis);
+            sector.skew = Some(*skew);
+        }
+        for (symbol, [low, normal, high]) in &preds.regime_probs {
+            entry(&mut sectors, symbol).regime =
+                Some(proto::RegimeProbabilities { low: *low, normal: *normal, high: *high });
+        }
+        proto::Predictions { sectors }
+    }
+
+    fn entry<'a>(sectors: &'a mut Vec<proto::SectorPrediction>, symbol: &str) -> &'a mut proto::SectorPrediction {
+        let i = sectors.iter().position(|s| s.symbol == symbol).unwrap_or_else(|| {
+            sectors.push(proto::SectorPrediction { symbol: symbol.to_string(), ..Default::default() });
+            sectors.len() - 1
+        });
+        &mut sectors[i]
+    }
+
+    /// Keep the entries whose symbol is in `symbols` (case-insensitive); empty keeps all
+    fn retain_symbols<T>(items: &mut Vec<T>, symbols: &[String], symbol: impl Fn(&T) -> &str) {
+        if !symbols.is_empty() {
+            items.retain(|item| symbols.iter().any(|s| s.trim().eq_ignore_ascii_case(symbol(item))));
+        }
+    }
+
+    struct Service {
+        shared: Shared,
+    }
+
+    impl Service {
+        fn latest(&self) -> Arc<proto::Snapshot> {
+            self.shared.snapshot.borrow().clone()
+        }
+    }
+
+    // The generated trait fixes the error type to `tonic::Status`
+    #[allow(clippy::result_large_err)]
+    #[tonic::async_trait]
+    impl VolAnalysis for Service {
+        async fn get_analysis(
+            &self,
+            request: Request<proto::AnalysisRequest>,
+        ) -> Result<Response<proto::Analysis>, Status> {
+            let mut analysis = self.latest().analysis.clone().unwrap_or_default();
+            retain_symbols(&mut analysis.sectors, &request.get_ref().symbols, |s| &s.symbol);
+            Ok(Response::new(analysis))
+        }
+
+        async fn get_predictions(
+            &self,
+            request: Request<proto::PredictionsRequest>,
+        ) -> Result<Response<proto::Predictions>, Status> {
+            let mut predictions = self.latest().predictions.clone().unwrap_or_default();
+            retain_symbols(&mut predictions.sectors, &request.get_ref().symbols, |s| &s.symbol);
+            Ok(Response::new(predictions))
+        }
+
+        type WatchSnapshotsStream = Pin<Box<dyn Stream<Item = Result<proto::Snapshot, Status>> + Send>>;
+
+        async fn watch_snapshots(
+            &self,
+            _request: Request<proto::WatchRequest>,
+        ) -> Result<Response<Self::WatchSnapshotsStream>, Status> {
+            let updates = tokio_stream::wrappers::WatchStream::new(self.shared.snapshot.subscribe());
+            Ok(Response::new(Box::pin(updates.map(|snapshot| Ok((*snapshot).clone())))))
+        }
+
+        async fn refresh_data(
+            &self,
+            _request: Request<proto::RefreshRequest>,
+        ) -> Result<Response<proto::RefreshReply>, Status> {
+            let already_requested = self.shared.refresh.swap(true, Ordering::SeqCst);
+            Ok(Response::new(proto::RefreshReply { started: !already_requested }))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_predictions_merge_by_symbol() {
+            let preds = NnPredictions {
+                vol: vec![("XLK".to_string(), 0.2), ("XLE".to_string(), 0.3)],
+                kurtosis: vec![("XLE".to_string(), 4.0, -0.5)],
+                regime_probs: vec![("XLF".to_string(), [0.1, 0.7, 0.2])],
+                ..Default::default()
+            };
+            let sectors = predictions(&preds).sectors;
+            let symbols: Vec<&str> = sectors.iter().map(|s| s.symbol.as_str()).collect();
+            assert_eq!(symbols, ["XLK", "XLE", "XLF"]);
+            assert_eq!((sectors[1].vol, sectors[1].kurtosis, sectors[1].skew), (Some(0.3), Some(4.0), Some(-0.5)));
+            assert_eq!(sectors[0].entropy, None);
+            assert_eq!(sectors[2].regime.as_ref().map(|r| r.normal), Some(0.7));
+        }
+
+        #[test]
+        fn test_retain_symbols() {
+            let mut sectors = predictions(&NnPredictions {
+                vol: vec![("XLK".to_string(), 0.2), ("XLE".to_string(), 0.3)],
+                ..Default::default()
+            })
+            .sectors;
+            retain_symbols(&mut sectors, &[], |s| &s.symbol);
+            assert_eq!(sectors.len(), 2);
+            retain_symbols(&mut sectors, &["xle ".to_string()], |s| &s.symbol);
+            assert_eq!(sectors.len(), 1);
+            assert_eq!(sectors[0].symbol, "XLE");
+        }
+    }
+}
+
+#[cfg(not(feature = "grpc"))]
+mod server {
+    use crate::app::AppState;
+    use crate::tasks::{TaskId, TaskManager};
+
+    #[derive(Clone, Default)]
+    pub struct Shared;
+
+    impl Shared {
+        pub fn take_refresh(&self) -> bool {
+            false
+        }
+    }
+
+    pub fn start(_tasks: &mut TaskManager, _address: &str, _shared: &Shared) -> Result<TaskId, String> {
+        Err("built without the `grpc` feature".to_string())
+    }
+
+    pub fn publish(_shared: &Shared, _state: &AppState) {}
+}
@@ -0,0 +1,73 @@
+//! Market structure volatility analyzer. The binary in `main.rs` only calls [`run`];
+//! the analysis and data model are public so the criterion benchmarks in `benches/`
+//! can drive them directly. Without the `native` feature only the analysis core, the
+//! data model and the chart helpers are built, which also compile to wasm32.
+
+// Without `native` the app that uses the settings and chart helpers isn't built
+#[cfg_attr(not(feature = "native"), allow(dead_code))]
+mod config;
+pub mod data;
+pub mod analysis;
+#[cfg_attr(not(feature = "native"), allow(dead_code))]
+mod ui;
+#[cfg_attr(not(feature = "native"), allow(dead_code))]
+mod notices;
+#[cfg(feature = "native")]
+mod app;
+#[cfg(feature = "native")]
+mod nn;
+#[cfg(feature = "native")]
+mod tasks;
+#[cfg(feature = "native")]
+mod logging;
+#[cfg(feature = "native")]
+mod grpc;
+#[cfg(feature = "native")]
+mod diagnostics;
+
+#[cfg(feature = "native")]
+use app::MktNoiseApp;
+
+#[cfg(feature = "diagnostics")]
+#[global_allocator]
+static ALLOCATOR: diagnostics::CountingAllocator = diagnostics::CountingAllocator;
+
+/// Load settings and keys, set up logging and open the main window
+#[cfg(feature = "native")]
+pub fn run() -> eframe::Result<()> {
+    data::api_keys::apply_saved();
+    config::load_env();
+    let _log_guard = logging::init();
+
+    // `--demo` replays the bundled fixtures instead of fetching
+    let fixture_mode = if std::env::args().any(|a| a == "--demo") {
+        data::fixtures::FixtureMode::Replay
+    } else {
+        data::fixtures::FixtureMode::from_env()
+    };
+    if fixture_mode != data::fixtures::FixtureMode::Live {
+        tracing::info!("{} in {}", fixture_mode.label(), data::fixtures::FIXTURE_DIR);
+    }
+    data::fixtures::set_mode(fixture_mode);
+
+    // Restore the window where it was left
+    let ui_state = app::UiState::load();
+    let mut viewport = eframe::egui::ViewportBuilder::default()
+        .with_inner_size(ui_state.window_size.unwrap_or([1280.0, 800.0]))
+        .with_min_inner_size([800.0, 600.0])
+        .with_maximized(ui_state.window_maximized);
+    if let Some(pos) = ui_state.window_pos {
+        viewport = viewport.with_position(pos);
+    }
+
+    let options = eframe::NativeOptions {
+        viewport,
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        "Volume Analysis",
+        options,
+        Box::new(|_cc| Ok(Box::new(MktNoiseApp::default()))),
+    )
+}
@@ -0,0 +1,93 @@
+//! Logging to the terminal and to daily-rotated files in `logs/`, so diagnostics
+//! survive when the app isn't started from a terminal. The level can be changed at
+//! runtime from Settings and is persisted in `config.toml`.
+
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, Registry};
+
+/// Folder log files are written to, in the working directory
+pub const LOG_DIR: &str = "logs";
+
+/// Daily files kept before the oldest is deleted
+pub const MAX_LOG_FILES: usize = 14;
+
+static LEVEL_HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [Self::Error, Self::Warn, Self::Info, Self::Debug, Self::Trace];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Error => "Error",
+            Self::Warn => "Warn",
+            Self::Info => "Info",
+            Self::Debug => "Debug",
+            Self::Trace => "Trace",
+        }
+    }
+
+    fn filter(self) -> LevelFilter {
+        match self {
+            Self::Error => LevelFilter::ERROR,
+            Self::Warn => LevelFilter::WARN,
+            Self::Info => LevelFilter::INFO,
+            Self::Debug => LevelFilter::DEBUG,
+            Self::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Install the global subscriber at the default level. Log lines are written on a
+/// background thread; keep the returned guard alive until exit so they are flushed.
+/// Falls back to terminal-only logging when the log folder can't be created.
+pub fn init() -> Option<WorkerGuard> {
+    let (level, handle) = reload::Layer::new(LogLevel::default().filter());
+    LEVEL_HANDLE.set(handle).ok();
+
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("vol-analysis")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(LOG_DIR);
+    let (file_layer, guard) = match appender {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            (Some(fmt::layer().with_writer(writer).with_ansi(false)), Some(guard))
+        }
+        Err(e) => {
+            eprintln!("File logging disabled: {}", e);
+            (None, None)
+        }
+    };
+
+    tracing_subscriber::registry().with(level).with(fmt::layer()).with(file_layer).init();
+    guard
+}
+
+/// Change the level of every log output
+pub fn set_level(level: LogLevel) {
+    if let Some(handle) = LEVEL_HANDLE.get() {
+        if let Err(e) = handle.reload(level.filter()) {
+            tracing::warn!("Failed to change log level: {}", e);
+        }
+    }
+}
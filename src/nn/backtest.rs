@@ -0,0 +1,270 @@
+use burn::{
+    backend::NdArray,
+    data::dataloader::batcher::Batcher,
+};
+
+use crate::config;
+use crate::data::models::{MarketData, NnFeatureFlags};
+use crate::nn::dataset::{build_dataset, output_to_vol, reference_vol, target_level, VolBatcher};
+use crate::nn::persistence::ModelMetadata;
+use crate::nn::LoadedModel;
+
+/// Number of windows pushed through the model per forward pass
+const BACKTEST_BATCH_SIZE: usize = 64;
+
+/// Residual autocorrelation lags reported in the diagnostics
+pub const RESIDUAL_ACF_LAGS: usize = 10;
+
+/// Realized-vol quantile buckets for the bias-by-vol-level breakdown
+const VOL_LEVEL_BUCKETS: usize = 5;
+
+/// Error metrics comparing predicted vs subsequently realized volatility
+#[derive(Debug, Clone, Default)]
+pub struct BacktestMetrics {
+    pub samples: usize,
+    pub mse: f64,
+    pub mae: f64,
+    /// QLIKE loss on variances (robust to noisy vol proxies, penalizes under-prediction)
+    pub qlike: f64,
+    /// Fraction of samples where predicted and realized vol moved in the same
+    /// direction relative to the vol at the end of the input window
+    pub hit_rate: f64,
+}
+
+/// Result of running a trained model over the full history
+#[derive(Debug, Clone, Default)]
+pub struct BacktestResult {
+    /// Predicted 5-day forward vol per window (chronological)
+    pub predicted: Vec<f64>,
+    /// Subsequently realized 5-day forward vol per window
+    pub actual: Vec<f64>,
+    /// Index of the first window not seen during training (chronological 80/20 split)
+    pub oos_start: usize,
+    pub overall: BacktestMetrics,
+    pub out_of_sample: BacktestMetrics,
+    /// Residual statistics over the out-of-sample windows
+    pub residuals: ResidualDiagnostics,
+}
+
+/// Statistics of the forecast residuals (predicted − realized)
+#[derive(Debug, Clone, Default)]
+pub struct ResidualDiagnostics {
+    pub samples: usize,
+    /// Mean residual; negative means vol is systematically under-predicted
+    pub bias: f64,
+    pub residual_std: f64,
+    /// Residual autocorrelation at lags 1..=`RESIDUAL_ACF_LAGS`. Overlapping 5-day
+    /// targets make the first few lags positive even for a good model.
+    pub autocorrelation: Vec<f64>,
+    /// Approximate 95% band for white-noise autocorrelation (±1.96/√n)
+    pub acf_band: f64,
+    /// (mean realized vol, mean residual) per realized-vol quintile, low to high
+    pub bias_by_vol_level: Vec<(f64, f64)>,
+}
+
+/// Run the model over every historical window and score predicted vs realized vol.
+/// Returns `None` if there is not enough data to build the dataset.
+pub fn run_backtest(
+    model: &LoadedModel,
+    market_data: &MarketData,
+    feature_flags: &NnFeatureFlags,
+    metadata: Option<&ModelMetadata>,
+) -> Option<BacktestResult> {
+    let lookback = metadata.map_or(config::NN_LOOKBACK_DAYS, |m| m.training_config.lookback);
+    let target = metadata.map(|m| m.training_config.vol_target).unwrap_or_default();
+    let mut dataset = build_dataset(market_data, lookback, config::NN_FORWARD_DAYS, feature_flags);
+    if dataset.samples.is_empty() {
+        return None;
+    }
+    if let Some(stats) = metadata.and_then(|m| m.feature_stats.as_ref()) {
+        dataset.standardize(stats);
+    }
+
+    let device = <NdArray as burn::tensor::backend::Backend>::Device::default();
+    let batcher = VolBatcher::<NdArray>::new(device, dataset.panel.clone());
+
+    let mut predicted = Vec::with_capacity(dataset.samples.len());
+    for chunk in dataset.samples.chunks(BACKTEST_BATCH_SIZE) {
+        let batch = batcher.batch(chunk.to_vec());
+        let output = model.forward(batch.inputs);
+        let [rows, cols] = output.dims();
+        let vals = output.into_data().to_vec::<f32>().unwrap_or_default();
+        for (r, sample) in chunk.iter().enumerate().take(rows) {
+            let output = vals.get(r * cols).copied().unwrap_or(f32::NAN) as f64;
+            predicted.push(output_to_vol(output, reference_vol(sample, target), target));
+        }
+    }
+
+    // Scored in vol levels of the target series, whatever space the model was trained in
+    let actual: Vec<f64> = dataset.samples.iter().map(|s| target_level(s, target)).collect();
+    let current: Vec<f64> = dataset.samples.iter().map(|s| reference_vol(s, target)).collect();
+    let oos_start = (actual.len() as f64 * 0.8) as usize;
+
+    let overall = compute_metrics(&predicted, &actual, &current);
+    let out_of_sample = compute_metrics(&predicted[oos_start..], &actual[oos_start..], &current[oos_start..]);
+    let residuals = compute_residual_diagnostics(&predicted[oos_start..], &actual[oos_start..]);
+
+    Some(BacktestResult {
+        predicted,
+        actual,
+        oos_start,
+        overall,
+        out_of_sample,
+        residuals,
+    })
+}
+
+/// Compute MSE, MAE, QLIKE, and directional hit rate over aligned slices.
+pub fn compute_metrics(predicted: &[f64], actual: &[f64], current: &[f64]) -> BacktestMetrics {
+    let n = predicted.len().min(actual.len()).min(current.len());
+    if n == 0 {
+        return BacktestMetrics::default();
+    }
+
+    let mut se = 0.0;
+    let mut ae = 0.0;
+    let mut ql = 0.0;
+    let mut hits = 0_usize;
+    for i in 0..n {
+        let err = predicted[i] - actual[i];
+        se += err * err;
+        ae += err.abs();
+        ql += qlike(predicted[i], actual[i]);
+
+        let pred_dir = predicted[i] - current[i];
+        let actual_dir = actual[i] - current[i];
+        if pred_dir * actual_dir > 0.0 || (pred_dir == 0.0 && actual_dir == 0.0) {
+            hits += 1;
+        }
+    }
+
+    let nf = n as f64;
+    BacktestMetrics {
+        samples: n,
+        mse: se / nf,
+        mae: ae / nf,
+        qlike: ql / nf,
+        hit_rate: hits as f64 / nf,
+    }
+}
+
+/// Bias, spread, autocorrelation, and bias by vol level of the residuals
+/// `predicted - actual`. Non-finite pairs are skipped.
+pub fn compute_residual_diagnostics(predicted: &[f64], actual: &[f64]) -> ResidualDiagnostics {
+    let pairs: Vec<(f64, f64)> = predicted
+        .iter()
+        .zip(actual)
+        .filter(|(p, a)| p.is_finite() && a.is_finite())
+        .map(|(p, a)| (*a, p - a))
+        .collect();
+    let n = pairs.len();
+    if n < 2 {
+        return ResidualDiagnostics::default();
+    }
+
+    let resid: Vec<f64> = pairs.iter().map(|(_, r)| *r).collect();
+    let nf = n as f64;
+    let bias = resid.iter().sum::<f64>() / nf;
+    let var = resid.iter().map(|r| (r - bias).powi(2)).sum::<f64>() / nf;
+
+    let autocorrelation = (1..=RESIDUAL_ACF_LAGS.min(n - 1))
+        .map(|lag| {
+            if var <= 0.0 {
+                return 0.0;
+            }
+            let cov = (lag..n).map(|i| (resid[i] - bias) * (resid[i - lag] - bias)).sum::<f64>() / nf;
+            cov / var
+        })
+        .collect();
+
+    let mut by_level = pairs;
+    by_level.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let buckets = VOL_LEVEL_BUCKETS.min(n);
+    let bias_by_vol_level = (0..buckets)
+        .map(|b| {
+            let bucket = &by_level[b * n / buckets..(b + 1) * n / buckets];
+            let len = bucket.len() as f64;
+            (
+                bucket.iter().map(|(a, _)| a).sum::<f64>() / len,
+                bucket.iter().map(|(_, r)| r).sum::<f64>() / len,
+            )
+        })
+        .collect();
+
+    ResidualDiagnostics {
+        samples: n,
+        bias,
+        residual_std: var.sqrt(),
+        autocorrelation,
+        acf_band: 1.96 / nf.sqrt(),
+        bias_by_vol_level,
+    }
+}
+
+/// QLIKE loss for a single (predicted, realized) vol pair, evaluated on variances:
+/// `r - ln(r) - 1` with `r = realized² / predicted²`. Zero when the forecast is exact.
+fn qlike(predicted_vol: f64, actual_vol: f64) -> f64 {
+    const EPS: f64 = 1e-8;
+    let pred_var = (predicted_vol * predicted_vol).max(EPS);
+    let actual_var = (actual_vol * actual_vol).max(EPS);
+    let ratio = actual_var / pred_var;
+    ratio - ratio.ln() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perfect_forecast() {
+        let actual = vec![0.15, 0.20, 0.18, 0.25];
+        let current = vec![0.16, 0.16, 0.21, 0.17];
+        let m = compute_metrics(&actual, &actual, &current);
+        assert_eq!(m.samples, 4);
+        assert!(m.mse.abs() < 1e-12);
+        assert!(m.mae.abs() < 1e-12);
+        assert!(m.qlike.abs() < 1e-12);
+        assert!((m.hit_rate - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors_and_direction() {
+        let predicted = vec![0.20, 0.10];
+        let actual = vec![0.30, 0.20];
+        let current = vec![0.15, 0.15];
+        let m = compute_metrics(&predicted, &actual, &current);
+        assert!((m.mse - 0.01).abs() < 1e-12);
+        assert!((m.mae - 0.10).abs() < 1e-12);
+        // First: both up (hit). Second: predicted down, actual up (miss).
+        assert!((m.hit_rate - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_qlike_penalizes_under_prediction() {
+        let under = qlike(0.10, 0.20);
+        let over = qlike(0.30, 0.20);
+        assert!(under > 0.0 && over > 0.0);
+        assert!(under > over);
+    }
+
+    #[test]
+    fn test_empty_metrics() {
+        let m = compute_metrics(&[], &[], &[]);
+        assert_eq!(m.samples, 0);
+    }
+
+    #[test]
+    fn test_residuals_show_spike_under_prediction() {
+        // Flat forecast against rising realized vol: high-vol windows are under-predicted
+        let actual: Vec<f64> = (0..50).map(|i| 0.10 + i as f64 * 0.005).collect();
+        let predicted = vec![0.20; 50];
+        let d = compute_residual_diagnostics(&predicted, &actual);
+        assert_eq!(d.samples, 50);
+        assert_eq!(d.autocorrelation.len(), RESIDUAL_ACF_LAGS);
+        assert_eq!(d.bias_by_vol_level.len(), VOL_LEVEL_BUCKETS);
+        let (low, high) = (d.bias_by_vol_level[0], d.bias_by_vol_level[VOL_LEVEL_BUCKETS - 1]);
+        assert!(low.1 > 0.0 && high.1 < 0.0);
+        // Trending residuals are strongly autocorrelated
+        assert!(d.autocorrelation[0] > d.acf_band);
+    }
+}
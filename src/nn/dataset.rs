@@ -1,31 +1,91 @@
+use std::ops::Range;
+use std::sync::Arc;
+
 use burn::{
     data::{dataloader::batcher::Batcher, dataset::Dataset},
     tensor::{backend::Backend, Tensor},
 };
+use chrono::NaiveDate;
+use ndarray::{s, Array2, ArrayView2, ArrayViewMut2};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::analysis;
 use crate::config;
-use crate::data::models::{MarketData, NnFeatureFlags};
+use crate::data::models::{MarketData, NnAugmentation, NnFeatureFlags, NnVolTarget};
+use crate::nn::model::CUSTOM_INDICATOR_FEATURES;
 
-/// A single training sample: a window of features and targets
+/// A single training sample: a window of the dataset's feature panel and its targets
 #[derive(Debug, Clone)]
 pub struct VolSample {
-    /// Feature matrix: [seq_length, num_features]
-    pub features: Vec<Vec<f64>>,
+    /// Panel rows of the window, oldest first: [seq_length, num_features] once sliced
+    pub rows: Range<usize>,
     /// Target: forward realized volatility
     pub target_vol: f64,
+    /// Average sector vol on the last day of the window (reference for vol direction)
+    pub current_vol: f64,
+    /// Target: per-sector forward realized vol over [end, end+forward), length 11
+    pub target_sector_vols: Vec<f64>,
+    /// Per-sector vol on the last day of the window, length 11
+    pub current_sector_vols: Vec<f64>,
+    /// Target: benchmark (SPY) forward realized vol (0 without benchmark data)
+    pub target_benchmark_vol: f64,
+    /// Benchmark vol on the last day of the window
+    pub current_benchmark_vol: f64,
     /// Target: per-sector entropy (5-day forward), length 11
     pub target_randomness: Vec<f64>,
     /// Target: per-sector (kurtosis, skewness) interleaved, length 22
     pub target_kurtosis: Vec<f64>,
 }
 
-/// Dataset of volatility prediction samples
-#[derive(Debug, Clone)]
+/// Dataset of volatility prediction samples. Consecutive windows overlap, so the
+/// features of each time step are stored once, in one contiguous panel shared by every
+/// sample (and every batcher), and a sample is a range of its rows.
+#[derive(Debug, Clone, Default)]
 pub struct VolDataset {
+    /// Features of every aligned time step: [steps, num_features]
+    pub panel: Arc<Array2<f64>>,
+    /// Trading date of each panel row
+    pub dates: Vec<NaiveDate>,
     pub samples: Vec<VolSample>,
 }
 
+impl VolDataset {
+    /// Feature matrix of `sample`'s window: [seq_length, num_features]
+    pub fn features(&self, sample: &VolSample) -> ArrayView2<'_, f64> {
+        self.panel.slice(s![sample.rows.clone(), ..])
+    }
+
+    /// The samples in `range`, sharing this dataset's panel
+    pub fn subset(&self, range: Range<usize>) -> VolDataset {
+        VolDataset { panel: self.panel.clone(), dates: self.dates.clone(), samples: self.samples[range].to_vec() }
+    }
+
+    /// Approximate heap bytes of the panel, dates and per-sample targets
+    pub fn heap_bytes(&self) -> usize {
+        let samples: usize = self
+            .samples
+            .iter()
+            .map(|s| {
+                std::mem::size_of::<VolSample>()
+                    + std::mem::size_of_val(&s.target_sector_vols[..])
+                    + std::mem::size_of_val(&s.current_sector_vols[..])
+                    + std::mem::size_of_val(&s.target_randomness[..])
+                    + std::mem::size_of_val(&s.target_kurtosis[..])
+            })
+            .sum();
+        self.panel.len() * std::mem::size_of::<f64>()
+            + std::mem::size_of_val(&self.dates[..])
+            + samples
+    }
+
+    /// Standardize the panel with `stats`. Done before the panel is shared, it is
+    /// updated in place.
+    pub fn standardize(&mut self, stats: &FeatureStats) {
+        stats.apply(Arc::make_mut(&mut self.panel).view_mut());
+    }
+}
+
 impl Dataset<VolSample> for VolDataset {
     fn get(&self, index: usize) -> Option<VolSample> {
         self.samples.get(index).cloned()
@@ -36,25 +96,157 @@ impl Dataset<VolSample> for VolDataset {
     }
 }
 
-/// Build a dataset from market data by engineering features and creating sliding windows
+/// Per-feature standardization statistics (z-score), fitted on the training split.
+///
+/// Raw features span very different scales (vols ~0.2, returns ~0.001, spreads in
+/// percent), so the same statistics must be applied at training and inference time.
+/// They are persisted alongside the model checkpoint in `ModelMetadata`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureStats {
+    pub mean: Vec<f64>,
+    pub std: Vec<f64>,
+}
+
+impl FeatureStats {
+    /// Compute per-feature mean and standard deviation over every time step (row) of `features`.
+    /// Features with (near) zero variance get a std of 1.0 so they pass through centered.
+    pub fn fit(features: ArrayView2<f64>) -> Self {
+        let num_features = features.ncols();
+
+        let mut sum = vec![0.0; num_features];
+        let mut sum_sq = vec![0.0; num_features];
+        let mut count = 0_usize;
+        for step in features.rows() {
+            for (j, &f) in step.iter().enumerate() {
+                sum[j] += f;
+                sum_sq[j] += f * f;
+            }
+            count += 1;
+        }
+
+        if count == 0 {
+            return Self { mean: vec![], std: vec![] };
+        }
+
+        let n = count as f64;
+        let mean: Vec<f64> = sum.iter().map(|s| s / n).collect();
+        let std: Vec<f64> = sum_sq
+            .iter()
+            .zip(&mean)
+            .map(|(sq, m)| {
+                let var = (sq / n - m * m).max(0.0);
+                let sd = var.sqrt();
+                if sd > 1e-12 { sd } else { 1.0 }
+            })
+            .collect();
+
+        Self { mean, std }
+    }
+
+    /// Standardize every time step (row) of `features` in place.
+    pub fn apply(&self, mut features: ArrayViewMut2<f64>) {
+        for mut step in features.rows_mut() {
+            for ((f, m), s) in step.iter_mut().zip(&self.mean).zip(&self.std) {
+                *f = (*f - m) / s;
+            }
+        }
+    }
+}
+
+/// Per-sector vol thresholds splitting forward vol into low / normal / high regimes.
+///
+/// Fitted as terciles of each sector's forward vol over the training split and
+/// persisted with the checkpoint so inference classifies against the same cut-offs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegimeThresholds {
+    /// Upper bound of the low regime, per sector
+    pub low: Vec<f64>,
+    /// Lower bound of the high regime, per sector
+    pub high: Vec<f64>,
+}
+
+impl RegimeThresholds {
+    pub fn fit(samples: &[VolSample]) -> Self {
+        let n_sectors = samples.first().map(|s| s.target_sector_vols.len()).unwrap_or(0);
+        let mut low = Vec::with_capacity(n_sectors);
+        let mut high = Vec::with_capacity(n_sectors);
+        for i in 0..n_sectors {
+            let mut vols: Vec<f64> = samples
+                .iter()
+                .filter_map(|s| s.target_sector_vols.get(i).copied())
+                .filter(|v| v.is_finite())
+                .collect();
+            vols.sort_by(|a, b| a.total_cmp(b));
+            let quantile = |q: f64| {
+                if vols.is_empty() {
+                    0.0
+                } else {
+                    vols[((vols.len() - 1) as f64 * q).round() as usize]
+                }
+            };
+            low.push(quantile(1.0 / 3.0));
+            high.push(quantile(2.0 / 3.0));
+        }
+        Self { low, high }
+    }
+
+    /// Regime class for a sector's vol: 0 = low, 1 = normal, 2 = high.
+    pub fn classify(&self, sector: usize, vol: f64) -> usize {
+        let low = self.low.get(sector).copied().unwrap_or(0.0);
+        let high = self.high.get(sector).copied().unwrap_or(0.0);
+        if vol <= low {
+            0
+        } else if vol < high {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+/// Names of the per-time-step input features, in the order `build_dataset` emits them
+/// for the enabled feature groups. Saved with the model so a checkpoint records exactly
+/// which inputs it expects.
+pub fn feature_schema(flags: &NnFeatureFlags) -> Vec<String> {
+    let sectors = || config::sector_etfs().iter().map(|s| s.symbol.as_str());
+    let mut names = Vec::with_capacity(crate::nn::model::num_features(flags));
+    if flags.sector_volatility {
+        names.extend(sectors().map(|s| format!("{}_vol", s)));
+    }
+    names.extend(sectors().map(|s| format!("{}_return", s)));
+    names.extend(["avg_correlation", "spread_10y_2y", "curve_slope", "vix_proxy"].map(String::from));
+    if flags.market_randomness {
+        names.extend(sectors().flat_map(|s| [format!("{}_entropy", s), format!("{}_hurst", s)]));
+    }
+    if flags.kurtosis {
+        names.extend(sectors().flat_map(|s| [format!("{}_kurtosis", s), format!("{}_skew", s)]));
+    }
+    for indicator in &flags.custom_indicators {
+        names.extend(sectors().map(|s| format!("{}_custom_{}", s, indicator.name)));
+    }
+    names
+}
+
+/// Build a dataset from market data by engineering features and creating sliding windows.
+/// Only the feature groups enabled in `flags` are included (see `feature_schema`).
 pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags: &NnFeatureFlags) -> VolDataset {
     // Compute log returns for each sector
     let sector_returns: Vec<Vec<f64>> = data.sectors.iter().map(|s| s.log_returns()).collect();
     let n_sectors = sector_returns.len();
 
     if n_sectors == 0 {
-        return VolDataset { samples: vec![] };
+        return VolDataset::default();
     }
 
     // Align all to same length (shortest)
     let min_len = sector_returns.iter().map(|r| r.len()).min().unwrap_or(0);
     if min_len < lookback + forward + config::LONG_VOL_WINDOW {
-        return VolDataset { samples: vec![] };
+        return VolDataset::default();
     }
 
-    let aligned_returns: Vec<Vec<f64>> = sector_returns
+    let aligned_returns: Vec<&[f64]> = sector_returns
         .iter()
-        .map(|r| r[r.len() - min_len..].to_vec())
+        .map(|r| &r[r.len() - min_len..])
         .collect();
 
     // Compute rolling volatilities for each sector
@@ -65,35 +257,37 @@ pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags:
 
     let vol_len = sector_vols.iter().map(|v| v.len()).min().unwrap_or(0);
     if vol_len < lookback + forward {
-        return VolDataset { samples: vec![] };
+        return VolDataset::default();
     }
 
+    // Trading date of each aligned step; all series end on the last bar
+    let all_dates = data.sectors[0].dates();
+    let step_dates = &all_dates[all_dates.len() - vol_len..];
+
     // Compute bond spreads
     let bond_spreads = analysis::bond_spreads::compute_term_spreads(&data.treasury_rates);
 
     // Compute cross-sector correlation (over entire period as a scalar)
     let symbols: Vec<String> = data.sectors.iter().map(|s| s.symbol.clone()).collect();
-    let returns_for_corr: Vec<Vec<f64>> = aligned_returns.clone();
-    let corr_matrix =
-        analysis::cross_sector::compute_correlation_matrix(&symbols, &returns_for_corr);
+    let corr_matrix = analysis::cross_sector::compute_correlation_matrix(&symbols, &aligned_returns);
     let avg_corr = analysis::cross_sector::average_cross_correlation(&corr_matrix);
 
-    // Benchmark (SPY) vol as VIX proxy
+    // Benchmark vol as VIX proxy
     let bench_vol = data.benchmark.as_ref().map(|b| {
         let ret = b.log_returns();
         analysis::volatility::rolling_volatility(&ret, config::SHORT_VOL_WINDOW)
     });
 
     // Align everything to vol_len
-    let aligned_vols: Vec<Vec<f64>> = sector_vols
+    let aligned_vols: Vec<&[f64]> = sector_vols
         .iter()
-        .map(|v| v[v.len() - vol_len..].to_vec())
+        .map(|v| &v[v.len() - vol_len..])
         .collect();
 
     // Trim returns to match vol length (vol starts SHORT_VOL_WINDOW into returns)
-    let aligned_rets: Vec<Vec<f64>> = aligned_returns
+    let aligned_rets: Vec<&[f64]> = aligned_returns
         .iter()
-        .map(|r| r[r.len() - vol_len..].to_vec())
+        .map(|r| &r[r.len() - vol_len..])
         .collect();
 
     // Rolling randomness per sector (entropy, hurst) - 2 values per sector, window 21
@@ -132,6 +326,34 @@ pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags:
         vec![0.0; vol_len]
     };
 
+    // Custom indicators per sector, right-aligned like the bars they were computed
+    // from; a failing script contributes zeros
+    let custom_vals: Vec<Vec<Vec<f64>>> = flags
+        .custom_indicators
+        .iter()
+        .map(|indicator| {
+            data.sectors
+                .iter()
+                .map(|sector| {
+                    let values = analysis::custom_indicators::evaluate(
+                        &indicator.script,
+                        sector,
+                        config::SHORT_VOL_WINDOW,
+                        config::LONG_VOL_WINDOW,
+                    )
+                    .unwrap_or_else(|e| {
+                        tracing::warn!("Custom indicator '{}' failed for {}: {}", indicator.name, sector.symbol, e);
+                        Vec::new()
+                    });
+                    // Value for aligned step t sits `vol_len - t` from the end, like the returns
+                    (0..vol_len)
+                        .map(|t| (values.len() + t).checked_sub(vol_len).and_then(|i| values.get(i)).copied().unwrap_or(0.0))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
     let slope_vals: Vec<f64> = if bond_spreads.len() >= vol_len {
         bond_spreads[..vol_len]
             .iter()
@@ -142,103 +364,100 @@ pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags:
         vec![0.0; vol_len]
     };
 
-    // Build sliding windows
-    let mut samples = Vec::new();
+    // Sliding windows need `forward` steps after them for the targets
+    let n_features = crate::nn::model::num_features(flags);
     let effective_len = vol_len.saturating_sub(forward);
     if effective_len <= lookback {
-        return VolDataset { samples: vec![] };
+        return VolDataset::default();
     }
+    let n_windows = effective_len - lookback;
+    let n_steps = n_windows - 1 + lookback;
+
+    // Feature panel: one row per step any window covers, row-major
+    let mut panel = Vec::with_capacity(n_steps * n_features);
+    for t in 0..n_steps {
+        // 11 sector volatilities (enabled by flag)
+        if flags.sector_volatility {
+            for sv in &aligned_vols {
+                panel.push(sv.get(t).copied().unwrap_or(0.0));
+            }
+            panel.extend(std::iter::repeat_n(0.0, 11usize.saturating_sub(n_sectors)));
+        }
 
-    for start in 0..(effective_len - lookback) {
-        let end = start + lookback;
-
-        // Build feature matrix for this window
-        let mut window_features = Vec::with_capacity(lookback);
-        for t in start..end {
-            let mut features = Vec::with_capacity(crate::nn::model::NUM_FEATURES);
-
-            // 11 sector volatilities (enabled by flag)
-            if flags.sector_volatility {
-                for sv in &aligned_vols {
-                    features.push(sv.get(t).copied().unwrap_or(0.0));
-                }
+        // 11 sector returns (always included, base features)
+        for sr in &aligned_rets {
+            panel.push(sr.get(t).copied().unwrap_or(0.0));
+        }
+        panel.extend(std::iter::repeat_n(0.0, 11usize.saturating_sub(n_sectors)));
+
+        // Average cross-sector correlation (base feature)
+        panel.push(avg_corr);
+
+        // Bond spread (10Y-2Y) (base feature)
+        panel.push(spread_vals.get(t).copied().unwrap_or(0.0));
+
+        // Curve slope (base feature)
+        panel.push(slope_vals.get(t).copied().unwrap_or(0.0));
+
+        // VIX proxy (benchmark vol) (base feature)
+        panel.push(
+            bench_v
+                .as_ref()
+                .and_then(|bv| bv.get(t).copied())
+                .unwrap_or(0.0),
+        );
+
+        // Randomness: entropy, hurst per sector (2 × 11 = 22) (enabled by flag)
+        if flags.market_randomness {
+            let rr_len = sector_randomness.first().map(|v| v.len()).unwrap_or(0);
+            let rr_idx = if t >= randomness_window - 1 && rr_len > 0 {
+                (t - (randomness_window - 1)).min(rr_len - 1)
             } else {
-                for _ in 0..11 {
-                    features.push(0.0);
+                0
+            };
+            for sr in &sector_randomness {
+                if let Some(&(entropy, hurst, _ac1, _ac5)) = sr.get(rr_idx) {
+                    panel.push(entropy);
+                    panel.push(hurst);
+                } else {
+                    panel.push(0.0);
+                    panel.push(0.0);
                 }
             }
+            for _ in n_sectors..11 {
+                panel.push(0.0);
+                panel.push(0.0);
+            }
+        }
 
-            // 11 sector returns (always included, base features)
-            for sr in &aligned_rets {
-                features.push(sr.get(t).copied().unwrap_or(0.0));
+        // Kurtosis: rolling_kurtosis, rolling_skewness per sector (2 × 11 = 22) (enabled by flag)
+        if flags.kurtosis {
+            let k_idx = t.saturating_sub(config::LONG_VOL_WINDOW - 1);
+            for (rk, rs) in sector_rolling_kurt.iter().zip(sector_rolling_skew.iter()) {
+                panel.push(rk.get(k_idx).copied().unwrap_or(0.0));
+                panel.push(rs.get(k_idx).copied().unwrap_or(0.0));
             }
             for _ in n_sectors..11 {
-                features.push(0.0);
+                panel.push(0.0);
+                panel.push(0.0);
             }
+        }
 
-            // Average cross-sector correlation (base feature)
-            features.push(avg_corr);
-
-            // Bond spread (10Y-2Y) (base feature)
-            features.push(spread_vals.get(t).copied().unwrap_or(0.0));
-
-            // Curve slope (base feature)
-            features.push(slope_vals.get(t).copied().unwrap_or(0.0));
-
-            // VIX proxy (benchmark vol) (base feature)
-            features.push(
-                bench_v
-                    .as_ref()
-                    .and_then(|bv| bv.get(t).copied())
-                    .unwrap_or(0.0),
-            );
-
-            // Randomness: entropy, hurst per sector (2 × 11 = 22) (enabled by flag)
-            if flags.market_randomness {
-                let rr_len = sector_randomness.first().map(|v| v.len()).unwrap_or(0);
-                let rr_idx = if t >= randomness_window - 1 && rr_len > 0 {
-                    (t - (randomness_window - 1)).min(rr_len - 1)
-                } else {
-                    0
-                };
-                for sr in &sector_randomness {
-                    if let Some(&(entropy, hurst, _ac1, _ac5)) = sr.get(rr_idx) {
-                        features.push(entropy);
-                        features.push(hurst);
-                    } else {
-                        features.push(0.0);
-                        features.push(0.0);
-                    }
-                }
-                for _ in n_sectors..11 {
-                    features.push(0.0);
-                    features.push(0.0);
-                }
-            } else {
-                for _ in 0..(11 * 2) {
-                    features.push(0.0);
-                }
+        // Custom indicators: one value per sector each (enabled per indicator)
+        for indicator in &custom_vals {
+            for sector in indicator {
+                panel.push(sector.get(t).copied().unwrap_or(0.0));
             }
+            panel.resize(panel.len() + CUSTOM_INDICATOR_FEATURES.saturating_sub(n_sectors), 0.0);
+        }
 
-            // Kurtosis: rolling_kurtosis, rolling_skewness per sector (2 × 11 = 22) (enabled by flag)
-            if flags.kurtosis {
-                let k_idx = t.saturating_sub(config::LONG_VOL_WINDOW - 1);
-                for (rk, rs) in sector_rolling_kurt.iter().zip(sector_rolling_skew.iter()) {
-                    features.push(rk.get(k_idx).copied().unwrap_or(0.0));
-                    features.push(rs.get(k_idx).copied().unwrap_or(0.0));
-                }
-                for _ in n_sectors..11 {
-                    features.push(0.0);
-                    features.push(0.0);
-                }
-            } else {
-                for _ in 0..(11 * 2) {
-                    features.push(0.0);
-                }
-            }
+        debug_assert_eq!(panel.len(), (t + 1) * n_features);
+    }
+    let panel = Array2::from_shape_vec((n_steps, n_features), panel).expect("one row of features per step");
 
-            window_features.push(features);
-        }
+    let mut samples = Vec::with_capacity(n_windows);
+    for start in 0..n_windows {
+        let end = start + lookback;
 
         // Target vol: average realized vol over [end, end+forward)
         let target_start = end;
@@ -259,6 +478,38 @@ pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags:
             0.0
         };
 
+        // Per-sector forward vol (used by regime classification targets)
+        let mut target_sector_vols = Vec::with_capacity(11);
+        for i in 0..11 {
+            let fwd: Vec<f64> = aligned_vols
+                .get(i)
+                .map(|sv| (target_start..target_end).filter_map(|tt| sv.get(tt).copied()).collect())
+                .unwrap_or_default();
+            target_sector_vols.push(if fwd.is_empty() {
+                0.0
+            } else {
+                fwd.iter().sum::<f64>() / fwd.len() as f64
+            });
+        }
+
+        // Current vol: average sector vol on the last day of the window
+        let current_vals: Vec<f64> = aligned_vols.iter().filter_map(|sv| sv.get(end - 1).copied()).collect();
+        let current_vol = if current_vals.is_empty() {
+            0.0
+        } else {
+            current_vals.iter().sum::<f64>() / current_vals.len() as f64
+        };
+        let current_sector_vols: Vec<f64> = (0..11)
+            .map(|i| aligned_vols.get(i).and_then(|sv| sv.get(end - 1).copied()).unwrap_or(0.0))
+            .collect();
+
+        // Benchmark forward and current vol (alternative regression targets)
+        let (target_benchmark_vol, current_benchmark_vol) = bench_v.as_ref().map_or((0.0, 0.0), |bv| {
+            let fwd: Vec<f64> = (target_start..target_end).filter_map(|tt| bv.get(tt).copied()).collect();
+            let mean = if fwd.is_empty() { 0.0 } else { fwd.iter().sum::<f64>() / fwd.len() as f64 };
+            (mean, bv.get(end - 1).copied().unwrap_or(0.0))
+        });
+
         // Target randomness: 5-day forward entropy per sector (11 values)
         let rr_len = sector_randomness.first().map(|v| v.len()).unwrap_or(0);
         let fwd_rr_idx = if end + forward >= randomness_window && rr_len > 0 {
@@ -293,25 +544,138 @@ pub fn build_dataset(data: &MarketData, lookback: usize, forward: usize, flags:
         }
 
         samples.push(VolSample {
-            features: window_features,
+            rows: start..end,
             target_vol,
+            current_vol,
+            target_sector_vols,
+            current_sector_vols,
+            target_benchmark_vol,
+            current_benchmark_vol,
             target_randomness,
             target_kurtosis,
         });
     }
 
-    VolDataset { samples }
+    VolDataset { panel: Arc::new(panel), dates: step_dates[..n_steps].to_vec(), samples }
+}
+
+/// Forward vol level of the series `target` forecasts
+pub fn target_level(sample: &VolSample, target: NnVolTarget) -> f64 {
+    match target {
+        NnVolTarget::Sector(i) => sample.target_sector_vols.get(i).copied().unwrap_or(0.0),
+        NnVolTarget::Benchmark => sample.target_benchmark_vol,
+        NnVolTarget::SectorAverage | NnVolTarget::VolChange | NnVolTarget::LogVol => sample.target_vol,
+    }
+}
+
+/// Vol level of the series `target` forecasts on the last day of the input window
+pub fn reference_vol(sample: &VolSample, target: NnVolTarget) -> f64 {
+    match target {
+        NnVolTarget::Sector(i) => sample.current_sector_vols.get(i).copied().unwrap_or(0.0),
+        NnVolTarget::Benchmark => sample.current_benchmark_vol,
+        NnVolTarget::SectorAverage | NnVolTarget::VolChange | NnVolTarget::LogVol => sample.current_vol,
+    }
+}
+
+/// Regression target for the model's primary output, in the space it is trained on
+pub fn target_value(sample: &VolSample, target: NnVolTarget) -> f64 {
+    let level = target_level(sample, target);
+    match target {
+        NnVolTarget::VolChange => level - sample.current_vol,
+        NnVolTarget::LogVol => level.max(config::NN_LOG_VOL_FLOOR).ln(),
+        _ => level,
+    }
+}
+
+/// Convert the model's primary output back to a forward vol level, given the
+/// target series' vol at the end of the input window.
+pub fn output_to_vol(output: f64, reference: f64, target: NnVolTarget) -> f64 {
+    match target {
+        NnVolTarget::VolChange => reference + output,
+        NnVolTarget::LogVol => output.exp(),
+        _ => output,
+    }
+}
+
+/// Randomly perturb a (standardized) training window `[seq_len, num_features]` in place:
+/// window warping, then feature dropout, then Gaussian jitter.
+pub fn augment(mut window: ArrayViewMut2<f64>, aug: &NnAugmentation, rng: &mut impl Rng) {
+    let (seq_len, n_features) = window.dim();
+    if seq_len < 2 || n_features == 0 {
+        return;
+    }
+
+    // Window warping: resample at a random speed, anchored on the latest day so the
+    // most recent observation is never moved
+    if aug.warp_strength > 0.0 {
+        let speed = 1.0 + rng.gen_range(-aug.warp_strength..=aug.warp_strength);
+        let last = (seq_len - 1) as f64;
+        let original = window.to_owned();
+        for (t, mut row) in window.rows_mut().into_iter().enumerate() {
+            let src = (last - (last - t as f64) * speed).clamp(0.0, last);
+            let (lo, frac) = (src.floor() as usize, src.fract());
+            let hi = (lo + 1).min(seq_len - 1);
+            for (f, v) in row.iter_mut().enumerate() {
+                *v = original[[lo, f]] * (1.0 - frac) + original[[hi, f]] * frac;
+            }
+        }
+    }
+
+    // Feature dropout: zero (= training mean) a feature for the whole window
+    if aug.feature_dropout > 0.0 {
+        for f in 0..n_features {
+            if rng.gen_bool(aug.feature_dropout.clamp(0.0, 1.0)) {
+                window.column_mut(f).fill(0.0);
+            }
+        }
+    }
+
+    if aug.jitter_std > 0.0 {
+        for v in window.iter_mut() {
+            // Box-Muller standard normal
+            let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+            let u2: f64 = rng.gen();
+            *v += aug.jitter_std * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        }
+    }
 }
 
-/// Batcher that converts VolSample slices into tensors for training
+/// Batcher that converts VolSample slices into tensors for training, copying each
+/// window straight from the dataset's feature panel
 #[derive(Clone, Debug)]
 pub struct VolBatcher<B: Backend> {
     device: B::Device,
+    panel: Arc<Array2<f64>>,
+    /// When set, targets are one-hot regime classes per sector instead of regression values
+    regimes: Option<RegimeThresholds>,
+    /// Series the primary regression output is trained on
+    target: NnVolTarget,
+    /// Training-time perturbations (training batcher only)
+    augmentation: Option<NnAugmentation>,
 }
 
 impl<B: Backend> VolBatcher<B> {
-    pub fn new(device: B::Device) -> Self {
-        Self { device }
+    /// Batch windows of `panel` (`VolDataset::panel`)
+    pub fn new(device: B::Device, panel: Arc<Array2<f64>>) -> Self {
+        Self { device, panel, regimes: None, target: NnVolTarget::default(), augmentation: None }
+    }
+
+    /// Perturb each window as it is batched (training data only).
+    pub fn with_augmentation(mut self, augmentation: NnAugmentation) -> Self {
+        self.augmentation = augmentation.is_enabled().then_some(augmentation);
+        self
+    }
+
+    /// Train the primary regression output on `target` instead of the sector average.
+    pub fn with_target(mut self, target: NnVolTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Emit one-hot [low, normal, high] regime targets per sector (regime classification mode).
+    pub fn with_regimes(mut self, regimes: RegimeThresholds) -> Self {
+        self.regimes = Some(regimes);
+        self
     }
 }
 
@@ -319,30 +683,45 @@ impl<B: Backend> VolBatcher<B> {
 #[derive(Debug, Clone)]
 pub struct VolBatch<B: Backend> {
     pub inputs: Tensor<B, 3>,  // [batch_size, seq_length, num_features]
-    pub targets: Tensor<B, 2>, // [batch_size, OUTPUT_SIZE]
+    pub targets: Tensor<B, 2>, // [batch_size, OUTPUT_SIZE] or [batch_size, REGIME_OUTPUT_SIZE]
 }
 
 impl<B: Backend> Batcher<VolSample, VolBatch<B>> for VolBatcher<B> {
     fn batch(&self, items: Vec<VolSample>) -> VolBatch<B> {
         let batch_size = items.len();
-        let seq_len = items.first().map(|s| s.features.len()).unwrap_or(0);
-        let num_features = items
-            .first()
-            .and_then(|s| s.features.first().map(|f| f.len()))
-            .unwrap_or(0);
-        let output_size = crate::nn::model::OUTPUT_SIZE;
+        let seq_len = items.first().map_or(0, |s| s.rows.len());
+        let num_features = self.panel.ncols();
+        let output_size = match self.regimes {
+            Some(_) => crate::nn::model::REGIME_OUTPUT_SIZE,
+            None => crate::nn::model::OUTPUT_SIZE,
+        };
 
         // Flatten features into a single vec for tensor creation
         let mut input_data = Vec::with_capacity(batch_size * seq_len * num_features);
         let mut target_data = Vec::with_capacity(batch_size * output_size);
 
+        let mut rng = rand::thread_rng();
         for sample in &items {
-            for step in &sample.features {
-                for &f in step {
-                    input_data.push(f as f32);
+            let window = self.panel.slice(s![sample.rows.clone(), ..]);
+            match self.augmentation {
+                // Augmentation perturbs a copy; the shared panel is never modified
+                Some(ref aug) => {
+                    let mut window = window.to_owned();
+                    augment(window.view_mut(), aug, &mut rng);
+                    input_data.extend(window.iter().map(|&f| f as f32));
+                }
+                None => input_data.extend(window.iter().map(|&f| f as f32)),
+            }
+            if let Some(ref regimes) = self.regimes {
+                for (i, &v) in sample.target_sector_vols.iter().enumerate() {
+                    let class = regimes.classify(i, v);
+                    for c in 0..crate::nn::model::NUM_REGIMES {
+                        target_data.push(if c == class { 1.0 } else { 0.0 });
+                    }
                 }
+                continue;
             }
-            target_data.push(sample.target_vol as f32);
+            target_data.push(target_value(sample, self.target) as f32);
             for &v in &sample.target_randomness {
                 target_data.push(v as f32);
             }
@@ -360,3 +739,121 @@ impl<B: Backend> Batcher<VolSample, VolBatch<B>> for VolBatcher<B> {
         VolBatch { inputs, targets }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn sample(rows: Range<usize>) -> VolSample {
+        VolSample {
+            rows,
+            target_vol: 0.0,
+            current_vol: 0.0,
+            target_sector_vols: vec![],
+            current_sector_vols: vec![],
+            target_benchmark_vol: 0.0,
+            current_benchmark_vol: 0.0,
+            target_randomness: vec![],
+            target_kurtosis: vec![],
+        }
+    }
+
+    #[test]
+    fn test_feature_stats_standardizes() {
+        let mut panel = array![[1.0, 0.001], [3.0, 0.003], [5.0, 0.005], [7.0, 0.007]];
+        let stats = FeatureStats::fit(panel.view());
+        assert!((stats.mean[0] - 4.0).abs() < 1e-10);
+        assert!((stats.mean[1] - 0.004).abs() < 1e-10);
+
+        stats.apply(panel.view_mut());
+        let col0: Vec<f64> = panel.column(0).to_vec();
+        let mean = col0.iter().sum::<f64>() / col0.len() as f64;
+        let var = col0.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / col0.len() as f64;
+        assert!(mean.abs() < 1e-10);
+        assert!((var - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_regime_thresholds_terciles() {
+        let samples: Vec<VolSample> = (1..=9)
+            .map(|i| VolSample {
+                target_sector_vols: vec![i as f64 * 0.1],
+                current_sector_vols: vec![],
+                target_benchmark_vol: 0.0,
+                current_benchmark_vol: 0.0,
+                ..sample(0..0)
+            })
+            .collect();
+        let regimes = RegimeThresholds::fit(&samples);
+        assert_eq!(regimes.classify(0, 0.1), 0);
+        assert_eq!(regimes.classify(0, 0.5), 1);
+        assert_eq!(regimes.classify(0, 0.9), 2);
+    }
+
+    #[test]
+    fn test_feature_stats_constant_feature() {
+        let mut panel = array![[0.0, 2.0], [0.0, 4.0]];
+        let stats = FeatureStats::fit(panel.view());
+        assert_eq!(stats.std[0], 1.0);
+        stats.apply(panel.view_mut());
+        assert!(panel.column(0).iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_feature_schema_matches_enabled_groups() {
+        let all = NnFeatureFlags::default();
+        assert_eq!(feature_schema(&all).len(), crate::nn::model::NUM_FEATURES);
+
+        let base_only = NnFeatureFlags { sector_volatility: false, market_randomness: false, kurtosis: false, ..NnFeatureFlags::default() };
+        let schema = feature_schema(&base_only);
+        assert_eq!(schema.len(), crate::nn::model::num_features(&base_only));
+        assert_eq!(schema.first().map(String::as_str), Some("XLK_return"));
+        assert!(!schema.iter().any(|n| n.ends_with("_vol") || n.ends_with("_hurst")));
+    }
+
+    #[test]
+    fn test_vol_targets_round_trip_to_levels() {
+        let mut s = sample(0..1);
+        s.target_vol = 0.25;
+        s.current_vol = 0.20;
+        s.target_sector_vols = vec![0.30, 0.10];
+        s.current_sector_vols = vec![0.28, 0.12];
+        for target in [NnVolTarget::SectorAverage, NnVolTarget::Sector(1), NnVolTarget::VolChange, NnVolTarget::LogVol] {
+            let back = output_to_vol(target_value(&s, target), reference_vol(&s, target), target);
+            assert!((back - target_level(&s, target)).abs() < 1e-12, "{:?}", target);
+        }
+        assert!((target_value(&s, NnVolTarget::VolChange) - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_augment_disabled_and_full_dropout() {
+        let rows = Array2::from_shape_fn((5, 2), |(t, f)| if f == 0 { t as f64 } else { 1.0 });
+        let mut rng = rand::thread_rng();
+
+        let mut w = rows.clone();
+        augment(w.view_mut(), &NnAugmentation::default(), &mut rng);
+        assert_eq!(w, rows);
+
+        let mut w = rows.clone();
+        augment(w.view_mut(), &NnAugmentation { warp_strength: 0.5, ..Default::default() }, &mut rng);
+        assert_eq!(w.row(4), rows.row(4));
+
+        let mut w = rows;
+        augment(w.view_mut(), &NnAugmentation { feature_dropout: 1.0, ..Default::default() }, &mut rng);
+        assert!(w.iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_batcher_copies_overlapping_windows() {
+        let panel = Arc::new(Array2::from_shape_fn((4, 2), |(t, f)| (10 * t + f) as f64));
+        let batcher = VolBatcher::<burn::backend::NdArray>::new(Default::default(), panel);
+        let with_targets =
+            |rows| VolSample { target_randomness: vec![0.0; 11], target_kurtosis: vec![0.0; 22], ..sample(rows) };
+        let batch = batcher.batch(vec![with_targets(0..3), with_targets(1..4)]);
+        assert_eq!(batch.inputs.dims(), [2, 3, 2]);
+        let inputs = batch.inputs.into_data().to_vec::<f32>().unwrap();
+        assert_eq!(inputs[..6], [0.0, 1.0, 10.0, 11.0, 20.0, 21.0]);
+        assert_eq!(inputs[6..], [10.0, 11.0, 20.0, 21.0, 30.0, 31.0]);
+    }
+}
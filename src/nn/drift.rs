@@ -0,0 +1,208 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis;
+use crate::config;
+use crate::data::cache;
+use crate::data::models::{MarketData, NnDriftSettings, NnPredictions, NnVolTarget, SectorTimeSeries};
+
+const LOG_FILENAME: &str = "nn_prediction_log.json";
+
+/// Oldest entries are dropped beyond this many to keep the log file small
+pub const MAX_LOG_ENTRIES: usize = 1000;
+
+/// A vol forecast made from live data, scored once its forward window has been observed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LivePrediction {
+    /// Last trading day of the input window the forecast was made from
+    pub made_on: NaiveDate,
+    /// `trained_at` of the model that made it
+    pub trained_at: String,
+    pub target: NnVolTarget,
+    pub predicted: f64,
+    /// Realized forward vol, filled in once `NN_FORWARD_DAYS` more days are available
+    pub realized: Option<f64>,
+}
+
+impl LivePrediction {
+    /// Absolute percentage error of a resolved prediction
+    pub fn abs_pct_error(&self) -> Option<f64> {
+        let realized = self.realized.filter(|r| *r > 0.0)?;
+        Some((self.predicted - realized).abs() / realized * 100.0)
+    }
+}
+
+/// Rolling live error of one model's resolved predictions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DriftStatus {
+    pub logged: usize,
+    pub resolved: usize,
+    /// Resolved predictions the rolling error covers (at most the configured window)
+    pub window_used: usize,
+    /// Rolling mean absolute percentage error, in percent
+    pub rolling_mape: Option<f64>,
+    pub exceeded: bool,
+}
+
+/// Load the prediction log from the cache (oldest first). Empty if none has been saved.
+pub fn load_log() -> Vec<LivePrediction> {
+    cache::load_json(LOG_FILENAME).unwrap_or_default()
+}
+
+pub fn save_log(log: &[LivePrediction]) -> Result<(), String> {
+    cache::save_json(LOG_FILENAME, &log).map_err(|e| format!("Failed to save prediction log: {}", e))
+}
+
+/// Build a log entry for the vol forecast in `preds`. `None` for regime models, which
+/// have no vol output to score.
+pub fn live_prediction(
+    preds: &NnPredictions,
+    market_data: &MarketData,
+    target: NnVolTarget,
+    trained_at: &str,
+) -> Option<LivePrediction> {
+    let predicted = preds.vol.first().map(|(_, v)| *v).filter(|v| v.is_finite())?;
+    let made_on = market_data.sectors.first()?.bars.last()?.date;
+    Some(LivePrediction {
+        made_on,
+        trained_at: trained_at.to_string(),
+        target,
+        predicted,
+        realized: None,
+    })
+}
+
+/// Add (or replace) the entry for the same day and model, keeping the log sorted by
+/// day and trimmed to `MAX_LOG_ENTRIES`.
+pub fn record(log: &mut Vec<LivePrediction>, entry: LivePrediction) {
+    log.retain(|p| !(p.made_on == entry.made_on && p.trained_at == entry.trained_at));
+    log.push(entry);
+    log.sort_by_key(|p| p.made_on);
+    if log.len() > MAX_LOG_ENTRIES {
+        let excess = log.len() - MAX_LOG_ENTRIES;
+        log.drain(..excess);
+    }
+}
+
+/// Fill in realized vol for pending entries whose forward window is now in `market_data`.
+/// Returns how many entries were resolved.
+pub fn resolve(log: &mut [LivePrediction], market_data: &MarketData) -> usize {
+    let mut resolved = 0;
+    for entry in log.iter_mut().filter(|p| p.realized.is_none()) {
+        entry.realized = realized_vol(market_data, entry.target, entry.made_on, config::NN_FORWARD_DAYS);
+        if entry.realized.is_some() {
+            resolved += 1;
+        }
+    }
+    resolved
+}
+
+/// Rolling error over the most recent resolved predictions of the model trained at `trained_at`.
+pub fn drift_status(log: &[LivePrediction], trained_at: &str, settings: &NnDriftSettings) -> DriftStatus {
+    let own: Vec<&LivePrediction> = log.iter().filter(|p| p.trained_at == trained_at).collect();
+    let errors: Vec<f64> = own.iter().filter_map(|p| p.abs_pct_error()).collect();
+    let recent = &errors[errors.len().saturating_sub(settings.window.max(1))..];
+    let rolling_mape = (!recent.is_empty()).then(|| recent.iter().sum::<f64>() / recent.len() as f64);
+    DriftStatus {
+        logged: own.len(),
+        resolved: errors.len(),
+        window_used: recent.len(),
+        rolling_mape,
+        exceeded: rolling_mape.is_some_and(|m| m > settings.threshold_pct),
+    }
+}
+
+/// Realized forward vol of the series `target` forecasts: the mean of its rolling
+/// `SHORT_VOL_WINDOW` vol over the `forward` days after `made_on`, as in the training
+/// targets. Aggregate targets average across sectors.
+fn realized_vol(market_data: &MarketData, target: NnVolTarget, made_on: NaiveDate, forward: usize) -> Option<f64> {
+    match target {
+        NnVolTarget::Sector(i) => series_forward_vol(market_data.sectors.get(i)?, made_on, forward),
+        NnVolTarget::Benchmark => series_forward_vol(market_data.benchmark.as_ref()?, made_on, forward),
+        NnVolTarget::SectorAverage | NnVolTarget::VolChange | NnVolTarget::LogVol => {
+            let vols: Vec<f64> = market_data
+                .sectors
+                .iter()
+                .take(11)
+                .map(|s| series_forward_vol(s, made_on, forward))
+                .collect::<Option<_>>()?;
+            (!vols.is_empty()).then(|| vols.iter().sum::<f64>() / vols.len() as f64)
+        }
+    }
+}
+
+fn series_forward_vol(series: &SectorTimeSeries, made_on: NaiveDate, forward: usize) -> Option<f64> {
+    let day = series.bars.iter().position(|b| b.date == made_on)?;
+    let vols = analysis::volatility::rolling_volatility(&series.log_returns(), config::SHORT_VOL_WINDOW);
+    // vols[k] covers returns k..k+W, the last of which closes on bar k+W
+    let k = day.checked_sub(config::SHORT_VOL_WINDOW)?;
+    let window = vols.get(k + 1..k + 1 + forward)?;
+    Some(window.iter().sum::<f64>() / forward as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::models::OhlcvBar;
+
+    fn entry(day: u32, trained_at: &str, predicted: f64, realized: Option<f64>) -> LivePrediction {
+        LivePrediction {
+            made_on: NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+            trained_at: trained_at.to_string(),
+            target: NnVolTarget::SectorAverage,
+            predicted,
+            realized,
+        }
+    }
+
+    #[test]
+    fn test_drift_status_uses_recent_window_of_current_model() {
+        let settings = NnDriftSettings { window: 2, threshold_pct: 25.0 };
+        let log = vec![
+            entry(1, "a", 0.10, Some(0.20)), // 50% error, outside the window
+            entry(2, "a", 0.22, Some(0.20)), // 10%
+            entry(3, "b", 0.50, Some(0.20)), // other model
+            entry(4, "a", 0.26, Some(0.20)), // 30%
+            entry(5, "a", 0.30, None),
+        ];
+        let status = drift_status(&log, "a", &settings);
+        assert_eq!((status.logged, status.resolved, status.window_used), (4, 3, 2));
+        assert!((status.rolling_mape.unwrap() - 20.0).abs() < 1e-9);
+        assert!(!status.exceeded);
+        assert!(drift_status(&log, "a", &NnDriftSettings { window: 3, ..settings }).exceeded);
+        assert_eq!(drift_status(&log, "c", &settings).rolling_mape, None);
+    }
+
+    #[test]
+    fn test_record_replaces_same_day_and_resolve_waits_for_forward_window() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let bars: Vec<OhlcvBar> = (0..40)
+            .map(|i| {
+                let close = 100.0 * (1.0 + 0.01 * ((i as f64) * 1.3).sin());
+                OhlcvBar {
+                    date: start + chrono::Days::new(i),
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    volume: 0,
+                }
+            })
+            .collect();
+        let series = SectorTimeSeries { symbol: "XLK".into(), name: "Technology".into(), bars };
+        let market_data = MarketData { sectors: vec![series], ..MarketData::default() };
+
+        let mut log = Vec::new();
+        let day = |i: u64| start + chrono::Days::new(i);
+        record(&mut log, LivePrediction { made_on: day(30), ..entry(1, "a", 0.2, None) });
+        record(&mut log, LivePrediction { made_on: day(35), ..entry(1, "a", 0.2, None) });
+        record(&mut log, LivePrediction { made_on: day(30), ..entry(1, "a", 0.3, None) });
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].predicted, 0.3);
+
+        // The last bar is day 39, so day 35's five-day forward window isn't complete yet
+        assert_eq!(resolve(&mut log, &market_data), 1);
+        assert!(log[0].realized.is_some_and(|r| r > 0.0));
+        assert_eq!(log[1].realized, None);
+    }
+}
@@ -1,6 +1,8 @@
 use std::process::Command;
 
-use crate::data::models::GpuAdapterInfo;
+use burn::backend::wgpu::WgpuDevice;
+
+use crate::data::models::{GpuAdapterInfo, NnTrainingConfig};
 
 /// GPU information collected via nvidia-smi or rocm-smi/amd-smi
 #[derive(Debug, Clone)]
@@ -38,10 +40,62 @@ pub fn detect_wgpu_adapters() -> Vec<GpuAdapterInfo> {
     adapters
 }
 
+/// Graphics API burn's WGPU runtime binds to by default (Metal on macOS, Vulkan elsewhere)
+fn native_wgpu_backend() -> wgpu::Backends {
+    if cfg!(target_os = "macos") {
+        wgpu::Backends::METAL
+    } else {
+        wgpu::Backends::VULKAN
+    }
+}
+
+/// Map an adapter name (as listed by `detect_wgpu_adapters`) to the WGPU device burn binds to.
+///
+/// burn indexes devices per adapter type (discrete, integrated, ...) on its native graphics
+/// API, so the index is counted over that same list. `None` or an adapter that is no longer
+/// present selects the default device.
+pub fn wgpu_device(adapter_name: Option<&str>) -> WgpuDevice {
+    let Some(wanted) = adapter_name else {
+        return WgpuDevice::DefaultDevice;
+    };
+
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: native_wgpu_backend(),
+        ..Default::default()
+    });
+
+    let (mut discrete, mut integrated, mut virtual_gpu) = (0, 0, 0);
+    for adapter in instance.enumerate_adapters(native_wgpu_backend()) {
+        let info = adapter.get_info();
+        let device = match info.device_type {
+            wgpu::DeviceType::DiscreteGpu => {
+                discrete += 1;
+                WgpuDevice::DiscreteGpu(discrete - 1)
+            }
+            wgpu::DeviceType::IntegratedGpu => {
+                integrated += 1;
+                WgpuDevice::IntegratedGpu(integrated - 1)
+            }
+            wgpu::DeviceType::VirtualGpu => {
+                virtual_gpu += 1;
+                WgpuDevice::VirtualGpu(virtual_gpu - 1)
+            }
+            wgpu::DeviceType::Cpu => WgpuDevice::Cpu,
+            wgpu::DeviceType::Other => continue,
+        };
+        if info.name == wanted {
+            return device;
+        }
+    }
+
+    tracing::warn!("GPU adapter '{}' not found; using the default device", wanted);
+    WgpuDevice::DefaultDevice
+}
+
 /// Detect an NVIDIA GPU by querying nvidia-smi.
 /// Returns `Some(GpuInfo)` if an NVIDIA GPU is found, `None` otherwise.
 pub fn detect_nvidia_gpu() -> Option<GpuInfo> {
-    query_nvidia_smi()
+    query_nvidia_smi(None)
 }
 
 /// Detect an AMD GPU by querying rocm-smi (Linux) or amd-smi (Windows).
@@ -59,21 +113,22 @@ pub fn detect_amd_gpu() -> Option<GpuInfo> {
 
 /// Poll live GPU stats (VRAM usage, utilization, temperature).
 /// Prefers NVIDIA (nvidia-smi), then AMD (rocm-smi/amd-smi).
-pub fn poll_gpu_stats() -> Option<GpuInfo> {
-    detect_nvidia_gpu().or_else(detect_amd_gpu)
+/// With an adapter name, picks the matching NVIDIA GPU on multi-GPU machines.
+pub fn poll_gpu_stats(adapter_name: Option<&str>) -> Option<GpuInfo> {
+    query_nvidia_smi(adapter_name).or_else(detect_amd_gpu)
 }
 
 /// Validate that the WGPU GPU backend is usable by running a small tensor computation.
 ///
-/// Performs a 4×4 matrix multiply on the WGPU device to verify allocation, compute,
-/// and readback. Returns the adapter name on success or an error description on failure.
-/// Call this before starting GPU training to gate on a known-good backend.
-pub fn validate_gpu() -> Result<String, String> {
+/// Performs a 4×4 matrix multiply on the selected WGPU device (`None` = default) to verify
+/// allocation, compute, and readback. Returns the adapter name on success or an error
+/// description on failure. Call this before starting GPU training to gate on a known-good backend.
+pub fn validate_gpu(adapter_name: Option<&str>) -> Result<String, String> {
     use burn::backend::Wgpu;
     use burn::tensor::Tensor;
     type B = Wgpu;
 
-    let device = <B as burn::tensor::backend::Backend>::Device::default();
+    let device = wgpu_device(adapter_name);
 
     // 4×4 matmul: ones × ones = all 4.0 -- tests allocation, compute, and readback
     let a = Tensor::<B, 2>::ones([4, 4], &device);
@@ -88,16 +143,43 @@ pub fn validate_gpu() -> Result<String, String> {
         return Err("GPU computation produced incorrect results".into());
     }
 
-    let name = detect_wgpu_adapters()
-        .into_iter()
-        .next()
-        .map(|a| a.name)
-        .unwrap_or_else(|| "Unknown GPU".into());
+    let name = match adapter_name {
+        Some(name) => name.to_string(),
+        None => detect_wgpu_adapters()
+            .into_iter()
+            .next()
+            .map(|a| a.name)
+            .unwrap_or_else(|| "Unknown GPU".into()),
+    };
 
     Ok(name)
 }
 
-fn query_nvidia_smi() -> Option<GpuInfo> {
+/// Check that the selected WGPU device can run half-precision (f16) kernels.
+///
+/// Not every adapter exposes `shader-f16`; the runtime panics when compiling an f16 kernel
+/// on those, so the probe runs under `catch_unwind` and reports an error instead.
+pub fn validate_gpu_f16(adapter_name: Option<&str>) -> Result<(), String> {
+    use burn::backend::Wgpu;
+    use burn::tensor::{f16, Tensor};
+    type B = Wgpu<f16, i32>;
+
+    let device = wgpu_device(adapter_name);
+    let probe = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let a = Tensor::<B, 2>::ones([4, 4], &device);
+        let b = Tensor::<B, 2>::ones([4, 4], &device);
+        a.matmul(b).into_data().to_vec::<f16>()
+    }));
+
+    match probe {
+        Ok(Ok(vals)) if vals.len() == 16 && vals.iter().all(|v| (v.to_f32() - 4.0).abs() < 0.01) => Ok(()),
+        Ok(Ok(_)) => Err("f16 computation produced incorrect results".into()),
+        Ok(Err(e)) => Err(format!("f16 tensor readback failed: {e:?}")),
+        Err(_) => Err("adapter does not support f16 shaders".into()),
+    }
+}
+
+fn query_nvidia_smi(adapter_name: Option<&str>) -> Option<GpuInfo> {
     let output = Command::new("nvidia-smi")
         .args([
             "--query-gpu=name,memory.total,memory.used,utilization.gpu,temperature.gpu",
@@ -111,7 +193,17 @@ fn query_nvidia_smi() -> Option<GpuInfo> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let line = stdout.lines().next()?.trim().to_string();
+    // One line per GPU; prefer the one matching the selected adapter
+    let line = adapter_name
+        .and_then(|wanted| {
+            stdout.lines().find(|l| {
+                let smi_name = l.split(',').next().unwrap_or("").trim();
+                !smi_name.is_empty() && (wanted.contains(smi_name) || smi_name.contains(wanted))
+            })
+        })
+        .or_else(|| stdout.lines().next())?
+        .trim()
+        .to_string();
     let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
 
     if parts.len() < 5 {
@@ -276,6 +368,48 @@ fn query_amd_smi() -> Option<GpuInfo> {
     None::<GpuInfo>
 }
 
+/// Smallest and largest batch sizes `recommend_batch_size` will choose
+const MIN_AUTO_BATCH: usize = 8;
+const MAX_AUTO_BATCH: usize = 1024;
+
+/// Fraction of currently free VRAM the training footprint may occupy
+const VRAM_BUDGET_FRACTION: f64 = 0.5;
+
+/// Pick the largest power-of-two batch size whose estimated LSTM training footprint fits in
+/// half of the currently free VRAM. Returns `None` when no VRAM stats are available.
+///
+/// The estimate counts per-step activations (input, 4 gates pre/post activation, cell and
+/// hidden state) doubled for autodiff, plus weights, gradients, and two Adam moments.
+pub fn recommend_batch_size(
+    info: &GpuInfo,
+    seq_len: usize,
+    input_size: usize,
+    hidden_size: usize,
+    output_size: usize,
+) -> Option<usize> {
+    if info.vram_total_mb == 0 {
+        return None;
+    }
+    let free_bytes = info.vram_total_mb.saturating_sub(info.vram_used_mb) as f64 * 1024.0 * 1024.0;
+
+    let lstm_params = 4 * (input_size * hidden_size + hidden_size * hidden_size + 2 * hidden_size);
+    let linear_params = hidden_size * output_size + output_size;
+    let model_bytes = (lstm_params + linear_params) as f64 * 4.0 * 4.0;
+
+    let per_sample_bytes = (seq_len * (input_size + 10 * hidden_size)) as f64 * 4.0 * 2.0;
+    let budget = free_bytes * VRAM_BUDGET_FRACTION - model_bytes;
+    if budget <= 0.0 || per_sample_bytes <= 0.0 {
+        return Some(MIN_AUTO_BATCH);
+    }
+
+    let fit = (budget / per_sample_bytes) as usize;
+    let mut batch = MIN_AUTO_BATCH;
+    while batch * 2 <= fit && batch * 2 <= MAX_AUTO_BATCH {
+        batch *= 2;
+    }
+    Some(batch)
+}
+
 /// Trait for GPU detection, enabling mock injection in tests.
 #[allow(dead_code)]
 pub trait GpuDetector {
@@ -302,6 +436,143 @@ impl GpuDetector for RealGpuDetector {
     }
 }
 
+/// Matrix size for the matmul throughput benchmark
+const DIAG_MATMUL_SIZE: usize = 2048;
+
+/// How long the sustained-throughput phase keeps the GPU busy
+const DIAG_SUSTAIN_SECS: f64 = 3.0;
+
+/// f32 elements in the bandwidth test buffer (64 MiB)
+const DIAG_BANDWIDTH_ELEMS: usize = 16 * 1024 * 1024;
+
+/// Timed training steps for the epoch-time estimate (after one warm-up step)
+const DIAG_TRAIN_STEPS: usize = 5;
+
+/// Results of the GPU diagnostic suite
+#[derive(Debug, Clone)]
+pub struct GpuDiagnostics {
+    pub adapter: String,
+    /// Best single-iteration f32 matmul throughput
+    pub peak_tflops: f64,
+    /// Mean f32 matmul throughput over `DIAG_SUSTAIN_SECS` of back-to-back work
+    pub sustained_tflops: f64,
+    /// Effective read + write bandwidth of a large elementwise op
+    pub bandwidth_gbs: f64,
+    /// Mean forward + backward + Adam step at the configured model and batch size
+    pub train_step_ms: f64,
+    pub batch_size: usize,
+    /// `train_step_ms` scaled to one pass over the training split, when its size is known
+    pub est_epoch_secs: Option<f64>,
+}
+
+/// Burn-in and benchmark the selected adapter: correctness check (`validate_gpu`), large
+/// matmul peak / sustained TFLOPS, memory bandwidth, and timed training steps of the real
+/// model so users can estimate epoch time before committing to a long run.
+pub fn run_gpu_diagnostics(
+    adapter_name: Option<&str>,
+    input_size: usize,
+    training_config: &NnTrainingConfig,
+    batch_size: usize,
+    train_samples: Option<usize>,
+) -> Result<GpuDiagnostics, String> {
+    use burn::backend::Wgpu;
+    use burn::tensor::backend::Backend;
+    use burn::tensor::Tensor;
+    use std::time::Instant;
+    type B = Wgpu;
+
+    let adapter = validate_gpu(adapter_name)?;
+    let device = wgpu_device(adapter_name);
+
+    // Matmul throughput: one warm-up (kernel compile), then timed iterations
+    let n = DIAG_MATMUL_SIZE;
+    let flops_per_matmul = 2.0 * (n as f64).powi(3);
+    let a = Tensor::<B, 2>::random([n, n], burn::tensor::Distribution::Default, &device);
+    let b = Tensor::<B, 2>::random([n, n], burn::tensor::Distribution::Default, &device);
+    let _ = a.clone().matmul(b.clone());
+    B::sync(&device);
+
+    let mut best_secs = f64::INFINITY;
+    let mut iterations = 0_usize;
+    let sustain_start = Instant::now();
+    while sustain_start.elapsed().as_secs_f64() < DIAG_SUSTAIN_SECS {
+        let t = Instant::now();
+        let _ = a.clone().matmul(b.clone());
+        B::sync(&device);
+        best_secs = best_secs.min(t.elapsed().as_secs_f64());
+        iterations += 1;
+    }
+    let sustained_secs = sustain_start.elapsed().as_secs_f64();
+    let peak_tflops = flops_per_matmul / best_secs / 1e12;
+    let sustained_tflops = flops_per_matmul * iterations as f64 / sustained_secs / 1e12;
+
+    // Bandwidth: x + 1 reads and writes the whole buffer once
+    let x = Tensor::<B, 1>::zeros([DIAG_BANDWIDTH_ELEMS], &device);
+    let _ = x.clone().add_scalar(1.0);
+    B::sync(&device);
+    let reps = 10;
+    let t = Instant::now();
+    for _ in 0..reps {
+        let _ = x.clone().add_scalar(1.0);
+    }
+    B::sync(&device);
+    let bytes = (DIAG_BANDWIDTH_ELEMS * std::mem::size_of::<f32>() * 2 * reps) as f64;
+    let bandwidth_gbs = bytes / t.elapsed().as_secs_f64() / 1e9;
+
+    let train_step_ms = time_training_steps(&device, input_size, training_config, batch_size);
+    let est_epoch_secs = train_samples.map(|n| n.div_ceil(batch_size.max(1)) as f64 * train_step_ms / 1000.0);
+
+    Ok(GpuDiagnostics {
+        adapter,
+        peak_tflops,
+        sustained_tflops,
+        bandwidth_gbs,
+        train_step_ms,
+        batch_size,
+        est_epoch_secs,
+    })
+}
+
+/// Mean wall time (ms) of a full optimizer step of the prediction model on random data.
+fn time_training_steps(
+    device: &WgpuDevice,
+    input_size: usize,
+    training_config: &NnTrainingConfig,
+    batch_size: usize,
+) -> f64 {
+    use burn::backend::{Autodiff, Wgpu};
+    use burn::optim::{AdamConfig, GradientsParams, Optimizer};
+    use burn::tensor::backend::Backend;
+    use burn::tensor::{Distribution, Tensor};
+    type B = Autodiff<Wgpu>;
+
+    let output_size = crate::nn::model::output_size(training_config.target_mode);
+    let mut model = crate::nn::model::VolPredictionModelConfig {
+        input_size,
+        hidden_size: training_config.hidden_size,
+        output_size,
+    }
+    .init::<B>(device);
+    let mut optim = AdamConfig::new().init();
+    let inputs = Tensor::<B, 3>::random([batch_size, training_config.lookback, input_size], Distribution::Default, device);
+    let targets = Tensor::<B, 2>::zeros([batch_size, output_size], device);
+
+    let mut elapsed = 0.0;
+    for step in 0..=DIAG_TRAIN_STEPS {
+        let t = std::time::Instant::now();
+        let diff = model.forward(inputs.clone()) - targets.clone();
+        let loss = (diff.clone() * diff).mean();
+        let grads = GradientsParams::from_grads(loss.backward(), &model);
+        model = optim.step(training_config.learning_rate, model, grads);
+        <Wgpu as Backend>::sync(device);
+        // Step 0 includes kernel compilation
+        if step > 0 {
+            elapsed += t.elapsed().as_secs_f64();
+        }
+    }
+    elapsed / DIAG_TRAIN_STEPS as f64 * 1000.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,7 +609,7 @@ mod tests {
     /// without a real GPU (WGPU will fall back to its software/null adapter).
     #[test]
     fn validate_gpu_does_not_panic() {
-        let result = validate_gpu();
+        let result = validate_gpu(None);
         // We only assert the absence of a panic; Ok vs Err depends on the host GPU.
         match result {
             Ok(name) => {
@@ -350,6 +621,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn recommend_batch_size_scales_with_free_vram() {
+        let mock = MockGpuDetector.amd_stats().unwrap();
+        let roomy = recommend_batch_size(&mock, 60, 70, 64, 34).unwrap();
+        assert!(roomy.is_power_of_two());
+        assert_eq!(roomy, MAX_AUTO_BATCH);
+
+        let nearly_full = GpuInfo { vram_used_mb: mock.vram_total_mb - 64, ..mock.clone() };
+        let tight = recommend_batch_size(&nearly_full, 60, 70, 64, 34).unwrap();
+        assert!(tight >= MIN_AUTO_BATCH && tight < roomy);
+
+        let no_stats = GpuInfo { vram_total_mb: 0, ..mock };
+        assert_eq!(recommend_batch_size(&no_stats, 60, 70, 64, 34), None);
+    }
+
     #[test]
     fn mock_amd_adapter_detected() {
         let mock = MockGpuDetector;
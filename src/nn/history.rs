@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::cache;
+use crate::data::models::{NnFeatureFlags, NnPredictions, NnTrainingConfig};
+
+const HISTORY_FILENAME: &str = "nn_run_history.json";
+
+/// Oldest runs are dropped beyond this many to keep the history file small
+pub const MAX_HISTORY_RUNS: usize = 50;
+
+/// How a recorded training run ended
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RunOutcome {
+    Complete,
+    Stopped,
+    Failed(String),
+}
+
+/// One training session as recorded in the run history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingRun {
+    pub started_at: String,
+    pub finished_at: String,
+    pub config: NnTrainingConfig,
+    pub feature_flags: NnFeatureFlags,
+    pub outcome: RunOutcome,
+    /// Per-epoch losses; non-finite values are stored as `None` (JSON has no NaN)
+    pub train_losses: Vec<Option<f64>>,
+    pub val_losses: Vec<Option<f64>>,
+    pub predictions: NnPredictions,
+}
+
+impl TrainingRun {
+    /// Start a record for a run about to be trained with `config` and `feature_flags`.
+    pub fn start(config: &NnTrainingConfig, feature_flags: &NnFeatureFlags) -> Self {
+        Self {
+            started_at: timestamp(),
+            finished_at: String::new(),
+            config: config.clone(),
+            feature_flags: feature_flags.clone(),
+            outcome: RunOutcome::Stopped,
+            train_losses: vec![],
+            val_losses: vec![],
+            predictions: NnPredictions::default(),
+        }
+    }
+
+    /// Fill in the results once the run has ended.
+    pub fn finish(
+        mut self,
+        outcome: RunOutcome,
+        train_losses: &[f64],
+        val_losses: &[f64],
+        predictions: &NnPredictions,
+    ) -> Self {
+        let finite = |v: &f64| v.is_finite().then_some(*v);
+        self.finished_at = timestamp();
+        self.outcome = outcome;
+        self.train_losses = train_losses.iter().map(finite).collect();
+        self.val_losses = val_losses.iter().map(finite).collect();
+        self.predictions = predictions.clone();
+        self
+    }
+
+    pub fn epochs_completed(&self) -> usize {
+        self.train_losses.len()
+    }
+
+    pub fn final_train_loss(&self) -> f64 {
+        self.train_losses.last().copied().flatten().unwrap_or(f64::NAN)
+    }
+
+    pub fn final_val_loss(&self) -> f64 {
+        self.val_losses.last().copied().flatten().unwrap_or(f64::NAN)
+    }
+
+    /// Loss curve as plot points `[epoch, loss]`, skipping non-finite epochs
+    pub fn curve(losses: &[Option<f64>]) -> Vec<[f64; 2]> {
+        losses
+            .iter()
+            .enumerate()
+            .filter_map(|(i, l)| l.map(|l| [i as f64, l]))
+            .collect()
+    }
+}
+
+/// Load the run history from the cache (oldest first). Empty if none has been saved.
+pub fn load_history() -> Vec<TrainingRun> {
+    cache::load_json(HISTORY_FILENAME).unwrap_or_default()
+}
+
+/// Append a run, trim to `MAX_HISTORY_RUNS`, and write the history back to the cache.
+pub fn append_run(history: &mut Vec<TrainingRun>, run: TrainingRun) -> Result<(), String> {
+    history.push(run);
+    if history.len() > MAX_HISTORY_RUNS {
+        let excess = history.len() - MAX_HISTORY_RUNS;
+        history.drain(..excess);
+    }
+    cache::save_json(HISTORY_FILENAME, history).map_err(|e| format!("Failed to save run history: {}", e))
+}
+
+/// Remove every recorded run.
+pub fn clear_history(history: &mut Vec<TrainingRun>) -> Result<(), String> {
+    history.clear();
+    cache::save_json(HISTORY_FILENAME, history).map_err(|e| format!("Failed to save run history: {}", e))
+}
+
+fn timestamp() -> String {
+    chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish_records_losses() {
+        let run = TrainingRun::start(&NnTrainingConfig::default(), &NnFeatureFlags::default()).finish(
+            RunOutcome::Complete,
+            &[0.5, 0.3],
+            &[0.6, f64::NAN],
+            &NnPredictions::default(),
+        );
+        assert_eq!(run.epochs_completed(), 2);
+        assert_eq!(run.final_train_loss(), 0.3);
+        assert!(run.final_val_loss().is_nan());
+        assert_eq!(TrainingRun::curve(&run.val_losses), vec![[0.0, 0.6]]);
+    }
+}
@@ -0,0 +1,193 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::data::cache;
+
+/// Metrics recorded at the end of each training epoch
+#[derive(Debug, Clone, Copy)]
+pub struct EpochMetrics {
+    pub epoch: usize,
+    pub train_loss: f64,
+    pub val_loss: f64,
+    pub learning_rate: f64,
+    /// Mean global L2 gradient norm over the epoch's batches (before clipping)
+    pub grad_norm: f64,
+}
+
+/// Writes per-epoch metrics to `metrics.csv` and, optionally, a TensorBoard event file
+/// in a per-run directory under `cache/training_runs/`.
+pub struct MetricsWriter {
+    dir: PathBuf,
+    csv: csv::Writer<File>,
+    tensorboard: Option<TensorBoardWriter>,
+}
+
+impl MetricsWriter {
+    /// Create a fresh run directory with `metrics.csv`; `tensorboard` also adds an event file.
+    pub fn create(tensorboard: bool) -> Result<Self, String> {
+        let dir = cache::cache_dir()
+            .map_err(|e| e.to_string())?
+            .join("training_runs")
+            .join(chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+        std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+        let mut csv = csv::Writer::from_path(dir.join("metrics.csv")).map_err(|e| e.to_string())?;
+        csv.write_record(["epoch", "train_loss", "val_loss", "learning_rate", "grad_norm"])
+            .map_err(|e| e.to_string())?;
+
+        let tensorboard = if tensorboard {
+            Some(TensorBoardWriter::create(&dir)?)
+        } else {
+            None
+        };
+
+        Ok(Self { dir, csv, tensorboard })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Append one epoch and flush so external tools can follow the run live.
+    pub fn write_epoch(&mut self, m: &EpochMetrics) -> Result<(), String> {
+        self.csv
+            .write_record([
+                m.epoch.to_string(),
+                m.train_loss.to_string(),
+                m.val_loss.to_string(),
+                m.learning_rate.to_string(),
+                m.grad_norm.to_string(),
+            ])
+            .map_err(|e| e.to_string())?;
+        self.csv.flush().map_err(|e| e.to_string())?;
+
+        if let Some(ref mut tb) = self.tensorboard {
+            let step = m.epoch as i64;
+            tb.write_scalar("loss/train", m.train_loss, step)?;
+            tb.write_scalar("loss/val", m.val_loss, step)?;
+            tb.write_scalar("learning_rate", m.learning_rate, step)?;
+            tb.write_scalar("grad_norm", m.grad_norm, step)?;
+            tb.file.flush().map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+/// Minimal TensorBoard event-file writer (TFRecord framing of `Event` protobufs with
+/// scalar summaries), hand-encoded to avoid a protobuf dependency.
+struct TensorBoardWriter {
+    file: File,
+}
+
+impl TensorBoardWriter {
+    fn create(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(format!(
+            "events.out.tfevents.{}.mkt-noise-analysis",
+            chrono::Utc::now().timestamp()
+        ));
+        let file = File::create(&path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let mut writer = Self { file };
+
+        // First record identifies the file format version
+        let mut event = Vec::new();
+        put_double(&mut event, 1, wall_time());
+        put_bytes(&mut event, 3, b"brain.Event:2");
+        writer.write_record(&event)?;
+        Ok(writer)
+    }
+
+    fn write_scalar(&mut self, tag: &str, value: f64, step: i64) -> Result<(), String> {
+        // Summary.Value { tag = 1, simple_value = 2 }
+        let mut summary_value = Vec::new();
+        put_bytes(&mut summary_value, 1, tag.as_bytes());
+        put_float(&mut summary_value, 2, value as f32);
+        // Summary { value = 1 (repeated) }
+        let mut summary = Vec::new();
+        put_bytes(&mut summary, 1, &summary_value);
+        // Event { wall_time = 1, step = 2, summary = 5 }
+        let mut event = Vec::new();
+        put_double(&mut event, 1, wall_time());
+        put_varint_field(&mut event, 2, step as u64);
+        put_bytes(&mut event, 5, &summary);
+        self.write_record(&event)
+    }
+
+    /// TFRecord framing: len (u64 LE), masked crc(len), data, masked crc(data)
+    fn write_record(&mut self, data: &[u8]) -> Result<(), String> {
+        let len = (data.len() as u64).to_le_bytes();
+        let mut record = Vec::with_capacity(data.len() + 16);
+        record.extend_from_slice(&len);
+        record.extend_from_slice(&masked_crc32c(&len).to_le_bytes());
+        record.extend_from_slice(data);
+        record.extend_from_slice(&masked_crc32c(data).to_le_bytes());
+        self.file.write_all(&record).map_err(|e| e.to_string())
+    }
+}
+
+fn wall_time() -> f64 {
+    chrono::Utc::now().timestamp_micros() as f64 / 1e6
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn put_varint_field(buf: &mut Vec<u8>, field: u32, v: u64) {
+    put_varint(buf, (field as u64) << 3);
+    put_varint(buf, v);
+}
+
+fn put_double(buf: &mut Vec<u8>, field: u32, v: f64) {
+    put_varint(buf, ((field as u64) << 3) | 1);
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_float(buf: &mut Vec<u8>, field: u32, v: f32) {
+    put_varint(buf, ((field as u64) << 3) | 5);
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_bytes(buf: &mut Vec<u8>, field: u32, bytes: &[u8]) {
+    put_varint(buf, ((field as u64) << 3) | 2);
+    put_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+/// CRC-32C (Castagnoli), bitwise
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0x82F6_3B78 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    crc.rotate_right(15).wrapping_add(0xA282_EAD8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32c_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn test_varint_encoding() {
+        let mut buf = Vec::new();
+        put_varint(&mut buf, 300);
+        assert_eq!(buf, vec![0xAC, 0x02]);
+    }
+}
@@ -1,7 +1,15 @@
+pub mod backtest;
 pub mod dataset;
+pub mod drift;
 pub mod gpu;
+pub mod history;
+pub mod metrics_export;
 pub mod model;
 pub mod persistence;
+pub mod prediction_history;
+pub mod quantized;
+pub mod saliency;
+pub mod sweep;
 pub mod training;
 
 /// Type alias for the persisted model (NdArray backend, always available)
@@ -4,13 +4,15 @@ use burn::{
     tensor::{backend::Backend, Tensor},
 };
 
+use crate::data::models::{NnFeatureFlags, NnTargetMode};
+
 /// LSTM-based volatility prediction model
 ///
 /// Architecture: LSTM -> take last hidden state -> Linear -> prediction
 #[derive(Module, Debug)]
 pub struct VolPredictionModel<B: Backend> {
-    lstm: Lstm<B>,
-    output_layer: Linear<B>,
+    pub lstm: Lstm<B>,
+    pub output_layer: Linear<B>,
 }
 
 /// Configuration for the model
@@ -58,9 +60,54 @@ impl<B: Backend> VolPredictionModel<B> {
     }
 }
 
-/// Number of input features per time step
-/// 26 base + 22 randomness (entropy, hurst per sector) + 22 kurtosis (kurtosis, skew per sector)
-pub const NUM_FEATURES: usize = 70;
+/// Always-on features: 11 sector returns + correlation, 10Y-2Y spread, curve slope, VIX proxy
+pub const BASE_FEATURES: usize = 15;
+
+/// Sector volatility group: one rolling vol per sector
+pub const SECTOR_VOL_FEATURES: usize = 11;
+
+/// Market randomness group: entropy, hurst per sector
+pub const RANDOMNESS_FEATURES: usize = 22;
+
+/// Kurtosis group: kurtosis, skew per sector
+pub const KURTOSIS_FEATURES: usize = 22;
+
+/// Custom indicator group: one value per sector for each indicator used as a feature
+pub const CUSTOM_INDICATOR_FEATURES: usize = 11;
+
+/// Input width with every feature group enabled. Checkpoints saved before the feature
+/// schema was recorded always used this width (disabled groups were zero-filled).
+pub const NUM_FEATURES: usize = BASE_FEATURES + SECTOR_VOL_FEATURES + RANDOMNESS_FEATURES + KURTOSIS_FEATURES;
+
+/// Number of input features per time step for the enabled feature groups
+pub fn num_features(flags: &NnFeatureFlags) -> usize {
+    let mut n = BASE_FEATURES;
+    if flags.sector_volatility {
+        n += SECTOR_VOL_FEATURES;
+    }
+    if flags.market_randomness {
+        n += RANDOMNESS_FEATURES;
+    }
+    if flags.kurtosis {
+        n += KURTOSIS_FEATURES;
+    }
+    n + CUSTOM_INDICATOR_FEATURES * flags.custom_indicators.len()
+}
 
 /// Output size: 1 vol + 11 entropy + 22 (kurtosis, skew per sector)
 pub const OUTPUT_SIZE: usize = 34;
+
+/// Number of vol regime classes (low, normal, high)
+pub const NUM_REGIMES: usize = 3;
+
+/// Output size in regime classification mode: 3 class logits per sector
+pub const REGIME_OUTPUT_SIZE: usize = 11 * NUM_REGIMES;
+
+/// Output layer size for the given training target
+pub fn output_size(target: NnTargetMode) -> usize {
+    match target {
+        NnTargetMode::Regression => OUTPUT_SIZE,
+        NnTargetMode::RegimeClassification => REGIME_OUTPUT_SIZE,
+    }
+}
+
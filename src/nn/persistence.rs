@@ -5,9 +5,11 @@ use burn::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::config;
 use crate::data::cache;
-use crate::nn::model::{VolPredictionModel, VolPredictionModelConfig, NUM_FEATURES, OUTPUT_SIZE};
+use crate::data::models::{InferencePrecision, NnFeatureFlags, NnTrainingConfig};
+use crate::nn::dataset::{feature_schema, FeatureStats, RegimeThresholds};
+use crate::nn::model::{num_features, output_size, VolPredictionModel, VolPredictionModelConfig, NUM_FEATURES};
+use crate::nn::quantized::QuantizedModel;
 
 /// Metadata saved alongside the trained model
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +17,77 @@ pub struct ModelMetadata {
     pub trained_at: String,
     pub final_loss: f64,
     pub epochs: usize,
+    /// Per-feature standardization fitted on the training split.
+    /// `None` for checkpoints saved before normalization was introduced.
+    #[serde(default)]
+    pub feature_stats: Option<FeatureStats>,
+    /// Per-sector regime cut-offs (regime classification checkpoints only)
+    #[serde(default)]
+    pub regime_thresholds: Option<RegimeThresholds>,
+    /// Hyperparameters the checkpoint was trained with (legacy checkpoints use the defaults)
+    #[serde(default)]
+    pub training_config: NnTrainingConfig,
+    /// Input feature groups the checkpoint was trained with (`None` for older checkpoints)
+    #[serde(default)]
+    pub feature_flags: Option<NnFeatureFlags>,
+    /// Ordered input feature names the checkpoint expects (empty for checkpoints saved
+    /// before the schema was recorded, which always took `NUM_FEATURES` inputs)
+    #[serde(default)]
+    pub feature_schema: Vec<String>,
+}
+
+impl ModelMetadata {
+    /// Input width of the saved model
+    pub fn input_size(&self) -> usize {
+        if self.feature_schema.is_empty() {
+            NUM_FEATURES
+        } else {
+            self.feature_schema.len()
+        }
+    }
+
+    /// Check that `flags` produce the inputs the checkpoint was trained with.
+    /// Checkpoints saved before flags were recorded are assumed compatible when the
+    /// input width lines up.
+    pub fn check_feature_flags(&self, flags: &NnFeatureFlags) -> Result<(), String> {
+        if let Some(ref trained) = self.feature_flags {
+            let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+            let mut diffs: Vec<String> = [
+                ("sector volatility", trained.sector_volatility, flags.sector_volatility),
+                ("market randomness", trained.market_randomness, flags.market_randomness),
+                ("kurtosis", trained.kurtosis, flags.kurtosis),
+            ]
+            .into_iter()
+            .filter(|(_, saved, current)| saved != current)
+            .map(|(name, saved, current)| format!("{} is {} in the checkpoint but {} now", name, on_off(saved), on_off(current)))
+            .collect();
+            let scripts = |f: &NnFeatureFlags| -> Vec<(String, String)> {
+                f.custom_indicators.iter().map(|c| (c.name.clone(), c.script.clone())).collect()
+            };
+            if scripts(trained) != scripts(flags) {
+                diffs.push("the custom indicator features differ from the checkpoint's".to_string());
+            }
+
+            if !diffs.is_empty() {
+                return Err(format!(
+                    "Feature settings do not match the saved model: {}. Retrain or restore the matching settings.",
+                    diffs.join("; ")
+                ));
+            }
+        }
+
+        if self.feature_schema.is_empty() && num_features(flags) != NUM_FEATURES {
+            // Pre-schema checkpoints zero-filled disabled groups, so only the full layout lines up
+            return Err(
+                "The saved model predates per-group features and needs every feature group enabled. Retrain or enable all feature groups."
+                    .to_string(),
+            );
+        }
+        if !self.feature_schema.is_empty() && self.feature_schema != feature_schema(flags) {
+            return Err("The saved model's input features differ from this version's. Retrain the model.".to_string());
+        }
+        Ok(())
+    }
 }
 
 // v2: multi-output model (vol + randomness + kurtosis); incompatible with v1
@@ -25,6 +98,10 @@ const METADATA_FILENAME: &str = "vol_model_v2_metadata";
 pub fn save_model<B: burn::tensor::backend::Backend>(
     model: &crate::nn::model::VolPredictionModel<B>,
     final_loss: f64,
+    feature_stats: &FeatureStats,
+    regime_thresholds: Option<&RegimeThresholds>,
+    feature_flags: &NnFeatureFlags,
+    training_config: &NnTrainingConfig,
 ) -> Result<(), String> {
     let cache_dir = cache::cache_dir().map_err(|e| e.to_string())?;
     let model_path = cache_dir.join(MODEL_FILENAME);
@@ -39,7 +116,12 @@ pub fn save_model<B: burn::tensor::backend::Backend>(
     let metadata = ModelMetadata {
         trained_at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
         final_loss,
-        epochs: config::NN_EPOCHS,
+        epochs: training_config.epochs,
+        feature_stats: Some(feature_stats.clone()),
+        regime_thresholds: regime_thresholds.cloned(),
+        training_config: training_config.clone(),
+        feature_flags: Some(feature_flags.clone()),
+        feature_schema: feature_schema(feature_flags),
     };
 
     cache::save_json(METADATA_FILENAME, &metadata).map_err(|e| format!("Failed to save metadata: {}", e))?;
@@ -60,10 +142,13 @@ pub fn load_model() -> Option<(VolPredictionModel<NdArray>, ModelMetadata)> {
     let recorder = NamedMpkGzFileRecorder::<FullPrecisionSettings>::default();
     let device = <NdArray as burn::tensor::backend::Backend>::Device::default();
 
+    // Metadata first: the checkpoint's hidden size and target mode determine the model shape
+    let metadata: ModelMetadata = cache::load_json(METADATA_FILENAME).ok()?;
+
     let model_config = VolPredictionModelConfig {
-        input_size: NUM_FEATURES,
-        hidden_size: config::NN_HIDDEN_SIZE,
-        output_size: OUTPUT_SIZE,
+        input_size: metadata.input_size(),
+        hidden_size: metadata.training_config.hidden_size,
+        output_size: output_size(metadata.training_config.target_mode),
     };
 
     let model = model_config
@@ -75,8 +160,6 @@ pub fn load_model() -> Option<(VolPredictionModel<NdArray>, ModelMetadata)> {
         })
         .ok()?;
 
-    let metadata: ModelMetadata = cache::load_json(METADATA_FILENAME).ok()?;
-
     tracing::info!(
         "Model loaded from disk (trained {}, loss: {:.6})",
         metadata.trained_at,
@@ -85,3 +168,123 @@ pub fn load_model() -> Option<(VolPredictionModel<NdArray>, ModelMetadata)> {
 
     Some((model, metadata))
 }
+
+/// Path of the compressed weights `save_model` writes
+fn weights_path() -> Result<std::path::PathBuf, String> {
+    let cache_dir = cache::cache_dir().map_err(|e| e.to_string())?;
+    Ok(cache_dir.join(MODEL_FILENAME).with_extension("mpk.gz"))
+}
+
+/// The saved checkpoint's raw weights file and metadata, for bundling elsewhere.
+/// `None` when no model has been saved.
+pub fn export_checkpoint() -> Option<(Vec<u8>, ModelMetadata)> {
+    let metadata: ModelMetadata = cache::load_json(METADATA_FILENAME).ok()?;
+    let weights = std::fs::read(weights_path().ok()?).ok()?;
+    Some((weights, metadata))
+}
+
+/// Replace the saved checkpoint with one from [`export_checkpoint`] and load it
+pub fn import_checkpoint(weights: &[u8], metadata: &ModelMetadata) -> Result<(VolPredictionModel<NdArray>, ModelMetadata), String> {
+    std::fs::write(weights_path()?, weights).map_err(|e| format!("Failed to write model: {}", e))?;
+    cache::save_json(METADATA_FILENAME, metadata).map_err(|e| format!("Failed to save metadata: {}", e))?;
+    load_model().ok_or_else(|| "The bundled model could not be loaded".to_string())
+}
+
+/// Quantized copy of the checkpoint, tagged with the run it was derived from
+#[derive(Serialize, Deserialize)]
+struct QuantizedCheckpoint {
+    trained_at: String,
+    model: QuantizedModel,
+}
+
+fn quantized_filename(precision: InferencePrecision) -> Option<String> {
+    let suffix = match precision {
+        InferencePrecision::Full => return None,
+        InferencePrecision::F16 => "f16",
+        InferencePrecision::Int8 => "int8",
+    };
+    Some(format!("{}_{}.json", MODEL_FILENAME, suffix))
+}
+
+/// Load the saved model quantized to `precision` for the inference-only path. The
+/// quantized copy is derived from the full checkpoint on first use (or after retraining)
+/// and cached next to it, so later prediction refreshes skip the full model entirely.
+/// Returns `None` for `InferencePrecision::Full` or when no model has been saved.
+pub fn load_quantized_model(precision: InferencePrecision) -> Option<(QuantizedModel, ModelMetadata)> {
+    let filename = quantized_filename(precision)?;
+    let metadata: ModelMetadata = cache::load_json(METADATA_FILENAME).ok()?;
+
+    if let Ok(ckpt) = cache::load_json::<QuantizedCheckpoint>(&filename) {
+        if ckpt.trained_at == metadata.trained_at && ckpt.model.precision == precision {
+            return Some((ckpt.model, metadata));
+        }
+    }
+
+    let (full, metadata) = load_model()?;
+    let model = QuantizedModel::from_model(&full, precision);
+    let ckpt = QuantizedCheckpoint { trained_at: metadata.trained_at.clone(), model };
+    // Compact JSON: the weight arrays dominate and pretty-printing would multiply the size
+    let written = cache::cache_dir()
+        .map_err(|e| e.to_string())
+        .and_then(|dir| {
+            let json = serde_json::to_vec(&ckpt).map_err(|e| e.to_string())?;
+            std::fs::write(dir.join(&filename), json).map_err(|e| e.to_string())
+        });
+    match written {
+        Ok(()) => tracing::info!(
+            "Quantized model ({}) saved: {} KiB of weights",
+            precision.label(),
+            ckpt.model.weight_bytes() / 1024
+        ),
+        Err(e) => tracing::warn!("Failed to cache quantized model: {}", e),
+    }
+    Some((ckpt.model, metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(feature_flags: Option<NnFeatureFlags>) -> ModelMetadata {
+        ModelMetadata {
+            trained_at: String::new(),
+            final_loss: 0.0,
+            epochs: 1,
+            feature_stats: None,
+            regime_thresholds: None,
+            training_config: NnTrainingConfig::default(),
+            feature_flags,
+            feature_schema: vec![],
+        }
+    }
+
+    #[test]
+    fn test_feature_flags_match() {
+        let flags = NnFeatureFlags::default();
+        assert!(metadata(Some(flags.clone())).check_feature_flags(&flags).is_ok());
+        // Legacy checkpoints without recorded flags are not rejected
+        assert!(metadata(None).check_feature_flags(&flags).is_ok());
+    }
+
+    #[test]
+    fn test_feature_flags_mismatch() {
+        let current = NnFeatureFlags { kurtosis: false, ..NnFeatureFlags::default() };
+        let err = metadata(Some(NnFeatureFlags::default())).check_feature_flags(&current).unwrap_err();
+        assert!(err.contains("kurtosis is on in the checkpoint but off now"));
+    }
+
+    #[test]
+    fn test_feature_schema_check() {
+        let base_only = NnFeatureFlags { sector_volatility: false, market_randomness: false, kurtosis: false, ..NnFeatureFlags::default() };
+        // Legacy checkpoints only accept the full zero-filled layout
+        assert!(metadata(None).check_feature_flags(&base_only).is_err());
+
+        let mut meta = metadata(Some(base_only.clone()));
+        meta.feature_schema = feature_schema(&base_only);
+        assert_eq!(meta.input_size(), num_features(&base_only));
+        assert!(meta.check_feature_flags(&base_only).is_ok());
+
+        meta.feature_schema.pop();
+        assert!(meta.check_feature_flags(&base_only).is_err());
+    }
+}
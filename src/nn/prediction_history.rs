@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use crate::data::cache;
+use crate::data::models::NnPredictions;
+
+const HISTORY_FILENAME: &str = "nn_prediction_history.json";
+
+/// Oldest snapshots are dropped beyond this many runs
+pub const MAX_SNAPSHOTS: usize = 60;
+
+/// Per-sector vol forecasts from one refresh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PredictionSnapshot {
+    /// Local time the predictions were made, RFC 3339
+    pub recorded_at: String,
+    /// (symbol, predicted forward vol)
+    pub vol: Vec<(String, f64)>,
+}
+
+/// Load the prediction history from the cache (oldest first). Empty if none has been saved.
+pub fn load_history() -> Vec<PredictionSnapshot> {
+    cache::load_json(HISTORY_FILENAME).unwrap_or_default()
+}
+
+pub fn save_history(history: &[PredictionSnapshot]) -> Result<(), String> {
+    cache::save_json(HISTORY_FILENAME, &history).map_err(|e| format!("Failed to save prediction history: {}", e))
+}
+
+/// Append a snapshot of the vol forecasts in `preds`, trimming to `MAX_SNAPSHOTS`.
+/// Returns false (and records nothing) when there are no finite vol forecasts.
+pub fn record(history: &mut Vec<PredictionSnapshot>, preds: &NnPredictions, recorded_at: String) -> bool {
+    let vol: Vec<(String, f64)> = preds.vol.iter().filter(|(_, v)| v.is_finite()).cloned().collect();
+    if vol.is_empty() {
+        return false;
+    }
+    history.push(PredictionSnapshot { recorded_at, vol });
+    if history.len() > MAX_SNAPSHOTS {
+        let excess = history.len() - MAX_SNAPSHOTS;
+        history.drain(..excess);
+    }
+    true
+}
+
+/// `symbol`'s forecast in each snapshot that has one, oldest first
+pub fn sector_series(history: &[PredictionSnapshot], symbol: &str) -> Vec<f64> {
+    history
+        .iter()
+        .filter_map(|s| s.vol.iter().find(|(sym, _)| sym == symbol).map(|(_, v)| *v))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preds(vol: &[(&str, f64)]) -> NnPredictions {
+        NnPredictions {
+            vol: vol.iter().map(|(s, v)| (s.to_string(), *v)).collect(),
+            ..NnPredictions::default()
+        }
+    }
+
+    #[test]
+    fn test_record_trims_and_skips_empty() {
+        let mut history = Vec::new();
+        assert!(!record(&mut history, &preds(&[]), "t".into()));
+        assert!(!record(&mut history, &preds(&[("XLK", f64::NAN)]), "t".into()));
+        for i in 0..MAX_SNAPSHOTS + 5 {
+            assert!(record(&mut history, &preds(&[("XLK", i as f64)]), i.to_string()));
+        }
+        assert_eq!(history.len(), MAX_SNAPSHOTS);
+        assert_eq!(history[0].recorded_at, "5");
+    }
+
+    #[test]
+    fn test_sector_series_skips_snapshots_without_symbol() {
+        let mut history = Vec::new();
+        record(&mut history, &preds(&[("XLK", 0.1), ("XLF", 0.2)]), "a".into());
+        record(&mut history, &preds(&[("XLF", 0.3)]), "b".into());
+        record(&mut history, &preds(&[("XLK", 0.4)]), "c".into());
+        assert_eq!(sector_series(&history, "XLK"), vec![0.1, 0.4]);
+        assert_eq!(sector_series(&history, "XLF"), vec![0.2, 0.3]);
+        assert!(sector_series(&history, "XLE").is_empty());
+    }
+}
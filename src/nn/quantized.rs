@@ -0,0 +1,224 @@
+use burn::{backend::NdArray, nn::Linear, tensor::f16};
+use ndarray::ArrayView2;
+use serde::{Deserialize, Serialize};
+
+use crate::data::models::InferencePrecision;
+use crate::nn::LoadedModel;
+
+/// Quantized weight matrix `[rows, cols]` (row-major, `y = x · W`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QuantMatrix {
+    rows: usize,
+    cols: usize,
+    values: QuantValues,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum QuantValues {
+    /// Symmetric int8 with one scale per output column
+    Int8 { values: Vec<i8>, scales: Vec<f32> },
+    /// IEEE half-precision bit patterns
+    F16 { bits: Vec<u16> },
+}
+
+impl QuantMatrix {
+    fn quantize(weights: &[f32], rows: usize, cols: usize, precision: InferencePrecision) -> Self {
+        let values = match precision {
+            InferencePrecision::Int8 => {
+                let scales: Vec<f32> = (0..cols)
+                    .map(|j| {
+                        let max = (0..rows).map(|i| weights[i * cols + j].abs()).fold(0.0_f32, f32::max);
+                        if max > 0.0 { max / 127.0 } else { 1.0 }
+                    })
+                    .collect();
+                let values = weights
+                    .iter()
+                    .enumerate()
+                    .map(|(k, &w)| (w / scales[k % cols]).round().clamp(-127.0, 127.0) as i8)
+                    .collect();
+                QuantValues::Int8 { values, scales }
+            }
+            InferencePrecision::F16 | InferencePrecision::Full => QuantValues::F16 {
+                bits: weights.iter().map(|&w| f16::from_f32(w).to_bits()).collect(),
+            },
+        };
+        Self { rows, cols, values }
+    }
+
+    /// `y += x · W`
+    fn matvec_add(&self, x: &[f32], y: &mut [f32]) {
+        match self.values {
+            QuantValues::Int8 { ref values, ref scales } => {
+                let mut acc = vec![0.0_f32; self.cols];
+                for (i, &xi) in x.iter().enumerate().take(self.rows) {
+                    let row = &values[i * self.cols..(i + 1) * self.cols];
+                    for (a, &q) in acc.iter_mut().zip(row) {
+                        *a += xi * q as f32;
+                    }
+                }
+                for ((yj, a), s) in y.iter_mut().zip(acc).zip(scales) {
+                    *yj += a * s;
+                }
+            }
+            QuantValues::F16 { ref bits } => {
+                for (i, &xi) in x.iter().enumerate().take(self.rows) {
+                    let row = &bits[i * self.cols..(i + 1) * self.cols];
+                    for (yj, &b) in y.iter_mut().zip(row) {
+                        *yj += xi * f16::from_bits(b).to_f32();
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_bytes(&self) -> usize {
+        match self.values {
+            QuantValues::Int8 { ref values, ref scales } => values.len() + scales.len() * 4,
+            QuantValues::F16 { ref bits } => bits.len() * 2,
+        }
+    }
+}
+
+/// Affine layer with quantized weights and f32 bias
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QuantLinear {
+    weight: QuantMatrix,
+    bias: Vec<f32>,
+}
+
+impl QuantLinear {
+    fn from_linear(linear: &Linear<NdArray>, precision: InferencePrecision) -> Self {
+        let [rows, cols] = linear.weight.val().dims();
+        let weights = linear.weight.val().into_data().to_vec::<f32>().unwrap_or_default();
+        let bias = linear
+            .bias
+            .as_ref()
+            .and_then(|b| b.val().into_data().to_vec::<f32>().ok())
+            .unwrap_or_else(|| vec![0.0; cols]);
+        Self { weight: QuantMatrix::quantize(&weights, rows, cols, precision), bias }
+    }
+
+    fn forward_into(&self, x: &[f32], y: &mut [f32]) {
+        y.copy_from_slice(&self.bias);
+        self.weight.matvec_add(x, y);
+    }
+}
+
+/// One LSTM gate: `input · W_x + b_x + hidden · W_h + b_h`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct QuantGate {
+    input: QuantLinear,
+    hidden: QuantLinear,
+}
+
+impl QuantGate {
+    fn from_controller(gate: &burn::nn::GateController<NdArray>, precision: InferencePrecision) -> Self {
+        Self {
+            input: QuantLinear::from_linear(&gate.input_transform, precision),
+            hidden: QuantLinear::from_linear(&gate.hidden_transform, precision),
+        }
+    }
+
+    fn product(&self, x: &[f32], h: &[f32], out: &mut [f32]) {
+        self.input.forward_into(x, out);
+        for (o, b) in out.iter_mut().zip(&self.hidden.bias) {
+            *o += b;
+        }
+        self.hidden.weight.matvec_add(h, out);
+    }
+}
+
+/// Weight-quantized copy of `VolPredictionModel` with a dependency-free CPU forward pass,
+/// used for fast inference-only prediction refreshes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantizedModel {
+    pub precision: InferencePrecision,
+    pub input_size: usize,
+    pub hidden_size: usize,
+    pub output_size: usize,
+    input_gate: QuantGate,
+    forget_gate: QuantGate,
+    output_gate: QuantGate,
+    cell_gate: QuantGate,
+    output_layer: QuantLinear,
+}
+
+impl QuantizedModel {
+    pub fn from_model(model: &LoadedModel, precision: InferencePrecision) -> Self {
+        let lstm = &model.lstm;
+        let [input_size, hidden_size] = lstm.input_gate.input_transform.weight.val().dims();
+        let [_, output_size] = model.output_layer.weight.val().dims();
+        Self {
+            precision,
+            input_size,
+            hidden_size,
+            output_size,
+            input_gate: QuantGate::from_controller(&lstm.input_gate, precision),
+            forget_gate: QuantGate::from_controller(&lstm.forget_gate, precision),
+            output_gate: QuantGate::from_controller(&lstm.output_gate, precision),
+            cell_gate: QuantGate::from_controller(&lstm.cell_gate, precision),
+            output_layer: QuantLinear::from_linear(&model.output_layer, precision),
+        }
+    }
+
+    /// Run one window `[seq_len][input_size]` and return the `output_size` outputs.
+    pub fn forward(&self, window: ArrayView2<f64>) -> Vec<f32> {
+        let h_size = self.hidden_size;
+        let mut hidden = vec![0.0_f32; h_size];
+        let mut cell = vec![0.0_f32; h_size];
+        let (mut i_g, mut f_g, mut o_g, mut c_g) =
+            (vec![0.0; h_size], vec![0.0; h_size], vec![0.0; h_size], vec![0.0; h_size]);
+        let sigmoid = |v: f32| 1.0 / (1.0 + (-v).exp());
+
+        for step in window.rows() {
+            let x: Vec<f32> = step.iter().map(|&v| v as f32).collect();
+            self.input_gate.product(&x, &hidden, &mut i_g);
+            self.forget_gate.product(&x, &hidden, &mut f_g);
+            self.output_gate.product(&x, &hidden, &mut o_g);
+            self.cell_gate.product(&x, &hidden, &mut c_g);
+            for k in 0..h_size {
+                cell[k] = sigmoid(f_g[k]) * cell[k] + sigmoid(i_g[k]) * c_g[k].tanh();
+                hidden[k] = sigmoid(o_g[k]) * cell[k].tanh();
+            }
+        }
+
+        let mut out = vec![0.0_f32; self.output_size];
+        self.output_layer.forward_into(&hidden, &mut out);
+        out
+    }
+
+    /// Bytes of weight storage (biases are kept in f32)
+    pub fn weight_bytes(&self) -> usize {
+        [&self.input_gate, &self.forget_gate, &self.output_gate, &self.cell_gate]
+            .iter()
+            .map(|g| g.input.weight.size_bytes() + g.hidden.weight.size_bytes())
+            .sum::<usize>()
+            + self.output_layer.weight.size_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::model::VolPredictionModelConfig;
+    use burn::tensor::Tensor;
+
+    #[test]
+    fn test_quantized_forward_matches_full_model() {
+        let device = Default::default();
+        let model = VolPredictionModelConfig { input_size: 6, hidden_size: 8, output_size: 4 }.init::<NdArray>(&device);
+        let window = ndarray::Array2::from_shape_fn((10, 6), |(t, f)| ((t * 6 + f) as f64 * 0.37).sin());
+        let flat: Vec<f32> = window.iter().map(|&v| v as f32).collect();
+        let input = Tensor::<NdArray, 1>::from_floats(flat.as_slice(), &device).reshape([1, 10, 6]);
+        let expected = model.forward(input).into_data().to_vec::<f32>().unwrap();
+
+        for (precision, tol) in [(InferencePrecision::F16, 1e-2), (InferencePrecision::Int8, 5e-2)] {
+            let q = QuantizedModel::from_model(&model, precision);
+            let out = q.forward(window.view());
+            assert_eq!(out.len(), 4);
+            for (a, b) in out.iter().zip(&expected) {
+                assert!((a - b).abs() < tol, "{:?}: {} vs {}", precision, a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use burn::{
+    backend::{Autodiff, NdArray},
+    module::Module,
+    record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+    tensor::{activation::log_softmax, Tensor},
+};
+use ndarray::ArrayView2;
+
+use crate::config;
+use crate::data::models::{MarketData, NnFeatureFlags, NnTargetMode};
+use crate::nn::dataset::{build_dataset, feature_schema};
+use crate::nn::model::{output_size, VolPredictionModel, VolPredictionModelConfig};
+use crate::nn::persistence::ModelMetadata;
+use crate::nn::LoadedModel;
+
+/// Gradients w.r.t. the input need an autodiff backend; the loaded model is copied onto it
+type SaliencyBackend = Autodiff<NdArray>;
+
+/// Gradient × input attribution of the latest prediction over its input window
+#[derive(Debug, Clone, Default)]
+pub struct Saliency {
+    /// Input feature names, one per column of `values`
+    pub feature_names: Vec<String>,
+    /// |gradient × input| per `[day][feature]`; day 0 is the oldest day in the window
+    pub values: Vec<Vec<f64>>,
+    /// Total attribution per lookback day
+    pub by_day: Vec<f64>,
+    /// Total attribution per feature
+    pub by_feature: Vec<f64>,
+    /// Objective of the model whose output is explained: the predicted 5-day vol, or
+    /// the high-vol regime log-probability
+    pub target: NnTargetMode,
+}
+
+impl Saliency {
+    /// Feature indices sorted by total attribution, most influential first
+    pub fn ranked_features(&self) -> Vec<usize> {
+        let mut idx: Vec<usize> = (0..self.by_feature.len()).collect();
+        idx.sort_by(|&a, &b| self.by_feature[b].total_cmp(&self.by_feature[a]));
+        idx
+    }
+}
+
+/// Attribute the model's prediction for the most recent window to its input days and
+/// features. Regression models explain the aggregate vol output; regime models explain
+/// the mean log-probability of the high-vol regime across sectors.
+pub fn compute_saliency(
+    model: &LoadedModel,
+    market_data: &MarketData,
+    feature_flags: &NnFeatureFlags,
+    metadata: Option<&ModelMetadata>,
+) -> Result<Saliency, String> {
+    let training_config = metadata.map(|m| m.training_config.clone()).unwrap_or_default();
+    let dataset = build_dataset(market_data, training_config.lookback, config::NN_FORWARD_DAYS, feature_flags);
+    let Some(sample) = dataset.samples.last() else {
+        return Err("Not enough data to build an input window.".to_string());
+    };
+    let mut window = dataset.features(sample).to_owned();
+    if let Some(stats) = metadata.and_then(|m| m.feature_stats.as_ref()) {
+        stats.apply(window.view_mut());
+    }
+    let n_features = window.ncols();
+
+    let device = Default::default();
+    let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+    let bytes = Recorder::<NdArray>::record(&recorder, model.clone().into_record(), ())
+        .map_err(|e| format!("Failed to copy model: {}", e))?;
+    let record = Recorder::<SaliencyBackend>::load(&recorder, bytes, &device)
+        .map_err(|e| format!("Failed to copy model: {}", e))?;
+    let model = VolPredictionModelConfig {
+        input_size: n_features,
+        hidden_size: training_config.hidden_size,
+        output_size: output_size(training_config.target_mode),
+    }
+    .init::<SaliencyBackend>(&device)
+    .load_record(record);
+
+    let values = input_attribution(&model, window.view(), training_config.target_mode, &device);
+
+    let mut feature_names = metadata
+        .map(|m| m.feature_schema.clone())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| feature_schema(feature_flags));
+    if feature_names.len() != n_features {
+        feature_names = (0..n_features).map(|i| format!("feature_{}", i)).collect();
+    }
+
+    let by_day = values.iter().map(|row| row.iter().sum()).collect();
+    let by_feature = (0..n_features).map(|f| values.iter().map(|row| row[f]).sum()).collect();
+    let target = training_config.target_mode;
+
+    Ok(Saliency { feature_names, values, by_day, by_feature, target })
+}
+
+/// |gradient × input| of the explained output w.r.t. each `[day][feature]` input value.
+fn input_attribution(
+    model: &VolPredictionModel<SaliencyBackend>,
+    features: ArrayView2<f64>,
+    target_mode: NnTargetMode,
+    device: &<SaliencyBackend as burn::tensor::backend::Backend>::Device,
+) -> Vec<Vec<f64>> {
+    let (seq_len, n_features) = features.dim();
+    let flat: Vec<f32> = features.iter().map(|&v| v as f32).collect();
+
+    let input = Tensor::<SaliencyBackend, 1>::from_floats(flat.as_slice(), device)
+        .reshape([1, seq_len, n_features])
+        .require_grad();
+    let pred = model.forward(input.clone());
+
+    let objective = match target_mode {
+        NnTargetMode::Regression => pred.slice([0..1, 0..1]).sum(),
+        NnTargetMode::RegimeClassification => {
+            let sectors = pred.dims()[1] / crate::nn::model::NUM_REGIMES;
+            log_softmax(pred.reshape([sectors, crate::nn::model::NUM_REGIMES]), 1)
+                .slice([0..sectors, 2..3])
+                .mean()
+        }
+    };
+
+    let grads = objective.backward();
+    let grad = input
+        .grad(&grads)
+        .map(|g| g.into_data().to_vec::<f32>().unwrap_or_default())
+        .unwrap_or_default();
+
+    (0..seq_len)
+        .map(|t| {
+            (0..n_features)
+                .map(|f| {
+                    let i = t * n_features + f;
+                    (grad.get(i).copied().unwrap_or(0.0) * flat[i]).abs() as f64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribution_shape_and_zero_inputs() {
+        let device = Default::default();
+        let model = VolPredictionModelConfig { input_size: 4, hidden_size: 8, output_size: 34 }
+            .init::<SaliencyBackend>(&device);
+        let mut features = ndarray::Array2::from_shape_fn((6, 4), |(_, f)| [0.5, -1.0, 2.0, 0.3][f]);
+        // A zero input contributes nothing under gradient × input
+        features.column_mut(1).fill(0.0);
+        let values = input_attribution(&model, features.view(), NnTargetMode::Regression, &device);
+        assert_eq!(values.len(), 6);
+        assert!(values.iter().all(|row| row.len() == 4 && row[1] == 0.0));
+        assert!(values.iter().flatten().any(|&v| v > 0.0));
+    }
+}
@@ -0,0 +1,279 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::data::models::{MarketData, NnFeatureFlags, NnTrainingConfig, TrainingStatus};
+use crate::nn::training::{self, ComputeDevice, TrainingProgress};
+
+/// How candidate configurations are drawn from the search space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweepMode {
+    /// Every combination of the listed values
+    Grid,
+    /// `trials` configurations sampled uniformly from the listed values
+    Random { trials: usize },
+}
+
+/// Candidate values for each swept hyperparameter
+#[derive(Debug, Clone)]
+pub struct SweepSpace {
+    pub hidden_sizes: Vec<usize>,
+    pub lookbacks: Vec<usize>,
+    pub learning_rates: Vec<f64>,
+    /// Epochs per trial (sweeps use shorter runs than a full training session)
+    pub epochs: usize,
+}
+
+impl Default for SweepSpace {
+    fn default() -> Self {
+        Self {
+            hidden_sizes: vec![32, 64, 128],
+            lookbacks: vec![20, 40, 60],
+            learning_rates: vec![1e-4, 1e-3, 3e-3],
+            epochs: 100,
+        }
+    }
+}
+
+/// Lifecycle of a single sweep trial
+#[derive(Debug, Clone, PartialEq)]
+pub enum SweepRunStatus {
+    Pending,
+    Running,
+    Done,
+    Failed(String),
+}
+
+/// One trial on the leaderboard
+#[derive(Debug, Clone)]
+pub struct SweepRun {
+    pub config: NnTrainingConfig,
+    pub status: SweepRunStatus,
+    pub best_val_loss: f64,
+    pub final_train_loss: f64,
+}
+
+/// Shared state for communicating sweep progress to the UI
+#[derive(Clone)]
+pub struct SweepProgress {
+    pub runs: Arc<Mutex<Vec<SweepRun>>>,
+    /// Progress of the trial currently training (for epoch display)
+    pub current: Arc<Mutex<Option<TrainingProgress>>>,
+    pub cancel_flag: Arc<AtomicBool>,
+    pub finished: Arc<AtomicBool>,
+}
+
+impl SweepProgress {
+    pub fn new(configs: Vec<NnTrainingConfig>) -> Self {
+        let runs = configs
+            .into_iter()
+            .map(|config| SweepRun {
+                config,
+                status: SweepRunStatus::Pending,
+                best_val_loss: f64::NAN,
+                final_train_loss: f64::NAN,
+            })
+            .collect();
+        Self {
+            runs: Arc::new(Mutex::new(runs)),
+            current: Arc::new(Mutex::new(None)),
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            finished: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Stop after the current trial's next epoch; remaining trials are skipped.
+    pub fn request_cancel(&self) {
+        self.cancel_flag.store(true, Ordering::SeqCst);
+        if let Ok(current) = self.current.lock() {
+            if let Some(ref p) = *current {
+                p.request_stop();
+            }
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::SeqCst)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+}
+
+/// Expand the search space into concrete training configs.
+/// `base` supplies any hyperparameters that are not swept.
+pub fn build_configs(space: &SweepSpace, mode: SweepMode, base: &NnTrainingConfig, seed: u64) -> Vec<NnTrainingConfig> {
+    let make = |hidden_size: usize, lookback: usize, learning_rate: f64| {
+        let mut config = base.clone();
+        config.hidden_size = hidden_size;
+        config.lookback = lookback;
+        config.learning_rate = learning_rate;
+        config.epochs = space.epochs;
+        config
+    };
+
+    if space.hidden_sizes.is_empty() || space.lookbacks.is_empty() || space.learning_rates.is_empty() {
+        return vec![];
+    }
+
+    match mode {
+        SweepMode::Grid => {
+            let mut configs = Vec::new();
+            for &h in &space.hidden_sizes {
+                for &l in &space.lookbacks {
+                    for &lr in &space.learning_rates {
+                        configs.push(make(h, l, lr));
+                    }
+                }
+            }
+            configs
+        }
+        SweepMode::Random { trials } => {
+            let mut rng = XorShift::new(seed);
+            (0..trials)
+                .map(|_| {
+                    make(
+                        space.hidden_sizes[rng.below(space.hidden_sizes.len())],
+                        space.lookbacks[rng.below(space.lookbacks.len())],
+                        space.learning_rates[rng.below(space.learning_rates.len())],
+                    )
+                })
+                .collect()
+        }
+    }
+}
+
+/// Train every pending trial sequentially, recording validation scores.
+/// Intended to run on a background thread; trials never overwrite the saved model.
+pub fn run_sweep(
+    market_data: &MarketData,
+    progress: &SweepProgress,
+    compute_device: &ComputeDevice,
+    feature_flags: &NnFeatureFlags,
+) {
+    let n_runs = progress.runs.lock().map(|r| r.len()).unwrap_or(0);
+
+    for i in 0..n_runs {
+        if progress.is_cancelled() {
+            break;
+        }
+        let Some(config) = progress.runs.lock().ok().and_then(|r| r.get(i).map(|run| run.config.clone())) else {
+            break;
+        };
+        set_run_status(progress, i, SweepRunStatus::Running);
+
+        let trial = TrainingProgress::new();
+        if let Ok(mut current) = progress.current.lock() {
+            *current = Some(trial.clone());
+        }
+
+        training::train(market_data, &trial, compute_device, feature_flags, &config, false);
+
+        let status = trial.status.lock().map(|s| s.clone()).unwrap_or_default();
+        let final_train_loss = trial
+            .losses
+            .lock()
+            .ok()
+            .and_then(|l| l.last().copied())
+            .unwrap_or(f64::NAN);
+        let best_val_loss = trial.best_val_loss();
+
+        if let Ok(mut runs) = progress.runs.lock() {
+            if let Some(run) = runs.get_mut(i) {
+                run.final_train_loss = final_train_loss;
+                run.best_val_loss = best_val_loss;
+                run.status = match status {
+                    TrainingStatus::Complete { .. } => SweepRunStatus::Done,
+                    TrainingStatus::Error(msg) => SweepRunStatus::Failed(msg),
+                    _ => SweepRunStatus::Failed("Cancelled".to_string()),
+                };
+            }
+        }
+    }
+
+    if let Ok(mut current) = progress.current.lock() {
+        *current = None;
+    }
+    progress.finished.store(true, Ordering::SeqCst);
+}
+
+/// Sort runs for the leaderboard: finished runs by ascending validation loss, then the rest.
+pub fn leaderboard(runs: &[SweepRun]) -> Vec<SweepRun> {
+    let mut sorted = runs.to_vec();
+    sorted.sort_by(|a, b| {
+        let ka = if a.best_val_loss.is_finite() { a.best_val_loss } else { f64::INFINITY };
+        let kb = if b.best_val_loss.is_finite() { b.best_val_loss } else { f64::INFINITY };
+        ka.total_cmp(&kb)
+    });
+    sorted
+}
+
+fn set_run_status(progress: &SweepProgress, idx: usize, status: SweepRunStatus) {
+    if let Ok(mut runs) = progress.runs.lock() {
+        if let Some(run) = runs.get_mut(idx) {
+            run.status = status;
+        }
+    }
+}
+
+/// Minimal xorshift64 generator for random search (no external RNG dependency)
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_covers_all_combinations() {
+        let space = SweepSpace::default();
+        let configs = build_configs(&space, SweepMode::Grid, &NnTrainingConfig::default(), 1);
+        assert_eq!(configs.len(), 27);
+        assert!(configs.iter().all(|c| c.epochs == space.epochs));
+        assert!(configs.iter().any(|c| c.hidden_size == 128 && c.lookback == 20 && c.learning_rate == 3e-3));
+    }
+
+    #[test]
+    fn test_random_draws_from_space() {
+        let space = SweepSpace::default();
+        let configs = build_configs(&space, SweepMode::Random { trials: 10 }, &NnTrainingConfig::default(), 42);
+        assert_eq!(configs.len(), 10);
+        for c in &configs {
+            assert!(space.hidden_sizes.contains(&c.hidden_size));
+            assert!(space.lookbacks.contains(&c.lookback));
+            assert!(space.learning_rates.contains(&c.learning_rate));
+        }
+    }
+
+    #[test]
+    fn test_leaderboard_orders_by_val_loss() {
+        let run = |loss: f64| SweepRun {
+            config: NnTrainingConfig::default(),
+            status: SweepRunStatus::Done,
+            best_val_loss: loss,
+            final_train_loss: 0.0,
+        };
+        let board = leaderboard(&[run(0.3), run(f64::NAN), run(0.1)]);
+        assert_eq!(board[0].best_val_loss, 0.1);
+        assert_eq!(board[1].best_val_loss, 0.3);
+        assert!(board[2].best_val_loss.is_nan());
+    }
+}
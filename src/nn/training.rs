@@ -4,32 +4,66 @@ use std::time::Instant;
 
 use burn::{
     backend::{Autodiff, NdArray, Wgpu},
-    data::dataloader::DataLoaderBuilder,
+    data::dataloader::{batcher::Batcher, DataLoaderBuilder},
+    grad_clipping::GradientClippingConfig,
     module::AutodiffModule,
-    module::Module,
+    module::{Module, ModuleVisitor, ParamId},
     optim::{AdamConfig, GradientsParams, Optimizer},
-    tensor::backend::AutodiffBackend,
+    tensor::{
+        activation::log_softmax,
+        backend::{AutodiffBackend, Backend},
+        ElementConversion, Tensor,
+    },
 };
+use ndarray::{s, Array2, ArrayView2};
 use sysinfo::System;
 
 use crate::config;
-use crate::data::models::{ComputeStats, MarketData, NnPredictions, TrainingStatus};
-use crate::nn::dataset::{build_dataset, VolBatcher};
-use crate::nn::model::{VolPredictionModelConfig, NUM_FEATURES, OUTPUT_SIZE};
+use crate::data::models::{
+    ComputeStats, MarketData, NnLossFunction, NnPredictions, NnTargetMode, NnTrainingConfig, NnVolTarget, TrainingStatus,
+};
+use crate::nn::dataset::{build_dataset, output_to_vol, reference_vol, FeatureStats, RegimeThresholds, VolBatcher, VolSample};
+use crate::nn::metrics_export::{EpochMetrics, MetricsWriter};
+use crate::nn::persistence::ModelMetadata;
+use crate::nn::model::{num_features, output_size, VolPredictionModelConfig, NUM_REGIMES};
 
 /// GPU training backend: Wgpu with autodiff
 pub type GpuBackend = Autodiff<Wgpu>;
 
+/// Half-precision GPU training backend (mixed-precision mode)
+pub type GpuHalfBackend = Autodiff<Wgpu<burn::tensor::f16, i32>>;
+
 /// CPU training backend: NdArray with autodiff
 pub type CpuBackend = Autodiff<NdArray>;
 
+/// Initial dynamic loss scale for half-precision training, and its bounds
+const LOSS_SCALE_INIT: f64 = 1024.0;
+const LOSS_SCALE_MAX: f64 = 65536.0;
+const LOSS_SCALE_MIN: f64 = 1.0;
+/// Consecutive overflow-free steps before the loss scale is doubled
+const LOSS_SCALE_GROWTH_INTERVAL: usize = 200;
+
+/// How often the telemetry thread polls GPU utilization / VRAM / temperature during GPU runs
+const GPU_TELEMETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Compute device for a training run
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputeDevice {
+    Cpu,
+    /// WGPU GPU; `adapter` names the adapter to bind to (`None` = WGPU default device)
+    Gpu { adapter: Option<String> },
+}
+
 /// Shared state for communicating training progress to the UI
 #[derive(Clone)]
 pub struct TrainingProgress {
     pub status: Arc<Mutex<TrainingStatus>>,
     pub losses: Arc<Mutex<Vec<f64>>>,
+    /// Per-epoch loss on the held-out (chronologically last 20%) validation split
+    pub val_losses: Arc<Mutex<Vec<f64>>>,
     pub predictions: Arc<Mutex<NnPredictions>>,
     pub pause_flag: Arc<AtomicBool>,
+    pub stop_flag: Arc<AtomicBool>,
     pub compute_stats: Arc<Mutex<ComputeStats>>,
 }
 
@@ -38,8 +72,10 @@ impl TrainingProgress {
         Self {
             status: Arc::new(Mutex::new(TrainingStatus::Idle)),
             losses: Arc::new(Mutex::new(Vec::new())),
+            val_losses: Arc::new(Mutex::new(Vec::new())),
             predictions: Arc::new(Mutex::new(NnPredictions::default())),
             pause_flag: Arc::new(AtomicBool::new(false)),
+            stop_flag: Arc::new(AtomicBool::new(false)),
             compute_stats: Arc::new(Mutex::new(ComputeStats::default())),
         }
     }
@@ -55,16 +91,51 @@ impl TrainingProgress {
     pub fn is_paused(&self) -> bool {
         self.pause_flag.load(Ordering::SeqCst)
     }
+
+    /// Ask the training thread to exit at the next epoch or batch boundary.
+    pub fn request_stop(&self) {
+        self.stop_flag.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stop_flag.load(Ordering::SeqCst)
+    }
+
+    /// Lowest validation loss recorded so far (NaN if none)
+    pub fn best_val_loss(&self) -> f64 {
+        self.val_losses
+            .lock()
+            .ok()
+            .and_then(|v| v.iter().copied().filter(|l| l.is_finite()).reduce(f64::min))
+            .unwrap_or(f64::NAN)
+    }
 }
 
 /// Run the full training pipeline, selecting GPU or CPU backend.
-pub fn train(market_data: &MarketData, progress: &TrainingProgress, use_gpu: bool, feature_flags: &crate::data::models::NnFeatureFlags) {
+///
+/// When `persist` is false the trained model is not written to disk (used by
+/// hyperparameter sweeps, which train many throwaway candidates).
+pub fn train(
+    market_data: &MarketData,
+    progress: &TrainingProgress,
+    compute_device: &ComputeDevice,
+    feature_flags: &crate::data::models::NnFeatureFlags,
+    training_config: &NnTrainingConfig,
+    persist: bool,
+) {
+    let (use_gpu, selected_adapter) = match compute_device {
+        ComputeDevice::Cpu => (false, None),
+        ComputeDevice::Gpu { adapter } => (true, adapter.as_deref()),
+    };
+
     // Prefer vendor-specific stats (NVIDIA via nvidia-smi, AMD via rocm-smi/amd-smi)
-    let gpu_stats = crate::nn::gpu::poll_gpu_stats();
-    let adapter_name = crate::nn::gpu::detect_wgpu_adapters()
-        .into_iter()
-        .next()
-        .map(|a| a.name);
+    let gpu_stats = crate::nn::gpu::poll_gpu_stats(selected_adapter);
+    let adapter_name = selected_adapter.map(str::to_string).or_else(|| {
+        crate::nn::gpu::detect_wgpu_adapters()
+            .into_iter()
+            .next()
+            .map(|a| a.name)
+    });
 
     // Populate initial GPU detection info from stats or adapter name
     if let Ok(mut stats) = progress.compute_stats.lock() {
@@ -84,10 +155,19 @@ pub fn train(market_data: &MarketData, progress: &TrainingProgress, use_gpu: boo
     }
 
     if use_gpu {
-        match crate::nn::gpu::validate_gpu() {
+        match crate::nn::gpu::validate_gpu(selected_adapter) {
             Ok(gpu_name) => {
+                let half_precision = training_config.mixed_precision
+                    && match crate::nn::gpu::validate_gpu_f16(selected_adapter) {
+                        Ok(()) => true,
+                        Err(reason) => {
+                            tracing::warn!("Half precision unavailable: {}. Training in f32.", reason);
+                            false
+                        }
+                    };
                 let backend_label = format!(
-                    "WGPU GPU: {}",
+                    "WGPU GPU{}: {}",
+                    if half_precision { " (f16)" } else { "" },
                     gpu_stats
                         .as_ref()
                         .map(|i| i.name.as_str())
@@ -99,8 +179,15 @@ pub fn train(market_data: &MarketData, progress: &TrainingProgress, use_gpu: boo
                 }
 
                 tracing::info!("GPU validation passed ({}). Starting GPU training.", gpu_name);
-                let device = <Wgpu as burn::tensor::backend::Backend>::Device::default();
-                train_impl::<GpuBackend>(device, market_data, progress, feature_flags);
+                let telemetry_done = spawn_gpu_telemetry(progress, selected_adapter);
+                let batch_size = resolve_batch_size(training_config, feature_flags, gpu_stats.as_ref());
+                let device = crate::nn::gpu::wgpu_device(selected_adapter);
+                if half_precision {
+                    train_impl::<GpuHalfBackend>(device, market_data, progress, feature_flags, training_config, batch_size, persist);
+                } else {
+                    train_impl::<GpuBackend>(device, market_data, progress, feature_flags, training_config, batch_size, persist);
+                }
+                telemetry_done.store(true, Ordering::SeqCst);
             }
             Err(reason) => {
                 tracing::warn!("GPU validation failed: {}. Falling back to CPU.", reason);
@@ -109,8 +196,9 @@ pub fn train(market_data: &MarketData, progress: &TrainingProgress, use_gpu: boo
                     stats.using_gpu = false;
                     stats.gpu_detected = false;
                 }
+                let batch_size = resolve_batch_size(training_config, feature_flags, None);
                 let device = <NdArray as burn::tensor::backend::Backend>::Device::default();
-                train_impl::<CpuBackend>(device, market_data, progress, feature_flags);
+                train_impl::<CpuBackend>(device, market_data, progress, feature_flags, training_config, batch_size, persist);
             }
         }
     } else {
@@ -120,9 +208,33 @@ pub fn train(market_data: &MarketData, progress: &TrainingProgress, use_gpu: boo
         }
 
         tracing::info!("Starting CPU training with NdArray backend");
+        let batch_size = resolve_batch_size(training_config, feature_flags, None);
         let device = <NdArray as burn::tensor::backend::Backend>::Device::default();
-        train_impl::<CpuBackend>(device, market_data, progress, feature_flags);
+        train_impl::<CpuBackend>(device, market_data, progress, feature_flags, training_config, batch_size, persist);
+    }
+}
+
+/// Batch size for a run: the configured override, else a VRAM-aware choice on GPU,
+/// else `config::NN_BATCH_SIZE`.
+pub fn resolve_batch_size(
+    training_config: &NnTrainingConfig,
+    feature_flags: &crate::data::models::NnFeatureFlags,
+    gpu_stats: Option<&crate::nn::gpu::GpuInfo>,
+) -> usize {
+    if let Some(batch_size) = training_config.batch_size {
+        return batch_size.max(1);
     }
+    gpu_stats
+        .and_then(|info| {
+            crate::nn::gpu::recommend_batch_size(
+                info,
+                training_config.lookback,
+                num_features(feature_flags),
+                training_config.hidden_size,
+                output_size(training_config.target_mode),
+            )
+        })
+        .unwrap_or(config::NN_BATCH_SIZE)
 }
 
 /// Generic training implementation that works with any autodiff backend.
@@ -131,7 +243,12 @@ fn train_impl<B: AutodiffBackend>(
     market_data: &MarketData,
     progress: &TrainingProgress,
     feature_flags: &crate::data::models::NnFeatureFlags,
+    training_config: &NnTrainingConfig,
+    batch_size: usize,
+    persist: bool,
 ) {
+    let total_epochs = training_config.epochs;
+
     // System info for compute stats
     let mut sys = System::new_all();
     sys.refresh_all();
@@ -140,12 +257,12 @@ fn train_impl<B: AutodiffBackend>(
     // Update status
     set_status(progress, TrainingStatus::Training {
         epoch: 0,
-        total_epochs: config::NN_EPOCHS,
+        total_epochs,
         loss: f64::NAN,
     });
 
     // Build dataset
-    let dataset = build_dataset(market_data, config::NN_LOOKBACK_DAYS, config::NN_FORWARD_DAYS, feature_flags);
+    let mut dataset = build_dataset(market_data, training_config.lookback, config::NN_FORWARD_DAYS, feature_flags);
 
     if dataset.samples.is_empty() {
         set_status(progress, TrainingStatus::Error(
@@ -154,6 +271,28 @@ fn train_impl<B: AutodiffBackend>(
         return;
     }
 
+    if training_config.target_mode == NnTargetMode::Regression {
+        let missing = match training_config.vol_target {
+            NnVolTarget::Benchmark => market_data
+                .benchmark
+                .is_none()
+                .then(|| format!("benchmark ({})", crate::config::benchmark_symbol())),
+            NnVolTarget::Sector(i) => (i >= market_data.sectors.len()).then(|| training_config.vol_target.label()),
+            _ => None,
+        };
+        if let Some(series) = missing {
+            set_status(progress, TrainingStatus::Error(format!(
+                "Prediction target needs {} data, which is not loaded.",
+                series
+            )));
+            return;
+        }
+    }
+
+    if let Ok(mut stats) = progress.compute_stats.lock() {
+        stats.dataset_bytes = dataset.heap_bytes();
+    }
+
     let total = dataset.samples.len();
     let train_size = (total as f64 * 0.8) as usize;
 
@@ -164,24 +303,61 @@ fn train_impl<B: AutodiffBackend>(
         return;
     }
 
-    // Split chronologically
-    let train_samples = dataset.samples[..train_size].to_vec();
-    let _val_samples = dataset.samples[train_size..].to_vec();
+    // No point in batches larger than the training split
+    let batch_size = batch_size.min(train_size);
+    tracing::info!("Training with batch size {}", batch_size);
+    if let Ok(mut stats) = progress.compute_stats.lock() {
+        stats.batch_size = batch_size;
+    }
+
+    // Standardize features using statistics from the training split only: the panel
+    // rows its windows cover
+    let train_rows = dataset.samples[train_size - 1].rows.end;
+    let feature_stats = FeatureStats::fit(dataset.panel.slice(s![..train_rows, ..]));
+    dataset.standardize(&feature_stats);
+
+    if let Some(idx) = dataset.samples.iter().position(|s| !sample_is_finite(dataset.features(s), s)) {
+        set_status(progress, TrainingStatus::Error(format!(
+            "Training data contains NaN/Inf values (sample {} of {}). Check the loaded market data.",
+            idx + 1,
+            total
+        )));
+        return;
+    }
 
-    let train_dataset = crate::nn::dataset::VolDataset { samples: train_samples };
+    // Regime cut-offs also come from the training split only
+    let target_mode = training_config.target_mode;
+    let regime_thresholds = match target_mode {
+        NnTargetMode::Regression => None,
+        NnTargetMode::RegimeClassification => Some(RegimeThresholds::fit(&dataset.samples[..train_size])),
+    };
 
-    let batcher = VolBatcher::<B>::new(device.clone());
+    // Split chronologically; both splits share the standardized panel
+    let train_dataset = dataset.subset(0..train_size);
+    let val_samples = &dataset.samples[train_size..];
+    let mut val_batcher = VolBatcher::<B::InnerBackend>::new(
+        <B::InnerBackend as burn::tensor::backend::Backend>::Device::default(),
+        dataset.panel.clone(),
+    )
+    .with_target(training_config.vol_target);
+    let mut batcher = VolBatcher::<B>::new(device.clone(), dataset.panel.clone())
+        .with_target(training_config.vol_target)
+        .with_augmentation(training_config.augmentation);
+    if let Some(ref regimes) = regime_thresholds {
+        val_batcher = val_batcher.with_regimes(regimes.clone());
+        batcher = batcher.with_regimes(regimes.clone());
+    }
 
     let dataloader = DataLoaderBuilder::new(batcher)
-        .batch_size(config::NN_BATCH_SIZE)
+        .batch_size(batch_size)
         .shuffle(42)
         .build(train_dataset);
 
     // Initialize model
     let model_config = VolPredictionModelConfig {
-        input_size: NUM_FEATURES,
-        hidden_size: config::NN_HIDDEN_SIZE,
-        output_size: OUTPUT_SIZE,
+        input_size: num_features(feature_flags),
+        hidden_size: training_config.hidden_size,
+        output_size: output_size(target_mode),
     };
     let mut model = model_config.init::<B>(&device);
 
@@ -191,13 +367,44 @@ fn train_impl<B: AutodiffBackend>(
     update_compute_stats(progress, &mut sys, total_memory_mb, 0, 0.0, param_count);
 
     // Optimizer
-    let mut optim = AdamConfig::new().init();
+    let mut optim = AdamConfig::new()
+        .with_grad_clipping(
+            training_config
+                .grad_clip_norm
+                .map(|norm| GradientClippingConfig::Norm(norm as f32)),
+        )
+        .init();
+
+    // Optional per-epoch metrics export (user runs only, not sweep trials)
+    let mut metrics_writer = if persist && training_config.export_metrics {
+        match MetricsWriter::create(training_config.export_tensorboard) {
+            Ok(writer) => {
+                tracing::info!("Writing training metrics to {}", writer.dir().display());
+                Some(writer)
+            }
+            Err(e) => {
+                tracing::warn!("Metrics export disabled: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Half-precision backends need dynamic loss scaling so small gradients don't underflow
+    let mut loss_scaler = (std::mem::size_of::<B::FloatElem>() < 4).then(LossScaler::new);
 
     // Training loop
     let mut best_loss = f64::INFINITY;
-    for epoch in 0..config::NN_EPOCHS {
+    for epoch in 0..total_epochs {
+        if progress.is_stopped() {
+            tracing::info!("Training stopped at epoch {}", epoch);
+            set_status(progress, TrainingStatus::Idle);
+            return;
+        }
+
         // Pause check: spin-wait while paused
-        while progress.is_paused() {
+        while progress.is_paused() && !progress.is_stopped() {
             if let Ok(status) = progress.status.lock() {
                 if matches!(*status, TrainingStatus::Training { .. }) {
                     drop(status);
@@ -207,7 +414,7 @@ fn train_impl<B: AutodiffBackend>(
                         .unwrap_or(f64::NAN);
                     set_status(progress, TrainingStatus::Paused {
                         epoch,
-                        total_epochs: config::NN_EPOCHS,
+                        total_epochs,
                         loss: current_loss,
                     });
                 }
@@ -218,7 +425,7 @@ fn train_impl<B: AutodiffBackend>(
         // When resuming from pause, set status back to Training
         set_status(progress, TrainingStatus::Training {
             epoch,
-            total_epochs: config::NN_EPOCHS,
+            total_epochs,
             loss: progress.losses.lock()
                 .ok()
                 .and_then(|l| l.last().copied())
@@ -229,13 +436,14 @@ fn train_impl<B: AutodiffBackend>(
         let mut epoch_loss = 0.0;
         let mut batch_count = 0;
         let mut samples_this_epoch = 0_usize;
+        let mut grad_norm_sum = 0.0;
 
         for batch in dataloader.iter() {
             // Check pause mid-epoch too; set Paused so UI updates
-            while progress.is_paused() {
+            while progress.is_paused() && !progress.is_stopped() {
                 set_status(progress, TrainingStatus::Paused {
                     epoch,
-                    total_epochs: config::NN_EPOCHS,
+                    total_epochs,
                     loss: progress.losses.lock()
                         .ok()
                         .and_then(|l| l.last().copied())
@@ -246,15 +454,45 @@ fn train_impl<B: AutodiffBackend>(
 
             let batch_size = batch.inputs.dims()[0];
             let output = model.forward(batch.inputs);
-            let loss = mse_loss(output, batch.targets);
+            let loss = training_loss(output, batch.targets, training_config);
 
             let loss_val = loss.clone().into_data().to_vec::<f32>().unwrap_or_default();
             let loss_scalar = loss_val.first().copied().unwrap_or(f32::NAN) as f64;
 
+            // Abort on divergence instead of training on NaN for the remaining epochs
+            if !loss_scalar.is_finite() {
+                set_status(progress, TrainingStatus::Error(format!(
+                    "Loss diverged to {} at epoch {}, batch {}. Lower the learning rate or enable gradient clipping.",
+                    loss_scalar,
+                    epoch + 1,
+                    batch_count + 1
+                )));
+                return;
+            }
+
             // Backward pass
-            let grads = loss.backward();
-            let grads = GradientsParams::from_grads(grads, &model);
-            model = optim.step(config::NN_LEARNING_RATE, model, grads);
+            match loss_scaler {
+                None => {
+                    let grads = GradientsParams::from_grads(loss.backward(), &model);
+                    // Only the metrics export reports the norm, and it costs a device sync
+                    if metrics_writer.is_some() {
+                        grad_norm_sum += grad_norm(&model, &grads);
+                    }
+                    model = optim.step(training_config.learning_rate, model, grads);
+                }
+                Some(ref mut scaler) => {
+                    let scale = scaler.scale;
+                    let mut grads = GradientsParams::from_grads((loss * scale).backward(), &model);
+                    let scaled_norm = grad_norm(&model, &grads);
+                    if scaler.update(scaled_norm.is_finite()) {
+                        scale_grads(&model, &mut grads, 1.0 / scale);
+                        grad_norm_sum += scaled_norm / scale;
+                        model = optim.step(training_config.learning_rate, model, grads);
+                    } else {
+                        tracing::debug!("Gradient overflow at loss scale {}; skipping step", scale);
+                    }
+                }
+            }
 
             epoch_loss += loss_scalar;
             batch_count += 1;
@@ -280,30 +518,70 @@ fn train_impl<B: AutodiffBackend>(
             best_loss = avg_loss;
         }
 
+        let val_loss = validation_loss(&model.valid(), val_samples, &val_batcher, training_config, batch_size);
+        let mean_grad_norm = if batch_count > 0 {
+            grad_norm_sum / batch_count as f64
+        } else {
+            f64::NAN
+        };
+
+        if let Some(ref mut writer) = metrics_writer {
+            let metrics = EpochMetrics {
+                epoch: epoch + 1,
+                train_loss: avg_loss,
+                val_loss,
+                learning_rate: training_config.learning_rate,
+                grad_norm: mean_grad_norm,
+            };
+            if let Err(e) = writer.write_epoch(&metrics) {
+                tracing::warn!("Failed to write epoch metrics: {}", e);
+                metrics_writer = None;
+            }
+        }
+
         // Update progress
         if let Ok(mut losses) = progress.losses.lock() {
             losses.push(avg_loss);
         }
+        if let Ok(mut val_losses) = progress.val_losses.lock() {
+            val_losses.push(val_loss);
+        }
         set_status(progress, TrainingStatus::Training {
             epoch: epoch + 1,
-            total_epochs: config::NN_EPOCHS,
+            total_epochs,
             loss: avg_loss,
         });
 
         // Update compute stats (including live GPU stats via nvidia-smi)
         update_compute_stats(progress, &mut sys, total_memory_mb, epoch_ms, samples_per_sec, param_count);
-        update_gpu_live_stats(progress);
     }
 
     // Generate predictions using the trained model in inference mode
     let valid_model = model.valid();
     let inference_device = <B::InnerBackend as burn::tensor::backend::Backend>::Device::default();
-    generate_predictions::<B::InnerBackend>(&valid_model, market_data, &inference_device, progress, feature_flags);
+    generate_predictions::<B::InnerBackend>(
+        &valid_model,
+        market_data,
+        &inference_device,
+        progress,
+        feature_flags,
+        &feature_stats,
+        training_config,
+    );
 
     // Save model to disk BEFORE setting Complete status so the UI's load_model()
     // call is guaranteed to find the file on the very first repaint after Complete.
-    if let Err(e) = crate::nn::persistence::save_model(&valid_model, best_loss) {
-        tracing::warn!("Failed to save trained model: {}", e);
+    if persist {
+        if let Err(e) = crate::nn::persistence::save_model(
+            &valid_model,
+            best_loss,
+            &feature_stats,
+            regime_thresholds.as_ref(),
+            feature_flags,
+            training_config,
+        ) {
+            tracing::warn!("Failed to save trained model: {}", e);
+        }
     }
 
     set_status(progress, TrainingStatus::Complete { final_loss: best_loss });
@@ -334,9 +612,26 @@ fn update_compute_stats(
     }
 }
 
-/// Poll nvidia-smi for live GPU utilization, VRAM, and temperature
-fn update_gpu_live_stats(progress: &TrainingProgress) {
-    if let Some(info) = crate::nn::gpu::poll_gpu_stats() {
+/// Poll GPU telemetry on a background thread for the duration of a GPU run, so stats stay
+/// live between epochs and the vendor tool's latency never stalls training.
+/// Set the returned flag to stop polling.
+fn spawn_gpu_telemetry(progress: &TrainingProgress, adapter_name: Option<&str>) -> Arc<AtomicBool> {
+    let done = Arc::new(AtomicBool::new(false));
+    let thread_done = done.clone();
+    let progress = progress.clone();
+    let adapter_name = adapter_name.map(str::to_string);
+    std::thread::spawn(move || {
+        while !thread_done.load(Ordering::SeqCst) && !progress.is_stopped() {
+            update_gpu_live_stats(&progress, adapter_name.as_deref());
+            std::thread::sleep(GPU_TELEMETRY_INTERVAL);
+        }
+    });
+    done
+}
+
+/// Poll nvidia-smi / rocm-smi for live GPU utilization, VRAM, and temperature
+fn update_gpu_live_stats(progress: &TrainingProgress, adapter_name: Option<&str>) {
+    if let Some(info) = crate::nn::gpu::poll_gpu_stats(adapter_name) {
         if let Ok(mut stats) = progress.compute_stats.lock() {
             stats.gpu_vram_used_mb = Some(info.vram_used_mb);
             stats.gpu_utilization_percent = Some(info.utilization_percent);
@@ -345,25 +640,227 @@ fn update_gpu_live_stats(progress: &TrainingProgress) {
     }
 }
 
+/// Dynamic loss scale for half-precision training: halves on gradient overflow (skipping
+/// that step) and doubles after `LOSS_SCALE_GROWTH_INTERVAL` consecutive clean steps.
+struct LossScaler {
+    scale: f64,
+    good_steps: usize,
+}
+
+impl LossScaler {
+    fn new() -> Self {
+        Self { scale: LOSS_SCALE_INIT, good_steps: 0 }
+    }
+
+    /// Record a step's outcome and return whether its gradients should be applied.
+    fn update(&mut self, grads_finite: bool) -> bool {
+        if !grads_finite {
+            self.scale = (self.scale / 2.0).max(LOSS_SCALE_MIN);
+            self.good_steps = 0;
+            return false;
+        }
+        self.good_steps += 1;
+        if self.good_steps >= LOSS_SCALE_GROWTH_INTERVAL {
+            self.scale = (self.scale * 2.0).min(LOSS_SCALE_MAX);
+            self.good_steps = 0;
+        }
+        true
+    }
+}
+
+/// Multiply every parameter gradient by `factor` (used to unscale loss-scaled gradients)
+fn scale_grads<B: AutodiffBackend, M: AutodiffModule<B>>(model: &M, grads: &mut GradientsParams, factor: f64) {
+    struct GradScaleVisitor<'a> {
+        grads: &'a mut GradientsParams,
+        factor: f64,
+    }
+
+    impl<B: AutodiffBackend> ModuleVisitor<B> for GradScaleVisitor<'_> {
+        fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+            if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+                self.grads.register::<B::InnerBackend, D>(id, grad.mul_scalar(self.factor));
+            }
+        }
+    }
+
+    model.visit(&mut GradScaleVisitor { grads, factor });
+}
+
+/// Global L2 norm of all parameter gradients. The squares are summed on the device and
+/// read back once.
+fn grad_norm<B: AutodiffBackend, M: AutodiffModule<B>>(model: &M, grads: &GradientsParams) -> f64 {
+    struct GradNormVisitor<'a, B: AutodiffBackend> {
+        grads: &'a GradientsParams,
+        sq_sum: Option<Tensor<B::InnerBackend, 1>>,
+    }
+
+    impl<B: AutodiffBackend> ModuleVisitor<B> for GradNormVisitor<'_, B> {
+        fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+            if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+                let sq = grad.powf_scalar(2.0).sum();
+                self.sq_sum = Some(match self.sq_sum.take() {
+                    Some(sum) => sum + sq,
+                    None => sq,
+                });
+            }
+        }
+    }
+
+    let mut visitor = GradNormVisitor::<B> { grads, sq_sum: None };
+    model.visit(&mut visitor);
+    visitor.sq_sum.map_or(0.0, |sum| sum.into_scalar().elem::<f64>().sqrt())
+}
+
+/// True if every feature in the sample's window and every target value is finite
+fn sample_is_finite(features: ArrayView2<f64>, sample: &VolSample) -> bool {
+    features.iter().all(|v| v.is_finite())
+        && sample.target_vol.is_finite()
+        && sample.target_sector_vols.iter().all(|v| v.is_finite())
+        && sample.target_benchmark_vol.is_finite()
+        && sample.target_randomness.iter().all(|v| v.is_finite())
+        && sample.target_kurtosis.iter().all(|v| v.is_finite())
+}
+
+/// Training objective over the validation split, evaluated in inference mode.
+/// Returns NaN if the split is empty.
+fn validation_loss<B: Backend>(
+    model: &crate::nn::model::VolPredictionModel<B>,
+    samples: &[VolSample],
+    batcher: &VolBatcher<B>,
+    training_config: &NnTrainingConfig,
+    batch_size: usize,
+) -> f64 {
+    let mut loss_sum = 0.0;
+    let mut count = 0_usize;
+    for chunk in samples.chunks(batch_size.max(1)) {
+        let batch = batcher.batch(chunk.to_vec());
+        let loss = training_loss(model.forward(batch.inputs), batch.targets, training_config);
+        let loss_val = loss.into_data().to_vec::<f32>().unwrap_or_default();
+        // Every batch loss is a mean, so weight by batch size for the split-wide mean
+        loss_sum += loss_val.first().copied().unwrap_or(f32::NAN) as f64 * chunk.len() as f64;
+        count += chunk.len();
+    }
+    if count == 0 { f64::NAN } else { loss_sum / count as f64 }
+}
+
+/// Loss for the configured training target and loss function
+fn training_loss<B: Backend>(
+    predictions: Tensor<B, 2>,
+    targets: Tensor<B, 2>,
+    training_config: &NnTrainingConfig,
+) -> Tensor<B, 1> {
+    if training_config.target_mode == NnTargetMode::RegimeClassification {
+        return regime_cross_entropy(predictions, targets);
+    }
+    match training_config.loss {
+        NnLossFunction::Mse => mse_loss(predictions, targets),
+        NnLossFunction::Mae => mae_loss(predictions, targets),
+        NnLossFunction::Huber => huber_loss(predictions, targets, config::NN_HUBER_DELTA),
+        // QLIKE needs a positive vol level; change / log targets fall back to MSE
+        NnLossFunction::Qlike if training_config.vol_target.is_level() => qlike_loss(predictions, targets),
+        NnLossFunction::Qlike => mse_loss(predictions, targets),
+    }
+}
+
 /// Mean squared error loss
-fn mse_loss<B: AutodiffBackend>(
-    predictions: burn::tensor::Tensor<B, 2>,
-    targets: burn::tensor::Tensor<B, 2>,
-) -> burn::tensor::Tensor<B, 1> {
+fn mse_loss<B: Backend>(predictions: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
     let diff = predictions - targets;
     let sq = diff.clone() * diff;
     sq.mean().unsqueeze()
 }
 
+/// Mean absolute error loss
+fn mae_loss<B: Backend>(predictions: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+    (predictions - targets).abs().mean().unsqueeze()
+}
+
+/// Huber loss: `0.5 * d²` for `|d| <= delta`, `delta * (|d| - 0.5 * delta)` beyond.
+fn huber_loss<B: Backend>(predictions: Tensor<B, 2>, targets: Tensor<B, 2>, delta: f64) -> Tensor<B, 1> {
+    let abs = (predictions - targets).abs();
+    let quadratic = abs.clone().clamp_max(delta);
+    let linear = abs - quadratic.clone();
+    (quadratic.clone() * quadratic * 0.5 + linear * delta).mean().unsqueeze()
+}
+
+/// QLIKE on the vol output (column 0) plus MSE on the remaining auxiliary outputs.
+/// QLIKE is evaluated on variances: `r - ln(r) - 1` with `r = realized² / predicted²`.
+fn qlike_loss<B: Backend>(predictions: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+    const EPS: f64 = 1e-8;
+    let [batch, cols] = predictions.dims();
+    let pred_vol = predictions.clone().slice([0..batch, 0..1]);
+    let target_vol = targets.clone().slice([0..batch, 0..1]);
+    let pred_var = (pred_vol.clone() * pred_vol).clamp_min(EPS);
+    let target_var = (target_vol.clone() * target_vol).clamp_min(EPS);
+    let ratio = target_var / pred_var;
+    let qlike = (ratio.clone() - ratio.log() - 1.0).mean();
+    if cols <= 1 {
+        return qlike.unsqueeze();
+    }
+    let aux = mse_loss(
+        predictions.slice([0..batch, 1..cols]),
+        targets.slice([0..batch, 1..cols]),
+    );
+    qlike.unsqueeze() + aux
+}
+
+/// Cross-entropy over per-sector regime logits.
+/// `logits` and one-hot `targets` are [batch, sectors * NUM_REGIMES].
+fn regime_cross_entropy<B: Backend>(logits: Tensor<B, 2>, targets: Tensor<B, 2>) -> Tensor<B, 1> {
+    let [batch, cols] = logits.dims();
+    let rows = batch * cols / NUM_REGIMES;
+    let log_probs = log_softmax(logits.reshape([rows, NUM_REGIMES]), 1);
+    let nll = (log_probs * targets.reshape([rows, NUM_REGIMES])).sum_dim(1).neg();
+    nll.mean().unsqueeze()
+}
+
 /// Run inference with a trained model and return predictions for each sector.
 /// Public for use when loading a saved model from disk.
+///
+/// `metadata` supplies the checkpoint's feature statistics, lookback, and target mode;
+/// pass `None` only for legacy checkpoints trained on raw features with default hyperparameters.
 pub fn run_inference(
     model: &crate::nn::model::VolPredictionModel<burn::backend::NdArray>,
     market_data: &MarketData,
     feature_flags: &crate::data::models::NnFeatureFlags,
+    metadata: Option<&ModelMetadata>,
 ) -> NnPredictions {
     let device = <burn::backend::NdArray as burn::tensor::backend::Backend>::Device::default();
-    run_inference_impl(model, market_data, &device, feature_flags)
+    let feature_stats = metadata.and_then(|m| m.feature_stats.as_ref());
+    let training_config = metadata.map(|m| m.training_config.clone()).unwrap_or_default();
+    run_inference_impl(model, market_data, &device, feature_flags, feature_stats, &training_config)
+}
+
+/// Run inference with a weight-quantized model (inference-only path).
+pub fn run_quantized_inference(
+    model: &crate::nn::quantized::QuantizedModel,
+    market_data: &MarketData,
+    feature_flags: &crate::data::models::NnFeatureFlags,
+    metadata: Option<&ModelMetadata>,
+) -> NnPredictions {
+    let feature_stats = metadata.and_then(|m| m.feature_stats.as_ref());
+    let training_config = metadata.map(|m| m.training_config.clone()).unwrap_or_default();
+    let Some((window, last_sample)) = latest_window(market_data, feature_flags, feature_stats, &training_config) else {
+        return NnPredictions::default();
+    };
+    let pred_val = model.forward(window.view());
+    predictions_from_output(&pred_val, &last_sample, market_data, &training_config)
+}
+
+/// The most recent (standardized) input window and its sample, or `None` if there is
+/// not enough data.
+fn latest_window(
+    market_data: &MarketData,
+    feature_flags: &crate::data::models::NnFeatureFlags,
+    feature_stats: Option<&FeatureStats>,
+    training_config: &NnTrainingConfig,
+) -> Option<(Array2<f64>, VolSample)> {
+    let mut dataset = build_dataset(market_data, training_config.lookback, config::NN_FORWARD_DAYS, feature_flags);
+    let last = dataset.samples.pop()?;
+    let mut window = dataset.features(&last).to_owned();
+    if let Some(stats) = feature_stats {
+        stats.apply(window.view_mut());
+    }
+    Some((window, last))
 }
 
 fn run_inference_impl<B: burn::tensor::backend::Backend>(
@@ -371,58 +868,100 @@ fn run_inference_impl<B: burn::tensor::backend::Backend>(
     market_data: &MarketData,
     device: &B::Device,
     feature_flags: &crate::data::models::NnFeatureFlags,
+    feature_stats: Option<&FeatureStats>,
+    training_config: &NnTrainingConfig,
 ) -> NnPredictions {
-    let dataset = build_dataset(market_data, config::NN_LOOKBACK_DAYS, config::NN_FORWARD_DAYS, feature_flags);
-
-    if let Some(last_sample) = dataset.samples.last() {
-        let seq_len = last_sample.features.len();
-        let num_features = last_sample.features.first().map(|f| f.len()).unwrap_or(0);
-
-        let mut input_data: Vec<f32> = Vec::with_capacity(seq_len * num_features);
-        for step in &last_sample.features {
-            for &f in step {
-                input_data.push(f as f32);
-            }
-        }
+    let Some((window, last_sample)) = latest_window(market_data, feature_flags, feature_stats, training_config) else {
+        return NnPredictions::default();
+    };
 
-        let input = burn::tensor::Tensor::<B, 1>::from_floats(input_data.as_slice(), device)
-            .reshape([1_usize, seq_len, num_features]);
+    let (seq_len, num_features) = window.dim();
+    let input_data: Vec<f32> = window.iter().map(|&f| f as f32).collect();
 
-        let pred = model.forward(input);
-        let pred_val = pred.into_data().to_vec::<f32>().unwrap_or_default();
+    let input = burn::tensor::Tensor::<B, 1>::from_floats(input_data.as_slice(), device)
+        .reshape([1_usize, seq_len, num_features]);
 
-        let predicted_vol = pred_val.get(0).copied().unwrap_or(0.0) as f64;
-        let vol: Vec<(String, f64)> = market_data
-            .sectors
-            .iter()
-            .map(|s| (s.symbol.clone(), predicted_vol))
-            .collect();
+    let pred_val = model.forward(input).into_data().to_vec::<f32>().unwrap_or_default();
+    predictions_from_output(&pred_val, &last_sample, market_data, training_config)
+}
 
-        let randomness: Vec<(String, f64)> = market_data
-            .sectors
+/// Turn one window's raw model outputs into per-sector predictions.
+fn predictions_from_output(
+    pred_val: &[f32],
+    last_sample: &VolSample,
+    market_data: &MarketData,
+    training_config: &NnTrainingConfig,
+) -> NnPredictions {
+    if training_config.target_mode == NnTargetMode::RegimeClassification {
+        let n_sectors = market_data.sectors.len().min(11);
+        let regime_probs = market_data.sectors[..n_sectors]
             .iter()
             .enumerate()
             .map(|(i, s)| {
-                let entropy = pred_val.get(1 + i).copied().unwrap_or(0.0) as f64;
-                (s.symbol.clone(), entropy)
+                let logit = |c: usize| pred_val.get(i * NUM_REGIMES + c).copied().unwrap_or(0.0) as f64;
+                // Softmax over the sector's three class logits
+                let max = logit(0).max(logit(1)).max(logit(2));
+                let exp = [0, 1, 2].map(|c| (logit(c) - max).exp());
+                let sum: f64 = exp.iter().sum();
+                (s.symbol.clone(), exp.map(|e| e / sum))
             })
             .collect();
+        return NnPredictions {
+            regime_probs,
+            ..NnPredictions::default()
+        };
+    }
 
-        let mut kurtosis = Vec::with_capacity(market_data.sectors.len());
-        for (i, s) in market_data.sectors.iter().enumerate() {
-            let k = pred_val.get(12 + i * 2).copied().unwrap_or(0.0) as f64;
-            let sk = pred_val.get(12 + i * 2 + 1).copied().unwrap_or(0.0) as f64;
-            kurtosis.push((s.symbol.clone(), k, sk));
+    let target = training_config.vol_target;
+    let predicted_vol = output_to_vol(
+        pred_val.first().copied().unwrap_or(0.0) as f64,
+        reference_vol(last_sample, target),
+        target,
+    );
+    // The vol output forecasts one series; label it with what it is
+    let vol: Vec<(String, f64)> = match target {
+        NnVolTarget::Sector(i) => market_data
+            .sectors
+            .get(i)
+            .map(|s| vec![(s.symbol.clone(), predicted_vol)])
+            .unwrap_or_default(),
+        NnVolTarget::Benchmark => {
+            let symbol = market_data
+                .benchmark
+                .as_ref()
+                .map_or_else(|| crate::config::benchmark_symbol().to_string(), |b| b.symbol.clone());
+            vec![(symbol, predicted_vol)]
         }
+        _ => market_data
+            .sectors
+            .iter()
+            .map(|s| (s.symbol.clone(), predicted_vol))
+            .collect(),
+    };
 
-        return NnPredictions {
-            vol,
-            randomness,
-            kurtosis,
-        };
+    let randomness: Vec<(String, f64)> = market_data
+        .sectors
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let entropy = pred_val.get(1 + i).copied().unwrap_or(0.0) as f64;
+            (s.symbol.clone(), entropy)
+        })
+        .collect();
+
+    let mut kurtosis = Vec::with_capacity(market_data.sectors.len());
+    for (i, s) in market_data.sectors.iter().enumerate() {
+        let k = pred_val.get(12 + i * 2).copied().unwrap_or(0.0) as f64;
+        let sk = pred_val.get(12 + i * 2 + 1).copied().unwrap_or(0.0) as f64;
+        kurtosis.push((s.symbol.clone(), k, sk));
     }
 
-    NnPredictions::default()
+    NnPredictions {
+        vol,
+        randomness,
+        kurtosis,
+        regime_probs: vec![],
+    }
 }
 
 /// Generate predictions for each sector using the trained model
@@ -432,8 +971,10 @@ fn generate_predictions<B: burn::tensor::backend::Backend>(
     device: &B::Device,
     progress: &TrainingProgress,
     feature_flags: &crate::data::models::NnFeatureFlags,
+    feature_stats: &FeatureStats,
+    training_config: &NnTrainingConfig,
 ) {
-    let predictions = run_inference_impl(model, market_data, device, feature_flags);
+    let predictions = run_inference_impl(model, market_data, device, feature_flags, Some(feature_stats), training_config);
     if let Ok(mut preds) = progress.predictions.lock() {
         *preds = predictions;
     }
@@ -444,3 +985,73 @@ fn set_status(progress: &TrainingProgress, status: TrainingStatus) {
         *s = status;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(t: Tensor<NdArray, 1>) -> f64 {
+        t.into_data().to_vec::<f32>().unwrap()[0] as f64
+    }
+
+    fn tensor(vals: &[f32]) -> Tensor<NdArray, 2> {
+        Tensor::<NdArray, 1>::from_floats(vals, &Default::default()).reshape([1_usize, vals.len()])
+    }
+
+    #[test]
+    fn test_loss_scaler_backs_off_and_grows() {
+        let mut scaler = LossScaler::new();
+        assert!(!scaler.update(false));
+        assert_eq!(scaler.scale, LOSS_SCALE_INIT / 2.0);
+        for _ in 0..LOSS_SCALE_GROWTH_INTERVAL {
+            assert!(scaler.update(true));
+        }
+        assert_eq!(scaler.scale, LOSS_SCALE_INIT);
+    }
+
+    #[test]
+    fn test_grad_norm_covers_every_parameter() {
+        type B = Autodiff<NdArray>;
+        let device = Default::default();
+        let linear = burn::nn::LinearConfig::new(2, 1).init::<B>(&device);
+        let input = Tensor::<B, 2>::from_floats([[3.0, 4.0]], &device);
+        let grads = GradientsParams::from_grads(linear.forward(input).sum().backward(), &linear);
+        // d/dW = input, d/db = 1
+        assert!((grad_norm(&linear, &grads) - 26.0_f64.sqrt()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_sample_is_finite_detects_nan() {
+        let mut features = Array2::from_elem((3, 2), 0.1);
+        let sample = VolSample {
+            rows: 0..3,
+            target_vol: 0.2,
+            current_vol: 0.2,
+            target_sector_vols: vec![0.2; 11],
+            current_sector_vols: vec![],
+            target_benchmark_vol: 0.0,
+            current_benchmark_vol: 0.0,
+            target_randomness: vec![],
+            target_kurtosis: vec![],
+        };
+        assert!(sample_is_finite(features.view(), &sample));
+        features[[1, 0]] = f64::NAN;
+        assert!(!sample_is_finite(features.view(), &sample));
+    }
+
+    #[test]
+    fn test_huber_quadratic_then_linear() {
+        // Errors 0.05 (quadratic region) and 0.3 (linear region) with delta 0.1
+        let loss = scalar(huber_loss(tensor(&[0.05, 0.3]), tensor(&[0.0, 0.0]), 0.1));
+        let expected = (0.5 * 0.05 * 0.05 + 0.1 * (0.3 - 0.05)) / 2.0;
+        assert!((loss - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_qlike_zero_when_exact_and_asymmetric() {
+        assert!(scalar(qlike_loss(tensor(&[0.2, 1.0]), tensor(&[0.2, 1.0]))).abs() < 1e-6);
+        let under = scalar(qlike_loss(tensor(&[0.1]), tensor(&[0.2])));
+        let over = scalar(qlike_loss(tensor(&[0.3]), tensor(&[0.2])));
+        assert!(under > over && over > 0.0);
+    }
+}
@@ -0,0 +1,223 @@
+//! Notification center. Fetches, analysis, training and every view report through
+//! here instead of overwriting one status string, so the latest notice still shows in
+//! the status bar while earlier ones stay reviewable in the Notifications window, with
+//! their time, severity, where they came from and, for failed work, a retry.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+
+/// Notices kept for review; older ones are dropped
+const MAX_NOTICES: usize = 200;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Info, Severity::Warning, Severity::Error];
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "ℹ",
+            Self::Warning => "⚠",
+            Self::Error => "⛔",
+        }
+    }
+}
+
+/// Part of the app a notice comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoticeSource {
+    Data,
+    Analysis,
+    Training,
+    Export,
+    Settings,
+    Jobs,
+}
+
+impl NoticeSource {
+    pub const ALL: [NoticeSource; 6] = [
+        NoticeSource::Data,
+        NoticeSource::Analysis,
+        NoticeSource::Training,
+        NoticeSource::Export,
+        NoticeSource::Settings,
+        NoticeSource::Jobs,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Data => "Data",
+            Self::Analysis => "Analysis",
+            Self::Training => "Training",
+            Self::Export => "Export",
+            Self::Settings => "Settings",
+            Self::Jobs => "Jobs",
+        }
+    }
+}
+
+/// Work a notice can start again from the Notifications window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryAction {
+    /// A full market data refresh
+    Refresh,
+    /// Download one symbol again
+    Refetch(String),
+    /// Train the model again with the current settings
+    Train,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notice {
+    pub id: u64,
+    pub at: DateTime<Local>,
+    pub severity: Severity,
+    pub source: NoticeSource,
+    pub message: String,
+    pub retry: Option<RetryAction>,
+}
+
+impl Notice {
+    /// A notice stamped now; its id is assigned when it is pushed
+    pub fn new(severity: Severity, source: NoticeSource, message: impl Into<String>) -> Self {
+        Self { id: 0, at: Local::now(), severity, source, message: message.into(), retry: None }
+    }
+
+    pub fn with_retry(mut self, retry: RetryAction) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+}
+
+/// Session history of notices, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct Notices {
+    items: VecDeque<Notice>,
+    next_id: u64,
+    /// Warnings and errors since the Notifications window was last opened
+    pub unread: usize,
+    /// Whether the Notifications window is shown
+    pub open: bool,
+    /// Least severe notice the window lists
+    pub min_severity: Severity,
+    /// Only notices from this source are listed; `None` = all
+    pub source_filter: Option<NoticeSource>,
+}
+
+impl Notices {
+    /// Add `notice`, dropping the oldest past [`MAX_NOTICES`]; returns its id
+    pub fn push(&mut self, mut notice: Notice) -> u64 {
+        self.next_id += 1;
+        notice.id = self.next_id;
+        match notice.severity {
+            Severity::Error => tracing::warn!("{}: {}", notice.source.label(), notice.message),
+            Severity::Warning | Severity::Info => tracing::debug!("{}: {}", notice.source.label(), notice.message),
+        }
+        if notice.severity > Severity::Info && !self.open {
+            self.unread += 1;
+        }
+        self.items.push_back(notice);
+        while self.items.len() > MAX_NOTICES {
+            self.items.pop_front();
+        }
+        self.next_id
+    }
+
+    pub fn info(&mut self, source: NoticeSource, message: impl Into<String>) {
+        self.push(Notice::new(Severity::Info, source, message));
+    }
+
+    pub fn warn(&mut self, source: NoticeSource, message: impl Into<String>) {
+        self.push(Notice::new(Severity::Warning, source, message));
+    }
+
+    pub fn error(&mut self, source: NoticeSource, message: impl Into<String>) {
+        self.push(Notice::new(Severity::Error, source, message));
+    }
+
+    /// An info notice on success, an error on failure, each with its own message
+    pub fn report(&mut self, source: NoticeSource, result: Result<String, String>) {
+        match result {
+            Ok(message) => self.info(source, message),
+            Err(message) => self.error(source, message),
+        }
+    }
+
+    /// Most recent notice, shown in the status bar
+    pub fn latest(&self) -> Option<&Notice> {
+        self.items.back()
+    }
+
+    /// Oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Notice> {
+        self.items.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.items.iter().filter(|n| n.severity == severity).count()
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.items.retain(|n| n.id != id);
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.unread = 0;
+    }
+
+    /// Show or hide the Notifications window; opening it marks everything read
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open;
+        if open {
+            self.unread = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_is_capped_oldest_first() {
+        let mut notices = Notices::default();
+        for i in 0..MAX_NOTICES + 5 {
+            notices.info(NoticeSource::Data, format!("notice {}", i));
+        }
+        assert_eq!(notices.iter().count(), MAX_NOTICES);
+        assert_eq!(notices.iter().next().unwrap().message, "notice 5");
+        assert_eq!(notices.latest().unwrap().message, format!("notice {}", MAX_NOTICES + 4));
+    }
+
+    #[test]
+    fn test_unread_counts_problems_while_closed() {
+        let mut notices = Notices::default();
+        notices.info(NoticeSource::Export, "saved");
+        notices.report(NoticeSource::Export, Err("failed".to_string()));
+        notices.warn(NoticeSource::Data, "stale");
+        assert_eq!(notices.unread, 2);
+        assert_eq!(notices.count(Severity::Error), 1);
+
+        notices.set_open(true);
+        assert_eq!(notices.unread, 0);
+        notices.error(NoticeSource::Training, "diverged");
+        assert_eq!(notices.unread, 0);
+
+        let id = notices.push(Notice::new(Severity::Error, NoticeSource::Data, "XLE failed").with_retry(RetryAction::Refetch("XLE".to_string())));
+        assert_eq!(notices.latest().unwrap().retry, Some(RetryAction::Refetch("XLE".to_string())));
+        notices.dismiss(id);
+        assert_eq!(notices.latest().unwrap().message, "diverged");
+    }
+}
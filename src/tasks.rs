@@ -0,0 +1,338 @@
+//! Background task manager. Every fetch, training run and benchmark is started here,
+//! so each one is listed with its progress, can be cancelled from the Tasks window,
+//! and reports its error once it ends instead of failing silently on a detached thread.
+//!
+//! Async work runs on the manager's tokio runtime and is dropped when cancelled.
+//! Blocking work (training, benchmarks) runs on its own thread and is stopped through
+//! the stop flag it already polls, passed in as a cancel hook.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::ui::i18n::tr;
+
+pub type TaskId = u64;
+
+/// How often a running async task checks whether it was cancelled
+const CANCEL_POLL: Duration = Duration::from_millis(100);
+
+/// Finished tasks kept in the list for review; older ones are dropped
+const MAX_FINISHED: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskKind {
+    Startup,
+    Refresh,
+    Refetch,
+    Watchlist,
+    ApiTest,
+    Training,
+    Sweep,
+    GpuDiagnostics,
+    Webhook,
+    Email,
+    #[cfg_attr(not(feature = "grpc"), allow(dead_code))]
+    Grpc,
+}
+
+impl TaskKind {
+    pub fn label(self) -> String {
+        tr(match self {
+            Self::Startup => "task-kind-startup",
+            Self::Refresh => "task-kind-refresh",
+            Self::Refetch => "task-kind-refetch",
+            Self::Watchlist => "task-kind-watchlist",
+            Self::ApiTest => "task-kind-api-test",
+            Self::Training => "task-kind-training",
+            Self::Sweep => "task-kind-sweep",
+            Self::GpuDiagnostics => "task-kind-gpu-diagnostics",
+            Self::Webhook => "task-kind-webhook",
+            Self::Email => "task-kind-email",
+            Self::Grpc => "task-kind-grpc",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskStatus {
+    Running,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskProgress {
+    /// Share done in `0.0..=1.0`; `None` when the work can't tell
+    pub fraction: Option<f32>,
+    pub detail: String,
+}
+
+/// Optional callbacks for work that tracks its own progress and stop flag
+#[derive(Default)]
+pub struct TaskHooks {
+    /// Asks the work to stop. Async tasks are dropped on cancel either way; blocking
+    /// tasks without this hook can't be cancelled.
+    pub cancel: Option<Box<dyn Fn() + Send>>,
+    /// Polled once per frame while the task runs
+    pub progress: Option<Box<dyn Fn() -> TaskProgress>>,
+}
+
+pub struct Task {
+    pub id: TaskId,
+    pub kind: TaskKind,
+    pub label: String,
+    pub started: Instant,
+    /// Set when the task finished, for the elapsed time
+    pub finished: Option<Instant>,
+    pub status: TaskStatus,
+    pub progress: TaskProgress,
+    /// Cancel was requested; a blocking task may take a moment to stop
+    pub cancel_requested: bool,
+    cancel_flag: Arc<AtomicBool>,
+    outcome: Arc<Mutex<Option<TaskStatus>>>,
+    hooks: TaskHooks,
+    is_async: bool,
+}
+
+impl Task {
+    pub fn is_running(&self) -> bool {
+        self.status == TaskStatus::Running
+    }
+
+    pub fn can_cancel(&self) -> bool {
+        self.is_running() && !self.cancel_requested && (self.is_async || self.hooks.cancel.is_some())
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(Instant::now) - self.started
+    }
+}
+
+/// Owns all background work; polled once per frame by `AppState::poll_tasks`
+pub struct TaskManager {
+    runtime: tokio::runtime::Runtime,
+    tasks: Vec<Task>,
+    next_id: TaskId,
+    /// Repainted when a task ends; set on the first poll
+    ctx: Option<egui::Context>,
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        Self {
+            runtime: tokio::runtime::Runtime::new().expect("Failed to create tokio runtime"),
+            tasks: Vec::new(),
+            next_id: 1,
+            ctx: None,
+        }
+    }
+}
+
+impl TaskManager {
+    /// Run `work` on the tokio runtime. Cancelling drops it at its next await point.
+    pub fn spawn(
+        &mut self,
+        kind: TaskKind,
+        label: impl Into<String>,
+        hooks: TaskHooks,
+        work: impl Future<Output = Result<(), String>> + Send + 'static,
+    ) -> TaskId {
+        let task = self.register(kind, label.into(), hooks, true);
+        let (cancel_flag, outcome, ctx) = (task.cancel_flag.clone(), task.outcome.clone(), self.ctx.clone());
+        let id = task.id;
+        self.tasks.push(task);
+
+        self.runtime.spawn(async move {
+            let mut inner = tokio::spawn(work);
+            let cancelled = async {
+                while !cancel_flag.load(Ordering::SeqCst) {
+                    tokio::time::sleep(CANCEL_POLL).await;
+                }
+            };
+            let status = tokio::select! {
+                joined = &mut inner => match joined {
+                    Ok(result) => status_of(result),
+                    Err(_) => TaskStatus::Failed("The task panicked".to_string()),
+                },
+                _ = cancelled => {
+                    inner.abort();
+                    TaskStatus::Cancelled
+                }
+            };
+            finish(&outcome, status, ctx.as_ref());
+        });
+        id
+    }
+
+    /// Run `work` on a dedicated thread, for CPU- or GPU-bound work that blocks
+    pub fn spawn_blocking(
+        &mut self,
+        kind: TaskKind,
+        label: impl Into<String>,
+        hooks: TaskHooks,
+        work: impl FnOnce() -> Result<(), String> + Send + 'static,
+    ) -> TaskId {
+        let task = self.register(kind, label.into(), hooks, false);
+        let (outcome, ctx) = (task.outcome.clone(), self.ctx.clone());
+        let id = task.id;
+        self.tasks.push(task);
+
+        std::thread::spawn(move || {
+            let status = std::panic::catch_unwind(std::panic::AssertUnwindSafe(work))
+                .map_or_else(|_| TaskStatus::Failed("The task panicked".to_string()), status_of);
+            finish(&outcome, status, ctx.as_ref());
+        });
+        id
+    }
+
+    fn register(&mut self, kind: TaskKind, label: String, hooks: TaskHooks, is_async: bool) -> Task {
+        let id = self.next_id;
+        self.next_id += 1;
+        Task {
+            id,
+            kind,
+            label,
+            started: Instant::now(),
+            finished: None,
+            status: TaskStatus::Running,
+            progress: TaskProgress::default(),
+            cancel_requested: false,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            outcome: Arc::new(Mutex::new(None)),
+            hooks,
+            is_async,
+        }
+    }
+
+    pub fn cancel(&mut self, id: TaskId) {
+        let Some(task) = self.tasks.iter_mut().find(|t| t.id == id && t.can_cancel()) else {
+            return;
+        };
+        task.cancel_requested = true;
+        if let Some(cancel) = &task.hooks.cancel {
+            cancel();
+        }
+        task.cancel_flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Refresh progress and pick up finished tasks. Returns the tasks that finished
+    /// since the last poll.
+    pub fn poll(&mut self, ctx: &egui::Context) -> Vec<&Task> {
+        self.ctx.get_or_insert_with(|| ctx.clone());
+
+        let mut finished = Vec::new();
+        for task in self.tasks.iter_mut().filter(|t| t.is_running()) {
+            if let Some(progress) = &task.hooks.progress {
+                task.progress = progress();
+            }
+            let outcome = task.outcome.lock().ok().and_then(|mut o| o.take());
+            if let Some(status) = outcome {
+                // A blocking task stopped by its cancel hook returns normally
+                task.status = if task.cancel_requested && status == TaskStatus::Done { TaskStatus::Cancelled } else { status };
+                task.finished = Some(Instant::now());
+                finished.push(task.id);
+            }
+        }
+
+        let n_finished = self.tasks.iter().filter(|t| !t.is_running()).count();
+        let mut excess = n_finished.saturating_sub(MAX_FINISHED);
+        self.tasks.retain(|t| {
+            let drop = excess > 0 && !t.is_running() && !finished.contains(&t.id);
+            excess -= drop as usize;
+            !drop
+        });
+
+        if self.tasks.iter().any(|t| t.is_running()) {
+            ctx.request_repaint_after(Duration::from_millis(250));
+        }
+        self.tasks.iter().filter(|t| finished.contains(&t.id)).collect()
+    }
+
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    pub fn running(&self) -> impl Iterator<Item = &Task> {
+        self.tasks.iter().filter(|t| t.is_running())
+    }
+
+    pub fn clear_finished(&mut self) {
+        self.tasks.retain(|t| t.is_running());
+    }
+}
+
+fn status_of(result: Result<(), String>) -> TaskStatus {
+    match result {
+        Ok(()) => TaskStatus::Done,
+        Err(e) => TaskStatus::Failed(e),
+    }
+}
+
+fn finish(outcome: &Mutex<Option<TaskStatus>>, status: TaskStatus, ctx: Option<&egui::Context>) {
+    if let Ok(mut slot) = outcome.lock() {
+        *slot = Some(status);
+    }
+    if let Some(ctx) = ctx {
+        ctx.request_repaint();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Poll until task `id` finishes and return its status
+    fn wait_for(manager: &mut TaskManager, id: TaskId) -> TaskStatus {
+        let ctx = egui::Context::default();
+        for _ in 0..200 {
+            manager.poll(&ctx);
+            let task = manager.tasks().iter().find(|t| t.id == id).unwrap();
+            if !task.is_running() {
+                return task.status.clone();
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("task {} did not finish", id);
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let mut manager = TaskManager::default();
+        let ok = manager.spawn(TaskKind::Refresh, "ok", TaskHooks::default(), async { Ok(()) });
+        let failed = manager.spawn_blocking(TaskKind::Training, "failed", TaskHooks::default(), || Err("boom".to_string()));
+        let panicked = manager.spawn_blocking(TaskKind::Sweep, "panicked", TaskHooks::default(), || panic!("boom"));
+        assert_eq!(wait_for(&mut manager, ok), TaskStatus::Done);
+        assert_eq!(wait_for(&mut manager, failed), TaskStatus::Failed("boom".to_string()));
+        assert_eq!(wait_for(&mut manager, panicked), TaskStatus::Failed("The task panicked".to_string()));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let mut manager = TaskManager::default();
+        let pending = manager.spawn(TaskKind::Refresh, "pending", TaskHooks::default(), std::future::pending());
+        manager.cancel(pending);
+        assert_eq!(wait_for(&mut manager, pending), TaskStatus::Cancelled);
+
+        // A blocking task stops through its cancel hook
+        let stop = Arc::new(AtomicBool::new(false));
+        let hook_stop = stop.clone();
+        let hooks = TaskHooks { cancel: Some(Box::new(move || hook_stop.store(true, Ordering::SeqCst))), progress: None };
+        let blocking = manager.spawn_blocking(TaskKind::Training, "blocking", hooks, move || {
+            while !stop.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Ok(())
+        });
+        manager.cancel(blocking);
+        assert_eq!(wait_for(&mut manager, blocking), TaskStatus::Cancelled);
+
+        // Without a hook a blocking task can't be cancelled
+        let uncancellable = manager.spawn_blocking(TaskKind::GpuDiagnostics, "diag", TaskHooks::default(), || Ok(()));
+        assert!(!manager.tasks().iter().find(|t| t.id == uncancellable).unwrap().can_cancel());
+    }
+}
@@ -0,0 +1,229 @@
+use eframe::egui;
+
+use crate::analysis::alerts::{self, AlertCondition, AlertEvent, AlertMetric, AlertRule};
+use crate::app::AppState;
+use crate::config;
+use crate::notices::NoticeSource;
+use crate::ui::i18n::{self, tr, tr_args};
+
+/// Seconds a toast stays on screen
+const TOAST_SECS: f64 = 8.0;
+
+/// Most recent fired alerts listed in the panel
+const HISTORY_SHOWN: usize = 100;
+
+/// Top-bar button toggling the alerts panel, with the unread count
+pub fn render_bell(ui: &mut egui::Ui, state: &mut AppState) {
+    let text = if state.alerts.unread > 0 {
+        egui::RichText::new(format!("🔔 {}", state.alerts.unread)).color(egui::Color32::from_rgb(230, 160, 40))
+    } else {
+        egui::RichText::new("🔔")
+    };
+    if ui.selectable_label(state.alerts.panel_open, text).on_hover_text(tr("alerts-heading")).clicked() {
+        state.alerts.panel_open = !state.alerts.panel_open;
+    }
+}
+
+/// Right-hand panel for editing alert rules and browsing fired alerts
+pub fn render_panel(ctx: &egui::Context, state: &mut AppState) {
+    if !state.alerts.panel_open {
+        return;
+    }
+    state.alerts.unread = 0;
+
+    egui::SidePanel::right("alerts_panel").default_width(320.0).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            ui.heading(tr("alerts-heading"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button("✕").clicked() {
+                    state.alerts.panel_open = false;
+                }
+            });
+        });
+        ui.small(tr("alerts-intro"));
+        ui.add_space(8.0);
+
+        let mut rules_changed = render_rules(ui, state);
+        ui.add_space(4.0);
+        rules_changed |= render_draft(ui, state);
+        if rules_changed {
+            if let Err(e) = alerts::save_rules(&state.alerts.rules) {
+                state.notices.error(NoticeSource::Settings, e);
+            }
+        }
+
+        ui.add_space(8.0);
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.strong(tr("alerts-history"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.add_enabled(!state.alerts.history.is_empty(), egui::Button::new(tr("alerts-clear"))).clicked() {
+                    state.alerts.history.clear();
+                    if let Err(e) = alerts::save_history(&state.alerts.history) {
+                        state.notices.error(NoticeSource::Settings, e);
+                    }
+                }
+            });
+        });
+        if state.alerts.history.is_empty() {
+            ui.weak(tr("alerts-history-empty"));
+        }
+        egui::ScrollArea::vertical().id_salt("alert_history").show(ui, |ui| {
+            for event in state.alerts.history.iter().rev().take(HISTORY_SHOWN) {
+                ui.weak(fired_at(event));
+                ui.label(event_text(event));
+                ui.add_space(4.0);
+            }
+        });
+    });
+}
+
+/// Existing rules with enable toggles and delete buttons. Returns whether any changed.
+fn render_rules(ui: &mut egui::Ui, state: &mut AppState) -> bool {
+    if state.alerts.rules.is_empty() {
+        ui.weak(tr("alerts-no-rules"));
+        return false;
+    }
+    let mut changed = false;
+    let mut remove = None;
+    egui::Grid::new("alert_rules_grid").num_columns(3).striped(true).show(ui, |ui| {
+        for (i, rule) in state.alerts.rules.iter_mut().enumerate() {
+            changed |= ui.checkbox(&mut rule.enabled, "").on_hover_text(tr("alerts-enabled")).changed();
+            if rule.triggered && rule.enabled {
+                ui.colored_label(egui::Color32::from_rgb(230, 160, 40), rule_text(rule))
+                    .on_hover_text(tr("alerts-holds"));
+            } else {
+                ui.label(rule_text(rule));
+            }
+            if ui.small_button("🗑").on_hover_text(tr("alerts-delete-rule")).clicked() {
+                remove = Some(i);
+            }
+            ui.end_row();
+        }
+    });
+    if let Some(i) = remove {
+        state.alerts.rules.remove(i);
+        changed = true;
+    }
+    changed
+}
+
+/// Composer for a new rule. Returns whether a rule was added.
+fn render_draft(ui: &mut egui::Ui, state: &mut AppState) -> bool {
+    let draft = &mut state.alerts.draft;
+    let mut added = false;
+    ui.group(|ui| {
+        ui.horizontal_wrapped(|ui| {
+            egui::ComboBox::from_id_salt("alert_metric")
+                .selected_text(metric_label(draft.metric))
+                .show_ui(ui, |ui| {
+                    for metric in AlertMetric::ALL {
+                        ui.selectable_value(&mut draft.metric, metric, metric_label(metric));
+                    }
+                });
+            if draft.metric.is_per_sector() {
+                egui::ComboBox::from_id_salt("alert_symbol")
+                    .selected_text(draft.symbol.clone().unwrap_or_else(|| tr("alerts-any-sector")))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut draft.symbol, None, tr("alerts-any-sector"));
+                        for sector in config::sector_etfs() {
+                            ui.selectable_value(&mut draft.symbol, Some(sector.symbol.clone()), &sector.symbol);
+                        }
+                    });
+            }
+            ui.selectable_value(&mut draft.condition, AlertCondition::Above, ">");
+            ui.selectable_value(&mut draft.condition, AlertCondition::Below, "<");
+            ui.add(egui::DragValue::new(&mut draft.threshold).speed(0.01).suffix(draft.metric.unit()));
+            if ui.button(tr("alerts-add-rule")).clicked() {
+                let mut rule = draft.clone();
+                if !rule.metric.is_per_sector() {
+                    rule.symbol = None;
+                }
+                rule.enabled = true;
+                rule.triggered = false;
+                state.alerts.rules.push(rule);
+                added = true;
+            }
+        });
+    });
+    added
+}
+
+fn metric_label(metric: AlertMetric) -> String {
+    tr(match metric {
+        AlertMetric::VolRatio => "alert-metric-vol-ratio",
+        AlertMetric::ShortVol => "alert-metric-short-vol",
+        AlertMetric::AvgCorrelation => "alert-metric-avg-correlation",
+        AlertMetric::TermSpread => "alert-metric-term-spread",
+        AlertMetric::PredictedVol => "alert-metric-predicted-vol",
+    })
+}
+
+/// [`AlertRule::description`] in the current language
+fn rule_text(rule: &AlertRule) -> String {
+    let condition = format!("{} {}{}", rule.condition.symbol(), i18n::number(rule.threshold, 2), rule.metric.unit());
+    let metric = metric_label(rule.metric);
+    match (&rule.symbol, rule.metric.is_per_sector()) {
+        (Some(symbol), true) => format!("{} {} {}", symbol, metric, condition),
+        (None, true) => format!("{} {} {}", tr("alerts-any-sector"), metric, condition),
+        _ => format!("{} {}", metric, condition),
+    }
+}
+
+fn fired_at(event: &AlertEvent) -> String {
+    chrono::NaiveDateTime::parse_from_str(&event.triggered_at, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .and_then(|at| at.and_local_timezone(chrono::Local).single())
+        .map_or_else(|| event.triggered_at.clone(), i18n::date_time)
+}
+
+/// [`AlertEvent::message`] in the current language; the rule text is kept as it fired
+fn event_text(event: &AlertEvent) -> String {
+    let value = format!("{}{}", i18n::number(event.value, 2), event.unit);
+    match &event.symbol {
+        Some(symbol) => tr_args(
+            "alerts-fired-sector",
+            &[("rule", event.rule.clone()), ("symbol", symbol.clone()), ("value", value)],
+        ),
+        None => tr_args("alerts-fired", &[("rule", event.rule.clone()), ("value", value)]),
+    }
+}
+
+/// Stack of recently fired alerts in the bottom-right corner, each dismissed after
+/// `TOAST_SECS` or on click
+pub fn render_toasts(ctx: &egui::Context, state: &mut AppState) {
+    if state.alerts.toasts.is_empty() {
+        return;
+    }
+    let now = ctx.input(|i| i.time);
+    state.alerts.toasts.retain(|t| t.shown_at.is_none_or(|shown| now - shown < TOAST_SECS));
+
+    let mut dismissed = None;
+    egui::Area::new(egui::Id::new("alert_toasts"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-12.0, -36.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            for (i, toast) in state.alerts.toasts.iter_mut().enumerate() {
+                toast.shown_at.get_or_insert(now);
+                let response = egui::Frame::popup(ui.style())
+                    .show(ui, |ui| {
+                        ui.set_max_width(320.0);
+                        ui.horizontal(|ui| {
+                            ui.colored_label(egui::Color32::from_rgb(230, 160, 40), "🔔");
+                            ui.label(&toast.message);
+                        });
+                    })
+                    .response
+                    .interact(egui::Sense::click());
+                if response.clicked() {
+                    dismissed = Some(i);
+                }
+            }
+        });
+    if let Some(i) = dismissed {
+        state.alerts.toasts.remove(i);
+    }
+    if !state.alerts.toasts.is_empty() {
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+}
@@ -4,20 +4,22 @@ use egui_plot::{Bar, BarChart, Line, Plot, PlotPoints};
 use crate::analysis::bond_spreads;
 use crate::app::AppState;
 use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::metric_info::{self, Metric};
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Bond Spreads & Yield Curve");
+    ui.heading(tr("bond-heading"));
     ui.add_space(8.0);
 
     if state.market_data.treasury_rates.is_empty() {
-        ui.label("No treasury rate data available. Load market data first.");
+        ui.label(tr("bond-no-data"));
         return;
     }
 
     // Yield curve for the latest date
-    ui.collapsing("Current Yield Curve", |ui| {
+    ui.collapsing(tr("bond-current-curve"), |ui| {
         if let Some(latest_rate) = state.market_data.treasury_rates.first() {
-            ui.label(format!("Date: {}", latest_rate.date));
+            ui.label(tr_args("bond-date", &[("date", i18n::iso_date(&latest_rate.date))]));
 
             let curve = bond_spreads::yield_curve_for_date(latest_rate);
             if !curve.is_empty() {
@@ -31,9 +33,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                     .iter()
                     .map(|p| Bar::new(p[0], p[1]).width(0.6))
                     .collect();
-                let yield_hover = [HoverSeries { name: "Yield", data: &bar_data, decimals: 2, suffix: "%" }];
+                let yield_name = tr("bond-yield");
+                let yield_hover = [HoverSeries { name: &yield_name, data: &bar_data, decimals: 2, suffix: "%" }];
 
-                height_control(ui, &mut state.chart_heights.bond_yield_curve, "Yield Curve Chart Height");
+                height_control(ui, &mut state.chart_heights.bond_yield_curve, &tr("bond-curve-height"));
                 chart_utils::plot_with_y_drag(
                     ui,
                     "yield_curve",
@@ -41,13 +44,13 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                         Plot::new("yield_curve")
                             .height(state.chart_heights.bond_yield_curve),
                     )
-                        .y_axis_label("Yield (%)")
+                        .y_axis_label(tr("bond-axis-yield"))
                         .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_labeled_x(&yield_hover, &x_labels))
                         .label_formatter(chart_utils::no_hover_label),
                     |plot_ui| {
                         plot_ui.bar_chart(
                             BarChart::new(bars)
-                                .name("Yield Curve")
+                                .name(tr("bond-yield-curve"))
                                 .color(egui::Color32::from_rgb(70, 130, 220)),
                         );
                     },
@@ -56,7 +59,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 // Show maturity labels
                 ui.horizontal_wrapped(|ui| {
                     for (i, (label, rate)) in curve.iter().enumerate() {
-                        ui.label(format!("[{}] {} = {:.2}%", i, label, rate));
+                        ui.label(format!("[{}] {} = {}%", i, label, i18n::number(*rate, 2)));
                     }
                 });
             }
@@ -67,7 +70,13 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Term spread (10Y-2Y) over time
     if !state.analysis.bond_spreads.is_empty() {
-        ui.heading("10Y-2Y Term Spread Over Time");
+        ui.horizontal(|ui| {
+            let heading = ui.heading(tr("bond-term-spread-heading"));
+            metric_info::explain(heading, state.explain_metrics, Metric::TermSpread, &state.analysis_windows);
+            chart_utils::export_csv_button(ui, &mut state.notices, "bond_spreads", || {
+                crate::data::export::spreads_table(&state.analysis.bond_spreads)
+            });
+        });
         ui.add_space(4.0);
 
         let spread_data: Vec<[f64; 2]> = state
@@ -78,15 +87,18 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .enumerate()
             .map(|(i, s)| [i as f64, s.spread_10y_2y])
             .collect();
-        let spread_points: PlotPoints = spread_data.iter().copied().collect();
+        let spread_points = chart_utils::plot_points(&spread_data);
 
         let zero_line: PlotPoints = PlotPoints::from_iter(
             (0..state.analysis.bond_spreads.len()).map(|i| [i as f64, 0.0]),
         );
 
-        let spread_hover = [HoverSeries { name: "10Y-2Y Spread", data: &spread_data, decimals: 2, suffix: " pp" }];
+        let spread_name = tr("bond-spread");
+        let spread_hover = [HoverSeries { name: &spread_name, data: &spread_data, decimals: 2, suffix: " pp" }];
+        let spread_dates: Vec<chrono::NaiveDate> = state.analysis.bond_spreads.iter().rev().map(|s| s.date).collect();
+        let markers = chart_utils::event_markers(&state.chart_events(), &spread_dates);
 
-        height_control(ui, &mut state.chart_heights.bond_term_spread, "Term Spread Chart Height");
+        height_control(ui, &mut state.chart_heights.bond_term_spread, &tr("bond-spread-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "term_spread_plot",
@@ -94,20 +106,21 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("term_spread_plot")
                     .height(state.chart_heights.bond_term_spread),
             )
-                .x_axis_label("Trading Day (recent -> past)")
-                .y_axis_label("Spread (percentage points)")
+                .x_axis_label(tr("axis-trading-day"))
+                .y_axis_label(tr("bond-axis-spread"))
                 .legend(egui_plot::Legend::default())
-                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&spread_hover))
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&spread_hover, &markers))
                 .label_formatter(chart_utils::no_hover_label),
             |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
                 plot_ui.line(
                     Line::new(spread_points)
-                        .name("10Y-2Y Spread")
+                        .name(&spread_name)
                         .color(egui::Color32::from_rgb(255, 150, 50)),
                 );
                 plot_ui.line(
                     Line::new(zero_line)
-                        .name("Zero")
+                        .name(tr("bond-zero"))
                         .color(egui::Color32::from_rgb(150, 150, 150))
                         .style(egui_plot::LineStyle::dashed_dense()),
                 );
@@ -116,7 +129,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
         // Curve slope over time
         ui.add_space(8.0);
-        ui.heading("Curve Slope (30Y - 3M)");
+        ui.heading(tr("bond-slope-heading"));
         ui.add_space(4.0);
 
         let slope_data: Vec<[f64; 2]> = state
@@ -127,10 +140,11 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .enumerate()
             .map(|(i, s)| [i as f64, s.curve_slope])
             .collect();
-        let slope_points: PlotPoints = slope_data.iter().copied().collect();
-        let slope_hover = [HoverSeries { name: "30Y-3M Slope", data: &slope_data, decimals: 2, suffix: " pp" }];
+        let slope_points = chart_utils::plot_points(&slope_data);
+        let slope_name = tr("bond-slope");
+        let slope_hover = [HoverSeries { name: &slope_name, data: &slope_data, decimals: 2, suffix: " pp" }];
 
-        height_control(ui, &mut state.chart_heights.bond_curve_slope, "Curve Slope Chart Height");
+        height_control(ui, &mut state.chart_heights.bond_curve_slope, &tr("bond-slope-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "curve_slope_plot",
@@ -138,14 +152,14 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("curve_slope_plot")
                     .height(state.chart_heights.bond_curve_slope),
             )
-                .x_axis_label("Trading Day (recent -> past)")
-                .y_axis_label("Slope (percentage points)")
+                .x_axis_label(tr("axis-trading-day"))
+                .y_axis_label(tr("bond-axis-slope"))
                 .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&slope_hover))
                 .label_formatter(chart_utils::no_hover_label),
             |plot_ui| {
                 plot_ui.line(
                     Line::new(slope_points)
-                        .name("30Y-3M Slope")
+                        .name(&slope_name)
                         .color(egui::Color32::from_rgb(100, 200, 100)),
                 );
             },
@@ -157,16 +171,14 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         ui.add_space(4.0);
 
         if let Some(latest) = state.analysis.bond_spreads.first() {
-            ui.label(format!(
-                "Latest ({}) | 10Y-2Y: {:.2} pp | Curve Slope: {:.2} pp | {}",
-                latest.date,
-                latest.spread_10y_2y,
-                latest.curve_slope,
-                if latest.spread_10y_2y < 0.0 {
-                    "INVERTED"
-                } else {
-                    "Normal"
-                }
+            ui.label(tr_args(
+                "bond-latest",
+                &[
+                    ("date", i18n::date(latest.date)),
+                    ("spread", i18n::number(latest.spread_10y_2y, 2)),
+                    ("slope", i18n::number(latest.curve_slope, 2)),
+                    ("shape", tr(if latest.spread_10y_2y < 0.0 { "bond-inverted" } else { "bond-normal" })),
+                ],
             ));
         }
 
@@ -176,10 +188,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             ui.add_space(4.0);
             ui.colored_label(
                 egui::Color32::from_rgb(220, 50, 50),
-                format!(
-                    "Warning: {} yield curve inversion(s) detected in data",
-                    inversions.len()
-                ),
+                tr_args("bond-inversions", &[("count", i18n::integer(inversions.len()))]),
             );
         }
     }
@@ -1,8 +1,21 @@
 /// Shared chart utilities for all UI views that render plots.
 
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use chrono::NaiveDate;
 use eframe::egui;
 use eframe::egui::Vec2b;
-use egui_plot::{CoordinatesFormatter, Corner, Plot, PlotBounds, PlotPoint, PlotUi};
+use egui_plot::{CoordinatesFormatter, Corner, Plot, PlotBounds, PlotMemory, PlotPoint, PlotPoints, PlotResponse, PlotUi, Polygon, VLine};
+
+use crate::analysis::regimes::{CorrelationRegime, VolRegime};
+use crate::data::models::{ChartEvent, EventKind};
+use crate::notices::{NoticeSource, Notices};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
 
 // ── Hover label utilities ───────────────────────────────────────────────────
 
@@ -19,24 +32,23 @@ pub struct HoverSeries<'a> {
 /// series at the cursor's X position.  Use with
 /// `Plot::coordinates_formatter(Corner::RightBottom, hover_formatter(&series))`.
 pub fn hover_formatter<'a>(series: &'a [HoverSeries<'a>]) -> CoordinatesFormatter<'a> {
-    CoordinatesFormatter::new(move |cursor: &PlotPoint, _bounds: &PlotBounds| {
-        let x = cursor.x;
-        let mut text = format!("x: {:.0}", x);
-        for s in series {
-            if let Some(idx) = nearest_x_index(s.data, x) {
-                use std::fmt::Write;
-                let _ = write!(
-                    text,
-                    "\n{}: {:.prec$}{}",
-                    s.name,
-                    s.data[idx][1],
-                    s.suffix,
-                    prec = s.decimals
-                );
-            }
+    CoordinatesFormatter::new(move |cursor: &PlotPoint, _bounds: &PlotBounds| hover_text(series, cursor.x))
+}
+
+/// Hover text for `series` at X position `x`: the X value, then each series' nearest Y.
+fn hover_text(series: &[HoverSeries<'_>], x: f64) -> String {
+    let mut text = format!("x: {}", i18n::number(x, 0));
+    append_series_values(&mut text, series, x);
+    text
+}
+
+fn append_series_values(text: &mut String, series: &[HoverSeries<'_>], x: f64) {
+    use std::fmt::Write;
+    for s in series {
+        if let Some(idx) = nearest_x_index(s.data, x) {
+            let _ = write!(text, "\n{}: {}{}", s.name, i18n::number(s.data[idx][1], s.decimals), s.suffix);
         }
-        text
-    })
+    }
 }
 
 /// Variant of [`hover_formatter`] for charts with discrete, labelled X
@@ -54,19 +66,7 @@ pub fn hover_formatter_labeled_x<'a>(
             .map(|s| s.as_str())
             .unwrap_or("?");
         let mut text = x_display.to_string();
-        for s in series {
-            if let Some(idx) = nearest_x_index(s.data, x) {
-                use std::fmt::Write;
-                let _ = write!(
-                    text,
-                    "\n{}: {:.prec$}{}",
-                    s.name,
-                    s.data[idx][1],
-                    s.suffix,
-                    prec = s.decimals
-                );
-            }
-        }
+        append_series_values(&mut text, series, x);
         text
     })
 }
@@ -102,16 +102,156 @@ fn nearest_x_index(data: &[[f64; 2]], target_x: f64) -> Option<usize> {
     }
 }
 
+// ── Downsampling ────────────────────────────────────────────────────────────
+
+/// Series longer than this are drawn downsampled with [`lttb`]
+pub const MAX_PLOT_POINTS: usize = 2_000;
+
+/// Points to draw for `data`, downsampled to [`MAX_PLOT_POINTS`] when longer.
+/// Keep passing the full `data` to [`HoverSeries`] so hover values stay exact.
+pub fn plot_points(data: &[[f64; 2]]) -> PlotPoints {
+    if data.len() > MAX_PLOT_POINTS {
+        PlotPoints::from(lttb(data, MAX_PLOT_POINTS))
+    } else {
+        data.iter().copied().collect()
+    }
+}
+
+/// Largest-triangle-three-buckets downsampling to `threshold` points. Keeps the
+/// first and last points and, from each bucket in between, the point forming the
+/// largest triangle with the previously kept point and the next bucket's average,
+/// so spikes and turning points survive. `data` must be sorted by X.
+pub fn lttb(data: &[[f64; 2]], threshold: usize) -> Vec<[f64; 2]> {
+    let n = data.len();
+    if threshold >= n || threshold < 3 {
+        return data.to_vec();
+    }
+    let bucket = (n - 2) as f64 / (threshold - 2) as f64;
+    let bounds = |i: usize| ((i as f64 * bucket) as usize + 1).min(n - 1);
+
+    let mut out = Vec::with_capacity(threshold);
+    out.push(data[0]);
+    let mut prev = data[0];
+    for i in 0..threshold - 2 {
+        let (start, end) = (bounds(i), bounds(i + 1));
+        let next = &data[end..bounds(i + 2).max(end + 1).min(n)];
+        let avg_x = next.iter().map(|p| p[0]).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p[1]).sum::<f64>() / next.len() as f64;
+
+        let area = |p: &[f64; 2]| {
+            ((prev[0] - avg_x) * (p[1] - prev[1]) - (prev[0] - p[0]) * (avg_y - prev[1])).abs()
+        };
+        let picked = data[start..end.max(start + 1)]
+            .iter()
+            .max_by(|a, b| area(a).total_cmp(&area(b)))
+            .copied()
+            .unwrap_or(data[start]);
+        out.push(picked);
+        prev = picked;
+    }
+    out.push(data[n - 1]);
+    out
+}
+
+// ── Plot data cache ─────────────────────────────────────────────────────────
+
+/// Chart data built once and reused across frames, so idle repaints don't rebuild
+/// series from the analysis results. Each chart owns one slot keyed by whatever its
+/// data depends on besides the analysis; [`PlotCache::invalidate`] drops every slot
+/// when the analysis is recomputed.
+#[derive(Default)]
+pub struct PlotCache {
+    slots: HashMap<&'static str, (u64, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl PlotCache {
+    /// The data cached for chart `id`, rebuilt with `build` when `key` differs from
+    /// the key it was last built with
+    pub fn get<T: Send + Sync + 'static>(&mut self, id: &'static str, key: impl Hash, build: impl FnOnce() -> T) -> Arc<T> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let key = hasher.finish();
+        if let Some((cached_key, value)) = self.slots.get(id) {
+            if *cached_key == key {
+                if let Ok(value) = Arc::clone(value).downcast::<T>() {
+                    return value;
+                }
+            }
+        }
+        let value = Arc::new(build());
+        self.slots.insert(id, (key, value.clone()));
+        value
+    }
+
+    /// Drop all cached chart data; call whenever the analysis results change
+    pub fn invalidate(&mut self) {
+        self.slots.clear();
+    }
+
+    /// Number of cached charts
+    pub fn entry_count(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+/// Hashes a float series by bit pattern, for cache keys over data that is replaced
+/// without any other change to mark it (e.g. a new backtest)
+pub struct FloatsKey<'a>(pub &'a [f64]);
+
+impl Hash for FloatsKey<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.len().hash(state);
+        for v in self.0 {
+            v.to_bits().hash(state);
+        }
+    }
+}
+
+/// A series kept in full for exact hover values, alongside the (possibly
+/// downsampled) points drawn from it
+#[derive(Default)]
+pub struct CachedSeries {
+    pub data: Vec<[f64; 2]>,
+    points: Vec<PlotPoint>,
+}
+
+impl CachedSeries {
+    pub fn new(data: Vec<[f64; 2]>) -> Self {
+        let points = plot_points(&data).points().to_vec();
+        Self { data, points }
+    }
+
+    /// Points to hand to a plot item. egui_plot takes them by value, so this is one
+    /// copy of the already-downsampled points; nothing is recomputed.
+    pub fn plot_points(&self) -> PlotPoints {
+        PlotPoints::Owned(self.points.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl FromIterator<[f64; 2]> for CachedSeries {
+    fn from_iter<I: IntoIterator<Item = [f64; 2]>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
 // ── Y-axis drag-to-zoom ─────────────────────────────────────────────────────
 
-/// Per-chart state cached across frames for the Y-axis drag sensor.
+/// Per-chart state cached across frames for the Y-axis drag sensor and box zoom.
 #[derive(Default, Clone)]
 struct YAxisDragState {
     /// Inner plot frame rect from the previous frame, used to position the
     /// drag sensor on the current frame before `Plot::show()` is called.
     plot_frame: Option<egui::Rect>,
+    /// Plot-space corner where an in-progress box-zoom drag started
+    box_start: Option<PlotPoint>,
 }
 
+const BOX_ZOOM_COLOR: egui::Color32 = egui::Color32::from_rgb(100, 160, 255);
+
 /// How many pixels of drag translate to ×1 zoom change (larger = faster).
 const Y_DRAG_SENSITIVITY: f32 = 0.005;
 
@@ -119,18 +259,20 @@ const Y_DRAG_SENSITIVITY: f32 = 0.005;
 const Y_AXIS_HIT_WIDTH: f32 = 50.0;
 
 /// Drop-in replacement for `Plot::show()` that adds click-and-drag Y-axis
-/// scaling. Drag **up** on the Y-axis label area to zoom in; drag **down**
-/// to zoom out.
+/// scaling and box zoom. Drag **up** on the Y-axis label area to zoom in; drag
+/// **down** to zoom out. Shift+drag inside the plot draws a rectangle and zooms
+/// to it; right-click or double-click resets to auto-bounds. Series hidden by
+/// clicking their legend entry stay hidden across sessions (see [`sync_legend`]).
 ///
 /// `id_source` should be the same string passed to `Plot::new(…)` so that the
 /// per-chart state is uniquely keyed. The `plot` argument should already have
 /// all modifiers applied (e.g. via [`default_plot_interaction`]).
-pub fn plot_with_y_drag<S: std::hash::Hash>(
+pub fn plot_with_y_drag(
     ui: &mut egui::Ui,
-    id_source: S,
+    id_source: &str,
     plot: Plot<'_>,
     build_fn: impl FnOnce(&mut PlotUi),
-) {
+) -> PlotResponse<()> {
     let state_id = egui::Id::new(("y_drag_state", id_source));
 
     // Read state cached from the previous frame.
@@ -157,8 +299,14 @@ pub fn plot_with_y_drag<S: std::hash::Hash>(
         0.0
     };
 
+    // Shift+drag is reserved for box zoom, so suspend panning while Shift is held
+    let shift = ui.input(|i| i.modifiers.shift);
+    let plot = if shift { plot.allow_drag(false) } else { plot };
+    let mut box_start = state.box_start;
+
     // Run the plot, applying Y-zoom inside the closure when a drag is active.
-    let response = plot.show(ui, |plot_ui| {
+    let response = plot.allow_boxed_zoom(false).show(ui, |plot_ui| {
+        apply_box_zoom(plot_ui, shift, &mut box_start);
         if y_delta_px.abs() > 0.001 {
             let bounds = plot_ui.plot_bounds();
             let y_min = bounds.min()[1];
@@ -183,8 +331,363 @@ pub fn plot_with_y_drag<S: std::hash::Hash>(
     // Cache the inner frame rect for the next frame.
     let new_state = YAxisDragState {
         plot_frame: Some(*response.transform.frame()),
+        box_start,
     };
     ui.data_mut(|d| d.insert_temp(state_id, new_state));
+    sync_legend(ui, id_source);
+    response
+}
+
+// ── Legend visibility ───────────────────────────────────────────────────────
+
+/// Names of the series hidden through each chart's legend, by chart `id_source`
+pub type HiddenSeries = BTreeMap<String, BTreeSet<String>>;
+
+fn hidden_series_id() -> egui::Id {
+    egui::Id::new("legend_hidden_series")
+}
+
+/// Hidden legend entries of every chart shown so far, for persisting
+pub fn hidden_series(ctx: &egui::Context) -> HiddenSeries {
+    ctx.data(|d| d.get_temp(hidden_series_id())).unwrap_or_default()
+}
+
+/// Seed the hidden legend entries restored from the last session. Call once at startup.
+pub fn set_hidden_series(ctx: &egui::Context, hidden: HiddenSeries) {
+    ctx.data_mut(|d| d.insert_temp(hidden_series_id(), hidden));
+}
+
+/// Keep a chart's legend visibility and the persisted set in step. egui_plot keeps
+/// hidden items in its plot memory, which starts empty each session: the first time
+/// a chart is shown its saved set is written into that memory (visible from the next
+/// frame); afterwards legend clicks are copied back to the saved set.
+fn sync_legend(ui: &egui::Ui, id_source: &str) {
+    // Same id `Plot::show` derives from `Plot::new(id_source)`
+    let plot_id = ui.make_persistent_id(egui::Id::new(id_source));
+    let ctx = ui.ctx();
+    let Some(mut mem) = PlotMemory::load(ctx, plot_id) else { return };
+    let restored_id = plot_id.with("legend_restored");
+    let restored = ctx.data(|d| d.get_temp::<bool>(restored_id)).unwrap_or(false);
+    let current: BTreeSet<String> = mem.hidden_items.iter().cloned().collect();
+
+    // Context locks aren't reentrant, so the plot memory is stored after `data_mut`
+    let restore = ctx.data_mut(|d| {
+        let saved = d.get_temp_mut_or_default::<HiddenSeries>(hidden_series_id());
+        if !restored {
+            let restore = saved.get(id_source).filter(|h| **h != current).cloned();
+            d.insert_temp(restored_id, true);
+            return restore;
+        }
+        if current.is_empty() {
+            saved.remove(id_source);
+        } else if saved.get(id_source) != Some(&current) {
+            saved.insert(id_source.to_string(), current);
+        }
+        None
+    });
+    if let Some(hidden) = restore {
+        mem.hidden_items = hidden.into_iter().collect();
+        mem.store(ctx, plot_id);
+        ctx.request_repaint();
+    }
+}
+
+/// Box-zoom handling inside `Plot::show`: a Shift+drag records its start corner
+/// and draws the rectangle; releasing zooms to it. A right-click resets bounds.
+fn apply_box_zoom(plot_ui: &mut PlotUi, shift: bool, box_start: &mut Option<PlotPoint>) {
+    let response = plot_ui.response().clone();
+    if response.secondary_clicked() {
+        plot_ui.set_auto_bounds(Vec2b::TRUE);
+        *box_start = None;
+        return;
+    }
+    if shift && response.drag_started_by(egui::PointerButton::Primary) {
+        *box_start = plot_ui.pointer_coordinate();
+    }
+    let (Some(start), Some(end)) = (*box_start, plot_ui.pointer_coordinate()) else {
+        return;
+    };
+    if response.drag_stopped() {
+        *box_start = None;
+        // Ignore accidental clicks that barely moved
+        let (min, max) = ([start.x.min(end.x), start.y.min(end.y)], [start.x.max(end.x), start.y.max(end.y)]);
+        let bounds = plot_ui.plot_bounds();
+        if (max[0] - min[0]) > bounds.width() * 0.01 && (max[1] - min[1]) > bounds.height() * 0.01 {
+            plot_ui.set_plot_bounds(PlotBounds::from_min_max(min, max));
+        }
+    } else if response.dragged() {
+        let corners = vec![[start.x, start.y], [end.x, start.y], [end.x, end.y], [start.x, end.y]];
+        plot_ui.polygon(
+            Polygon::new(PlotPoints::new(corners))
+                .fill_color(BOX_ZOOM_COLOR.gamma_multiply(0.15))
+                .stroke(egui::Stroke::new(1.0, BOX_ZOOM_COLOR)),
+        );
+    } else {
+        *box_start = None;
+    }
+}
+
+// ── Synchronized crosshair ──────────────────────────────────────────────────
+
+/// X position hovered in any plot of a crosshair group, shared through egui
+/// temp memory.
+#[derive(Default, Clone, Copy)]
+struct CrosshairState {
+    x: f64,
+    /// Pass in which the position was last written; older positions are stale
+    /// (the pointer has left every plot in the group).
+    pass: u64,
+}
+
+const CROSSHAIR_COLOR: egui::Color32 = egui::Color32::from_rgb(160, 160, 160);
+
+/// [`plot_with_y_drag`] for plots stacked on a shared X axis. Hovering any plot
+/// in `group` draws a vertical crosshair at the same X on the others, each
+/// showing its own `series` values there in the [`HOVER_CORNER`]. The hovered
+/// plot keeps its usual `coordinates_formatter` label.
+pub fn plot_with_crosshair(
+    ui: &mut egui::Ui,
+    id_source: &str,
+    group: &str,
+    plot: Plot<'_>,
+    series: &[HoverSeries<'_>],
+    build_fn: impl FnOnce(&mut PlotUi),
+) {
+    let group_id = egui::Id::new(("crosshair_group", group));
+    let pass = ui.ctx().cumulative_pass_nr();
+    let shared_x = ui
+        .data(|d| d.get_temp::<CrosshairState>(group_id))
+        .filter(|s| s.pass + 1 >= pass)
+        .map(|s| s.x);
+
+    let mut hovered_x = None;
+    let mut crosshair_x = None;
+    let response = plot_with_y_drag(ui, id_source, plot, |plot_ui| {
+        if plot_ui.response().hovered() {
+            hovered_x = plot_ui.pointer_coordinate().map(|p| p.x);
+        } else if let Some(x) = shared_x {
+            // Only draw inside the current X range so the line never stretches auto-bounds
+            let bounds = plot_ui.plot_bounds();
+            if (bounds.min()[0]..=bounds.max()[0]).contains(&x) {
+                plot_ui.vline(VLine::new(x).color(CROSSHAIR_COLOR).width(1.0));
+                crosshair_x = Some(x);
+            }
+        }
+        build_fn(plot_ui);
+    });
+
+    if let Some(x) = hovered_x {
+        ui.data_mut(|d| d.insert_temp(group_id, CrosshairState { x, pass }));
+    } else if let Some(x) = crosshair_x {
+        let frame = response.transform.frame().shrink(4.0);
+        let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+        ui.painter().with_clip_rect(*response.transform.frame()).text(
+            frame.right_bottom(),
+            egui::Align2::RIGHT_BOTTOM,
+            hover_text(series, x),
+            font_id,
+            ui.visuals().text_color(),
+        );
+    }
+}
+
+// ── Regime ribbon ───────────────────────────────────────────────────────────
+
+/// One colored span of a ribbon row, covering X in `[start, end)`
+pub struct RibbonSegment {
+    pub start: f64,
+    pub end: f64,
+    pub color: egui::Color32,
+    /// Shown on hover, e.g. "High Vol since 2024-03-05"
+    pub label: String,
+}
+
+/// A named row of regime segments, sorted by X
+pub struct RibbonRow {
+    pub name: String,
+    pub segments: Vec<RibbonSegment>,
+}
+
+/// Height of one ribbon row in pixels (plus axis padding for the strip)
+const RIBBON_ROW_HEIGHT: f32 = 14.0;
+
+pub fn vol_regime_color(regime: VolRegime) -> egui::Color32 {
+    match regime {
+        VolRegime::Low => palette::positive(),
+        VolRegime::Normal => palette::neutral(),
+        VolRegime::High => palette::negative(),
+    }
+}
+
+pub fn vol_regime_label(regime: VolRegime) -> String {
+    tr(match regime {
+        VolRegime::Low => "regime-low-vol",
+        VolRegime::Normal => "regime-normal-vol",
+        VolRegime::High => "regime-high-vol",
+    })
+}
+
+pub fn correlation_regime_label(regime: CorrelationRegime) -> String {
+    tr(match regime {
+        CorrelationRegime::Dispersed => "regime-dispersed",
+        CorrelationRegime::Normal => "regime-normal",
+        CorrelationRegime::Herding => "regime-herding",
+    })
+}
+
+pub fn correlation_regime_color(regime: CorrelationRegime) -> egui::Color32 {
+    match regime {
+        CorrelationRegime::Dispersed => palette::positive(),
+        CorrelationRegime::Normal => palette::neutral(),
+        CorrelationRegime::Herding => palette::negative(),
+    }
+}
+
+/// Thin strip of colored regime rows (first row on top) that shares `group`'s
+/// crosshair and linked X axis, so it lines up beneath the charts it annotates.
+/// Hovering shows each row's regime label at the cursor.
+pub fn regime_ribbon(ui: &mut egui::Ui, id_source: &str, group: &str, rows: &[RibbonRow]) {
+    if rows.iter().all(|r| r.segments.is_empty()) {
+        return;
+    }
+    let n_rows = rows.len() as f64;
+    // Row i occupies y in [n_rows - i - 1, n_rows - i), so the first row is drawn on top
+    let row_y = move |i: usize| n_rows - i as f64 - 1.0;
+
+    let formatter = CoordinatesFormatter::new(move |cursor: &PlotPoint, _bounds: &PlotBounds| {
+        rows.iter()
+            .filter_map(|row| {
+                let seg = row.segments.iter().find(|s| (s.start..s.end).contains(&cursor.x))?;
+                Some(format!("{}: {}", row.name, seg.label))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+
+    plot_with_crosshair(
+        ui,
+        id_source,
+        group,
+        linked_x_interaction(
+            Plot::new(id_source)
+                .height(RIBBON_ROW_HEIGHT * rows.len() as f32 + 24.0)
+                .include_y(0.0)
+                .include_y(n_rows)
+                .show_grid(Vec2b::new(false, false))
+                .y_axis_formatter(move |mark, _range| {
+                    let i = (n_rows - mark.value - 0.5).round();
+                    if i >= 0.0 && (mark.value.fract() - 0.5).abs() < 1e-6 {
+                        rows.get(i as usize).map(|r| r.name.clone()).unwrap_or_default()
+                    } else {
+                        String::new()
+                    }
+                })
+                .y_grid_spacer(move |_input| {
+                    (0..rows.len())
+                        .map(|i| egui_plot::GridMark { value: row_y(i) + 0.5, step_size: 1.0 })
+                        .collect()
+                }),
+            group,
+        )
+            .allow_drag(Vec2b::new(true, false))
+            .allow_zoom(Vec2b::new(true, false))
+            .coordinates_formatter(HOVER_CORNER, formatter)
+            .label_formatter(no_hover_label),
+        &[],
+        |plot_ui| {
+            for (i, row) in rows.iter().enumerate() {
+                let (lo, hi) = (row_y(i) + 0.1, row_y(i) + 0.9);
+                for seg in &row.segments {
+                    plot_ui.polygon(
+                        Polygon::new(PlotPoints::new(vec![
+                            [seg.start, lo],
+                            [seg.end, lo],
+                            [seg.end, hi],
+                            [seg.start, hi],
+                        ]))
+                        .fill_color(seg.color.gamma_multiply(0.8))
+                        .stroke(egui::Stroke::NONE)
+                        .allow_hover(false),
+                    );
+                }
+            }
+        },
+    );
+}
+
+// ── Event markers ───────────────────────────────────────────────────────────
+
+/// A [`ChartEvent`] positioned on a chart's X axis
+pub struct EventMarker {
+    pub x: f64,
+    pub kind: EventKind,
+    pub label: String,
+}
+
+pub fn event_kind_label(kind: EventKind) -> String {
+    tr(match kind {
+        EventKind::Fomc => "event-fomc",
+        EventKind::Cpi => "event-cpi",
+        EventKind::Earnings => "event-earnings",
+        EventKind::Inversion => "event-inversion",
+        EventKind::Note => "event-note",
+    })
+}
+
+pub fn event_color(kind: EventKind) -> egui::Color32 {
+    match kind {
+        EventKind::Fomc => egui::Color32::from_rgb(100, 150, 255),
+        EventKind::Cpi => egui::Color32::from_rgb(220, 180, 50),
+        EventKind::Earnings => egui::Color32::from_rgb(180, 100, 220),
+        EventKind::Inversion => egui::Color32::from_rgb(220, 50, 50),
+        EventKind::Note => egui::Color32::from_rgb(50, 180, 50),
+    }
+}
+
+/// Place `events` on a chart whose X value `i` is the trading day `dates[i]`
+/// (sorted ascending). Events on non-trading days snap to the next trading day;
+/// events outside the dates' range are dropped.
+pub fn event_markers(events: &[ChartEvent], dates: &[NaiveDate]) -> Vec<EventMarker> {
+    let (Some(first), Some(last)) = (dates.first(), dates.last()) else {
+        return Vec::new();
+    };
+    events
+        .iter()
+        .filter(|e| (*first..=*last).contains(&e.date))
+        .map(|e| EventMarker {
+            x: dates.partition_point(|d| *d < e.date) as f64,
+            kind: e.kind,
+            label: format!("{} {}", i18n::date(e.date), e.label),
+        })
+        .collect()
+}
+
+/// Draw `markers` as dashed vertical lines. Call from a plot's build closure.
+pub fn draw_event_markers(plot_ui: &mut PlotUi, markers: &[EventMarker]) {
+    for m in markers {
+        plot_ui.vline(
+            VLine::new(m.x)
+                .color(event_color(m.kind).gamma_multiply(0.7))
+                .width(1.0)
+                .style(egui_plot::LineStyle::dashed_loose()),
+        );
+    }
+}
+
+/// [`hover_formatter`] that also lists the labels of any `markers` on the
+/// trading day under the cursor.
+pub fn hover_formatter_with_events<'a>(
+    series: &'a [HoverSeries<'a>],
+    markers: &'a [EventMarker],
+) -> CoordinatesFormatter<'a> {
+    CoordinatesFormatter::new(move |cursor: &PlotPoint, _bounds: &PlotBounds| {
+        let mut text = hover_text(series, cursor.x);
+        for m in markers.iter().filter(|m| (m.x - cursor.x).abs() <= 0.5) {
+            text.push_str("\n▶ ");
+            text.push_str(&m.label);
+        }
+        text
+    })
 }
 
 // ── Plot interaction presets ─────────────────────────────────────────────────
@@ -193,12 +696,133 @@ pub fn plot_with_y_drag<S: std::hash::Hash>(
 /// - Drag: Y-axis only (vertical panning)
 /// - Scroll/zoom: Y-axis only (vertical stretch/compress)
 /// - X-axis is locked on both axes; double-click resets to auto-bounds.
+/// - Shift+drag box zoom (both axes) comes from [`plot_with_y_drag`].
 pub fn default_plot_interaction(plot: Plot<'_>) -> Plot<'_> {
     plot.allow_drag(Vec2b::new(false, true))
         .allow_scroll(false)
         .allow_zoom(Vec2b::new(false, true))
 }
 
+/// [`default_plot_interaction`] with the X axis unlocked and linked across every
+/// plot in `group`: panning (drag) or zooming (Ctrl+scroll, Shift+drag box) any of
+/// them moves the others' X bounds in lockstep. Y bounds stay per plot, and a
+/// double-click resets the group to auto-bounds.
+pub fn linked_x_interaction<'a>(plot: Plot<'a>, group: &str) -> Plot<'a> {
+    default_plot_interaction(plot)
+        .allow_drag(Vec2b::new(true, true))
+        .allow_zoom(Vec2b::new(true, true))
+        .link_axis(egui::Id::new(("x_link_group", group)), Vec2b::new(true, false))
+}
+
+/// Height of the range brush overview strip in pixels
+const BRUSH_HEIGHT: f32 = 48.0;
+
+/// Part of the range brush a drag started on
+#[derive(Debug, Clone, Copy)]
+enum BrushDrag {
+    Start,
+    End,
+    /// Moving the whole selection; offset of the grab point from the selection start
+    Move(usize),
+    /// Drawing a new selection from the anchor index
+    New(usize),
+}
+
+/// Overview strip of `values` with a selectable sub-range (inclusive indices). Drag a
+/// handle to resize, drag inside the selection to move it, drag elsewhere to draw a new
+/// one; double-click selects everything. Returns whether `range` changed.
+pub fn range_brush(ui: &mut egui::Ui, id_source: &str, values: &[f64], range: &mut (usize, usize)) -> bool {
+    let n = values.len();
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(ui.available_width(), BRUSH_HEIGHT), egui::Sense::click_and_drag());
+    if n < 2 {
+        return false;
+    }
+    let last = n - 1;
+    let x_of = |i: usize| rect.left() + rect.width() * i as f32 / last as f32;
+    let index_of = |x: f32| (((x - rect.left()) / rect.width()).clamp(0.0, 1.0) * last as f32).round() as usize;
+
+    let before = *range;
+    let drag_id = ui.id().with(("range_brush", id_source));
+    if response.double_clicked() {
+        *range = (0, last);
+    } else if let Some(pos) = response.interact_pointer_pos() {
+        let i = index_of(pos.x);
+        if response.drag_started() {
+            const GRAB: f32 = 6.0;
+            let drag = if (pos.x - x_of(range.0)).abs() <= GRAB {
+                BrushDrag::Start
+            } else if (pos.x - x_of(range.1)).abs() <= GRAB {
+                BrushDrag::End
+            } else if (range.0..=range.1).contains(&i) {
+                BrushDrag::Move(i - range.0)
+            } else {
+                BrushDrag::New(i)
+            };
+            ui.data_mut(|d| d.insert_temp(drag_id, drag));
+        }
+        if response.dragged() {
+            match ui.data(|d| d.get_temp::<BrushDrag>(drag_id)) {
+                Some(BrushDrag::Start) => range.0 = i.min(range.1.saturating_sub(1)),
+                Some(BrushDrag::End) => range.1 = i.max(range.0 + 1).min(last),
+                Some(BrushDrag::Move(grab)) => {
+                    let width = range.1 - range.0;
+                    let start = i.saturating_sub(grab).min(last - width);
+                    *range = (start, start + width);
+                }
+                Some(BrushDrag::New(anchor)) if i != anchor => *range = (anchor.min(i), anchor.max(i)),
+                _ => {}
+            }
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+    let (lo, hi) = values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+    let span = (hi - lo).max(f64::EPSILON);
+    let points: Vec<egui::Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| egui::pos2(x_of(i), rect.bottom() - 4.0 - ((v - lo) / span) as f32 * (rect.height() - 8.0)))
+        .collect();
+    painter.add(egui::Shape::line(points, egui::Stroke::new(1.0, egui::Color32::from_rgb(100, 150, 255))));
+
+    // Dim everything outside the selection and mark its edges with handles
+    let shade = egui::Color32::from_black_alpha(110);
+    let (x0, x1) = (x_of(range.0), x_of(range.1));
+    painter.rect_filled(egui::Rect::from_x_y_ranges(rect.left()..=x0, rect.y_range()), 0.0, shade);
+    painter.rect_filled(egui::Rect::from_x_y_ranges(x1..=rect.right(), rect.y_range()), 0.0, shade);
+    let handle_color = visuals.strong_text_color();
+    for x in [x0, x1] {
+        painter.line_segment([egui::pos2(x, rect.top()), egui::pos2(x, rect.bottom())], egui::Stroke::new(2.0, handle_color));
+        painter.rect_filled(egui::Rect::from_center_size(egui::pos2(x, rect.center().y), egui::vec2(6.0, 14.0)), 2.0, handle_color);
+    }
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(egui::CursorIcon::ResizeHorizontal);
+    }
+
+    *range != before
+}
+
+/// Persisted open state of a collapsible section, for `CollapsingHeader::open`.
+/// Pair with `track_section` after `show` so header clicks are remembered.
+pub fn section_open(sections: &BTreeMap<String, bool>, key: &str, default_open: bool) -> bool {
+    sections.get(key).copied().unwrap_or(default_open)
+}
+
+/// Flip a section's persisted state when its header was clicked this frame
+pub fn track_section<R>(
+    sections: &mut BTreeMap<String, bool>,
+    key: &str,
+    open: bool,
+    response: &egui::CollapsingResponse<R>,
+) {
+    if response.header_response.clicked() {
+        sections.insert(key.to_string(), !open);
+    }
+}
+
 /// Inline height-adjustment drag control placed immediately above a chart.
 /// Allows all drawn charts to be vertically resized via a shared implementation.
 pub fn height_control(ui: &mut egui::Ui, height: &mut f32, label: &str) {
@@ -217,9 +841,43 @@ pub fn height_control(ui: &mut egui::Ui, height: &mut f32, label: &str) {
                             .range(80.0..=800.0)
                             .suffix(" px"),
                     );
-                    ui.colored_label(egui::Color32::from_gray(130), "drag to resize ·");
+                    ui.colored_label(egui::Color32::from_gray(130), tr("chart-drag-to-resize"));
                 });
             });
         });
     ui.add_space(2.0);
 }
+
+/// "Export CSV" button that builds `table` only when clicked, writes it under
+/// `exports/`, and reports the file path (or error) as a notice.
+pub fn export_csv_button(
+    ui: &mut egui::Ui,
+    notices: &mut Notices,
+    name: &str,
+    table: impl FnOnce() -> crate::data::export::CsvTable,
+) {
+    if ui.button(tr("export-csv")).on_hover_text(tr("export-csv-hover")).clicked() {
+        let result = crate::data::export::write_csv(name, &table())
+            .map(|path| tr_args("export-csv-done", &[("path", path.display().to_string())]))
+            .map_err(|e| tr_args("export-csv-failed", &[("error", e)]));
+        notices.report(NoticeSource::Export, result);
+    }
+}
+
+/// Right-click menu on `response` with "Copy table", which puts `table` on the
+/// clipboard as tab-separated text so it pastes into Excel as cells. The table is only
+/// built when the item is picked.
+pub fn copy_table_menu(
+    response: &egui::Response,
+    notices: &mut Notices,
+    table: impl FnOnce() -> crate::data::export::CsvTable,
+) {
+    response.context_menu(|ui| {
+        if ui.button(tr("copy-table")).clicked() {
+            let table = table();
+            ui.ctx().copy_text(table.to_tsv());
+            notices.info(NoticeSource::Export, tr_args("copy-table-done", &[("count", i18n::integer(table.rows.len()))]));
+            ui.close_menu();
+        }
+    });
+}
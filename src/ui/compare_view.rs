@@ -0,0 +1,151 @@
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot};
+
+use crate::analysis;
+use crate::app::{AppState, CompareMetric};
+use crate::data::models::{OhlcvBar, SectorTimeSeries};
+use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("compare-heading"));
+    ui.add_space(8.0);
+
+    if state.market_data.sectors.is_empty() {
+        ui.label(tr("no-data-loaded"));
+        return;
+    }
+
+    // Sector selection (kept in sector order so legend colors stay stable)
+    let all_symbols: Vec<String> = state.market_data.sectors.iter().map(|s| s.symbol.clone()).collect();
+    ui.horizontal_wrapped(|ui| {
+        ui.label(tr("compare-sectors"));
+        for sym in &all_symbols {
+            let mut selected = state.compare.symbols.contains(sym);
+            if ui.checkbox(&mut selected, sym).changed() {
+                if selected {
+                    state.compare.symbols.push(sym.clone());
+                } else {
+                    state.compare.symbols.retain(|s| s != sym);
+                }
+            }
+        }
+        ui.separator();
+        if ui.small_button(tr("compare-all")).clicked() {
+            state.compare.symbols = all_symbols.clone();
+        }
+        if ui.small_button(tr("compare-none")).clicked() {
+            state.compare.symbols.clear();
+        }
+    });
+    state.compare.symbols.sort_by_key(|s| all_symbols.iter().position(|a| a == s));
+
+    ui.horizontal(|ui| {
+        ui.label(tr("compare-metric"));
+        ui.selectable_value(&mut state.compare.metric, CompareMetric::NormalizedPrice, tr("compare-normalized-price"));
+        ui.selectable_value(
+            &mut state.compare.metric,
+            CompareMetric::ShortVol,
+            tr_args("compare-short-vol", &[("days", state.analysis_windows.short_vol.to_string())]),
+        );
+    });
+    ui.add_space(8.0);
+
+    let selected: Vec<&SectorTimeSeries> = state
+        .market_data
+        .sectors
+        .iter()
+        .filter(|s| state.compare.symbols.contains(&s.symbol))
+        .collect();
+    if selected.is_empty() {
+        ui.label(tr("compare-select-sectors"));
+        return;
+    }
+
+    // Overlay over the window every selected sector covers, aligned on the latest bar
+    let common_len = selected.iter().map(|s| s.bars.len()).min().unwrap_or(0);
+    let series: Vec<(String, Vec<[f64; 2]>)> = selected
+        .iter()
+        .map(|s| {
+            let bars = &s.bars[s.bars.len() - common_len..];
+            (s.symbol.clone(), overlay_series(bars, state.compare.metric, state.analysis_windows.short_vol))
+        })
+        .collect();
+
+    let (y_label, decimals, suffix) = match state.compare.metric {
+        CompareMetric::NormalizedPrice => (tr("compare-axis-rebased"), 1, ""),
+        CompareMetric::ShortVol => (tr("compare-axis-vol"), 1, "%"),
+    };
+    let hover: Vec<HoverSeries> = series
+        .iter()
+        .map(|(name, data)| HoverSeries { name, data, decimals, suffix })
+        .collect();
+
+    height_control(ui, &mut state.chart_heights.compare_overlay, &tr("compare-chart-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "compare_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("compare_plot")
+                .height(state.chart_heights.compare_overlay)
+                .include_x(0.0),
+        )
+            .x_axis_label(tr("axis-trading-day-plain"))
+            .y_axis_label(y_label)
+            .legend(Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            for (name, data) in &series {
+                let points = chart_utils::plot_points(data);
+                plot_ui.line(Line::new(points).name(name));
+            }
+        },
+    );
+
+    // Change over the window, for a quick ranking alongside the chart
+    ui.add_space(8.0);
+    egui::Grid::new("compare_summary").striped(true).show(ui, |ui| {
+        ui.strong(tr("col-symbol"));
+        ui.strong(tr("compare-start"));
+        ui.strong(tr("compare-end"));
+        ui.strong(tr("compare-change"));
+        ui.end_row();
+        for (name, data) in &series {
+            let (Some(first), Some(last)) = (data.first(), data.last()) else { continue };
+            ui.label(name);
+            ui.label(format!("{}{}", i18n::number(first[1], 1), suffix));
+            ui.label(format!("{}{}", i18n::number(last[1], 1), suffix));
+            // Rebased prices start at 100, so their change is already a percentage
+            let change = i18n::signed(last[1] - first[1], 1);
+            ui.label(match state.compare.metric {
+                CompareMetric::NormalizedPrice => tr_args("unit-percent", &[("value", change)]),
+                CompareMetric::ShortVol => tr_args("unit-pts", &[("value", change)]),
+            });
+            ui.end_row();
+        }
+    });
+}
+
+/// Plot points `[trading day, value]` for one sector over the common window `bars`.
+fn overlay_series(bars: &[OhlcvBar], metric: CompareMetric, short_window: usize) -> Vec<[f64; 2]> {
+    match metric {
+        CompareMetric::NormalizedPrice => {
+            let base = bars.first().map_or(1.0, |b| b.close);
+            bars.iter()
+                .enumerate()
+                .map(|(i, b)| [i as f64, b.close / base * 100.0])
+                .collect()
+        }
+        CompareMetric::ShortVol => {
+            let log_returns: Vec<f64> = bars.windows(2).map(|w| (w[1].close / w[0].close).ln()).collect();
+            let vols = analysis::volatility::rolling_volatility(&log_returns, short_window);
+            // vols[k] ends on bar k + short_window
+            let offset = bars.len() - vols.len();
+            vols.iter()
+                .enumerate()
+                .map(|(k, v)| [(offset + k) as f64, v * 100.0])
+                .collect()
+        }
+    }
+}
@@ -1,74 +1,130 @@
+use std::collections::BTreeMap;
+
 use eframe::egui;
+use egui_plot::{AxisHints, Line, Plot, PlotPoints, Points, Polygon};
 
+use crate::analysis;
+use crate::analysis::regimes::{CorrelationRegime, DISPERSED_CORR, HERDING_CORR};
 use crate::app::AppState;
+use crate::config;
+use crate::data::models::{CorrelationMatrix, VolatilityMetrics};
+use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::metric_info::{self, Metric};
+use crate::ui::palette;
+
+/// Trailing window choices for the matrix (trading days)
+const MATRIX_WINDOWS: [usize; 3] = [30, 63, 126];
+
+/// Default look-back of the change matrix (about a month)
+const DEFAULT_DELTA_LAG: usize = 21;
+
+/// Correlation change shaded at full intensity in the change matrix
+const DELTA_FULL_SCALE: f64 = 0.5;
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Cross-Sector Correlation Matrix");
+    ui.heading(tr("corr-heading"));
     ui.add_space(8.0);
 
-    let corr = match &state.analysis.correlation {
-        Some(c) if !c.symbols.is_empty() => c,
-        _ => {
-            ui.label("No correlation data available. Load market data first.");
-            return;
-        }
-    };
+    if state.analysis.correlation.as_ref().is_none_or(|c| c.symbols.is_empty()) {
+        ui.label(tr("corr-no-data"));
+        return;
+    }
 
-    ui.label(format!(
-        "Average cross-sector correlation: {:.3}",
-        state.analysis.avg_cross_correlation
-    ));
-    ui.add_space(8.0);
+    render_rolling_correlation(ui, state);
+    ui.add_space(16.0);
+    render_dispersion(ui, state);
+    ui.add_space(16.0);
+
+    let (windowed, delta) = render_window_selector(ui, state);
+    let Some(corr) = windowed.as_ref().or(state.analysis.correlation.as_ref()) else { return };
+    let mut export_name = match state.corr_window {
+        Some(days) => format!("correlation_{}d", days),
+        None => "correlation_full".to_string(),
+    };
+    if let Some(lag) = state.corr_delta_lag.filter(|_| delta.is_some()) {
+        export_name = format!("{}_change_{}d", export_name, lag);
+    }
+    // The grid shows the change when delta mode is on; the network and pair panel
+    // always work from levels
+    let shown = delta.as_ref().unwrap_or(corr);
+    chart_utils::export_csv_button(ui, &mut state.notices, &export_name, || {
+        crate::data::export::correlation_table(shown)
+    });
+    ui.add_space(4.0);
 
     // Render the correlation matrix as a colored grid
     let n = corr.symbols.len();
     let cell_size = 48.0;
+    let mut clicked_pair = None;
 
     egui::ScrollArea::both().show(ui, |ui| {
         // ID: <mgrid>
-        egui::Grid::new("corr_matrix")
-            .min_col_width(cell_size)
-            .max_col_width(cell_size)
-            .spacing(egui::vec2(2.0, 2.0))
-            .show(ui, |ui| {
-                // Header row
-                ui.label(""); // empty corner cell
-                for sym in &corr.symbols {
-                    ui.vertical_centered(|ui| {
-                        ui.small(sym);
+        let table_ui = egui::UiBuilder::new().sense(egui::Sense::click());
+        let table_response = ui
+            .scope_builder(table_ui, |ui| {
+                egui::Grid::new("corr_matrix")
+                    .min_col_width(cell_size)
+                    .max_col_width(cell_size)
+                    .spacing(egui::vec2(2.0, 2.0))
+                    .show(ui, |ui| {
+                        // Header row
+                        ui.label(""); // empty corner cell
+                        for sym in &corr.symbols {
+                            ui.vertical_centered(|ui| {
+                                ui.small(sym);
+                            });
+                        }
+                        ui.end_row();
+
+                        // Data rows
+                        for i in 0..n {
+                            ui.small(&corr.symbols[i]);
+                            for j in 0..n {
+                                let val = shown.matrix[i][j];
+                                let shade = if delta.is_some() { val / DELTA_FULL_SCALE } else { val };
+                                let color = palette::correlation_color(shade);
+                                let text_color = if shade.abs() > 0.5 {
+                                    egui::Color32::WHITE
+                                } else {
+                                    egui::Color32::BLACK
+                                };
+
+                                let sense = if i == j { egui::Sense::hover() } else { egui::Sense::click() };
+                                let (rect, resp) = ui.allocate_exact_size(egui::vec2(cell_size, 24.0), sense);
+                                if i != j {
+                                    let resp = resp
+                                        .on_hover_cursor(egui::CursorIcon::PointingHand)
+                                        .on_hover_text(tr_args(
+                                            "corr-cell-hover",
+                                            &[("a", corr.symbols[i].clone()), ("b", corr.symbols[j].clone())],
+                                        ));
+                                    if resp.clicked() {
+                                        clicked_pair = Some((corr.symbols[i].clone(), corr.symbols[j].clone()));
+                                    }
+                                    chart_utils::copy_table_menu(&resp, &mut state.notices, || {
+                                        crate::data::export::correlation_table(shown)
+                                    });
+                                }
+                                ui.painter().rect_filled(rect, 2.0, color);
+                                let text = if delta.is_some() && i != j { i18n::signed(val, 2) } else { i18n::number(val, 2) };
+                                ui.painter().text(
+                                    rect.center(),
+                                    egui::Align2::CENTER_CENTER,
+                                    text,
+                                    egui::FontId::proportional(11.0),
+                                    text_color,
+                                );
+                            }
+                            ui.end_row();
+                        }
                     });
-                }
-                ui.end_row();
-
-                // Data rows
-                for i in 0..n {
-                    ui.small(&corr.symbols[i]);
-                    for j in 0..n {
-                        let val = corr.matrix[i][j];
-                        let color = correlation_color(val);
-                        let text_color = if val.abs() > 0.5 {
-                            egui::Color32::WHITE
-                        } else {
-                            egui::Color32::BLACK
-                        };
-
-                        let (rect, _resp) = ui.allocate_exact_size(
-                            egui::vec2(cell_size, 24.0),
-                            egui::Sense::hover(),
-                        );
-                        ui.painter().rect_filled(rect, 2.0, color);
-                        ui.painter().text(
-                            rect.center(),
-                            egui::Align2::CENTER_CENTER,
-                            format!("{:.2}", val),
-                            egui::FontId::proportional(11.0),
-                            text_color,
-                        );
-                    }
-                    ui.end_row();
-                }
-            });
-            // ID: </mgrid>
+            })
+            .response;
+        // ID: </mgrid>
+        chart_utils::copy_table_menu(&table_response, &mut state.notices, || {
+            crate::data::export::correlation_table(shown)
+        });
     });
 
     ui.add_space(16.0);
@@ -77,32 +133,552 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Color legend
     ui.horizontal(|ui| {
-        ui.label("Legend: ");
-        color_swatch(ui, egui::Color32::from_rgb(220, 50, 50), "-1.0");
-        color_swatch(ui, egui::Color32::from_rgb(240, 240, 240), " 0.0");
-        color_swatch(ui, egui::Color32::from_rgb(50, 50, 220), "+1.0");
+        ui.label(tr("corr-legend"));
+        let zero = format!(" {}", i18n::number(0.0, 1));
+        if delta.is_some() {
+            let less = tr_args("corr-less-correlated", &[("value", i18n::signed(-DELTA_FULL_SCALE, 1))]);
+            let more = tr_args("corr-more-correlated", &[("value", i18n::signed(DELTA_FULL_SCALE, 1))]);
+            color_swatch(ui, palette::correlation_extreme(-1.0), &less);
+            color_swatch(ui, palette::correlation_color(0.0), &zero);
+            color_swatch(ui, palette::correlation_extreme(1.0), &more);
+        } else {
+            color_swatch(ui, palette::correlation_extreme(-1.0), &i18n::signed(-1.0, 1));
+            color_swatch(ui, palette::correlation_color(0.0), &zero);
+            color_swatch(ui, palette::correlation_extreme(1.0), &i18n::signed(1.0, 1));
+        }
     });
+
+    ui.add_space(16.0);
+    ui.separator();
+    ui.add_space(8.0);
+    render_network(
+        ui,
+        corr,
+        &state.analysis.volatility,
+        state.analysis_windows.short_vol,
+        &mut state.corr_network,
+        &mut state.chart_heights.corr_network,
+    );
+
+    if clicked_pair.is_some() {
+        state.corr_pair = clicked_pair;
+    }
+    if state.corr_pair.is_some() {
+        ui.add_space(16.0);
+        ui.separator();
+        ui.add_space(8.0);
+        render_pair_panel(ui, state);
+    }
 }
 
-fn correlation_color(val: f64) -> egui::Color32 {
-    let clamped = val.clamp(-1.0, 1.0);
-    if clamped >= 0.0 {
-        // White to blue
-        let t = clamped as f32;
-        egui::Color32::from_rgb(
-            (240.0 * (1.0 - t)) as u8,
-            (240.0 * (1.0 - t)) as u8,
-            (240.0 * (1.0 - t) + 220.0 * t) as u8,
-        )
+/// Minimum spanning tree of the matrix as a node-link diagram: node size and color
+/// follow each sector's short-window vol, edge width and color its correlation.
+/// Nodes can be dragged; positions persist in `layout` until reset.
+fn render_network(
+    ui: &mut egui::Ui,
+    corr: &CorrelationMatrix,
+    volatility: &[VolatilityMetrics],
+    short_window: usize,
+    layout: &mut BTreeMap<String, egui::Pos2>,
+    height: &mut f32,
+) {
+    let edges = analysis::cross_sector::minimum_spanning_tree(corr);
+    if edges.is_empty() {
+        return;
+    }
+    ui.horizontal(|ui| {
+        ui.strong(tr("corr-network-heading"));
+        if ui.small_button(tr("corr-reset-layout")).clicked() {
+            layout.clear();
+        }
+    });
+    if corr.symbols.iter().any(|s| !layout.contains_key(s)) {
+        let positions = force_layout(corr.symbols.len(), &edges);
+        *layout = corr.symbols.iter().cloned().zip(positions).collect();
+    }
+
+    let vols: Vec<Option<f64>> = corr
+        .symbols
+        .iter()
+        .map(|sym| volatility.iter().find(|v| &v.symbol == sym).and_then(|v| v.short_window_vol.last().copied()))
+        .collect();
+    let (vol_lo, vol_hi) = vols
+        .iter()
+        .flatten()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+    let radius = |vol: Option<f64>| match vol {
+        Some(v) if vol_hi > vol_lo => 8.0 + 14.0 * ((v - vol_lo) / (vol_hi - vol_lo)) as f32,
+        _ => 12.0,
+    };
+
+    height_control(ui, height, &tr("corr-network-height"));
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), *height), egui::Sense::hover());
+    let to_screen = |p: egui::Pos2| rect.min + egui::vec2(p.x * rect.width(), p.y * rect.height());
+
+    // Drag before painting so moved nodes draw at their new position this frame
+    for (i, sym) in corr.symbols.iter().enumerate() {
+        let r = radius(vols[i]);
+        let pos = layout[sym];
+        let resp = ui
+            .interact(
+                egui::Rect::from_center_size(to_screen(pos), egui::vec2(2.0 * r, 2.0 * r)),
+                ui.id().with(("corr_network_node", sym)),
+                egui::Sense::drag(),
+            )
+            .on_hover_cursor(egui::CursorIcon::Grab)
+            .on_hover_text(tr_args(
+                "corr-node-hover",
+                &[
+                    ("symbol", sym.clone()),
+                    ("days", short_window.to_string()),
+                    ("vol", vols[i].map_or("-".to_string(), |v| i18n::percent(v, 1))),
+                ],
+            ));
+        if resp.dragged() {
+            let delta = resp.drag_delta();
+            let moved = pos + egui::vec2(delta.x / rect.width(), delta.y / rect.height());
+            layout.insert(sym.clone(), egui::pos2(moved.x.clamp(0.02, 0.98), moved.y.clamp(0.02, 0.98)));
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect_stroke(rect, 4.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    for &(i, j, rho) in &edges {
+        let (a, b) = (to_screen(layout[&corr.symbols[i]]), to_screen(layout[&corr.symbols[j]]));
+        let color = palette::correlation_extreme(rho).gamma_multiply(0.3 + 0.7 * rho.abs() as f32);
+        painter.line_segment([a, b], egui::Stroke::new(1.0 + 6.0 * rho.abs() as f32, color));
+        painter.text(
+            a + (b - a) * 0.5,
+            egui::Align2::CENTER_BOTTOM,
+            i18n::number(rho, 2),
+            egui::FontId::proportional(10.0),
+            ui.visuals().weak_text_color(),
+        );
+    }
+    for (i, sym) in corr.symbols.iter().enumerate() {
+        let center = to_screen(layout[sym]);
+        let fill = vols[i].map_or(egui::Color32::GRAY, palette::vol_color);
+        painter.circle(center, radius(vols[i]), fill, egui::Stroke::new(1.0, egui::Color32::BLACK));
+        painter.text(
+            center,
+            egui::Align2::CENTER_CENTER,
+            sym,
+            egui::FontId::proportional(11.0),
+            egui::Color32::BLACK,
+        );
+    }
+    ui.small(tr_args("corr-network-note", &[("days", short_window.to_string())]));
+}
+
+/// Fruchterman-Reingold spring layout in the unit square, seeded on a circle so the
+/// result is deterministic
+fn force_layout(n: usize, edges: &[(usize, usize, f64)]) -> Vec<egui::Pos2> {
+    let mut pos: Vec<egui::Vec2> = (0..n)
+        .map(|i| {
+            let angle = i as f32 / n as f32 * std::f32::consts::TAU;
+            egui::vec2(0.5 + 0.4 * angle.cos(), 0.5 + 0.4 * angle.sin())
+        })
+        .collect();
+    let k = (1.0 / n as f32).sqrt();
+    let iterations = 300;
+    for step in 0..iterations {
+        let mut disp = vec![egui::Vec2::ZERO; n];
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = pos[i] - pos[j];
+                let len = d.length().max(1e-3);
+                let push = d / len * (k * k / len);
+                disp[i] += push;
+                disp[j] -= push;
+            }
+        }
+        for &(i, j, _) in edges {
+            let d = pos[i] - pos[j];
+            let len = d.length().max(1e-3);
+            let pull = d / len * (len * len / k);
+            disp[i] -= pull;
+            disp[j] += pull;
+        }
+        let temperature = 0.1 * (1.0 - step as f32 / iterations as f32);
+        for (p, d) in pos.iter_mut().zip(&disp) {
+            let len = d.length().max(1e-9);
+            *p += *d / len * len.min(temperature);
+            *p = p.clamp(egui::vec2(0.05, 0.05), egui::vec2(0.95, 0.95));
+        }
+    }
+    pos.into_iter().map(|p| p.to_pos2()).collect()
+}
+
+/// Drill-down for one matrix cell: the pair's rolling correlation, a scatter of their
+/// daily returns, and the beta of the second sector on the first.
+fn render_pair_panel(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some((sym_a, sym_b)) = state.corr_pair.clone() else { return };
+    let find = |sym: &str| state.market_data.sectors.iter().find(|s| s.symbol == sym).map(|s| s.log_returns());
+    let (Some(returns_a), Some(returns_b)) = (find(&sym_a), find(&sym_b)) else {
+        state.corr_pair = None;
+        return;
+    };
+    let n = returns_a.len().min(returns_b.len());
+    let (returns_a, returns_b) = (&returns_a[returns_a.len() - n..], &returns_b[returns_b.len() - n..]);
+
+    ui.horizontal(|ui| {
+        ui.heading(tr_args("corr-pair-heading", &[("a", sym_a.clone()), ("b", sym_b.clone())]));
+        if ui.small_button("✕").on_hover_text(tr("corr-close-pair")).clicked() {
+            state.corr_pair = None;
+        }
+    });
+    let beta = analysis::cross_sector::beta(returns_b, returns_a);
+    ui.label(tr_args(
+        "corr-pair-summary",
+        &[
+            ("correlation", i18n::number(analysis::cross_sector::pearson_correlation(returns_a, returns_b), 3)),
+            ("b", sym_b.clone()),
+            ("a", sym_a.clone()),
+            ("beta", beta.map_or("-".to_string(), |b| i18n::number(b, 2))),
+        ],
+    ));
+    ui.add_space(4.0);
+
+    let rolling = analysis::cross_sector::rolling_correlation(returns_a, returns_b, config::ROLLING_CORR_WINDOW);
+    if rolling.is_empty() {
+        ui.label(tr_args("corr-pair-short", &[("days", config::ROLLING_CORR_WINDOW.to_string())]));
     } else {
-        // White to red
-        let t = (-clamped) as f32;
-        egui::Color32::from_rgb(
-            (240.0 * (1.0 - t) + 220.0 * t) as u8,
-            (240.0 * (1.0 - t)) as u8,
-            (240.0 * (1.0 - t)) as u8,
+        ui.strong(tr_args("corr-pair-rolling", &[("days", config::ROLLING_CORR_WINDOW.to_string())]));
+        let data: Vec<[f64; 2]> = rolling
+            .iter()
+            .enumerate()
+            .map(|(i, v)| [(i + config::ROLLING_CORR_WINDOW) as f64, *v])
+            .collect();
+        let corr_name = tr("corr-correlation");
+        let hover = [HoverSeries { name: &corr_name, data: &data, decimals: 3, suffix: "" }];
+        height_control(ui, &mut state.chart_heights.pair_correlation, &tr("corr-pair-height"));
+        chart_utils::plot_with_y_drag(
+            ui,
+            "pair_corr_plot",
+            chart_utils::default_plot_interaction(
+                Plot::new("pair_corr_plot")
+                    .height(state.chart_heights.pair_correlation),
+            )
+                .x_axis_label(tr("axis-trading-day-plain"))
+                .y_axis_label(&corr_name)
+                .include_y(-1.0)
+                .include_y(1.0)
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+                .label_formatter(chart_utils::no_hover_label),
+            |plot_ui| {
+                plot_ui.line(
+                    Line::new(chart_utils::plot_points(&data))
+                        .name(&corr_name)
+                        .color(egui::Color32::from_rgb(100, 150, 255))
+                        .width(1.5),
+                );
+            },
+        );
+    }
+    ui.add_space(8.0);
+
+    ui.strong(tr("corr-daily-returns"));
+    let points: Vec<[f64; 2]> = returns_a.iter().zip(returns_b).map(|(a, b)| [a * 100.0, b * 100.0]).collect();
+    let (x_lo, x_hi) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| (lo.min(p[0]), hi.max(p[0])));
+    let mean_a = returns_a.iter().sum::<f64>() / n.max(1) as f64 * 100.0;
+    let mean_b = returns_b.iter().sum::<f64>() / n.max(1) as f64 * 100.0;
+    height_control(ui, &mut state.chart_heights.pair_scatter, &tr("corr-scatter-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "pair_scatter_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("pair_scatter_plot")
+                .height(state.chart_heights.pair_scatter)
+                .data_aspect(1.0),
+        )
+            .x_axis_label(format!("{} (%)", sym_a))
+            .y_axis_label(format!("{} (%)", sym_b))
+            .legend(egui_plot::Legend::default()),
+        |plot_ui| {
+            plot_ui.points(
+                Points::new(PlotPoints::from(points))
+                    .name(tr("corr-daily-returns-series"))
+                    .radius(1.5)
+                    .color(egui::Color32::from_rgb(100, 150, 255)),
+            );
+            if let (Some(b), true) = (beta, x_lo < x_hi) {
+                // Regression line through the means
+                let fit = |x: f64| mean_b + b * (x - mean_a);
+                plot_ui.line(
+                    Line::new(PlotPoints::from(vec![[x_lo, fit(x_lo)], [x_hi, fit(x_hi)]]))
+                        .name(format!("{} {}", tr("col-beta"), i18n::number(b, 2)))
+                        .color(egui::Color32::from_rgb(255, 150, 50))
+                        .width(1.5),
+                );
+            }
+        },
+    );
+}
+
+/// Window length and end-date controls for the matrix. Returns the matrix for the
+/// selected window, or `None` for the full sample (already in `state.analysis`).
+/// Window and end-date controls for the matrix. Returns the windowed matrix (`None`
+/// when the full-sample one in `analysis` applies) and, in change mode, the change
+/// versus the same window ending `corr_delta_lag` days earlier.
+fn render_window_selector(ui: &mut egui::Ui, state: &mut AppState) -> (Option<CorrelationMatrix>, Option<CorrelationMatrix>) {
+    let sectors = &state.market_data.sectors;
+    let symbols: Vec<String> = sectors.iter().map(|s| s.symbol.clone()).collect();
+    let returns: Vec<Vec<f64>> = sectors.iter().map(|s| s.log_returns()).collect();
+    let min_len = returns.iter().map(|r| r.len()).min().unwrap_or(0);
+
+    ui.horizontal(|ui| {
+        ui.label(tr("corr-window"));
+        for days in MATRIX_WINDOWS {
+            ui.add_enabled_ui(days <= min_len, |ui| {
+                ui.selectable_value(&mut state.corr_window, Some(days), tr_args("unit-days-short", &[("days", days.to_string())]));
+            });
+        }
+        ui.selectable_value(&mut state.corr_window, None, tr("corr-full-sample"));
+    });
+
+    let window = state.corr_window.filter(|&w| w <= min_len);
+    let max_offset = min_len.saturating_sub(window.unwrap_or(min_len));
+    state.corr_end_offset = state.corr_end_offset.min(max_offset);
+    if max_offset > 0 {
+        ui.horizontal(|ui| {
+            ui.label(tr("corr-ending"));
+            ui.add(egui::Slider::new(&mut state.corr_end_offset, 0..=max_offset).suffix(format!(" {}", tr("corr-days-ago"))));
+        });
+    }
+
+    let end = min_len - state.corr_end_offset;
+    let start = window.map_or(0, |w| end - w);
+
+    // The earlier window needs as many returns before it as the current one
+    let max_lag = end.saturating_sub(window.unwrap_or(2));
+    ui.horizontal(|ui| {
+        let mut on = state.corr_delta_lag.is_some();
+        ui.add_enabled_ui(max_lag > 0, |ui| {
+            ui.checkbox(&mut on, tr("corr-show-change"));
+        });
+        let mut lag = state.corr_delta_lag.unwrap_or(DEFAULT_DELTA_LAG).clamp(1, max_lag.max(1));
+        ui.add_enabled(on && max_lag > 0, egui::DragValue::new(&mut lag).range(1..=max_lag.max(1)));
+        ui.label(tr("corr-days-earlier"));
+        state.corr_delta_lag = (on && max_lag > 0).then_some(lag);
+    });
+    let window_matrix = |start: usize, end: usize| {
+        let sliced: Vec<Vec<f64>> = returns
+            .iter()
+            .map(|r| r[r.len() - min_len..][start..end].to_vec())
+            .collect();
+        analysis::cross_sector::compute_correlation_matrix(&symbols, &sliced)
+    };
+
+    // Aligned return k ends on bar (bars - min_len + k) of each sector
+    if let Some(first) = sectors.first() {
+        let date = |k: usize| first.bars.get(first.bars.len() - min_len + k).map(|b| i18n::date(b.date));
+        if let (Some(from), Some(to)) = (date(start), date(end.saturating_sub(1))) {
+            ui.label(tr_args("corr-sample", &[("from", from), ("to", to), ("days", i18n::integer(end - start))]));
+        }
+    }
+
+    let matrix = (window.is_some() || state.corr_end_offset > 0).then(|| window_matrix(start, end));
+    let delta = state.corr_delta_lag.map(|lag| {
+        let earlier = window_matrix(start.saturating_sub(lag), end - lag);
+        let current = matrix.as_ref().or(state.analysis.correlation.as_ref());
+        let change = current.map(|c| analysis::cross_sector::correlation_change(c, &earlier));
+        if let Some(first) = sectors.first() {
+            let date = |k: usize| first.bars.get(first.bars.len() - min_len + k).map(|b| i18n::date(b.date));
+            if let Some(to) = date(end - lag - 1) {
+                let average = change.as_ref().map_or(0.0, analysis::cross_sector::average_cross_correlation);
+                ui.label(tr_args("corr-change-versus", &[("date", to), ("change", i18n::signed(average, 3))]));
+            }
+        }
+        change
+    });
+    if let Some(matrix) = &matrix {
+        ui.label(tr_args(
+            "corr-window-average",
+            &[("value", i18n::number(analysis::cross_sector::average_cross_correlation(matrix), 3))],
+        ));
+    }
+    (matrix, delta.flatten())
+}
+
+/// Rolling average cross-sector correlation over time, shaded by correlation regime
+fn render_rolling_correlation(ui: &mut egui::Ui, state: &mut AppState) {
+    let rolling = &state.analysis.rolling_avg_correlation;
+    let Some(&latest) = rolling.last() else {
+        ui.label(tr_args(
+            "corr-average-short",
+            &[
+                ("value", i18n::number(state.analysis.avg_cross_correlation, 3)),
+                ("days", config::ROLLING_CORR_WINDOW.to_string()),
+            ],
+        ));
+        return;
+    };
+
+    let (regime, regime_color) = correlation_regime(latest);
+    ui.horizontal(|ui| {
+        let label = ui.label(tr_args(
+            "corr-rolling-average",
+            &[("days", config::ROLLING_CORR_WINDOW.to_string()), ("value", i18n::number(latest, 3))],
+        ));
+        metric_info::explain(label, state.explain_metrics, Metric::AvgCorrelation, &state.analysis_windows);
+        ui.colored_label(regime_color, regime);
+        ui.label(tr_args("corr-full-sample-value", &[("value", i18n::number(state.analysis.avg_cross_correlation, 3))]));
+    });
+
+    // X is the trading day each window ends on, counted from the first return
+    let data: Vec<[f64; 2]> = rolling
+        .iter()
+        .enumerate()
+        .map(|(i, v)| [(i + config::ROLLING_CORR_WINDOW) as f64, *v])
+        .collect();
+    let (x_min, x_max) = (data[0][0], data[data.len() - 1][0]);
+    let avg_name = tr("corr-avg-corr");
+    let hover = [HoverSeries { name: &avg_name, data: &data, decimals: 3, suffix: "" }];
+
+    let band = |lo: f64, hi: f64, color: egui::Color32| {
+        Polygon::new(PlotPoints::new(vec![[x_min, lo], [x_max, lo], [x_max, hi], [x_min, hi]]))
+            .fill_color(color.gamma_multiply(0.12))
+            .stroke(egui::Stroke::NONE)
+            .allow_hover(false)
+    };
+    let y_min = data.iter().map(|p| p[1]).fold(0.0_f64, f64::min);
+
+    height_control(ui, &mut state.chart_heights.rolling_correlation, &tr("corr-rolling-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "rolling_corr_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("rolling_corr_plot")
+                .height(state.chart_heights.rolling_correlation),
         )
+            .x_axis_label(tr("axis-trading-day-plain"))
+            .y_axis_label(tr("corr-axis-avg"))
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.polygon(band(y_min, DISPERSED_CORR, correlation_regime(y_min).1));
+            plot_ui.polygon(band(DISPERSED_CORR, HERDING_CORR, correlation_regime(DISPERSED_CORR).1));
+            plot_ui.polygon(band(HERDING_CORR, 1.0, correlation_regime(1.0).1));
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&data))
+                    .name(&avg_name)
+                    .color(egui::Color32::from_rgb(100, 150, 255))
+                    .width(1.5),
+            );
+        },
+    );
+    ui.horizontal(|ui| {
+        ui.label(tr("corr-bands"));
+        let (dispersed, normal, herding) = (correlation_regime(0.0), correlation_regime(DISPERSED_CORR), correlation_regime(1.0));
+        color_swatch(ui, dispersed.1, &format!("{} (< {})", dispersed.0, i18n::number(DISPERSED_CORR, 1)));
+        color_swatch(ui, normal.1, &normal.0);
+        color_swatch(ui, herding.1, &format!("{} (> {})", herding.0, i18n::number(HERDING_CORR, 1)));
+    });
+}
+
+/// Daily cross-sectional dispersion of sector returns with the rolling average
+/// correlation on a right-hand axis; herding shows up as the two lines diverging
+fn render_dispersion(ui: &mut egui::Ui, state: &mut AppState) {
+    let dispersion = &state.analysis.dispersion;
+    if dispersion.len() < 2 {
+        return;
+    }
+    ui.strong(tr("corr-dispersion-heading"));
+    let window = config::ROLLING_CORR_WINDOW;
+
+    // Same x as the rolling correlation chart: trading day counted from the first return
+    let disp_data: Vec<[f64; 2]> = dispersion.iter().enumerate().map(|(k, d)| [(k + 1) as f64, d * 100.0]).collect();
+    let disp_avg: Vec<[f64; 2]> = dispersion
+        .windows(window)
+        .enumerate()
+        .map(|(i, w)| [(i + window) as f64, w.iter().sum::<f64>() / window as f64 * 100.0])
+        .collect();
+    let corr_data: Vec<[f64; 2]> = state
+        .analysis
+        .rolling_avg_correlation
+        .iter()
+        .enumerate()
+        .map(|(i, v)| [(i + window) as f64, *v])
+        .collect();
+
+    // Correlation is drawn on the dispersion axis, mapping [corr_lo, 1] onto [0, disp_hi]
+    let disp_hi = disp_data.iter().map(|p| p[1]).fold(0.0_f64, f64::max).max(1e-6);
+    let corr_lo = corr_data.iter().map(|p| p[1]).fold(0.0_f64, f64::min);
+    let corr_span = 1.0 - corr_lo;
+    let to_axis = move |c: f64| (c - corr_lo) / corr_span * disp_hi;
+    let corr_points: Vec<[f64; 2]> = corr_data.iter().map(|[x, c]| [*x, to_axis(*c)]).collect();
+
+    if let (Some(d), Some(c)) = (disp_avg.last(), corr_data.last()) {
+        let (regime, color) = correlation_regime(c[1]);
+        ui.horizontal(|ui| {
+            ui.label(tr_args(
+                "corr-dispersion-summary",
+                &[
+                    ("days", window.to_string()),
+                    ("dispersion", i18n::percent(d[1] / 100.0, 2)),
+                    ("correlation", i18n::number(c[1], 3)),
+                ],
+            ));
+            ui.colored_label(color, regime);
+        });
     }
+
+    let (disp_name, disp_avg_name, avg_name) = (tr("corr-dispersion"), tr("corr-dispersion-avg"), tr("corr-avg-corr"));
+    let mut hover = vec![HoverSeries { name: &disp_name, data: &disp_data, decimals: 2, suffix: "%" }];
+    if !disp_avg.is_empty() {
+        hover.push(HoverSeries { name: &disp_avg_name, data: &disp_avg, decimals: 2, suffix: "%" });
+        hover.push(HoverSeries { name: &avg_name, data: &corr_data, decimals: 3, suffix: "" });
+    }
+    let axes = vec![
+        AxisHints::new_y().label(tr("corr-axis-dispersion")),
+        AxisHints::new_y()
+            .label(tr("corr-axis-avg"))
+            .placement(egui_plot::HPlacement::Right)
+            .formatter(move |mark, _range| i18n::number(corr_lo + mark.value / disp_hi * corr_span, 2)),
+    ];
+
+    height_control(ui, &mut state.chart_heights.dispersion, &tr("corr-dispersion-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "dispersion_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("dispersion_plot")
+                .height(state.chart_heights.dispersion)
+                .custom_y_axes(axes),
+        )
+            .x_axis_label(tr("axis-trading-day-plain"))
+            .include_y(0.0)
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&disp_data))
+                    .name(tr("corr-daily-dispersion"))
+                    .color(egui::Color32::from_rgb(150, 150, 150))
+                    .width(0.8),
+            );
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&disp_avg))
+                    .name(tr_args("corr-avg-dispersion", &[("days", window.to_string())]))
+                    .color(egui::Color32::from_rgb(220, 150, 50))
+                    .width(1.5),
+            );
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&corr_points))
+                    .name(tr("corr-avg-right-axis"))
+                    .color(egui::Color32::from_rgb(100, 150, 255))
+                    .width(1.5),
+            );
+        },
+    );
+    ui.small(tr("corr-dispersion-note"));
+}
+
+/// Regime label and color for an average cross-sector correlation level
+fn correlation_regime(avg_corr: f64) -> (String, egui::Color32) {
+    let regime = CorrelationRegime::classify(avg_corr);
+    (chart_utils::correlation_regime_label(regime), chart_utils::correlation_regime_color(regime))
 }
 
 fn color_swatch(ui: &mut egui::Ui, color: egui::Color32, label: &str) {
@@ -1,54 +1,66 @@
 use eframe::egui;
 use egui_plot::{Line, Plot, PlotPoints};
 
-use crate::app::AppState;
-use crate::ui::chart_utils::{self, height_control};
+use crate::app::{AppState, HeatmapColumn, HeatmapSort, Tab};
 use crate::config;
-
-fn fmt_usd(value: f64) -> String {
-    if value < 0.0 {
-        format!("(${:.2})", value.abs())
-    } else {
-        format!("${:.2}", value)
-    }
-}
+use crate::data::export::CsvTable;
+use crate::ui::chart_utils::{self, height_control};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::metric_info::{self, Metric};
+use crate::ui::{palette, search, table_nav};
+
+/// Heatmap table columns and the catalog ids of their header labels
+const HEATMAP_COLUMNS: [(HeatmapColumn, &str); 8] = [
+    (HeatmapColumn::Sector, "col-sector"),
+    (HeatmapColumn::Symbol, "col-symbol"),
+    (HeatmapColumn::LastClose, "col-last-close"),
+    (HeatmapColumn::ShortVol, "col-short-vol"),
+    (HeatmapColumn::LongVol, "col-long-vol"),
+    (HeatmapColumn::VolRatio, "col-vol-ratio"),
+    (HeatmapColumn::Beta, "col-beta"),
+    (HeatmapColumn::Bars, "col-bars"),
+];
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Market Structure Dashboard");
+    ui.heading(tr("dashboard-heading"));
     ui.add_space(8.0);
 
     if state.market_data.sectors.is_empty() {
-        ui.label("No data loaded. Click 'Refresh Data' to fetch market data.");
+        ui.label(tr("dashboard-no-data"));
         return;
     }
 
     // Key metrics row
     ui.horizontal(|ui| {
         let n_sectors = state.market_data.sectors.len();
-        metric_card(ui, "Sectors Loaded", &format!("{}", n_sectors));
+        let explain = state.explain_metrics;
+        metric_card(ui, &tr("card-sectors-loaded"), &i18n::integer(n_sectors));
 
         if let Some(ref bench) = state.market_data.benchmark {
             if let Some(last) = bench.bars.last() {
-                metric_card(ui, "SPY Last Close", &fmt_usd(last.close));
+                let label = tr_args("card-benchmark-last-close", &[("symbol", config::benchmark_symbol().to_string())]);
+                metric_card(ui, &label, &i18n::usd(last.close));
             }
         }
 
-        metric_card(
+        let card = metric_card(
             ui,
-            "Avg Cross-Correlation",
-            &format!("{:.3}", state.analysis.avg_cross_correlation),
+            &tr("card-avg-correlation"),
+            &i18n::number(state.analysis.avg_cross_correlation, 3),
         );
+        metric_info::explain(card, explain, Metric::AvgCorrelation, &state.analysis_windows);
 
         if let Some(spread) = state.analysis.bond_spreads.first() {
-            metric_card(
+            let card = metric_card(
                 ui,
-                "10Y-2Y Spread",
-                &format!("{:.2} bps", spread.spread_10y_2y * 100.0),
+                &tr("card-term-spread"),
+                &tr_args("unit-bps", &[("value", i18n::number(spread.spread_10y_2y * 100.0, 2))]),
             );
+            metric_info::explain(card, explain, Metric::TermSpread, &state.analysis_windows);
         }
 
         let n_rates = state.market_data.treasury_rates.len();
-        metric_card(ui, "Treasury Data Points", &format!("{}", n_rates));
+        metric_card(ui, &tr("card-treasury-points"), &i18n::integer(n_rates));
     });
 
     ui.add_space(16.0);
@@ -65,74 +77,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     }
 
     // Sector heatmap
-    ui.heading("Sector Volatility Heatmap");
+    ui.heading(tr("dashboard-heatmap-heading"));
     ui.add_space(8.0);
 
-    egui::Grid::new("sector_heatmap")
-        .striped(true)
-        .min_col_width(100.0)
-        .show(ui, |ui| {
-            ui.strong("Sector");
-            ui.strong("Symbol");
-            ui.strong("Last Close");
-            ui.strong("21D Vol");
-            ui.strong("63D Vol");
-            ui.strong("Vol Ratio");
-            ui.strong("Bars");
-            ui.end_row();
-
-            for (i, sector) in state.market_data.sectors.iter().enumerate() {
-                let name = config::SECTOR_ETFS
-                    .iter()
-                    .find(|(s, _)| *s == sector.symbol)
-                    .map(|(_, n)| *n)
-                    .unwrap_or("Unknown");
-
-                ui.label(name);
-                ui.label(&sector.symbol);
-
-                if let Some(last) = sector.bars.last() {
-                    ui.label(fmt_usd(last.close));
-                } else {
-                    ui.label("-");
-                }
-
-                if let Some(vm) = state
-                    .analysis
-                    .volatility
-                    .iter()
-                    .find(|v| v.symbol == sector.symbol)
-                {
-                    let sv = vm.short_window_vol.last().copied().unwrap_or(0.0);
-                    let lv = vm.long_window_vol.last().copied().unwrap_or(0.0);
-                    let vr = vm.vol_ratio.last().copied().unwrap_or(0.0);
-
-                    let vol_color = vol_to_color(sv);
-                    ui.colored_label(vol_color, format!("{:.1}%", sv * 100.0));
-                    ui.colored_label(vol_to_color(lv), format!("{:.1}%", lv * 100.0));
-
-                    let ratio_color = if vr > 1.2 {
-                        egui::Color32::from_rgb(220, 50, 50)
-                    } else if vr < 0.8 {
-                        egui::Color32::from_rgb(50, 180, 50)
-                    } else {
-                        ui.visuals().text_color()
-                    };
-                    ui.colored_label(ratio_color, format!("{:.2}", vr));
-                } else {
-                    ui.label("-");
-                    ui.label("-");
-                    ui.label("-");
-                }
-
-                ui.label(format!("{}", sector.bars.len()));
-                ui.end_row();
-
-                if i == state.selected_sector_idx {
-                    // selection indicator is handled by grid striping
-                }
-            }
-        });
+    render_heatmap_table(ui, state);
 
     // Put/Call Ratio & SKEW
     render_put_call_skew_section(ui, state);
@@ -142,25 +90,26 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         ui.add_space(16.0);
         ui.separator();
         ui.add_space(8.0);
-        ui.heading("FMP Sector Performance (Real-Time)");
+        ui.heading(tr("dashboard-sector-performance-heading"));
         ui.add_space(8.0);
 
         egui::Grid::new("fmp_sector_perf")
             .striped(true)
             .min_col_width(120.0)
             .show(ui, |ui| {
-                ui.strong("Sector");
-                ui.strong("Change %");
+                ui.strong(tr("col-sector"));
+                ui.strong(tr("col-change-pct"));
                 ui.end_row();
 
                 for sp in &state.market_data.sector_performance {
                     ui.label(&sp.sector);
                     let color = if sp.changes_percentage >= 0.0 {
-                        egui::Color32::from_rgb(50, 180, 50)
+                        palette::positive()
                     } else {
-                        egui::Color32::from_rgb(220, 50, 50)
+                        palette::negative()
                     };
-                    ui.colored_label(color, format!("{:+.2}%", sp.changes_percentage));
+                    let sign = if sp.changes_percentage > 0.0 { "+" } else { "" };
+                    ui.colored_label(color, format!("{}{}", sign, i18n::percent(sp.changes_percentage / 100.0, 2)));
                     ui.end_row();
                 }
             });
@@ -171,6 +120,215 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 // Put/Call Ratio & SKEW section
 // ---------------------------------------------------------------------------
 
+/// One row of the sector heatmap table, gathered up front so it can be sorted
+struct HeatmapRow<'a> {
+    sector_idx: usize,
+    name: &'static str,
+    symbol: &'a str,
+    last_close: Option<f64>,
+    /// Latest 21D vol, 63D vol and short/long ratio
+    vols: Option<(f64, f64, f64)>,
+    /// Latest rolling beta vs the benchmark
+    beta: Option<f64>,
+    bars: usize,
+}
+
+/// Sector heatmap table. Clicking a header sorts by that column (again to reverse,
+/// a third time to restore sector order); clicking a row opens the sector in Sector Vol.
+/// Cells are keyboard-navigable with the arrow keys; Enter on a cell opens its sector.
+fn render_heatmap_table(ui: &mut egui::Ui, state: &mut AppState) {
+    let mut rows: Vec<HeatmapRow> = state
+        .market_data
+        .sectors
+        .iter()
+        .enumerate()
+        .map(|(i, sector)| HeatmapRow {
+            sector_idx: i,
+            name: search::sector_name(&sector.symbol),
+            symbol: &sector.symbol,
+            last_close: sector.bars.last().map(|b| b.close),
+            vols: state.analysis.volatility.iter().find(|v| v.symbol == sector.symbol).map(|vm| {
+                (
+                    vm.short_window_vol.last().copied().unwrap_or(0.0),
+                    vm.long_window_vol.last().copied().unwrap_or(0.0),
+                    vm.vol_ratio.last().copied().unwrap_or(0.0),
+                )
+            }),
+            beta: state
+                .analysis
+                .rolling_beta
+                .iter()
+                .find(|(s, _)| *s == sector.symbol)
+                .and_then(|(_, b)| b.last().copied())
+                .filter(|b| b.is_finite()),
+            bars: sector.bars.len(),
+        })
+        .collect();
+
+    let query = state.search_query.trim();
+    if !query.is_empty() {
+        rows.retain(|r| search::matches(query, r.symbol, r.name));
+        ui.label(tr_args("dashboard-search-matches", &[("count", i18n::integer(rows.len())), ("query", query.to_string())]));
+    }
+
+    let sort = state.heatmap_sort;
+    if let Some(column) = sort.column {
+        // Rows missing the value sort last in either direction
+        let key = |r: &HeatmapRow| -> Option<f64> {
+            match column {
+                HeatmapColumn::LastClose => r.last_close,
+                HeatmapColumn::ShortVol => r.vols.map(|v| v.0),
+                HeatmapColumn::LongVol => r.vols.map(|v| v.1),
+                HeatmapColumn::VolRatio => r.vols.map(|v| v.2),
+                HeatmapColumn::Beta => r.beta,
+                HeatmapColumn::Bars => Some(r.bars as f64),
+                HeatmapColumn::Sector | HeatmapColumn::Symbol => None,
+            }
+        };
+        rows.sort_by(|a, b| {
+            let ord = match column {
+                HeatmapColumn::Sector => a.name.cmp(b.name),
+                HeatmapColumn::Symbol => a.symbol.cmp(b.symbol),
+                _ => match (key(a), key(b)) {
+                    (Some(x), Some(y)) => x.total_cmp(&y),
+                    (a, b) => return b.is_some().cmp(&a.is_some()),
+                },
+            };
+            if sort.descending { ord.reverse() } else { ord }
+        });
+    }
+
+    let mut clicked_header = None;
+    let mut clicked_row = None;
+    let table_ui = egui::UiBuilder::new().sense(egui::Sense::click());
+    let table_response = ui
+        .scope_builder(table_ui, |ui| {
+            egui::Grid::new("sector_heatmap")
+                .striped(true)
+                .min_col_width(100.0)
+                .show(ui, |ui| {
+                    for (column, id) in HEATMAP_COLUMNS {
+                        let active = sort.column == Some(column);
+                        let arrow = match (active, sort.descending) {
+                            (false, _) => "",
+                            (true, false) => " ▲",
+                            (true, true) => " ▼",
+                        };
+                        let text = egui::RichText::new(format!("{}{}", tr(id), arrow)).strong();
+                        let header = ui.selectable_label(active, text).on_hover_text(tr("sort-by-column"));
+                        let header = match column {
+                            HeatmapColumn::VolRatio => metric_info::explain(header, state.explain_metrics, Metric::VolRatio, &state.analysis_windows),
+                            _ => header,
+                        };
+                        if header.clicked() {
+                            clicked_header = Some(column);
+                        }
+                    }
+                    ui.end_row();
+
+                    for row in &rows {
+                        let selected = row.sector_idx == state.selected_sector_idx;
+                        let mut texts = vec![
+                            if selected { egui::RichText::new(row.name).strong() } else { egui::RichText::new(row.name) },
+                            egui::RichText::new(row.symbol),
+                            egui::RichText::new(row.last_close.map_or("-".to_string(), i18n::usd)),
+                        ];
+
+                        if let Some((sv, lv, vr)) = row.vols {
+                            texts.push(egui::RichText::new(i18n::percent(sv, 1)).color(palette::vol_color(sv)));
+                            texts.push(egui::RichText::new(i18n::percent(lv, 1)).color(palette::vol_color(lv)));
+
+                            let ratio_color = if vr > 1.2 {
+                                palette::negative()
+                            } else if vr < 0.8 {
+                                palette::positive()
+                            } else {
+                                ui.visuals().text_color()
+                            };
+                            texts.push(egui::RichText::new(i18n::number(vr, 2)).color(ratio_color));
+                        } else {
+                            texts.extend(std::iter::repeat_n(egui::RichText::new("-"), 3));
+                        }
+                        texts.push(egui::RichText::new(row.beta.map_or("-".to_string(), |b| i18n::number(b, 2))));
+
+                        texts.push(egui::RichText::new(i18n::integer(row.bars)));
+
+                        let mut cells = Vec::with_capacity(texts.len());
+                        let mut focus = Vec::with_capacity(texts.len());
+                        for (text, (_, column)) in texts.into_iter().zip(HEATMAP_COLUMNS) {
+                            let value = text.text().to_string();
+                            let cell = ui.label(text);
+                            focus.push(table_nav::cell(ui, &cell, row.symbol, column, &value));
+                            cells.push(cell);
+                        }
+
+                        // The whole row is one click target on top of its (non-interactive) labels
+                        let row_rect = cells.iter().fold(egui::Rect::NOTHING, |r, c| r.union(c.rect));
+                        let response = ui
+                            .interact(row_rect, ui.id().with(("heatmap_row", row.sector_idx)), egui::Sense::click())
+                            .on_hover_text(tr_args("dashboard-open-sector", &[("symbol", row.symbol.to_string())]));
+                        if selected || response.hovered() {
+                            let stroke = if selected {
+                                ui.visuals().selection.stroke
+                            } else {
+                                ui.visuals().widgets.hovered.bg_stroke
+                            };
+                            ui.painter().rect_stroke(row_rect.expand(2.0), 2.0, stroke);
+                        }
+                        if response.clicked() || table_nav::activated(ui, &focus) {
+                            clicked_row = Some(row.sector_idx);
+                        }
+                        chart_utils::copy_table_menu(&response, &mut state.notices, || heatmap_table(&rows));
+                        ui.end_row();
+                    }
+                });
+        })
+        .response;
+    chart_utils::copy_table_menu(&table_response, &mut state.notices, || heatmap_table(&rows));
+
+    if let Some(column) = clicked_header {
+        let sort = &mut state.heatmap_sort;
+        *sort = match (sort.column == Some(column), sort.descending) {
+            (false, _) => HeatmapSort { column: Some(column), descending: false },
+            (true, false) => HeatmapSort { column: Some(column), descending: true },
+            (true, true) => HeatmapSort::default(),
+        };
+    }
+    if let Some(idx) = clicked_row {
+        state.selected_sector_idx = idx;
+        state.active_tab = Tab::SectorVol;
+    }
+}
+
+/// The heatmap rows in their shown order, with raw numbers for pasting into a
+/// spreadsheet
+fn heatmap_table(rows: &[HeatmapRow]) -> CsvTable {
+    let headers: Vec<String> = HEATMAP_COLUMNS
+        .iter()
+        .map(|(column, id)| match column {
+            HeatmapColumn::ShortVol | HeatmapColumn::LongVol => format!("{} (%)", tr(id)),
+            _ => tr(id),
+        })
+        .collect();
+    let number = |v: Option<f64>| v.map_or(String::new(), |v| v.to_string());
+    let rows = rows
+        .iter()
+        .map(|r| {
+            vec![
+                r.name.to_string(),
+                r.symbol.to_string(),
+                number(r.last_close),
+                number(r.vols.map(|v| v.0 * 100.0)),
+                number(r.vols.map(|v| v.1 * 100.0)),
+                number(r.vols.map(|v| v.2)),
+                number(r.beta),
+                r.bars.to_string(),
+            ]
+        })
+        .collect();
+    CsvTable { headers, rows }
+}
+
 fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
     let has_pc = !state.market_data.put_call_ratio.is_empty();
     let has_skew = !state.market_data.skew_history.is_empty();
@@ -178,11 +336,11 @@ fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
     ui.add_space(16.0);
     ui.separator();
     ui.add_space(8.0);
-    ui.heading("Put/Call Ratio & SKEW");
+    ui.heading(tr("dashboard-put-call-heading"));
     ui.add_space(4.0);
 
     if !has_pc && !has_skew {
-        ui.label("No Put/Call or SKEW data. Data is fetched with Refresh Data.");
+        ui.label(tr("dashboard-put-call-empty"));
         return;
     }
 
@@ -196,7 +354,7 @@ fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
             .map(|(i, r)| [i as f64, r.pc_ratio])
             .collect();
 
-        height_control(ui, &mut state.chart_heights.put_call_skew, "P/C Ratio & SKEW Chart Height");
+        height_control(ui, &mut state.chart_heights.put_call_skew, &tr("dashboard-put-call-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "put_call_ratio_plot",
@@ -204,13 +362,13 @@ fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("put_call_ratio_plot")
                     .height(state.chart_heights.put_call_skew),
             )
-                .x_axis_label("Trading Day (recent -> past)")
-                .y_axis_label("P/C Ratio")
+                .x_axis_label(tr("axis-trading-day"))
+                .y_axis_label(tr("axis-pc-ratio"))
                 .legend(egui_plot::Legend::default()),
             |plot_ui| {
                 plot_ui.line(
                     Line::new(pc_points)
-                        .name("Total P/C Ratio")
+                        .name(tr("series-total-pc-ratio"))
                         .color(egui::Color32::from_rgb(255, 150, 50)),
                 );
             },
@@ -236,7 +394,7 @@ fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("skew_plot")
                     .height(state.chart_heights.put_call_skew),
             )
-                .x_axis_label("Trading Day (recent -> past)")
+                .x_axis_label(tr("axis-trading-day"))
                 .y_axis_label("SKEW")
                 .legend(egui_plot::Legend::default()),
             |plot_ui| {
@@ -255,7 +413,7 @@ fn render_put_call_skew_section(ui: &mut egui::Ui, state: &mut AppState) {
 // ---------------------------------------------------------------------------
 
 fn render_3d_section(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Market Randomness - 3D Joint Return Distribution");
+    ui.heading(tr("dashboard-randomness-heading"));
     ui.add_space(4.0);
 
     let n_sectors = state.market_data.sectors.len();
@@ -268,7 +426,7 @@ fn render_3d_section(ui: &mut egui::Ui, state: &mut AppState) {
     // Sector pair selector + rotation controls
     let mut redraw = false;
     ui.horizontal(|ui| {
-        ui.label("Sector X:");
+        ui.label(tr("randomness-sector-x"));
         let prev_x = state.plot_3d.sector_x_idx;
         egui::ComboBox::from_id_salt("sector_x_combo")
             .selected_text(&symbols[state.plot_3d.sector_x_idx.min(n_sectors - 1)])
@@ -281,7 +439,7 @@ fn render_3d_section(ui: &mut egui::Ui, state: &mut AppState) {
             redraw = true;
         }
 
-        ui.label("Sector Y:");
+        ui.label(tr("randomness-sector-y"));
         let prev_y = state.plot_3d.sector_y_idx;
         egui::ComboBox::from_id_salt("sector_y_combo")
             .selected_text(&symbols[state.plot_3d.sector_y_idx.min(n_sectors - 1)])
@@ -296,14 +454,14 @@ fn render_3d_section(ui: &mut egui::Ui, state: &mut AppState) {
     });
 
     ui.horizontal(|ui| {
-        ui.label("Pitch:");
+        ui.label(tr("randomness-pitch"));
         let prev_pitch = state.plot_3d.pitch;
         ui.add(egui::Slider::new(&mut state.plot_3d.pitch, 0.05..=1.2).step_by(0.01));
         if (state.plot_3d.pitch - prev_pitch).abs() > 0.001 {
             redraw = true;
         }
 
-        ui.label("Yaw:");
+        ui.label(tr("randomness-yaw"));
         let prev_yaw = state.plot_3d.yaw;
         ui.add(egui::Slider::new(&mut state.plot_3d.yaw, -1.5..=1.5).step_by(0.01));
         if (state.plot_3d.yaw - prev_yaw).abs() > 0.001 {
@@ -352,64 +510,64 @@ fn render_3d_section(ui: &mut egui::Ui, state: &mut AppState) {
         let size = egui::vec2(640.0, 480.0);
         ui.image(egui::load::SizedTexture::new(texture.id(), size));
     } else {
-        ui.label("Rendering 3D distribution...");
+        ui.label(tr("randomness-rendering"));
     }
 
     ui.add_space(8.0);
 
     // Randomness metrics table
     if !state.analysis.randomness.is_empty() {
-        ui.heading("Sector Randomness Metrics");
+        ui.heading(tr("randomness-metrics-heading"));
         ui.add_space(4.0);
 
         egui::Grid::new("randomness_table")
             .striped(true)
             .min_col_width(90.0)
             .show(ui, |ui| {
-                ui.strong("Sector");
-                ui.strong("Entropy");
-                ui.strong("Hurst Exp.");
+                ui.strong(tr("col-sector"));
+                ui.strong(tr("col-entropy"));
+                ui.strong(tr("col-hurst"));
                 ui.strong("AC(1)");
                 ui.strong("AC(5)");
-                ui.strong("Behavior");
+                ui.strong(tr("col-behavior"));
                 ui.end_row();
 
                 for rm in &state.analysis.randomness {
                     ui.label(&rm.symbol);
 
-                    ui.label(format!("{:.3}", rm.entropy));
+                    ui.label(i18n::number(rm.entropy, 3));
 
                     let hurst_color = if (rm.hurst_exponent - 0.5).abs() < 0.05 {
-                        egui::Color32::from_rgb(50, 180, 50)
+                        palette::positive()
                     } else if rm.hurst_exponent > 0.5 {
                         egui::Color32::from_rgb(80, 160, 255)
                     } else {
                         egui::Color32::from_rgb(220, 150, 50)
                     };
-                    ui.colored_label(hurst_color, format!("{:.3}", rm.hurst_exponent));
+                    ui.colored_label(hurst_color, i18n::number(rm.hurst_exponent, 3));
 
                     let ac1_color = if rm.autocorrelation_lag1.abs() > 0.1 {
-                        egui::Color32::from_rgb(220, 50, 50)
+                        palette::negative()
                     } else {
                         egui::Color32::from_rgb(150, 150, 150)
                     };
-                    ui.colored_label(ac1_color, format!("{:.4}", rm.autocorrelation_lag1));
-                    ui.colored_label(ac1_color, format!("{:.4}", rm.autocorrelation_lag5));
+                    ui.colored_label(ac1_color, i18n::number(rm.autocorrelation_lag1, 4));
+                    ui.colored_label(ac1_color, i18n::number(rm.autocorrelation_lag5, 4));
 
                     let behavior = if (rm.hurst_exponent - 0.5).abs() < 0.05 {
-                        "Random Walk"
+                        "behavior-random-walk"
                     } else if rm.hurst_exponent > 0.5 {
-                        "Trending"
+                        "behavior-trending"
                     } else {
-                        "Mean-Reverting"
+                        "behavior-mean-reverting"
                     };
-                    ui.label(behavior);
+                    ui.label(tr(behavior));
                     ui.end_row();
                 }
             });
 
         ui.add_space(4.0);
-        ui.small("Entropy: higher = more random. Hurst: 0.5 = random walk, >0.5 = trending, <0.5 = mean-reverting. AC = autocorrelation (near 0 = random).");
+        ui.small(tr("randomness-legend"));
     }
 }
 
@@ -468,7 +626,7 @@ fn render_3d_surface(
             BitMapBackend::with_buffer(&mut rgb_buf, (width, height)).into_drawing_area();
         root.fill(&RGBColor(24, 24, 32)).ok()?;
 
-        let caption = format!("Joint Distribution: {} vs {}", label_x, label_y);
+        let caption = tr_args("randomness-caption", &[("x", label_x.to_string()), ("y", label_y.to_string())]);
 
         let mut chart = ChartBuilder::on(&root)
             .caption(&caption, ("sans-serif", 18).into_font().color(&WHITE))
@@ -582,7 +740,7 @@ fn heat_color(t: f64) -> plotters::style::RGBColor {
     )
 }
 
-fn metric_card(ui: &mut egui::Ui, label: &str, value: &str) {
+fn metric_card(ui: &mut egui::Ui, label: &str, value: &str) -> egui::Response {
     egui::Frame::group(ui.style())
         .inner_margin(egui::Margin::same(8.0))
         .show(ui, |ui| {
@@ -590,18 +748,6 @@ fn metric_card(ui: &mut egui::Ui, label: &str, value: &str) {
                 ui.small(label);
                 ui.strong(value);
             });
-        });
-}
-
-fn vol_to_color(vol: f64) -> egui::Color32 {
-    let pct = vol * 100.0;
-    if pct > 30.0 {
-        egui::Color32::from_rgb(220, 50, 50)
-    } else if pct > 20.0 {
-        egui::Color32::from_rgb(220, 150, 50)
-    } else if pct > 10.0 {
-        egui::Color32::from_rgb(200, 200, 50)
-    } else {
-        egui::Color32::from_rgb(50, 180, 50)
-    }
+        })
+        .response
 }
@@ -0,0 +1,242 @@
+use eframe::egui;
+
+use crate::app::{AppState, DataManagerState};
+use crate::config;
+use crate::data::cache;
+use crate::data::health::{DataSource, FetchOutcome};
+use crate::data::refresh::RefreshItem;
+use crate::data::models::SectorTimeSeries;
+use crate::data::yahoo;
+use crate::notices::NoticeSource;
+use crate::tasks::{TaskHooks, TaskKind, TaskManager};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::{palette, status_bar};
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("tab-data"));
+    ui.small(tr("data-intro"));
+    ui.add_space(8.0);
+
+    let universe = config::universe();
+    let symbols: Vec<(&str, &str)> = universe
+        .sectors
+        .iter()
+        .chain(std::iter::once(&universe.benchmark))
+        .map(|s| (s.symbol.as_str(), s.name.as_str()))
+        .collect();
+
+    let mut toggled = None;
+    egui::Grid::new("data_manager_grid").num_columns(9).striped(true).spacing([16.0, 4.0]).show(ui, |ui| {
+        for id in ["col-symbol", "data-col-name", "col-bars", "data-col-from", "data-col-to", "data-col-source", "data-col-cache-age", "data-col-include"] {
+            ui.strong(tr(id));
+        }
+        ui.label("");
+        ui.end_row();
+
+        for (symbol, name) in symbols {
+            let series = find_series(state, symbol);
+            let excluded = state.data_manager.excluded.iter().any(|s| s == symbol);
+
+            ui.label(symbol);
+            ui.label(name);
+            match series {
+                Some(s) => {
+                    ui.label(i18n::integer(s.bars.len()));
+                    ui.label(s.bars.first().map_or("-".to_string(), |b| i18n::date(b.date)));
+                    ui.label(s.bars.last().map_or("-".to_string(), |b| i18n::date(b.date)));
+                }
+                None => {
+                    ui.weak(tr("data-not-loaded"));
+                    ui.label("-");
+                    ui.label("-");
+                }
+            }
+            render_source(ui, state.market_data.refresh_report.series(symbol));
+            match cache::modified_time(&yahoo::cache_filename(symbol)) {
+                Some(t) => ui.label(status_bar::format_age(chrono::Local::now() - t)),
+                None => ui.weak(tr("data-no-cache")),
+            };
+
+            if symbol == config::benchmark_symbol() {
+                ui.add_enabled(false, egui::Checkbox::new(&mut true, ""))
+                    .on_disabled_hover_text(tr("data-benchmark-always"));
+            } else {
+                let mut included = !excluded;
+                if ui.checkbox(&mut included, "").changed() {
+                    toggled = Some((symbol.to_string(), !included));
+                }
+            }
+
+            ui.horizontal(|ui| {
+                let pending = state.data_manager.pending.iter().any(|s| s == symbol);
+                if pending {
+                    ui.spinner();
+                } else if ui
+                    .add_enabled(!state.is_loading, egui::Button::new("⟳").small())
+                    .on_hover_text(tr("data-refetch-hover"))
+                    .clicked()
+                {
+                    start_refetch(&mut state.tasks, &mut state.data_manager, symbol, name, state.analysis_windows.lookback_days);
+                }
+                if ui.small_button("🗑").on_hover_text(tr("data-purge-hover")).clicked() {
+                    let result = cache::remove(&yahoo::cache_filename(symbol))
+                        .map(|()| tr_args("data-purged", &[("symbol", symbol.to_string())]))
+                        .map_err(|e| tr_args("data-purge-failed", &[("symbol", symbol.to_string()), ("error", e.to_string())]));
+                    state.notices.report(NoticeSource::Data, result);
+                }
+            });
+            ui.end_row();
+        }
+    });
+
+    if let Some((symbol, exclude)) = toggled {
+        let excluded = &mut state.data_manager.excluded;
+        excluded.retain(|s| *s != symbol);
+        if exclude {
+            excluded.push(symbol);
+        }
+        if let Err(e) = state.save_config() {
+            state.notices.error(NoticeSource::Settings, tr_args("data-save-excluded-failed", &[("error", e)]));
+        }
+        if state.apply_exclusions() {
+            state.recompute_analysis();
+        }
+    }
+
+    render_refresh_report(ui, state);
+}
+
+/// Display name for a sector or benchmark symbol (the symbol itself if unknown)
+pub fn series_name(symbol: &str) -> &str {
+    let universe = config::universe();
+    universe
+        .sectors
+        .iter()
+        .chain(std::iter::once(&universe.benchmark))
+        .find(|s| s.symbol == symbol)
+        .map_or(symbol, |s| s.name.as_str())
+}
+
+/// A loaded series by symbol, whether in use, set aside, or the benchmark
+fn find_series<'a>(state: &'a AppState, symbol: &str) -> Option<&'a SectorTimeSeries> {
+    state
+        .market_data
+        .sectors
+        .iter()
+        .chain(&state.data_manager.set_aside)
+        .chain(&state.market_data.benchmark)
+        .find(|s| s.symbol == symbol)
+}
+
+fn render_source(ui: &mut egui::Ui, item: Option<&RefreshItem>) {
+    let Some(item) = item else {
+        ui.weak("-");
+        return;
+    };
+    let response = match &item.outcome {
+        FetchOutcome::Fetched { from_cache: true, .. } => ui.label(tr("data-source-cache")),
+        FetchOutcome::Fetched { from_cache: false, .. } => ui.label(tr("data-source-network")),
+        FetchOutcome::Failed(e) if item.kept_previous => ui
+            .colored_label(palette::neutral(), tr("data-failed-kept"))
+            .on_hover_text(e),
+        FetchOutcome::Failed(e) => ui.colored_label(palette::negative(), tr("task-failed")).on_hover_text(e),
+    };
+    if item.attempts > 1 {
+        response.on_hover_text(tr_args("data-attempts", &[("count", i18n::integer(item.attempts as usize))]));
+    }
+}
+
+/// Every item of the last refresh with its outcome and attempts, and a retry for failed series
+fn render_refresh_report(ui: &mut egui::Ui, state: &mut AppState) {
+    let report = &state.market_data.refresh_report;
+    if report.items.is_empty() {
+        return;
+    }
+    ui.add_space(12.0);
+    ui.heading(tr("data-last-refresh"));
+    if let Some(at) = report.finished_at {
+        ui.label(tr_args("data-finished", &[("time", i18n::date_time(at)), ("summary", report.summary())]));
+    }
+
+    egui::Grid::new("refresh_report_grid").num_columns(5).striped(true).spacing([16.0, 4.0]).show(ui, |ui| {
+        for id in ["data-col-source", "data-col-item", "data-col-status", "data-col-attempts", "data-col-detail"] {
+            ui.strong(tr(id));
+        }
+        ui.end_row();
+        for item in &report.items {
+            ui.label(item.source.label());
+            ui.label(&item.name);
+            match &item.outcome {
+                FetchOutcome::Fetched { from_cache: true, .. } => ui.label(tr("data-cached")),
+                FetchOutcome::Fetched { from_cache: false, .. } => ui.label(tr("data-downloaded")),
+                FetchOutcome::Failed(_) if item.kept_previous => ui.colored_label(palette::neutral(), tr("data-failed-kept")),
+                FetchOutcome::Failed(_) => ui.colored_label(palette::negative(), tr("task-failed")),
+            };
+            ui.label(i18n::integer(item.attempts as usize));
+            match &item.outcome {
+                FetchOutcome::Failed(e) => ui.label(e),
+                FetchOutcome::Fetched { fetched_at, .. } => ui.weak(tr_args("source-data-as-of", &[("time", i18n::date_time(*fetched_at))])),
+            };
+            ui.end_row();
+        }
+    });
+
+    let failed_symbols: Vec<String> = report
+        .failures()
+        .filter(|i| i.source == DataSource::Yahoo)
+        .map(|i| i.name.clone())
+        .collect();
+    let other_failures = report.failures().any(|i| i.source != DataSource::Yahoo);
+    ui.horizontal(|ui| {
+        if !failed_symbols.is_empty()
+            && ui
+                .add_enabled(!state.is_loading, egui::Button::new(tr_args("data-retry-failed", &[("count", i18n::integer(failed_symbols.len()))])))
+                .on_hover_text(tr("data-retry-failed-hover"))
+                .clicked()
+        {
+            for symbol in &failed_symbols {
+                start_refetch(&mut state.tasks, &mut state.data_manager, symbol, series_name(symbol), state.analysis_windows.lookback_days);
+            }
+        }
+        if other_failures {
+            ui.weak(tr("data-other-failures"));
+        }
+    });
+}
+
+/// Download `symbol` as a background task, bypassing the cache; the result lands in
+/// `data_manager.results` and is swapped in by `AppState::collect_refetches`.
+pub fn start_refetch(
+    tasks: &mut TaskManager,
+    data_manager: &mut DataManagerState,
+    symbol: &str,
+    name: &str,
+    lookback_days: u32,
+) {
+    if data_manager.pending.iter().any(|s| s == symbol) {
+        return;
+    }
+    data_manager.pending.push(symbol.to_string());
+    let slot = data_manager.results.clone();
+    let (symbol, name) = (symbol.to_string(), name.to_string());
+
+    // A cancelled refetch reports an error so the symbol stops showing as pending
+    let cancel_slot = slot.clone();
+    let cancel_symbol = symbol.clone();
+    let hooks = TaskHooks {
+        cancel: Some(Box::new(move || {
+            if let Ok(mut results) = cancel_slot.lock() {
+                results.push((cancel_symbol.clone(), Err(anyhow::anyhow!("Cancelled"))));
+            }
+        })),
+        progress: None,
+    };
+    tasks.spawn(TaskKind::Refetch, tr_args("retry-refetch", &[("symbol", symbol.clone())]), hooks, async move {
+        let result = yahoo::download_symbol_history(&symbol, &name, lookback_days).await;
+        let error = result.as_ref().err().map(|e| format!("{:#}", e));
+        if let Ok(mut results) = slot.lock() {
+            results.push((symbol, result));
+        }
+        error.map_or(Ok(()), Err)
+    });
+}
@@ -0,0 +1,151 @@
+use std::time::Duration;
+
+use eframe::egui;
+use egui_plot::{Line, Plot, PlotPoints};
+
+use crate::app::AppState;
+use crate::diagnostics::{self, format_bytes};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
+
+/// Frame time above which the status-bar reading is highlighted (under 30 fps)
+const SLOW_FRAME: Duration = Duration::from_millis(33);
+
+/// Status-bar reading of the last frame's CPU time. Click to open the Diagnostics window.
+pub fn render_indicator(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some(frame) = state.diagnostics.frames.last() else {
+        return;
+    };
+    let text = millis(frame, 1);
+    let text = if frame > SLOW_FRAME {
+        egui::RichText::new(text).color(palette::negative())
+    } else {
+        egui::RichText::new(text)
+    };
+    let response = ui.selectable_label(state.diagnostics.open, text);
+    if response.on_hover_text(tr("diag-indicator-hover")).clicked() {
+        state.diagnostics.open = !state.diagnostics.open;
+    }
+}
+
+/// Window with heap and per-subsystem memory, frame times and the last refresh's timings
+pub fn render_window(ctx: &egui::Context, state: &mut AppState) {
+    if !state.diagnostics.open {
+        return;
+    }
+    state.diagnostics.sample();
+    // Keep the heap and frame readings live while nothing else repaints
+    ctx.request_repaint_after(Duration::from_secs(1));
+
+    let mut open = true;
+    egui::Window::new(tr("diag-title"))
+        .id(egui::Id::new("diagnostics_window"))
+        .open(&mut open)
+        .resizable(false)
+        .default_width(360.0)
+        .show(ctx, |ui| {
+            render_memory(ui, state);
+            ui.separator();
+            render_frames(ui, state);
+            ui.separator();
+            render_timings(ui, state);
+        });
+    state.diagnostics.open = open;
+}
+
+fn render_memory(ui: &mut egui::Ui, state: &AppState) {
+    ui.strong(tr("diag-memory"));
+    let dataset_bytes = state
+        .training_progress
+        .as_ref()
+        .and_then(|p| p.compute_stats.lock().ok().map(|s| s.dataset_bytes))
+        .unwrap_or(state.compute_stats.dataset_bytes);
+    let bytes = |b: usize| format_bytes(b as u64);
+    egui::Grid::new("diagnostics_memory").num_columns(2).striped(true).show(ui, |ui| {
+        ui.label(tr("diag-heap"));
+        match diagnostics::heap_usage() {
+            Some((now, peak)) => ui.label(tr_args("diag-with-peak", &[("now", bytes(now)), ("peak", bytes(peak))])),
+            None => ui.weak(tr("diag-not-counted")).on_hover_text(tr("diag-not-counted-hover")),
+        };
+        ui.end_row();
+        ui.label(tr("diag-resident"));
+        let resident = &state.diagnostics;
+        ui.label(match (resident.resident_bytes, resident.peak_resident_bytes) {
+            (Some(now), Some(peak)) => {
+                tr_args("diag-with-peak", &[("now", format_bytes(now)), ("peak", format_bytes(peak))])
+            }
+            _ => "-".to_string(),
+        })
+        .on_hover_text(tr("diag-resident-hover"));
+        ui.end_row();
+        ui.label(tr("diag-price-data"));
+        let sectors = state.market_data.sectors.len() + state.data_manager.set_aside.len();
+        ui.label(tr_args(
+            "diag-series",
+            &[
+                ("size", bytes(diagnostics::market_data_bytes(&state.market_data, &state.data_manager.set_aside))),
+                ("count", i18n::integer(sectors)),
+            ],
+        ));
+        ui.end_row();
+        ui.label(tr("diag-analysis"));
+        ui.label(bytes(diagnostics::analysis_bytes(&state.analysis)));
+        ui.end_row();
+        ui.label(tr("diag-nn-dataset"));
+        if dataset_bytes > 0 {
+            ui.label(bytes(dataset_bytes));
+        } else {
+            ui.weak(tr("diag-not-built"));
+        }
+        ui.end_row();
+        ui.label(tr("diag-chart-cache"));
+        ui.label(tr_args("diag-charts", &[("count", i18n::integer(state.plot_cache.entry_count()))]));
+        ui.end_row();
+        ui.label(tr("diag-disk-cache"));
+        ui.label(state.diagnostics.disk_cache_bytes.map_or("-".to_string(), format_bytes));
+        ui.end_row();
+    });
+    ui.weak(tr("diag-estimates"));
+}
+
+fn render_frames(ui: &mut egui::Ui, state: &AppState) {
+    let frames = &state.diagnostics.frames;
+    let ms = |d: Option<Duration>| d.map_or("-".to_string(), |d| millis(d, 1));
+    ui.strong(tr("diag-frame-time"));
+    ui.label(tr_args(
+        "diag-frame-stats",
+        &[("last", ms(frames.last())), ("mean", ms(frames.mean())), ("max", ms(frames.max()))],
+    ));
+    let points: PlotPoints = frames.millis().enumerate().map(|(i, t)| [i as f64, t]).collect();
+    Plot::new("diagnostics_frame_times")
+        .height(80.0)
+        .include_y(0.0)
+        .show_axes([false, true])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .show(ui, |plot_ui| plot_ui.line(Line::new(points).name(tr("diag-cpu-ms"))));
+}
+
+fn render_timings(ui: &mut egui::Ui, state: &AppState) {
+    ui.strong(tr("diag-timings"));
+    let report = &state.market_data.refresh_report;
+    egui::Grid::new("diagnostics_timings").num_columns(2).striped(true).show(ui, |ui| {
+        ui.label(tr("diag-analysis"));
+        ui.label(state.diagnostics.last_analysis.map_or("-".to_string(), |d| millis(d, 0)));
+        ui.end_row();
+        for (stage, elapsed) in &report.timings {
+            ui.label(tr_args("diag-refresh-stage", &[("stage", stage.clone())]));
+            ui.label(tr_args("unit-seconds", &[("value", i18n::number(elapsed.as_secs_f64(), 2))]));
+            ui.end_row();
+        }
+    });
+    match report.finished_at {
+        Some(t) if !report.timings.is_empty() => ui.weak(tr_args("diag-last-refresh", &[("time", i18n::date_time(t))])),
+        _ => ui.weak(tr("diag-no-refresh")),
+    };
+}
+
+fn millis(d: Duration, decimals: usize) -> String {
+    tr_args("unit-ms", &[("value", i18n::number(d.as_secs_f64() * 1000.0, decimals))])
+}
@@ -0,0 +1,260 @@
+//! Localization. UI text is looked up by message id in a per-language catalog under
+//! `locales/`, written in Fluent syntax (`id = text`, with `{ $name }` placeables), and
+//! numbers, percentages, prices and dates go through the formatters here so decimal and
+//! grouping separators and date order follow the chosen language. A message missing
+//! from a catalog falls back to English, then to its id.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Self::English, Self::German];
+
+    /// Name of the language in itself, as listed in the language picker
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::German => "Deutsch",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Self::English => include_str!("../../locales/en.ftl"),
+            Self::German => include_str!("../../locales/de.ftl"),
+        }
+    }
+
+    fn catalog(&self) -> &'static HashMap<&'static str, String> {
+        static CATALOGS: [OnceLock<HashMap<&'static str, String>>; 2] = [OnceLock::new(), OnceLock::new()];
+        CATALOGS[*self as usize].get_or_init(|| parse(self.source()))
+    }
+
+    fn decimal_separator(&self) -> char {
+        match self {
+            Self::English => '.',
+            Self::German => ',',
+        }
+    }
+
+    fn group_separator(&self) -> char {
+        match self {
+            Self::English => ',',
+            Self::German => '.',
+        }
+    }
+}
+
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+/// Switch the language used by every helper below; called once per frame from the app state
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::German,
+        _ => Locale::English,
+    }
+}
+
+/// Messages of a catalog by id. Indented lines continue the message above on a new line.
+fn parse(source: &'static str) -> HashMap<&'static str, String> {
+    let mut messages = HashMap::new();
+    let mut current: Option<&'static str> = None;
+    for line in source.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some(text) = current.and_then(|id| messages.get_mut(id)) {
+                let text: &mut String = text;
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(line.trim());
+            }
+            continue;
+        }
+        match line.split_once('=') {
+            Some((id, text)) => {
+                let id = id.trim();
+                messages.insert(id, text.trim().to_string());
+                current = Some(id);
+            }
+            None => {
+                tracing::warn!("Ignoring malformed catalog line: {}", line);
+                current = None;
+            }
+        }
+    }
+    messages
+}
+
+fn lookup(locale: Locale, id: &str) -> String {
+    locale
+        .catalog()
+        .get(id)
+        .or_else(|| Locale::English.catalog().get(id))
+        .cloned()
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Text of message `id` in the current language
+pub fn tr(id: &str) -> String {
+    lookup(locale(), id)
+}
+
+/// Text of message `id` with each `{ $name }` placeable replaced by its value
+pub fn tr_args(id: &str, args: &[(&str, String)]) -> String {
+    fill(tr(id), args)
+}
+
+fn fill(mut text: String, args: &[(&str, String)]) -> String {
+    for (name, value) in args {
+        text = text.replace(&format!("{{ ${} }}", name), value).replace(&format!("{{${}}}", name), value);
+    }
+    text
+}
+
+/// `value` with `decimals` places and grouped thousands, e.g. "1,234.56" or "1.234,56"
+pub fn number(value: f64, decimals: usize) -> String {
+    format_number(locale(), value, decimals)
+}
+
+fn format_number(locale: Locale, value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = formatted.split_once('.').unwrap_or((&formatted, ""));
+    let mut grouped = String::new();
+    for (i, digit) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(locale.group_separator());
+        }
+        grouped.push(digit);
+    }
+    if !frac_part.is_empty() {
+        grouped.push(locale.decimal_separator());
+        grouped.push_str(frac_part);
+    }
+    let is_zero = formatted.chars().all(|c| c == '0' || c == '.');
+    if value < 0.0 && !is_zero {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+/// [`number`] with a leading "+" on positive values, for changes
+pub fn signed(value: f64, decimals: usize) -> String {
+    let text = number(value, decimals);
+    if value > 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        format!("+{}", text)
+    } else {
+        text
+    }
+}
+
+/// Whole count with grouped thousands
+pub fn integer(value: usize) -> String {
+    format_number(locale(), value as f64, 0)
+}
+
+/// A fraction as a percentage, e.g. 0.1234 → "12.3%" or "12,3 %"
+pub fn percent(fraction: f64, decimals: usize) -> String {
+    let value = number(fraction * 100.0, decimals);
+    match locale() {
+        Locale::English => format!("{}%", value),
+        Locale::German => format!("{} %", value),
+    }
+}
+
+/// US dollar amount, negatives in parentheses as accountants write them
+pub fn usd(value: f64) -> String {
+    let amount = number(value.abs(), 2);
+    let amount = match locale() {
+        Locale::English => format!("${}", amount),
+        Locale::German => format!("{} $", amount),
+    };
+    if value < 0.0 {
+        format!("({})", amount)
+    } else {
+        amount
+    }
+}
+
+pub fn date(date: NaiveDate) -> String {
+    match locale() {
+        Locale::English => date.format("%Y-%m-%d").to_string(),
+        Locale::German => date.format("%d.%m.%Y").to_string(),
+    }
+}
+
+/// A `YYYY-MM-DD` date as provider data stores it, or the text unchanged if it isn't one
+pub fn iso_date(text: &str) -> String {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").map_or_else(|_| text.to_string(), date)
+}
+
+pub fn date_time(at: DateTime<Local>) -> String {
+    format!("{} {}", date(at.date_naive()), at.format("%H:%M:%S"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Message ids and the placeables each one uses
+    fn shape(locale: Locale) -> Vec<(&'static str, Vec<String>)> {
+        let mut messages: Vec<_> = locale
+            .catalog()
+            .iter()
+            .map(|(id, text)| {
+                let mut names: Vec<String> =
+                    text.split("{ $").skip(1).filter_map(|s| s.split_once(" }").map(|(name, _)| name.to_string())).collect();
+                names.sort();
+                (*id, names)
+            })
+            .collect();
+        messages.sort();
+        messages
+    }
+
+    #[test]
+    fn test_catalogs_match() {
+        assert!(Locale::English.catalog().len() > 50);
+        for locale in Locale::ALL {
+            assert_eq!(shape(locale), shape(Locale::English), "{:?} catalog differs from English", locale);
+        }
+    }
+
+    #[test]
+    fn test_parse_and_fallback() {
+        let messages = parse("# comment\nhello = Hi { $name }\nlong = First\n    second\n");
+        assert_eq!(messages["hello"], "Hi { $name }");
+        assert_eq!(messages["long"], "First\nsecond");
+        assert_eq!(fill(messages["hello"].clone(), &[("name", "Ada".to_string())]), "Hi Ada");
+        assert_eq!(lookup(Locale::German, "no-such-message"), "no-such-message");
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        assert_eq!(format_number(Locale::English, 1234567.891, 2), "1,234,567.89");
+        assert_eq!(format_number(Locale::German, 1234567.891, 2), "1.234.567,89");
+        assert_eq!(format_number(Locale::English, -0.004, 2), "0.00");
+        assert_eq!(format_number(Locale::German, -42.5, 1), "-42,5");
+        assert_eq!(format_number(Locale::English, 999.0, 0), "999");
+    }
+}
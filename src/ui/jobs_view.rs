@@ -0,0 +1,165 @@
+use eframe::egui;
+
+use crate::app::AppState;
+use crate::data::jobs::{CronSchedule, Job, JobAction, JobTimezone};
+use crate::notices::NoticeSource;
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
+use crate::ui::status_bar::format_age;
+
+fn timezone_label(zone: JobTimezone) -> String {
+    tr(match zone {
+        JobTimezone::Local => "job-zone-local",
+        JobTimezone::NewYork => "job-zone-new-york",
+    })
+}
+
+fn action_label(action: JobAction) -> String {
+    tr(match action {
+        JobAction::Refresh => "job-action-refresh",
+        JobAction::Retrain => "job-action-retrain",
+        JobAction::Report => "job-action-report",
+        JobAction::EmailReport => "job-action-email-report",
+        JobAction::Screenshot => "job-action-screenshot",
+    })
+}
+
+/// Date and minute of a job run in the current language
+fn run_time(at: chrono::NaiveDateTime) -> String {
+    format!("{} {}", i18n::date(at.date()), at.format("%H:%M"))
+}
+
+/// Status-bar button for the Jobs window, with the next scheduled job on hover
+pub fn render_indicator(ui: &mut egui::Ui, state: &mut AppState) {
+    let now = chrono::Local::now().naive_local();
+    let next = state
+        .jobs
+        .jobs
+        .iter()
+        .filter_map(|job| Some((job.next_run(now)?, job)))
+        .min_by_key(|(at, _)| *at);
+    let hover = match next {
+        Some((at, job)) => tr_args("jobs-next", &[("name", job.name.clone()), ("time", run_time(at))]),
+        None => tr("jobs-none-enabled"),
+    };
+    if ui.selectable_label(state.jobs.open, tr("jobs-title")).on_hover_text(hover).clicked() {
+        state.jobs.open = !state.jobs.open;
+    }
+}
+
+/// Window listing the editable cron jobs, auto-refresh included, each with its next run
+/// and last result. Returns a job to run now.
+pub fn render_window(ctx: &egui::Context, state: &mut AppState) -> Option<usize> {
+    if !state.jobs.open {
+        return None;
+    }
+    let mut open = true;
+    let mut run = None;
+    let mut remove = None;
+    let before = state.jobs.jobs.clone();
+    let now = chrono::Local::now().naive_local();
+    egui::Window::new(tr("jobs-title"))
+        .id(egui::Id::new("jobs_window"))
+        .open(&mut open)
+        .default_width(760.0)
+        .show(ctx, |ui| {
+            egui::Grid::new("jobs_grid").num_columns(8).striped(true).spacing(egui::vec2(8.0, 6.0)).show(ui, |ui| {
+                for id in ["jobs-col-on", "jobs-col-name", "jobs-col-schedule", "jobs-col-zone", "jobs-col-action", "jobs-col-next-run", "jobs-col-last-result"] {
+                    ui.strong(tr(id));
+                }
+                ui.label("");
+                ui.end_row();
+
+                for (i, job) in state.jobs.jobs.iter_mut().enumerate() {
+                    ui.checkbox(&mut job.enabled, "");
+                    ui.add(egui::TextEdit::singleline(&mut job.name).desired_width(140.0));
+                    let parsed = CronSchedule::parse(&job.cron);
+                    let mut edit = egui::TextEdit::singleline(&mut job.cron).desired_width(110.0).code_editor();
+                    if parsed.is_err() {
+                        edit = edit.text_color(palette::negative());
+                    }
+                    let response = ui.add(edit);
+                    if let Err(e) = parsed {
+                        response.on_hover_text(e);
+                    }
+                    egui::ComboBox::from_id_salt(("job_timezone", i))
+                        .selected_text(timezone_label(job.timezone))
+                        .width(80.0)
+                        .show_ui(ui, |ui| {
+                            for zone in JobTimezone::ALL {
+                                ui.selectable_value(&mut job.timezone, zone, timezone_label(zone));
+                            }
+                        });
+                    egui::ComboBox::from_id_salt(("job_action", i))
+                        .selected_text(action_label(job.action))
+                        .width(130.0)
+                        .show_ui(ui, |ui| {
+                            for action in JobAction::ALL {
+                                ui.selectable_value(&mut job.action, action, action_label(action));
+                            }
+                        });
+                    render_next_run(ui, job.next_run(now).map(|at| (at, at - now)));
+                    render_last_result(ui, job);
+                    ui.horizontal(|ui| {
+                        if ui.small_button(tr("jobs-run-now")).clicked() {
+                            run = Some(i);
+                        }
+                        if ui.small_button("🗑").on_hover_text(tr("jobs-remove")).clicked() {
+                            remove = Some(i);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+            ui.add_space(4.0);
+            if ui.button(tr("jobs-add")).clicked() {
+                state.jobs.jobs.push(Job::default());
+            }
+            ui.small(tr("jobs-help"));
+        });
+
+    if let Some(i) = remove {
+        state.jobs.jobs.remove(i);
+    }
+    if state.jobs.jobs != before {
+        if let Err(e) = state.save_config() {
+            state.notices.error(NoticeSource::Settings, tr_args("jobs-save-failed", &[("error", e)]));
+        }
+    }
+    state.jobs.open = open;
+    run
+}
+
+fn render_next_run(ui: &mut egui::Ui, next: Option<(chrono::NaiveDateTime, chrono::Duration)>) {
+    match next {
+        Some((_, wait)) if wait <= chrono::Duration::zero() => ui.label(tr("jobs-due-now")),
+        Some((at, wait)) => ui
+            .label(tr_args("jobs-in", &[("wait", format_age(wait))]))
+            .on_hover_text(run_time(at)),
+        None => ui.weak("—"),
+    };
+}
+
+fn render_last_result(ui: &mut egui::Ui, job: &Job) {
+    if job.task.is_some() {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label(tr("task-running"));
+        });
+        return;
+    }
+    match &job.last_result {
+        Some(result) => {
+            let when = run_time(result.at);
+            if result.ok {
+                ui.colored_label(palette::positive(), when).on_hover_text(&result.message);
+            } else {
+                ui.colored_label(palette::negative(), format!("⚠ {}", when)).on_hover_text(&result.message);
+            }
+        }
+        None => {
+            ui.weak(tr("jobs-not-run"));
+        }
+    }
+}
@@ -3,13 +3,14 @@ use egui_plot::{Line, Plot, PlotPoints};
 
 use crate::app::AppState;
 use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Kurtosis & Return Distribution Analysis");
+    ui.heading(tr("kurt-heading"));
     ui.add_space(8.0);
 
     if state.analysis.kurtosis.is_empty() {
-        ui.label("Load market data first to compute distribution statistics.");
+        ui.label(tr("kurt-no-data"));
         return;
     }
 
@@ -18,21 +19,21 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     let selected = state.selected_sector_idx.min(symbols.len().saturating_sub(1));
 
     ui.horizontal(|ui| {
-        ui.label("Sector:");
+        ui.label(tr("kurt-sector"));
         for (i, sym) in symbols.iter().enumerate() {
             if ui.selectable_label(selected == i, sym).clicked() {
                 state.selected_sector_idx = i;
             }
         }
         ui.separator();
-        ui.label("Window:");
-        if ui.selectable_label(state.kurtosis_window == 30, "30-Day").clicked()
+        ui.label(tr("corr-window"));
+        if ui.selectable_label(state.kurtosis_window == 30, tr_args("unit-day-window", &[("days", "30".to_string())])).clicked()
             && state.kurtosis_window != 30
         {
             state.kurtosis_window = 30;
             state.recompute_kurtosis();
         }
-        if ui.selectable_label(state.kurtosis_window == 60, "60-Day").clicked()
+        if ui.selectable_label(state.kurtosis_window == 60, tr_args("unit-day-window", &[("days", "60".to_string())])).clicked()
             && state.kurtosis_window != 60
         {
             state.kurtosis_window = 60;
@@ -47,12 +48,12 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Summary statistics cards
     ui.group(|ui| {
-        ui.strong(format!("{} - Distribution Statistics", metrics.symbol));
+        ui.strong(tr_args("kurt-stats-heading", &[("symbol", metrics.symbol.clone())]));
         ui.add_space(4.0);
 
         ui.columns(6, |cols| {
-            stat_card(&mut cols[0], "Mean", &format!("{:.4}%", metrics.mean * 100.0));
-            stat_card(&mut cols[1], "Std Dev", &format!("{:.4}%", metrics.std_dev * 100.0));
+            stat_card(&mut cols[0], &tr("kurt-mean"), &i18n::percent(metrics.mean, 4));
+            stat_card(&mut cols[1], &tr("kurt-std-dev"), &i18n::percent(metrics.std_dev, 4));
 
             let kurt_color = if metrics.excess_kurtosis.abs() > 3.0 {
                 egui::Color32::from_rgb(220, 50, 50)
@@ -61,7 +62,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             } else {
                 egui::Color32::from_rgb(50, 180, 50)
             };
-            stat_card_colored(&mut cols[2], "Excess Kurtosis", &format!("{:.3}", metrics.excess_kurtosis), kurt_color);
+            stat_card_colored(&mut cols[2], &tr("kurt-excess"), &i18n::number(metrics.excess_kurtosis, 3), kurt_color);
 
             let skew_color = if metrics.skewness.abs() > 1.0 {
                 egui::Color32::from_rgb(220, 50, 50)
@@ -70,23 +71,23 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             } else {
                 egui::Color32::from_rgb(50, 180, 50)
             };
-            stat_card_colored(&mut cols[3], "Skewness", &format!("{:.3}", metrics.skewness), skew_color);
+            stat_card_colored(&mut cols[3], &tr("kurt-skewness"), &i18n::number(metrics.skewness, 3), skew_color);
 
             let jb_color = if metrics.jarque_bera > 10.0 {
                 egui::Color32::from_rgb(220, 50, 50)
             } else {
                 egui::Color32::from_rgb(50, 180, 50)
             };
-            stat_card_colored(&mut cols[4], "Jarque-Bera", &format!("{:.1}", metrics.jarque_bera), jb_color);
+            stat_card_colored(&mut cols[4], &tr("kurt-jarque-bera"), &i18n::number(metrics.jarque_bera, 1), jb_color);
 
             let tail_label = if metrics.excess_kurtosis > 1.0 {
-                "Leptokurtic (fat tails)"
+                "kurt-leptokurtic"
             } else if metrics.excess_kurtosis < -1.0 {
-                "Platykurtic (thin tails)"
+                "kurt-platykurtic"
             } else {
-                "Mesokurtic (near normal)"
+                "kurt-mesokurtic"
             };
-            stat_card(&mut cols[5], "Tail Type", tail_label);
+            stat_card(&mut cols[5], &tr("kurt-tail-type"), &tr(tail_label));
         });
     });
 
@@ -94,7 +95,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Distribution curve: empirical density vs fitted normal
     if !metrics.empirical_density.is_empty() {
-        ui.heading("Return Distribution (KDE vs Normal Fit)");
+        ui.heading(tr("kurt-distribution-heading"));
         ui.add_space(4.0);
 
         let empirical_data: Vec<[f64; 2]> = metrics
@@ -111,12 +112,13 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .collect();
         let normal_points: PlotPoints = normal_data.iter().copied().collect();
 
+        let (kde_name, normal_name) = (tr("kurt-empirical-kde"), tr("kurt-normal-fit"));
         let dist_hover = [
-            HoverSeries { name: "Empirical KDE", data: &empirical_data, decimals: 4, suffix: "" },
-            HoverSeries { name: "Normal Fit", data: &normal_data, decimals: 4, suffix: "" },
+            HoverSeries { name: &kde_name, data: &empirical_data, decimals: 4, suffix: "" },
+            HoverSeries { name: &normal_name, data: &normal_data, decimals: 4, suffix: "" },
         ];
 
-        height_control(ui, &mut state.chart_heights.kurtosis_distribution, "Distribution Plot Height");
+        height_control(ui, &mut state.chart_heights.kurtosis_distribution, &tr("kurt-distribution-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "distribution_plot",
@@ -124,21 +126,21 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("distribution_plot")
                     .height(state.chart_heights.kurtosis_distribution),
             )
-                .x_axis_label("Daily Log Return (%)")
-                .y_axis_label("Density")
+                .x_axis_label(tr("kurt-axis-log-return"))
+                .y_axis_label(tr("kurt-axis-density"))
                 .legend(egui_plot::Legend::default())
                 .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&dist_hover))
                 .label_formatter(chart_utils::no_hover_label),
             |plot_ui| {
                 plot_ui.line(
                     Line::new(empirical_points)
-                        .name("Empirical (KDE)")
+                        .name(tr("kurt-empirical-series"))
                         .color(egui::Color32::from_rgb(80, 160, 255))
                         .width(2.5),
                 );
                 plot_ui.line(
                     Line::new(normal_points)
-                        .name("Normal Fit")
+                        .name(&normal_name)
                         .color(egui::Color32::from_rgb(255, 100, 100))
                         .width(2.0)
                         .style(egui_plot::LineStyle::dashed_dense()),
@@ -147,14 +149,14 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         );
 
         ui.add_space(4.0);
-        ui.small("Blue = empirical density (Gaussian KDE). Red dashed = fitted normal distribution. Fat tails appear where the blue curve extends beyond the red.");
+        ui.small(tr("kurt-distribution-note"));
     }
 
     ui.add_space(12.0);
 
     // Rolling kurtosis over time
     if !metrics.rolling_kurtosis.is_empty() && !metrics.rolling_dates.is_empty() {
-        ui.heading(format!("Rolling Excess Kurtosis ({}-day window)", state.kurtosis_window));
+        ui.heading(tr_args("kurt-rolling-heading", &[("days", state.kurtosis_window.to_string())]));
         ui.add_space(4.0);
 
         let base_date = metrics.rolling_dates.first().copied();
@@ -175,7 +177,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 [day, *k]
             })
             .collect();
-        let kurt_points: PlotPoints = kurt_data.iter().copied().collect();
+        let kurt_points = chart_utils::plot_points(&kurt_data);
 
         // Reference line at 0 (normal distribution)
         let x_max = metrics
@@ -185,9 +187,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .unwrap_or(metrics.rolling_kurtosis.len() as f64);
         let zero_line: PlotPoints = vec![[0.0, 0.0], [x_max, 0.0]].into_iter().collect();
 
-        let kurt_hover = [HoverSeries { name: "Rolling Kurtosis", data: &kurt_data, decimals: 3, suffix: "" }];
+        let kurt_name = tr("kurt-rolling");
+        let kurt_hover = [HoverSeries { name: &kurt_name, data: &kurt_data, decimals: 3, suffix: "" }];
 
-        height_control(ui, &mut state.chart_heights.kurtosis_rolling_kurtosis, "Rolling Kurtosis Chart Height");
+        height_control(ui, &mut state.chart_heights.kurtosis_rolling_kurtosis, &tr("kurt-rolling-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "rolling_kurtosis_plot",
@@ -195,21 +198,21 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("rolling_kurtosis_plot")
                     .height(state.chart_heights.kurtosis_rolling_kurtosis),
             )
-                .x_axis_label("Trading Days")
-                .y_axis_label("Excess Kurtosis")
+                .x_axis_label(tr("axis-trading-days"))
+                .y_axis_label(tr("kurt-excess"))
                 .legend(egui_plot::Legend::default())
                 .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&kurt_hover))
                 .label_formatter(chart_utils::no_hover_label),
             |plot_ui| {
                 plot_ui.line(
                     Line::new(kurt_points)
-                        .name("Rolling Kurtosis")
+                        .name(&kurt_name)
                         .color(egui::Color32::from_rgb(180, 100, 255))
                         .width(1.8),
                 );
                 plot_ui.line(
                     Line::new(zero_line)
-                        .name("Normal (0)")
+                        .name(tr("kurt-normal-zero"))
                         .color(egui::Color32::from_rgb(150, 150, 150))
                         .style(egui_plot::LineStyle::dashed_loose()),
                 );
@@ -222,7 +225,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     // Kurtosis acceleration / deceleration chart and stats table
     if let Some(ref accel) = metrics.accel_metrics {
         if !accel.velocity.is_empty() && !accel.acceleration.is_empty() {
-            ui.heading("Kurtosis Acceleration / Deceleration");
+            ui.heading(tr("kurt-accel-heading"));
             ui.add_space(4.0);
 
             let vel_data: Vec<[f64; 2]> = accel
@@ -231,7 +234,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 .enumerate()
                 .map(|(i, v)| [i as f64, *v])
                 .collect();
-            let vel_points: PlotPoints = vel_data.iter().copied().collect();
+            let vel_points = chart_utils::plot_points(&vel_data);
 
             let acc_data: Vec<[f64; 2]> = accel
                 .acceleration
@@ -239,17 +242,18 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 .enumerate()
                 .map(|(i, a)| [i as f64, *a])
                 .collect();
-            let accel_points: PlotPoints = acc_data.iter().copied().collect();
+            let accel_points = chart_utils::plot_points(&acc_data);
 
             let x_max = accel.velocity.len() as f64;
             let zero_line: PlotPoints = vec![[0.0, 0.0], [x_max, 0.0]].into_iter().collect();
 
+            let (vel_name, accel_name) = (tr("kurt-velocity"), tr("kurt-acceleration"));
             let accel_hover = [
-                HoverSeries { name: "Velocity", data: &vel_data, decimals: 4, suffix: "" },
-                HoverSeries { name: "Acceleration", data: &acc_data, decimals: 4, suffix: "" },
+                HoverSeries { name: &vel_name, data: &vel_data, decimals: 4, suffix: "" },
+                HoverSeries { name: &accel_name, data: &acc_data, decimals: 4, suffix: "" },
             ];
 
-            height_control(ui, &mut state.chart_heights.kurtosis_accel_chart, "Acceleration Chart Height");
+            height_control(ui, &mut state.chart_heights.kurtosis_accel_chart, &tr("kurt-accel-height"));
             chart_utils::plot_with_y_drag(
                 ui,
                 "kurtosis_accel_plot",
@@ -257,27 +261,27 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                     Plot::new("kurtosis_accel_plot")
                         .height(state.chart_heights.kurtosis_accel_chart),
                 )
-                    .x_axis_label("Observation")
-                    .y_axis_label("Rate of Change")
+                    .x_axis_label(tr("kurt-axis-observation"))
+                    .y_axis_label(tr("kurt-axis-rate"))
                     .legend(egui_plot::Legend::default())
                     .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&accel_hover))
                     .label_formatter(chart_utils::no_hover_label),
                 |plot_ui| {
                     plot_ui.line(
                         Line::new(vel_points)
-                            .name("Velocity (trend)")
+                            .name(tr("kurt-velocity-trend"))
                             .color(egui::Color32::from_rgb(80, 200, 120))
                             .width(1.8),
                     );
                     plot_ui.line(
                         Line::new(accel_points)
-                            .name("Acceleration")
+                            .name(&accel_name)
                             .color(egui::Color32::from_rgb(255, 140, 50))
                             .width(1.8),
                     );
                     plot_ui.line(
                         Line::new(zero_line)
-                            .name("Zero")
+                            .name(tr("kurt-zero"))
                             .color(egui::Color32::from_rgb(150, 150, 150))
                             .style(egui_plot::LineStyle::dashed_loose()),
                     );
@@ -288,15 +292,15 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
             // Embedded stats table
             ui.group(|ui| {
-                ui.strong("Projected Metrics (one-step-ahead linear extrapolation)");
+                ui.strong(tr("kurt-projected-heading"));
                 ui.add_space(4.0);
 
                 egui::Grid::new("accel_stats_table")
                     .min_col_width(180.0)
                     .striped(true)
                     .show(ui, |ui| {
-                        ui.strong("Metric");
-                        ui.strong("Value");
+                        ui.strong(tr("col-metric"));
+                        ui.strong(tr("col-value"));
                         ui.end_row();
 
                         let trend_color = if accel.trend >= 0.0 {
@@ -315,22 +319,22 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                             egui::Color32::from_rgb(220, 60, 60)
                         };
 
-                        ui.label("Kurtosis Trend (velocity)");
-                        ui.colored_label(trend_color, format!("{:.6}", accel.trend));
+                        ui.label(tr("kurt-trend"));
+                        ui.colored_label(trend_color, i18n::number(accel.trend, 6));
                         ui.end_row();
 
-                        ui.label("Projected Accel/Decel");
-                        ui.colored_label(accel_color, format!("{:.6}", accel.projected_accel));
+                        ui.label(tr("kurt-projected-accel"));
+                        ui.colored_label(accel_color, i18n::number(accel.projected_accel, 6));
                         ui.end_row();
 
-                        ui.label("Projected Kurtosis");
-                        ui.colored_label(kurt_color, format!("{:.6}", accel.projected_kurtosis));
+                        ui.label(tr("kurt-projected-kurtosis"));
+                        ui.colored_label(kurt_color, i18n::number(accel.projected_kurtosis, 6));
                         ui.end_row();
                     });
             });
 
             ui.add_space(4.0);
-            ui.small("Velocity = first difference of rolling kurtosis (positive = rising). Acceleration = second difference (positive = accelerating). Projections use linear extrapolation one step ahead.");
+            ui.small(tr("kurt-accel-note"));
         }
     }
 
@@ -338,7 +342,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Rolling skewness over time
     if !metrics.rolling_skewness.is_empty() && !metrics.rolling_dates.is_empty() {
-        ui.heading(format!("Rolling Skewness ({}-day window)", state.kurtosis_window));
+        ui.heading(tr_args("kurt-skew-heading", &[("days", state.kurtosis_window.to_string())]));
         ui.add_space(4.0);
 
         let base_date = metrics.rolling_dates.first().copied();
@@ -359,7 +363,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 [day, *s]
             })
             .collect();
-        let skew_points: PlotPoints = skew_data.iter().copied().collect();
+        let skew_points = chart_utils::plot_points(&skew_data);
 
         let x_max = metrics
             .rolling_dates
@@ -368,9 +372,10 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .unwrap_or(metrics.rolling_skewness.len() as f64);
         let zero_line: PlotPoints = vec![[0.0, 0.0], [x_max, 0.0]].into_iter().collect();
 
-        let skew_hover = [HoverSeries { name: "Rolling Skewness", data: &skew_data, decimals: 3, suffix: "" }];
+        let skew_name = tr("kurt-rolling-skew");
+        let skew_hover = [HoverSeries { name: &skew_name, data: &skew_data, decimals: 3, suffix: "" }];
 
-        height_control(ui, &mut state.chart_heights.kurtosis_rolling_skewness, "Rolling Skewness Chart Height");
+        height_control(ui, &mut state.chart_heights.kurtosis_rolling_skewness, &tr("kurt-skew-height"));
         chart_utils::plot_with_y_drag(
             ui,
             "rolling_skewness_plot",
@@ -378,21 +383,21 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                 Plot::new("rolling_skewness_plot")
                     .height(state.chart_heights.kurtosis_rolling_skewness),
             )
-                .x_axis_label("Trading Days")
-                .y_axis_label("Skewness")
+                .x_axis_label(tr("axis-trading-days"))
+                .y_axis_label(tr("kurt-skewness"))
                 .legend(egui_plot::Legend::default())
                 .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&skew_hover))
                 .label_formatter(chart_utils::no_hover_label),
             |plot_ui| {
                 plot_ui.line(
                     Line::new(skew_points)
-                        .name("Rolling Skewness")
+                        .name(&skew_name)
                         .color(egui::Color32::from_rgb(255, 180, 50))
                         .width(1.8),
                 );
                 plot_ui.line(
                     Line::new(zero_line)
-                        .name("Symmetricity (0)")
+                        .name(tr("kurt-symmetric-zero"))
                         .color(egui::Color32::from_rgb(150, 150, 150))
                         .style(egui_plot::LineStyle::dashed_loose()),
                 );
@@ -404,18 +409,18 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Cross-sector kurtosis comparison table
     if state.analysis.kurtosis.len() > 1 {
-        ui.heading("Cross-Sector Kurtosis Comparison");
+        ui.heading(tr("kurt-comparison-heading"));
         ui.add_space(4.0);
 
         egui::Grid::new("kurtosis_table")
             .striped(true)
             .min_col_width(80.0)
             .show(ui, |ui| {
-                ui.strong("Sector");
-                ui.strong("Excess Kurtosis");
-                ui.strong("Skewness");
-                ui.strong("Jarque-Bera");
-                ui.strong("Tail Type");
+                ui.strong(tr("col-sector"));
+                ui.strong(tr("kurt-excess"));
+                ui.strong(tr("kurt-skewness"));
+                ui.strong(tr("kurt-jarque-bera"));
+                ui.strong(tr("kurt-tail-type"));
                 ui.end_row();
 
                 let mut sorted: Vec<_> = state.analysis.kurtosis.iter().collect();
@@ -425,26 +430,26 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                     ui.label(&km.symbol);
 
                     let kurt_color = kurt_color(km.excess_kurtosis);
-                    ui.colored_label(kurt_color, format!("{:.3}", km.excess_kurtosis));
+                    ui.colored_label(kurt_color, i18n::number(km.excess_kurtosis, 3));
 
                     let skew_color = skew_color(km.skewness);
-                    ui.colored_label(skew_color, format!("{:.3}", km.skewness));
+                    ui.colored_label(skew_color, i18n::number(km.skewness, 3));
 
                     let jb_color = if km.jarque_bera > 10.0 {
                         egui::Color32::from_rgb(220, 50, 50)
                     } else {
                         egui::Color32::from_rgb(50, 180, 50)
                     };
-                    ui.colored_label(jb_color, format!("{:.1}", km.jarque_bera));
+                    ui.colored_label(jb_color, i18n::number(km.jarque_bera, 1));
 
                     let tail = if km.excess_kurtosis > 1.0 {
-                        "Fat tails"
+                        "kurt-fat-tails"
                     } else if km.excess_kurtosis < -1.0 {
-                        "Thin tails"
+                        "kurt-thin-tails"
                     } else {
-                        "Normal-like"
+                        "kurt-normal-like"
                     };
-                    ui.label(tail);
+                    ui.label(tr(tail));
                     ui.end_row();
                 }
             });
@@ -453,7 +458,7 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     ui.add_space(16.0);
     ui.separator();
     ui.add_space(4.0);
-    ui.small("Excess kurtosis > 0 indicates heavier tails than a normal distribution (leptokurtic). The Jarque-Bera test statistic measures departure from normality (critical value ~5.99 at 5% significance).");
+    ui.small(tr("kurt-footer-note"));
 }
 
 fn stat_card(ui: &mut egui::Ui, label: &str, value: &str) {
@@ -0,0 +1,107 @@
+//! Explain mode: definitions, formulas and readings for the headline metrics, shown
+//! as hover cards wherever those metrics are displayed.
+
+use eframe::egui;
+
+use crate::config;
+use crate::data::models::AnalysisWindows;
+use crate::ui::i18n::{tr, tr_args};
+
+/// A metric with an entry in the explain-mode table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    ParkinsonVol,
+    VolRatio,
+    TermSpread,
+    AvgCorrelation,
+}
+
+/// What explain mode shows for a metric: catalog ids for the prose, and the formula,
+/// which reads the same in every language
+pub struct MetricInfo {
+    pub name: &'static str,
+    pub definition: &'static str,
+    pub formula: &'static str,
+    pub interpretation: &'static str,
+}
+
+const PARKINSON_VOL: MetricInfo = MetricInfo {
+    name: "metric-parkinson-name",
+    definition: "metric-parkinson-definition",
+    formula: "σ = √( 1/(4·ln 2) · mean[ ln(H/L)² ] ) · √252",
+    interpretation: "metric-parkinson-interpretation",
+};
+
+const VOL_RATIO: MetricInfo = MetricInfo {
+    name: "metric-vol-ratio-name",
+    definition: "metric-vol-ratio-definition",
+    formula: "ratio = σ(short window) / σ(long window)",
+    interpretation: "metric-vol-ratio-interpretation",
+};
+
+const TERM_SPREAD: MetricInfo = MetricInfo {
+    name: "metric-term-spread-name",
+    definition: "metric-term-spread-definition",
+    formula: "spread = y(10Y) − y(2Y)   (1 pp = 100 bps)",
+    interpretation: "metric-term-spread-interpretation",
+};
+
+const AVG_CORRELATION: MetricInfo = MetricInfo {
+    name: "metric-avg-correlation-name",
+    definition: "metric-avg-correlation-definition",
+    formula: "ρ̄ = 2 / (n(n−1)) · Σ_{i<j} ρ(r_i, r_j)",
+    interpretation: "metric-avg-correlation-interpretation",
+};
+
+impl Metric {
+    pub fn info(&self) -> &'static MetricInfo {
+        match self {
+            Self::ParkinsonVol => &PARKINSON_VOL,
+            Self::VolRatio => &VOL_RATIO,
+            Self::TermSpread => &TERM_SPREAD,
+            Self::AvgCorrelation => &AVG_CORRELATION,
+        }
+    }
+
+    /// Window lengths this app uses for the metric, if it has any
+    fn windows(&self, windows: &AnalysisWindows) -> Option<String> {
+        match self {
+            Self::ParkinsonVol => Some(tr_args("metric-window", &[("days", windows.short_vol.to_string())])),
+            Self::VolRatio => Some(tr_args(
+                "metric-window-pair",
+                &[("short", windows.short_vol.to_string()), ("long", windows.long_vol.to_string())],
+            )),
+            Self::AvgCorrelation => Some(tr_args("metric-rolling-window", &[("days", config::ROLLING_CORR_WINDOW.to_string())])),
+            Self::TermSpread => None,
+        }
+    }
+}
+
+/// Attach the metric's explanation as a hover card when explain mode is on
+pub fn explain(response: egui::Response, enabled: bool, metric: Metric, windows: &AnalysisWindows) -> egui::Response {
+    if !enabled {
+        return response;
+    }
+    response.on_hover_ui(|ui| render_card(ui, metric, windows))
+}
+
+/// A small ⓘ marker carrying the explanation, for places without a single label to hover
+pub fn badge(ui: &mut egui::Ui, enabled: bool, metric: Metric, windows: &AnalysisWindows) {
+    if enabled {
+        explain(ui.weak("ⓘ"), true, metric, windows);
+    }
+}
+
+fn render_card(ui: &mut egui::Ui, metric: Metric, windows: &AnalysisWindows) {
+    let info = metric.info();
+    ui.set_max_width(360.0);
+    ui.strong(tr(info.name));
+    ui.label(tr(info.definition));
+    ui.add_space(4.0);
+    ui.monospace(info.formula);
+    if let Some(windows) = metric.windows(windows) {
+        ui.small(windows);
+    }
+    ui.add_space(4.0);
+    ui.label(egui::RichText::new(tr(info.interpretation)).italics());
+}
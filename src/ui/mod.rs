@@ -1,8 +1,57 @@
+#[cfg(feature = "native")]
+pub mod alerts_view;
+#[cfg(feature = "native")]
 pub mod bond_view;
 pub mod chart_utils;
+#[cfg(feature = "native")]
+pub mod compare_view;
+#[cfg(feature = "native")]
 pub mod correlation_view;
+#[cfg(feature = "native")]
 pub mod dashboard;
+#[cfg(feature = "native")]
+pub mod data_view;
+#[cfg(feature = "native")]
+pub mod diagnostics_view;
+pub mod i18n;
+#[cfg(feature = "native")]
+pub mod jobs_view;
+#[cfg(feature = "native")]
 pub mod kurtosis_view;
+pub mod metric_info;
+#[cfg(feature = "native")]
 pub mod nn_view;
+#[cfg(feature = "native")]
+pub mod notices_view;
+pub mod palette;
+#[cfg(feature = "native")]
+pub mod plugins_view;
+#[cfg(feature = "native")]
+pub mod profiles;
+#[cfg(feature = "native")]
+pub mod rates_view;
+#[cfg(feature = "native")]
+pub mod refresh_progress;
+#[cfg(feature = "native")]
+pub mod report;
+#[cfg(feature = "native")]
+pub mod risk_view;
+#[cfg(feature = "native")]
+pub mod screenshot;
+#[cfg(feature = "native")]
+pub mod search;
+#[cfg(feature = "native")]
 pub mod sector_view;
+#[cfg(feature = "native")]
 pub mod settings_view;
+#[cfg(feature = "native")]
+pub mod shortcuts;
+#[cfg(feature = "native")]
+pub mod status_bar;
+pub mod table_nav;
+#[cfg(feature = "native")]
+pub mod tasks_view;
+#[cfg(feature = "native")]
+pub mod tray;
+#[cfg(feature = "native")]
+pub mod watchlist_view;
@@ -1,28 +1,46 @@
+use std::sync::{Arc, Mutex};
+
 use eframe::egui;
-use egui_plot::{Line, Plot, PlotPoints};
+use egui_plot::{Bar, BarChart, Line, Plot, PlotPoint, PlotPoints, Points, VLine};
 
 use crate::app::AppState;
-use crate::data::models::TrainingStatus;
+use crate::data::models::{NnTargetMode, NnTrainingConfig, NnVolTarget, TrainingStatus};
+use crate::nn::drift::DriftStatus;
+use crate::nn::history::{self, RunOutcome, TrainingRun};
+use crate::nn::prediction_history;
+use crate::nn::sweep::{self, SweepMode, SweepProgress, SweepRunStatus};
 use crate::nn::training::TrainingProgress;
-use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::notices::NoticeSource;
+use crate::ui::chart_utils::{self, height_control, CachedSeries, FloatsKey, HoverSeries};
+use crate::tasks::{TaskHooks, TaskId, TaskKind, TaskProgress};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::table_nav;
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Neural Network - Volatility Regime Prediction");
+    ui.heading(tr("nn-heading"));
     ui.add_space(8.0);
 
     if state.market_data.sectors.is_empty() {
-        ui.label("Load market data first before training the neural network.");
+        ui.label(tr("nn-no-data"));
         return;
     }
 
     // Model info
     ui.group(|ui| {
-        ui.label("Model Architecture: LSTM (hidden=64) -> Linear");
-        ui.label("Input: 70 features (vols, returns, randomness, kurtosis, cross-corr, spread, slope, VIX-proxy)");
-        ui.label("Output: 5-day forward vol + entropy + kurtosis/skewness per sector");
-        ui.label(format!(
-            "Lookback: {} trading days per sample",
-            crate::config::NN_LOOKBACK_DAYS
+        ui.label(tr_args("nn-architecture", &[("hidden", state.nn_training_config.hidden_size.to_string())]));
+        ui.label(tr_args(
+            "nn-input",
+            &[("count", crate::nn::model::num_features(&state.nn_feature_flags).to_string())],
+        ));
+        ui.label(tr_args("nn-output", &[("target", vol_target_label(state.nn_training_config.vol_target))]));
+        ui.label(tr_args(
+            "nn-lookback",
+            &[
+                ("lookback", state.nn_training_config.lookback.to_string()),
+                ("lr", state.nn_training_config.learning_rate.to_string()),
+                ("epochs", state.nn_training_config.epochs.to_string()),
+                ("loss", loss_label(&state.nn_training_config)),
+            ],
         ));
     });
 
@@ -36,6 +54,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         if let Ok(losses) = progress.losses.lock() {
             state.training_losses = losses.clone();
         }
+        if let Ok(losses) = progress.val_losses.lock() {
+            state.validation_losses = losses.clone();
+        }
         if let Ok(preds) = progress.predictions.lock() {
             state.nn_predictions = preds.clone();
         }
@@ -44,21 +65,28 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         }
     }
 
+    // Record the run in the history once the training thread reports how it ended
+    match state.training_status.clone() {
+        TrainingStatus::Complete { .. } => finish_active_run(state, RunOutcome::Complete),
+        TrainingStatus::Error(msg) => finish_active_run(state, RunOutcome::Failed(msg)),
+        _ => {}
+    }
+
     // After training completes, load the saved model so we have it for future inference.
     // persistence_message is only set here (not in Default) so the banner is fresh each session.
     if matches!(state.training_status, TrainingStatus::Complete { .. }) && state.loaded_model.is_none() {
         match crate::nn::persistence::load_model() {
             Some((model, meta)) => {
-                state.persistence_message = Some(format!(
-                    "Model saved and loaded from disk (trained {}; loss: {:.6}).",
-                    meta.trained_at, meta.final_loss
-                ));
+                state.persistence_message = Some(Ok(tr_args(
+                    "nn-model-saved",
+                    &[("trained", meta.trained_at.clone()), ("loss", i18n::number(meta.final_loss, 6))],
+                )));
                 state.loaded_model = Some(model);
                 state.model_metadata = Some(meta);
+                state.track_live_predictions(true);
             }
             None => {
-                state.persistence_message =
-                    Some("Warning: model could not be saved or loaded from disk.".to_string());
+                state.persistence_message = Some(Err(tr("nn-model-save-failed")));
             }
         }
     }
@@ -70,29 +98,32 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     );
     if !is_training {
         ui.horizontal(|ui| {
-            ui.label("Training Device:");
+            ui.label(tr("nn-training-device"));
 
             if !state.available_gpus.is_empty() {
-                let gpu_label = format!(
-                    "GPU ({})",
-                    state.available_gpus.first().map(|a| a.name.as_str()).unwrap_or("WGPU")
-                );
+                let gpu_label = format!("GPU ({})", state.gpu_adapter_name().unwrap_or("WGPU"));
                 ui.selectable_value(&mut state.use_gpu, true, gpu_label);
-                ui.selectable_value(&mut state.use_gpu, false, "CPU (NdArray)");
-                ui.colored_label(
-                    egui::Color32::from_rgb(50, 180, 50),
-                    format!("  Detected: {}", state.available_gpus[0].name),
-                );
+                ui.selectable_value(&mut state.use_gpu, false, tr("nn-cpu-device"));
+                let detected = if state.available_gpus.len() > 1 {
+                    tr_args("nn-detected-many", &[("count", state.available_gpus.len().to_string())])
+                } else {
+                    tr_args("nn-detected", &[("name", state.available_gpus[0].name.clone())])
+                };
+                ui.add_space(8.0);
+                ui.colored_label(egui::Color32::from_rgb(50, 180, 50), detected);
             } else {
-                ui.selectable_value(&mut state.use_gpu, false, "CPU (NdArray)");
+                ui.selectable_value(&mut state.use_gpu, false, tr("nn-cpu-device"));
                 state.use_gpu = false;
-                ui.colored_label(
-                    egui::Color32::from_rgb(220, 100, 50),
-                    "  No GPU detected (WGPU found no adapters)",
-                );
+                ui.add_space(8.0);
+                ui.colored_label(egui::Color32::from_rgb(220, 100, 50), tr("nn-no-gpu"));
             }
         });
         ui.add_space(4.0);
+
+        if !state.available_gpus.is_empty() {
+            render_gpu_diagnostics(ui, state);
+            ui.add_space(4.0);
+        }
     }
 
     // Model loaded from disk indicator
@@ -100,22 +131,27 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         ui.horizontal(|ui| {
             ui.colored_label(
                 egui::Color32::from_rgb(100, 180, 255),
-                format!("Model loaded from disk (trained {}; loss: {:.6})", meta.trained_at, meta.final_loss),
+                tr_args(
+                    "nn-model-loaded",
+                    &[("trained", meta.trained_at.clone()), ("loss", i18n::number(meta.final_loss, 6))],
+                ),
             );
         });
         ui.add_space(4.0);
     }
 
+    if let Some(drift) = state.drift_status() {
+        render_drift_status(ui, &drift, state.drift_settings.threshold_pct);
+    }
+
     // Persistence feedback (save/load result from the most recent training session)
     if let Some(ref msg) = state.persistence_message.clone() {
-        let is_warning = msg.starts_with("Warning");
-        let color = if is_warning {
-            egui::Color32::from_rgb(220, 120, 50)
-        } else {
-            egui::Color32::from_rgb(50, 200, 100)
+        let (color, text) = match msg {
+            Ok(msg) => (egui::Color32::from_rgb(50, 200, 100), msg.clone()),
+            Err(msg) => (egui::Color32::from_rgb(220, 120, 50), tr_args("nn-warning", &[("message", msg.clone())])),
         };
         ui.horizontal(|ui| {
-            ui.colored_label(color, msg.as_str());
+            ui.colored_label(color, text);
             if ui.small_button("✕").clicked() {
                 state.persistence_message = None;
             }
@@ -126,44 +162,71 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
     // Training controls -- each arm owns its own layout so ProgressBar never hides buttons
     match state.training_status.clone() {
         TrainingStatus::Idle => {
+            let sweep_running = state
+                .sweep_progress
+                .as_ref()
+                .is_some_and(|p| !p.is_finished());
             ui.horizontal(|ui| {
-                if ui.button("Train Model").clicked() {
+                if ui
+                    .add_enabled(!sweep_running, egui::Button::new(tr("nn-train")))
+                    .on_disabled_hover_text(tr("nn-sweep-running"))
+                    .clicked()
+                {
                     start_training(state);
                 }
+                if ui
+                    .button(tr("nn-predict"))
+                    .on_hover_text(tr("nn-predict-hover"))
+                    .clicked()
+                {
+                    predict_from_checkpoint(state);
+                }
                 if state.loaded_model.is_some() {
-                    if ui.button("Run Inference").clicked() {
-                        if let Some(ref model) = state.loaded_model {
-                            let preds = crate::nn::training::run_inference(model, &state.market_data, &state.nn_feature_flags);
-                            if !preds.is_empty() {
-                                state.nn_predictions = preds.clone();
-                                if let Some(ref meta) = state.model_metadata {
-                                    state.training_status = TrainingStatus::Complete {
-                                        final_loss: meta.final_loss,
-                                    };
-                                }
-                            }
-                        }
+                    if ui.button(tr("nn-run-backtest")).clicked() {
+                        run_backtest(state);
+                    }
+                    if ui
+                        .button(tr("nn-saliency"))
+                        .on_hover_text(tr("nn-saliency-hover"))
+                        .clicked()
+                    {
+                        run_saliency(state);
                     }
                 }
+                if ui
+                    .button(tr("nn-export-features"))
+                    .on_hover_text(tr("nn-export-features-hover"))
+                    .clicked()
+                {
+                    let result = state
+                        .export_feature_dataset()
+                        .map(|path| tr_args("nn-export-features-done", &[("path", path.display().to_string())]))
+                        .map_err(|e| tr_args("nn-export-features-failed", &[("error", e.to_string())]));
+                    state.notices.report(NoticeSource::Export, result);
+                }
             });
         }
         TrainingStatus::Training { epoch, total_epochs, loss } => {
             // Row 1: spinner + status text + Pause button
             ui.horizontal(|ui| {
                 ui.spinner();
-                ui.label(format!(
-                    "Training... Epoch {}/{} | Loss: {:.6}",
-                    epoch, total_epochs, loss
+                ui.label(tr_args(
+                    "nn-training-epoch",
+                    &[
+                        ("epoch", epoch.to_string()),
+                        ("total", total_epochs.to_string()),
+                        ("loss", i18n::number(loss, 6)),
+                    ],
                 ));
-                if ui.button("Pause").clicked() {
+                if ui.button(tr("nn-pause")).clicked() {
                     if let Some(ref progress) = state.training_progress {
                         progress.request_pause();
                     }
                 }
             });
-            // Row 2: full-width progress bar
+            // Row 2: progress bar, with live GPU telemetry alongside on GPU runs
             let frac = epoch as f32 / total_epochs as f32;
-            ui.add(egui::ProgressBar::new(frac).show_percentage());
+            progress_with_telemetry(ui, frac, &state.compute_stats);
             ui.ctx().request_repaint_after(std::time::Duration::from_millis(200));
         }
         TrainingStatus::Paused { epoch, total_epochs, loss } => {
@@ -171,43 +234,64 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             ui.horizontal(|ui| {
                 ui.colored_label(
                     egui::Color32::from_rgb(220, 180, 50),
-                    format!("Paused at Epoch {}/{} | Loss: {:.6}", epoch, total_epochs, loss),
+                    tr_args(
+                        "nn-paused-epoch",
+                        &[
+                            ("epoch", epoch.to_string()),
+                            ("total", total_epochs.to_string()),
+                            ("loss", i18n::number(loss, 6)),
+                        ],
+                    ),
                 );
-                if ui.button("Resume").clicked() {
+                if ui.button(tr("nn-resume")).clicked() {
                     if let Some(ref progress) = state.training_progress {
                         progress.request_resume();
                     }
                 }
-                if ui.button("Stop").clicked() {
+                if ui.button(tr("nn-stop")).clicked() {
+                    if let Some(ref progress) = state.training_progress {
+                        progress.request_stop();
+                    }
+                    finish_active_run(state, RunOutcome::Stopped);
                     state.training_status = TrainingStatus::Idle;
                     state.training_progress = None;
                 }
             });
-            // Row 2: full-width progress bar
+            // Row 2: progress bar, with live GPU telemetry alongside on GPU runs
             let frac = epoch as f32 / total_epochs as f32;
-            ui.add(egui::ProgressBar::new(frac).show_percentage());
+            progress_with_telemetry(ui, frac, &state.compute_stats);
             ui.ctx().request_repaint_after(std::time::Duration::from_millis(300));
         }
         TrainingStatus::Complete { final_loss } => {
             ui.horizontal(|ui| {
                 ui.colored_label(
                     egui::Color32::from_rgb(50, 180, 50),
-                    format!("Training complete! Final loss: {:.6}", final_loss),
+                    tr_args("nn-training-complete", &[("loss", i18n::number(final_loss, 6))]),
                 );
-                if ui.button("Retrain").clicked() {
+                if ui.button(tr("nn-retrain")).clicked() {
                     state.training_status = TrainingStatus::Idle;
                     state.training_losses.clear();
+                    state.validation_losses.clear();
                     state.nn_predictions = crate::data::models::NnPredictions::default();
                     state.training_progress = None;
                 }
+                if ui
+                    .button(tr("nn-predict"))
+                    .on_hover_text(tr("nn-predict-hover"))
+                    .clicked()
+                {
+                    predict_from_checkpoint(state);
+                }
                 if state.loaded_model.is_some() {
-                    if ui.button("Run Inference").clicked() {
-                        if let Some(ref model) = state.loaded_model {
-                            let preds = crate::nn::training::run_inference(model, &state.market_data, &state.nn_feature_flags);
-                            if !preds.is_empty() {
-                                state.nn_predictions = preds.clone();
-                            }
-                        }
+                    if ui.button(tr("nn-run-backtest")).clicked() {
+                        run_backtest(state);
+                    }
+                    if ui
+                        .button(tr("nn-saliency"))
+                        .on_hover_text(tr("nn-saliency-hover"))
+                        .clicked()
+                    {
+                        run_saliency(state);
                     }
                 }
             });
@@ -216,9 +300,9 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             ui.horizontal(|ui| {
                 ui.colored_label(
                     egui::Color32::from_rgb(220, 50, 50),
-                    format!("Error: {}", msg),
+                    tr_args("nn-error", &[("error", msg.clone())]),
                 );
-                if ui.button("Retry").clicked() {
+                if ui.button(tr("nn-retry")).clicked() {
                     state.training_status = TrainingStatus::Idle;
                     state.training_progress = None;
                 }
@@ -243,42 +327,43 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
 
     // Loss curve
     if !state.training_losses.is_empty() {
-        ui.heading("Training Loss");
-        let loss_data: Vec<[f64; 2]> = state
-            .training_losses
-            .iter()
-            .enumerate()
-            .map(|(i, l)| [i as f64, *l])
-            .collect();
-        let loss_points: PlotPoints = loss_data.iter().copied().collect();
-        let loss_hover = [HoverSeries { name: "MSE Loss", data: &loss_data, decimals: 6, suffix: "" }];
-
-        height_control(ui, &mut state.chart_heights.nn_loss, "Loss Chart Height");
-        chart_utils::plot_with_y_drag(
-            ui,
-            "loss_plot",
-            chart_utils::default_plot_interaction(
-                Plot::new("loss_plot")
-                    .height(state.chart_heights.nn_loss),
-            )
-                .x_axis_label("Epoch")
-                .y_axis_label("MSE Loss")
-                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&loss_hover))
-                .label_formatter(chart_utils::no_hover_label),
-            |plot_ui| {
-                plot_ui.line(
-                    Line::new(loss_points)
-                        .name("Training Loss")
-                        .color(egui::Color32::from_rgb(255, 100, 100)),
-                );
-            },
-        );
+        render_loss_chart(ui, state);
     }
 
     ui.add_space(8.0);
 
+    // Hyperparameter sweep (only offered when no single training run is active)
+    if !is_training {
+        render_sweep(ui, state);
+        ui.add_space(8.0);
+    }
+
+    if !state.run_history.is_empty() {
+        render_run_history(ui, state);
+        ui.add_space(8.0);
+    }
+
+    // Backtest: predicted vs realized forward vol over history
+    if state.backtest.is_some() {
+        render_backtest(ui, state);
+        ui.add_space(8.0);
+    }
+
+    if state.saliency.is_some() {
+        render_saliency(ui, state);
+        ui.add_space(8.0);
+    }
+
+    // Regime classification models predict class probabilities instead of levels
+    if !state.nn_predictions.regime_probs.is_empty() {
+        let table_ui = egui::UiBuilder::new().sense(egui::Sense::click());
+        let table_response =
+            ui.scope_builder(table_ui, |ui| render_regime_predictions(ui, &state.nn_predictions.regime_probs)).response;
+        chart_utils::copy_table_menu(&table_response, &mut state.notices, || {
+            crate::data::export::predictions_table(&state.nn_predictions)
+        });
     // Predictions: only show columns for enabled feature flags (Vol | Randomness | Kurtosis)
-    if !state.nn_predictions.is_empty() {
+    } else if !state.nn_predictions.is_empty() {
         let flags = state.nn_feature_flags.clone();
         let show_vol = flags.sector_volatility;
         let show_rand = flags.market_randomness;
@@ -288,11 +373,11 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             .filter(|&&v| v)
             .count();
 
-        ui.heading("5-Day Forward Predictions");
+        ui.heading(tr("nn-predictions-heading"));
         ui.add_space(4.0);
 
         if col_count == 0 {
-            ui.label("All feature groups are disabled. Enable at least one feature in Settings to see predictions.");
+            ui.label(tr("nn-all-disabled"));
         } else {
             // Build ordered list of enabled column renderers so the column indices stay contiguous.
             // We use a nested columns call whose count matches the number of active flags.
@@ -300,92 +385,1379 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
             let rand_data: Vec<_> = state.nn_predictions.randomness.clone();
             let kurt_data: Vec<_> = state.nn_predictions.kurtosis.clone();
 
-            ui.columns(col_count, |cols| {
-                let mut col_idx = 0;
-
-                if show_vol {
-                    cols[col_idx].group(|ui| {
-                        ui.strong("Volatility");
-                        ui.add_space(4.0);
-                        egui::Grid::new("pred_vol_grid")
-                            .striped(true)
-                            .min_col_width(80.0)
-                            .show(ui, |ui| {
-                                ui.strong("Sector");
-                                ui.strong("Vol (%)");
-                                ui.end_row();
-                                for (sector, vol) in &vol_data {
-                                    ui.label(sector);
-                                    let vol_pct = vol * 100.0;
-                                    let color = if vol_pct > 30.0 {
-                                        egui::Color32::from_rgb(220, 50, 50)
-                                    } else if vol_pct > 20.0 {
-                                        egui::Color32::from_rgb(220, 150, 50)
-                                    } else {
-                                        egui::Color32::from_rgb(50, 180, 50)
-                                    };
-                                    ui.colored_label(color, format!("{:.2}%", vol_pct));
-                                    ui.end_row();
-                                }
+            let table_ui = egui::UiBuilder::new().sense(egui::Sense::click());
+            let table_response = ui
+                .scope_builder(table_ui, |ui| {
+                    ui.columns(col_count, |cols| {
+                        let mut col_idx = 0;
+
+                        if show_vol {
+                            cols[col_idx].group(|ui| {
+                                ui.strong(tr("nn-volatility"));
+                                ui.add_space(4.0);
+                                egui::Grid::new("pred_vol_grid")
+                                    .striped(true)
+                                    .min_col_width(80.0)
+                                    .show(ui, |ui| {
+                                        ui.strong(tr("col-sector"));
+                                        ui.strong(tr("nn-col-vol"));
+                                        ui.strong(tr("nn-col-recent-runs"));
+                                        ui.end_row();
+                                        for (sector, vol) in &vol_data {
+                                            table_nav::label(ui, sector, sector, &tr("col-sector"));
+                                            let vol_pct = vol * 100.0;
+                                            let color = if vol_pct > 30.0 {
+                                                egui::Color32::from_rgb(220, 50, 50)
+                                            } else if vol_pct > 20.0 {
+                                                egui::Color32::from_rgb(220, 150, 50)
+                                            } else {
+                                                egui::Color32::from_rgb(50, 180, 50)
+                                            };
+                                            table_nav::label(ui, egui::RichText::new(i18n::percent(*vol, 2)).color(color), sector, &tr("nn-predicted-vol"));
+                                            prediction_sparkline(ui, &prediction_history::sector_series(&state.prediction_history, sector));
+                                            ui.end_row();
+                                        }
+                                    });
                             });
-                    });
-                    col_idx += 1;
-                }
+                            col_idx += 1;
+                        }
 
-                if show_rand {
-                    cols[col_idx].group(|ui| {
-                        ui.strong("Randomness");
-                        ui.add_space(4.0);
-                        egui::Grid::new("pred_randomness_grid")
-                            .striped(true)
-                            .min_col_width(80.0)
-                            .show(ui, |ui| {
-                                ui.strong("Sector");
-                                ui.strong("Entropy");
-                                ui.end_row();
-                                for (sector, entropy) in &rand_data {
-                                    ui.label(sector);
-                                    ui.label(format!("{:.3}", entropy));
-                                    ui.end_row();
-                                }
+                        if show_rand {
+                            cols[col_idx].group(|ui| {
+                                ui.strong(tr("nn-randomness"));
+                                ui.add_space(4.0);
+                                egui::Grid::new("pred_randomness_grid")
+                                    .striped(true)
+                                    .min_col_width(80.0)
+                                    .show(ui, |ui| {
+                                        ui.strong(tr("col-sector"));
+                                        ui.strong(tr("col-entropy"));
+                                        ui.end_row();
+                                        for (sector, entropy) in &rand_data {
+                                            table_nav::label(ui, sector, sector, &tr("col-sector"));
+                                            table_nav::label(ui, i18n::number(*entropy, 3), sector, &tr("nn-predicted-entropy"));
+                                            ui.end_row();
+                                        }
+                                    });
                             });
-                    });
-                    col_idx += 1;
-                }
+                            col_idx += 1;
+                        }
 
-                if show_kurt {
-                    cols[col_idx].group(|ui| {
-                        ui.strong("Kurtosis");
-                        ui.add_space(4.0);
-                        egui::Grid::new("pred_kurtosis_grid")
-                            .striped(true)
-                            .min_col_width(70.0)
-                            .show(ui, |ui| {
-                                ui.strong("Sector");
-                                ui.strong("Kurt");
-                                ui.strong("Skew");
-                                ui.end_row();
-                                for (sector, k, s) in &kurt_data {
-                                    ui.label(sector);
-                                    ui.label(format!("{:.2}", k));
-                                    ui.label(format!("{:.2}", s));
-                                    ui.end_row();
-                                }
+                        if show_kurt {
+                            cols[col_idx].group(|ui| {
+                                ui.strong(tr("nn-kurtosis"));
+                                ui.add_space(4.0);
+                                egui::Grid::new("pred_kurtosis_grid")
+                                    .striped(true)
+                                    .min_col_width(70.0)
+                                    .show(ui, |ui| {
+                                        ui.strong(tr("col-sector"));
+                                        ui.strong(tr("nn-col-kurt"));
+                                        ui.strong(tr("nn-col-skew"));
+                                        ui.end_row();
+                                        for (sector, k, s) in &kurt_data {
+                                            table_nav::label(ui, sector, sector, &tr("col-sector"));
+                                            table_nav::label(ui, i18n::number(*k, 2), sector, &tr("nn-predicted-kurtosis"));
+                                            table_nav::label(ui, i18n::number(*s, 2), sector, &tr("nn-predicted-skew"));
+                                            ui.end_row();
+                                        }
+                                    });
                             });
+                            let _ = col_idx + 1; // suppress unused warning
+                        }
                     });
-                    let _ = col_idx + 1; // suppress unused warning
-                }
+                })
+                .response;
+            chart_utils::copy_table_menu(&table_response, &mut state.notices, || {
+                crate::data::export::predictions_table(&state.nn_predictions)
             });
         }
     } else if matches!(state.training_status, TrainingStatus::Idle) {
         ui.add_space(8.0);
-        ui.label("No predictions yet. Train the model to generate predictions.");
+        ui.label(tr("nn-no-predictions"));
     }
 
     ui.add_space(16.0);
     ui.separator();
     ui.add_space(4.0);
-    ui.small("Neural network powered by the Burn deep learning framework.");
+    ui.small(tr("nn-footer"));
+}
+
+fn render_regime_predictions(ui: &mut egui::Ui, regime_probs: &[(String, [f64; 3])]) {
+    ui.heading(tr("nn-regime-heading"));
+    ui.add_space(4.0);
+    ui.group(|ui| {
+        egui::Grid::new("pred_regime_grid")
+            .striped(true)
+            .min_col_width(80.0)
+            .show(ui, |ui| {
+                ui.strong(tr("col-sector"));
+                ui.strong(tr("nn-regime-low"));
+                ui.strong(tr("nn-regime-normal"));
+                ui.strong(tr("nn-regime-high"));
+                ui.strong(tr("nn-most-likely"));
+                ui.end_row();
+                for (sector, probs) in regime_probs {
+                    table_nav::label(ui, sector, sector, &tr("col-sector"));
+                    for (p, regime) in probs.iter().zip(["nn-low-regime", "nn-normal-regime", "nn-high-regime"]) {
+                        table_nav::label(ui, i18n::percent(*p, 1), sector, &tr(regime));
+                    }
+                    let best = (0..3).max_by(|&a, &b| probs[a].total_cmp(&probs[b])).unwrap_or(1);
+                    let (label, color) = match best {
+                        0 => ("nn-regime-low", egui::Color32::from_rgb(50, 180, 50)),
+                        1 => ("nn-regime-normal", egui::Color32::from_rgb(220, 180, 50)),
+                        _ => ("nn-regime-high", egui::Color32::from_rgb(220, 50, 50)),
+                    };
+                    table_nav::label(ui, egui::RichText::new(tr(label)).color(color), sector, &tr("nn-most-likely-regime"));
+                    ui.end_row();
+                }
+            });
+    });
+    ui.small(tr("nn-regime-note"));
+}
+
+fn render_sweep(ui: &mut egui::Ui, state: &mut AppState) {
+    let running = state
+        .sweep_progress
+        .as_ref()
+        .is_some_and(|p| !p.is_finished());
+
+    let open = chart_utils::section_open(&state.open_sections, "nn_sweep", running);
+    let response = egui::CollapsingHeader::new(tr("nn-sweep-heading"))
+        .id_salt("nn_sweep")
+        .open(Some(open))
+        .show(ui, |ui| {
+            if !running {
+                egui::Grid::new("sweep_space_grid")
+                    .num_columns(2)
+                    .spacing(egui::vec2(12.0, 6.0))
+                    .show(ui, |ui| {
+                        ui.label(tr("nn-sweep-hidden"));
+                        list_edit(ui, "sweep_hidden", &mut state.sweep_space.hidden_sizes);
+                        ui.end_row();
+
+                        ui.label(tr("nn-sweep-lookbacks"));
+                        list_edit(ui, "sweep_lookback", &mut state.sweep_space.lookbacks);
+                        ui.end_row();
+
+                        ui.label(tr("nn-sweep-lrs"));
+                        list_edit(ui, "sweep_lr", &mut state.sweep_space.learning_rates);
+                        ui.end_row();
+
+                        ui.label(tr("nn-sweep-epochs"));
+                        ui.add(egui::DragValue::new(&mut state.sweep_space.epochs).range(1..=5000));
+                        ui.end_row();
+
+                        ui.label(tr("nn-sweep-mode"));
+                        ui.horizontal(|ui| {
+                            let is_random = matches!(state.sweep_mode, SweepMode::Random { .. });
+                            if ui.selectable_label(!is_random, tr("nn-sweep-grid")).clicked() {
+                                state.sweep_mode = SweepMode::Grid;
+                            }
+                            if ui.selectable_label(is_random, tr("nn-sweep-random")).clicked() && !is_random {
+                                state.sweep_mode = SweepMode::Random { trials: 10 };
+                            }
+                            if let SweepMode::Random { ref mut trials } = state.sweep_mode {
+                                ui.add(egui::DragValue::new(trials).range(1..=500).suffix(format!(" {}", tr("nn-sweep-trials"))));
+                            }
+                        });
+                        ui.end_row();
+                    });
+
+                let seed = chrono::Local::now().timestamp_nanos_opt().unwrap_or(1) as u64;
+                let configs = sweep::build_configs(&state.sweep_space, state.sweep_mode, &state.nn_training_config, seed);
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    let btn = ui.add_enabled(
+                        !configs.is_empty(),
+                        egui::Button::new(tr_args("nn-sweep-start", &[("count", configs.len().to_string())])),
+                    );
+                    if btn.clicked() {
+                        start_sweep(state, configs);
+                    }
+                });
+            }
+
+            let Some(progress) = state.sweep_progress.clone() else { return };
+            let runs = progress.runs.lock().map(|r| r.clone()).unwrap_or_default();
+            let done = runs
+                .iter()
+                .filter(|r| matches!(r.status, SweepRunStatus::Done | SweepRunStatus::Failed(_)))
+                .count();
+
+            ui.add_space(4.0);
+            if running {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    let epoch_text = progress
+                        .current
+                        .lock()
+                        .ok()
+                        .and_then(|c| c.as_ref().and_then(|p| p.status.lock().ok().map(|s| s.clone())))
+                        .map(|s| match s {
+                            TrainingStatus::Training { epoch, total_epochs, .. } => {
+                                format!(
+                                    " | {}",
+                                    tr_args("nn-sweep-epoch", &[("epoch", epoch.to_string()), ("total", total_epochs.to_string())])
+                                )
+                            }
+                            _ => String::new(),
+                        })
+                        .unwrap_or_default();
+                    ui.label(format!(
+                        "{}{}",
+                        tr_args("nn-sweep-running-status", &[("done", done.to_string()), ("total", runs.len().to_string())]),
+                        epoch_text
+                    ));
+                    if ui.button(tr("nn-cancel")).clicked() {
+                        progress.request_cancel();
+                    }
+                });
+                ui.add(egui::ProgressBar::new(done as f32 / runs.len().max(1) as f32).show_percentage());
+                ui.ctx().request_repaint_after(std::time::Duration::from_millis(300));
+            } else {
+                ui.horizontal(|ui| {
+                    ui.label(tr_args("nn-sweep-finished", &[("done", done.to_string()), ("total", runs.len().to_string())]));
+                    if ui.small_button(tr("nn-clear")).clicked() {
+                        state.sweep_progress = None;
+                    }
+                });
+            }
+
+            ui.add_space(4.0);
+            ui.strong(tr("nn-leaderboard"));
+            egui::Grid::new("sweep_leaderboard")
+                .striped(true)
+                .min_col_width(70.0)
+                .show(ui, |ui| {
+                    ui.strong("#");
+                    ui.strong(tr("nn-col-hidden"));
+                    ui.strong(tr("nn-col-lookback"));
+                    ui.strong(tr("nn-col-lr"));
+                    let loss_name = runs.first().map_or_else(String::new, |r| loss_label(&r.config));
+                    ui.strong(tr_args("nn-col-val-loss-of", &[("loss", loss_name.clone())]));
+                    ui.strong(tr_args("nn-col-train-loss-of", &[("loss", loss_name)]));
+                    ui.strong(tr("nn-col-status"));
+                    ui.strong("");
+                    ui.end_row();
+
+                    for (rank, run) in sweep::leaderboard(&runs).iter().enumerate() {
+                        ui.label(format!("{}", rank + 1));
+                        ui.label(format!("{}", run.config.hidden_size));
+                        ui.label(format!("{}", run.config.lookback));
+                        ui.label(format!("{}", run.config.learning_rate));
+                        ui.label(fmt_loss(run.best_val_loss));
+                        ui.label(fmt_loss(run.final_train_loss));
+                        match run.status {
+                            SweepRunStatus::Pending => ui.weak(tr("nn-status-pending")),
+                            SweepRunStatus::Running => ui.colored_label(egui::Color32::from_rgb(50, 180, 50), tr("nn-status-running")),
+                            SweepRunStatus::Done => ui.label(tr("nn-status-done")),
+                            SweepRunStatus::Failed(ref msg) => {
+                                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), tr("nn-status-failed")).on_hover_text(msg)
+                            }
+                        };
+                        if run.status == SweepRunStatus::Done
+                            && ui
+                                .small_button(tr("nn-sweep-use"))
+                                .on_hover_text(tr("nn-sweep-use-hover"))
+                                .clicked()
+                        {
+                            state.nn_training_config = NnTrainingConfig {
+                                epochs: state.nn_training_config.epochs,
+                                ..run.config.clone()
+                            };
+                            state.notices.info(
+                                NoticeSource::Training,
+                                tr_args(
+                                    "nn-sweep-applied",
+                                    &[
+                                        ("hidden", run.config.hidden_size.to_string()),
+                                        ("lookback", run.config.lookback.to_string()),
+                                        ("lr", run.config.learning_rate.to_string()),
+                                    ],
+                                ),
+                            );
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    chart_utils::track_section(&mut state.open_sections, "nn_sweep", open, &response);
+}
+
+/// Comma-separated list editor for sweep values; keeps the previous list while the text is unparsable.
+fn list_edit<T: std::str::FromStr + std::fmt::Display>(ui: &mut egui::Ui, id: &str, values: &mut Vec<T>) {
+    let text_id = ui.id().with(id);
+    let mut text = ui.data(|d| d.get_temp::<String>(text_id)).unwrap_or_else(|| {
+        values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")
+    });
+    let resp = ui.add(egui::TextEdit::singleline(&mut text).desired_width(200.0));
+    if resp.changed() {
+        let parsed: Result<Vec<T>, _> = text
+            .split(',')
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .map(|p| p.parse::<T>())
+            .collect();
+        if let Ok(parsed) = parsed {
+            *values = parsed;
+        }
+    }
+    ui.data_mut(|d| d.insert_temp(text_id, text));
+}
+
+/// Name of the objective the configured run optimizes
+fn loss_label(config: &NnTrainingConfig) -> String {
+    match config.target_mode {
+        NnTargetMode::Regression => config.loss.label().to_string(),
+        NnTargetMode::RegimeClassification => tr("nn-cross-entropy"),
+    }
+}
+
+/// Name of the series a regression model forecasts
+pub fn vol_target_label(target: NnVolTarget) -> String {
+    match target {
+        NnVolTarget::SectorAverage => tr("nn-target-sector-average"),
+        NnVolTarget::Sector(i) => tr_args(
+            "nn-target-sector",
+            &[("symbol", crate::config::sector_etfs().get(i).map_or_else(|| tr("col-sector"), |s| s.symbol.clone()))],
+        ),
+        NnVolTarget::Benchmark => {
+            tr_args("nn-target-benchmark", &[("symbol", crate::config::benchmark_symbol().to_string())])
+        }
+        NnVolTarget::VolChange => tr("nn-target-vol-change"),
+        NnVolTarget::LogVol => tr("nn-target-log-vol"),
+    }
+}
+
+/// The model output a saliency map explains
+fn saliency_target(target_mode: NnTargetMode) -> String {
+    tr(match target_mode {
+        NnTargetMode::Regression => "nn-saliency-target-vol",
+        NnTargetMode::RegimeClassification => "nn-saliency-target-regime",
+    })
+}
+
+fn fmt_loss(loss: f64) -> String {
+    if loss.is_finite() {
+        i18n::number(loss, 6)
+    } else {
+        "-".to_string()
+    }
+}
+
+fn start_sweep(state: &mut AppState, configs: Vec<NnTrainingConfig>) {
+    let progress = SweepProgress::new(configs);
+    state.sweep_progress = Some(progress.clone());
+
+    let market_data = state.market_data.clone();
+    let compute_device = state.compute_device();
+    let feature_flags = state.nn_feature_flags.clone();
+
+    let n_trials = progress.runs.lock().map(|r| r.len()).unwrap_or(0);
+    let (cancel, watched) = (progress.clone(), progress.clone());
+    let hooks = TaskHooks {
+        cancel: Some(Box::new(move || cancel.request_cancel())),
+        progress: Some(Box::new(move || {
+            let done = watched.runs.lock().map(|r| r.iter().filter(|run| matches!(run.status, SweepRunStatus::Done | SweepRunStatus::Failed(_))).count()).unwrap_or(0);
+            TaskProgress {
+                fraction: (n_trials > 0).then(|| done as f32 / n_trials as f32),
+                detail: tr_args("nn-task-trials", &[("done", done.to_string()), ("total", n_trials.to_string())]),
+            }
+        })),
+    };
+    state.tasks.spawn_blocking(TaskKind::Sweep, tr("nn-task-sweep"), hooks, move || {
+        sweep::run_sweep(&market_data, &progress, &compute_device, &feature_flags);
+        Ok(())
+    });
+}
+
+fn render_backtest(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some(bt) = state.backtest.take() else { return };
+
+    let mut close = false;
+    ui.horizontal(|ui| {
+        ui.heading(tr("nn-backtest-heading"));
+        close = ui.small_button("✕").clicked();
+    });
+    ui.add_space(4.0);
+
+    egui::Grid::new("backtest_metrics_grid")
+        .striped(true)
+        .min_col_width(80.0)
+        .show(ui, |ui| {
+            ui.strong(tr("nn-col-period"));
+            ui.strong(tr("nn-col-samples"));
+            ui.strong("MSE");
+            ui.strong("MAE");
+            ui.strong("QLIKE");
+            ui.strong(tr("nn-col-hit-rate"));
+            ui.end_row();
+            for (label, m) in [("nn-all-history", &bt.overall), ("nn-out-of-sample", &bt.out_of_sample)] {
+                ui.label(tr(label));
+                ui.label(i18n::integer(m.samples));
+                ui.label(i18n::number(m.mse, 6));
+                ui.label(i18n::percent(m.mae, 2));
+                ui.label(i18n::number(m.qlike, 4));
+                let hit_color = if m.hit_rate >= 0.6 {
+                    egui::Color32::from_rgb(50, 180, 50)
+                } else if m.hit_rate >= 0.5 {
+                    egui::Color32::from_rgb(220, 180, 50)
+                } else {
+                    egui::Color32::from_rgb(220, 50, 50)
+                };
+                ui.colored_label(hit_color, i18n::percent(m.hit_rate, 1));
+                ui.end_row();
+            }
+        });
+    ui.small(tr("nn-backtest-note"));
+    ui.add_space(4.0);
+
+    let charts = state.plot_cache.get(
+        "backtest",
+        (FloatsKey(&bt.predicted), FloatsKey(&bt.actual), bt.oos_start),
+        || BacktestCharts::new(&bt),
+    );
+    render_backtest_timeline(ui, state, &charts, bt.oos_start);
+    ui.add_space(4.0);
+
+    height_control(ui, &mut state.chart_heights.nn_backtest_scatter, &tr("nn-backtest-scatter-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "backtest_scatter",
+        chart_utils::default_plot_interaction(
+            Plot::new("backtest_scatter")
+                .height(state.chart_heights.nn_backtest_scatter),
+        )
+            .x_axis_label(tr("nn-axis-realized-vol"))
+            .y_axis_label(tr("nn-axis-predicted-vol"))
+            .legend(egui_plot::Legend::default()),
+        |plot_ui| {
+            plot_ui.points(
+                Points::new(PlotPoints::Owned(charts.in_sample.clone()))
+                    .name(tr("nn-in-sample"))
+                    .radius(2.0)
+                    .color(egui::Color32::from_rgb(100, 150, 255)),
+            );
+            plot_ui.points(
+                Points::new(PlotPoints::Owned(charts.out_of_sample.clone()))
+                    .name(tr("nn-out-of-sample"))
+                    .radius(2.5)
+                    .color(egui::Color32::from_rgb(255, 150, 50)),
+            );
+            plot_ui.line(
+                Line::new(PlotPoints::from(vec![[0.0, 0.0], [charts.max_vol, charts.max_vol]]))
+                    .name(tr("nn-perfect-forecast"))
+                    .color(egui::Color32::from_rgb(150, 150, 150))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+        },
+    );
+
+    render_residual_diagnostics(ui, state, &bt);
+    if !close {
+        state.backtest = Some(bt);
+    }
+}
+
+/// Backtest chart series in percent, cached until another backtest replaces them
+struct BacktestCharts {
+    predicted: CachedSeries,
+    actual: CachedSeries,
+    /// (realized, predicted) per window before and from the out-of-sample start
+    in_sample: Vec<PlotPoint>,
+    out_of_sample: Vec<PlotPoint>,
+    /// Largest finite vol on either axis, where the perfect-forecast line ends
+    max_vol: f64,
+}
+
+impl BacktestCharts {
+    fn new(bt: &crate::nn::backtest::BacktestResult) -> Self {
+        let to_series = |values: &[f64]| -> CachedSeries {
+            values.iter().enumerate().map(|(i, v)| [i as f64, v * 100.0]).collect()
+        };
+        let scatter = |actual: &[f64], predicted: &[f64]| -> Vec<PlotPoint> {
+            actual.iter().zip(predicted).map(|(a, p)| PlotPoint::new(a * 100.0, p * 100.0)).collect()
+        };
+        Self {
+            predicted: to_series(&bt.predicted),
+            actual: to_series(&bt.actual),
+            in_sample: scatter(&bt.actual[..bt.oos_start], &bt.predicted),
+            out_of_sample: scatter(&bt.actual[bt.oos_start..], &bt.predicted[bt.oos_start..]),
+            max_vol: bt
+                .actual
+                .iter()
+                .chain(&bt.predicted)
+                .filter(|v| v.is_finite())
+                .fold(0.0_f64, |m, v| m.max(*v))
+                * 100.0,
+        }
+    }
+}
+
+/// Train and validation loss per epoch, optionally on a log10 Y axis, with the
+/// best epoch (lowest validation loss, or training loss if there is no validation
+/// split) marked.
+fn render_loss_chart(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.horizontal(|ui| {
+        ui.heading(tr("nn-loss-heading"));
+        ui.checkbox(&mut state.loss_log_scale, tr("nn-log-scale"))
+            .on_hover_text(tr("nn-log-scale-hover"));
+    });
+    let log_scale = state.loss_log_scale;
+    let curves = state.plot_cache.get(
+        "loss_curves",
+        (FloatsKey(&state.training_losses), FloatsKey(&state.validation_losses), log_scale),
+        || LossCurves::new(&state.training_losses, &state.validation_losses, log_scale),
+    );
+
+    let loss_name = tr_args("nn-loss-name", &[("loss", loss_label(&state.nn_training_config))]);
+    let (train_name, val_name) = (tr("nn-train-series"), tr("nn-validation-series"));
+    let mut loss_hover = vec![HoverSeries { name: &train_name, data: &curves.train, decimals: 6, suffix: "" }];
+    if !curves.val.is_empty() {
+        loss_hover.push(HoverSeries { name: &val_name, data: &curves.val, decimals: 6, suffix: "" });
+    }
+
+    height_control(ui, &mut state.chart_heights.nn_loss, &tr("nn-loss-height"));
+    let mut plot = chart_utils::default_plot_interaction(
+        Plot::new("loss_plot")
+            .height(state.chart_heights.nn_loss),
+    )
+        .x_axis_label(tr("nn-axis-epoch"))
+        .y_axis_label(if log_scale { tr_args("nn-loss-log", &[("loss", loss_name.clone())]) } else { loss_name.clone() })
+        .legend(egui_plot::Legend::default())
+        .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&loss_hover))
+        .label_formatter(chart_utils::no_hover_label);
+    if log_scale {
+        plot = plot.y_axis_formatter(|mark, _range| format!("{:.1e}", 10f64.powf(mark.value)));
+    }
+    chart_utils::plot_with_y_drag(ui, "loss_plot", plot, |plot_ui| {
+        plot_ui.line(
+            Line::new(curves.train_plot.plot_points())
+                .name(&train_name)
+                .color(egui::Color32::from_rgb(255, 100, 100)),
+        );
+        if !curves.val.is_empty() {
+            plot_ui.line(
+                Line::new(curves.val_plot.plot_points())
+                    .name(&val_name)
+                    .color(egui::Color32::from_rgb(100, 150, 255)),
+            );
+        }
+        if let Some((epoch, point)) = curves.best {
+            plot_ui.vline(
+                VLine::new(epoch)
+                    .name(tr_args("nn-best-epoch", &[("epoch", (epoch as usize).to_string())]))
+                    .color(egui::Color32::from_rgb(50, 180, 50))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+            if let Some(point) = point {
+                plot_ui.points(
+                    Points::new(vec![point])
+                        .radius(4.0)
+                        .color(egui::Color32::from_rgb(50, 180, 50)),
+                );
+            }
+        }
+    });
+}
+
+/// Loss curves for the chart, cached until another epoch lands or the scale changes
+struct LossCurves {
+    /// Finite losses per epoch; hover reads these raw values
+    train: Vec<[f64; 2]>,
+    val: Vec<[f64; 2]>,
+    /// The plotted points, in log10 space when the log scale is on
+    train_plot: CachedSeries,
+    val_plot: CachedSeries,
+    /// Best epoch, and its plotted point unless the log scale can't show it
+    best: Option<(f64, Option<[f64; 2]>)>,
+}
+
+impl LossCurves {
+    fn new(train_losses: &[f64], val_losses: &[f64], log_scale: bool) -> Self {
+        let curve = |losses: &[f64]| -> Vec<[f64; 2]> {
+            losses.iter().enumerate().filter(|(_, l)| l.is_finite()).map(|(i, l)| [i as f64, *l]).collect()
+        };
+        let to_plot = |[x, l]: [f64; 2]| -> Option<[f64; 2]> {
+            match log_scale {
+                true if l <= 0.0 => None,
+                true => Some([x, l.log10()]),
+                false => Some([x, l]),
+            }
+        };
+        let train = curve(train_losses);
+        let val = curve(val_losses);
+        let best_source = if val.is_empty() { &train } else { &val };
+        let best = best_source
+            .iter()
+            .copied()
+            .min_by(|a, b| a[1].total_cmp(&b[1]))
+            .map(|best| (best[0], to_plot(best)));
+        Self {
+            train_plot: train.iter().copied().filter_map(to_plot).collect(),
+            val_plot: val.iter().copied().filter_map(to_plot).collect(),
+            train,
+            val,
+            best,
+        }
+    }
+}
+
+/// Inline line of a sector's vol forecast across recent refreshes, with the range on hover
+fn prediction_sparkline(ui: &mut egui::Ui, values: &[f64]) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(80.0, 16.0), egui::Sense::hover());
+    if values.len() < 2 {
+        response.on_hover_text(tr("nn-sparkline-short"));
+        return;
+    }
+    let (lo, hi) = values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(*v), hi.max(*v)));
+    let span = (hi - lo).max(1e-9);
+    let points: Vec<egui::Pos2> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let x = rect.left() + rect.width() * i as f32 / (values.len() - 1) as f32;
+            let y = rect.bottom() - rect.height() * ((v - lo) / span) as f32;
+            egui::pos2(x, y)
+        })
+        .collect();
+    let color = ui.visuals().text_color();
+    ui.painter().add(egui::Shape::line(points, egui::Stroke::new(1.0, color)));
+    response.on_hover_text(tr_args(
+        "nn-sparkline-range",
+        &[("count", values.len().to_string()), ("low", i18n::percent(lo, 2)), ("high", i18n::percent(hi, 2))],
+    ));
+}
+
+/// Predicted and subsequently realized vol per backtest window, in chronological order,
+/// with the start of the out-of-sample period marked.
+fn render_backtest_timeline(ui: &mut egui::Ui, state: &mut AppState, charts: &BacktestCharts, oos_start: usize) {
+    let (predicted_name, realized_name) = (tr("nn-predicted"), tr("nn-realized"));
+    let hover = [
+        HoverSeries { name: &predicted_name, data: &charts.predicted.data, decimals: 1, suffix: "%" },
+        HoverSeries { name: &realized_name, data: &charts.actual.data, decimals: 1, suffix: "%" },
+    ];
+
+    height_control(ui, &mut state.chart_heights.nn_backtest_timeline, &tr("nn-backtest-timeline-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "backtest_timeline",
+        chart_utils::default_plot_interaction(
+            Plot::new("backtest_timeline")
+                .height(state.chart_heights.nn_backtest_timeline),
+        )
+            .x_axis_label(tr("nn-axis-window"))
+            .y_axis_label(tr("nn-axis-5d-vol"))
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.line(
+                Line::new(charts.actual.plot_points())
+                    .name(&realized_name)
+                    .color(egui::Color32::from_rgb(150, 150, 150)),
+            );
+            plot_ui.line(
+                Line::new(charts.predicted.plot_points())
+                    .name(&predicted_name)
+                    .color(egui::Color32::from_rgb(100, 150, 255)),
+            );
+            plot_ui.vline(
+                VLine::new(oos_start as f64)
+                    .name(tr("nn-oos-start"))
+                    .color(egui::Color32::from_rgb(255, 150, 50))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+        },
+    );
+}
+
+/// Out-of-sample residual statistics, residual autocorrelation, and error vs vol level.
+fn render_residual_diagnostics(ui: &mut egui::Ui, state: &mut AppState, bt: &crate::nn::backtest::BacktestResult) {
+    let d = &bt.residuals;
+    if d.samples == 0 {
+        return;
+    }
+
+    let open = chart_utils::section_open(&state.open_sections, "nn_residuals", true);
+    let response = egui::CollapsingHeader::new(tr("nn-residuals-heading"))
+        .id_salt("nn_residuals")
+        .open(Some(open))
+        .show(ui, |ui| {
+            let red = egui::Color32::from_rgb(220, 50, 50);
+            let yellow = egui::Color32::from_rgb(220, 180, 50);
+            let green = egui::Color32::from_rgb(50, 180, 50);
+
+            egui::Grid::new("residual_stats_grid").striped(true).min_col_width(80.0).show(ui, |ui| {
+                ui.label(tr("nn-bias"));
+                // Flag bias larger than a quarter of the residual spread
+                let bias_color = if d.bias.abs() > 0.25 * d.residual_std { yellow } else { green };
+                ui.colored_label(bias_color, format!("{}%", i18n::signed(d.bias * 100.0, 2)));
+                ui.end_row();
+
+                ui.label(tr("nn-residual-std"));
+                ui.label(i18n::percent(d.residual_std, 2));
+                ui.end_row();
+
+                let lag1 = d.autocorrelation.first().copied().unwrap_or(0.0);
+                ui.label(tr("nn-lag1-acf"));
+                let acf_color = if lag1.abs() > d.acf_band { yellow } else { green };
+                ui.colored_label(
+                    acf_color,
+                    tr_args("nn-acf-band", &[("value", i18n::number(lag1, 3)), ("band", i18n::number(d.acf_band, 3))]),
+                );
+                ui.end_row();
+
+                if let Some(&(level, bias)) = d.bias_by_vol_level.last() {
+                    ui.label(tr("nn-top-quintile-bias"));
+                    let spike_color = if bias < -d.residual_std { red } else if bias < 0.0 { yellow } else { green };
+                    ui.colored_label(
+                        spike_color,
+                        tr_args(
+                            "nn-bias-at-level",
+                            &[("bias", format!("{}%", i18n::signed(bias * 100.0, 2))), ("level", i18n::percent(level, 1))],
+                        ),
+                    );
+                    ui.end_row();
+                }
+            });
+            ui.small(tr("nn-residuals-note"));
+            ui.add_space(4.0);
+
+            height_control(ui, &mut state.chart_heights.nn_backtest_residuals, &tr("nn-residuals-height"));
+            ui.label(tr("nn-residual-acf"));
+            let acf_bars: Vec<Bar> = d
+                .autocorrelation
+                .iter()
+                .enumerate()
+                .map(|(i, &r)| Bar::new((i + 1) as f64, r).width(0.6))
+                .collect();
+            let band = d.acf_band;
+            let max_lag = d.autocorrelation.len() as f64 + 0.5;
+            chart_utils::plot_with_y_drag(
+                ui,
+                "residual_acf",
+                chart_utils::default_plot_interaction(
+                    Plot::new("residual_acf").height(state.chart_heights.nn_backtest_residuals),
+                )
+                    .x_axis_label(tr("nn-axis-lag"))
+                    .y_axis_label(tr("nn-axis-autocorrelation")),
+                |plot_ui| {
+                    plot_ui.bar_chart(
+                        BarChart::new(acf_bars)
+                            .name("ACF")
+                            .color(egui::Color32::from_rgb(70, 130, 220)),
+                    );
+                    for bound in [band, -band] {
+                        plot_ui.line(
+                            Line::new(PlotPoints::from(vec![[0.5, bound], [max_lag, bound]]))
+                                .color(egui::Color32::from_rgb(150, 150, 150))
+                                .style(egui_plot::LineStyle::dashed_dense()),
+                        );
+                    }
+                },
+            );
+            ui.add_space(4.0);
+
+            ui.label(tr("nn-error-vs-level"));
+            let residual_points = |range: std::ops::Range<usize>| -> Vec<[f64; 2]> {
+                bt.actual[range.clone()]
+                    .iter()
+                    .zip(&bt.predicted[range])
+                    .filter(|(a, p)| a.is_finite() && p.is_finite())
+                    .map(|(a, p)| [a * 100.0, (p - a) * 100.0])
+                    .collect()
+            };
+            let in_sample = residual_points(0..bt.oos_start);
+            let oos = residual_points(bt.oos_start..bt.actual.len().min(bt.predicted.len()));
+            let bucket_line: Vec<[f64; 2]> = d.bias_by_vol_level.iter().map(|(a, r)| [a * 100.0, r * 100.0]).collect();
+
+            chart_utils::plot_with_y_drag(
+                ui,
+                "residual_vs_level",
+                chart_utils::default_plot_interaction(
+                    Plot::new("residual_vs_level").height(state.chart_heights.nn_backtest_residuals),
+                )
+                    .x_axis_label(tr("nn-axis-realized-vol"))
+                    .y_axis_label(tr("nn-axis-residual"))
+                    .legend(egui_plot::Legend::default()),
+                |plot_ui| {
+                    plot_ui.points(
+                        Points::new(PlotPoints::from(in_sample))
+                            .name(tr("nn-in-sample"))
+                            .radius(2.0)
+                            .color(egui::Color32::from_rgb(100, 150, 255)),
+                    );
+                    plot_ui.points(
+                        Points::new(PlotPoints::from(oos))
+                            .name(tr("nn-out-of-sample"))
+                            .radius(2.5)
+                            .color(egui::Color32::from_rgb(255, 150, 50)),
+                    );
+                    plot_ui.line(
+                        Line::new(PlotPoints::from(bucket_line))
+                            .name(tr("nn-oos-mean"))
+                            .color(red)
+                            .width(2.0),
+                    );
+                    plot_ui.hline(
+                        egui_plot::HLine::new(0.0)
+                            .color(egui::Color32::from_rgb(150, 150, 150))
+                            .style(egui_plot::LineStyle::dashed_dense()),
+                    );
+                },
+            );
+        });
+    chart_utils::track_section(&mut state.open_sections, "nn_residuals", open, &response);
+}
+
+/// Benchmark results for the selected adapter, with the expected epoch time for the
+/// current settings, so users can judge a long run before starting it.
+/// Live tracking line for the loaded model, with a retraining warning once the rolling
+/// error of its resolved predictions exceeds the threshold
+fn render_drift_status(ui: &mut egui::Ui, drift: &DriftStatus, threshold_pct: f64) {
+    if drift.logged == 0 {
+        return;
+    }
+    let pending = drift.logged - drift.resolved;
+    match drift.rolling_mape {
+        Some(mape) if drift.exceeded => {
+            ui.colored_label(
+                egui::Color32::from_rgb(220, 50, 50),
+                tr_args(
+                    "nn-drift-exceeded",
+                    &[
+                        ("error", i18n::percent(mape / 100.0, 1)),
+                        ("count", drift.window_used.to_string()),
+                        ("threshold", i18n::percent(threshold_pct / 100.0, 0)),
+                    ],
+                ),
+            );
+        }
+        Some(mape) => {
+            ui.label(tr_args(
+                "nn-drift-tracking",
+                &[
+                    ("error", i18n::percent(mape / 100.0, 1)),
+                    ("count", drift.window_used.to_string()),
+                    ("pending", pending.to_string()),
+                    ("threshold", i18n::percent(threshold_pct / 100.0, 0)),
+                ],
+            ));
+        }
+        None => {
+            ui.label(tr_args(
+                "nn-drift-pending",
+                &[("count", pending.to_string()), ("days", crate::config::NN_FORWARD_DAYS.to_string())],
+            ));
+        }
+    }
+    ui.add_space(4.0);
+}
+
+fn render_gpu_diagnostics(ui: &mut egui::Ui, state: &mut AppState) {
+    let open = chart_utils::section_open(&state.open_sections, "nn_gpu_diagnostics", false);
+    let response = egui::CollapsingHeader::new(tr("nn-gpu-diagnostics"))
+        .id_salt("nn_gpu_diagnostics")
+        .open(Some(open)).show(ui, |ui| {
+        let result = state
+            .gpu_diagnostics
+            .as_ref()
+            .map(|slot| slot.lock().ok().and_then(|r| r.clone()));
+        let running = matches!(result, Some(None));
+
+        ui.horizontal(|ui| {
+            if ui.add_enabled(!running, egui::Button::new(tr("nn-run-diagnostics"))).clicked() {
+                start_gpu_diagnostics(state);
+            }
+            if running {
+                ui.spinner();
+                ui.label(tr("nn-benchmarking"));
+                ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+            } else {
+                ui.small(tr("nn-diagnostics-note"));
+            }
+        });
+
+        match result.flatten() {
+            Some(Ok(d)) => {
+                egui::Grid::new("gpu_diag_grid").striped(true).min_col_width(80.0).show(ui, |ui| {
+                    ui.label(tr("nn-diag-adapter"));
+                    ui.label(&d.adapter);
+                    ui.end_row();
+                    ui.label(tr("nn-diag-matmul"));
+                    ui.label(tr_args(
+                        "nn-diag-tflops",
+                        &[("peak", i18n::number(d.peak_tflops, 2)), ("sustained", i18n::number(d.sustained_tflops, 2))],
+                    ));
+                    ui.end_row();
+                    ui.label(tr("nn-diag-bandwidth"));
+                    ui.label(tr_args("nn-diag-gbs", &[("value", i18n::number(d.bandwidth_gbs, 0))]));
+                    ui.end_row();
+                    ui.label(tr("nn-diag-step"));
+                    ui.label(tr_args(
+                        "nn-diag-step-time",
+                        &[("ms", i18n::number(d.train_step_ms, 1)), ("batch", d.batch_size.to_string())],
+                    ));
+                    ui.end_row();
+                    if let Some(epoch_secs) = d.est_epoch_secs {
+                        ui.label(tr("nn-diag-epoch"));
+                        let total_mins = epoch_secs * state.nn_training_config.epochs as f64 / 60.0;
+                        ui.label(tr_args(
+                            "nn-diag-epoch-time",
+                            &[
+                                ("seconds", i18n::number(epoch_secs, 2)),
+                                ("epochs", state.nn_training_config.epochs.to_string()),
+                                ("minutes", i18n::number(total_mins, 1)),
+                            ],
+                        ));
+                        ui.end_row();
+                    }
+                });
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), tr_args("nn-diagnostics-failed", &[("error", e)]));
+            }
+            None => {}
+        }
+    });
+    chart_utils::track_section(&mut state.open_sections, "nn_gpu_diagnostics", open, &response);
+}
+
+fn start_gpu_diagnostics(state: &mut AppState) {
+    let slot = Arc::new(Mutex::new(None));
+    state.gpu_diagnostics = Some(slot.clone());
+
+    let adapter = state.gpu_adapter.clone();
+    let market_data = state.market_data.clone();
+    let flags = state.nn_feature_flags.clone();
+    let config = state.nn_training_config.clone();
+    state.tasks.spawn_blocking(TaskKind::GpuDiagnostics, tr("nn-task-gpu-diagnostics"), TaskHooks::default(), move || {
+        let stats = crate::nn::gpu::poll_gpu_stats(adapter.as_deref());
+        let batch_size = crate::nn::training::resolve_batch_size(&config, &flags, stats.as_ref());
+        let windows = crate::nn::dataset::build_dataset(&market_data, config.lookback, crate::config::NN_FORWARD_DAYS, &flags)
+            .samples
+            .len();
+        let train_samples = (windows > 0).then_some((windows as f64 * 0.8) as usize);
+        // A driver fault during the benchmark must not leave the panel spinning forever
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::nn::gpu::run_gpu_diagnostics(
+                adapter.as_deref(),
+                crate::nn::model::num_features(&flags),
+                &config,
+                batch_size.min(train_samples.unwrap_or(batch_size)).max(1),
+                train_samples,
+            )
+        }))
+        .unwrap_or_else(|_| Err("GPU benchmark crashed".to_string()));
+        let error = result.as_ref().err().cloned();
+        if let Ok(mut guard) = slot.lock() {
+            *guard = Some(result);
+        }
+        error.map_or(Ok(()), Err)
+    });
+}
+
+/// Number of most influential features shown as heatmap rows
+const SALIENCY_TOP_FEATURES: usize = 20;
+
+/// Heatmap of |gradient × input| over the lookback window (columns, oldest → latest)
+/// for the most influential features (rows).
+fn render_saliency(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some(sal) = state.saliency.clone() else { return };
+
+    ui.horizontal(|ui| {
+        ui.heading(tr("nn-saliency-heading"));
+        if ui.small_button("✕").clicked() {
+            state.saliency = None;
+        }
+    });
+    ui.small(tr_args("nn-saliency-note", &[("target", saliency_target(sal.target))]));
+    ui.add_space(4.0);
+
+    let days = sal.by_day.len();
+    if days == 0 {
+        return;
+    }
+    let rows: Vec<usize> = sal.ranked_features().into_iter().take(SALIENCY_TOP_FEATURES).collect();
+    let max_val = rows
+        .iter()
+        .flat_map(|&f| sal.values.iter().map(move |day| day[f]))
+        .fold(0.0_f64, f64::max);
+
+    let label_width = 110.0;
+    let cell_h = 14.0;
+    let cell_w = ((ui.available_width() - label_width) / days as f32).clamp(4.0, 24.0);
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(label_width + cell_w * days as f32, cell_h * rows.len() as f32 + 16.0),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter_at(rect);
+    let text_color = ui.visuals().text_color();
+
+    for (r, &f) in rows.iter().enumerate() {
+        let y = rect.top() + r as f32 * cell_h;
+        painter.text(
+            egui::pos2(rect.left() + label_width - 6.0, y + cell_h / 2.0),
+            egui::Align2::RIGHT_CENTER,
+            &sal.feature_names[f],
+            egui::FontId::proportional(10.0),
+            text_color,
+        );
+        for (t, day) in sal.values.iter().enumerate() {
+            let cell = egui::Rect::from_min_size(
+                egui::pos2(rect.left() + label_width + t as f32 * cell_w, y),
+                egui::vec2(cell_w - 1.0, cell_h - 1.0),
+            );
+            painter.rect_filled(cell, 0.0, saliency_color(if max_val > 0.0 { day[f] / max_val } else { 0.0 }));
+        }
+    }
+
+    // Day axis: oldest on the left, the latest day (t-0) on the right
+    let axis_y = rect.top() + cell_h * rows.len() as f32 + 8.0;
+    for (t, label) in [(0, format!("t-{}", days - 1)), (days - 1, "t-0".to_string())] {
+        painter.text(
+            egui::pos2(rect.left() + label_width + (t as f32 + 0.5) * cell_w, axis_y),
+            egui::Align2::CENTER_CENTER,
+            label,
+            egui::FontId::proportional(10.0),
+            text_color,
+        );
+    }
+
+    if let Some(pos) = response.hover_pos() {
+        let t = ((pos.x - rect.left() - label_width) / cell_w).floor();
+        let r = ((pos.y - rect.top()) / cell_h).floor();
+        if t >= 0.0 && (t as usize) < days && r >= 0.0 && (r as usize) < rows.len() {
+            let (t, f) = (t as usize, rows[r as usize]);
+            response.on_hover_text(tr_args(
+                "nn-saliency-cell",
+                &[
+                    ("feature", sal.feature_names[f].clone()),
+                    ("day", (days - 1 - t).to_string()),
+                    ("value", i18n::number(sal.values[t][f], 4)),
+                ],
+            ));
+        }
+    }
+
+    ui.add_space(4.0);
+    let top_day = (0..days).max_by(|&a, &b| sal.by_day[a].total_cmp(&sal.by_day[b])).unwrap_or(0);
+    ui.label(tr_args(
+        "nn-saliency-summary",
+        &[
+            ("day", (days - 1 - top_day).to_string()),
+            ("features", rows.iter().take(5).map(|&f| sal.feature_names[f].as_str()).collect::<Vec<_>>().join(", ")),
+        ],
+    ));
+}
+
+/// White → red ramp for a normalized saliency value in [0, 1]
+fn saliency_color(t: f64) -> egui::Color32 {
+    let t = t.clamp(0.0, 1.0) as f32;
+    egui::Color32::from_rgb(
+        (240.0 * (1.0 - t) + 220.0 * t) as u8,
+        (240.0 * (1.0 - t) + 50.0 * t) as u8,
+        (240.0 * (1.0 - t) + 50.0 * t) as u8,
+    )
+}
+
+fn render_run_history(ui: &mut egui::Ui, state: &mut AppState) {
+    let open = chart_utils::section_open(&state.open_sections, "nn_run_history", false);
+    let response = egui::CollapsingHeader::new(tr_args("nn-history-heading", &[("count", state.run_history.len().to_string())]))
+        .id_salt("nn_run_history")
+        .open(Some(open))
+        .show(ui, |ui| {
+            ui.small(tr("nn-history-note"));
+            egui::ScrollArea::vertical()
+                .id_salt("run_history_scroll")
+                .max_height(240.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("run_history_grid")
+                        .striped(true)
+                        .min_col_width(50.0)
+                        .show(ui, |ui| {
+                            ui.strong("A");
+                            ui.strong("B");
+                            ui.strong(tr("nn-col-started"));
+                            ui.strong(tr("nn-col-objective"));
+                            ui.strong(tr("nn-col-hidden"));
+                            ui.strong(tr("nn-col-lookback"));
+                            ui.strong(tr("nn-col-lr"));
+                            ui.strong(tr("nn-col-epochs"));
+                            ui.strong(tr("nn-col-train-loss"));
+                            ui.strong(tr("nn-col-val-loss"));
+                            ui.strong(tr("nn-col-outcome"));
+                            ui.end_row();
+
+                            // Newest first
+                            for idx in (0..state.run_history.len()).rev() {
+                                for slot in 0..2 {
+                                    let mut selected = state.history_compare[slot] == Some(idx);
+                                    if ui.checkbox(&mut selected, "").changed() {
+                                        state.history_compare[slot] = selected.then_some(idx);
+                                    }
+                                }
+                                let run = &state.run_history[idx];
+                                ui.label(&run.started_at);
+                                ui.label(loss_label(&run.config));
+                                ui.label(format!("{}", run.config.hidden_size));
+                                ui.label(format!("{}", run.config.lookback));
+                                ui.label(format!("{}", run.config.learning_rate));
+                                ui.label(format!("{}/{}", run.epochs_completed(), run.config.epochs));
+                                ui.label(fmt_loss(run.final_train_loss()));
+                                ui.label(fmt_loss(run.final_val_loss()));
+                                match run.outcome {
+                                    RunOutcome::Complete => ui.label(tr("nn-status-complete")),
+                                    RunOutcome::Stopped => ui.weak(tr("nn-status-stopped")),
+                                    RunOutcome::Failed(ref msg) => {
+                                        ui.colored_label(egui::Color32::from_rgb(220, 50, 50), tr("nn-status-failed"))
+                                            .on_hover_text(msg)
+                                    }
+                                };
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            if ui.button(tr("nn-clear-history")).clicked() {
+                if let Err(e) = history::clear_history(&mut state.run_history) {
+                    state.notices.error(NoticeSource::Training, e);
+                }
+                state.history_compare = [None, None];
+            }
+
+            let [Some(a), Some(b)] = state.history_compare else { return };
+            let (Some(run_a), Some(run_b)) = (state.run_history.get(a).cloned(), state.run_history.get(b).cloned()) else {
+                return;
+            };
+            ui.add_space(8.0);
+            render_run_comparison(ui, state, &run_a, &run_b);
+        });
+    chart_utils::track_section(&mut state.open_sections, "nn_run_history", open, &response);
+}
+
+fn render_run_comparison(ui: &mut egui::Ui, state: &mut AppState, run_a: &TrainingRun, run_b: &TrainingRun) {
+    ui.strong(tr_args("nn-compare-heading", &[("a", run_a.started_at.clone()), ("b", run_b.started_at.clone())]));
+
+    // Only list settings that differ between the two runs
+    let change = |id: &str, from: String, to: String| tr_args(id, &[("from", from), ("to", to)]);
+    let mut diffs = Vec::new();
+    let (ca, cb) = (&run_a.config, &run_b.config);
+    if ca.hidden_size != cb.hidden_size {
+        diffs.push(change("nn-diff-hidden", ca.hidden_size.to_string(), cb.hidden_size.to_string()));
+    }
+    if ca.lookback != cb.lookback {
+        diffs.push(change("nn-diff-lookback", ca.lookback.to_string(), cb.lookback.to_string()));
+    }
+    if ca.learning_rate != cb.learning_rate {
+        diffs.push(change("nn-diff-lr", ca.learning_rate.to_string(), cb.learning_rate.to_string()));
+    }
+    if loss_label(ca) != loss_label(cb) {
+        diffs.push(change("nn-diff-objective", loss_label(ca), loss_label(cb)));
+    }
+    if ca.vol_target != cb.vol_target {
+        diffs.push(change("nn-diff-target", vol_target_label(ca.vol_target), vol_target_label(cb.vol_target)));
+    }
+    if ca.augmentation != cb.augmentation {
+        diffs.push(tr("nn-diff-augmentation"));
+    }
+    if ca.grad_clip_norm != cb.grad_clip_norm {
+        diffs.push(change("nn-diff-grad-clip", format!("{:?}", ca.grad_clip_norm), format!("{:?}", cb.grad_clip_norm)));
+    }
+    let (fa, fb) = (&run_a.feature_flags, &run_b.feature_flags);
+    if (fa.sector_volatility, fa.market_randomness, fa.kurtosis) != (fb.sector_volatility, fb.market_randomness, fb.kurtosis)
+        || fa.custom_indicators != fb.custom_indicators
+    {
+        diffs.push(tr("nn-diff-features"));
+    }
+    if diffs.is_empty() {
+        ui.label(tr("nn-same-config"));
+    } else {
+        ui.label(tr_args("nn-changes", &[("changes", diffs.join(", "))]));
+    }
+
+    let a_train = TrainingRun::curve(&run_a.train_losses);
+    let a_val = TrainingRun::curve(&run_a.val_losses);
+    let b_train = TrainingRun::curve(&run_b.train_losses);
+    let b_val = TrainingRun::curve(&run_b.val_losses);
+    let series = |run: &str, id: &str| tr_args(id, &[("run", run.to_string())]);
+    let (a_train_name, a_val_name) = (series("A", "nn-run-train"), series("A", "nn-run-val"));
+    let (b_train_name, b_val_name) = (series("B", "nn-run-train"), series("B", "nn-run-val"));
+    let hover = [
+        HoverSeries { name: &a_train_name, data: &a_train, decimals: 6, suffix: "" },
+        HoverSeries { name: &a_val_name, data: &a_val, decimals: 6, suffix: "" },
+        HoverSeries { name: &b_train_name, data: &b_train, decimals: 6, suffix: "" },
+        HoverSeries { name: &b_val_name, data: &b_val, decimals: 6, suffix: "" },
+    ];
+    let color_a = egui::Color32::from_rgb(100, 150, 255);
+    let color_b = egui::Color32::from_rgb(255, 150, 50);
+
+    height_control(ui, &mut state.chart_heights.nn_run_compare, &tr("nn-compare-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "run_compare_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("run_compare_plot")
+                .height(state.chart_heights.nn_run_compare),
+        )
+            .x_axis_label(tr("nn-axis-epoch"))
+            .y_axis_label(tr("nn-axis-loss"))
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.line(Line::new(PlotPoints::from(a_train.clone())).name(&a_train_name).color(color_a));
+            plot_ui.line(
+                Line::new(PlotPoints::from(a_val.clone()))
+                    .name(&a_val_name)
+                    .color(color_a)
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+            plot_ui.line(Line::new(PlotPoints::from(b_train.clone())).name(&b_train_name).color(color_b));
+            plot_ui.line(
+                Line::new(PlotPoints::from(b_val.clone()))
+                    .name(&b_val_name)
+                    .color(color_b)
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+        },
+    );
+}
+
+/// Move the active run record into the history with the given outcome and persist it.
+fn finish_active_run(state: &mut AppState, outcome: RunOutcome) {
+    let Some(run) = state.active_run.take() else { return };
+    let (losses, val_losses) = state
+        .training_progress
+        .as_ref()
+        .map(|p| {
+            (
+                p.losses.lock().map(|l| l.clone()).unwrap_or_default(),
+                p.val_losses.lock().map(|l| l.clone()).unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default();
+    let run = run.finish(outcome, &losses, &val_losses, &state.nn_predictions);
+    if let Err(e) = history::append_run(&mut state.run_history, run) {
+        tracing::warn!("{}", e);
+    }
+    // Indices shift when the oldest run is trimmed
+    state.history_compare = [None, None];
+}
+
+/// Load the last saved checkpoint and produce fresh predictions without retraining.
+fn predict_from_checkpoint(state: &mut AppState) {
+    let Some((model, meta)) = crate::nn::persistence::load_model() else {
+        state.notices.warn(NoticeSource::Training, tr("nn-no-saved-model"));
+        return;
+    };
+
+    let flags_check = meta.check_feature_flags(&state.nn_feature_flags);
+    let (trained_at, final_loss) = (meta.trained_at.clone(), meta.final_loss);
+    state.loaded_model = Some(model);
+    state.model_metadata = Some(meta);
+
+    match flags_check.map(|()| state.latest_predictions().unwrap_or_default()) {
+        Ok(preds) if !preds.is_empty() => {
+            state.notices.info(
+                NoticeSource::Training,
+                tr_args(
+                    "nn-predictions-generated",
+                    &[("trained", trained_at), ("precision", state.inference_precision.label().to_string())],
+                ),
+            );
+            state.nn_predictions = preds;
+            state.training_status = TrainingStatus::Complete { final_loss };
+            state.track_live_predictions(true);
+        }
+        Ok(_) => {
+            state.notices.error(NoticeSource::Training, tr("nn-prediction-short"));
+        }
+        Err(msg) => state.notices.error(NoticeSource::Training, msg),
+    }
+}
+
+fn run_backtest(state: &mut AppState) {
+    let Some(ref model) = state.loaded_model else { return };
+    let target_mode = state.model_metadata.as_ref().map(|m| m.training_config.target_mode);
+    if target_mode == Some(NnTargetMode::RegimeClassification) {
+        state.notices.warn(NoticeSource::Analysis, tr("nn-backtest-regression-only"));
+        return;
+    }
+    if let Some(Err(e)) = state.model_metadata.as_ref().map(|m| m.check_feature_flags(&state.nn_feature_flags)) {
+        state.notices.error(NoticeSource::Analysis, tr_args("nn-backtest-failed", &[("error", e)]));
+        return;
+    }
+    match crate::nn::backtest::run_backtest(model, &state.market_data, &state.nn_feature_flags, state.model_metadata.as_ref()) {
+        Some(result) => {
+            state.notices.info(
+                NoticeSource::Analysis,
+                tr_args(
+                    "nn-backtest-complete",
+                    &[
+                        ("count", i18n::integer(result.predicted.len())),
+                        ("mae", i18n::percent(result.out_of_sample.mae, 2)),
+                    ],
+                ),
+            );
+            state.backtest = Some(result);
+        }
+        None => {
+            state.notices.error(NoticeSource::Analysis, tr("nn-backtest-short"));
+        }
+    }
+}
+
+fn run_saliency(state: &mut AppState) {
+    let Some(ref model) = state.loaded_model else { return };
+    if let Some(Err(e)) = state.model_metadata.as_ref().map(|m| m.check_feature_flags(&state.nn_feature_flags)) {
+        state.notices.error(NoticeSource::Analysis, tr_args("nn-saliency-failed", &[("error", e)]));
+        return;
+    }
+    match crate::nn::saliency::compute_saliency(model, &state.market_data, &state.nn_feature_flags, state.model_metadata.as_ref()) {
+        Ok(saliency) => {
+            state.notices.info(NoticeSource::Analysis, tr_args("nn-saliency-done", &[("target", saliency_target(saliency.target))]));
+            state.saliency = Some(saliency);
+        }
+        Err(e) => state.notices.error(NoticeSource::Analysis, tr_args("nn-saliency-failed", &[("error", e)])),
+    }
+}
+
+/// Epoch progress bar; on GPU runs, a compact utilization / VRAM / temperature readout sits beside it.
+fn progress_with_telemetry(ui: &mut egui::Ui, frac: f32, stats: &crate::data::models::ComputeStats) {
+    if !stats.using_gpu {
+        ui.add(egui::ProgressBar::new(frac).show_percentage());
+        return;
+    }
+
+    let mut parts = Vec::new();
+    if let Some(util) = stats.gpu_utilization_percent {
+        parts.push(format!("GPU {:.0}%", util));
+    }
+    if let (Some(used), Some(total)) = (stats.gpu_vram_used_mb, stats.gpu_vram_total_mb) {
+        parts.push(format!("VRAM {}", gigabytes(used, total)));
+    }
+    if let Some(temp) = stats.gpu_temperature_c {
+        parts.push(format!("{:.0}°C", temp));
+    }
+    if parts.is_empty() {
+        ui.add(egui::ProgressBar::new(frac).show_percentage());
+        return;
+    }
+
+    let hot = stats.gpu_temperature_c.is_some_and(|t| t >= 85.0);
+    ui.horizontal(|ui| {
+        let bar_width = (ui.available_width() - 260.0).max(120.0);
+        ui.add(egui::ProgressBar::new(frac).desired_width(bar_width).show_percentage());
+        let color = if hot {
+            egui::Color32::from_rgb(220, 50, 50)
+        } else {
+            egui::Color32::from_rgb(150, 150, 150)
+        };
+        ui.colored_label(color, parts.join(" | "));
+    });
 }
 
 fn render_compute_stats(
@@ -394,13 +1766,13 @@ fn render_compute_stats(
     status: &TrainingStatus,
 ) {
     ui.group(|ui| {
-        ui.heading("Compute Statistics");
+        ui.heading(tr("nn-compute-heading"));
         ui.add_space(4.0);
 
         ui.columns(2, |cols| {
             // Left column: System / Training stats
             cols[0].group(|ui| {
-                ui.strong("System & Training");
+                ui.strong(tr("nn-system-training"));
                 ui.add_space(4.0);
 
                 egui::Grid::new("system_stats_grid")
@@ -408,7 +1780,7 @@ fn render_compute_stats(
                     .spacing(egui::vec2(12.0, 3.0))
                     .show(ui, |ui| {
                         // Backend
-                        ui.label("Backend:");
+                        ui.label(tr("nn-backend"));
                         if stats.backend_name.contains("fallback") {
                             ui.colored_label(
                                 egui::Color32::from_rgb(255, 165, 0),
@@ -421,11 +1793,17 @@ fn render_compute_stats(
 
                         // Model parameters
                         if stats.total_params > 0 {
-                            ui.label("Parameters:");
+                            ui.label(tr("nn-parameters"));
                             ui.strong(format_param_count(stats.total_params));
                             ui.end_row();
                         }
 
+                        if stats.batch_size > 0 {
+                            ui.label(tr("nn-batch-size"));
+                            ui.strong(i18n::integer(stats.batch_size));
+                            ui.end_row();
+                        }
+
                         // CPU usage
                         ui.label("CPU:");
                         let cpu_color = cpu_color(stats.cpu_usage_percent);
@@ -434,7 +1812,7 @@ fn render_compute_stats(
                                 egui::ProgressBar::new(stats.cpu_usage_percent / 100.0)
                                     .desired_width(100.0),
                             );
-                            ui.colored_label(cpu_color, format!("{:.1}%", stats.cpu_usage_percent));
+                            ui.colored_label(cpu_color, i18n::percent(stats.cpu_usage_percent as f64 / 100.0, 1));
                         });
                         ui.end_row();
 
@@ -447,59 +1825,55 @@ fn render_compute_stats(
                                 ui.add(
                                     egui::ProgressBar::new(mem_frac).desired_width(100.0),
                                 );
-                                ui.label(format!(
-                                    "{:.1} / {:.1} GB",
-                                    stats.memory_used_mb as f64 / 1024.0,
-                                    stats.memory_total_mb as f64 / 1024.0,
-                                ));
+                                ui.label(gigabytes(stats.memory_used_mb, stats.memory_total_mb));
                             });
                             ui.end_row();
                         }
 
                         // Epoch duration
                         if stats.epoch_duration_ms > 0 {
-                            ui.label("Epoch Time:");
+                            ui.label(tr("nn-epoch-time"));
                             if stats.epoch_duration_ms >= 1000 {
-                                ui.strong(format!(
-                                    "{:.2}s",
-                                    stats.epoch_duration_ms as f64 / 1000.0
+                                ui.strong(tr_args(
+                                    "unit-seconds",
+                                    &[("value", i18n::number(stats.epoch_duration_ms as f64 / 1000.0, 2))],
                                 ));
                             } else {
-                                ui.strong(format!("{}ms", stats.epoch_duration_ms));
+                                ui.strong(tr_args("unit-ms", &[("value", stats.epoch_duration_ms.to_string())]));
                             }
                             ui.end_row();
                         }
 
                         // Throughput
                         if stats.samples_per_sec > 0.0 {
-                            ui.label("Throughput:");
-                            ui.strong(format!("{:.0} samples/sec", stats.samples_per_sec));
+                            ui.label(tr("nn-throughput"));
+                            ui.strong(tr_args("nn-samples-per-sec", &[("value", i18n::number(stats.samples_per_sec, 0))]));
                             ui.end_row();
                         }
 
                         // Status indicator
-                        ui.label("Status:");
+                        ui.label(tr("nn-status"));
                         match status {
                             TrainingStatus::Training { .. } => {
                                 ui.colored_label(
                                     egui::Color32::from_rgb(50, 180, 50),
-                                    "Running",
+                                    tr("nn-status-running"),
                                 );
                             }
                             TrainingStatus::Paused { .. } => {
                                 ui.colored_label(
                                     egui::Color32::from_rgb(220, 180, 50),
-                                    "Paused",
+                                    tr("nn-status-paused"),
                                 );
                             }
                             TrainingStatus::Complete { .. } => {
                                 ui.colored_label(
                                     egui::Color32::from_rgb(100, 150, 255),
-                                    "Complete",
+                                    tr("nn-status-complete"),
                                 );
                             }
                             _ => {
-                                ui.label("Idle");
+                                ui.label(tr("nn-status-idle"));
                             }
                         }
                         ui.end_row();
@@ -514,7 +1888,7 @@ fn render_compute_stats(
                 if !stats.gpu_detected {
                     ui.colored_label(
                         egui::Color32::from_rgb(150, 150, 150),
-                        "No NVIDIA GPU detected",
+                        tr("nn-no-nvidia"),
                     );
                     return;
                 }
@@ -524,12 +1898,12 @@ fn render_compute_stats(
                     .spacing(egui::vec2(12.0, 3.0))
                     .show(ui, |ui| {
                         // GPU name
-                        ui.label("Device:");
-                        ui.strong(stats.gpu_name.as_deref().unwrap_or("Unknown"));
+                        ui.label(tr("nn-device"));
+                        ui.strong(stats.gpu_name.clone().unwrap_or_else(|| tr("sector-unknown")));
                         ui.end_row();
 
                         // Active indicator
-                        ui.label("Training on:");
+                        ui.label(tr("nn-training-on"));
                         if stats.using_gpu {
                             ui.colored_label(
                                 egui::Color32::from_rgb(50, 220, 50),
@@ -538,7 +1912,7 @@ fn render_compute_stats(
                         } else {
                             ui.colored_label(
                                 egui::Color32::from_rgb(150, 150, 150),
-                                "CPU (GPU idle)",
+                                tr("nn-cpu-gpu-idle"),
                             );
                         }
                         ui.end_row();
@@ -562,14 +1936,7 @@ fn render_compute_stats(
                                         egui::ProgressBar::new(vram_frac)
                                             .desired_width(100.0),
                                     );
-                                    ui.colored_label(
-                                        vram_color,
-                                        format!(
-                                            "{:.1} / {:.1} GB",
-                                            used as f64 / 1024.0,
-                                            total as f64 / 1024.0,
-                                        ),
-                                    );
+                                    ui.colored_label(vram_color, gigabytes(used, total));
                                 });
                                 ui.end_row();
                             }
@@ -577,7 +1944,7 @@ fn render_compute_stats(
 
                         // GPU utilization
                         if let Some(util) = stats.gpu_utilization_percent {
-                            ui.label("Utilization:");
+                            ui.label(tr("nn-utilization"));
                             let util_color = if util > 80.0 {
                                 egui::Color32::from_rgb(50, 180, 50)
                             } else if util > 30.0 {
@@ -590,14 +1957,14 @@ fn render_compute_stats(
                                     egui::ProgressBar::new(util / 100.0)
                                         .desired_width(100.0),
                                 );
-                                ui.colored_label(util_color, format!("{:.0}%", util));
+                                ui.colored_label(util_color, i18n::percent(util as f64 / 100.0, 0));
                             });
                             ui.end_row();
                         }
 
                         // Temperature
                         if let Some(temp) = stats.gpu_temperature_c {
-                            ui.label("Temperature:");
+                            ui.label(tr("nn-temperature"));
                             let temp_color = if temp > 85.0 {
                                 egui::Color32::from_rgb(220, 50, 50)
                             } else if temp > 70.0 {
@@ -614,11 +1981,8 @@ fn render_compute_stats(
                             && stats.gpu_vram_total_mb.is_none()
                             && stats.gpu_utilization_percent.is_none()
                         {
-                            ui.label("Stats:");
-                            ui.colored_label(
-                                egui::Color32::from_gray(150),
-                                "Unavailable (nvidia-smi / rocm-smi / amd-smi not found)",
-                            );
+                            ui.label(tr("nn-stats"));
+                            ui.colored_label(egui::Color32::from_gray(150), tr("nn-stats-unavailable"));
                             ui.end_row();
                         }
                     });
@@ -639,30 +2003,104 @@ fn cpu_color(percent: f32) -> egui::Color32 {
 
 fn format_param_count(count: usize) -> String {
     if count >= 1_000_000 {
-        format!("{:.2}M", count as f64 / 1_000_000.0)
+        format!("{}M", i18n::number(count as f64 / 1_000_000.0, 2))
     } else if count >= 1_000 {
-        format!("{:.1}K", count as f64 / 1_000.0)
+        format!("{}K", i18n::number(count as f64 / 1_000.0, 1))
     } else {
-        format!("{}", count)
+        i18n::integer(count)
     }
 }
 
-fn start_training(state: &mut AppState) {
+/// "used / total GB" for two sizes in MB
+fn gigabytes(used_mb: u64, total_mb: u64) -> String {
+    tr_args(
+        "nn-gigabytes",
+        &[("used", i18n::number(used_mb as f64 / 1024.0, 1)), ("total", i18n::number(total_mb as f64 / 1024.0, 1))],
+    )
+}
+
+/// Start a training run, or stop the one in progress (keyboard shortcut entry point)
+pub fn toggle_training(state: &mut AppState) -> Result<(), String> {
+    match state.training_status {
+        TrainingStatus::Training { .. } | TrainingStatus::Paused { .. } => {
+            if let Some(ref progress) = state.training_progress {
+                progress.request_stop();
+            }
+            finish_active_run(state, RunOutcome::Stopped);
+            state.training_status = TrainingStatus::Idle;
+            state.training_progress = None;
+        }
+        _ => {
+            if state.sweep_progress.as_ref().is_some_and(|p| !p.is_finished()) {
+                return Err(tr("nn-sweep-running"));
+            }
+            if state.market_data.sectors.is_empty() {
+                return Err(tr("nn-load-data-first"));
+            }
+            start_training(state);
+        }
+    }
+    Ok(())
+}
+
+/// Start a training run for a scheduled job. Unlike the button, it never stops a run
+/// already in progress.
+pub fn start_scheduled_training(state: &mut AppState) -> Result<TaskId, String> {
+    if matches!(state.training_status, TrainingStatus::Training { .. } | TrainingStatus::Paused { .. }) {
+        return Err(tr("nn-already-training"));
+    }
+    if state.sweep_progress.as_ref().is_some_and(|p| !p.is_finished()) {
+        return Err(tr("nn-sweep-running"));
+    }
+    if state.market_data.sectors.is_empty() {
+        return Err(tr("nn-load-data-first"));
+    }
+    Ok(start_training(state))
+}
+
+fn start_training(state: &mut AppState) -> TaskId {
     let progress = TrainingProgress::new();
     state.training_progress = Some(progress.clone());
     state.training_status = TrainingStatus::Training {
         epoch: 0,
-        total_epochs: crate::config::NN_EPOCHS,
+        total_epochs: state.nn_training_config.epochs,
         loss: f64::NAN,
     };
     state.training_losses.clear();
+    state.validation_losses.clear();
     state.nn_predictions = crate::data::models::NnPredictions::default();
 
     let market_data = state.market_data.clone();
-    let use_gpu = state.use_gpu;
+    let compute_device = state.compute_device();
     let feature_flags = state.nn_feature_flags.clone();
+    let training_config = state.nn_training_config.clone();
+    state.active_run = Some(TrainingRun::start(&training_config, &feature_flags));
 
-    std::thread::spawn(move || {
-        crate::nn::training::train(&market_data, &progress, use_gpu, &feature_flags);
-    });
+    let (cancel, watched) = (progress.clone(), progress.clone());
+    let hooks = TaskHooks {
+        cancel: Some(Box::new(move || cancel.request_stop())),
+        progress: Some(Box::new(move || training_task_progress(&watched))),
+    };
+    state.tasks.spawn_blocking(TaskKind::Training, tr("nn-task-training"), hooks, move || {
+        crate::nn::training::train(&market_data, &progress, &compute_device, &feature_flags, &training_config, true);
+        match progress.status.lock().map(|s| s.clone()) {
+            Ok(TrainingStatus::Error(e)) => Err(e),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Task progress of a training run: epochs done out of all epochs
+fn training_task_progress(progress: &TrainingProgress) -> TaskProgress {
+    match progress.status.lock().map(|s| s.clone()) {
+        Ok(TrainingStatus::Training { epoch, total_epochs, .. } | TrainingStatus::Paused { epoch, total_epochs, .. })
+            if total_epochs > 0 =>
+        {
+            TaskProgress {
+            fraction: Some(epoch as f32 / total_epochs as f32),
+                detail: tr_args("nn-task-epoch", &[("epoch", epoch.to_string()), ("total", total_epochs.to_string())]),
+            }
+        }
+        _ => TaskProgress::default(),
+    }
 }
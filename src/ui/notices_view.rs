@@ -0,0 +1,199 @@
+use eframe::egui;
+
+use crate::app::AppState;
+use crate::notices::{Notice, NoticeSource, RetryAction, Severity};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
+
+fn severity_label(severity: Severity) -> String {
+    tr(match severity {
+        Severity::Info => "severity-info",
+        Severity::Warning => "severity-warning",
+        Severity::Error => "severity-error",
+    })
+}
+
+fn source_label(source: NoticeSource) -> String {
+    tr(match source {
+        NoticeSource::Data => "source-data",
+        NoticeSource::Analysis => "source-analysis",
+        NoticeSource::Training => "source-training",
+        NoticeSource::Export => "source-export",
+        NoticeSource::Settings => "source-settings",
+        NoticeSource::Jobs => "source-jobs",
+    })
+}
+
+fn retry_label(action: &RetryAction) -> String {
+    match action {
+        RetryAction::Refresh => tr("retry-refresh"),
+        RetryAction::Refetch(symbol) => tr_args("retry-refetch", &[("symbol", symbol.clone())]),
+        RetryAction::Train => tr("retry-train"),
+    }
+}
+
+fn severity_color(ui: &egui::Ui, severity: Severity) -> egui::Color32 {
+    match severity {
+        Severity::Info => ui.visuals().text_color(),
+        Severity::Warning => egui::Color32::from_rgb(230, 160, 40),
+        Severity::Error => palette::negative(),
+    }
+}
+
+/// Latest notice for the left of the status bar, colored by severity. Click to open the
+/// Notifications window.
+pub fn render_latest(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some(notice) = state.notices.latest() else {
+        ui.label(tr("notices-placeholder"));
+        return;
+    };
+    let text = match notice.severity {
+        Severity::Info => egui::RichText::new(&notice.message),
+        severity => egui::RichText::new(format!("{} {}", severity.icon(), notice.message))
+            .color(severity_color(ui, severity)),
+    };
+    let response = ui
+        .add(egui::Label::new(text).truncate().sense(egui::Sense::click()))
+        .on_hover_text(tr_args(
+            "notices-latest-hover",
+            &[("time", notice.at.format("%H:%M:%S").to_string()), ("source", source_label(notice.source))],
+        ));
+    if response.clicked() {
+        state.notices.set_open(true);
+    }
+}
+
+/// Status-bar count of warnings and errors not yet seen. Click to open the
+/// Notifications window.
+pub fn render_indicator(ui: &mut egui::Ui, state: &mut AppState) {
+    let notices = &state.notices;
+    let text = if notices.unread > 0 {
+        egui::RichText::new(tr_args("notices-unread", &[("count", i18n::integer(notices.unread))])).color(palette::negative())
+    } else {
+        egui::RichText::new(tr("notices-title"))
+    };
+    let response = ui.selectable_label(notices.open, text);
+    if response.on_hover_text(tr("notices-hover")).clicked() {
+        let open = !state.notices.open;
+        state.notices.set_open(open);
+    }
+}
+
+/// Window listing the session's notices, newest first, with severity and source
+/// filters. Returns the retry picked for a failed fetch or training run.
+pub fn render_window(ctx: &egui::Context, state: &mut AppState) -> Option<RetryAction> {
+    if !state.notices.open {
+        return None;
+    }
+    let mut open = true;
+    let mut retry = None;
+    let mut dismiss = None;
+    let mut clear = false;
+    egui::Window::new(tr("notices-title"))
+        .id(egui::Id::new("notices_window"))
+        .open(&mut open)
+        .default_width(560.0)
+        .default_pos(ctx.screen_rect().left_bottom() + egui::vec2(20.0, -380.0))
+        .show(ctx, |ui| {
+            let notices = &mut state.notices;
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt("notice_severity")
+                    .selected_text(tr_args("notices-min-severity", &[("severity", severity_label(notices.min_severity))]))
+                    .show_ui(ui, |ui| {
+                        for severity in Severity::ALL {
+                            ui.selectable_value(
+                                &mut notices.min_severity,
+                                severity,
+                                tr_args("notices-min-severity", &[("severity", severity_label(severity))]),
+                            );
+                        }
+                    });
+                egui::ComboBox::from_id_salt("notice_source")
+                    .selected_text(notices.source_filter.map_or_else(|| tr("notices-all-sources"), source_label))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut notices.source_filter, None, tr("notices-all-sources"));
+                        for source in NoticeSource::ALL {
+                            ui.selectable_value(&mut notices.source_filter, Some(source), source_label(source));
+                        }
+                    });
+                ui.weak(tr_args(
+                    "notices-counts",
+                    &[
+                        ("errors", i18n::integer(notices.count(Severity::Error))),
+                        ("warnings", i18n::integer(notices.count(Severity::Warning))),
+                    ],
+                ));
+            });
+            ui.separator();
+
+            let shown: Vec<&Notice> = notices
+                .iter()
+                .rev()
+                .filter(|n| n.severity >= notices.min_severity)
+                .filter(|n| notices.source_filter.is_none_or(|s| s == n.source))
+                .collect();
+            if shown.is_empty() {
+                ui.weak(tr("notices-empty"));
+            } else {
+                egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                    egui::Grid::new("notices_grid").num_columns(5).striped(true).show(ui, |ui| {
+                        for notice in &shown {
+                            ui.weak(notice.at.format("%H:%M:%S").to_string())
+                                .on_hover_text(i18n::date_time(notice.at));
+                            ui.colored_label(severity_color(ui, notice.severity), notice.severity.icon())
+                                .on_hover_text(severity_label(notice.severity));
+                            ui.weak(source_label(notice.source));
+                            ui.add(egui::Label::new(&notice.message).wrap());
+                            ui.horizontal(|ui| {
+                                if let Some(action) = &notice.retry {
+                                    let busy = state.is_loading && *action != RetryAction::Train;
+                                    if ui
+                                        .add_enabled(!busy, egui::Button::new(retry_label(action)).small())
+                                        .on_disabled_hover_text(tr("notices-retry-busy"))
+                                        .clicked()
+                                    {
+                                        retry = Some(action.clone());
+                                        dismiss = Some(notice.id);
+                                    }
+                                }
+                                if ui.small_button("✕").on_hover_text(tr("dismiss")).clicked() {
+                                    dismiss = Some(notice.id);
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+                });
+            }
+
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                if ui.button(tr("copy")).on_hover_text(tr("notices-copy-hover")).clicked() {
+                    let text: Vec<String> = shown
+                        .iter()
+                        .map(|n| {
+                            format!(
+                                "{} [{}] {}: {}",
+                                i18n::date_time(n.at),
+                                severity_label(n.severity),
+                                source_label(n.source),
+                                n.message
+                            )
+                        })
+                        .collect();
+                    ui.ctx().copy_text(text.join("\n"));
+                }
+                if ui.add_enabled(!notices.is_empty(), egui::Button::new(tr("notices-clear-all"))).clicked() {
+                    clear = true;
+                }
+            });
+        });
+    if let Some(id) = dismiss {
+        state.notices.dismiss(id);
+    }
+    if clear {
+        state.notices.clear();
+    }
+    state.notices.set_open(open);
+    retry
+}
@@ -0,0 +1,103 @@
+//! Shared semantic colors. Views ask for "good"/"bad"/scale colors here instead of
+//! hard-coding red and green, so the colorblind-safe mode applies everywhere at once.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use eframe::egui::Color32;
+use serde::{Deserialize, Serialize};
+
+use crate::ui::i18n::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PaletteMode {
+    /// Green for calm/gains, red for stress/losses
+    #[default]
+    Standard,
+    /// Blue for calm/gains, orange for stress/losses (Okabe-Ito hues)
+    ColorblindSafe,
+}
+
+impl PaletteMode {
+    pub const ALL: [PaletteMode; 2] = [Self::Standard, Self::ColorblindSafe];
+
+    pub fn label(&self) -> String {
+        tr(match self {
+            Self::Standard => "palette-standard",
+            Self::ColorblindSafe => "palette-colorblind",
+        })
+    }
+}
+
+static COLORBLIND: AtomicBool = AtomicBool::new(false);
+
+/// Switch the palette used by every helper below; called once per frame from the app state
+pub fn set_mode(mode: PaletteMode) {
+    COLORBLIND.store(mode == PaletteMode::ColorblindSafe, Ordering::Relaxed);
+}
+
+pub fn mode() -> PaletteMode {
+    if COLORBLIND.load(Ordering::Relaxed) {
+        PaletteMode::ColorblindSafe
+    } else {
+        PaletteMode::Standard
+    }
+}
+
+fn pick(standard: Color32, colorblind: Color32) -> Color32 {
+    match mode() {
+        PaletteMode::Standard => standard,
+        PaletteMode::ColorblindSafe => colorblind,
+    }
+}
+
+/// Gains, calm markets, low readings
+pub fn positive() -> Color32 {
+    pick(Color32::from_rgb(50, 180, 50), Color32::from_rgb(0, 114, 178))
+}
+
+/// Losses, stressed markets, high readings
+pub fn negative() -> Color32 {
+    pick(Color32::from_rgb(220, 50, 50), Color32::from_rgb(213, 94, 0))
+}
+
+/// Middle band between `positive` and `negative`
+pub fn neutral() -> Color32 {
+    pick(Color32::from_rgb(220, 180, 50), Color32::from_rgb(240, 228, 66))
+}
+
+/// Four-step scale for annualized vol: <10%, 10-20%, 20-30%, >30%
+pub fn vol_color(vol: f64) -> Color32 {
+    let pct = vol * 100.0;
+    if pct > 30.0 {
+        negative()
+    } else if pct > 20.0 {
+        pick(Color32::from_rgb(220, 150, 50), Color32::from_rgb(230, 159, 0))
+    } else if pct > 10.0 {
+        pick(Color32::from_rgb(200, 200, 50), Color32::from_rgb(86, 180, 233))
+    } else {
+        positive()
+    }
+}
+
+/// Diverging fill for a correlation coefficient: white at 0, shading to the
+/// positive-correlation hue at +1 and the negative-correlation hue at -1
+pub fn correlation_color(val: f64) -> Color32 {
+    let clamped = val.clamp(-1.0, 1.0);
+    let (target, t) = if clamped >= 0.0 {
+        (correlation_extreme(1.0), clamped as f32)
+    } else {
+        (correlation_extreme(-1.0), (-clamped) as f32)
+    };
+    let blend = |c: u8| (240.0 * (1.0 - t) + c as f32 * t) as u8;
+    Color32::from_rgb(blend(target.r()), blend(target.g()), blend(target.b()))
+}
+
+/// Fully saturated end of the correlation scale for the sign of `val`, for legends
+pub fn correlation_extreme(val: f64) -> Color32 {
+    if val >= 0.0 {
+        pick(Color32::from_rgb(0, 0, 220), Color32::from_rgb(0, 114, 178))
+    } else {
+        pick(Color32::from_rgb(220, 0, 0), Color32::from_rgb(213, 94, 0))
+    }
+}
+
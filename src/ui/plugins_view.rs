@@ -0,0 +1,121 @@
+//! Generic results view for analysis plugins: each plugin's series on one chart over
+//! the dates they cover, and its tables as grids with CSV export.
+
+use chrono::NaiveDate;
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot};
+
+use crate::analysis::plugin::{PluginOutput, PluginTable};
+use crate::app::AppState;
+use crate::notices::Notices;
+use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::{i18n, palette};
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(i18n::tr("plugins-heading"));
+    ui.add_space(8.0);
+
+    if state.plugins.is_empty() {
+        ui.label(i18n::tr("plugins-none"));
+        return;
+    }
+    if state.market_data.sectors.is_empty() {
+        ui.label(i18n::tr("no-data-loaded"));
+        return;
+    }
+
+    for result in &state.analysis.plugins {
+        egui::CollapsingHeader::new(egui::RichText::new(&result.name).strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                if !result.description.is_empty() {
+                    ui.label(&result.description);
+                }
+                match &result.output {
+                    Ok(output) => render_output(ui, &result.name, output, &mut state.chart_heights.plugin_series, &mut state.notices),
+                    Err(e) => {
+                        ui.colored_label(palette::negative(), i18n::tr_args("plugins-failed", &[("error", e.to_string())]));
+                    }
+                }
+            });
+        ui.add_space(8.0);
+    }
+}
+
+fn render_output(ui: &mut egui::Ui, plugin: &str, output: &PluginOutput, height: &mut f32, notices: &mut Notices) {
+    if !output.series.is_empty() {
+        // Put every series on the union of their dates so they share one X axis
+        let mut dates: Vec<NaiveDate> = output.series.iter().flat_map(|s| s.dates.iter().copied()).collect();
+        dates.sort_unstable();
+        dates.dedup();
+        let x_labels: Vec<String> = dates.iter().map(|d| d.to_string()).collect();
+        let data: Vec<Vec<[f64; 2]>> = output
+            .series
+            .iter()
+            .map(|s| {
+                s.dates
+                    .iter()
+                    .zip(&s.values)
+                    .filter_map(|(d, v)| dates.binary_search(d).ok().map(|x| [x as f64, *v]))
+                    .collect()
+            })
+            .collect();
+        let hover: Vec<HoverSeries> = output
+            .series
+            .iter()
+            .zip(&data)
+            .map(|(s, data)| HoverSeries { name: &s.name, data, decimals: 2, suffix: "" })
+            .collect();
+
+        let id = format!("plugin_plot_{}", plugin);
+        height_control(ui, height, &i18n::tr("plugins-height"));
+        chart_utils::plot_with_y_drag(
+            ui,
+            &id,
+            chart_utils::default_plot_interaction(Plot::new(&id).height(*height))
+                .legend(Legend::default())
+                .x_axis_formatter(move |mark, _range| {
+                    let idx = mark.value.round();
+                    if idx < 0.0 {
+                        return String::new();
+                    }
+                    dates.get(idx as usize).map_or(String::new(), |d| d.format("%Y-%m").to_string())
+                })
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_labeled_x(&hover, &x_labels))
+                .label_formatter(chart_utils::no_hover_label),
+            |plot_ui| {
+                for (s, data) in output.series.iter().zip(&data) {
+                    plot_ui.line(Line::new(chart_utils::plot_points(data)).name(&s.name));
+                }
+            },
+        );
+    }
+
+    for table in &output.tables {
+        ui.add_space(4.0);
+        render_table(ui, plugin, table, notices);
+    }
+}
+
+fn render_table(ui: &mut egui::Ui, plugin: &str, table: &PluginTable, notices: &mut Notices) {
+    ui.horizontal(|ui| {
+        ui.strong(&table.name);
+        let name = format!("{}_{}", plugin, table.name).replace(' ', "_").to_lowercase();
+        chart_utils::export_csv_button(ui, notices, &name, || table.table.clone());
+    });
+    egui::Grid::new(("plugin_table", plugin, &table.name))
+        .striped(true)
+        .min_col_width(80.0)
+        .show(ui, |ui| {
+            for header in &table.table.headers {
+                ui.strong(header);
+            }
+            ui.end_row();
+            for row in &table.table.rows {
+                for cell in row {
+                    ui.label(cell);
+                }
+                ui.end_row();
+            }
+        });
+}
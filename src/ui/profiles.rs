@@ -0,0 +1,92 @@
+//! Named settings profiles: the sector universe, watchlist, analysis windows, NN
+//! hyperparameters and theme saved under one name (e.g. "Macro weekly") and switched
+//! together from the Settings tab.
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+use crate::data::cache;
+use crate::data::models::{AnalysisWindows, DisplaySettings, NnFeatureFlags, NnTrainingConfig};
+use crate::ui::palette::PaletteMode;
+use crate::ui::settings_view;
+use crate::ui::watchlist_view;
+
+/// Cache file the saved profiles are persisted to
+pub const PROFILES_FILENAME: &str = "settings_profiles.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsProfile {
+    pub name: String,
+    /// Sector symbols left out of analysis and training
+    pub excluded: Vec<String>,
+    pub watchlist: Vec<String>,
+    pub analysis_windows: AnalysisWindows,
+    pub nn_training_config: NnTrainingConfig,
+    /// Stored without the custom indicator scripts, which follow the current indicators
+    pub nn_feature_flags: NnFeatureFlags,
+    pub palette_mode: PaletteMode,
+    pub display_settings: DisplaySettings,
+}
+
+impl SettingsProfile {
+    /// Snapshot of the current settings under `name`
+    pub fn capture(name: &str, state: &AppState) -> Self {
+        Self {
+            name: name.to_string(),
+            excluded: state.data_manager.excluded.clone(),
+            watchlist: state.watchlist.symbols.clone(),
+            analysis_windows: state.analysis_windows,
+            nn_training_config: state.nn_training_config.clone(),
+            nn_feature_flags: NnFeatureFlags { custom_indicators: Vec::new(), ..state.nn_feature_flags.clone() },
+            palette_mode: state.palette_mode,
+            display_settings: state.display_settings,
+        }
+    }
+
+    /// Make this profile the current settings, persist each one as if it had been
+    /// changed by hand, and recompute analysis for the new universe and windows
+    pub fn apply(&self, state: &mut AppState, ctx: &egui::Context) -> Result<(), String> {
+        state.data_manager.excluded = self.excluded.clone();
+        state.watchlist.symbols = self.watchlist.clone();
+        state.watchlist.entries.retain(|e| self.watchlist.contains(&e.series.symbol));
+        state.watchlist.errors.retain(|(s, _)| self.watchlist.contains(s));
+        if self.analysis_windows.validate().is_ok() {
+            state.analysis_windows = self.analysis_windows;
+            state.analysis_windows_draft = self.analysis_windows;
+        }
+        state.nn_training_config = self.nn_training_config.clone();
+        state.nn_feature_flags = NnFeatureFlags {
+            custom_indicators: state.custom_indicators.nn_features(),
+            ..self.nn_feature_flags.clone()
+        };
+        state.palette_mode = self.palette_mode;
+        state.display_settings = self.display_settings;
+
+        state.apply_exclusions();
+        state.recompute_analysis();
+        watchlist_view::recompute_entries(&mut state.watchlist, &state.analysis_windows);
+        settings_view::apply_display_settings(ctx, &state.display_settings);
+
+        state
+            .save_config()
+            .map_err(|e| format!("Profile applied but the settings were not saved: {}", e))
+    }
+}
+
+/// Saved profiles in the order they were created. Empty if none have been saved.
+pub fn load_profiles() -> Vec<SettingsProfile> {
+    cache::load_json(PROFILES_FILENAME).unwrap_or_default()
+}
+
+pub fn save_profiles(profiles: &[SettingsProfile]) -> Result<(), String> {
+    cache::save_json(PROFILES_FILENAME, &profiles).map_err(|e| format!("Failed to save profiles: {}", e))
+}
+
+/// Replace the profile with the same name, or append it
+pub fn upsert(profiles: &mut Vec<SettingsProfile>, profile: SettingsProfile) {
+    match profiles.iter_mut().find(|p| p.name == profile.name) {
+        Some(existing) => *existing = profile,
+        None => profiles.push(profile),
+    }
+}
@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use eframe::egui;
+use egui_plot::{AxisHints, HLine, Line, Plot, PlotPoints, Points, Polygon};
+
+use crate::analysis::bond_spreads::{self, MATURITY_LABELS};
+use crate::app::AppState;
+use crate::data::models::TreasuryRate;
+use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("rates-heading"));
+    ui.add_space(8.0);
+
+    // Oldest first, so the slider runs forward in time
+    let mut history: Vec<&TreasuryRate> = state
+        .market_data
+        .treasury_rates
+        .iter()
+        .filter(|r| r.parsed_date().is_some())
+        .collect();
+    history.sort_by_key(|r| r.parsed_date());
+    if history.is_empty() {
+        ui.label(tr("bond-no-data"));
+        return;
+    }
+    let last = history.len() - 1;
+    let rates = &mut state.rates;
+
+    // Playback: advance one observation per 1/play_speed seconds, stopping at the latest date
+    if rates.playing {
+        let now = ui.input(|i| i.time);
+        let step_secs = 1.0 / rates.play_speed.max(0.1);
+        let mut idx = rates.date_idx.unwrap_or(0);
+        let last_step = *rates.last_step.get_or_insert(now);
+        let steps = ((now - last_step) / step_secs) as usize;
+        if steps > 0 {
+            idx = (idx + steps).min(last);
+            rates.last_step = Some(last_step + steps as f64 * step_secs);
+        }
+        rates.date_idx = Some(idx);
+        if idx == last {
+            rates.playing = false;
+        } else {
+            ui.ctx().request_repaint();
+        }
+    }
+
+    let mut idx = rates.date_idx.unwrap_or(last).min(last);
+    ui.horizontal(|ui| {
+        let play_label = tr(if rates.playing { "rates-pause" } else { "rates-play" });
+        if ui.button(play_label).clicked() {
+            rates.playing = !rates.playing;
+            rates.last_step = None;
+            if rates.playing && idx == last {
+                idx = 0;
+            }
+        }
+        ui.label(tr("rates-date"));
+        let date_label = i18n::iso_date(&history[idx].date);
+        if ui
+            .add(egui::Slider::new(&mut idx, 0..=last).show_value(false).text(date_label))
+            .changed()
+        {
+            rates.playing = false;
+        }
+        if ui.small_button(tr("rates-latest")).clicked() {
+            rates.playing = false;
+            idx = last;
+        }
+    });
+    // Following the latest date keeps the view current across data refreshes
+    rates.date_idx = (idx != last || rates.playing).then_some(idx);
+
+    ui.horizontal(|ui| {
+        ui.label(tr("rates-speed"));
+        ui.add(egui::DragValue::new(&mut rates.play_speed).speed(1.0).range(1.0..=250.0).suffix(format!(" {}", tr("rates-days-per-sec"))));
+        ui.separator();
+        ui.checkbox(&mut rates.compare_enabled, tr("rates-overlay-from"));
+        ui.add_enabled_ui(rates.compare_enabled, |ui| {
+            let mut pinned = rates.compare_date.is_some();
+            ui.radio_value(&mut pinned, false, "");
+            ui.add(egui::DragValue::new(&mut rates.compare_lag).range(1..=last.max(1)));
+            ui.label(tr("rates-observations-earlier"));
+            ui.radio_value(&mut pinned, true, tr("rates-on-date"));
+            match (pinned, rates.compare_date) {
+                (true, None) => rates.compare_date = history[idx.saturating_sub(rates.compare_lag)].parsed_date(),
+                (false, Some(_)) => rates.compare_date = None,
+                _ => {}
+            }
+            if let Some(date) = rates.compare_date {
+                let mut compare_idx = observation_on_or_before(&history, date);
+                let label = i18n::iso_date(&history[compare_idx].date);
+                if ui.add(egui::Slider::new(&mut compare_idx, 0..=last).show_value(false).text(label)).changed() {
+                    rates.compare_date = history[compare_idx].parsed_date();
+                }
+            }
+        });
+    });
+    ui.add_space(8.0);
+
+    let current = history[idx];
+    let previous = match rates.compare_date {
+        _ if !rates.compare_enabled => None,
+        Some(date) => Some(history[observation_on_or_before(&history, date)]).filter(|r| r.date != current.date),
+        None => (idx >= rates.compare_lag).then(|| history[idx - rates.compare_lag]),
+    };
+
+    let curves: Vec<&TreasuryRate> = std::iter::once(current).chain(previous).collect();
+    chart_utils::export_csv_button(ui, &mut state.notices, &format!("yield_curve_{}", current.date), || {
+        crate::data::export::yield_curve_table(&curves)
+    });
+    ui.add_space(4.0);
+
+    let current_data = curve_points(current);
+    let previous_data = previous.map(curve_points).unwrap_or_default();
+    let x_labels: Vec<String> = MATURITY_LABELS.iter().map(|l| l.to_string()).collect();
+    let current_name = i18n::iso_date(&current.date);
+    let previous_name = previous.map(|r| i18n::iso_date(&r.date)).unwrap_or_default();
+    let mut hover = vec![HoverSeries { name: &current_name, data: &current_data, decimals: 2, suffix: "%" }];
+    if previous.is_some() {
+        hover.push(HoverSeries { name: &previous_name, data: &previous_data, decimals: 2, suffix: "%" });
+    }
+
+    height_control(ui, &mut state.chart_heights.rates_curve, &tr("bond-curve-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "rates_curve",
+        chart_utils::default_plot_interaction(
+            Plot::new("rates_curve")
+                .height(state.chart_heights.rates_curve)
+                .include_x(0.0)
+                .include_x((MATURITY_LABELS.len() - 1) as f64)
+                .x_axis_formatter(|mark, _range| {
+                    let i = mark.value.round();
+                    if (mark.value - i).abs() < 1e-6 && i >= 0.0 {
+                        MATURITY_LABELS.get(i as usize).map(|l| l.to_string()).unwrap_or_default()
+                    } else {
+                        String::new()
+                    }
+                }),
+        )
+            .x_axis_label(tr("rates-maturity"))
+            .y_axis_label(tr("bond-axis-yield"))
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_labeled_x(&hover, &x_labels))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            if previous.is_some() {
+                // One legend entry per direction for the shaded difference band
+                let mut named = (false, false);
+                for (higher, band) in difference_band(&current_data, &previous_data) {
+                    let (name, color, seen) = if higher {
+                        (tr("rates-higher-yield"), palette::negative(), &mut named.0)
+                    } else {
+                        (tr("rates-lower-yield"), palette::positive(), &mut named.1)
+                    };
+                    let band = band.fill_color(color.gamma_multiply(0.2)).stroke(egui::Stroke::NONE).allow_hover(false);
+                    plot_ui.polygon(if std::mem::replace(seen, true) { band } else { band.name(name) });
+                }
+                plot_ui.line(
+                    Line::new(PlotPoints::from_iter(previous_data.iter().copied()))
+                        .name(&previous_name)
+                        .color(egui::Color32::from_rgb(150, 150, 150))
+                        .style(egui_plot::LineStyle::dashed_dense()),
+                );
+            }
+            plot_ui.line(
+                Line::new(PlotPoints::from_iter(current_data.iter().copied()))
+                    .name(&current_name)
+                    .color(egui::Color32::from_rgb(70, 130, 220))
+                    .width(2.0),
+            );
+            plot_ui.points(
+                Points::new(PlotPoints::from_iter(current_data.iter().copied()))
+                    .radius(3.0)
+                    .color(egui::Color32::from_rgb(70, 130, 220)),
+            );
+        },
+    );
+
+    // Key spreads for the selected date, with the change versus the overlay date
+    ui.add_space(8.0);
+    let spreads = |r: &TreasuryRate| bond_spreads::compute_term_spreads(std::slice::from_ref(r)).pop();
+    if let Some(now) = spreads(current) {
+        ui.horizontal(|ui| {
+            let inverted = now.spread_10y_2y < 0.0;
+            let color = if inverted {
+                egui::Color32::from_rgb(220, 50, 50)
+            } else {
+                egui::Color32::from_rgb(50, 180, 50)
+            };
+            let bps = |v: f64| tr_args("unit-bps", &[("value", i18n::signed(v * 100.0, 0))]);
+            ui.colored_label(color, format!("10Y-2Y: {}", bps(now.spread_10y_2y)));
+            ui.label(format!("30Y-3M: {}", bps(now.curve_slope)));
+            if let Some(then) = previous.and_then(spreads) {
+                ui.label(tr_args(
+                    "rates-versus",
+                    &[
+                        ("date", previous_name.clone()),
+                        ("spread", i18n::signed((now.spread_10y_2y - then.spread_10y_2y) * 100.0, 0)),
+                        ("slope", i18n::signed((now.curve_slope - then.curve_slope) * 100.0, 0)),
+                    ],
+                ));
+            }
+            if inverted {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), tr("rates-inverted"));
+            }
+        });
+    }
+
+    if let Some(previous) = previous {
+        ui.add_space(8.0);
+        render_curve_changes(ui, previous, current);
+    }
+
+    ui.add_space(16.0);
+    render_spread_history(ui, &history, &mut state.chart_heights.rates_spread_history);
+}
+
+/// 10Y-2Y spread and 30Y-3M slope over the whole history, with inverted stretches
+/// shaded and the NY Fed recession probability on a right-hand axis
+fn render_spread_history(ui: &mut egui::Ui, history: &[&TreasuryRate], height: &mut f32) {
+    ui.heading(tr("rates-history-heading"));
+    let rates: Vec<TreasuryRate> = history.iter().map(|r| (*r).clone()).collect();
+    let spreads = bond_spreads::compute_term_spreads(&rates);
+    if spreads.len() < 2 {
+        ui.label(tr("rates-history-short"));
+        return;
+    }
+    let index: HashMap<NaiveDate, usize> = spreads.iter().enumerate().map(|(i, s)| (s.date, i)).collect();
+    let x_labels: Vec<String> = spreads.iter().map(|s| i18n::date(s.date)).collect();
+    let spread_data: Vec<[f64; 2]> = spreads.iter().enumerate().map(|(i, s)| [i as f64, s.spread_10y_2y]).collect();
+    let slope_data: Vec<[f64; 2]> = spreads.iter().enumerate().map(|(i, s)| [i as f64, s.curve_slope]).collect();
+    let prob_data: Vec<[f64; 2]> = bond_spreads::recession_probabilities(&rates)
+        .into_iter()
+        .filter_map(|(date, p)| Some([*index.get(&date)? as f64, p * 100.0]))
+        .collect();
+
+    // Probability (0-100%) is drawn on the spread axis, mapped onto the spread range
+    let (lo, hi) = spread_data
+        .iter()
+        .chain(&slope_data)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| (lo.min(p[1]), hi.max(p[1])));
+    let (lo, hi) = (lo.min(0.0), hi.max(0.0));
+    let span = (hi - lo).max(1e-6);
+    let to_axis = move |pct: f64| lo + pct / 100.0 * span;
+    let prob_points: Vec<[f64; 2]> = prob_data.iter().map(|[x, p]| [*x, to_axis(*p)]).collect();
+
+    let prob_name = tr("rates-recession-prob");
+    let mut hover = vec![
+        HoverSeries { name: "10Y-2Y", data: &spread_data, decimals: 2, suffix: " pp" },
+        HoverSeries { name: "30Y-3M", data: &slope_data, decimals: 2, suffix: " pp" },
+    ];
+    if !prob_data.is_empty() {
+        hover.push(HoverSeries { name: &prob_name, data: &prob_data, decimals: 1, suffix: "%" });
+    }
+    let inverted = bond_spreads::inversion_periods(&spreads);
+
+    height_control(ui, height, &tr("rates-history-height"));
+    let axes = vec![
+        AxisHints::new_y().label(tr("rates-axis-spread")),
+        AxisHints::new_y()
+            .label(tr("rates-axis-recession"))
+            .placement(egui_plot::HPlacement::Right)
+            .formatter(move |mark, _range| i18n::percent((mark.value - lo) / span, 0)),
+    ];
+    chart_utils::plot_with_y_drag(
+        ui,
+        "rates_spread_history",
+        chart_utils::default_plot_interaction(
+            Plot::new("rates_spread_history")
+                .height(*height)
+                .custom_y_axes(axes),
+        )
+            .x_axis_label(tr("rates-observation"))
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_labeled_x(&hover, &x_labels))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            let red = egui::Color32::from_rgb(220, 50, 50);
+            for (i, (start, end)) in inverted.iter().enumerate() {
+                let (x0, x1) = (index[start] as f64 - 0.5, index[end] as f64 + 0.5);
+                let shade = Polygon::new(PlotPoints::new(vec![[x0, lo], [x1, lo], [x1, hi], [x0, hi]]))
+                    .fill_color(red.gamma_multiply(0.15))
+                    .stroke(egui::Stroke::NONE)
+                    .allow_hover(false);
+                // One legend entry for all shaded periods
+                plot_ui.polygon(if i == 0 { shade.name(tr("rates-inverted-periods")) } else { shade });
+            }
+            plot_ui.hline(HLine::new(0.0).color(egui::Color32::from_rgb(150, 150, 150)));
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&spread_data))
+                    .name("10Y-2Y")
+                    .color(egui::Color32::from_rgb(70, 130, 220))
+                    .width(1.5),
+            );
+            plot_ui.line(
+                Line::new(chart_utils::plot_points(&slope_data))
+                    .name("30Y-3M")
+                    .color(egui::Color32::from_rgb(100, 200, 150)),
+            );
+            if !prob_points.is_empty() {
+                plot_ui.line(
+                    Line::new(chart_utils::plot_points(&prob_points))
+                        .name(tr("rates-recession-line"))
+                        .color(egui::Color32::from_rgb(220, 150, 50))
+                        .style(egui_plot::LineStyle::dashed_dense()),
+                );
+            }
+        },
+    );
+    ui.small(tr("rates-recession-note"));
+}
+
+/// Index of the latest observation on or before `date` (the first one if all are later)
+fn observation_on_or_before(history: &[&TreasuryRate], date: NaiveDate) -> usize {
+    history.partition_point(|r| r.parsed_date().is_some_and(|d| d <= date)).saturating_sub(1)
+}
+
+/// Polygons filling the area between two curves, flagged `true` where `current` is
+/// above `previous`. Only maturities present on both curves are joined; a segment
+/// where the curves cross is split at the crossing into two triangles.
+fn difference_band(current: &[[f64; 2]], previous: &[[f64; 2]]) -> Vec<(bool, Polygon)> {
+    let common: Vec<(f64, f64, f64)> = current
+        .iter()
+        .filter_map(|c| previous.iter().find(|p| p[0] == c[0]).map(|p| (c[0], c[1], p[1])))
+        .collect();
+    let poly = |points: Vec<[f64; 2]>| Polygon::new(PlotPoints::new(points));
+    let mut bands = Vec::new();
+    for w in common.windows(2) {
+        let ((x0, a0, b0), (x1, a1, b1)) = (w[0], w[1]);
+        let (d0, d1) = (a0 - b0, a1 - b1);
+        if d0 * d1 < 0.0 {
+            let t = d0 / (d0 - d1);
+            let cross = [x0 + t * (x1 - x0), a0 + t * (a1 - a0)];
+            bands.push((d0 > 0.0, poly(vec![[x0, a0], cross, [x0, b0]])));
+            bands.push((d1 > 0.0, poly(vec![cross, [x1, a1], [x1, b1]])));
+        } else if d0 != 0.0 || d1 != 0.0 {
+            bands.push((d0 + d1 > 0.0, poly(vec![[x0, a0], [x1, a1], [x1, b1], [x0, b0]])));
+        }
+    }
+    bands
+}
+
+/// Yields on both dates and the change in basis points, per maturity
+fn render_curve_changes(ui: &mut egui::Ui, previous: &TreasuryRate, current: &TreasuryRate) {
+    let before: HashMap<&str, f64> = bond_spreads::yield_curve_for_date(previous).into_iter().collect();
+    let after: HashMap<&str, f64> = bond_spreads::yield_curve_for_date(current).into_iter().collect();
+    let fmt = |v: Option<&f64>| v.map(|v| i18n::percent(v / 100.0, 2)).unwrap_or_else(|| "—".to_string());
+    let (then_date, now_date) = (i18n::iso_date(&previous.date), i18n::iso_date(&current.date));
+
+    ui.collapsing(tr_args("rates-changes", &[("from", then_date.clone()), ("to", now_date.clone())]), |ui| {
+        egui::Grid::new("rates_curve_changes")
+            .num_columns(4)
+            .striped(true)
+            .spacing(egui::vec2(16.0, 4.0))
+            .show(ui, |ui| {
+                ui.strong(tr("rates-maturity"));
+                ui.strong(&then_date);
+                ui.strong(&now_date);
+                ui.strong(tr("compare-change"));
+                ui.end_row();
+                for label in MATURITY_LABELS {
+                    let (then, now) = (before.get(label), after.get(label));
+                    if then.is_none() && now.is_none() {
+                        continue;
+                    }
+                    ui.label(label);
+                    ui.label(fmt(then));
+                    ui.label(fmt(now));
+                    match (then, now) {
+                        (Some(then), Some(now)) => {
+                            let bps = (now - then) * 100.0;
+                            let color = if bps > 0.0 { palette::negative() } else if bps < 0.0 { palette::positive() } else { palette::neutral() };
+                            ui.colored_label(color, tr_args("unit-bps", &[("value", i18n::signed(bps, 0))]));
+                        }
+                        _ => {
+                            ui.label("—");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+/// `[maturity index, yield]` points for one date, positioned on `MATURITY_LABELS` so
+/// curves with different available maturities share an X axis
+fn curve_points(rate: &TreasuryRate) -> Vec<[f64; 2]> {
+    bond_spreads::yield_curve_for_date(rate)
+        .into_iter()
+        .filter_map(|(label, v)| {
+            let i = MATURITY_LABELS.iter().position(|l| *l == label)?;
+            Some([i as f64, v])
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use eframe::egui;
+
+use crate::app::AppState;
+use crate::data::yahoo::SymbolStatus;
+use crate::ui::{data_view, i18n, palette};
+
+/// Top-bar summary of the price refresh: a spinner with a done/total count while it
+/// runs, then a warning with the failure count if any symbol failed. Click to open the
+/// per-symbol list.
+pub fn render_indicator(ui: &mut egui::Ui, state: &mut AppState) {
+    let Some(progress) = &state.refresh_progress else {
+        return;
+    };
+    let entries = progress.snapshot();
+    let failed = entries.iter().filter(|(_, s)| matches!(s, SymbolStatus::Failed(_))).count();
+
+    let response = if state.is_loading {
+        let done = entries.iter().filter(|(_, s)| s.is_done()).count();
+        ui.spinner();
+        ui.selectable_label(state.refresh_progress_open, i18n::tr_args("refresh-loading", &[("done", i18n::integer(done)), ("total", i18n::integer(entries.len()))]))
+    } else if failed > 0 {
+        ui.selectable_label(
+            state.refresh_progress_open,
+            egui::RichText::new(i18n::tr_args("refresh-failed", &[("count", i18n::integer(failed))])).color(palette::negative()),
+        )
+    } else {
+        return;
+    };
+    if response.on_hover_text(i18n::tr("refresh-hover")).clicked() {
+        state.refresh_progress_open = !state.refresh_progress_open;
+    }
+}
+
+/// Window listing each symbol's refresh status, with retry buttons for failures
+pub fn render_window(ctx: &egui::Context, state: &mut AppState) {
+    if !state.refresh_progress_open {
+        return;
+    }
+    let Some(progress) = state.refresh_progress.clone() else {
+        return;
+    };
+    let mut open = true;
+    egui::Window::new(i18n::tr("refresh-window"))
+        .id(egui::Id::new("refresh_progress_window"))
+        .open(&mut open)
+        .resizable(false)
+        .default_pos(ctx.screen_rect().right_top() + egui::vec2(-320.0, 40.0))
+        .show(ctx, |ui| {
+            egui::Grid::new("refresh_progress_grid").num_columns(3).striped(true).show(ui, |ui| {
+                for (symbol, status) in progress.snapshot() {
+                    ui.label(&symbol);
+                    render_status(ui, &status);
+                    if let SymbolStatus::Failed(_) = status {
+                        if ui
+                            .add_enabled(!state.is_loading, egui::Button::new(i18n::tr("refresh-retry")).small())
+                            .on_disabled_hover_text(i18n::tr("notices-retry-busy"))
+                            .clicked()
+                        {
+                            progress.set(&symbol, SymbolStatus::Fetching);
+                            let name = data_view::series_name(&symbol).to_string();
+                            data_view::start_refetch(
+                                &mut state.tasks,
+                                &mut state.data_manager,
+                                &symbol,
+                                &name,
+                                state.analysis_windows.lookback_days,
+                            );
+                        }
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    state.refresh_progress_open = open;
+}
+
+fn render_status(ui: &mut egui::Ui, status: &SymbolStatus) {
+    match status {
+        SymbolStatus::Pending => {
+            ui.weak(status_label(status));
+        }
+        SymbolStatus::Fetching => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(status_label(status));
+            });
+        }
+        SymbolStatus::Cached | SymbolStatus::Downloaded => {
+            ui.colored_label(palette::positive(), status_label(status));
+        }
+        SymbolStatus::Failed(e) => {
+            ui.colored_label(palette::negative(), status_label(status)).on_hover_text(e);
+        }
+    }
+}
+
+fn status_label(status: &SymbolStatus) -> String {
+    i18n::tr(match status {
+        SymbolStatus::Pending => "refresh-status-pending",
+        SymbolStatus::Fetching => "refresh-status-fetching",
+        SymbolStatus::Cached => "refresh-status-cached",
+        SymbolStatus::Downloaded => "refresh-status-downloaded",
+        SymbolStatus::Failed(_) => "refresh-status-failed",
+    })
+}
@@ -0,0 +1,399 @@
+//! One-click market report: dashboard metrics, key charts, the correlation matrix and
+//! the latest NN predictions rendered into a single self-contained HTML file. For email
+//! the same report is rendered with PNG charts referenced by content ID, since mail
+//! clients drop inline SVG.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use eframe::egui;
+use image::ImageEncoder;
+use plotters::prelude::*;
+
+use crate::analysis::regimes::CorrelationRegime;
+use crate::app::AppState;
+use crate::config;
+use crate::ui::{palette, search};
+
+/// Directory (under the working directory) reports are written to
+const REPORT_DIR: &str = "reports";
+
+/// Trailing trading days shown in the report's time-series charts (~1 year)
+const CHART_DAYS: usize = 252;
+
+const CHART_SIZE: (u32, u32) = (900, 320);
+
+/// Line colors for multi-series charts, cycled per series
+const SERIES_COLORS: [RGBColor; 11] = [
+    RGBColor(31, 119, 180),
+    RGBColor(255, 127, 14),
+    RGBColor(44, 160, 44),
+    RGBColor(214, 39, 40),
+    RGBColor(148, 103, 189),
+    RGBColor(140, 86, 75),
+    RGBColor(227, 119, 194),
+    RGBColor(127, 127, 127),
+    RGBColor(188, 189, 34),
+    RGBColor(23, 190, 207),
+    RGBColor(0, 0, 0),
+];
+
+const STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 32px; color: #222; }
+h1 { margin-bottom: 0; }
+.subtitle { color: #777; margin-top: 4px; }
+.cards { display: flex; flex-wrap: wrap; gap: 12px; margin: 16px 0; }
+.card { border: 1px solid #ddd; border-radius: 6px; padding: 8px 14px; }
+.card .label { font-size: 12px; color: #777; }
+.card .value { font-size: 18px; font-weight: 600; }
+table { border-collapse: collapse; margin: 8px 0 24px; }
+th, td { border: 1px solid #ddd; padding: 4px 10px; text-align: right; font-size: 13px; }
+th:first-child, td:first-child { text-align: left; }
+.chart { margin: 8px 0 24px; }
+@media print { body { margin: 12mm; } .chart, table { page-break-inside: avoid; } }
+";
+
+/// A chart rendered for an emailed report, referenced from its HTML as `cid:{content_id}`
+pub struct InlineImage {
+    pub content_id: String,
+    pub png: Vec<u8>,
+}
+
+/// How charts are embedded in the rendered HTML
+enum ChartEmbed {
+    /// Inline SVG markup, for the saved file
+    Svg,
+    /// `<img src="cid:…">`, collecting the PNGs to attach
+    Png(Vec<InlineImage>),
+}
+
+/// Render the report for the current data into `reports/market_report_{timestamp}.html`.
+/// Returns the file path.
+pub fn generate(state: &AppState) -> Result<PathBuf, String> {
+    let dir = std::env::current_dir().map_err(|e| e.to_string())?.join(REPORT_DIR);
+    generate_in(state, &dir)
+}
+
+/// Render the report into `dir/market_report_{timestamp}.html`, creating `dir`
+pub fn generate_in(state: &AppState, dir: &Path) -> Result<PathBuf, String> {
+    if state.market_data.sectors.is_empty() {
+        return Err("no market data loaded".to_string());
+    }
+    let html = render_html(state, &mut ChartEmbed::Svg)?;
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let path = dir.join(format!("market_report_{}.html", timestamp));
+    std::fs::write(&path, html).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Render the report as an email body: the HTML plus the chart images it references
+pub fn render_email(state: &AppState) -> Result<(String, Vec<InlineImage>), String> {
+    if state.market_data.sectors.is_empty() {
+        return Err("no market data loaded".to_string());
+    }
+    let mut embed = ChartEmbed::Png(Vec::new());
+    let html = render_html(state, &mut embed)?;
+    let ChartEmbed::Png(images) = embed else { unreachable!() };
+    Ok((html, images))
+}
+
+fn render_html(state: &AppState, embed: &mut ChartEmbed) -> Result<String, String> {
+    let mut html = String::new();
+    let now = chrono::Local::now();
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Market Report {}</title><style>{}</style></head><body>",
+        now.format("%Y-%m-%d"),
+        STYLE
+    );
+    let _ = writeln!(
+        html,
+        "<h1>Market Structure Report</h1><p class=\"subtitle\">Generated {} &middot; data as of {}</p>",
+        now.format("%Y-%m-%d %H:%M"),
+        escape(state.market_data.last_refresh.as_deref().unwrap_or("N/A"))
+    );
+
+    render_metrics(&mut html, state);
+    render_sector_table(&mut html, state);
+
+    html.push_str("<h2>Charts</h2>\n");
+    html.push_str(&vol_chart(state, embed)?);
+    html.push_str(&correlation_chart(state, embed)?);
+    html.push_str(&spread_chart(state, embed)?);
+
+    render_correlation_matrix(&mut html, state);
+    render_predictions(&mut html, state);
+
+    html.push_str("</body></html>\n");
+    Ok(html)
+}
+
+fn render_metrics(html: &mut String, state: &AppState) {
+    let close_label = format!("{} Last Close", config::benchmark_symbol());
+    let mut cards: Vec<(&str, String)> = vec![("Sectors Loaded", state.market_data.sectors.len().to_string())];
+    if let Some(last) = state.market_data.benchmark.as_ref().and_then(|b| b.bars.last()) {
+        cards.push((&close_label, format!("${:.2}", last.close)));
+    }
+    let avg_corr = state.analysis.avg_cross_correlation;
+    cards.push(("Avg Cross-Correlation", format!("{:.3}", avg_corr)));
+    cards.push(("Correlation Regime", CorrelationRegime::classify(avg_corr).label().to_string()));
+    if let Some(spread) = state.analysis.bond_spreads.iter().max_by_key(|s| s.date) {
+        cards.push(("10Y-2Y Spread", format!("{:.0} bps", spread.spread_10y_2y * 100.0)));
+        cards.push(("30Y-3M Slope", format!("{:.0} bps", spread.curve_slope * 100.0)));
+    }
+
+    html.push_str("<div class=\"cards\">");
+    for (label, value) in cards {
+        let _ = write!(
+            html,
+            "<div class=\"card\"><div class=\"label\">{}</div><div class=\"value\">{}</div></div>",
+            label,
+            escape(&value)
+        );
+    }
+    html.push_str("</div>\n");
+}
+
+fn render_sector_table(html: &mut String, state: &AppState) {
+    let _ = writeln!(
+        html,
+        "<h2>Sector Volatility</h2><table><tr><th>Sector</th><th>Symbol</th><th>Last Close</th>\
+         <th>{}D Vol</th><th>{}D Vol</th><th>Vol Ratio</th><th>VaR {:.0}%</th><th>Max Drawdown</th></tr>",
+        state.analysis_windows.short_vol,
+        state.analysis_windows.long_vol,
+        config::VAR_CONFIDENCE * 100.0
+    );
+    for sector in &state.market_data.sectors {
+        let vm = state.analysis.volatility.iter().find(|v| v.symbol == sector.symbol);
+        let risk = state.analysis.risk.iter().find(|r| r.symbol == sector.symbol);
+        let vol_cell = |v: Option<&f64>| match v {
+            Some(v) => format!("<td style=\"background:{}\">{:.1}%</td>", hex(palette::vol_color(*v)), v * 100.0),
+            None => "<td>-</td>".to_string(),
+        };
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td>{}{}<td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape(search::sector_name(&sector.symbol)),
+            escape(&sector.symbol),
+            sector.bars.last().map_or("-".to_string(), |b| format!("{:.2}", b.close)),
+            vol_cell(vm.and_then(|v| v.short_window_vol.last())),
+            vol_cell(vm.and_then(|v| v.long_window_vol.last())),
+            vm.and_then(|v| v.vol_ratio.last()).map_or("-".to_string(), |r| format!("{:.2}", r)),
+            risk.and_then(|r| r.var).map_or("-".to_string(), |v| format!("{:.2}%", v * 100.0)),
+            risk.and_then(|r| r.max_drawdown).map_or("-".to_string(), |d| format!("{:.1}%", d * 100.0)),
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn render_correlation_matrix(html: &mut String, state: &AppState) {
+    let Some(corr) = state.analysis.correlation.as_ref().filter(|c| !c.symbols.is_empty()) else { return };
+    html.push_str("<h2>Correlation Matrix</h2><table><tr><th></th>");
+    for symbol in &corr.symbols {
+        let _ = write!(html, "<th>{}</th>", escape(symbol));
+    }
+    html.push_str("</tr>\n");
+    for (symbol, row) in corr.symbols.iter().zip(&corr.matrix) {
+        let _ = write!(html, "<tr><td>{}</td>", escape(symbol));
+        for v in row {
+            let color = palette::correlation_color(*v);
+            let text = if v.abs() > 0.5 { "#fff" } else { "#000" };
+            let _ = write!(html, "<td style=\"background:{};color:{}\">{:.2}</td>", hex(color), text, v);
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+fn render_predictions(html: &mut String, state: &AppState) {
+    let preds = &state.nn_predictions;
+    html.push_str("<h2>Neural Network Predictions</h2>\n");
+    if preds.is_empty() {
+        html.push_str("<p>No predictions available. Train or load a model first.</p>\n");
+        return;
+    }
+    if let Some(meta) = &state.model_metadata {
+        let _ = writeln!(
+            html,
+            "<p class=\"subtitle\">Model trained {} &middot; target: {}</p>",
+            escape(&meta.trained_at),
+            escape(&meta.training_config.vol_target.label())
+        );
+    }
+    if let Some((_, vol)) = preds.vol.first() {
+        let _ = writeln!(
+            html,
+            "<p>Predicted {}-day forward vol: <strong>{:.1}%</strong></p>",
+            config::NN_FORWARD_DAYS,
+            vol * 100.0
+        );
+    }
+    if !preds.regime_probs.is_empty() {
+        html.push_str("<table><tr><th>Sector</th><th>Low</th><th>Normal</th><th>High</th></tr>\n");
+        for (symbol, probs) in &preds.regime_probs {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{:.0}%</td><td>{:.0}%</td><td>{:.0}%</td></tr>",
+                escape(symbol),
+                probs[0] * 100.0,
+                probs[1] * 100.0,
+                probs[2] * 100.0
+            );
+        }
+        html.push_str("</table>\n");
+    }
+    if !preds.kurtosis.is_empty() {
+        html.push_str("<table><tr><th>Sector</th><th>Entropy</th><th>Kurtosis</th><th>Skewness</th></tr>\n");
+        for (symbol, kurt, skew) in &preds.kurtosis {
+            let entropy = preds.randomness.iter().find(|(s, _)| s == symbol).map(|(_, e)| *e);
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2}</td></tr>",
+                escape(symbol),
+                entropy.map_or("-".to_string(), |e| format!("{:.3}", e)),
+                kurt,
+                skew
+            );
+        }
+        html.push_str("</table>\n");
+    }
+}
+
+/// Trailing-year short-window vol of every sector
+fn vol_chart(state: &AppState, embed: &mut ChartEmbed) -> Result<String, String> {
+    let series: Vec<(String, Vec<(NaiveDate, f64)>)> = state
+        .analysis
+        .volatility
+        .iter()
+        .map(|vm| {
+            let points = trailing(&vm.dates, &vm.short_window_vol).map(|(d, v)| (d, v * 100.0)).collect();
+            (vm.symbol.clone(), points)
+        })
+        .collect();
+    line_chart(&format!("{}D Realized Vol (%)", state.analysis_windows.short_vol), &series, embed)
+}
+
+/// Rolling average cross-sector correlation
+fn correlation_chart(state: &AppState, embed: &mut ChartEmbed) -> Result<String, String> {
+    let Some(dates) = state.market_data.sectors.first().map(|s| s.dates()) else { return Ok(String::new()) };
+    let points = trailing(&dates, &state.analysis.rolling_avg_correlation).collect();
+    line_chart(
+        &format!("{}D Average Cross-Sector Correlation", config::ROLLING_CORR_WINDOW),
+        &[("Avg correlation".to_string(), points)],
+        embed,
+    )
+}
+
+/// Trailing-year 10Y-2Y term spread
+fn spread_chart(state: &AppState, embed: &mut ChartEmbed) -> Result<String, String> {
+    let mut spreads: Vec<(NaiveDate, f64)> =
+        state.analysis.bond_spreads.iter().map(|s| (s.date, s.spread_10y_2y)).collect();
+    spreads.sort_by_key(|(d, _)| *d);
+    let points = spreads[spreads.len().saturating_sub(CHART_DAYS)..].to_vec();
+    line_chart("10Y-2Y Term Spread (pp)", &[("10Y-2Y".to_string(), points)], embed)
+}
+
+/// The last `CHART_DAYS` values paired with their dates, with `values` aligned to the
+/// trailing end of `dates`
+fn trailing<'a>(dates: &'a [NaiveDate], values: &'a [f64]) -> impl Iterator<Item = (NaiveDate, f64)> + 'a {
+    let n = values.len().min(dates.len());
+    let start = n.saturating_sub(CHART_DAYS);
+    dates[dates.len() - n..][start..].iter().copied().zip(values[values.len() - n..][start..].iter().copied())
+}
+
+/// Date-axis line chart, as inline SVG or an `<img>` of a PNG depending on `embed`.
+/// Empty when there's nothing to plot.
+fn line_chart(
+    title: &str,
+    series: &[(String, Vec<(NaiveDate, f64)>)],
+    embed: &mut ChartEmbed,
+) -> Result<String, String> {
+    let all = || series.iter().flat_map(|(_, pts)| pts.iter());
+    let (Some(start), Some(end)) = (all().map(|(d, _)| *d).min(), all().map(|(d, _)| *d).max()) else {
+        return Ok(String::new());
+    };
+    if start == end {
+        return Ok(String::new());
+    }
+    let (lo, hi) = all().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, v)| (lo.min(*v), hi.max(*v)));
+    let pad = ((hi - lo) * 0.05).max(1e-6);
+    let range = (start..end, (lo - pad)..(hi + pad));
+
+    match embed {
+        ChartEmbed::Svg => {
+            let mut svg = String::new();
+            draw_line_chart(SVGBackend::with_string(&mut svg, CHART_SIZE).into_drawing_area(), title, series, range)?;
+            Ok(format!("<div class=\"chart\">{}</div>\n", svg))
+        }
+        ChartEmbed::Png(images) => {
+            let (width, height) = CHART_SIZE;
+            let mut rgb = vec![0u8; (width * height * 3) as usize];
+            draw_line_chart(BitMapBackend::with_buffer(&mut rgb, CHART_SIZE).into_drawing_area(), title, series, range)?;
+            let mut png = Vec::new();
+            image::codecs::png::PngEncoder::new(&mut png)
+                .write_image(&rgb, width, height, image::ExtendedColorType::Rgb8)
+                .map_err(|e| format!("Failed to encode chart: {}", e))?;
+            let content_id = format!("chart{}", images.len() + 1);
+            let tag = format!(
+                "<div class=\"chart\"><img src=\"cid:{}\" alt=\"{}\" width=\"{}\" height=\"{}\"></div>\n",
+                content_id,
+                escape(title),
+                width,
+                height
+            );
+            images.push(InlineImage { content_id, png });
+            Ok(tag)
+        }
+    }
+}
+
+fn draw_line_chart<DB: DrawingBackend>(
+    root: DrawingArea<DB, plotters::coord::Shift>,
+    title: &str,
+    series: &[(String, Vec<(NaiveDate, f64)>)],
+    (x_range, y_range): (std::ops::Range<NaiveDate>, std::ops::Range<f64>),
+) -> Result<(), String> {
+    root.fill(&WHITE).map_err(|e| e.to_string())?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(title, ("sans-serif", 18))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .build_cartesian_2d(x_range, y_range)
+        .map_err(|e| e.to_string())?;
+    chart
+        .configure_mesh()
+        .light_line_style(WHITE.mix(0.0))
+        .x_labels(8)
+        .draw()
+        .map_err(|e| e.to_string())?;
+    for (i, (name, points)) in series.iter().enumerate() {
+        let color = SERIES_COLORS[i % SERIES_COLORS.len()];
+        chart
+            .draw_series(LineSeries::new(points.iter().copied(), color.stroke_width(2)))
+            .map_err(|e| e.to_string())?
+            .label(name.as_str())
+            .legend(move |(x, y)| PathElement::new([(x, y), (x + 16, y)], color.stroke_width(2)));
+    }
+    if series.len() > 1 {
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK.mix(0.3))
+            .draw()
+            .map_err(|e| e.to_string())?;
+    }
+    root.present().map_err(|e| e.to_string())
+}
+
+fn hex(color: egui::Color32) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,299 @@
+use eframe::egui;
+use egui_plot::{HLine, Legend, Line, Plot, PlotPoint, PlotPoints, Points, Text};
+
+use crate::analysis;
+use crate::app::{AppState, Tab};
+use crate::config;
+use crate::data::models::SectorTimeSeries;
+use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::ui::i18n::{self, tr, tr_args};
+
+/// Trailing windows offered for the risk/return scatter (trading days)
+const RISK_RETURN_WINDOWS: [(usize, &str); 4] =
+    [(21, "risk-window-1m"), (63, "risk-window-3m"), (126, "risk-window-6m"), (252, "risk-window-1y")];
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("risk-heading"));
+    ui.add_space(8.0);
+
+    if state.analysis.risk.is_empty() {
+        ui.label(tr("no-data-loaded"));
+        return;
+    }
+
+    let confidence = i18n::percent(config::VAR_CONFIDENCE, 0);
+    ui.label(tr_args(
+        "risk-intro",
+        &[
+            ("confidence", confidence.clone()),
+            ("tail", i18n::percent(config::TAIL_INDEX_FRACTION, 0)),
+            ("days", state.analysis_windows.short_vol.to_string()),
+        ],
+    ));
+    ui.add_space(8.0);
+
+    let mut drill_down = None;
+    egui::Grid::new("risk_table")
+        .striped(true)
+        .min_col_width(80.0)
+        .show(ui, |ui| {
+            ui.strong(tr("col-sector"));
+            ui.strong(tr_args("risk-col-var", &[("confidence", confidence.clone())]));
+            ui.strong(tr_args("risk-col-es", &[("confidence", confidence.clone())]));
+            ui.strong(tr("risk-col-drawdown"));
+            ui.strong(tr("risk-col-tail-index"));
+            ui.strong(tr_args("compare-short-vol", &[("days", state.analysis_windows.short_vol.to_string())]));
+            ui.strong(tr("risk-col-percentile"));
+            ui.end_row();
+
+            for risk in &state.analysis.risk {
+                let name = config::sector_etfs()
+                    .iter()
+                    .find(|s| s.symbol == risk.symbol)
+                    .map_or_else(|| tr("risk-unknown"), |s| s.name.clone());
+                if ui
+                    .link(&risk.symbol)
+                    .on_hover_text(tr_args("risk-open-sector", &[("name", name)]))
+                    .clicked()
+                {
+                    drill_down = Some(risk.symbol.clone());
+                }
+
+                ui.label(pct(risk.var, 2));
+                ui.label(pct(risk.expected_shortfall, 2));
+                ui.label(pct(risk.max_drawdown, 1));
+                match risk.tail_index {
+                    Some(alpha) => ui.colored_label(tail_color(alpha), i18n::number(alpha, 2)),
+                    None => ui.label("-"),
+                };
+                ui.label(pct(risk.current_vol, 1));
+                match risk.vol_percentile {
+                    Some(p) => ui.colored_label(percentile_color(p), tr_args("risk-percentile", &[("rank", i18n::number(p, 0))])),
+                    None => ui.label("-"),
+                };
+                ui.end_row();
+            }
+        });
+
+    ui.add_space(8.0);
+    ui.small(tr("risk-tail-note"));
+
+    ui.add_space(12.0);
+    render_risk_return_scatter(ui, state);
+
+    ui.add_space(12.0);
+    render_beta_panel(ui, state);
+
+    if let Some(symbol) = drill_down {
+        if let Some(idx) = state.market_data.sectors.iter().position(|s| s.symbol == symbol) {
+            state.selected_sector_idx = idx;
+            state.active_tab = Tab::SectorVol;
+        }
+    }
+}
+
+/// Rolling beta of one sector vs the benchmark, with the sector picked from a combo box
+fn render_beta_panel(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr_args(
+        "risk-beta-heading",
+        &[("days", config::ROLLING_BETA_WINDOW.to_string()), ("benchmark", config::benchmark_symbol().to_string())],
+    ));
+    if state.analysis.rolling_beta.is_empty() {
+        ui.label(tr("risk-beta-no-benchmark"));
+        return;
+    }
+
+    let sectors = &state.market_data.sectors;
+    state.beta_sector_idx = state.beta_sector_idx.min(sectors.len().saturating_sub(1));
+    ui.horizontal(|ui| {
+        ui.label(tr("risk-sector"));
+        let selected = sectors.get(state.beta_sector_idx).map_or("", |s| s.symbol.as_str());
+        egui::ComboBox::from_id_salt("beta_sector_combo")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                for (i, sector) in sectors.iter().enumerate() {
+                    let label = format!("{} - {}", sector.symbol, sector.name);
+                    ui.selectable_value(&mut state.beta_sector_idx, i, label);
+                }
+            });
+    });
+
+    let Some(sector) = sectors.get(state.beta_sector_idx) else { return };
+    let Some((_, betas)) = state.analysis.rolling_beta.iter().find(|(s, _)| *s == sector.symbol) else { return };
+    if betas.is_empty() {
+        ui.label(tr_args("risk-beta-short", &[("days", config::ROLLING_BETA_WINDOW.to_string())]));
+        return;
+    }
+    if let Some(latest) = betas.last().filter(|b| b.is_finite()) {
+        ui.label(tr_args(
+            "risk-beta-latest",
+            &[
+                ("beta", i18n::number(*latest, 2)),
+                ("one", i18n::percent(0.01, 0)),
+                ("move", i18n::percent(*latest / 100.0, 2)),
+                ("benchmark", config::benchmark_symbol().to_string()),
+                ("symbol", sector.symbol.clone()),
+            ],
+        ));
+    }
+
+    // Betas end on the sector's latest bar; label each point with its window's end date
+    let offset = sector.bars.len().saturating_sub(betas.len());
+    let x_labels: Vec<String> = sector.bars[offset..].iter().map(|b| i18n::date(b.date)).collect();
+    let data: Vec<[f64; 2]> = betas
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| b.is_finite())
+        .map(|(i, b)| [i as f64, *b])
+        .collect();
+    let beta_name = tr("col-beta");
+    let hover = [HoverSeries { name: &beta_name, data: &data, decimals: 2, suffix: "" }];
+
+    height_control(ui, &mut state.chart_heights.rolling_beta, &tr("risk-beta-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "rolling_beta_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("rolling_beta_plot")
+                .height(state.chart_heights.rolling_beta),
+        )
+            .x_axis_label(tr("axis-trading-day-plain"))
+            .y_axis_label(&beta_name)
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_labeled_x(&hover, &x_labels))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.hline(
+                HLine::new(1.0)
+                    .color(egui::Color32::from_rgb(150, 150, 150))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+            plot_ui.line(
+                Line::new(data.iter().copied().collect::<PlotPoints>())
+                    .name(&beta_name)
+                    .color(egui::Color32::from_rgb(100, 150, 255))
+                    .width(1.5),
+            );
+        },
+    );
+    ui.small(tr("risk-beta-note"));
+}
+
+/// Trailing return against realized vol over the same window, one point per sector,
+/// sized by the sector's current short/long vol ratio
+fn render_risk_return_scatter(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("risk-map-heading"));
+    ui.horizontal(|ui| {
+        ui.label(tr("risk-trailing-window"));
+        for (days, id) in RISK_RETURN_WINDOWS {
+            ui.selectable_value(&mut state.risk_return_window, days, tr(id));
+        }
+    });
+
+    let window = state.risk_return_window;
+    let points: Vec<(&str, [f64; 2], Option<f64>)> = state
+        .market_data
+        .sectors
+        .iter()
+        .filter_map(|s| {
+            let point = risk_return_point(s, window)?;
+            let ratio = state
+                .analysis
+                .volatility
+                .iter()
+                .find(|v| v.symbol == s.symbol)
+                .and_then(|v| v.vol_ratio.last().copied());
+            Some((s.symbol.as_str(), point, ratio))
+        })
+        .collect();
+    if points.is_empty() {
+        ui.label(tr_args("risk-map-short", &[("days", window.to_string())]));
+        return;
+    }
+
+    let mean_return = points.iter().map(|(_, p, _)| p[1]).sum::<f64>() / points.len() as f64;
+    let max_vol = points.iter().map(|(_, p, _)| p[0]).fold(0.0, f64::max);
+    let text_color = ui.visuals().text_color();
+
+    height_control(ui, &mut state.chart_heights.risk_return_scatter, &tr("risk-map-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "risk_return_plot",
+        chart_utils::default_plot_interaction(
+            Plot::new("risk_return_plot")
+                .height(state.chart_heights.risk_return_scatter)
+                .include_x(0.0)
+                .include_y(0.0),
+        )
+            .x_axis_label(tr("risk-axis-vol"))
+            .y_axis_label(tr_args("risk-axis-return", &[("days", window.to_string())]))
+            .legend(Legend::default())
+            .label_formatter(|name, point| {
+                if name.is_empty() {
+                    String::new()
+                } else {
+                    tr_args(
+                        "risk-map-point",
+                        &[
+                            ("name", name.to_string()),
+                            ("vol", i18n::percent(point.x / 100.0, 1)),
+                            ("return", tr_args("unit-percent", &[("value", i18n::signed(point.y, 1))])),
+                        ],
+                    )
+                }
+            }),
+        |plot_ui| {
+            // Sector average return, for a quick above/below read
+            plot_ui.line(
+                Line::new(vec![[0.0, mean_return], [max_vol * 1.1, mean_return]])
+                    .name(tr("risk-avg-return"))
+                    .color(egui::Color32::from_rgb(150, 150, 150))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+            for (symbol, point, ratio) in &points {
+                // Ratio 1.0 (vol steady) plots at the base size; rising vol grows the point
+                let radius = (6.0 * ratio.unwrap_or(1.0)).clamp(3.0, 16.0) as f32;
+                plot_ui.points(Points::new(vec![*point]).radius(radius).name(*symbol));
+                plot_ui.text(
+                    Text::new(PlotPoint::new(point[0], point[1]), *symbol)
+                        .anchor(egui::Align2::LEFT_BOTTOM)
+                        .color(text_color),
+                );
+            }
+        },
+    );
+    ui.small(tr("risk-map-note"));
+}
+
+/// `[realized vol %, trailing return %]` over the last `window` trading days
+fn risk_return_point(series: &SectorTimeSeries, window: usize) -> Option<[f64; 2]> {
+    let closes = series.close_prices();
+    let start = *closes.get(closes.len().checked_sub(window + 1)?)?;
+    let end = *closes.last()?;
+    let returns = series.log_returns();
+    let vol = *analysis::volatility::rolling_volatility(&returns[returns.len() - window..], window).last()?;
+    Some([vol * 100.0, (end / start - 1.0) * 100.0])
+}
+
+fn pct(value: Option<f64>, decimals: usize) -> String {
+    value.map_or("-".to_string(), |v| i18n::percent(v, decimals))
+}
+
+fn tail_color(alpha: f64) -> egui::Color32 {
+    if alpha < 3.0 {
+        egui::Color32::from_rgb(220, 50, 50)
+    } else if alpha < 4.0 {
+        egui::Color32::from_rgb(220, 180, 50)
+    } else {
+        egui::Color32::from_rgb(50, 180, 50)
+    }
+}
+
+fn percentile_color(p: f64) -> egui::Color32 {
+    if p >= 80.0 {
+        egui::Color32::from_rgb(220, 50, 50)
+    } else if p >= 40.0 {
+        egui::Color32::from_rgb(220, 180, 50)
+    } else {
+        egui::Color32::from_rgb(50, 180, 50)
+    }
+}
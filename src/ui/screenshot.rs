@@ -0,0 +1,259 @@
+//! Screenshot capture: the whole window, the active tab, or a region dragged out on
+//! screen, saved to disk or copied to the clipboard. Captures are requested with
+//! `ViewportCommand::Screenshot` and finished in `handle_event` when the image arrives.
+
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use eframe::egui;
+
+use crate::app::{AppState, Tab};
+use crate::data::models::{ScreenshotCompression, ScreenshotFileType, ScreenshotScope, ScreenshotSettings};
+use crate::ui::i18n::{tr, tr_args};
+
+/// Where a captured image goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScreenshotTarget {
+    #[default]
+    File,
+    Clipboard,
+}
+
+/// Carried through the viewport command as user data, so the finished image knows
+/// how to be cropped and where to go
+#[derive(Debug, Clone, Default)]
+struct ScreenshotRequest {
+    /// Area to keep, in UI points; `None` keeps the whole window
+    crop: Option<egui::Rect>,
+    target: ScreenshotTarget,
+    /// Save here instead of the configured save path
+    save_dir: Option<PathBuf>,
+    /// Tab to switch back to once captured, when the capture switched tabs
+    restore_tab: Option<Tab>,
+}
+
+/// Region selection in progress: the drag start once the user has pressed
+#[derive(Debug, Clone, Copy)]
+pub struct RegionSelect {
+    pub target: ScreenshotTarget,
+    pub start: Option<egui::Pos2>,
+}
+
+/// Menu and settings name of a capture scope
+pub fn scope_label(scope: ScreenshotScope) -> String {
+    tr(match scope {
+        ScreenshotScope::Window => "screenshot-scope-window",
+        ScreenshotScope::ActiveTab => "screenshot-scope-active-tab",
+        ScreenshotScope::Region => "screenshot-scope-region",
+    })
+}
+
+/// Capture using the configured scope. Region scope starts a drag selection first.
+pub fn capture(ctx: &egui::Context, state: &mut AppState, target: ScreenshotTarget) {
+    match state.screenshot_settings.scope {
+        ScreenshotScope::Window => send(ctx, None, target),
+        ScreenshotScope::ActiveTab => send(ctx, state.central_rect, target),
+        ScreenshotScope::Region => state.screenshot_region = Some(RegionSelect { target, start: None }),
+    }
+}
+
+/// Switch to the dashboard and save a whole-window capture of it into `dir`, switching
+/// back to the current tab afterwards
+pub fn capture_dashboard_to(ctx: &egui::Context, state: &mut AppState, dir: PathBuf) {
+    let restore_tab = (state.active_tab != Tab::Dashboard).then_some(state.active_tab);
+    state.active_tab = Tab::Dashboard;
+    let request = ScreenshotRequest { save_dir: Some(dir), restore_tab, ..Default::default() };
+    ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(request)));
+}
+
+fn send(ctx: &egui::Context, crop: Option<egui::Rect>, target: ScreenshotTarget) {
+    let request = ScreenshotRequest { crop, target, ..Default::default() };
+    ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(egui::UserData::new(request)));
+}
+
+/// Full-window overlay for dragging out a capture region; Escape cancels. The capture
+/// is requested on release, after which the overlay is gone from the captured frame.
+pub fn render_region_overlay(ctx: &egui::Context, state: &mut AppState) {
+    let Some(mut select) = state.screenshot_region else { return };
+    if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+        state.screenshot_region = None;
+        return;
+    }
+    let screen = ctx.screen_rect();
+    egui::Area::new(egui::Id::new("screenshot_region_overlay"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            let (rect, resp) = ui.allocate_exact_size(screen.size(), egui::Sense::drag());
+            let resp = resp.on_hover_cursor(egui::CursorIcon::Crosshair);
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, egui::Color32::from_black_alpha(60));
+            painter.text(
+                rect.center_top() + egui::vec2(0.0, 24.0),
+                egui::Align2::CENTER_TOP,
+                tr("screenshot-region-hint"),
+                egui::FontId::proportional(16.0),
+                egui::Color32::WHITE,
+            );
+
+            if resp.drag_started() {
+                select.start = resp.interact_pointer_pos();
+            }
+            let current = resp.interact_pointer_pos().or(ctx.pointer_latest_pos());
+            if let (Some(start), Some(end)) = (select.start, current) {
+                let region = egui::Rect::from_two_pos(start, end);
+                painter.rect_stroke(region, 0.0, egui::Stroke::new(2.0, egui::Color32::WHITE));
+                if resp.drag_stopped() {
+                    state.screenshot_region = None;
+                    if region.width() >= 4.0 && region.height() >= 4.0 {
+                        send(ctx, Some(region), select.target);
+                    }
+                    return;
+                }
+            }
+            state.screenshot_region = Some(select);
+        });
+}
+
+/// Finish a capture: crop it to the request and save or copy it. Returns the message
+/// to report, as an error if saving or copying failed.
+pub fn handle_event(
+    ctx: &egui::Context,
+    state: &mut AppState,
+    image: &egui::ColorImage,
+    user_data: &egui::UserData,
+) -> Result<String, String> {
+    let request = user_data
+        .data
+        .as_ref()
+        .and_then(|d| d.downcast_ref::<ScreenshotRequest>())
+        .cloned()
+        .unwrap_or_default();
+    if let Some(tab) = request.restore_tab {
+        state.active_tab = tab;
+    }
+    let cropped;
+    let image = match request.crop {
+        Some(rect) => {
+            cropped = image.region(&rect, Some(ctx.pixels_per_point()));
+            &cropped
+        }
+        None => image,
+    };
+    match request.target {
+        ScreenshotTarget::File => {
+            let mut settings = state.screenshot_settings.clone();
+            if let Some(dir) = request.save_dir {
+                settings.save_path = dir.to_string_lossy().into_owned();
+            }
+            save_screenshot(image, &settings)
+                .map(|path| tr_args("screenshot-saved", &[("path", path)]))
+                .map_err(|e| tr_args("screenshot-failed", &[("error", e)]))
+        }
+        ScreenshotTarget::Clipboard => copy_screenshot(image, state)
+            .map(|()| tr("screenshot-copied"))
+            .map_err(|e| tr_args("screenshot-copy-failed", &[("error", e)])),
+    }
+}
+
+/// Encode and write a screenshot to disk under `settings.save_path`.
+///
+/// The filename is `YYYYMMDD_HHMMSS.{ext}`. Returns the full path on success.
+fn save_screenshot(
+    image: &egui::ColorImage,
+    settings: &ScreenshotSettings,
+) -> Result<String, String> {
+    std::fs::create_dir_all(&settings.save_path)
+        .map_err(|e| format!("Failed to create directory '{}': {}", settings.save_path, e))?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let ext = match settings.file_type {
+        ScreenshotFileType::Png => "png",
+        ScreenshotFileType::Jpeg => "jpg",
+        ScreenshotFileType::Tiff => "tif",
+    };
+    let path = std::path::Path::new(&settings.save_path).join(format!("{timestamp}.{ext}"));
+
+    let bytes = encode(image, settings)?;
+    std::fs::write(&path, bytes)
+        .map_err(|e| format!("Failed to write file '{}': {}", path.display(), e))?;
+
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Put the screenshot on the clipboard as it would be saved: it is encoded with the
+/// configured format and compression and decoded again, so JPEG drops alpha and
+/// picks up its compression artifacts.
+fn copy_screenshot(image: &egui::ColorImage, state: &mut AppState) -> Result<(), String> {
+    let bytes = encode(image, &state.screenshot_settings)?;
+    let decoded = image::load_from_memory(&bytes)
+        .map_err(|e| format!("Failed to decode encoded image: {e}"))?
+        .to_rgba8();
+    let (width, height) = decoded.dimensions();
+
+    // Keep one clipboard handle alive: on Linux the contents vanish with the last handle
+    if state.clipboard.is_none() {
+        state.clipboard = Some(arboard::Clipboard::new().map_err(|e| e.to_string())?);
+    }
+    let clipboard = state.clipboard.as_mut().ok_or("Clipboard unavailable")?;
+    clipboard
+        .set_image(arboard::ImageData {
+            width: width as usize,
+            height: height as usize,
+            bytes: decoded.into_raw().into(),
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Encode with the configured file type and compression
+fn encode(image: &egui::ColorImage, settings: &ScreenshotSettings) -> Result<Vec<u8>, String> {
+    let width = image.size[0] as u32;
+    let height = image.size[1] as u32;
+    let pixels: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|c| [c.r(), c.g(), c.b(), c.a()])
+        .collect();
+    let rgba = image::RgbaImage::from_raw(width, height, pixels)
+        .ok_or_else(|| "Failed to create image buffer from pixel data".to_string())?;
+
+    let mut writer = Cursor::new(Vec::new());
+    match settings.file_type {
+        ScreenshotFileType::Png => {
+            use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+            let compression = match settings.compression {
+                ScreenshotCompression::None => CompressionType::Fast,
+                ScreenshotCompression::Low => CompressionType::Default,
+                ScreenshotCompression::High => CompressionType::Best,
+            };
+            let encoder = PngEncoder::new_with_quality(&mut writer, compression, FilterType::Sub);
+            image::DynamicImage::ImageRgba8(rgba)
+                .write_with_encoder(encoder)
+                .map_err(|e| format!("PNG encode failed: {e}"))?;
+        }
+        ScreenshotFileType::Jpeg => {
+            use image::codecs::jpeg::JpegEncoder;
+            let quality: u8 = match settings.compression {
+                ScreenshotCompression::None => 100,
+                ScreenshotCompression::Low => 80,
+                ScreenshotCompression::High => 50,
+            };
+            // JPEG does not support an alpha channel — convert to RGB first
+            let rgb = image::DynamicImage::ImageRgba8(rgba).to_rgb8();
+            let encoder = JpegEncoder::new_with_quality(&mut writer, quality);
+            image::DynamicImage::ImageRgb8(rgb)
+                .write_with_encoder(encoder)
+                .map_err(|e| format!("JPEG encode failed: {e}"))?;
+        }
+        ScreenshotFileType::Tiff => {
+            use image::codecs::tiff::TiffEncoder;
+            // TIFF compression is not directly controllable via this encoder; the
+            // compression setting is informational (visible in the settings tooltip).
+            let encoder = TiffEncoder::new(&mut writer);
+            image::DynamicImage::ImageRgba8(rgba)
+                .write_with_encoder(encoder)
+                .map_err(|e| format!("TIFF encode failed: {e}"))?;
+        }
+    }
+    Ok(writer.into_inner())
+}
@@ -0,0 +1,146 @@
+use eframe::egui;
+
+use crate::app::{AppState, Tab};
+use crate::config;
+use crate::ui::i18n::tr;
+
+/// Most matches listed under the search box
+const MAX_RESULTS: usize = 8;
+
+/// Where a search hit opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchTarget {
+    /// Index into `market_data.sectors`, opened in Sector Vol
+    Sector(usize),
+    /// Watchlist symbol, highlighted in the Watchlist tab
+    Watchlist(usize),
+}
+
+pub struct SearchHit {
+    pub symbol: String,
+    pub name: String,
+    pub target: SearchTarget,
+}
+
+/// Display name of a sector ETF symbol
+pub fn sector_name(symbol: &str) -> &'static str {
+    config::sector_etfs()
+        .iter()
+        .find(|s| s.symbol == symbol)
+        .map_or("Unknown", |s| s.name.as_str())
+}
+
+/// Case-insensitive match of `query` against a symbol or its name. An empty query
+/// matches everything.
+pub fn matches(query: &str, symbol: &str, name: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty() || symbol.to_lowercase().contains(&query) || name.to_lowercase().contains(&query)
+}
+
+/// Loaded symbols matching `query`, exact symbol matches first
+pub fn search(state: &AppState, query: &str) -> Vec<SearchHit> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut hits: Vec<SearchHit> = state
+        .market_data
+        .sectors
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| matches(query, &s.symbol, sector_name(&s.symbol)))
+        .map(|(i, s)| SearchHit {
+            symbol: s.symbol.clone(),
+            name: sector_name(&s.symbol).to_string(),
+            target: SearchTarget::Sector(i),
+        })
+        .collect();
+    hits.extend(
+        state
+            .watchlist
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| matches(query, s, ""))
+            .map(|(i, s)| SearchHit {
+                symbol: s.clone(),
+                name: tr("tab-watchlist"),
+                target: SearchTarget::Watchlist(i),
+            }),
+    );
+    let exact = query.trim().to_uppercase();
+    hits.sort_by_key(|h| h.symbol != exact);
+    hits
+}
+
+/// Open a search hit in its view
+pub fn open(state: &mut AppState, target: SearchTarget) {
+    match target {
+        SearchTarget::Sector(idx) => {
+            state.selected_sector_idx = idx;
+            state.active_tab = Tab::SectorVol;
+        }
+        SearchTarget::Watchlist(idx) => {
+            state.watchlist.highlight = state.watchlist.symbols.get(idx).cloned();
+            state.active_tab = Tab::Watchlist;
+        }
+    }
+}
+
+/// Quick-search box for the top bar. Ctrl+K (Cmd+K on macOS) focuses it; typing
+/// filters the dashboard table and lists matching symbols, Enter opens the first.
+pub fn render_search_box(ui: &mut egui::Ui, state: &mut AppState) {
+    let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::K);
+    let text_id = ui.id().with("quick_search");
+    let popup_id = ui.id().with("quick_search_popup");
+
+    if ui.input_mut(|i| i.consume_shortcut(&shortcut)) {
+        ui.memory_mut(|m| m.request_focus(text_id));
+    }
+
+    let response = ui.add(
+        egui::TextEdit::singleline(&mut state.search_query)
+            .id(text_id)
+            .hint_text(format!("🔍 Search ({})", ui.ctx().format_shortcut(&shortcut)))
+            .desired_width(160.0),
+    );
+    if response.gained_focus() || response.changed() {
+        ui.memory_mut(|m| m.open_popup(popup_id));
+    }
+
+    let hits = search(state, &state.search_query);
+    let mut chosen = None;
+    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+        chosen = hits.first().map(|h| h.target);
+    }
+    if ui.input(|i| i.key_pressed(egui::Key::Escape)) && response.has_focus() {
+        state.search_query.clear();
+        ui.memory_mut(|m| m.close_popup());
+    }
+
+    if !hits.is_empty() {
+        egui::popup_below_widget(
+            ui,
+            popup_id,
+            &response,
+            egui::PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                ui.set_min_width(220.0);
+                for hit in hits.iter().take(MAX_RESULTS) {
+                    let text = format!("{}  {}", hit.symbol, hit.name);
+                    if ui.selectable_label(false, text).clicked() {
+                        chosen = Some(hit.target);
+                    }
+                }
+                if hits.len() > MAX_RESULTS {
+                    ui.weak(format!("{} more…", hits.len() - MAX_RESULTS));
+                }
+            },
+        );
+    }
+
+    if let Some(target) = chosen {
+        open(state, target);
+        state.search_query.clear();
+        ui.memory_mut(|m| m.close_popup());
+    }
+}
@@ -1,22 +1,39 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use eframe::egui;
-use egui_plot::{Line, Plot, PlotPoints};
+use egui_plot::{Bar, BarChart, Line, Plot, PlotPoints, Points, Text};
 
+use crate::analysis::{drawdown, kurtosis, volatility, AnalysisResults};
+use crate::analysis::regimes::{self, CorrelationRegime};
 use crate::app::AppState;
 use crate::config;
-use crate::ui::chart_utils::{self, height_control, HoverSeries};
+use crate::data::events;
+use crate::data::models::{ChartEvent, EventKind, OhlcvBar, SectorTimeSeries, VolatilityMetrics};
+use crate::ui::chart_utils::{self, height_control, CachedSeries, EventMarker, HoverSeries, PlotCache, RibbonRow, RibbonSegment};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::metric_info::{self, Metric};
+use crate::ui::palette;
+
+/// Crosshair and X-axis link group shared by every stacked chart and regime ribbon in the view
+const SECTOR_CHART_GROUP: &str = "sector_charts";
+
+/// Line color of the benchmark overlay series
+const BENCHMARK_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 200, 200);
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Sector Volatility Analysis");
+    let windows = state.analysis_windows;
+    ui.heading(tr("sector-heading"));
     ui.add_space(8.0);
 
     if state.market_data.sectors.is_empty() {
-        ui.label("No data loaded. Click 'Refresh Data' to fetch market data.");
+        ui.label(tr("no-data-loaded"));
         return;
     }
 
     // Sector selector
     ui.horizontal(|ui| {
-        ui.label("Select Sector:");
+        ui.label(tr("sector-select"));
         egui::ComboBox::from_id_salt("sector_selector")
             .selected_text(
                 state
@@ -24,22 +41,20 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                     .sectors
                     .get(state.selected_sector_idx)
                     .map(|s| {
-                        let name = config::SECTOR_ETFS
+                        let name = config::sector_etfs()
                             .iter()
-                            .find(|(sym, _)| *sym == s.symbol)
-                            .map(|(_, n)| *n)
-                            .unwrap_or("Unknown");
+                            .find(|etf| etf.symbol == s.symbol)
+                            .map_or_else(|| tr("sector-unknown"), |etf| etf.name.clone());
                         format!("{} ({})", name, s.symbol)
                     })
-                    .unwrap_or_else(|| "Select...".to_string()),
+                    .unwrap_or_else(|| tr("sector-select-placeholder")),
             )
             .show_ui(ui, |ui| {
                 for (i, sector) in state.market_data.sectors.iter().enumerate() {
-                    let name = config::SECTOR_ETFS
+                    let name = config::sector_etfs()
                         .iter()
-                        .find(|(sym, _)| *sym == sector.symbol)
-                        .map(|(_, n)| *n)
-                        .unwrap_or("Unknown");
+                        .find(|etf| etf.symbol == sector.symbol)
+                        .map_or_else(|| tr("sector-unknown"), |etf| etf.name.clone());
                     ui.selectable_value(
                         &mut state.selected_sector_idx,
                         i,
@@ -47,168 +62,389 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
                     );
                 }
             });
+
+        ui.add_enabled(
+            state.market_data.benchmark.is_some(),
+            egui::Checkbox::new(
+                &mut state.sector_benchmark_overlay,
+                tr_args("sector-overlay", &[("symbol", config::benchmark_symbol().to_string())]),
+            ),
+        )
+        .on_hover_text(tr("sector-overlay-hover"));
+
+        if let Some(sector) = state.market_data.sectors.get(state.selected_sector_idx) {
+            let vol = state.analysis.volatility.iter().find(|v| v.symbol == sector.symbol);
+            chart_utils::export_csv_button(ui, &mut state.notices, &sector.symbol, || {
+                crate::data::export::sector_table(sector, vol)
+            });
+        }
     });
 
+    ui.add_space(8.0);
+    render_events_panel(ui, state);
     ui.add_space(8.0);
 
-    let sector = match state.market_data.sectors.get(state.selected_sector_idx) {
-        Some(s) => s,
-        None => return,
+    let full_sector = match state.market_data.sectors.get(state.selected_sector_idx) {
+        Some(s) if !s.bars.is_empty() => s,
+        _ => return,
     };
 
-    let vol_metrics = state
-        .analysis
-        .volatility
-        .iter()
-        .find(|v| v.symbol == sector.symbol);
+    // Everything below covers only the sub-period picked in the range brush
+    let n_bars = full_sector.bars.len();
+    let full_range = (0, n_bars.saturating_sub(1));
+    let mut range = state
+        .sector_range
+        .and_then(|(start, end)| date_range_indices(&full_sector.bars, start, end))
+        .unwrap_or(full_range);
+    ui.horizontal(|ui| {
+        ui.label(tr("sector-period"));
+        if let (Some(first), Some(last)) = (full_sector.bars.get(range.0), full_sector.bars.get(range.1)) {
+            ui.strong(tr_args(
+                "sector-period-range",
+                &[
+                    ("from", i18n::date(first.date)),
+                    ("to", i18n::date(last.date)),
+                    ("days", i18n::integer(range.1 - range.0 + 1)),
+                ],
+            ));
+        }
+        if ui.add_enabled(range != full_range, egui::Button::new(tr("sector-full-history")).small()).clicked() {
+            range = full_range;
+            state.sector_range = None;
+        }
+    });
+    let closes = state.plot_cache.get("sector_range_closes", &full_sector.symbol, || full_sector.close_prices());
+    if chart_utils::range_brush(ui, "sector_range", &closes, &mut range) {
+        state.sector_range = (range != full_range)
+            .then(|| (full_sector.bars[range.0].date, full_sector.bars[range.1].date));
+    }
+    ui.add_space(4.0);
+
+    // Ribbon colors and labels are resolved when the charts are built, so a palette or
+    // language switch rebuilds them
+    let charts = state.plot_cache.get(
+        "sector_charts",
+        (&full_sector.symbol, range, state.sector_benchmark_overlay, palette::mode(), i18n::locale()),
+        || {
+            sector_charts(
+                full_sector,
+                &state.analysis,
+                state.market_data.benchmark.as_ref(),
+                range,
+                windows.short_vol,
+                state.sector_benchmark_overlay,
+            )
+        },
+    );
+    let sector = &charts.sector;
+
+    ui.small(tr("sector-chart-help"));
+
+    let markers: Vec<EventMarker> = chart_utils::event_markers(&state.chart_events(), &charts.dates);
+
+    let overlay = charts.overlay.as_ref();
+    let bench_price_name = tr_args("sector-rebased", &[("symbol", config::benchmark_symbol().to_string())]);
+    let bench_vol_name = format!(
+        "{} {}",
+        config::benchmark_symbol(),
+        tr_args("sector-vol-series", &[("days", windows.short_vol.to_string())])
+    );
+    let trading_day = tr("axis-trading-day-plain");
 
     // Price chart
-    ui.collapsing("Price Chart", |ui| {
-        height_control(ui, &mut state.chart_heights.sector_price, "Price Chart Height");
+    ui.collapsing(tr("sector-price-chart"), |ui| {
+        height_control(ui, &mut state.chart_heights.sector_price, &tr("sector-price-height"));
 
-        let price_data: Vec<[f64; 2]> = sector
-            .bars
-            .iter()
-            .enumerate()
-            .map(|(i, b)| [i as f64, b.close])
-            .collect();
-        let prices: PlotPoints = price_data.iter().copied().collect();
-        let hover = [HoverSeries { name: &sector.symbol, data: &price_data, decimals: 2, suffix: "" }];
+        let mut hover = vec![HoverSeries { name: &sector.symbol, data: &charts.price.data, decimals: 2, suffix: "" }];
+        if let Some(o) = overlay {
+            hover.push(HoverSeries { name: &bench_price_name, data: &o.price.data, decimals: 2, suffix: "" });
+        }
 
-        chart_utils::plot_with_y_drag(
+        chart_utils::plot_with_crosshair(
             ui,
             "price_plot",
-            chart_utils::default_plot_interaction(
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
                 Plot::new("price_plot")
                     .height(state.chart_heights.sector_price),
+                SECTOR_CHART_GROUP,
             )
-                .x_axis_label("Trading Day")
-                .y_axis_label("Price ($)")
-                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+                .x_axis_label(&trading_day)
+                .y_axis_label(tr("sector-axis-price"))
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&hover, &markers))
                 .label_formatter(chart_utils::no_hover_label),
+            &hover,
             |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
                 plot_ui.line(
-                    Line::new(prices)
+                    Line::new(charts.price.plot_points())
                         .name(&sector.symbol)
                         .color(egui::Color32::from_rgb(100, 150, 255)),
                 );
+                if let Some(o) = overlay {
+                    plot_ui.line(
+                        Line::new(o.price.plot_points())
+                            .name(&bench_price_name)
+                            .color(BENCHMARK_COLOR)
+                            .style(egui_plot::LineStyle::dashed_loose()),
+                    );
+                }
+            },
+        );
+
+        chart_utils::regime_ribbon(ui, "regime_ribbon_price", SECTOR_CHART_GROUP, &charts.ribbon_rows);
+
+        // Volume subchart, x-linked to the price plot
+        height_control(ui, &mut state.chart_heights.sector_volume, &tr("sector-volume-height"));
+
+        let avg_name = tr_args("sector-volume-avg", &[("days", config::VOLUME_AVG_WINDOW.to_string())]);
+        let volume_name = tr("sector-volume");
+        let volume_hover = [
+            HoverSeries { name: &volume_name, data: &charts.volume.data, decimals: 2, suffix: "M" },
+            HoverSeries { name: &avg_name, data: &charts.volume_avg.data, decimals: 2, suffix: "M" },
+        ];
+
+        chart_utils::plot_with_crosshair(
+            ui,
+            "volume_plot",
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
+                Plot::new("volume_plot")
+                    .height(state.chart_heights.sector_volume),
+                SECTOR_CHART_GROUP,
+            )
+                .x_axis_label(&trading_day)
+                .y_axis_label(tr("sector-axis-volume"))
+                .include_y(0.0)
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&volume_hover, &markers))
+                .label_formatter(chart_utils::no_hover_label),
+            &volume_hover,
+            |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
+                plot_ui.bar_chart(BarChart::new(charts.volume_bars.clone()).name(&volume_name));
+                plot_ui.line(
+                    Line::new(charts.volume_avg.plot_points())
+                        .name(&avg_name)
+                        .color(egui::Color32::from_rgb(220, 180, 50)),
+                );
+            },
+        );
+
+        // Underwater chart: drawdown from the running peak, for the sector and the benchmark
+        height_control(ui, &mut state.chart_heights.sector_drawdown, &tr("sector-drawdown-height"));
+
+        let benchmark = state.market_data.benchmark.as_ref();
+        let bench_symbol = benchmark.map_or(config::benchmark_symbol(), |b| b.symbol.as_str());
+        let dd_hover = [
+            HoverSeries { name: &sector.symbol, data: &charts.drawdown.data, decimals: 1, suffix: "%" },
+            HoverSeries { name: bench_symbol, data: &charts.bench_drawdown.data, decimals: 1, suffix: "%" },
+        ];
+        let sector_color = egui::Color32::from_rgb(220, 50, 50);
+        let bench_color = egui::Color32::from_rgb(150, 150, 150);
+
+        chart_utils::plot_with_crosshair(
+            ui,
+            "drawdown_plot",
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
+                Plot::new("drawdown_plot")
+                    .height(state.chart_heights.sector_drawdown),
+                SECTOR_CHART_GROUP,
+            )
+                .x_axis_label(&trading_day)
+                .y_axis_label(tr("sector-axis-drawdown"))
+                .include_y(0.0)
+                .legend(egui_plot::Legend::default())
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&dd_hover, &markers))
+                .label_formatter(chart_utils::no_hover_label),
+            &dd_hover,
+            |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
+                plot_ui.line(
+                    Line::new(charts.drawdown.plot_points())
+                        .name(&sector.symbol)
+                        .color(sector_color)
+                        .fill(0.0),
+                );
+                if !charts.bench_drawdown.is_empty() {
+                    plot_ui.line(
+                        Line::new(charts.bench_drawdown.plot_points())
+                            .name(bench_symbol)
+                            .color(bench_color),
+                    );
+                }
+                // Mark each series' deepest trough
+                if let Some(mdd) = charts.max_drawdown {
+                    let point = [mdd.trough_idx as f64, mdd.depth * 100.0];
+                    plot_ui.points(Points::new(vec![point]).radius(4.0).color(sector_color));
+                    plot_ui.text(
+                        Text::new(point.into(), tr_args("sector-max-dd", &[("depth", i18n::percent(mdd.depth, 1))]))
+                            .anchor(egui::Align2::LEFT_TOP)
+                            .color(sector_color),
+                    );
+                }
+                if let Some(point) = charts.bench_trough {
+                    plot_ui.points(Points::new(vec![point]).radius(3.0).color(bench_color));
+                }
             },
         );
+
+        let describe = |symbol: &str, bars: &[OhlcvBar], mdd: drawdown::MaxDrawdown| {
+            let recovery = mdd
+                .recovery_idx
+                .and_then(|i| bars.get(i))
+                .map_or_else(
+                    || tr("sector-not-recovered"),
+                    |b| tr_args("sector-recovered", &[("date", i18n::date(b.date))]),
+                );
+            tr_args(
+                "sector-drawdown-summary",
+                &[
+                    ("symbol", symbol.to_string()),
+                    ("depth", i18n::percent(mdd.depth, 1)),
+                    ("peak", i18n::date(bars[mdd.peak_idx].date)),
+                    ("trough", i18n::date(bars[mdd.trough_idx].date)),
+                    ("recovery", recovery),
+                ],
+            )
+        };
+        if let Some(mdd) = charts.max_drawdown {
+            ui.label(describe(&sector.symbol, &sector.bars, mdd));
+        }
+        if let (Some(b), Some(mdd)) = (benchmark, charts.bench_max_drawdown) {
+            ui.label(describe(&b.symbol, &b.bars, mdd));
+        }
+    });
+
+    let period = (sector.symbol.as_str(), range);
+    ui.collapsing(tr("sector-return-distribution"), |ui| {
+        render_return_distribution(
+            ui,
+            &mut state.plot_cache,
+            period,
+            &charts.returns,
+            &mut state.return_dist_window,
+            &mut state.chart_heights.return_histogram,
+        );
+    });
+
+    ui.collapsing(tr("sector-qq-plot"), |ui| {
+        ui.small(tr("sector-qq-window"));
+        render_qq_plot(
+            ui,
+            &mut state.plot_cache,
+            period,
+            &charts.returns,
+            state.return_dist_window,
+            &mut state.chart_heights.qq_plot,
+        );
     });
 
     ui.add_space(8.0);
 
     // Volatility chart
-    if let Some(vm) = vol_metrics {
-        ui.label(format!(
-            "Showing {}-day and {}-day rolling volatility",
-            config::SHORT_VOL_WINDOW,
-            config::LONG_VOL_WINDOW
-        ));
-
-        let short_data: Vec<[f64; 2]> = vm
-            .short_window_vol
-            .iter()
-            .enumerate()
-            .map(|(i, v)| [i as f64, *v * 100.0])
-            .collect();
-        let short_points: PlotPoints = short_data.iter().copied().collect();
+    if let Some(vol) = &charts.vol {
+        ui.horizontal(|ui| {
+            ui.label(tr_args(
+                "sector-showing-vol",
+                &[("short", windows.short_vol.to_string()), ("long", windows.long_vol.to_string())],
+            ));
+            metric_info::badge(ui, state.explain_metrics, Metric::ParkinsonVol, &windows);
+        });
 
-        let long_data: Vec<[f64; 2]> = vm
-            .long_window_vol
-            .iter()
-            .enumerate()
-            .map(|(i, v)| [i as f64, *v * 100.0])
-            .collect();
-        let long_points: PlotPoints = long_data.iter().copied().collect();
-
-        let park_data: Vec<[f64; 2]> = vm
-            .parkinson_vol
-            .iter()
-            .enumerate()
-            .map(|(i, v)| [i as f64, *v * 100.0])
-            .collect();
-        let park_points: PlotPoints = park_data.iter().copied().collect();
-
-        let short_name = format!("{}D Vol", config::SHORT_VOL_WINDOW);
-        let long_name = format!("{}D Vol", config::LONG_VOL_WINDOW);
-        let vol_hover = [
-            HoverSeries { name: &short_name, data: &short_data, decimals: 1, suffix: "%" },
-            HoverSeries { name: &long_name, data: &long_data, decimals: 1, suffix: "%" },
-            HoverSeries { name: "Parkinson Vol", data: &park_data, decimals: 1, suffix: "%" },
+        let short_name = tr_args("sector-vol-series", &[("days", windows.short_vol.to_string())]);
+        let long_name = tr_args("sector-vol-series", &[("days", windows.long_vol.to_string())]);
+        let parkinson_name = tr("sector-parkinson");
+        let mut vol_hover = vec![
+            HoverSeries { name: &short_name, data: &vol.short.data, decimals: 1, suffix: "%" },
+            HoverSeries { name: &long_name, data: &vol.long.data, decimals: 1, suffix: "%" },
+            HoverSeries { name: &parkinson_name, data: &vol.parkinson.data, decimals: 1, suffix: "%" },
         ];
+        if let Some(o) = overlay {
+            vol_hover.push(HoverSeries { name: &bench_vol_name, data: &o.vol.data, decimals: 1, suffix: "%" });
+        }
 
-        height_control(ui, &mut state.chart_heights.sector_vol, "Volatility Chart Height");
-        chart_utils::plot_with_y_drag(
+        height_control(ui, &mut state.chart_heights.sector_vol, &tr("sector-vol-height"));
+        chart_utils::plot_with_crosshair(
             ui,
             "vol_plot",
-            chart_utils::default_plot_interaction(
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
                 Plot::new("vol_plot")
-                    .height(state.chart_heights.sector_vol),
+                    .height(state.chart_heights.sector_vol)
+                    .include_x(0.0),
+                SECTOR_CHART_GROUP,
             )
-                .x_axis_label("Trading Day (aligned)")
-                .y_axis_label("Annualized Vol (%)")
+                .x_axis_label(&trading_day)
+                .y_axis_label(tr("sector-axis-annualized-vol"))
                 .legend(egui_plot::Legend::default())
-                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&vol_hover))
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&vol_hover, &markers))
                 .label_formatter(chart_utils::no_hover_label),
+            &vol_hover,
             |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
                 plot_ui.line(
-                    Line::new(short_points)
-                        .name(format!("{}D Vol", config::SHORT_VOL_WINDOW))
+                    Line::new(vol.short.plot_points())
+                        .name(short_name.as_str())
                         .color(egui::Color32::from_rgb(255, 100, 100)),
                 );
                 plot_ui.line(
-                    Line::new(long_points)
-                        .name(format!("{}D Vol", config::LONG_VOL_WINDOW))
+                    Line::new(vol.long.plot_points())
+                        .name(long_name.as_str())
                         .color(egui::Color32::from_rgb(100, 100, 255)),
                 );
                 plot_ui.line(
-                    Line::new(park_points)
-                        .name("Parkinson Vol")
+                    Line::new(vol.parkinson.plot_points())
+                        .name(&parkinson_name)
                         .color(egui::Color32::from_rgb(100, 220, 100)),
                 );
+                if let Some(o) = overlay {
+                    plot_ui.line(
+                        Line::new(o.vol.plot_points())
+                            .name(&bench_vol_name)
+                            .color(BENCHMARK_COLOR)
+                            .style(egui_plot::LineStyle::dashed_loose()),
+                    );
+                }
             },
         );
 
+        chart_utils::regime_ribbon(ui, "regime_ribbon_vol", SECTOR_CHART_GROUP, &charts.ribbon_rows);
+
         // Vol ratio chart
         ui.add_space(8.0);
-        ui.label("Volatility Ratio (Short / Long) - above 1.0 indicates rising vol regime");
-
-        let ratio_data: Vec<[f64; 2]> = vm
-            .vol_ratio
-            .iter()
-            .enumerate()
-            .map(|(i, v)| [i as f64, *v])
-            .collect();
-        let ratio_points: PlotPoints = ratio_data.iter().copied().collect();
-
-        let baseline: PlotPoints = PlotPoints::from_iter(
-            (0..vm.vol_ratio.len()).map(|i| [i as f64, 1.0]),
-        );
+        let ratio_label = ui.label(tr("sector-ratio-heading"));
+        metric_info::explain(ratio_label, state.explain_metrics, Metric::VolRatio, &windows);
 
-        let ratio_hover = [HoverSeries { name: "Vol Ratio", data: &ratio_data, decimals: 2, suffix: "" }];
+        let ratio_name = tr("col-vol-ratio");
+        let ratio_hover = [HoverSeries { name: &ratio_name, data: &vol.ratio.data, decimals: 2, suffix: "" }];
 
-        height_control(ui, &mut state.chart_heights.sector_ratio, "Vol Ratio Chart Height");
-        chart_utils::plot_with_y_drag(
+        height_control(ui, &mut state.chart_heights.sector_ratio, &tr("sector-ratio-height"));
+        chart_utils::plot_with_crosshair(
             ui,
             "ratio_plot",
-            chart_utils::default_plot_interaction(
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
                 Plot::new("ratio_plot")
-                    .height(state.chart_heights.sector_ratio),
+                    .height(state.chart_heights.sector_ratio)
+                    .include_x(0.0),
+                SECTOR_CHART_GROUP,
             )
-                .x_axis_label("Trading Day (aligned)")
-                .y_axis_label("Vol Ratio")
-                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&ratio_hover))
+                .x_axis_label(&trading_day)
+                .y_axis_label(&ratio_name)
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&ratio_hover, &markers))
                 .label_formatter(chart_utils::no_hover_label),
+            &ratio_hover,
             |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
                 plot_ui.line(
-                    Line::new(ratio_points)
-                        .name("Vol Ratio")
+                    Line::new(vol.ratio.plot_points())
+                        .name(&ratio_name)
                         .color(egui::Color32::from_rgb(255, 180, 50)),
                 );
                 plot_ui.line(
-                    Line::new(baseline)
-                        .name("Baseline (1.0)")
+                    Line::new(vol.baseline.plot_points())
+                        .name(tr("sector-baseline"))
                         .color(egui::Color32::from_rgb(150, 150, 150))
                         .style(egui_plot::LineStyle::dashed_dense()),
                 );
@@ -220,23 +456,581 @@ pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
         ui.separator();
         ui.add_space(4.0);
 
-        if let (Some(sv), Some(lv), Some(vr)) = (
-            vm.short_window_vol.last(),
-            vm.long_window_vol.last(),
-            vm.vol_ratio.last(),
-        ) {
+        if let Some((sv, lv, vr)) = vol.latest {
             ui.horizontal(|ui| {
-                ui.label(format!(
-                    "Latest: {}D Vol = {:.1}% | {}D Vol = {:.1}% | Ratio = {:.2}",
-                    config::SHORT_VOL_WINDOW,
-                    sv * 100.0,
-                    config::LONG_VOL_WINDOW,
-                    lv * 100.0,
-                    vr
+                ui.label(tr_args(
+                    "sector-latest-vol",
+                    &[
+                        ("short", windows.short_vol.to_string()),
+                        ("short_vol", i18n::percent(sv, 1)),
+                        ("long", windows.long_vol.to_string()),
+                        ("long_vol", i18n::percent(lv, 1)),
+                        ("ratio", i18n::number(vr, 2)),
+                    ],
                 ));
             });
         }
     } else {
-        ui.label("No volatility data computed for this sector yet.");
+        ui.label(tr("sector-no-vol"));
     }
+
+    // Custom indicator chart
+    if charts.custom.is_empty() && state.analysis.custom_errors.is_empty() {
+        return;
+    }
+    ui.add_space(8.0);
+    ui.collapsing(tr("sector-custom-indicators"), |ui| {
+        for (name, e) in &state.analysis.custom_errors {
+            ui.colored_label(palette::negative(), format!("{}: {}", name, e));
+        }
+        if charts.custom.is_empty() {
+            return;
+        }
+        let custom_hover: Vec<HoverSeries> = charts
+            .custom
+            .iter()
+            .map(|(name, series)| HoverSeries { name, data: &series.data, decimals: 2, suffix: "" })
+            .collect();
+
+        height_control(ui, &mut state.chart_heights.sector_custom, &tr("sector-custom-height"));
+        chart_utils::plot_with_crosshair(
+            ui,
+            "custom_indicator_plot",
+            SECTOR_CHART_GROUP,
+            chart_utils::linked_x_interaction(
+                Plot::new("custom_indicator_plot")
+                    .height(state.chart_heights.sector_custom)
+                    .include_x(0.0),
+                SECTOR_CHART_GROUP,
+            )
+                .x_axis_label(&trading_day)
+                .legend(egui_plot::Legend::default())
+                .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter_with_events(&custom_hover, &markers))
+                .label_formatter(chart_utils::no_hover_label),
+            &custom_hover,
+            |plot_ui| {
+                chart_utils::draw_event_markers(plot_ui, &markers);
+                for (name, series) in &charts.custom {
+                    plot_ui.line(Line::new(series.plot_points()).name(name));
+                }
+            },
+        );
+    });
+}
+
+/// Series for the stacked charts of one sector over one period, built when the
+/// sector, period, palette or analysis changes and reused on every other frame
+struct SectorCharts {
+    /// The sector's bars within the period
+    sector: SectorTimeSeries,
+    dates: Vec<NaiveDate>,
+    /// Daily log returns over the period, for the distribution panels
+    returns: Vec<f64>,
+    ribbon_rows: Vec<RibbonRow>,
+    overlay: Option<BenchmarkOverlay>,
+    price: CachedSeries,
+    volume: CachedSeries,
+    volume_bars: Vec<Bar>,
+    volume_avg: CachedSeries,
+    drawdown: CachedSeries,
+    max_drawdown: Option<drawdown::MaxDrawdown>,
+    /// Benchmark drawdown placed on the sector's trading days by date
+    bench_drawdown: CachedSeries,
+    bench_max_drawdown: Option<drawdown::MaxDrawdown>,
+    /// The benchmark's deepest trough on the sector's X axis
+    bench_trough: Option<[f64; 2]>,
+    vol: Option<VolCharts>,
+    custom: Vec<(String, CachedSeries)>,
+}
+
+/// Rolling vol series of a sector, offset onto the price chart's X axis
+struct VolCharts {
+    short: CachedSeries,
+    long: CachedSeries,
+    parkinson: CachedSeries,
+    ratio: CachedSeries,
+    baseline: CachedSeries,
+    /// Latest short vol, long vol and ratio
+    latest: Option<(f64, f64, f64)>,
+}
+
+fn sector_charts(
+    full_sector: &SectorTimeSeries,
+    analysis: &AnalysisResults,
+    benchmark: Option<&SectorTimeSeries>,
+    range: (usize, usize),
+    short_window: usize,
+    show_overlay: bool,
+) -> SectorCharts {
+    let n_bars = full_sector.bars.len();
+    let sector = SectorTimeSeries {
+        bars: full_sector.bars[range.0..=range.1].to_vec(),
+        ..full_sector.clone()
+    };
+    let vol_metrics = analysis
+        .volatility
+        .iter()
+        .find(|v| v.symbol == sector.symbol)
+        .map(|vm| VolatilityMetrics {
+            symbol: vm.symbol.clone(),
+            dates: trim_aligned(&vm.dates, n_bars, range),
+            short_window_vol: trim_aligned(&vm.short_window_vol, n_bars, range),
+            long_window_vol: trim_aligned(&vm.long_window_vol, n_bars, range),
+            parkinson_vol: trim_aligned(&vm.parkinson_vol, n_bars, range),
+            vol_ratio: trim_aligned(&vm.vol_ratio, n_bars, range),
+        });
+    let rolling_corr = trim_aligned(&analysis.rolling_avg_correlation, n_bars, range);
+    let ribbon_rows = regime_rows(
+        &sector,
+        vol_metrics.as_ref().map(|vm| vm.short_window_vol.as_slice()),
+        &rolling_corr,
+    );
+    let overlay = benchmark
+        .filter(|_| show_overlay)
+        .map(|bench| benchmark_overlay(&sector, bench, short_window));
+
+    let price = sector
+        .bars
+        .iter()
+        .enumerate()
+        .map(|(i, b)| [i as f64, b.close])
+        .collect();
+    let volume: CachedSeries = sector
+        .bars
+        .iter()
+        .enumerate()
+        .map(|(i, b)| [i as f64, b.volume as f64 / 1e6])
+        .collect();
+    let volume_bars = sector
+        .bars
+        .iter()
+        .zip(&volume.data)
+        .map(|(b, p)| {
+            let color = if b.close >= b.open {
+                egui::Color32::from_rgb(50, 180, 50)
+            } else {
+                egui::Color32::from_rgb(220, 50, 50)
+            };
+            Bar::new(p[0], p[1]).width(0.8).fill(color)
+        })
+        .collect();
+    let volume_avg = volume
+        .data
+        .windows(config::VOLUME_AVG_WINDOW)
+        .map(|w| [w[w.len() - 1][0], w.iter().map(|p| p[1]).sum::<f64>() / w.len() as f64])
+        .collect();
+
+    let closes = sector.close_prices();
+    let drawdown = drawdown::drawdown_series(&closes)
+        .iter()
+        .enumerate()
+        .map(|(i, d)| [i as f64, d * 100.0])
+        .collect();
+    let max_drawdown = drawdown::max_drawdown(&closes);
+
+    let on_sector_axis = |date: NaiveDate| sector.bars.binary_search_by_key(&date, |b| b.date).ok();
+    let (bench_drawdown, bench_max_drawdown) = benchmark
+        .map(|b| {
+            let bench_closes = b.close_prices();
+            let dd = drawdown::drawdown_series(&bench_closes);
+            let series = b
+                .bars
+                .iter()
+                .zip(&dd)
+                .filter_map(|(bar, d)| Some([on_sector_axis(bar.date)? as f64, d * 100.0]))
+                .collect();
+            (series, drawdown::max_drawdown(&bench_closes))
+        })
+        .unwrap_or_default();
+    let bench_trough = benchmark.zip(bench_max_drawdown).and_then(|(b, mdd)| {
+        let i = on_sector_axis(b.bars.get(mdd.trough_idx)?.date)?;
+        Some([i as f64, mdd.depth * 100.0])
+    });
+
+    let vol = vol_metrics.map(|vm| {
+        // Vol series start `windows.long_vol` bars in; offset them onto the price chart's X axis
+        let offset = sector.bars.len().saturating_sub(vm.short_window_vol.len()) as f64;
+        let on_axis = |values: &[f64], scale: f64| -> CachedSeries {
+            values.iter().enumerate().map(|(i, v)| [offset + i as f64, v * scale]).collect()
+        };
+        VolCharts {
+            short: on_axis(&vm.short_window_vol, 100.0),
+            long: on_axis(&vm.long_window_vol, 100.0),
+            parkinson: on_axis(&vm.parkinson_vol, 100.0),
+            ratio: on_axis(&vm.vol_ratio, 1.0),
+            baseline: (0..vm.vol_ratio.len()).map(|i| [offset + i as f64, 1.0]).collect(),
+            latest: match (vm.short_window_vol.last(), vm.long_window_vol.last(), vm.vol_ratio.last()) {
+                (Some(&sv), Some(&lv), Some(&vr)) => Some((sv, lv, vr)),
+                _ => None,
+            },
+        }
+    });
+
+    let custom = analysis
+        .custom
+        .iter()
+        .filter(|c| c.symbol == sector.symbol)
+        .map(|c| {
+            let values = trim_aligned(&c.values, n_bars, range);
+            let offset = sector.bars.len().saturating_sub(values.len()) as f64;
+            let series = values.iter().enumerate().map(|(i, v)| [offset + i as f64, *v]).collect();
+            (c.name.clone(), series)
+        })
+        .collect();
+
+    SectorCharts {
+        dates: sector.dates(),
+        returns: sector.log_returns(),
+        sector,
+        ribbon_rows,
+        overlay,
+        price,
+        volume,
+        volume_bars,
+        volume_avg,
+        drawdown,
+        max_drawdown,
+        bench_drawdown,
+        bench_max_drawdown,
+        bench_trough,
+        vol,
+        custom,
+    }
+}
+
+/// Trailing windows offered for the return distribution panels (trading days; `None` = all)
+const RETURN_DIST_WINDOWS: [(Option<usize>, &str); 4] =
+    [(Some(63), "risk-window-3m"), (Some(126), "risk-window-6m"), (Some(252), "risk-window-1y"), (None, "sector-window-all")];
+
+/// The trailing `window` of `returns`
+fn window_returns(returns: &[f64], window: Option<usize>) -> &[f64] {
+    &returns[returns.len().saturating_sub(window.unwrap_or(usize::MAX))..]
+}
+
+/// Histogram bars, fitted normal curve and moments of one window's returns
+struct ReturnDistribution {
+    hist: Vec<[f64; 2]>,
+    bars: Vec<Bar>,
+    normal: CachedSeries,
+    count: usize,
+    mean: f64,
+    std: f64,
+    skew: f64,
+    excess_kurtosis: f64,
+}
+
+impl ReturnDistribution {
+    fn new(returns: &[f64]) -> Self {
+        // Plot in percent; densities scale by 1/100 so the bars still integrate to one
+        let to_pct = |p: &[f64; 2]| [p[0] * 100.0, p[1] / 100.0];
+        let bins = kurtosis::histogram(returns, 40);
+        let bin_width = bins.get(1).map_or(1.0, |b| b[0] - bins[0][0]) * 100.0;
+        let hist: Vec<[f64; 2]> = bins.iter().map(to_pct).collect();
+        let bars = hist.iter().map(|p| Bar::new(p[0], p[1]).width(bin_width * 0.95)).collect();
+        let normal = kurtosis::normal_pdf_curve(returns, 200).iter().map(to_pct).collect();
+
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let std = (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n).sqrt();
+        Self {
+            hist,
+            bars,
+            normal,
+            count: returns.len(),
+            mean,
+            std,
+            skew: kurtosis::skewness(returns),
+            excess_kurtosis: kurtosis::excess_kurtosis(returns),
+        }
+    }
+}
+
+/// Histogram of daily log returns over the selected trailing window, with the fitted
+/// normal density and the window's moments. `period` names the sector and bar range
+/// `returns` covers.
+fn render_return_distribution(
+    ui: &mut egui::Ui,
+    cache: &mut PlotCache,
+    period: (&str, (usize, usize)),
+    returns: &[f64],
+    window: &mut Option<usize>,
+    height: &mut f32,
+) {
+    ui.horizontal(|ui| {
+        ui.label(tr("corr-window"));
+        for (days, id) in RETURN_DIST_WINDOWS {
+            ui.selectable_value(window, days, tr(id));
+        }
+    });
+
+    let returns = window_returns(returns, *window);
+    if returns.len() < 20 {
+        ui.label(tr("sector-not-enough-returns"));
+        return;
+    }
+    let dist = cache.get("return_distribution", (period, *window), || ReturnDistribution::new(returns));
+    let (empirical_name, normal_name) = (tr("sector-empirical"), tr("sector-normal"));
+    let hover = [
+        HoverSeries { name: &empirical_name, data: &dist.hist, decimals: 2, suffix: "" },
+        HoverSeries { name: &normal_name, data: &dist.normal.data, decimals: 2, suffix: "" },
+    ];
+
+    height_control(ui, height, &tr("sector-histogram-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "return_histogram",
+        chart_utils::default_plot_interaction(Plot::new("return_histogram").height(*height))
+            .x_axis_label(tr("kurt-axis-log-return"))
+            .y_axis_label(tr("kurt-axis-density"))
+            .include_y(0.0)
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(dist.bars.clone()).name(&empirical_name).color(egui::Color32::from_rgb(100, 150, 255)));
+            plot_ui.line(
+                Line::new(dist.normal.plot_points())
+                    .name(tr("kurt-normal-fit"))
+                    .color(egui::Color32::from_rgb(220, 180, 50))
+                    .width(2.0),
+            );
+        },
+    );
+
+    ui.horizontal(|ui| {
+        ui.label(tr_args("sector-return-count", &[("count", i18n::integer(dist.count))]));
+        ui.separator();
+        ui.label(tr_args("sector-mean", &[("value", format!("{}%", i18n::signed(dist.mean * 100.0, 3)))]));
+        ui.separator();
+        ui.label(tr_args("sector-std-dev", &[("value", i18n::percent(dist.std, 3))]));
+        ui.separator();
+        ui.label(tr_args("sector-skew", &[("value", i18n::signed(dist.skew, 3))]));
+        ui.separator();
+        ui.label(tr_args("sector-excess-kurtosis", &[("value", i18n::signed(dist.excess_kurtosis, 3))]));
+    });
+}
+
+/// Standardized sample quantiles of the window's returns against normal quantiles,
+/// with the y = x reference line a normal sample would follow
+fn render_qq_plot(
+    ui: &mut egui::Ui,
+    cache: &mut PlotCache,
+    period: (&str, (usize, usize)),
+    returns: &[f64],
+    window: Option<usize>,
+    height: &mut f32,
+) {
+    let qq_data = cache.get("qq_plot", (period, window), || kurtosis::qq_points(window_returns(returns, window)));
+    let (Some(first), Some(last)) = (qq_data.first(), qq_data.last()) else {
+        ui.label(tr("sector-not-enough-returns"));
+        return;
+    };
+    let reference = [[first[0], first[0]], [last[0], last[0]]];
+    let sample_name = tr("sector-qq-sample");
+    let hover = [HoverSeries { name: &sample_name, data: &qq_data, decimals: 2, suffix: "" }];
+
+    height_control(ui, height, &tr("sector-qq-height"));
+    chart_utils::plot_with_y_drag(
+        ui,
+        "qq_plot",
+        chart_utils::default_plot_interaction(Plot::new("qq_plot").height(*height))
+            .x_axis_label(tr("sector-axis-normal-quantile"))
+            .y_axis_label(tr("sector-axis-sample-quantile"))
+            .legend(egui_plot::Legend::default())
+            .coordinates_formatter(chart_utils::HOVER_CORNER, chart_utils::hover_formatter(&hover))
+            .label_formatter(chart_utils::no_hover_label),
+        |plot_ui| {
+            plot_ui.line(
+                Line::new(PlotPoints::from_iter(reference))
+                    .name(tr("sector-qq-reference"))
+                    .color(egui::Color32::from_rgb(150, 150, 150))
+                    .style(egui_plot::LineStyle::dashed_dense()),
+            );
+            plot_ui.points(
+                Points::new(PlotPoints::from_iter(qq_data.iter().copied()))
+                    .name(period.0)
+                    .radius(2.0)
+                    .color(egui::Color32::from_rgb(100, 150, 255)),
+            );
+        },
+    );
+    ui.small(tr("sector-qq-note"));
+}
+
+/// Event marker filters plus the list and editor for user annotations
+fn render_events_panel(ui: &mut egui::Ui, state: &mut AppState) {
+    let open = chart_utils::section_open(&state.open_sections, "events", false);
+    let response = egui::CollapsingHeader::new(tr("sector-events"))
+        .id_salt("sector_events")
+        .open(Some(open)).show(ui, |ui| {
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr("sector-events-show"));
+            for kind in EventKind::ALL {
+                let mut shown = !state.events.hidden.contains(&kind);
+                let text = egui::RichText::new(chart_utils::event_kind_label(kind)).color(chart_utils::event_color(kind));
+                if ui.checkbox(&mut shown, text).changed() {
+                    if shown {
+                        state.events.hidden.retain(|k| *k != kind);
+                    } else {
+                        state.events.hidden.push(kind);
+                    }
+                }
+            }
+        });
+        ui.small(tr("sector-events-note"));
+
+        ui.add_space(4.0);
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            let draft = &mut state.events;
+            ui.label(tr("sector-events-add-label"));
+            ui.add(egui::TextEdit::singleline(&mut draft.draft_date).hint_text("YYYY-MM-DD").desired_width(90.0));
+            egui::ComboBox::from_id_salt("annotation_kind")
+                .selected_text(chart_utils::event_kind_label(draft.draft_kind))
+                .show_ui(ui, |ui| {
+                    for kind in [EventKind::Note, EventKind::Earnings, EventKind::Fomc, EventKind::Cpi] {
+                        ui.selectable_value(&mut draft.draft_kind, kind, chart_utils::event_kind_label(kind));
+                    }
+                });
+            ui.add(egui::TextEdit::singleline(&mut draft.draft_label).hint_text(tr("sector-events-label-hint")).desired_width(200.0));
+            if ui.button(tr("sector-events-add")).clicked() {
+                match chrono::NaiveDate::parse_from_str(draft.draft_date.trim(), "%Y-%m-%d") {
+                    Ok(date) if !draft.draft_label.trim().is_empty() => {
+                        state.annotations.push(ChartEvent {
+                            date,
+                            kind: draft.draft_kind,
+                            label: draft.draft_label.trim().to_string(),
+                        });
+                        draft.draft_label.clear();
+                        draft.draft_error = None;
+                        changed = true;
+                    }
+                    Ok(_) => draft.draft_error = Some(tr("sector-events-need-label")),
+                    Err(_) => draft.draft_error = Some(tr("sector-events-bad-date")),
+                }
+            }
+        });
+        if let Some(err) = &state.events.draft_error {
+            ui.colored_label(egui::Color32::from_rgb(220, 50, 50), err);
+        }
+
+        let mut remove = None;
+        for (i, a) in state.annotations.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.colored_label(chart_utils::event_color(a.kind), format!("{} [{}] {}", i18n::date(a.date), chart_utils::event_kind_label(a.kind), a.label));
+                if ui.small_button("🗑").on_hover_text(tr("sector-events-delete")).clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+        if let Some(i) = remove {
+            state.annotations.remove(i);
+            changed = true;
+        }
+
+        if changed {
+            state.annotations.sort_by_key(|a| a.date);
+            if let Err(e) = events::save_annotations(&state.annotations) {
+                state.events.draft_error = Some(e);
+            }
+        }
+    });
+    chart_utils::track_section(&mut state.open_sections, "events", open, &response);
+}
+
+/// First and last bar index within `start..=end`, if that covers at least two bars
+fn date_range_indices(bars: &[OhlcvBar], start: NaiveDate, end: NaiveDate) -> Option<(usize, usize)> {
+    let first = bars.partition_point(|b| b.date < start);
+    let last = bars.partition_point(|b| b.date <= end).checked_sub(1)?;
+    (first < last).then_some((first, last))
+}
+
+/// The part of a series whose last value falls on bar `n - 1` that lies on bars
+/// `range.0..=range.1`; the result ends on the last bar of the range
+fn trim_aligned<T: Clone>(values: &[T], n: usize, range: (usize, usize)) -> Vec<T> {
+    let lead = n.saturating_sub(values.len());
+    let start = range.0.saturating_sub(lead).min(values.len());
+    let end = (range.1 + 1).saturating_sub(lead).clamp(start, values.len());
+    values[start..end].to_vec()
+}
+
+/// Benchmark series placed on a sector's trading-day X axis
+struct BenchmarkOverlay {
+    /// Close rebased so it starts at the sector's first close
+    price: CachedSeries,
+    /// Short-window annualized vol (%)
+    vol: CachedSeries,
+}
+
+/// Align the benchmark to the sector's bars by date; days the benchmark lacks are skipped
+fn benchmark_overlay(sector: &SectorTimeSeries, benchmark: &SectorTimeSeries, short_window: usize) -> BenchmarkOverlay {
+    let bench_dates = benchmark.dates();
+    let closes: HashMap<NaiveDate, f64> = bench_dates.iter().copied().zip(benchmark.close_prices()).collect();
+    let short_vol = volatility::rolling_volatility(&benchmark.log_returns(), short_window);
+    // Vol values end on the last bar, like the sector's own vol series
+    let vol_offset = bench_dates.len().saturating_sub(short_vol.len());
+    let vols: HashMap<NaiveDate, f64> = bench_dates[vol_offset..].iter().copied().zip(short_vol).collect();
+
+    let first = sector.bars.iter().find_map(|b| closes.get(&b.date).map(|c| (b.close, *c)));
+    let (mut price, mut vol) = (Vec::new(), Vec::new());
+    for (i, bar) in sector.bars.iter().enumerate() {
+        if let (Some((sector_first, bench_first)), Some(close)) = (first, closes.get(&bar.date)) {
+            price.push([i as f64, close / bench_first * sector_first]);
+        }
+        if let Some(v) = vols.get(&bar.date) {
+            vol.push([i as f64, v * 100.0]);
+        }
+    }
+    BenchmarkOverlay { price: CachedSeries::new(price), vol: CachedSeries::new(vol) }
+}
+
+/// Ribbon rows for the sector's vol regime (21D vol against its own terciles) and the
+/// cross-sector correlation regime, positioned on the sector's trading days. Both
+/// series end on the latest bar.
+fn regime_rows(sector: &SectorTimeSeries, short_vol: Option<&[f64]>, rolling_corr: &[f64]) -> Vec<RibbonRow> {
+    let mut rows = Vec::new();
+    if let Some(vols) = short_vol {
+        rows.push(RibbonRow {
+            name: tr("sector-ribbon-vol"),
+            segments: regime_segments(
+                &sector.bars,
+                &regimes::classify_vol_regimes(vols),
+                chart_utils::vol_regime_label,
+                chart_utils::vol_regime_color,
+            ),
+        });
+    }
+    let corr_regimes: Vec<CorrelationRegime> = rolling_corr.iter().map(|&c| CorrelationRegime::classify(c)).collect();
+    rows.push(RibbonRow {
+        name: tr("sector-ribbon-corr"),
+        segments: regime_segments(
+            &sector.bars,
+            &corr_regimes,
+            chart_utils::correlation_regime_label,
+            chart_utils::correlation_regime_color,
+        ),
+    });
+    rows
+}
+
+/// Ribbon segments for a regime series whose last value falls on the last bar
+fn regime_segments<R: Copy + PartialEq>(
+    bars: &[OhlcvBar],
+    regimes: &[R],
+    label: impl Fn(R) -> String,
+    color: impl Fn(R) -> egui::Color32,
+) -> Vec<RibbonSegment> {
+    let offset = bars.len().saturating_sub(regimes.len());
+    regimes::regime_spans(regimes)
+        .into_iter()
+        .map(|(r, start, end)| RibbonSegment {
+            start: (offset + start) as f64 - 0.5,
+            end: (offset + end) as f64 - 0.5,
+            color: color(r),
+            label: match bars.get(offset + start) {
+                Some(b) => tr_args("sector-regime-since", &[("regime", label(r)), ("date", i18n::date(b.date))]),
+                None => label(r),
+            },
+        })
+        .collect()
 }
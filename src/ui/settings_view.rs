@@ -2,22 +2,224 @@ use std::sync::{Arc, Mutex};
 
 use eframe::egui;
 
-use crate::app::AppState;
-use crate::data::models::{ScreenshotCompression, ScreenshotFileType};
+use crate::app::{ApiKeysState, AppState};
+use crate::data::api_keys::{self, ApiProvider, DEFAULT_ENVIRONMENT};
+use crate::data::email::SmtpSecurity;
+use crate::data::webhooks::{self, Webhook, WebhookKind};
+use crate::data::workspace::{self, Workspace};
+use crate::logging::{self, LogLevel};
+use crate::notices::{NoticeSource, Notices};
+use crate::data::models::{AnalysisWindows, CustomIndicator, DisplaySettings, InferencePrecision, NnLossFunction, NnTargetMode, NnVolTarget, ScreenshotCompression, ScreenshotFileType, ScreenshotScope};
+use crate::ui::i18n::{self, tr, tr_args, Locale};
+use crate::ui::palette::{self, PaletteMode};
+use crate::ui::profiles::{self, SettingsProfile};
+use crate::ui::{nn_view, screenshot, tray};
+use crate::grpc;
+use crate::analysis::custom_indicators;
+use crate::tasks::{TaskHooks, TaskKind, TaskManager};
+
+/// Zoom factors offered in the display settings
+const ZOOM_LEVELS: [f32; 8] = [0.75, 0.9, 1.0, 1.1, 1.25, 1.5, 1.75, 2.0];
 
 pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
-    ui.heading("Settings");
+    ui.heading(tr("tab-settings"));
     ui.add_space(8.0);
 
     let mut prev_visible = false;
 
+    // Named profiles bundling the settings below
+    render_profiles_section(ui, state, &mut prev_visible);
+
+    // Whole-session save and open
+    render_workspace_section(ui, state, &mut prev_visible);
+
     // Screenshot settings section (above NN Training)
     render_screenshot_section(ui, state, &mut prev_visible);
 
+    // Daily scheduled report/screenshot
+    render_schedule_section(ui, state, &mut prev_visible);
+
+    // SMTP server and recipients for emailed reports
+    render_email_section(ui, state, &mut prev_visible);
+
+    // Minimize to tray and alert notifications
+    render_tray_section(ui, state, &mut prev_visible);
+
+    // Programmatic access over gRPC
+    render_grpc_section(ui, state, &mut prev_visible);
+
+    // Slack/Discord/JSON endpoints for fired alerts
+    render_webhooks_section(ui, state, &mut prev_visible);
+
+    // Display and accessibility settings section
+    render_display_section(ui, state, &mut prev_visible);
+
+    // Data provider API keys
+    render_api_keys_section(ui, state, &mut prev_visible);
+
+    // Log level and log file location
+    render_logging_section(ui, state, &mut prev_visible);
+
+    // Vol windows and history lookback
+    render_analysis_windows_section(ui, state, &mut prev_visible);
+
+    // Scripted indicators for charts and NN features
+    render_custom_indicators_section(ui, state, &mut prev_visible);
+
     // NN Training Settings section
     render_nn_training_section(ui, state, &mut prev_visible);
 }
 
+fn render_profiles_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-profiles"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let profiles = &mut state.profiles;
+        profiles.selected = profiles.selected.take().filter(|name| profiles.profiles.iter().any(|p| &p.name == name));
+        let mut load = None;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-profile"));
+            egui::ComboBox::from_id_salt("settings_profile_combo")
+                .selected_text(profiles.selected.clone().unwrap_or_else(|| tr("settings-select")))
+                .show_ui(ui, |ui| {
+                    for profile in &profiles.profiles {
+                        ui.selectable_value(&mut profiles.selected, Some(profile.name.clone()), &profile.name);
+                    }
+                });
+            let has_selection = profiles.selected.is_some();
+            if ui.add_enabled(has_selection, egui::Button::new(tr("settings-load"))).clicked() {
+                load = profiles.selected.clone();
+            }
+            if ui.add_enabled(has_selection, egui::Button::new(tr("settings-delete"))).clicked() {
+                let name = profiles.selected.take();
+                profiles.profiles.retain(|p| Some(&p.name) != name.as_ref());
+                if let Err(e) = profiles::save_profiles(&profiles.profiles) {
+                    state.notices.error(NoticeSource::Settings, e);
+                }
+            }
+        });
+
+        let mut save_as = None;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-profile-save-as"));
+            ui.add(
+                egui::TextEdit::singleline(&mut profiles.name_input)
+                    .hint_text(tr("settings-profile-hint"))
+                    .desired_width(180.0),
+            );
+            let name = profiles.name_input.trim();
+            let exists = profiles.profiles.iter().any(|p| p.name == name);
+            let label = tr(if exists { "settings-overwrite" } else { "settings-save" });
+            if ui.add_enabled(!name.is_empty(), egui::Button::new(label)).clicked() {
+                save_as = Some(name.to_string());
+            }
+        });
+
+        if let Some(name) = save_as {
+            let profile = SettingsProfile::capture(&name, state);
+            let profiles = &mut state.profiles;
+            profiles::upsert(&mut profiles.profiles, profile);
+            profiles.selected = Some(name.clone());
+            profiles.name_input.clear();
+            let result = profiles::save_profiles(&profiles.profiles).map(|_| tr_args("settings-profile-saved", &[("name", name)]));
+            state.notices.report(NoticeSource::Settings, result);
+        }
+        if let Some(profile) = load.and_then(|name| state.profiles.profiles.iter().find(|p| p.name == name).cloned()) {
+            let result = profile.apply(state, ui.ctx()).map(|_| tr_args("settings-profile-loaded", &[("name", profile.name.clone())]));
+            state.notices.report(NoticeSource::Settings, result);
+        }
+
+        ui.small(tr("settings-profile-help"));
+    });
+
+    *prev_visible = true;
+}
+
+fn render_workspace_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-workspace"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let workspaces = &mut state.workspaces;
+        let mut open = None;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-workspace-label"));
+            egui::ComboBox::from_id_salt("workspace_combo")
+                .selected_text(workspaces.selected.clone().unwrap_or_else(|| tr("settings-select")))
+                .show_ui(ui, |ui| {
+                    for name in workspace::list() {
+                        ui.selectable_value(&mut workspaces.selected, Some(name.clone()), name);
+                    }
+                });
+            if ui
+                .add_enabled(workspaces.selected.is_some(), egui::Button::new(tr("settings-workspace-open")))
+                .on_hover_text(tr("settings-workspace-open-hover"))
+                .clicked()
+            {
+                open = workspaces.selected.clone();
+            }
+        });
+
+        let mut save_as = None;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-workspace-save-as"));
+            ui.add(
+                egui::TextEdit::singleline(&mut workspaces.name_input)
+                    .hint_text(tr("settings-workspace-hint"))
+                    .desired_width(180.0),
+            );
+            let name = workspaces.name_input.trim();
+            let valid = !name.is_empty() && !name.contains(['/', '\\']);
+            let label = tr(if workspace::path_for(name).exists() { "settings-overwrite" } else { "settings-workspace-save" });
+            if ui.add_enabled(valid && !state.is_loading, egui::Button::new(label)).clicked() {
+                save_as = Some(name.to_string());
+            }
+        });
+
+        if let Some(name) = save_as {
+            let path = workspace::path_for(&name);
+            let result = Workspace::capture(state, ui.ctx()).save(&path);
+            if result.is_ok() {
+                state.workspaces.selected = Some(name);
+                state.workspaces.name_input.clear();
+            }
+            state.notices.report(NoticeSource::Settings, result.map(|()| tr_args("settings-workspace-saved", &[("path", path.display().to_string())])));
+        }
+        if let Some(name) = open {
+            let path = workspace::path_for(&name);
+            let result = Workspace::load(&path)
+                .and_then(|w| w.apply(state, ui.ctx()))
+                .map(|()| tr_args("settings-workspace-opened", &[("name", name)]));
+            state.notices.report(NoticeSource::Settings, result);
+        }
+
+        ui.small(tr_args("settings-workspace-help", &[("dir", workspace::WORKSPACE_DIR.to_string())]));
+    });
+
+    *prev_visible = true;
+}
+
 fn render_screenshot_section(
     ui: &mut egui::Ui,
     state: &mut AppState,
@@ -29,7 +231,7 @@ fn render_screenshot_section(
         ui.add_space(8.0);
     }
 
-    ui.heading("Screenshot");
+    ui.heading(tr("settings-screenshot"));
     ui.add_space(4.0);
 
     ui.group(|ui| {
@@ -38,7 +240,7 @@ fn render_screenshot_section(
             .spacing(egui::vec2(12.0, 6.0))
             .show(ui, |ui| {
                 // Save path — native folder browser
-                ui.label("Save Path:");
+                ui.label(tr("settings-save-path"));
                 ui.horizontal(|ui| {
                     // Show the current path as greyed-out, non-editable text
                     ui.add_enabled(
@@ -48,7 +250,7 @@ fn render_screenshot_section(
                     );
 
                     let picking = state.folder_picker_result.is_some();
-                    let btn = ui.add_enabled(!picking, egui::Button::new("Browse…"));
+                    let btn = ui.add_enabled(!picking, egui::Button::new(tr("settings-browse")));
                     if btn.clicked() {
                         let slot: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
                         state.folder_picker_result = Some(slot.clone());
@@ -67,7 +269,7 @@ fn render_screenshot_section(
                 ui.end_row();
 
                 // File type
-                ui.label("File Type:");
+                ui.label(tr("settings-file-type"));
                 ui.horizontal(|ui| {
                     ui.selectable_value(
                         &mut state.screenshot_settings.file_type,
@@ -84,50 +286,848 @@ fn render_screenshot_section(
                         ScreenshotFileType::Tiff,
                         "TIFF",
                     )
-                    .on_hover_text("TIFF compression level is informational only");
+                    .on_hover_text(tr("settings-tiff-hover"));
                 });
                 ui.end_row();
 
                 // Compression
-                ui.label("Compression:");
+                ui.label(tr("settings-compression"));
                 ui.horizontal(|ui| {
                     ui.selectable_value(
                         &mut state.screenshot_settings.compression,
                         ScreenshotCompression::None,
-                        "None",
+                        tr("settings-compression-none"),
                     );
                     ui.selectable_value(
                         &mut state.screenshot_settings.compression,
                         ScreenshotCompression::Low,
-                        "Low",
+                        tr("settings-compression-low"),
                     );
                     ui.selectable_value(
                         &mut state.screenshot_settings.compression,
                         ScreenshotCompression::High,
-                        "High",
+                        tr("settings-compression-high"),
                     );
                 });
                 ui.end_row();
+
+                // Scope
+                ui.label(tr("settings-capture"));
+                ui.horizontal(|ui| {
+                    for scope in ScreenshotScope::ALL {
+                        ui.selectable_value(&mut state.screenshot_settings.scope, scope, screenshot::scope_label(scope));
+                    }
+                });
+                ui.end_row();
+            });
+
+        ui.add_space(8.0);
+
+        if ui.button(tr("settings-save-settings")).clicked() {
+            let result = state
+                .save_config()
+                .map(|_| tr("settings-screenshot-saved"))
+                .map_err(|e| tr_args("settings-screenshot-save-failed", &[("error", e)]));
+            state.notices.report(NoticeSource::Settings, result);
+        }
+
+        ui.label(tr("settings-screenshot-help"));
+    });
+
+    *prev_visible = true;
+}
+
+fn render_schedule_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-scheduled-capture"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let before = state.jobs.output_dir.clone();
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-output-folder"));
+            ui.add(egui::TextEdit::singleline(&mut state.jobs.output_dir).desired_width(220.0));
+        });
+        ui.horizontal(|ui| {
+            if ui.button(tr("settings-open-jobs")).clicked() {
+                state.jobs.open = true;
+            }
+            ui.label(tr("settings-jobs-help"));
+        });
+        ui.small(tr("settings-output-help"));
+
+        if state.jobs.output_dir != before {
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-output-save-failed", &[("error", e)]));
+            }
+        }
+    });
+
+    *prev_visible = true;
+}
+
+fn render_email_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-email"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let before = state.email.clone();
+        let email = &mut state.email;
+        egui::Grid::new("email_settings_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(12.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(tr("settings-smtp-server"));
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut email.host).hint_text("smtp.example.com").desired_width(180.0));
+                    ui.label(tr("settings-port"));
+                    ui.add(egui::DragValue::new(&mut email.port).range(1..=65535));
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-security"));
+                let security = email.security;
+                egui::ComboBox::from_id_salt("email_security_combo")
+                    .selected_text(security_label(email.security))
+                    .show_ui(ui, |ui| {
+                        for option in SmtpSecurity::ALL {
+                            ui.selectable_value(&mut email.security, option, security_label(option));
+                        }
+                    });
+                if email.security != security && email.port == security.default_port() {
+                    email.port = email.security.default_port();
+                }
+                ui.end_row();
+
+                ui.label(tr("settings-username"));
+                ui.add(egui::TextEdit::singleline(&mut email.username).desired_width(220.0));
+                ui.end_row();
+
+                ui.label(tr("settings-from"));
+                ui.add(egui::TextEdit::singleline(&mut email.from).hint_text(tr("settings-from-hint")).desired_width(220.0));
+                ui.end_row();
+
+                ui.label(tr("settings-recipients"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut email.recipients)
+                        .hint_text("a@example.com, b@example.com")
+                        .desired_width(320.0),
+                );
+                ui.end_row();
+            });
+
+        ui.add_space(4.0);
+        let password_set = std::env::var(ApiProvider::Smtp.env_var()).is_ok_and(|p| !p.is_empty());
+        if !password_set && !state.email.username.trim().is_empty() {
+            ui.colored_label(palette::negative(), tr("settings-smtp-password-missing"));
+        }
+        if ui
+            .add_enabled(state.email.is_configured(), egui::Button::new(tr("settings-send-report")))
+            .on_disabled_hover_text(tr("settings-send-report-disabled"))
+            .clicked()
+        {
+            let result = state
+                .email_report()
+                .map(|(n, _)| tr_args("report-emailing", &[("count", i18n::integer(n))]))
+                .map_err(|e| tr_args("report-email-failed", &[("error", e)]));
+            state.notices.report(NoticeSource::Export, result);
+        }
+        ui.small(tr("settings-email-help"));
+
+        if state.email != before {
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-email-save-failed", &[("error", e)]));
+            }
+        }
+    });
+
+    *prev_visible = true;
+}
+
+fn render_tray_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-background"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let before = state.tray.settings.clone();
+        let close_hint = tr(if tray::AVAILABLE { "settings-tray-close-hint" } else { "settings-no-tray-close-hint" });
+        ui.checkbox(&mut state.tray.settings.minimize_to_tray, tr("settings-keep-running"))
+            .on_hover_text(close_hint);
+        ui.checkbox(&mut state.tray.settings.native_notifications, tr("settings-desktop-notifications"));
+        if let Some(e) = &state.tray.icon_error {
+            if tray::AVAILABLE {
+                ui.colored_label(palette::negative(), tr_args("settings-tray-unavailable", &[("error", e.to_string())]));
+            }
+        }
+        if ui.button(tr("settings-test-notification")).clicked() {
+            tray::notify("Volume Analysis", &tr("settings-test-notification-body"));
+        }
+        if state.tray.settings != before {
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-background-save-failed", &[("error", e)]));
+            }
+        }
+    });
+
+    *prev_visible = true;
+}
+
+fn render_grpc_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-grpc"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        if !grpc::AVAILABLE {
+            ui.weak(tr("settings-grpc-unavailable"));
+        }
+        ui.add_enabled_ui(grpc::AVAILABLE, |ui| {
+            let before = state.grpc.settings.clone();
+            let toggled = ui
+                .checkbox(&mut state.grpc.settings.enabled, tr("settings-grpc-enable"))
+                .on_hover_text(tr("settings-grpc-enable-hover"))
+                .changed();
+            let address_edited = ui
+                .horizontal(|ui| {
+                    ui.label(tr("settings-grpc-address"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.grpc.settings.address)
+                            .hint_text(grpc::DEFAULT_ADDRESS)
+                            .desired_width(160.0),
+                    )
+                    .on_hover_text(tr("settings-grpc-address-hover"))
+                    .lost_focus()
+                })
+                .inner;
+            match &state.grpc.listening {
+                Some(address) => {
+                    ui.colored_label(palette::positive(), tr_args("settings-grpc-listening", &[("address", address.clone())]));
+                }
+                None if state.grpc.settings.enabled => {
+                    ui.horizontal(|ui| {
+                        ui.weak(tr("settings-grpc-stopped"));
+                        if ui.button(tr("settings-grpc-start")).clicked() {
+                            state.grpc.restart(&mut state.tasks);
+                        }
+                    });
+                }
+                None => {}
+            }
+            // A new address is applied once the field loses focus, not on every keystroke
+            let new_address = state.grpc.listening.as_deref() != Some(state.grpc.settings.address.trim());
+            if toggled || (address_edited && new_address) {
+                state.grpc.restart(&mut state.tasks);
+            }
+            if state.grpc.settings != before {
+                if let Err(e) = state.save_config() {
+                    state.notices.error(NoticeSource::Settings, tr_args("settings-grpc-save-failed", &[("error", e)]));
+                }
+            }
+        });
+    });
+
+    *prev_visible = true;
+}
+
+fn render_webhooks_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-webhooks"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let before = state.alerts.webhooks.clone();
+        let mut remove = None;
+        let mut test = None;
+        if state.alerts.webhooks.is_empty() {
+            ui.weak(tr("settings-webhooks-empty"));
+        }
+        egui::Grid::new("webhooks_grid")
+            .num_columns(5)
+            .spacing(egui::vec2(8.0, 6.0))
+            .show(ui, |ui| {
+                for (i, webhook) in state.alerts.webhooks.iter_mut().enumerate() {
+                    ui.checkbox(&mut webhook.enabled, "").on_hover_text(tr("alerts-enabled"));
+                    ui.add(egui::TextEdit::singleline(&mut webhook.name).hint_text(tr("settings-webhook-name")).desired_width(100.0));
+                    egui::ComboBox::from_id_salt(("webhook_kind", i))
+                        .selected_text(webhook.kind.label())
+                        .show_ui(ui, |ui| {
+                            for kind in WebhookKind::ALL {
+                                ui.selectable_value(&mut webhook.kind, kind, kind.label());
+                            }
+                        });
+                    ui.add(
+                        egui::TextEdit::singleline(&mut webhook.url)
+                            .hint_text("https://hooks.slack.com/services/…")
+                            .desired_width(260.0),
+                    );
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(!webhook.url.trim().is_empty(), egui::Button::new(tr("settings-test")))
+                            .on_hover_text(tr("settings-webhook-test-hover"))
+                            .clicked()
+                        {
+                            test = Some(i);
+                        }
+                        if ui.small_button("🗑").on_hover_text(tr("settings-webhook-delete")).clicked() {
+                            remove = Some(i);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if ui.button(tr("settings-webhook-add")).clicked() {
+            state.alerts.webhooks.push(Webhook { enabled: true, ..Webhook::default() });
+        }
+        ui.small(tr("settings-webhooks-help"));
+
+        if let Some(i) = test {
+            let webhook = &state.alerts.webhooks[i];
+            webhooks::send_test(&mut state.tasks, webhook);
+            state.notices.info(NoticeSource::Settings, tr_args("settings-webhook-testing", &[("webhook", webhook.label().to_string())]));
+        }
+        if let Some(i) = remove {
+            state.alerts.webhooks.remove(i);
+        }
+        if state.alerts.webhooks != before {
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-webhooks-save-failed", &[("error", e)]));
+            }
+        }
+    });
+
+    *prev_visible = true;
+}
+
+fn render_display_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-display"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-language"));
+            let before = state.locale;
+            egui::ComboBox::from_id_salt("locale_combo")
+                .selected_text(state.locale.label())
+                .show_ui(ui, |ui| {
+                    for locale in Locale::ALL {
+                        ui.selectable_value(&mut state.locale, locale, locale.label());
+                    }
+                })
+                .response
+                .on_hover_text(tr("settings-language-hover"));
+            if state.locale != before {
+                i18n::set_locale(state.locale);
+                state.plot_3d.needs_redraw = true;
+                if let Err(e) = state.save_config() {
+                    state.notices.error(NoticeSource::Settings, tr_args("settings-language-save-failed", &[("error", e)]));
+                }
+            }
+        });
+
+        let before = state.display_settings;
+        let mut commit = false;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-zoom"));
+            let display = &mut state.display_settings;
+            egui::ComboBox::from_id_salt("ui_zoom_combo")
+                .selected_text(i18n::percent(display.zoom as f64, 0))
+                .show_ui(ui, |ui| {
+                    for zoom in ZOOM_LEVELS {
+                        ui.selectable_value(&mut display.zoom, zoom, i18n::percent(zoom as f64, 0));
+                    }
+                })
+                .response
+                .on_hover_text(tr("settings-zoom-hover"));
+            commit |= display.zoom != before.zoom;
+        });
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-font-size"));
+            let response = ui.add(
+                egui::Slider::new(&mut state.display_settings.font_size, 9.0..=24.0)
+                    .step_by(0.5)
+                    .suffix(" pt"),
+            );
+            // Re-layout on release so the slider doesn't move under the cursor while dragging
+            commit |= response.drag_stopped() || (response.changed() && !response.dragged());
+            if ui.small_button(tr("reset")).clicked() {
+                state.display_settings = DisplaySettings::default();
+                commit = true;
+            }
+        });
+        if commit {
+            apply_display_settings(ui.ctx(), &state.display_settings);
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-display-save-failed", &[("error", e)]));
+            }
+        }
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-palette"));
+            let before = state.palette_mode;
+            egui::ComboBox::from_id_salt("palette_mode_combo")
+                .selected_text(state.palette_mode.label())
+                .show_ui(ui, |ui| {
+                    for mode in PaletteMode::ALL {
+                        ui.selectable_value(&mut state.palette_mode, mode, mode.label());
+                    }
+                })
+                .response
+                .on_hover_text(tr("settings-palette-hover"));
+            if state.palette_mode != before {
+                if let Err(e) = state.save_config() {
+                    state.notices.error(NoticeSource::Settings, tr_args("settings-palette-save-failed", &[("error", e)]));
+                }
+            }
+        });
+
+        ui.horizontal(|ui| {
+            for (color, label) in [
+                (palette::positive(), "palette-calm"),
+                (palette::neutral(), "palette-normal"),
+                (palette::negative(), "palette-stressed"),
+            ] {
+                ui.colored_label(color, "●");
+                ui.label(tr(label));
+            }
+        });
+        ui.add_space(4.0);
+
+        if ui
+            .checkbox(&mut state.explain_metrics, tr("settings-explain-metrics"))
+            .on_hover_text(tr("settings-explain-metrics-hover"))
+            .changed()
+        {
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-explain-save-failed", &[("error", e)]));
+            }
+        }
+    });
+
+    *prev_visible = true;
+}
+
+fn render_api_keys_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-api-keys"));
+    ui.add_space(4.0);
+
+    let keys = &mut state.api_keys;
+    collect_api_tests(keys);
+
+    ui.group(|ui| {
+        render_api_environment(ui, keys, &mut state.notices);
+        ui.add_space(6.0);
+
+        egui::Grid::new("api_keys_grid")
+            .num_columns(4)
+            .spacing(egui::vec2(12.0, 6.0))
+            .show(ui, |ui| {
+                for provider in ApiProvider::ALL {
+                    ui.label(provider.label()).on_hover_text(tr_args("settings-api-used-for", &[("uses", provider.used_for().to_string())]));
+
+                    let current = std::env::var(provider.env_var()).unwrap_or_default();
+                    if current.is_empty() {
+                        ui.colored_label(palette::negative(), tr("settings-api-not-set"));
+                    } else if keys.reveal {
+                        ui.monospace(&current);
+                    } else {
+                        ui.monospace(api_keys::mask(&current));
+                    }
+
+                    let draft = keys.drafts.entry(provider).or_default();
+                    ui.add(
+                        egui::TextEdit::singleline(draft)
+                            .password(!keys.reveal)
+                            .hint_text(tr("settings-api-new-key"))
+                            .desired_width(220.0),
+                    );
+                    let draft = draft.trim().to_string();
+
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!draft.is_empty(), egui::Button::new(tr("settings-save"))).clicked() {
+                            let result = keys
+                                .environments
+                                .set_key(provider, &draft)
+                                .map(|_| tr_args("settings-api-key-saved", &[("provider", provider.label().to_string())]))
+                                .map_err(|e| tr_args("settings-api-key-save-failed", &[("provider", provider.label().to_string()), ("error", e.to_string())]));
+                            state.notices.report(NoticeSource::Settings, result);
+                            keys.drafts.remove(&provider);
+                            keys.results.remove(&provider);
+                        }
+                        if ui
+                            .add_enabled(!current.is_empty(), egui::Button::new(tr("settings-api-clear")))
+                            .on_hover_text(tr_args("settings-api-clear-hover", &[("var", provider.env_var().to_string())]))
+                            .clicked()
+                        {
+                            let result = keys
+                                .environments
+                                .set_key(provider, "")
+                                .map(|_| tr_args("settings-api-key-cleared", &[("provider", provider.label().to_string())]))
+                                .map_err(|e| tr_args("settings-api-key-clear-failed", &[("provider", provider.label().to_string()), ("error", e.to_string())]));
+                            state.notices.report(NoticeSource::Settings, result);
+                            keys.results.remove(&provider);
+                        }
+
+                        // Test the key being typed, or the active one when the box is empty
+                        let candidate = if draft.is_empty() { current.clone() } else { draft.clone() };
+                        let testing = keys.testing.contains(&provider);
+                        if ui
+                            .add_enabled(!testing && !candidate.is_empty(), egui::Button::new(tr("settings-api-test")))
+                            .clicked()
+                        {
+                            start_api_test(&mut state.tasks, keys, provider, candidate);
+                        }
+                        if testing {
+                            ui.spinner();
+                        } else {
+                            match keys.results.get(&provider) {
+                                Some(Ok(msg)) => {
+                                    ui.colored_label(palette::positive(), msg);
+                                }
+                                Some(Err(e)) => {
+                                    ui.colored_label(palette::negative(), tr("task-failed")).on_hover_text(e);
+                                }
+                                None => {}
+                            }
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+        ui.checkbox(&mut keys.reveal, tr("settings-api-show-keys"));
+        ui.small(tr_args("settings-api-help", &[("file", api_keys::API_KEYS_FILENAME.to_string())]));
+    });
+
+    *prev_visible = true;
+}
+
+/// Picker for the active API environment, adding and removing environments, and the
+/// active one's FMP endpoint
+fn render_api_environment(ui: &mut egui::Ui, keys: &mut ApiKeysState, notices: &mut Notices) {
+    let environments = &mut keys.environments;
+    let mut switch_to = None;
+    ui.horizontal(|ui| {
+        ui.label(tr("settings-api-environment"));
+        egui::ComboBox::from_id_salt("api_environment")
+            .selected_text(&environments.active)
+            .show_ui(ui, |ui| {
+                for environment in &environments.environments {
+                    if ui.selectable_label(environment.name == environments.active, &environment.name).clicked() {
+                        switch_to = Some(environment.name.clone());
+                    }
+                }
             });
+        if ui
+            .add_enabled(environments.active != DEFAULT_ENVIRONMENT, egui::Button::new(tr("settings-api-env-remove")))
+            .on_hover_text(tr("settings-api-env-remove-hover"))
+            .clicked()
+        {
+            let name = environments.active.clone();
+            let result = environments
+                .remove(&name)
+                .map(|()| tr_args("settings-api-env-removed", &[("name", name.clone())]))
+                .map_err(|e| tr_args("settings-api-env-remove-failed", &[("name", name.clone()), ("error", e.to_string())]));
+            notices.report(NoticeSource::Settings, result);
+        }
+
+        ui.separator();
+        ui.add(egui::TextEdit::singleline(&mut keys.new_environment).hint_text(tr("settings-api-env-new")).desired_width(140.0));
+        if ui.add_enabled(!keys.new_environment.trim().is_empty(), egui::Button::new(tr("settings-api-env-add"))).clicked() {
+            let name = keys.new_environment.trim().to_string();
+            match environments.add(&name) {
+                Ok(()) => {
+                    keys.new_environment.clear();
+                    switch_to = Some(name);
+                }
+                Err(e) => notices.error(NoticeSource::Settings, tr_args("settings-api-env-add-failed", &[("error", e.to_string())])),
+            }
+        }
+    });
+    if let Some(name) = switch_to.filter(|name| *name != environments.active) {
+        let result = environments
+            .activate(&name)
+            .map(|()| tr_args("settings-api-env-switched", &[("name", name.clone())]))
+            .map_err(|e| tr_args("settings-api-env-switch-failed", &[("error", e.to_string())]));
+        notices.report(NoticeSource::Settings, result);
+    }
+    if keys.environments.active != keys.endpoint_for {
+        keys.endpoint_for = keys.environments.active.clone();
+        keys.endpoint_draft = keys.environments.active().fmp_base_url.clone();
+        keys.drafts.clear();
+        keys.results.clear();
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(tr("settings-fmp-endpoint"));
+        ui.add(
+            egui::TextEdit::singleline(&mut keys.endpoint_draft)
+                .hint_text(crate::data::fmp::DEFAULT_BASE_URL)
+                .desired_width(320.0),
+        );
+        let changed = keys.endpoint_draft.trim() != keys.environments.active().fmp_base_url;
+        if ui
+            .add_enabled(changed, egui::Button::new(tr("settings-save")))
+            .on_hover_text(tr("settings-fmp-endpoint-hover"))
+            .clicked()
+        {
+            let result = keys
+                .environments
+                .set_fmp_base_url(&keys.endpoint_draft)
+                .map(|()| tr("settings-fmp-endpoint-saved"))
+                .map_err(|e| tr_args("settings-fmp-endpoint-save-failed", &[("error", e.to_string())]));
+            notices.report(NoticeSource::Settings, result);
+            keys.endpoint_draft = keys.environments.active().fmp_base_url.clone();
+        }
+    });
+}
+
+/// Run a connection test as a background task; the result lands in `keys.slot`
+fn start_api_test(tasks: &mut TaskManager, keys: &mut ApiKeysState, provider: ApiProvider, key: String) {
+    keys.testing.push(provider);
+    keys.results.remove(&provider);
+    let slot = keys.slot.clone();
+
+    let cancel_slot = slot.clone();
+    let hooks = TaskHooks {
+        cancel: Some(Box::new(move || {
+            if let Ok(mut results) = cancel_slot.lock() {
+                results.push((provider, Err(tr("task-cancelled"))));
+            }
+        })),
+        progress: None,
+    };
+    tasks.spawn(TaskKind::ApiTest, tr_args("settings-api-test-task", &[("provider", provider.label().to_string())]), hooks, async move {
+        let result = api_keys::test_connection(provider, &key).await.map_err(|e| format!("{:#}", e));
+        if let Ok(mut results) = slot.lock() {
+            results.push((provider, result));
+        }
+        Ok(())
+    });
+}
 
+/// Move finished connection tests into `keys.results`
+fn collect_api_tests(keys: &mut ApiKeysState) {
+    let finished: Vec<_> = match keys.slot.lock() {
+        Ok(mut results) => results.drain(..).collect(),
+        Err(_) => return,
+    };
+    for (provider, result) in finished {
+        keys.testing.retain(|p| *p != provider);
+        keys.results.insert(provider, result);
+    }
+}
+
+fn render_logging_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
         ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
 
-        if ui.button("Save Settings").clicked() {
-            match crate::data::cache::save_json(
-                "screenshot_settings.json",
-                &state.screenshot_settings,
-            ) {
-                Ok(_) => state.status_message = "Screenshot settings saved.".to_string(),
-                Err(_) => state.status_message = "Failed to save screenshot settings.".to_string(),
+    ui.heading(tr("settings-logging"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let before = state.log_level;
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-log-level"));
+            egui::ComboBox::from_id_salt("log_level_combo")
+                .selected_text(state.log_level.label())
+                .show_ui(ui, |ui| {
+                    for level in LogLevel::ALL {
+                        ui.selectable_value(&mut state.log_level, level, level.label());
+                    }
+                })
+                .response
+                .on_hover_text(tr("settings-log-level-hover"));
+        });
+        if state.log_level != before {
+            logging::set_level(state.log_level);
+            tracing::info!("Log level set to {}", state.log_level.label());
+            if let Err(e) = state.save_config() {
+                state.notices.error(NoticeSource::Settings, tr_args("settings-log-level-save-failed", &[("error", e)]));
             }
         }
+        ui.small(tr_args(
+            "settings-log-help",
+            &[("dir", logging::LOG_DIR.to_string()), ("days", i18n::integer(logging::MAX_LOG_FILES))],
+        ));
+    });
+
+    *prev_visible = true;
+}
+
+fn render_analysis_windows_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-analysis-windows"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let draft = &mut state.analysis_windows_draft;
+        egui::Grid::new("analysis_windows_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(12.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(tr("settings-short-vol"));
+                ui.add(egui::DragValue::new(&mut draft.short_vol).range(2..=126).suffix(format!(" {}", tr("settings-days"))));
+                ui.end_row();
+
+                ui.label(tr("settings-long-vol"));
+                ui.add(egui::DragValue::new(&mut draft.long_vol).range(3..=252).suffix(format!(" {}", tr("settings-days"))));
+                ui.end_row();
+
+                ui.label(tr("settings-lookback"));
+                ui.add(egui::DragValue::new(&mut draft.lookback_days).range(90..=3650).suffix(format!(" {}", tr("settings-calendar-days"))))
+                    .on_hover_text(tr("settings-lookback-hover"));
+                ui.end_row();
+            });
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-presets"));
+            for (short, long) in [(10, 30), (21, 63), (42, 126)] {
+                if ui.small_button(tr_args("settings-preset", &[("short", short.to_string()), ("long", long.to_string())])).clicked() {
+                    draft.short_vol = short;
+                    draft.long_vol = long;
+                }
+            }
+            if ui.small_button(tr("settings-defaults")).clicked() {
+                *draft = AnalysisWindows::default();
+            }
+        });
+        ui.add_space(4.0);
 
-        ui.label("Use the 📷 camera button in the tab bar to capture a screenshot.");
+        let draft = state.analysis_windows_draft;
+        let valid = draft.validate();
+        if let Err(e) = &valid {
+            ui.colored_label(palette::negative(), e);
+        }
+        ui.horizontal(|ui| {
+            let changed = draft != state.analysis_windows;
+            if ui.add_enabled(changed && valid.is_ok(), egui::Button::new(tr("settings-apply"))).clicked() {
+                let lookback_changed = draft.lookback_days != state.analysis_windows.lookback_days;
+                state.analysis_windows = draft;
+                state.recompute_analysis();
+                crate::ui::watchlist_view::recompute_entries(&mut state.watchlist, &draft);
+                let result = match state.save_config() {
+                    Err(e) => Err(tr_args("settings-windows-save-failed", &[("error", e)])),
+                    Ok(_) if lookback_changed => Ok(tr("settings-windows-applied-lookback")),
+                    Ok(_) => Ok(tr("settings-windows-applied")),
+                };
+                state.notices.report(NoticeSource::Settings, result);
+            }
+            if ui.add_enabled(changed, egui::Button::new(tr("settings-revert"))).clicked() {
+                state.analysis_windows_draft = state.analysis_windows;
+            }
+        });
+        ui.small(tr("settings-windows-help"));
     });
 
     *prev_visible = true;
 }
 
+/// SMTP security option names; the TLS modes are protocol names and stay as they are
+fn security_label(security: SmtpSecurity) -> String {
+    match security {
+        SmtpSecurity::None => tr("settings-security-none"),
+        other => other.label().to_string(),
+    }
+}
+
+/// Apply zoom (via pixels-per-point) and font size to every theme's style
+pub fn apply_display_settings(ctx: &egui::Context, settings: &DisplaySettings) {
+    ctx.set_zoom_factor(settings.zoom);
+    let scale = settings.font_size / DisplaySettings::DEFAULT_FONT_SIZE;
+    let defaults = egui::Style::default().text_styles;
+    ctx.all_styles_mut(|style| {
+        for (text_style, font) in style.text_styles.iter_mut() {
+            if let Some(default) = defaults.get(text_style) {
+                font.size = default.size * scale;
+            }
+        }
+    });
+}
+
 /// Open a native OS folder-selection dialog and return the chosen path.
 ///
 /// On Windows, uses PowerShell's `FolderBrowserDialog`. On other platforms,
@@ -182,6 +1182,127 @@ if ($d.ShowDialog() -eq 'OK') {{ Write-Output $d.SelectedPath }}
     }
 }
 
+fn render_custom_indicators_section(
+    ui: &mut egui::Ui,
+    state: &mut AppState,
+    prev_visible: &mut bool,
+) {
+    if *prev_visible {
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(8.0);
+    }
+
+    ui.heading(tr("settings-custom-indicators"));
+    ui.add_space(4.0);
+
+    ui.group(|ui| {
+        let custom = &mut state.custom_indicators;
+        let mut changed = false;
+        let mut remove = None;
+        for (i, indicator) in custom.indicators.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                changed |= ui.checkbox(&mut indicator.enabled, &indicator.name).on_hover_text(tr("settings-indicator-enabled-hover")).changed();
+                if let Some((_, e)) = state.analysis.custom_errors.iter().find(|(name, _)| *name == indicator.name) {
+                    ui.colored_label(palette::negative(), "⚠").on_hover_text(e);
+                }
+                if ui.small_button(tr("settings-edit")).clicked() {
+                    custom.editing = Some(i);
+                    custom.draft = Some(indicator.clone());
+                    custom.test_result = None;
+                }
+                if ui.small_button(tr("settings-delete")).clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+        if let Some(i) = remove {
+            custom.indicators.remove(i);
+            custom.editing = None;
+            custom.draft = None;
+            changed = true;
+        }
+        if custom.draft.is_none() && ui.button(tr("settings-indicator-new")).clicked() {
+            custom.editing = None;
+            custom.draft = Some(CustomIndicator::example());
+            custom.test_result = None;
+        }
+
+        let mut save_draft = false;
+        let mut cancel = false;
+        if let Some(draft) = &mut custom.draft {
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.label(tr("settings-indicator-name"));
+                ui.add(egui::TextEdit::singleline(&mut draft.name).desired_width(200.0));
+            });
+            ui.add(
+                egui::TextEdit::multiline(&mut draft.script)
+                    .code_editor()
+                    .desired_rows(8)
+                    .desired_width(f32::INFINITY),
+            );
+            let sector = state.market_data.sectors.get(state.selected_sector_idx);
+            ui.horizontal(|ui| {
+                if ui.add_enabled(sector.is_some(), egui::Button::new(tr("settings-test"))).clicked() {
+                    if let Some(sector) = sector {
+                        let windows = state.analysis_windows;
+                        custom.test_result = Some(
+                            custom_indicators::evaluate(&draft.script, sector, windows.short_vol, windows.long_vol).map(|values| {
+                                let last = values.last().map_or("-".to_string(), |v| i18n::number(*v, 4));
+                                tr_args(
+                                    "settings-indicator-test-result",
+                                    &[("symbol", sector.symbol.clone()), ("count", i18n::integer(values.len())), ("latest", last)],
+                                )
+                            }),
+                        );
+                    }
+                }
+                let name = draft.name.trim();
+                let duplicate = custom.indicators.iter().enumerate().any(|(i, c)| c.name == name && Some(i) != custom.editing);
+                if ui.add_enabled(!name.is_empty() && !duplicate, egui::Button::new(tr("settings-save"))).clicked() {
+                    save_draft = true;
+                }
+                cancel = ui.button(tr("cancel")).clicked();
+                if duplicate {
+                    ui.colored_label(palette::negative(), tr("settings-indicator-duplicate"));
+                }
+            });
+            match &custom.test_result {
+                Some(Ok(summary)) => { ui.colored_label(palette::positive(), summary); }
+                Some(Err(e)) => { ui.colored_label(palette::negative(), e); }
+                None => {}
+            }
+        }
+        if cancel {
+            custom.draft = None;
+            custom.editing = None;
+        }
+        if save_draft {
+            if let Some(mut draft) = custom.draft.take() {
+                draft.name = draft.name.trim().to_string();
+                match custom.editing.take() {
+                    Some(i) if i < custom.indicators.len() => custom.indicators[i] = draft,
+                    _ => custom.indicators.push(draft),
+                }
+                changed = true;
+            }
+        }
+
+        if changed {
+            state.nn_feature_flags.custom_indicators = custom.nn_features();
+            if let Err(e) = custom_indicators::save(&custom.indicators) {
+                state.notices.error(NoticeSource::Settings, e);
+            }
+            state.recompute_analysis();
+        }
+
+        ui.small(tr("settings-indicators-help"));
+    });
+
+    *prev_visible = true;
+}
+
 fn render_nn_training_section(
     ui: &mut egui::Ui,
     state: &mut AppState,
@@ -194,45 +1315,259 @@ fn render_nn_training_section(
         ui.add_space(8.0);
     }
 
-    ui.heading("Neural Network Training");
+    ui.heading(tr("settings-nn-training"));
     ui.add_space(4.0);
 
     ui.group(|ui| {
-        ui.label("Select input features to use during model training:");
+        ui.label(tr("settings-nn-features"));
         ui.add_space(4.0);
 
         // Sector Volatility checkbox
         let mut vol_enabled = state.nn_feature_flags.sector_volatility;
-        ui.checkbox(&mut vol_enabled, "Sector Volatility (11 features)");
+        ui.checkbox(&mut vol_enabled, tr("settings-nn-sector-vol"));
         if vol_enabled != state.nn_feature_flags.sector_volatility {
             state.nn_feature_flags.sector_volatility = vol_enabled;
         }
 
         // Market Randomness checkbox
         let mut rand_enabled = state.nn_feature_flags.market_randomness;
-        ui.checkbox(&mut rand_enabled, "Market Randomness (22 features)");
+        ui.checkbox(&mut rand_enabled, tr("settings-nn-randomness"));
         if rand_enabled != state.nn_feature_flags.market_randomness {
             state.nn_feature_flags.market_randomness = rand_enabled;
         }
 
         // Kurtosis checkbox
         let mut kurt_enabled = state.nn_feature_flags.kurtosis;
-        ui.checkbox(&mut kurt_enabled, "Kurtosis (22 features)");
+        ui.checkbox(&mut kurt_enabled, tr("settings-nn-kurtosis"));
         if kurt_enabled != state.nn_feature_flags.kurtosis {
             state.nn_feature_flags.kurtosis = kurt_enabled;
         }
 
+        // Custom indicator checkboxes
+        let mut custom_changed = false;
+        for indicator in &mut state.custom_indicators.indicators {
+            let label = tr_args(
+                "settings-nn-custom",
+                &[("name", indicator.name.clone()), ("count", crate::nn::model::CUSTOM_INDICATOR_FEATURES.to_string())],
+            );
+            custom_changed |= ui.checkbox(&mut indicator.nn_feature, label).changed();
+        }
+        if custom_changed {
+            state.nn_feature_flags.custom_indicators = state.custom_indicators.nn_features();
+            if let Err(e) = custom_indicators::save(&state.custom_indicators.indicators) {
+                state.notices.error(NoticeSource::Settings, e);
+            }
+        }
+
+        ui.label(tr_args(
+            "settings-nn-feature-count",
+            &[
+                ("count", crate::nn::model::num_features(&state.nn_feature_flags).to_string()),
+                ("base", crate::nn::model::BASE_FEATURES.to_string()),
+            ],
+        ));
+
+        ui.add_space(8.0);
+        ui.label(tr("settings-nn-hyperparameters"));
+        ui.add_space(4.0);
+
+        egui::Grid::new("nn_hyperparams_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(12.0, 6.0))
+            .show(ui, |ui| {
+                let cfg = &mut state.nn_training_config;
+
+                ui.label(tr("settings-nn-hidden-size"));
+                ui.add(egui::DragValue::new(&mut cfg.hidden_size).range(4..=1024));
+                ui.end_row();
+
+                ui.label(tr("settings-nn-lookback"));
+                ui.add(egui::DragValue::new(&mut cfg.lookback).range(5..=250).suffix(format!(" {}", tr("settings-days"))));
+                ui.end_row();
+
+                ui.label(tr("settings-nn-learning-rate"));
+                ui.add(
+                    egui::DragValue::new(&mut cfg.learning_rate)
+                        .speed(1e-5)
+                        .range(1e-6..=1e-1)
+                        .max_decimals(6),
+                );
+                ui.end_row();
+
+                ui.label(tr("settings-nn-epochs"));
+                ui.add(egui::DragValue::new(&mut cfg.epochs).range(1..=10000));
+                ui.end_row();
+
+                ui.label(tr("settings-nn-target"));
+                ui.horizontal(|ui| {
+                    ui.radio_value(&mut cfg.target_mode, NnTargetMode::Regression, tr("settings-nn-target-level"));
+                    ui.radio_value(
+                        &mut cfg.target_mode,
+                        NnTargetMode::RegimeClassification,
+                        tr("settings-nn-target-regime"),
+                    );
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-forecast"));
+                ui.add_enabled_ui(cfg.target_mode == NnTargetMode::Regression, |ui| {
+                    egui::ComboBox::from_id_salt("nn_vol_target")
+                        .selected_text(nn_view::vol_target_label(cfg.vol_target))
+                        .show_ui(ui, |ui| {
+                            for target in NnVolTarget::AGGREGATE {
+                                ui.selectable_value(&mut cfg.vol_target, target, nn_view::vol_target_label(target));
+                            }
+                            ui.separator();
+                            for i in 0..crate::config::sector_etfs().len() {
+                                let target = NnVolTarget::Sector(i);
+                                ui.selectable_value(&mut cfg.vol_target, target, nn_view::vol_target_label(target));
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr("settings-nn-forecast-hover"));
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-loss"));
+                ui.add_enabled_ui(cfg.target_mode == NnTargetMode::Regression, |ui| {
+                    egui::ComboBox::from_id_salt("nn_loss_fn")
+                        .selected_text(cfg.loss.label())
+                        .show_ui(ui, |ui| {
+                            for loss in NnLossFunction::ALL {
+                                ui.selectable_value(&mut cfg.loss, loss, loss.label());
+                            }
+                        });
+                    if cfg.loss == NnLossFunction::Qlike && !cfg.vol_target.is_level() {
+                        ui.small(tr("settings-nn-loss-mse"));
+                    }
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-grad-clip"));
+                ui.horizontal(|ui| {
+                    let mut clip_enabled = cfg.grad_clip_norm.is_some();
+                    if ui.checkbox(&mut clip_enabled, tr("settings-nn-max-norm")).changed() {
+                        cfg.grad_clip_norm = clip_enabled.then_some(crate::config::NN_GRAD_CLIP_NORM);
+                    }
+                    if let Some(ref mut norm) = cfg.grad_clip_norm {
+                        ui.add(egui::DragValue::new(norm).speed(0.01).range(0.01..=100.0));
+                    }
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-precision"));
+                ui.checkbox(&mut cfg.mixed_precision, tr("settings-nn-half-precision"))
+                    .on_hover_text(tr("settings-nn-half-precision-hover"));
+                ui.end_row();
+
+                ui.label(tr("settings-nn-batch-size"));
+                ui.horizontal(|ui| {
+                    let mut auto = cfg.batch_size.is_none();
+                    if ui
+                        .checkbox(&mut auto, tr("settings-nn-batch-auto"))
+                        .on_hover_text(tr("settings-nn-batch-auto-hover"))
+                        .changed()
+                    {
+                        cfg.batch_size = if auto { None } else { Some(crate::config::NN_BATCH_SIZE) };
+                    }
+                    if let Some(ref mut batch_size) = cfg.batch_size {
+                        ui.add(egui::DragValue::new(batch_size).range(1..=4096));
+                    }
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-augmentation"))
+                    .on_hover_text(tr("settings-nn-augmentation-hover"));
+                ui.horizontal(|ui| {
+                    let aug = &mut cfg.augmentation;
+                    ui.label(tr("settings-nn-jitter"));
+                    ui.add(egui::DragValue::new(&mut aug.jitter_std).speed(0.005).range(0.0..=1.0).max_decimals(3));
+                    ui.label(tr("settings-nn-warp"));
+                    ui.add(egui::DragValue::new(&mut aug.warp_strength).speed(0.01).range(0.0..=0.5).max_decimals(2));
+                    ui.label(tr("settings-nn-feature-dropout"));
+                    ui.add(egui::DragValue::new(&mut aug.feature_dropout).speed(0.01).range(0.0..=0.9).max_decimals(2));
+                });
+                ui.end_row();
+
+                ui.label(tr("settings-nn-metrics-export"));
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut cfg.export_metrics, "CSV")
+                        .on_hover_text(tr("settings-nn-metrics-csv-hover"));
+                    ui.add_enabled(
+                        cfg.export_metrics,
+                        egui::Checkbox::new(&mut cfg.export_tensorboard, tr("settings-nn-tensorboard")),
+                    );
+                });
+                ui.end_row();
+            });
+
         ui.add_space(8.0);
 
-        if ui.button("Save Settings").clicked() {
-            if let Ok(_) = crate::data::cache::save_json("nn_feature_flags.json", &state.nn_feature_flags) {
-                state.status_message = "Settings saved successfully.".to_string();
-            } else {
-                state.status_message = "Failed to save settings.".to_string();
+        if !state.available_gpus.is_empty() {
+            ui.horizontal(|ui| {
+                ui.label(tr("settings-nn-gpu-adapter"));
+                let before = state.gpu_adapter.clone();
+                // The same GPU can be listed once per graphics API; show each name once
+                let mut names: Vec<String> = state.available_gpus.iter().map(|a| a.name.clone()).collect();
+                names.sort();
+                names.dedup();
+                egui::ComboBox::from_id_salt("gpu_adapter_combo")
+                    .selected_text(state.gpu_adapter.clone().unwrap_or_else(|| tr("settings-nn-gpu-default")))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut state.gpu_adapter, None, tr("settings-nn-gpu-default"));
+                        for name in names {
+                            ui.selectable_value(&mut state.gpu_adapter, Some(name.clone()), name);
+                        }
+                    });
+                if state.gpu_adapter != before {
+                    if let Err(e) = state.save_config() {
+                        state.notices.error(NoticeSource::Settings, tr_args("settings-nn-gpu-save-failed", &[("error", e)]));
+                    }
+                }
+            });
+            ui.add_space(8.0);
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-nn-inference-precision"));
+            let before = state.inference_precision;
+            egui::ComboBox::from_id_salt("inference_precision_combo")
+                .selected_text(state.inference_precision.label())
+                .show_ui(ui, |ui| {
+                    for precision in InferencePrecision::ALL {
+                        ui.selectable_value(&mut state.inference_precision, precision, precision.label());
+                    }
+                })
+                .response
+                .on_hover_text(tr("settings-nn-inference-precision-hover"));
+            if state.inference_precision != before {
+                if let Err(e) = state.save_config() {
+                    state.notices.error(NoticeSource::Settings, tr_args("settings-nn-inference-save-failed", &[("error", e)]));
+                }
             }
+        });
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.label(tr("settings-nn-drift"))
+                .on_hover_text(tr("settings-nn-drift-hover"));
+            let drift = &mut state.drift_settings;
+            ui.label(tr("settings-nn-drift-window"));
+            ui.add(egui::DragValue::new(&mut drift.window).range(1..=250));
+            ui.label(tr("settings-nn-drift-threshold"));
+            ui.add(egui::DragValue::new(&mut drift.threshold_pct).speed(0.5).range(1.0..=200.0).suffix("%"));
+        });
+        ui.add_space(8.0);
+
+        if ui.button(tr("settings-save-settings")).clicked() {
+            let result = state
+                .save_config()
+                .map(|()| tr("settings-saved"))
+                .map_err(|e| tr_args("settings-save-failed", &[("error", e)]));
+            state.notices.report(NoticeSource::Settings, result);
         }
 
-        ui.label("Settings are applied when you start a new training session.");
+        ui.label(tr("settings-nn-apply-help"));
     });
 
     *prev_visible = true;
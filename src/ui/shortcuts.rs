@@ -0,0 +1,136 @@
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+
+use crate::app::{AppState, Tab};
+use crate::notices::NoticeSource;
+use crate::ui::{i18n, nn_view};
+
+/// What a keyboard shortcut does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Tab(Tab),
+    Refresh,
+    Screenshot,
+    CopyScreenshot,
+    ToggleTraining,
+    NextSector,
+    PrevSector,
+    /// Handled by the quick-search box itself; listed here only for the overlay
+    FocusSearch,
+    ToggleOverlay,
+}
+
+/// Work the app shell performs for a shortcut, since it owns the runtime and viewport
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Refresh,
+    Screenshot,
+    CopyScreenshot,
+}
+
+const fn ctrl(key: Key) -> KeyboardShortcut {
+    KeyboardShortcut::new(Modifiers::COMMAND, key)
+}
+
+const fn plain(key: Key) -> KeyboardShortcut {
+    KeyboardShortcut::new(Modifiers::NONE, key)
+}
+
+/// Every shortcut, in the order the overlay lists them
+const SHORTCUTS: [(KeyboardShortcut, Action); 15] = [
+    (ctrl(Key::Num1), Action::Tab(Tab::Dashboard)),
+    (ctrl(Key::Num2), Action::Tab(Tab::SectorVol)),
+    (ctrl(Key::Num3), Action::Tab(Tab::Correlations)),
+    (ctrl(Key::Num4), Action::Tab(Tab::Bonds)),
+    (ctrl(Key::Num5), Action::Tab(Tab::Rates)),
+    (ctrl(Key::Num6), Action::Tab(Tab::Kurtosis)),
+    (plain(Key::F5), Action::Refresh),
+    // Before Ctrl+S, which also matches with Shift held
+    (KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::S), Action::CopyScreenshot),
+    (ctrl(Key::S), Action::Screenshot),
+    (ctrl(Key::T), Action::ToggleTraining),
+    (ctrl(Key::CloseBracket), Action::NextSector),
+    (ctrl(Key::OpenBracket), Action::PrevSector),
+    (ctrl(Key::K), Action::FocusSearch),
+    (plain(Key::F1), Action::ToggleOverlay),
+    (ctrl(Key::Slash), Action::ToggleOverlay),
+];
+
+impl Action {
+    fn description(&self) -> String {
+        let id = match self {
+            Self::Tab(tab @ (Tab::Dashboard | Tab::SectorVol | Tab::Correlations | Tab::Bonds | Tab::Rates | Tab::Kurtosis)) => {
+                return i18n::tr_args("shortcut-tab", &[("tab", tab.label())]);
+            }
+            Self::Tab(_) => "shortcut-switch-tab",
+            Self::Refresh => "shortcut-refresh",
+            Self::Screenshot => "shortcut-screenshot",
+            Self::CopyScreenshot => "shortcut-copy-screenshot",
+            Self::ToggleTraining => "shortcut-toggle-training",
+            Self::NextSector => "shortcut-next-sector",
+            Self::PrevSector => "shortcut-prev-sector",
+            Self::FocusSearch => "shortcut-focus-search",
+            Self::ToggleOverlay => "shortcut-toggle-overlay",
+        };
+        i18n::tr(id)
+    }
+}
+
+/// Consume this frame's shortcut key presses. State-only actions are applied directly;
+/// the rest are returned for the app shell to run.
+pub fn handle(ctx: &egui::Context, state: &mut AppState) -> Vec<Command> {
+    let mut commands = Vec::new();
+    for (shortcut, action) in SHORTCUTS {
+        if action == Action::FocusSearch || !ctx.input_mut(|i| i.consume_shortcut(&shortcut)) {
+            continue;
+        }
+        match action {
+            Action::Tab(tab) => state.active_tab = tab,
+            Action::Refresh => commands.push(Command::Refresh),
+            Action::Screenshot => commands.push(Command::Screenshot),
+            Action::CopyScreenshot => commands.push(Command::CopyScreenshot),
+            Action::ToggleTraining => {
+                state.active_tab = Tab::NeuralNet;
+                if let Err(e) = nn_view::toggle_training(state) {
+                    state.notices.error(NoticeSource::Training, e);
+                }
+            }
+            Action::NextSector | Action::PrevSector => {
+                let n = state.market_data.sectors.len();
+                if n > 0 {
+                    let step = if action == Action::NextSector { 1 } else { n - 1 };
+                    state.selected_sector_idx = (state.selected_sector_idx + step) % n;
+                }
+            }
+            Action::FocusSearch => {}
+            Action::ToggleOverlay => state.shortcuts_open = !state.shortcuts_open,
+        }
+    }
+    commands
+}
+
+/// Top-bar button opening the shortcuts overlay
+pub fn render_button(ui: &mut egui::Ui, state: &mut AppState) {
+    let hint = i18n::tr_args("shortcuts-hint", &[("key", ui.ctx().format_shortcut(&plain(Key::F1)))]);
+    if ui.selectable_label(state.shortcuts_open, "⌨").on_hover_text(hint).clicked() {
+        state.shortcuts_open = !state.shortcuts_open;
+    }
+}
+
+/// Floating window listing every shortcut
+pub fn render_overlay(ctx: &egui::Context, state: &mut AppState) {
+    egui::Window::new(i18n::tr("shortcuts-title"))
+        .id(egui::Id::new("shortcuts_window"))
+        .open(&mut state.shortcuts_open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            egui::Grid::new("shortcuts_grid").num_columns(2).spacing([24.0, 4.0]).striped(true).show(ui, |ui| {
+                for (shortcut, action) in SHORTCUTS {
+                    ui.monospace(ctx.format_shortcut(&shortcut));
+                    ui.label(action.description());
+                    ui.end_row();
+                }
+            });
+        });
+}
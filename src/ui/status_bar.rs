@@ -0,0 +1,155 @@
+use eframe::egui;
+
+use crate::app::AppState;
+use crate::data::fixtures::{self, FixtureMode};
+use crate::data::jobs;
+use crate::data::health::{DataSource, SourceHealth};
+use crate::notices::NoticeSource;
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::{diagnostics_view, jobs_view, notices_view, palette, tasks_view};
+
+/// Auto-refresh intervals offered in the status bar (minutes) and their catalog ids
+const AUTO_REFRESH_OPTIONS: [(Option<u64>, &str); 5] = [
+    (None, "auto-refresh-off"),
+    (Some(15), "auto-refresh-15m"),
+    (Some(30), "auto-refresh-30m"),
+    (Some(60), "auto-refresh-1h"),
+    (Some(240), "auto-refresh-4h"),
+];
+
+/// Data older than this is flagged as stale (hours)
+const STALE_HOURS: i64 = 24;
+
+/// Latest notice on the left; per-source freshness, cache and error counters, and the
+/// auto-refresh schedule on the right
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.horizontal(|ui| {
+        let fixtures = fixtures::mode();
+        if fixtures != FixtureMode::Live {
+            ui.colored_label(palette::neutral(), fixtures.label())
+                .on_hover_text(tr_args("fixtures-hover", &[("folder", fixtures::FIXTURE_DIR.to_string())]));
+            ui.separator();
+        }
+        notices_view::render_latest(ui, state);
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            render_schedule(ui, state);
+            ui.separator();
+            notices_view::render_indicator(ui, state);
+            jobs_view::render_indicator(ui, state);
+            tasks_view::render_indicator(ui, state);
+            diagnostics_view::render_indicator(ui, state);
+            ui.separator();
+
+            let health = &state.status.health;
+            let errors = health.total_errors();
+            let error_text = tr_args("status-errors", &[("count", i18n::integer(errors))]);
+            if errors > 0 {
+                ui.colored_label(egui::Color32::from_rgb(220, 50, 50), error_text);
+            } else {
+                ui.weak(error_text);
+            }
+            ui.weak(tr_args(
+                "status-cache",
+                &[
+                    ("hits", i18n::integer(health.total_cache_hits())),
+                    ("misses", i18n::integer(health.total_cache_misses())),
+                ],
+            ));
+            ui.separator();
+
+            for source in DataSource::ALL.iter().rev() {
+                render_source(ui, *source, health.get(*source));
+            }
+        });
+    });
+}
+
+/// Colored dot and age for one source, with details on hover
+fn render_source(ui: &mut egui::Ui, source: DataSource, health: &SourceHealth) {
+    let age = health.data_as_of.map(|t| chrono::Local::now() - t);
+    let color = if health.failing {
+        egui::Color32::from_rgb(220, 50, 50)
+    } else if age.is_some_and(|a| a.num_hours() >= STALE_HOURS) {
+        egui::Color32::from_rgb(230, 160, 40)
+    } else if age.is_some() {
+        egui::Color32::from_rgb(50, 180, 50)
+    } else {
+        ui.visuals().weak_text_color()
+    };
+    let age_text = age.map_or("-".to_string(), format_age);
+
+    let response = ui
+        .horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 3.0;
+            ui.colored_label(color, "●");
+            ui.label(format!("{} {}", source.label(), age_text));
+        })
+        .response;
+    response.on_hover_ui(|ui| {
+        ui.strong(source.label());
+        match health.data_as_of {
+            Some(t) => ui.label(tr_args("source-data-as-of", &[("time", i18n::date_time(t))])),
+            None => ui.label(tr("source-not-fetched")),
+        };
+        ui.label(tr_args(
+            "source-cache",
+            &[("hits", i18n::integer(health.cache_hits)), ("misses", i18n::integer(health.cache_misses))],
+        ));
+        ui.label(tr_args("status-errors", &[("count", i18n::integer(health.errors))]));
+        if let Some(e) = &health.last_error {
+            ui.colored_label(egui::Color32::from_rgb(220, 50, 50), tr_args("source-last-error", &[("error", e.clone())]));
+        }
+    });
+}
+
+/// Next auto-refresh countdown and the interval picker, which sets up the auto-refresh
+/// job. A job edited into another schedule in the Jobs window shows as custom.
+fn render_schedule(ui: &mut egui::Ui, state: &mut AppState) {
+    let current = state.jobs.auto_refresh().filter(|job| job.enabled).map(|job| jobs::cron_interval(&job.cron));
+    let selected = match current {
+        None => "auto-refresh-off",
+        Some(minutes) => AUTO_REFRESH_OPTIONS
+            .iter()
+            .find(|(m, _)| m.is_some() && *m == minutes)
+            .map_or("auto-refresh-custom", |(_, id)| *id),
+    };
+    let mut picked = None;
+    egui::ComboBox::from_id_salt("auto_refresh")
+        .selected_text(tr(selected))
+        .width(70.0)
+        .show_ui(ui, |ui| {
+            for (minutes, id) in AUTO_REFRESH_OPTIONS {
+                if ui.selectable_label(id == selected, tr(id)).clicked() && id != selected {
+                    picked = Some(minutes);
+                }
+            }
+        });
+    if let Some(minutes) = picked {
+        state.jobs.set_auto_refresh(minutes);
+        if let Err(e) = state.save_config() {
+            state.notices.error(NoticeSource::Settings, tr_args("auto-refresh-save-failed", &[("error", e)]));
+        }
+    }
+
+    let now = chrono::Local::now().naive_local();
+    match state.jobs.auto_refresh().and_then(|job| job.due_in(now)) {
+        Some(wait) if state.is_loading || wait.is_zero() => ui.label(tr("auto-refresh-now")),
+        Some(wait) => ui.label(tr_args(
+            "auto-refresh-next",
+            &[("wait", format_age(chrono::Duration::seconds(wait.as_secs() as i64)))],
+        )),
+        None => ui.weak(tr("auto-refresh-label")),
+    };
+}
+
+/// Compact duration such as "45s", "12m", "3h" or "2d"
+pub fn format_age(age: chrono::Duration) -> String {
+    let secs = age.num_seconds().max(0);
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86_399 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86_400),
+    }
+}
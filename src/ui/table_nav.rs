@@ -0,0 +1,36 @@
+//! Keyboard navigation and screen-reader labels for data tables. Each cell is made
+//! focusable, so Tab and the arrow keys move between cells (egui moves focus to the
+//! nearest focusable widget in the arrow's direction), and is announced with its row
+//! and column, e.g. "XLK, 21D Vol: 18.2%", instead of the bare value.
+
+use eframe::egui;
+
+/// Make the cell drawn as `cell` focusable and label it `"{row}, {column}: {value}"`
+/// for assistive tech. A focused cell gets a focus ring and is scrolled into view.
+/// Returns the focus response; check `has_focus()` on it for keyboard activation.
+pub fn cell(ui: &egui::Ui, cell: &egui::Response, row: &str, column: &str, value: &str) -> egui::Response {
+    let focus = ui.interact(cell.rect, cell.id.with("table_cell"), egui::Sense::focusable_noninteractive());
+    let label = format!("{}, {}: {}", row, column, value);
+    focus.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Label, true, &label));
+    if focus.gained_focus() {
+        focus.scroll_to_me(None);
+    }
+    if focus.has_focus() {
+        ui.painter().rect_stroke(cell.rect.expand(2.0), 2.0, ui.visuals().selection.stroke);
+    }
+    focus
+}
+
+/// Label a plain-text cell and make it navigable in one go
+pub fn label(ui: &mut egui::Ui, text: impl Into<egui::WidgetText>, row: &str, column: &str) -> egui::Response {
+    let text = text.into();
+    let value = text.text().to_string();
+    let response = ui.label(text);
+    cell(ui, &response, row, column, &value)
+}
+
+/// Whether Enter or Space was pressed while one of `cells` had keyboard focus
+pub fn activated(ui: &egui::Ui, cells: &[egui::Response]) -> bool {
+    cells.iter().any(|c| c.has_focus())
+        && ui.input(|i| i.key_pressed(egui::Key::Enter) || i.key_pressed(egui::Key::Space))
+}
@@ -0,0 +1,107 @@
+use eframe::egui;
+
+use crate::app::AppState;
+use crate::tasks::{Task, TaskStatus};
+use crate::ui::i18n::{self, tr, tr_args};
+use crate::ui::palette;
+
+/// Status-bar summary of background work: a spinner with the running count, or a
+/// warning when a recent task failed. Click to open the Tasks window.
+pub fn render_indicator(ui: &mut egui::Ui, state: &mut AppState) {
+    let running = state.tasks.running().count();
+    let failed = state.tasks.tasks().iter().filter(|t| matches!(t.status, TaskStatus::Failed(_))).count();
+
+    let response = if running > 0 {
+        ui.spinner();
+        ui.selectable_label(state.tasks_open, tr_args("tasks-running", &[("count", i18n::integer(running))]))
+    } else if failed > 0 {
+        ui.selectable_label(
+            state.tasks_open,
+            egui::RichText::new(tr_args("tasks-failed", &[("count", i18n::integer(failed))])).color(palette::negative()),
+        )
+    } else {
+        ui.selectable_label(state.tasks_open, tr("tasks-title"))
+    };
+    if response.on_hover_text(tr("tasks-hover")).clicked() {
+        state.tasks_open = !state.tasks_open;
+    }
+}
+
+/// Window listing running and recent tasks with progress, cancel buttons and errors
+pub fn render_window(ctx: &egui::Context, state: &mut AppState) {
+    if !state.tasks_open {
+        return;
+    }
+    let mut open = true;
+    let mut cancel = None;
+    let mut clear = false;
+    egui::Window::new(tr("tasks-title"))
+        .id(egui::Id::new("tasks_window"))
+        .open(&mut open)
+        .resizable(false)
+        .default_pos(ctx.screen_rect().right_bottom() + egui::vec2(-420.0, -300.0))
+        .show(ctx, |ui| {
+            if state.tasks.tasks().is_empty() {
+                ui.weak(tr("tasks-empty"));
+                return;
+            }
+            egui::Grid::new("tasks_grid").num_columns(4).striped(true).show(ui, |ui| {
+                for task in state.tasks.tasks().iter().rev() {
+                    ui.label(&task.label).on_hover_text(task.kind.label());
+                    render_status(ui, task);
+                    ui.weak(format!("{}s", i18n::number(task.elapsed().as_secs_f64(), 0)));
+                    if task.can_cancel() {
+                        if ui.small_button(tr("cancel")).clicked() {
+                            cancel = Some(task.id);
+                        }
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.add_space(4.0);
+            let any_finished = state.tasks.tasks().iter().any(|t| !t.is_running());
+            if ui.add_enabled(any_finished, egui::Button::new(tr("tasks-clear-finished"))).clicked() {
+                clear = true;
+            }
+        });
+    if let Some(id) = cancel {
+        state.tasks.cancel(id);
+    }
+    if clear {
+        state.tasks.clear_finished();
+    }
+    state.tasks_open = open;
+}
+
+fn render_status(ui: &mut egui::Ui, task: &Task) {
+    match &task.status {
+        TaskStatus::Running if task.cancel_requested => {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(tr("task-cancelling"));
+            });
+        }
+        TaskStatus::Running => match task.progress.fraction {
+            Some(fraction) => {
+                ui.add(egui::ProgressBar::new(fraction).desired_width(160.0).text(&task.progress.detail));
+            }
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(if task.progress.detail.is_empty() { tr("task-running") } else { task.progress.detail.clone() });
+                });
+            }
+        },
+        TaskStatus::Done => {
+            ui.colored_label(palette::positive(), tr("task-done"));
+        }
+        TaskStatus::Cancelled => {
+            ui.weak(tr("task-cancelled"));
+        }
+        TaskStatus::Failed(e) => {
+            ui.colored_label(palette::negative(), tr("task-failed")).on_hover_text(e);
+        }
+    }
+}
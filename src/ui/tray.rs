@@ -0,0 +1,223 @@
+//! Background mode: closing the window hides it to the system tray (or minimizes it
+//! when the app is built without the `tray` feature) while auto-refresh keeps running,
+//! and alert triggers are shown as native desktop notifications while it is hidden.
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::app::AppState;
+
+/// Whether this build can show a tray icon
+pub const AVAILABLE: bool = cfg!(feature = "tray");
+
+/// Seconds between wake-ups while the window is hidden, to poll the tray menu and
+/// keep the refresh scheduler running
+const HIDDEN_POLL_SECS: u64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TraySettings {
+    /// Closing the window hides it instead of quitting
+    pub minimize_to_tray: bool,
+    /// Show fired alerts as desktop notifications while the window is hidden
+    pub native_notifications: bool,
+}
+
+impl Default for TraySettings {
+    fn default() -> Self {
+        Self { minimize_to_tray: false, native_notifications: true }
+    }
+}
+
+/// Tray settings and the window's background state
+#[derive(Debug, Clone, Default)]
+pub struct TrayState {
+    /// Persisted
+    pub settings: TraySettings,
+    /// The window is hidden to the tray or minimized
+    pub hidden: bool,
+    /// The tray icon has been created
+    pub icon_active: bool,
+    /// Why the tray icon couldn't be created
+    pub icon_error: Option<String>,
+    /// Quit from the tray menu: let the next close request through
+    pub quitting: bool,
+}
+
+/// Actions picked from the tray menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(feature = "tray"), allow(dead_code))]
+pub enum TrayCommand {
+    Show,
+    Refresh,
+    Quit,
+}
+
+/// Per-frame tray handling: create the icon once minimize-to-tray is enabled, turn a
+/// close request into hiding the window, and apply tray menu picks. Returns the
+/// commands the caller handles (refresh).
+pub fn update(ctx: &egui::Context, state: &mut AppState) -> Vec<TrayCommand> {
+    let tray = &mut state.tray;
+    if tray.settings.minimize_to_tray && !tray.icon_active && tray.icon_error.is_none() {
+        match native::start() {
+            Ok(()) => tray.icon_active = true,
+            Err(e) => {
+                tracing::warn!("Tray icon unavailable: {}", e);
+                tray.icon_error = Some(e);
+            }
+        }
+    }
+
+    let (close_requested, minimized) =
+        ctx.input(|i| (i.viewport().close_requested(), i.viewport().minimized.unwrap_or(false)));
+    if close_requested && tray.settings.minimize_to_tray && !tray.quitting {
+        ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+        if tray.icon_active {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+        } else {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+        }
+        tray.hidden = true;
+    } else if !minimized && tray.hidden && !tray.icon_active {
+        // Restored from the taskbar
+        tray.hidden = false;
+    }
+
+    let mut unhandled = Vec::new();
+    for command in native::poll() {
+        match command {
+            TrayCommand::Show => {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+                ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+                tray.hidden = false;
+            }
+            TrayCommand::Quit => {
+                tray.quitting = true;
+                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+            TrayCommand::Refresh => unhandled.push(command),
+        }
+    }
+
+    if tray.hidden || tray.icon_active {
+        ctx.request_repaint_after(std::time::Duration::from_secs(HIDDEN_POLL_SECS));
+    }
+    unhandled
+}
+
+/// Show a desktop notification on a background thread. Failures are only logged.
+pub fn notify(summary: &str, body: &str) {
+    let (summary, body) = (summary.to_string(), body.to_string());
+    std::thread::spawn(move || {
+        let shown = notify_rust::Notification::new()
+            .appname("Volume Analysis")
+            .summary(&summary)
+            .body(&body)
+            .show();
+        if let Err(e) = shown {
+            tracing::warn!("Failed to show notification: {}", e);
+        }
+    });
+}
+
+#[cfg(feature = "tray")]
+mod native {
+    use tray_icon::menu::{Menu, MenuEvent, MenuItem};
+    use tray_icon::{Icon, TrayIconBuilder};
+
+    use super::TrayCommand;
+    use crate::ui::i18n::tr;
+
+    const MENU_SHOW: &str = "show";
+    const MENU_REFRESH: &str = "refresh";
+    const MENU_QUIT: &str = "quit";
+
+    /// Edge length of the generated tray icon (pixels)
+    const ICON_SIZE: u32 = 32;
+
+    /// Create the tray icon. On Linux it lives on its own GTK thread; elsewhere it is
+    /// created on the UI thread, which runs the platform event loop, and kept for the
+    /// rest of the session.
+    pub fn start() -> Result<(), String> {
+        #[cfg(target_os = "linux")]
+        {
+            let (tx, rx) = std::sync::mpsc::channel();
+            std::thread::spawn(move || {
+                if let Err(e) = gtk::init() {
+                    tx.send(Err(format!("GTK init failed: {}", e))).ok();
+                    return;
+                }
+                match build() {
+                    Ok(icon) => {
+                        tx.send(Ok(())).ok();
+                        let _icon = icon;
+                        gtk::main();
+                    }
+                    Err(e) => {
+                        tx.send(Err(e)).ok();
+                    }
+                }
+            });
+            rx.recv().map_err(|e| e.to_string())?
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            std::mem::forget(build()?);
+            Ok(())
+        }
+    }
+
+    fn build() -> Result<tray_icon::TrayIcon, String> {
+        let menu = Menu::with_items(&[
+            &MenuItem::with_id(MENU_SHOW, tr("tray-show"), true, None),
+            &MenuItem::with_id(MENU_REFRESH, tr("tray-refresh"), true, None),
+            &MenuItem::with_id(MENU_QUIT, tr("tray-quit"), true, None),
+        ])
+        .map_err(|e| e.to_string())?;
+        TrayIconBuilder::new()
+            .with_tooltip("Volume Analysis")
+            .with_icon(icon()?)
+            .with_menu(Box::new(menu))
+            .build()
+            .map_err(|e| e.to_string())
+    }
+
+    /// A filled green circle
+    fn icon() -> Result<Icon, String> {
+        let center = (ICON_SIZE as f32 - 1.0) / 2.0;
+        let rgba = (0..ICON_SIZE * ICON_SIZE)
+            .flat_map(|i| {
+                let (x, y) = ((i % ICON_SIZE) as f32, (i / ICON_SIZE) as f32);
+                let inside = (x - center).hypot(y - center) <= center;
+                if inside { [50, 180, 50, 255] } else { [0, 0, 0, 0] }
+            })
+            .collect();
+        Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).map_err(|e| e.to_string())
+    }
+
+    pub fn poll() -> Vec<TrayCommand> {
+        MenuEvent::receiver()
+            .try_iter()
+            .filter_map(|event| match event.id.0.as_str() {
+                MENU_SHOW => Some(TrayCommand::Show),
+                MENU_REFRESH => Some(TrayCommand::Refresh),
+                MENU_QUIT => Some(TrayCommand::Quit),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(feature = "tray"))]
+mod native {
+    use super::TrayCommand;
+
+    pub fn start() -> Result<(), String> {
+        Err("built without the `tray` feature".to_string())
+    }
+
+    pub fn poll() -> Vec<TrayCommand> {
+        Vec::new()
+    }
+}
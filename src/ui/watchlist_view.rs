@@ -0,0 +1,277 @@
+use eframe::egui;
+use egui_plot::{Line, Plot, PlotPoints};
+
+use crate::analysis;
+use crate::app::{AppState, WatchlistEntry, WatchlistState};
+use crate::data::models::{AnalysisWindows, SectorTimeSeries};
+use crate::notices::NoticeSource;
+use crate::tasks::{TaskHooks, TaskKind, TaskManager};
+use crate::ui::i18n::{self, tr, tr_args};
+
+/// Closes shown in each card's sparkline (~6 months)
+const SPARKLINE_BARS: usize = 126;
+
+pub fn render(ui: &mut egui::Ui, state: &mut AppState) {
+    ui.heading(tr("tab-watchlist"));
+    ui.add_space(8.0);
+
+    let windows = state.analysis_windows;
+    let watchlist = &mut state.watchlist;
+    let tasks = &mut state.tasks;
+    collect_results(watchlist, &windows);
+
+    // Symbols not yet fetched this session (e.g. restored from the saved list)
+    let unfetched: Vec<String> = watchlist
+        .symbols
+        .iter()
+        .filter(|s| {
+            !watchlist.pending.contains(s)
+                && !watchlist.entries.iter().any(|e| &e.series.symbol == *s)
+                && !watchlist.errors.iter().any(|(sym, _)| sym == *s)
+        })
+        .cloned()
+        .collect();
+    for symbol in unfetched {
+        start_fetch(tasks, watchlist, symbol, windows.lookback_days);
+    }
+
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(tr("watchlist-add-symbol"));
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut watchlist.input)
+                .hint_text(tr("watchlist-add-hint"))
+                .desired_width(160.0),
+        );
+        let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        if ui.button(tr("watchlist-add")).clicked() || submitted {
+            let symbol = watchlist.input.trim().to_uppercase();
+            if is_valid_symbol(&symbol) && !watchlist.symbols.contains(&symbol) {
+                watchlist.symbols.push(symbol.clone());
+                watchlist.errors.retain(|(s, _)| *s != symbol);
+                start_fetch(tasks, watchlist, symbol, windows.lookback_days);
+                changed = true;
+            }
+            watchlist.input.clear();
+        }
+        ui.separator();
+        let busy = !watchlist.pending.is_empty();
+        if ui.add_enabled(!busy, egui::Button::new(tr("watchlist-refresh-all"))).clicked() {
+            watchlist.errors.clear();
+            for symbol in watchlist.symbols.clone() {
+                start_fetch(tasks, watchlist, symbol, windows.lookback_days);
+            }
+        }
+        if busy {
+            ui.spinner();
+            ui.label(tr_args("watchlist-fetching", &[("symbols", watchlist.pending.join(", "))]));
+        }
+    });
+    ui.small(tr_args(
+        "watchlist-intro",
+        &[("short", windows.short_vol.to_string()), ("long", windows.long_vol.to_string())],
+    ));
+    ui.add_space(8.0);
+
+    if watchlist.symbols.is_empty() {
+        ui.label(tr("watchlist-empty"));
+    }
+
+    let mut remove = None;
+    ui.horizontal_wrapped(|ui| {
+        for symbol in &watchlist.symbols {
+            let highlighted = watchlist.highlight.as_ref() == Some(symbol);
+            let mut frame = egui::Frame::group(ui.style());
+            if highlighted {
+                frame = frame.stroke(ui.visuals().selection.stroke);
+            }
+            let card = frame.show(ui, |ui| {
+                ui.set_width(240.0);
+                ui.horizontal(|ui| {
+                    ui.strong(symbol);
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("✕").on_hover_text(tr("watchlist-remove")).clicked() {
+                            remove = Some(symbol.clone());
+                        }
+                    });
+                });
+                if let Some(entry) = watchlist.entries.iter().find(|e| &e.series.symbol == symbol) {
+                    render_card_body(ui, entry, &windows);
+                } else if let Some((_, err)) = watchlist.errors.iter().find(|(s, _)| s == symbol) {
+                    ui.colored_label(egui::Color32::from_rgb(220, 50, 50), err);
+                } else {
+                    ui.spinner();
+                }
+            });
+            if highlighted {
+                card.response.scroll_to_me(Some(egui::Align::Center));
+            }
+        }
+    });
+    watchlist.highlight = None;
+
+    if let Some(symbol) = remove {
+        watchlist.symbols.retain(|s| *s != symbol);
+        watchlist.entries.retain(|e| e.series.symbol != symbol);
+        watchlist.errors.retain(|(s, _)| *s != symbol);
+        changed = true;
+    }
+    if changed {
+        if let Err(e) = state.save_config() {
+            state.notices.error(NoticeSource::Settings, tr_args("watchlist-save-failed", &[("error", e)]));
+        }
+    }
+}
+
+fn render_card_body(ui: &mut egui::Ui, entry: &WatchlistEntry, windows: &AnalysisWindows) {
+    let bars = &entry.series.bars;
+    let vm = &entry.volatility;
+    if let [.., prev, last] = bars.as_slice() {
+        let change = last.close / prev.close - 1.0;
+        let color = if change >= 0.0 {
+            egui::Color32::from_rgb(50, 180, 50)
+        } else {
+            egui::Color32::from_rgb(220, 50, 50)
+        };
+        ui.horizontal(|ui| {
+            ui.label(i18n::number(last.close, 2));
+            ui.colored_label(color, tr_args("unit-percent", &[("value", i18n::signed(change * 100.0, 2))]));
+            ui.weak(i18n::date(last.date));
+        });
+    }
+
+    if let (Some(sv), Some(lv), Some(vr)) =
+        (vm.short_window_vol.last(), vm.long_window_vol.last(), vm.vol_ratio.last())
+    {
+        ui.label(tr_args(
+            "watchlist-vols",
+            &[
+                ("short", windows.short_vol.to_string()),
+                ("short_vol", i18n::percent(*sv, 1)),
+                ("long", windows.long_vol.to_string()),
+                ("long_vol", i18n::percent(*lv, 1)),
+            ],
+        ));
+        let ratio_color = if *vr > 1.2 {
+            egui::Color32::from_rgb(220, 50, 50)
+        } else if *vr < 0.8 {
+            egui::Color32::from_rgb(50, 180, 50)
+        } else {
+            ui.visuals().text_color()
+        };
+        ui.horizontal(|ui| {
+            ui.colored_label(ratio_color, tr_args("watchlist-ratio", &[("ratio", i18n::number(*vr, 2))]));
+            if let Some(p) = analysis::tail_risk::percentile_rank(&vm.short_window_vol, *sv) {
+                ui.label(tr_args("watchlist-percentile", &[("rank", i18n::number(p, 0))]));
+            }
+        });
+    }
+
+    let recent = &bars[bars.len().saturating_sub(SPARKLINE_BARS)..];
+    let up = recent.last().map(|b| b.close) >= recent.first().map(|b| b.close);
+    let line_color = if up {
+        egui::Color32::from_rgb(50, 180, 50)
+    } else {
+        egui::Color32::from_rgb(220, 50, 50)
+    };
+    Plot::new(("watchlist_sparkline", &entry.series.symbol))
+        .height(50.0)
+        .show_axes(false)
+        .show_grid(false)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .allow_double_click_reset(false)
+        .show_x(false)
+        .show_y(false)
+        .show(ui, |plot_ui| {
+            let points: PlotPoints = recent.iter().enumerate().map(|(i, b)| [i as f64, b.close]).collect();
+            plot_ui.line(Line::new(points).color(line_color));
+        });
+}
+
+/// Tickers are short runs of letters, digits and the `.-^=` used by index, class and
+/// crypto symbols
+fn is_valid_symbol(symbol: &str) -> bool {
+    !symbol.is_empty()
+        && symbol.len() <= 12
+        && symbol.chars().all(|c| c.is_ascii_alphanumeric() || ".-^=".contains(c))
+}
+
+/// Fetch `symbol`'s history as a background task; the result lands in `watchlist.results`.
+fn start_fetch(tasks: &mut TaskManager, watchlist: &mut WatchlistState, symbol: String, lookback_days: u32) {
+    if watchlist.pending.contains(&symbol) {
+        return;
+    }
+    watchlist.pending.push(symbol.clone());
+    let slot = watchlist.results.clone();
+
+    // A cancelled fetch is recorded as an error so the symbol stops showing as pending
+    let cancel_slot = slot.clone();
+    let cancel_symbol = symbol.clone();
+    let hooks = TaskHooks {
+        cancel: Some(Box::new(move || {
+            if let Ok(mut results) = cancel_slot.lock() {
+                results.push((cancel_symbol.clone(), Err(tr("task-cancelled"))));
+            }
+        })),
+        progress: None,
+    };
+    tasks.spawn(TaskKind::Watchlist, tr_args("watchlist-task", &[("symbol", symbol.clone())]), hooks, async move {
+        let result = crate::data::yahoo::fetch_symbol_history(&symbol, &symbol, lookback_days)
+            .await
+            .map(|fetched| fetched.data)
+            .map_err(|e| format!("{:#}", e));
+        let error = result.as_ref().err().cloned();
+        if let Ok(mut results) = slot.lock() {
+            results.push((symbol, result));
+        }
+        error.map_or(Ok(()), Err)
+    });
+}
+
+/// Move finished fetches into `entries` / `errors`
+fn collect_results(watchlist: &mut WatchlistState, windows: &AnalysisWindows) {
+    let finished: Vec<_> = match watchlist.results.lock() {
+        Ok(mut results) => results.drain(..).collect(),
+        Err(_) => return,
+    };
+    for (symbol, result) in finished {
+        watchlist.pending.retain(|s| *s != symbol);
+        watchlist.entries.retain(|e| e.series.symbol != symbol);
+        watchlist.errors.retain(|(s, _)| *s != symbol);
+        match result.and_then(|series| watchlist_entry(series, windows)) {
+            Ok(entry) => watchlist.entries.push(entry),
+            Err(e) => watchlist.errors.push((symbol, e)),
+        }
+    }
+}
+
+/// Recompute every entry's vol metrics after the analysis windows change; entries
+/// without enough history for the new windows move to `errors`
+pub fn recompute_entries(watchlist: &mut WatchlistState, windows: &AnalysisWindows) {
+    for entry in std::mem::take(&mut watchlist.entries) {
+        let symbol = entry.series.symbol.clone();
+        match watchlist_entry(entry.series, windows) {
+            Ok(entry) => watchlist.entries.push(entry),
+            Err(e) => watchlist.errors.push((symbol, e)),
+        }
+    }
+}
+
+fn watchlist_entry(series: SectorTimeSeries, windows: &AnalysisWindows) -> Result<WatchlistEntry, String> {
+    if series.bars.len() <= windows.long_vol + 1 {
+        return Err(tr_args("watchlist-short-history", &[("count", i18n::integer(series.bars.len()))]));
+    }
+    let volatility = analysis::volatility::compute_sector_volatility(
+        &series.symbol,
+        &series.dates(),
+        &series.log_returns(),
+        &series.highs(),
+        &series.lows(),
+        windows.short_vol,
+        windows.long_vol,
+    );
+    Ok(WatchlistEntry { series, volatility })
+}